@@ -110,11 +110,7 @@ fn benchmark_value_serialization(c: &mut Criterion) {
         ("simple_string", Value::String("hello world".to_string())),
         (
             "small_vector",
-            Value::Vector(vec![
-                Value::Integer(1),
-                Value::Integer(2),
-                Value::Integer(3),
-            ]),
+            Value::Vector(vec![Value::Integer(1), Value::Integer(2), Value::Integer(3)].into()),
         ),
         (
             "small_map",
@@ -45,7 +45,7 @@ use ccos::CCOS;
 use clap::Parser;
 use rtfs::runtime::error::RuntimeResult;
 use rtfs::runtime::security::RuntimeContext;
-use rtfs::runtime::values::Value;
+use rtfs::runtime::values::{Value, ValueMap};
 use serde_json;
 
 // Import Modular Planner components
@@ -1342,16 +1342,14 @@ Respond ONLY with the corrected RTFS function code. Do not add markdown formatti
                     Value::Map(m) => {
                         // Check if it's the marketplace wrapper format {:args [...]}
                         if let Some(inner) = m.get(&rtfs::ast::MapKey::Keyword(rtfs::ast::Keyword(
-                            "args".to_string(),
+                            "args".into(),
                         ))) {
                             // If it's a list, we need to decide if we want the first element or the list itself
                             // The previous code assumed list[0] if len == 1, but for synthesized caps expecting a map, this might be right.
                             // However, if the synthesis expects specific keys, we might need to handle it better.
                             match inner {
                                 Value::List(list) if list.len() == 1 => list[0].clone(),
-                                Value::List(list) if list.is_empty() => {
-                                    Value::Map(std::collections::HashMap::new())
-                                }
+                                Value::List(list) if list.is_empty() => Value::Map(ValueMap::new()),
                                 // If multiple args, wrap them in a map with indices or similar?
                                 // For now, let's just pass the inner value if it's not a single-element list
                                 _ => inner.clone(),
@@ -2233,7 +2231,7 @@ fn value_to_rtfs_literal(value: &Value) -> String {
             let items: Vec<String> = l.iter().map(value_to_rtfs_literal).collect();
             format!("({})", items.join(" "))
         }
-        Value::Symbol(s) => s.0.clone(),
+        Value::Symbol(s) => s.0.to_string(),
         Value::Keyword(k) => format!(":{}", k.0),
         Value::Timestamp(t) => format!("\"{}\"", t),
         Value::Uuid(u) => format!("\"{}\"", u),
@@ -2444,10 +2442,10 @@ fn json_to_rtfs_value(v: serde_json::Value) -> Value {
             Value::Vector(a.into_iter().map(json_to_rtfs_value).collect())
         }
         serde_json::Value::Object(o) => {
-            let mut map = HashMap::new();
+            let mut map = ValueMap::new();
             for (k, v) in o {
                 map.insert(
-                    rtfs::ast::MapKey::Keyword(rtfs::ast::Keyword(k)),
+                    rtfs::ast::MapKey::Keyword(rtfs::ast::Keyword(k.into())),
                     json_to_rtfs_value(v),
                 );
             }
@@ -38,7 +38,7 @@ use ccos::config::types::AgentConfig;
 use ccos::mcp::core::MCPDiscoveryService;
 use ccos::mcp::types::DiscoveryOptions;
 use rtfs::ast::{Keyword, MapKey};
-use rtfs::runtime::values::Value;
+use rtfs::runtime::values::{Value, ValueMap};
 use tokio::sync::RwLock;
 
 #[derive(Parser, Debug)]
@@ -457,7 +457,7 @@ impl CapabilityExplorer {
                     kw.push(c);
                     chars.next();
                 }
-                Ok(Value::Keyword(Keyword(kw)))
+                Ok(Value::Keyword(Keyword(kw.into())))
             }
             Some('[') => {
                 // Vector
@@ -477,7 +477,7 @@ impl CapabilityExplorer {
                     }
                     items.push(Self::parse_rtfs_value(chars)?);
                 }
-                Ok(Value::Vector(items))
+                Ok(Value::Vector(items.into()))
             }
             Some('t') | Some('f') => {
                 // Boolean
@@ -519,7 +519,7 @@ impl CapabilityExplorer {
     fn get_string_arg(args: &HashMap<String, Value>, key: &str) -> Option<String> {
         args.get(key).and_then(|v| match v {
             Value::String(s) => Some(s.clone()),
-            Value::Keyword(k) => Some(k.0.clone()),
+            Value::Keyword(k) => Some(k.0.to_string()),
             _ => None,
         })
     }
@@ -629,7 +629,7 @@ impl CapabilityExplorer {
                 let mut obj = serde_json::Map::new();
                 for (k, v) in m {
                     let key = match k {
-                        MapKey::Keyword(kw) => kw.0.clone(),
+                        MapKey::Keyword(kw) => kw.0.to_string(),
                         MapKey::String(s) => s.clone(),
                         MapKey::Integer(i) => i.to_string(),
                     };
@@ -648,19 +648,19 @@ impl CapabilityExplorer {
         let server_values: Vec<Value> = servers
             .iter()
             .map(|s| {
-                let mut map = HashMap::new();
+                let mut map = ValueMap::new();
                 map.insert(
-                    MapKey::Keyword(Keyword("name".to_string())),
+                    MapKey::Keyword(Keyword("name".into())),
                     Value::String(s.name.clone()),
                 );
                 map.insert(
-                    MapKey::Keyword(Keyword("endpoint".to_string())),
+                    MapKey::Keyword(Keyword("endpoint".into())),
                     Value::String(s.endpoint.clone()),
                 );
                 Value::Map(map)
             })
             .collect();
-        Value::Vector(server_values)
+        Value::Vector(server_values.into())
     }
 
     async fn discover_rtfs(
@@ -729,7 +729,7 @@ impl CapabilityExplorer {
                     .iter()
                     .map(|m| Value::String(m.id.clone()))
                     .collect();
-                Ok(Value::Vector(ids))
+                Ok(Value::Vector(ids.into()))
             }
             Err(e) => Err(format!("Discovery failed: {}", e)),
         }
@@ -740,23 +740,23 @@ impl CapabilityExplorer {
         let values: Vec<Value> = results
             .iter()
             .map(|r| {
-                let mut map = HashMap::new();
+                let mut map = ValueMap::new();
                 map.insert(
-                    MapKey::Keyword(Keyword("id".to_string())),
+                    MapKey::Keyword(Keyword("id".into())),
                     Value::String(r.entry.id.clone()),
                 );
                 map.insert(
-                    MapKey::Keyword(Keyword("name".to_string())),
+                    MapKey::Keyword(Keyword("name".into())),
                     Value::String(r.entry.name.clone().unwrap_or_default()),
                 );
                 map.insert(
-                    MapKey::Keyword(Keyword("score".to_string())),
+                    MapKey::Keyword(Keyword("score".into())),
                     Value::Float(r.score as f64),
                 );
                 Value::Map(map)
             })
             .collect();
-        Value::Vector(values)
+        Value::Vector(values.into())
     }
 
     fn list_rtfs(&self) -> Value {
@@ -764,23 +764,23 @@ impl CapabilityExplorer {
             .discovered_tools
             .iter()
             .map(|t| {
-                let mut map = HashMap::new();
+                let mut map = ValueMap::new();
                 map.insert(
-                    MapKey::Keyword(Keyword("id".to_string())),
+                    MapKey::Keyword(Keyword("id".into())),
                     Value::String(t.manifest.id.clone()),
                 );
                 map.insert(
-                    MapKey::Keyword(Keyword("name".to_string())),
+                    MapKey::Keyword(Keyword("name".into())),
                     Value::String(t.manifest.name.clone()),
                 );
                 map.insert(
-                    MapKey::Keyword(Keyword("server".to_string())),
+                    MapKey::Keyword(Keyword("server".into())),
                     Value::String(t.server_name.clone()),
                 );
                 Value::Map(map)
             })
             .collect();
-        Value::Vector(values)
+        Value::Vector(values.into())
     }
 
     async fn inspect_rtfs(&self, id: &str) -> Result<Value, String> {
@@ -802,21 +802,21 @@ impl CapabilityExplorer {
     }
 
     fn manifest_to_value(&self, manifest: &CapabilityManifest) -> Value {
-        let mut map = HashMap::new();
+        let mut map = ValueMap::new();
         map.insert(
-            MapKey::Keyword(Keyword("id".to_string())),
+            MapKey::Keyword(Keyword("id".into())),
             Value::String(manifest.id.clone()),
         );
         map.insert(
-            MapKey::Keyword(Keyword("name".to_string())),
+            MapKey::Keyword(Keyword("name".into())),
             Value::String(manifest.name.clone()),
         );
         map.insert(
-            MapKey::Keyword(Keyword("description".to_string())),
+            MapKey::Keyword(Keyword("description".into())),
             Value::String(manifest.description.clone()),
         );
         map.insert(
-            MapKey::Keyword(Keyword("version".to_string())),
+            MapKey::Keyword(Keyword("version".into())),
             Value::String(manifest.version.clone()),
         );
 
@@ -830,14 +830,14 @@ impl CapabilityExplorer {
             _ => "Other".to_string(),
         };
         map.insert(
-            MapKey::Keyword(Keyword("provider".to_string())),
+            MapKey::Keyword(Keyword("provider".into())),
             Value::String(provider_str),
         );
 
         // Add schema info
         if let Some(schema) = &manifest.input_schema {
             map.insert(
-                MapKey::Keyword(Keyword("input_schema".to_string())),
+                MapKey::Keyword(Keyword("input_schema".into())),
                 Value::String(format!("{:?}", schema)),
             );
         }
@@ -852,9 +852,9 @@ impl CapabilityExplorer {
         quiet: bool,
     ) -> Result<Value, String> {
         // Convert args HashMap to RTFS Map
-        let mut rtfs_map = HashMap::new();
+        let mut rtfs_map = ValueMap::new();
         for (k, v) in args {
-            rtfs_map.insert(MapKey::Keyword(Keyword(k)), v);
+            rtfs_map.insert(MapKey::Keyword(Keyword(k.into())), v);
         }
         let input = Value::Map(rtfs_map);
 
@@ -1850,7 +1850,7 @@ impl CapabilityExplorer {
 
         if let Some(schema) = &manifest.input_schema {
             if let TypeExpr::Map { entries, .. } = schema {
-                let mut map = std::collections::HashMap::new();
+                let mut map = ValueMap::new();
 
                 println!("  Enter values for each parameter (or 'skip' to use default, 'cancel' to abort):");
                 println!();
@@ -1989,9 +1989,9 @@ impl CapabilityExplorer {
                 Value::Vector(arr.iter().map(|v| self.json_to_rtfs_value(v)).collect())
             }
             serde_json::Value::Object(obj) => {
-                let mut map = std::collections::HashMap::new();
+                let mut map = ValueMap::new();
                 for (k, v) in obj {
-                    let key = rtfs::ast::MapKey::Keyword(rtfs::ast::Keyword(k.clone()));
+                    let key = rtfs::ast::MapKey::Keyword(rtfs::ast::Keyword::new(k.as_str()));
                     map.insert(key, self.json_to_rtfs_value(v));
                 }
                 Value::Map(map)
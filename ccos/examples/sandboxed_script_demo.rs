@@ -23,7 +23,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
         MapKey::String("task".to_string()),
         Value::String("demonstrate generic sandboxing".to_string()),
     );
-    let input = Value::Map(input_map);
+    let input = Value::Map(input_map.into());
 
     // 1. Python in Process
     println!("🚀 [1/2] Running Python script in local Process provider...");
@@ -75,7 +75,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
         MapKey::String("large_data".to_string()),
         Value::String(large_string),
     );
-    let large_input = Value::Map(large_map);
+    let large_input = Value::Map(large_map.into());
 
     let python_large = ProviderType::Sandboxed(SandboxedCapability {
         runtime: "python".to_string(),
@@ -52,7 +52,7 @@ mod single_mcp_discovery_impl {
                 for (key, value) in map {
                     let key_str = match key {
                         rtfs::ast::MapKey::String(s) => s.clone(),
-                        rtfs::ast::MapKey::Keyword(k) => k.0.clone(),
+                        rtfs::ast::MapKey::Keyword(k) => k.0.to_string(),
                         rtfs::ast::MapKey::Integer(i) => i.to_string(),
                     };
                     json_obj.insert(key_str, rtfs_value_to_json(value));
@@ -60,7 +60,7 @@ mod single_mcp_discovery_impl {
                 serde_json::Value::Object(json_obj)
             }
             RtfsValue::Keyword(k) => serde_json::Value::String(format!(":{}", k.0)),
-            RtfsValue::Symbol(s) => serde_json::Value::String(s.0.clone()),
+            RtfsValue::Symbol(s) => serde_json::Value::String(s.0.to_string()),
             RtfsValue::Timestamp(ts) => serde_json::Value::String(format!("@{}", ts)),
             RtfsValue::Uuid(uuid) => serde_json::Value::String(format!("@{}", uuid)),
             RtfsValue::ResourceHandle(handle) => serde_json::Value::String(format!("@{}", handle)),
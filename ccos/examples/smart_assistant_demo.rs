@@ -45,7 +45,7 @@ use rtfs::ast::{Expression, Keyword, Literal, MapKey, MapTypeEntry, PrimitiveTyp
 use rtfs::config::profile_selection::expand_profiles;
 use rtfs::parser::parse_expression;
 use rtfs::runtime::error::{RuntimeError, RuntimeResult};
-use rtfs::runtime::values::Value;
+use rtfs::runtime::values::{Value, ValueMap};
 use serde_json::{self, Value as JsonValue};
 use std::time::SystemTime;
 use toml;
@@ -1273,7 +1273,7 @@ async fn generate_clarifying_questions(
 
 fn extract_question_items(value: &Value) -> Option<Vec<Value>> {
     match value {
-        Value::Vector(items) | Value::List(items) => Some(items.clone()),
+        Value::Vector(items) | Value::List(items) => Some(items.iter().cloned().collect()),
         Value::Map(map) => {
             let keys = [
                 "questions",
@@ -1392,7 +1392,7 @@ fn json_to_demo_value(value: &serde_json::Value) -> Value {
             Value::Vector(vec)
         }
         serde_json::Value::Object(map) => {
-            let mut rtfs_map = HashMap::with_capacity(map.len());
+            let mut rtfs_map = ValueMap::new();
             for (key, val) in map {
                 rtfs_map.insert(json_key_to_map_key(key), json_to_demo_value(val));
             }
@@ -1403,7 +1403,7 @@ fn json_to_demo_value(value: &serde_json::Value) -> Value {
 
 fn json_key_to_map_key(key: &str) -> MapKey {
     if let Some(stripped) = key.trim().strip_prefix(':') {
-        MapKey::Keyword(Keyword(stripped.to_string()))
+        MapKey::Keyword(Keyword(stripped.into()))
     } else {
         MapKey::String(key.trim().to_string())
     }
@@ -1415,7 +1415,7 @@ fn json_string_to_value(raw: &str) -> Value {
         if stripped.is_empty() {
             Value::String(trimmed.to_string())
         } else {
-            Value::Keyword(Keyword(stripped.to_string()))
+            Value::Keyword(Keyword(stripped.into()))
         }
     } else {
         Value::String(trimmed.to_string())
@@ -1513,9 +1513,9 @@ mod tests {
         let mut inner = HashMap::new();
         inner.insert(
             MapKey::Keyword(Keyword("clarifying-questions".to_string())),
-            Value::Vector(vec![Value::String("Budget?".into())]),
+            Value::Vector(vec![Value::String("Budget?".into())].into()),
         );
-        let map_value = Value::Map(inner);
+        let map_value = Value::Map(inner.into());
         let items = extract_question_items(&map_value).expect("map alias yields items");
         assert_eq!(items.len(), 1);
     }
@@ -2269,7 +2269,7 @@ fn expression_to_value(expr: &Expression) -> Value {
             Value::List(list)
         }
         Expression::Map(entries) => {
-            let mut map = HashMap::new();
+            let mut map = ValueMap::new();
             for (key, value) in entries {
                 map.insert(key.clone(), expression_to_value(value));
             }
@@ -2474,7 +2474,7 @@ fn json_to_rtfs_value(json: &JsonValue) -> Value {
         JsonValue::String(s) => Value::String(s.clone()),
         JsonValue::Array(items) => Value::Vector(items.iter().map(json_to_rtfs_value).collect()),
         JsonValue::Object(map) => {
-            let mut rtfs_map = HashMap::new();
+            let mut rtfs_map = ValueMap::new();
             for (k, v) in map {
                 rtfs_map.insert(MapKey::String(k.clone()), json_to_rtfs_value(v));
             }
@@ -2552,7 +2552,7 @@ fn slugify(text: &str) -> String {
     slug.trim_matches('-').to_string()
 }
 
-fn map_get<'a>(map: &'a HashMap<MapKey, Value>, key: &str) -> Option<&'a Value> {
+fn map_get<'a>(map: &'a ValueMap, key: &str) -> Option<&'a Value> {
     let normalized = key.trim_matches(':');
     for (map_key, value) in map {
         match map_key {
@@ -2568,8 +2568,8 @@ fn map_get<'a>(map: &'a HashMap<MapKey, Value>, key: &str) -> Option<&'a Value>
 fn value_to_string(value: &Value) -> Option<String> {
     match value {
         Value::String(s) => Some(s.clone()),
-        Value::Keyword(k) => Some(k.0.clone()),
-        Value::Symbol(sym) => Some(sym.0.clone()),
+        Value::Keyword(k) => Some(k.0.to_string()),
+        Value::Symbol(sym) => Some(sym.0.to_string()),
         Value::Integer(i) => Some(i.to_string()),
         Value::Float(f) => Some(f.to_string()),
         Value::Boolean(b) => Some(b.to_string()),
@@ -2640,7 +2640,7 @@ fn parse_answer_value(kind: AnswerKind, raw: &str) -> Value {
                 .filter(|s| !s.is_empty())
                 .map(|s| Value::String(s.to_string()))
                 .collect();
-            Value::Vector(items)
+            Value::Vector(items.into())
         }
         AnswerKind::Number => {
             if let Ok(i) = raw.trim().parse::<i64>() {
@@ -2717,7 +2717,7 @@ async fn register_stub_capabilities(ccos: &Arc<CCOS>) -> DemoResult<Vec<StubCapa
         let description = spec.description.to_string();
         let handler_id = id.clone();
         let handler = Arc::new(move |_input: &Value| -> RuntimeResult<Value> {
-            let mut out = HashMap::new();
+            let mut out = ValueMap::new();
             out.insert(
                 MapKey::String("status".into()),
                 Value::String("ok".to_string()),
@@ -2834,7 +2834,7 @@ fn build_needs_capabilities(steps: &[ProposedStep]) -> Value {
                 step.expected_outputs.clone(),
                 rationale,
             );
-            let mut map = HashMap::new();
+            let mut map = ValueMap::new();
             map.insert(
                 MapKey::String("class".into()),
                 Value::String(step.capability_class.clone()),
@@ -2877,7 +2877,7 @@ fn build_needs_capabilities(steps: &[ProposedStep]) -> Value {
             Value::Map(map)
         })
         .collect();
-    Value::Vector(entries)
+    Value::Vector(entries.into())
 }
 
 #[derive(Debug, Clone)]
@@ -3370,13 +3370,13 @@ fn compute_input_bindings_for_step(
             let mut candidate_keys: Vec<String> = entries
                 .iter()
                 .filter(|entry| !entry.optional)
-                .map(|entry| entry.key.0.clone())
+                .map(|entry| entry.key.0.to_string())
                 .collect();
             candidate_keys.extend(
                 entries
                     .iter()
                     .filter(|entry| entry.optional)
-                    .map(|entry| entry.key.0.clone()),
+                    .map(|entry| entry.key.0.to_string()),
             );
 
             for input in &step.required_inputs {
@@ -3705,7 +3705,7 @@ async fn register_stub_capability(
     let step_copy = step.clone();
 
     let handler = Arc::new(move |_inputs: &Value| {
-        let mut out_map = HashMap::new();
+        let mut out_map = ValueMap::new();
         for output_key in &step_copy.expected_outputs {
             out_map.insert(
                 MapKey::String(output_key.clone()),
@@ -3903,7 +3903,7 @@ async fn register_orchestrator_in_marketplace(
 
     // Create a handler that returns the RTFS plan code when invoked
     let handler = Arc::new(move |_inputs: &Value| {
-        let mut out_map = HashMap::new();
+        let mut out_map = ValueMap::new();
         out_map.insert(
             MapKey::String("plan".into()),
             Value::String(rtfs_code.clone()),
@@ -4557,7 +4557,7 @@ fn extract_plan_properties(plan_rtfs: &str) -> DemoResult<ExtractedPlanPropertie
                                 for (key, expr) in map {
                                     let key_str = match key {
                                         rtfs::ast::MapKey::String(s) => s.clone(),
-                                        rtfs::ast::MapKey::Keyword(k) => k.0.clone(),
+                                        rtfs::ast::MapKey::Keyword(k) => k.0.to_string(),
                                         rtfs::ast::MapKey::Integer(i) => i.to_string(),
                                     };
                                     if let Some(value) =
@@ -4665,10 +4665,10 @@ fn value_to_rtfs_value(value: &Value) -> DemoResult<rtfs::runtime::values::Value
         Value::Symbol(s) => Ok(rtfs::runtime::values::Value::Symbol(s.clone())),
         Value::Vector(v) => {
             let rtfs_vec: Result<Vec<_>, _> = v.iter().map(value_to_rtfs_value).collect();
-            Ok(rtfs::runtime::values::Value::Vector(rtfs_vec?))
+            Ok(rtfs::runtime::values::Value::Vector(rtfs_vec?.into()))
         }
         Value::Map(m) => {
-            let mut rtfs_map = std::collections::HashMap::new();
+            let mut rtfs_map = ValueMap::new();
             for (k, v) in m {
                 let rtfs_key = match k {
                     rtfs::ast::MapKey::String(s) => rtfs::ast::MapKey::String(s.clone()),
@@ -4850,7 +4850,7 @@ fn build_sample_input_for_manifest(
         return None;
     }
 
-    let mut map_entries: HashMap<MapKey, Value> = HashMap::new();
+    let mut map_entries = ValueMap::new();
 
     let owner_repo = derive_owner_repo_from_context(context);
 
@@ -4880,7 +4880,7 @@ fn build_sample_input_for_manifest(
             return None;
         };
 
-        map_entries.insert(MapKey::Keyword(Keyword(field.clone())), val);
+        map_entries.insert(MapKey::Keyword(Keyword::new(field.as_str())), val);
     }
 
     Some(Value::Map(map_entries))
@@ -4914,7 +4914,7 @@ fn infer_type_expr_from_value(value: &Value) -> TypeExpr {
         Value::Boolean(_) => TypeExpr::Primitive(PrimitiveType::Bool),
         Value::Vector(items) => {
             let element_type = items
-                .first()
+                .front()
                 .map(|v| infer_type_expr_from_value(v))
                 .unwrap_or(TypeExpr::Any);
             TypeExpr::Vector(Box::new(element_type))
@@ -4924,12 +4924,12 @@ fn infer_type_expr_from_value(value: &Value) -> TypeExpr {
                 .iter()
                 .map(|(key, val)| {
                     let key_str = match key {
-                        MapKey::Keyword(k) => k.0.clone(),
+                        MapKey::Keyword(k) => k.0.to_string(),
                         MapKey::String(s) => s.clone(),
                         MapKey::Integer(i) => i.to_string(),
                     };
                     let entry = MapTypeEntry {
-                        key: Keyword(key_str.clone()),
+                        key: Keyword::new(&key_str),
                         value_type: Box::new(infer_type_expr_from_value(val)),
                         optional: false,
                     };
@@ -5179,12 +5179,12 @@ fn generate_orchestrator_capability(
         let mut schema_parts = Vec::new();
         let mut sorted_inputs: Vec<_> = external_inputs.iter().collect();
         sorted_inputs.sort();
-        let mut map = HashMap::new();
+        let mut map = ValueMap::new();
         for input in sorted_inputs {
             let ty = infer_input_type(input);
             schema_parts.push(format!("    :{} :{}", input, ty));
             map.insert(
-                rtfs::ast::MapKey::Keyword(rtfs::ast::Keyword(input.clone())),
+                rtfs::ast::MapKey::Keyword(rtfs::ast::Keyword::new(input.as_str())),
                 rtfs::runtime::values::Value::String(ty.to_string()),
             );
         }
@@ -5210,11 +5210,11 @@ fn generate_orchestrator_capability(
     // Build output-schema from the union of all steps' expected outputs; fallback to :result
     let (output_schema_section, output_schema_value) = if !all_outputs.is_empty() {
         let mut parts = Vec::new();
-        let mut map = HashMap::new();
+        let mut map = ValueMap::new();
         for key in &all_outputs {
             parts.push(format!("    :{} :any", key));
             map.insert(
-                rtfs::ast::MapKey::Keyword(rtfs::ast::Keyword(key.clone())),
+                rtfs::ast::MapKey::Keyword(rtfs::ast::Keyword::new(key.as_str())),
                 rtfs::runtime::values::Value::String("any".to_string()),
             );
         }
@@ -5225,8 +5225,8 @@ fn generate_orchestrator_capability(
     } else {
         (
             "  :output-schema {\n    :result :any\n  }\n".to_string(),
-            Some(rtfs::runtime::values::Value::Map(HashMap::from([(
-                rtfs::ast::MapKey::Keyword(rtfs::ast::Keyword("result".to_string())),
+            Some(rtfs::runtime::values::Value::Map(ValueMap::from_iter([(
+                rtfs::ast::MapKey::Keyword(rtfs::ast::Keyword("result".into())),
                 rtfs::runtime::values::Value::String("any".to_string()),
             )]))),
         )
@@ -5513,7 +5513,7 @@ fn build_resolved_steps_metadata(resolved_steps: &[ResolvedStep]) -> Value {
         .iter()
         .enumerate()
         .map(|(idx, resolved)| {
-            let mut map = HashMap::new();
+            let mut map = ValueMap::new();
             map.insert(MapKey::String("index".into()), Value::Integer(idx as i64));
             map.insert(
                 MapKey::String("step_id".into()),
@@ -5537,7 +5537,7 @@ fn build_resolved_steps_metadata(resolved_steps: &[ResolvedStep]) -> Value {
             Value::Map(map)
         })
         .collect();
-    Value::Vector(entries)
+    Value::Vector(entries.into())
 }
 
 fn derive_orchestrator_capability_id(goal: &str, steps: &[ResolvedStep]) -> String {
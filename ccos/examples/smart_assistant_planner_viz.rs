@@ -18,7 +18,7 @@ use rtfs::ast::{Keyword, MapKey};
 use rtfs::config::profile_selection::expand_profiles;
 use rtfs::runtime::error::{RuntimeError, RuntimeResult};
 use rtfs::runtime::security::RuntimeContext;
-use rtfs::runtime::values::Value;
+use rtfs::runtime::values::{Value, ValueMap};
 
 use ccos::arbiter::config::{self, LlmProviderType};
 use ccos::arbiter::prompt::{FilePromptStore, PromptManager};
@@ -1367,7 +1367,7 @@ fn value_to_string_repr(value: &Value) -> String {
         Value::Float(f) => format!("{:.2}", f),
         Value::Boolean(b) => b.to_string(),
         Value::Keyword(k) => format!(":{}", k.0),
-        Value::Symbol(sym) => sym.0.clone(),
+        Value::Symbol(sym) => sym.0.to_string(),
         Value::Vector(vec) => {
             let inner = vec.iter().map(value_to_string_repr).collect::<Vec<_>>();
             format!("[{}]", inner.join(", "))
@@ -2136,8 +2136,8 @@ fn register_pending_requests(
 fn value_to_string(value: &Value) -> Option<String> {
     match value {
         Value::String(s) => Some(s.clone()),
-        Value::Keyword(k) => Some(k.0.clone()),
-        Value::Symbol(sym) => Some(sym.0.clone()),
+        Value::Keyword(k) => Some(k.0.to_string()),
+        Value::Symbol(sym) => Some(sym.0.to_string()),
         _ => None,
     }
 }
@@ -2264,12 +2264,12 @@ async fn assemble_plan_from_steps(
         render_plan_body(steps, &step_index)?
     };
 
-    let mut input_schema_entries = HashMap::new();
+    let mut input_schema_entries = ValueMap::new();
     let mut sorted_vars: Vec<String> = external_vars.into_iter().collect();
     sorted_vars.sort();
     for name in &sorted_vars {
         input_schema_entries.insert(
-            MapKey::Keyword(Keyword(name.clone())),
+            MapKey::Keyword(Keyword::new(name.as_str())),
             Value::String("any".to_string()),
         );
     }
@@ -2280,12 +2280,12 @@ async fn assemble_plan_from_steps(
         Some(Value::Map(input_schema_entries))
     };
 
-    let mut output_schema_entries = HashMap::new();
+    let mut output_schema_entries = ValueMap::new();
     let mut sorted_outputs: Vec<(String, usize)> = output_map.into_iter().collect();
     sorted_outputs.sort_by(|a, b| a.0.cmp(&b.0));
     for (name, _) in &sorted_outputs {
         output_schema_entries.insert(
-            MapKey::Keyword(Keyword(name.clone())),
+            MapKey::Keyword(Keyword::new(name.as_str())),
             Value::String("any".to_string()),
         );
     }
@@ -2841,7 +2841,7 @@ async fn main() -> Result<(), Box<dyn Error>> {
     //                             json_to_rtfs(v)
     //                         );
     //                     }
-    //                     Value::Map(map)
+    //                     Value::Map(map.into())
     //                 }
     //             }
     //         }
@@ -380,7 +380,7 @@ impl ApprovalQueue {
                                 let key = match k_expr {
                                     rtfs::ast::Expression::Literal(
                                         rtfs::ast::Literal::Keyword(k),
-                                    ) => Some(k.0.clone()),
+                                    ) => Some(k.0.to_string()),
                                     rtfs::ast::Expression::Literal(rtfs::ast::Literal::String(
                                         s,
                                     )) => Some(s.clone()),
@@ -434,7 +434,7 @@ impl ApprovalQueue {
                 rtfs::ast::Literal::String(s) => Ok(serde_json::Value::String(s.clone())),
                 rtfs::ast::Literal::Boolean(b) => Ok(serde_json::Value::Bool(*b)),
                 rtfs::ast::Literal::Nil => Ok(serde_json::Value::Null),
-                rtfs::ast::Literal::Keyword(k) => Ok(serde_json::Value::String(k.0.clone())),
+                rtfs::ast::Literal::Keyword(k) => Ok(serde_json::Value::String(k.0.to_string())),
                 _ => Ok(serde_json::Value::String(format!("{}", lit))),
             },
             rtfs::ast::Expression::List(l) | rtfs::ast::Expression::Vector(l) => {
@@ -542,7 +542,7 @@ impl ApprovalQueue {
 
             for (k, v) in entries {
                 let key_str = match k {
-                    rtfs::ast::MapKey::Keyword(kw) => kw.0,
+                    rtfs::ast::MapKey::Keyword(kw) => kw.0.to_string(),
                     rtfs::ast::MapKey::String(s) => format!(":{}", s.replace(" ", "_")), // Force keyword style
                     _ => format!(":{}", k),
                 };
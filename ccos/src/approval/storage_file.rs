@@ -260,7 +260,9 @@ impl FileApprovalStorage {
             .find_map(|item| {
                 if let rtfs::ast::TopLevel::Expression(expr) = item {
                     match expr {
-                        Expression::FunctionCall { callee, arguments } => {
+                        Expression::FunctionCall {
+                            callee, arguments, ..
+                        } => {
                             if let Expression::Symbol(sym) = &**callee {
                                 if sym.0 == "server" {
                                     return Some(arguments.as_slice());
@@ -303,7 +305,7 @@ impl FileApprovalStorage {
             } else if let Expression::Literal(rtfs::ast::Literal::Symbol(sym)) = &server_args[i] {
                 // Support :symbol style if parsed as symbol (though unlikely for keywords)
                 if sym.0.starts_with(':') {
-                    properties.insert(sym.0[1..].to_string(), &server_args[i + 1]);
+                    properties.insert(sym.0[1..].into(), &server_args[i + 1]);
                 }
             }
         }
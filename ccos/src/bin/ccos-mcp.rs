@@ -60,6 +60,7 @@ use rtfs::runtime::security::RuntimeContext;
 use ccos::working_memory::{
     AgentMemory, InMemoryJsonlBackend, LearnedPattern, WorkingMemory,
 };
+use rtfs::runtime::values::ValueMap;
 
 #[derive(Clone)]
 struct SkillApprovalAutoActivator {
@@ -655,7 +656,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     //
     // `ccos_consolidate_session` depends on `planner.synthesize_agent_from_trace`, which is a
     // Planner v2 capability. Without this, MCP consolidation fails with:
-    // "Unknown capability: planner.synthesize_agent_from_trace".
+    // "Capability not found: planner.synthesize_agent_from_trace".
     //
     // We keep this best-effort (warn, don't abort) so MCP can still run in reduced mode.
     if let Err(e) = ccos::planner::capabilities_v2::register_planner_capabilities_v2(
@@ -4487,8 +4488,8 @@ fn register_ccos_tools(
                 }
 
                 let marketplace = ccos.get_capability_marketplace();
-                let mut fetch_inputs: std::collections::HashMap<rtfs::ast::MapKey, rtfs::runtime::values::Value> =
-                    std::collections::HashMap::new();
+                let mut fetch_inputs: ValueMap =
+                    ValueMap::new();
                 fetch_inputs.insert(
                     rtfs::ast::MapKey::String("url".to_string()),
                     rtfs::runtime::values::Value::String(url.to_string()),
@@ -4499,8 +4500,8 @@ fn register_ccos_tools(
                 );
 
                 if let Some(hdrs) = headers {
-                    let mut hmap: std::collections::HashMap<rtfs::ast::MapKey, rtfs::runtime::values::Value> =
-                        std::collections::HashMap::new();
+                    let mut hmap: ValueMap =
+                        ValueMap::new();
                     for (k, v) in hdrs {
                         if let Some(vs) = v.as_str() {
                             hmap.insert(
@@ -5227,7 +5228,7 @@ mod tests {
         let handler: Arc<dyn Fn(&Value) -> BoxFuture<'static, RuntimeResult<Value>> + Send + Sync> =
             Arc::new(|_inputs: &Value| {
                 Box::pin(async move {
-                    Ok(Value::Map(HashMap::from([
+                    Ok(Value::Map(ValueMap::from_iter([
                         (
                             MapKey::String("body".to_string()),
                             Value::String("{\"ok\":true}".to_string()),
@@ -5381,7 +5382,7 @@ curl https://example.com
         let result = marketplace2
             .execute_capability(
                 "basic-skill.fetch",
-                &Value::Map(HashMap::from([(
+                &Value::Map(ValueMap::from_iter([(
                     MapKey::String("url".to_string()),
                     Value::String("https://example.com".to_string()),
                 )])),
@@ -68,12 +68,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     inputs.insert(MapKey::String("max_turns".to_string()), Value::Float(3.0));
 
     let result = marketplace
-        .execute_capability("ccos.code.refined_execute", &Value::Map(inputs))
+        .execute_capability("ccos.code.refined_execute", &Value::Map(inputs.into()))
         .await?;
 
     // 5. Inspect Results
     if let Value::Map(m) = result {
-        if let Some(history) = m.get(&MapKey::Keyword(Keyword("refinement_history".to_string()))) {
+        if let Some(history) = m.get(&MapKey::Keyword(Keyword("refinement_history".into()))) {
             if let Value::Vector(turns) = history {
                 println!("\n📊 Refinement History ({} turns):", turns.len());
                 for (i, turn) in turns.iter().enumerate() {
@@ -43,14 +43,14 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             };
 
             let code = map
-                .get(&MapKey::Keyword(Keyword("code".to_string())))
+                .get(&MapKey::Keyword(Keyword("code".into())))
                 .and_then(|v| v.as_string())
                 .ok_or_else(|| RuntimeError::Generic("Missing 'code'".to_string()))?
                 .to_string();
 
             // Extract dependencies if present
             let dependencies = map
-                .get(&MapKey::Keyword(Keyword("dependencies".to_string())))
+                .get(&MapKey::Keyword(Keyword("dependencies".into())))
                 .and_then(|v| match v {
                     Value::Vector(v) => Some(
                         v.iter()
@@ -61,7 +61,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 });
 
             let timeout_ms = map
-                .get(&MapKey::Keyword(Keyword("timeout_ms".to_string())))
+                .get(&MapKey::Keyword(Keyword("timeout_ms".into())))
                 .and_then(|v| match v {
                     Value::Float(f) => Some(*f as u32),
                     Value::Integer(i) => Some(*i as u32),
@@ -70,7 +70,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 .unwrap_or(30000);
 
             let max_memory_mb = map
-                .get(&MapKey::Keyword(Keyword("max_memory_mb".to_string())))
+                .get(&MapKey::Keyword(Keyword("max_memory_mb".into())))
                 .and_then(|v| match v {
                     Value::Float(f) => Some(*f as u32),
                     Value::Integer(i) => Some(*i as u32),
@@ -96,19 +96,19 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
             let mut out = HashMap::new();
             out.insert(
-                MapKey::Keyword(Keyword("success".to_string())),
+                MapKey::Keyword(Keyword("success".into())),
                 Value::Boolean(result.success),
             );
             out.insert(
-                MapKey::Keyword(Keyword("stdout".to_string())),
+                MapKey::Keyword(Keyword("stdout".into())),
                 Value::String(result.stdout),
             );
             out.insert(
-                MapKey::Keyword(Keyword("stderr".to_string())),
+                MapKey::Keyword(Keyword("stderr".into())),
                 Value::String(result.stderr),
             );
 
-            Ok(Value::Map(out))
+            Ok(Value::Map(out.into()))
         };
         Box::pin(fut)
             as futures::future::BoxFuture<'static, rtfs::runtime::error::RuntimeResult<Value>>
@@ -147,10 +147,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let input_a = Value::Map({
         let mut m = HashMap::new();
         m.insert(
-            MapKey::Keyword(Keyword("code".to_string())),
+            MapKey::Keyword(Keyword("code".into())),
             Value::String("console.log(1 + 2 * 3);".to_string()),
         );
-        m
+        m.into()
     });
     let result_a = marketplace
         .execute_capability("ccos.execute.javascript", &input_a)
@@ -162,17 +162,17 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let input_b = Value::Map({
         let mut m = HashMap::new();
         m.insert(
-            MapKey::Keyword(Keyword("code".to_string())),
+            MapKey::Keyword(Keyword("code".into())),
             Value::String(
                 "const buf = Buffer.alloc(100 * 1024 * 1024); console.log('Allocated 100MB');"
                     .to_string(),
             ),
         );
         m.insert(
-            MapKey::Keyword(Keyword("max_memory_mb".to_string())),
+            MapKey::Keyword(Keyword("max_memory_mb".into())),
             Value::Float(50.0),
         );
-        m
+        m.into()
     });
     let result_b = marketplace
         .execute_capability("ccos.execute.javascript", &input_b)
@@ -187,14 +187,14 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let input_c = Value::Map({
         let mut m = HashMap::new();
         m.insert(
-            MapKey::Keyword(Keyword("code".to_string())),
+            MapKey::Keyword(Keyword("code".into())),
             Value::String("while(true) {}".to_string()),
         );
         m.insert(
-            MapKey::Keyword(Keyword("timeout_ms".to_string())),
+            MapKey::Keyword(Keyword("timeout_ms".into())),
             Value::Float(2000.0),
         );
-        m
+        m.into()
     });
     let result_c = marketplace
         .execute_capability("ccos.execute.javascript", &input_c)
@@ -209,17 +209,17 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let input_d = Value::Map({
         let mut m = HashMap::new();
         m.insert(
-            MapKey::Keyword(Keyword("code".to_string())),
+            MapKey::Keyword(Keyword("code".into())),
             Value::String(
                 "const _ = require('lodash'); console.log(_.chunk(['a', 'b', 'c', 'd'], 2));"
                     .to_string(),
             ),
         );
         m.insert(
-            MapKey::Keyword(Keyword("dependencies".to_string())),
-            Value::Vector(vec![Value::String("lodash".to_string())]),
+            MapKey::Keyword(Keyword("dependencies".into())),
+            Value::Vector(vec![Value::String("lodash".to_string())].into()),
         );
-        m
+        m.into()
     });
     let result_d = marketplace
         .execute_capability("ccos.execute.javascript", &input_d)
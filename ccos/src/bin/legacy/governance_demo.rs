@@ -261,8 +261,8 @@ fn extract_u32_from_map(value: &Value, key: &str) -> Option<u32> {
     if let Value::Map(map) = value {
         for (k, v) in map {
             let key_str = match k {
-                MapKey::Keyword(kw) => &kw.0,
-                MapKey::String(s) => s,
+                MapKey::Keyword(kw) => kw.0.as_str(),
+                MapKey::String(s) => s.as_str(),
                 _ => continue,
             };
             if key_str == key {
@@ -282,8 +282,8 @@ fn extract_u64_from_map(value: &Value, key: &str) -> Option<u64> {
     if let Value::Map(map) = value {
         for (k, v) in map {
             let key_str = match k {
-                MapKey::Keyword(kw) => &kw.0,
-                MapKey::String(s) => s,
+                MapKey::Keyword(kw) => kw.0.as_str(),
+                MapKey::String(s) => s.as_str(),
                 _ => continue,
             };
             if key_str == key {
@@ -303,8 +303,8 @@ fn extract_f64_from_map(value: &Value, key: &str) -> Option<f64> {
     if let Value::Map(map) = value {
         for (k, v) in map {
             let key_str = match k {
-                MapKey::Keyword(kw) => &kw.0,
-                MapKey::String(s) => s,
+                MapKey::Keyword(kw) => kw.0.as_str(),
+                MapKey::String(s) => s.as_str(),
                 _ => continue,
             };
             if key_str == key {
@@ -324,14 +324,17 @@ fn create_retry_hint(max_retries: i64, backoff_ms: i64) -> HashMap<String, Value
     let mut hints = HashMap::new();
     let mut retry_map = HashMap::new();
     retry_map.insert(
-        MapKey::Keyword(Keyword("max-retries".to_string())),
+        MapKey::Keyword(Keyword("max-retries".into())),
         Value::Integer(max_retries),
     );
     retry_map.insert(
-        MapKey::Keyword(Keyword("backoff-ms".to_string())),
+        MapKey::Keyword(Keyword("backoff-ms".into())),
         Value::Integer(backoff_ms),
     );
-    hints.insert("runtime.learning.retry".to_string(), Value::Map(retry_map));
+    hints.insert(
+        "runtime.learning.retry".to_string(),
+        Value::Map(retry_map.into()),
+    );
     hints
 }
 
@@ -340,16 +343,16 @@ fn create_timeout_hint(multiplier: f64, absolute_ms: i64) -> HashMap<String, Val
     let mut hints = HashMap::new();
     let mut timeout_map = HashMap::new();
     timeout_map.insert(
-        MapKey::Keyword(Keyword("multiplier".to_string())),
+        MapKey::Keyword(Keyword("multiplier".into())),
         Value::Float(multiplier),
     );
     timeout_map.insert(
-        MapKey::Keyword(Keyword("absolute-ms".to_string())),
+        MapKey::Keyword(Keyword("absolute-ms".into())),
         Value::Integer(absolute_ms),
     );
     hints.insert(
         "runtime.learning.timeout".to_string(),
-        Value::Map(timeout_map),
+        Value::Map(timeout_map.into()),
     );
     hints
 }
@@ -71,10 +71,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let echo_args = {
         let mut m = HashMap::new();
         m.insert(
-            MapKey::Keyword(Keyword("message".to_string())),
+            MapKey::Keyword(Keyword("message".into())),
             Value::String("hello".to_string()),
         );
-        Value::Map(m)
+        Value::Map(m.into())
     };
     let echo_call_id = execute_and_log(
         &chain,
@@ -89,14 +89,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let add_args = {
         let mut m = HashMap::new();
         m.insert(
-            MapKey::Keyword(Keyword("args".to_string())),
-            Value::Vector(vec![
-                Value::Integer(1),
-                Value::Integer(2),
-                Value::Integer(3),
-            ]),
+            MapKey::Keyword(Keyword("args".into())),
+            Value::Vector(vec![Value::Integer(1), Value::Integer(2), Value::Integer(3)].into()),
         );
-        Value::Map(m)
+        Value::Map(m.into())
     };
     let _add_call_id = execute_and_log(
         &chain,
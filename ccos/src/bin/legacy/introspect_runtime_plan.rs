@@ -14,7 +14,7 @@ use ccos::types::{Action, ActionType, ExecutionResult, Plan, PlanBody, PlanLangu
 use ccos::utils::value_conversion::rtfs_value_to_json;
 use rtfs::ast::{Expression, Literal, TopLevel};
 use rtfs::runtime::error::{RuntimeError, RuntimeResult};
-use rtfs::runtime::values::Value;
+use rtfs::runtime::values::{Value, ValueMap};
 use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
 use tokio::sync::RwLock;
@@ -188,7 +188,7 @@ async fn execute_plan_rtfs(
             plan_id,
             intent_id,
             &cap_id,
-            Value::Map(HashMap::new()),
+            Value::Map(ValueMap::new()),
             parent,
         )
         .await?;
@@ -205,7 +205,9 @@ fn collect_calls(expr: &Expression, out: &mut Vec<String>) {
                 collect_calls(e, out);
             }
         }
-        Expression::FunctionCall { callee, arguments } if matches!(**callee, Expression::Symbol(ref s) if s.0 == "call") => {
+        Expression::FunctionCall {
+            callee, arguments, ..
+        } if matches!(**callee, Expression::Symbol(ref s) if s.0 == "call") => {
             if let Some(cap_id) = parse_cap_id(arguments) {
                 out.push(cap_id);
             }
@@ -220,8 +222,8 @@ fn parse_cap_id(args: &[Expression]) -> Option<String> {
     }
     match &args[0] {
         Expression::Literal(Literal::String(s)) => Some(s.clone()),
-        Expression::Literal(Literal::Keyword(k)) => Some(k.0.clone()),
-        Expression::Symbol(sym) => Some(sym.0.clone()),
+        Expression::Literal(Literal::Keyword(k)) => Some(k.0.to_string()),
+        Expression::Symbol(sym) => Some(sym.0.to_string()),
         _ => None,
     }
 }
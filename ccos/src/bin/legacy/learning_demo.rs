@@ -16,7 +16,7 @@ use ccos::types::{Action, ActionType, ExecutionResult};
 use ccos::utils::value_conversion::rtfs_value_to_json;
 use ccos::working_memory::{InMemoryJsonlBackend, WorkingMemory};
 use rtfs::runtime::error::{RuntimeError, RuntimeResult};
-use rtfs::runtime::values::Value;
+use rtfs::runtime::values::{Value, ValueMap};
 use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
 use tokio::sync::RwLock;
@@ -90,7 +90,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         plan_id,
         intent_id,
         "demo.validate_schema",
-        Value::Map(HashMap::new()), // Missing required 'name' field
+        Value::Map(ValueMap::new()), // Missing required 'name' field
     )
     .await;
 
@@ -101,7 +101,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         plan_id,
         intent_id,
         "demo.slow_operation",
-        Value::Map(HashMap::new()),
+        Value::Map(ValueMap::new()),
     )
     .await;
 
@@ -112,7 +112,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         plan_id,
         intent_id,
         "demo.nonexistent",
-        Value::Map(HashMap::new()),
+        Value::Map(ValueMap::new()),
     )
     .await;
 
@@ -520,7 +520,7 @@ async fn execute_and_record(
 /// Helper to create Value::Map with MapKey::String keys
 fn make_map(entries: Vec<(&str, Value)>) -> Value {
     use rtfs::ast::MapKey;
-    let map: HashMap<MapKey, Value> = entries
+    let map: ValueMap = entries
         .into_iter()
         .map(|(k, v)| (MapKey::String(k.to_string()), v))
         .collect();
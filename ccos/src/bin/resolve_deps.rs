@@ -20,7 +20,7 @@ use ccos::synthesis::missing_capability_resolver::{MissingCapabilityResolver, Re
 use clap::{Parser, Subcommand};
 use rtfs::ast::{Keyword, MapKey};
 use rtfs::config::profile_selection::expand_profiles;
-use rtfs::runtime::values::Value;
+use rtfs::runtime::values::{Value, ValueMap};
 use serde_json;
 use std::collections::{HashMap, HashSet};
 use std::fs;
@@ -593,7 +593,7 @@ fn build_sample_invocation(
                 "Guard division by zero and return either {:value <number>} or {:error {:message string}}".to_string(),
             );
 
-            let mut payload = HashMap::new();
+            let mut payload = ValueMap::new();
             payload.insert(
                 MapKey::Keyword(Keyword::new("numerator")),
                 Value::Integer(42),
@@ -629,10 +629,10 @@ fn build_sample_invocation(
                 ),
             ];
 
-            let mut payload = HashMap::new();
+            let mut payload = ValueMap::new();
             payload.insert(
                 MapKey::Keyword(Keyword::new("articles")),
-                Value::Vector(articles),
+                Value::Vector(articles.into()),
             );
             payload.insert(
                 MapKey::Keyword(Keyword::new("topic")),
@@ -655,7 +655,7 @@ fn build_sample_invocation(
 }
 
 fn make_article(title: &str, topic: &str, summary: &str) -> Value {
-    let mut article = HashMap::new();
+    let mut article = ValueMap::new();
     article.insert(
         MapKey::Keyword(Keyword::new("title")),
         Value::String(title.to_string()),
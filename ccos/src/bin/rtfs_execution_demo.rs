@@ -63,7 +63,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         );
 
         match marketplace
-            .execute_capability("ccos.execute.rtfs", &Value::Map(inputs))
+            .execute_capability("ccos.execute.rtfs", &Value::Map(inputs.into()))
             .await
         {
             Ok(result) => println!("  Result: {}", result),
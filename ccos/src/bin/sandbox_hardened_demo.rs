@@ -42,13 +42,13 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             };
 
             let code = map
-                .get(&MapKey::Keyword(Keyword("code".to_string())))
+                .get(&MapKey::Keyword(Keyword("code".into())))
                 .and_then(|v| v.as_string())
                 .unwrap_or_default()
                 .to_string();
 
             let timeout_ms = map
-                .get(&MapKey::Keyword(Keyword("timeout_ms".to_string())))
+                .get(&MapKey::Keyword(Keyword("timeout_ms".into())))
                 .and_then(|v| match v {
                     Value::Integer(i) => Some(*i as u64),
                     Value::Float(f) => Some(*f as u64),
@@ -57,7 +57,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 .unwrap_or(30000);
 
             let memory_mb = map
-                .get(&MapKey::Keyword(Keyword("memory_mb".to_string())))
+                .get(&MapKey::Keyword(Keyword("memory_mb".into())))
                 .and_then(|v| match v {
                     Value::Integer(i) => Some(*i as u64),
                     Value::Float(f) => Some(*f as u64),
@@ -66,7 +66,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 .unwrap_or(512);
 
             let allowed_hosts = map
-                .get(&MapKey::Keyword(Keyword("allowed_hosts".to_string())))
+                .get(&MapKey::Keyword(Keyword("allowed_hosts".into())))
                 .and_then(|v| match v {
                     Value::Vector(vec) => Some(
                         vec.iter()
@@ -98,18 +98,18 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
             let mut out = HashMap::new();
             out.insert(
-                MapKey::Keyword(Keyword("success".to_string())),
+                MapKey::Keyword(Keyword("success".into())),
                 Value::Boolean(result.success),
             );
             out.insert(
-                MapKey::Keyword(Keyword("stdout".to_string())),
+                MapKey::Keyword(Keyword("stdout".into())),
                 Value::String(result.stdout),
             );
             out.insert(
-                MapKey::Keyword(Keyword("stderr".to_string())),
+                MapKey::Keyword(Keyword("stderr".into())),
                 Value::String(result.stderr),
             );
-            Ok(Value::Map(out))
+            Ok(Value::Map(out.into()))
         };
         Box::pin(fut)
             as futures::future::BoxFuture<'static, rtfs::runtime::error::RuntimeResult<Value>>
@@ -147,16 +147,16 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let input1 = Value::Map({
         let mut m = HashMap::new();
         m.insert(
-            MapKey::Keyword(Keyword("code".to_string())),
+            MapKey::Keyword(Keyword("code".into())),
             Value::String(
                 "const a = Buffer.alloc(100*1024*1024); console.log('success');".to_string(),
             ),
         );
         m.insert(
-            MapKey::Keyword(Keyword("memory_mb".to_string())),
+            MapKey::Keyword(Keyword("memory_mb".into())),
             Value::Integer(50),
         );
-        m
+        m.into()
     });
     let result1 = marketplace
         .execute_capability("test.execute.js", &input1)
@@ -171,14 +171,14 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let input2 = Value::Map({
         let mut m = HashMap::new();
         m.insert(
-            MapKey::Keyword(Keyword("code".to_string())),
+            MapKey::Keyword(Keyword("code".into())),
             Value::String("while(true) {}".to_string()),
         );
         m.insert(
-            MapKey::Keyword(Keyword("timeout_ms".to_string())),
+            MapKey::Keyword(Keyword("timeout_ms".into())),
             Value::Integer(2000),
         );
-        m
+        m.into()
     });
     let result2 = marketplace
         .execute_capability("test.execute.js", &input2)
@@ -193,8 +193,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let input3 = Value::Map({
         let mut m = HashMap::new();
         // Trying to resolve google.com without network should fail
-        m.insert(MapKey::Keyword(Keyword("code".to_string())), Value::String("require('dns').lookup('google.com', (err) => { if(err) console.log('blocked: ' + err.code); else console.log('accessible'); });".to_string()));
-        m
+        m.insert(MapKey::Keyword(Keyword("code".into())), Value::String("require('dns').lookup('google.com', (err) => { if(err) console.log('blocked: ' + err.code); else console.log('accessible'); });".to_string()));
+        m.into()
     });
     let result3 = marketplace
         .execute_capability("test.execute.js", &input3)
@@ -208,12 +208,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("\n[Test 4] Network Isolation (Allow list provided)");
     let input4 = Value::Map({
         let mut m = HashMap::new();
-        m.insert(MapKey::Keyword(Keyword("code".to_string())), Value::String("require('dns').lookup('google.com', (err) => { if(err) console.log('blocked: ' + err.code); else console.log('accessible'); });".to_string()));
+        m.insert(MapKey::Keyword(Keyword("code".into())), Value::String("require('dns').lookup('google.com', (err) => { if(err) console.log('blocked: ' + err.code); else console.log('accessible'); });".to_string()));
         m.insert(
-            MapKey::Keyword(Keyword("allowed_hosts".to_string())),
-            Value::Vector(vec![Value::String("google.com".to_string())]),
+            MapKey::Keyword(Keyword("allowed_hosts".into())),
+            Value::Vector(vec![Value::String("google.com".to_string())].into()),
         );
-        m
+        m.into()
     });
     let result4 = marketplace
         .execute_capability("test.execute.js", &input4)
@@ -0,0 +1,375 @@
+//! Agent Mailboxes
+//!
+//! In-process message passing between CCOS agents. Agents themselves are
+//! registered in the CapabilityMarketplace (`:kind :agent`, see `agent_ops`);
+//! this module gives each agent id a mailbox so agents can coordinate by
+//! exchanging RTFS `Value` messages.
+//!
+//! Delivery guarantees:
+//! - at-least-once: a received message stays in flight until the receiver acks
+//!   it with `Agent::ack`. When an `Agent` is dropped, its unacked and still
+//!   queued messages go back to the router and are redelivered, in order, the
+//!   next time its mailbox is opened. Messages sent to an agent whose mailbox is
+//!   not open are buffered the same way
+//! - bounded buffering: the router holds at most `max_undelivered` new messages
+//!   for offline agents (`DEFAULT_MAX_UNDELIVERED` by default); sending beyond
+//!   that fails instead of growing the buffer. Messages handed back by a dropped
+//!   `Agent` are always kept
+//! - per-sender ordering: each mailbox is a single mpsc channel and every message
+//!   carries a per (sender, recipient) sequence number. Receivers skip any
+//!   `(from, seq)` they have already received, so redelivery never duplicates
+
+use rtfs::runtime::error::{RuntimeError, RuntimeResult};
+use rtfs::runtime::values::Value;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use tokio::sync::mpsc;
+
+/// A message delivered to an agent's mailbox.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AgentEnvelope {
+    /// Sending agent id
+    pub from: String,
+    /// Receiving agent id
+    pub to: String,
+    /// Sequence number for this (sender, recipient) pair, starting at 1
+    pub seq: u64,
+    pub message: Value,
+}
+
+/// Default cap on messages buffered for agents whose mailbox is not open
+pub const DEFAULT_MAX_UNDELIVERED: usize = 1024;
+
+#[derive(Default)]
+struct RouterState {
+    mailboxes: HashMap<String, mpsc::UnboundedSender<AgentEnvelope>>,
+    /// Messages for agents whose mailbox is not open yet
+    undelivered: HashMap<String, Vec<AgentEnvelope>>,
+    /// Total number of messages in `undelivered`
+    undelivered_total: usize,
+}
+
+/// Routes messages between agent mailboxes. Cheap to clone; all clones share state.
+#[derive(Clone)]
+pub struct AgentMailboxRouter {
+    state: Arc<Mutex<RouterState>>,
+    max_undelivered: usize,
+}
+
+impl Default for AgentMailboxRouter {
+    fn default() -> Self {
+        Self::with_max_undelivered(DEFAULT_MAX_UNDELIVERED)
+    }
+}
+
+impl std::fmt::Debug for AgentMailboxRouter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AgentMailboxRouter").finish()
+    }
+}
+
+impl AgentMailboxRouter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Router buffering at most `max_undelivered` messages (across all
+    /// recipients) for agents whose mailbox is not open
+    pub fn with_max_undelivered(max_undelivered: usize) -> Self {
+        Self {
+            state: Arc::new(Mutex::new(RouterState::default())),
+            max_undelivered,
+        }
+    }
+
+    /// Open the mailbox for `agent_id` and return the agent handle owning it.
+    /// Messages buffered while the agent was offline are delivered first.
+    pub fn open(&self, agent_id: impl Into<String>) -> RuntimeResult<Agent> {
+        let agent_id = agent_id.into();
+        let (tx, rx) = mpsc::unbounded_channel();
+        let mut state = self.lock()?;
+        if state
+            .mailboxes
+            .get(&agent_id)
+            .is_some_and(|tx| !tx.is_closed())
+        {
+            return Err(RuntimeError::Generic(format!(
+                "Mailbox for agent '{}' is already open",
+                agent_id
+            )));
+        }
+        let buffered = state.undelivered.remove(&agent_id).unwrap_or_default();
+        state.undelivered_total -= buffered.len();
+        for envelope in buffered {
+            // The receiver is alive in this scope, so the send cannot fail
+            let _ = tx.send(envelope);
+        }
+        state.mailboxes.insert(agent_id.clone(), tx);
+        Ok(Agent {
+            id: agent_id,
+            router: self.clone(),
+            inbox: rx,
+            next_seq: HashMap::new(),
+            unacked: Vec::new(),
+            last_received: HashMap::new(),
+        })
+    }
+
+    /// Number of messages waiting for agents whose mailbox is not open
+    pub fn undelivered_count(&self, agent_id: &str) -> usize {
+        self.lock()
+            .map(|state| state.undelivered.get(agent_id).map_or(0, Vec::len))
+            .unwrap_or(0)
+    }
+
+    fn deliver(&self, envelope: AgentEnvelope) -> RuntimeResult<()> {
+        let mut state = self.lock()?;
+        let envelope = match state.mailboxes.get(&envelope.to) {
+            Some(tx) => match tx.send(envelope) {
+                Ok(()) => return Ok(()),
+                // Mailbox was dropped: keep the message until the agent reopens it
+                Err(mpsc::error::SendError(envelope)) => envelope,
+            },
+            None => envelope,
+        };
+        state.mailboxes.remove(&envelope.to);
+        if state.undelivered_total >= self.max_undelivered {
+            return Err(RuntimeError::Generic(format!(
+                "Agent '{}' is offline and the mailbox router already buffers {} undelivered messages",
+                envelope.to, self.max_undelivered
+            )));
+        }
+        state.undelivered_total += 1;
+        state
+            .undelivered
+            .entry(envelope.to.clone())
+            .or_default()
+            .push(envelope);
+        Ok(())
+    }
+
+    /// Put `envelopes` back at the front of `agent_id`'s undelivered queue,
+    /// so they are redelivered before anything newer
+    fn requeue(state: &mut RouterState, agent_id: &str, mut envelopes: Vec<AgentEnvelope>) {
+        state.undelivered_total += envelopes.len();
+        let queued = state.undelivered.entry(agent_id.to_string()).or_default();
+        envelopes.append(queued);
+        *queued = envelopes;
+    }
+
+    fn lock(&self) -> RuntimeResult<std::sync::MutexGuard<'_, RouterState>> {
+        self.state
+            .lock()
+            .map_err(|_| RuntimeError::Generic("Agent mailbox router lock poisoned".to_string()))
+    }
+}
+
+/// An agent with its own mailbox.
+pub struct Agent {
+    id: String,
+    router: AgentMailboxRouter,
+    inbox: mpsc::UnboundedReceiver<AgentEnvelope>,
+    next_seq: HashMap<String, u64>,
+    /// Received but not yet acked, in receive order
+    unacked: Vec<AgentEnvelope>,
+    /// Highest sequence number received from each sender
+    last_received: HashMap<String, u64>,
+}
+
+impl std::fmt::Debug for Agent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Agent").field("id", &self.id).finish()
+    }
+}
+
+impl Agent {
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Send `message` to `agent_id`, returning the sequence number it was sent with.
+    /// Fails without consuming a sequence number when the recipient is offline and
+    /// the router's undelivered buffer is full.
+    pub fn send(&mut self, agent_id: &str, message: Value) -> RuntimeResult<u64> {
+        let seq = self.next_seq.get(agent_id).copied().unwrap_or(0) + 1;
+        self.router.deliver(AgentEnvelope {
+            from: self.id.clone(),
+            to: agent_id.to_string(),
+            seq,
+            message,
+        })?;
+        self.next_seq.insert(agent_id.to_string(), seq);
+        Ok(seq)
+    }
+
+    /// Wait for the next message. Returns `None` only once every sender handle is
+    /// gone, which cannot happen while the router keeps this mailbox registered.
+    /// The message stays in flight until it is acked.
+    pub async fn receive(&mut self) -> Option<AgentEnvelope> {
+        loop {
+            let envelope = self.inbox.recv().await?;
+            if let Some(envelope) = self.accept(envelope) {
+                return Some(envelope);
+            }
+        }
+    }
+
+    /// Next message if one is already waiting
+    pub fn try_receive(&mut self) -> Option<AgentEnvelope> {
+        while let Ok(envelope) = self.inbox.try_recv() {
+            if let Some(envelope) = self.accept(envelope) {
+                return Some(envelope);
+            }
+        }
+        None
+    }
+
+    /// Acknowledge every message received from `from` up to and including `seq`.
+    /// Acked messages are not redelivered when this agent is dropped.
+    pub fn ack(&mut self, from: &str, seq: u64) {
+        self.unacked
+            .retain(|envelope| envelope.from != from || envelope.seq > seq);
+    }
+
+    fn accept(&mut self, envelope: AgentEnvelope) -> Option<AgentEnvelope> {
+        let last = self.last_received.entry(envelope.from.clone()).or_default();
+        if envelope.seq <= *last {
+            return None;
+        }
+        *last = envelope.seq;
+        self.unacked.push(envelope.clone());
+        Some(envelope)
+    }
+}
+
+impl Drop for Agent {
+    fn drop(&mut self) {
+        let Ok(mut state) = self.router.lock() else {
+            return;
+        };
+        // Unregister first: `deliver` sends while holding the lock, so nothing can
+        // land in the inbox after it has been drained below
+        state.mailboxes.remove(&self.id);
+        let mut pending = std::mem::take(&mut self.unacked);
+        while let Ok(envelope) = self.inbox.try_recv() {
+            pending.push(envelope);
+        }
+        if !pending.is_empty() {
+            AgentMailboxRouter::requeue(&mut state, &self.id, pending);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn messages_arrive_in_send_order() {
+        let router = AgentMailboxRouter::new();
+        let mut producer = router.open("producer").unwrap();
+        let mut consumer = router.open("consumer").unwrap();
+
+        let receiver = tokio::spawn(async move {
+            let mut received = Vec::new();
+            while received.len() < 3 {
+                received.push(consumer.receive().await.unwrap());
+            }
+            received
+        });
+        for i in 1..=3 {
+            producer.send("consumer", Value::Integer(i)).unwrap();
+        }
+
+        let received = receiver.await.unwrap();
+        assert_eq!(
+            received
+                .iter()
+                .map(|e| e.message.clone())
+                .collect::<Vec<_>>(),
+            vec![Value::Integer(1), Value::Integer(2), Value::Integer(3)]
+        );
+        assert_eq!(
+            received.iter().map(|e| e.seq).collect::<Vec<_>>(),
+            vec![1, 2, 3]
+        );
+        assert!(received.iter().all(|e| e.from == "producer"));
+    }
+
+    #[tokio::test]
+    async fn messages_for_offline_agents_are_buffered() {
+        let router = AgentMailboxRouter::new();
+        let mut sender = router.open("sender").unwrap();
+        sender
+            .send("late", Value::String("hello".to_string()))
+            .unwrap();
+        assert_eq!(router.undelivered_count("late"), 1);
+
+        let mut late = router.open("late").unwrap();
+        let envelope = late.receive().await.unwrap();
+        assert_eq!(envelope.message, Value::String("hello".to_string()));
+        assert_eq!(router.undelivered_count("late"), 0);
+        late.ack(&envelope.from, envelope.seq);
+
+        // Re-opening a live mailbox is rejected
+        assert!(router.open("late").is_err());
+        drop(late);
+        sender.send("late", Value::Nil).unwrap();
+        let mut late = router.open("late").unwrap();
+        assert_eq!(late.try_receive().unwrap().seq, 2);
+    }
+
+    #[tokio::test]
+    async fn undelivered_buffer_is_bounded() {
+        let router = AgentMailboxRouter::with_max_undelivered(2);
+        let mut sender = router.open("sender").unwrap();
+        sender.send("a", Value::Integer(1)).unwrap();
+        sender.send("b", Value::Integer(2)).unwrap();
+        assert!(sender.send("b", Value::Integer(3)).is_err());
+        assert_eq!(router.undelivered_count("b"), 1);
+
+        // Opening a mailbox frees its share of the buffer
+        let mut a = router.open("a").unwrap();
+        assert_eq!(a.try_receive().unwrap().message, Value::Integer(1));
+        assert_eq!(sender.send("b", Value::Integer(3)).unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn unacked_messages_are_redelivered_in_order_after_reopen() {
+        let router = AgentMailboxRouter::new();
+        let mut sender = router.open("sender").unwrap();
+        let mut receiver = router.open("receiver").unwrap();
+        for i in 1..=3 {
+            sender.send("receiver", Value::Integer(i)).unwrap();
+        }
+        // 1 is acked, 2 is received but not acked, 3 is still queued
+        assert_eq!(receiver.try_receive().unwrap().seq, 1);
+        receiver.ack("sender", 1);
+        assert_eq!(receiver.try_receive().unwrap().seq, 2);
+        drop(receiver);
+        assert_eq!(router.undelivered_count("receiver"), 2);
+
+        sender.send("receiver", Value::Integer(4)).unwrap();
+        let mut receiver = router.open("receiver").unwrap();
+        let mut redelivered = Vec::new();
+        while let Some(envelope) = receiver.try_receive() {
+            redelivered.push(envelope.seq);
+        }
+        assert_eq!(redelivered, vec![2, 3, 4]);
+    }
+
+    #[tokio::test]
+    async fn receivers_skip_messages_they_already_received() {
+        let router = AgentMailboxRouter::new();
+        let mut sender = router.open("sender").unwrap();
+        let mut receiver = router.open("receiver").unwrap();
+        sender.send("receiver", Value::Integer(1)).unwrap();
+        let envelope = receiver.try_receive().unwrap();
+
+        // A duplicate of an envelope already received is dropped
+        router.deliver(envelope).unwrap();
+        sender.send("receiver", Value::Integer(2)).unwrap();
+        assert_eq!(receiver.try_receive().unwrap().seq, 2);
+        assert!(receiver.try_receive().is_none());
+    }
+}
@@ -6,9 +6,14 @@
 
 use rtfs::ast::{MapKey, MapTypeEntry, TypeExpr};
 use rtfs::runtime::error::{RuntimeError, RuntimeResult};
+use rtfs::runtime::values::ValueMap;
 use rtfs::runtime::Value;
 use std::collections::HashMap;
 
+/// Manifest metadata key listing (comma separated) the named parameters a
+/// capability takes; capabilities without it receive positional args.
+pub const NAMED_PARAMS_METADATA_KEY: &str = "args.named_params";
+
 /// Normalize positional arguments to a map based on a TypeExpr::Map schema.
 ///
 /// # Rules:
@@ -42,7 +47,7 @@ pub fn normalize_args_to_map(args: Vec<Value>, schema: &TypeExpr) -> RuntimeResu
         }
         _ => {
             // Non-map schemas don't need normalization - just wrap in vector
-            return Ok(Value::Vector(args));
+            return Ok(Value::Vector(args.into()));
         }
     };
 
@@ -85,7 +90,7 @@ pub fn normalize_args_to_map(args: Vec<Value>, schema: &TypeExpr) -> RuntimeResu
 
     // Case 2: Zero args with optional-only schema → empty map
     if args.is_empty() && required_count == 0 {
-        return Ok(Value::Map(HashMap::new()));
+        return Ok(Value::Map(HashMap::new().into()));
     }
 
     // Case 3: Zero args with required fields → error
@@ -119,6 +124,52 @@ pub fn normalize_args_to_map(args: Vec<Value>, schema: &TypeExpr) -> RuntimeResu
     )))
 }
 
+/// Deliver the arguments of a capability declaring named parameters as one map.
+///
+/// `(call :cap {:a 1 :b 2})` passes the map through as `{:a 1 :b 2}`. Keys other
+/// than the declared `params` are rejected so a misspelt name fails loudly instead
+/// of being ignored. No args yields an empty map; positional args are rejected.
+pub fn spread_named_args(args: Vec<Value>, params: &[String]) -> RuntimeResult<Value> {
+    let expected = || {
+        params
+            .iter()
+            .map(|p| format!(":{}", p))
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+    match args.as_slice() {
+        [] => Ok(Value::Map(ValueMap::new())),
+        [Value::Map(map)] => {
+            for key in map.keys() {
+                let name = match key {
+                    MapKey::Keyword(k) => k.0.as_str(),
+                    MapKey::String(s) => s.as_str(),
+                    MapKey::Integer(i) => {
+                        return Err(RuntimeError::Generic(format!(
+                            "Named parameter keys must be keywords, got {}. Expected keys: [{}]",
+                            i,
+                            expected()
+                        )))
+                    }
+                };
+                if !params.iter().any(|p| p == name) {
+                    return Err(RuntimeError::Generic(format!(
+                        "Unknown named parameter :{}. Expected keys: [{}]",
+                        name,
+                        expected()
+                    )));
+                }
+            }
+            Ok(args.into_iter().next().unwrap())
+        }
+        _ => Err(RuntimeError::Generic(format!(
+            "Capability takes named parameters; pass a single map with keys [{}]. Got {} positional args.",
+            expected(),
+            args.len()
+        ))),
+    }
+}
+
 /// Check if optional fields are all trailing (after required fields)
 fn validate_trailing_optionals(entries: &[MapTypeEntry]) -> bool {
     let mut seen_optional = false;
@@ -134,7 +185,7 @@ fn validate_trailing_optionals(entries: &[MapTypeEntry]) -> bool {
 }
 
 /// Check if a map value should be treated as passthrough (already has expected keys)
-fn is_passthrough_map(map: &HashMap<MapKey, Value>, entries: &[MapTypeEntry]) -> bool {
+fn is_passthrough_map(map: &ValueMap, entries: &[MapTypeEntry]) -> bool {
     // If the schema has no required fields (i.e., optional-only), any map is valid passthrough.
     //
     // This is important for many OpenAPI-derived capabilities where all query params are optional,
@@ -169,7 +220,7 @@ fn normalize_positional_to_map(
         result.insert(key, value);
     }
 
-    Ok(Value::Map(result))
+    Ok(Value::Map(result.into()))
 }
 
 /// Format field names for error messages
@@ -191,7 +242,7 @@ mod tests {
             entries: fields
                 .iter()
                 .map(|(name, optional)| MapTypeEntry {
-                    key: Keyword(name.to_string()),
+                    key: Keyword::new(name),
                     value_type: Box::new(TypeExpr::Primitive(PrimitiveType::String)),
                     optional: *optional,
                 })
@@ -231,7 +282,7 @@ mod tests {
             MapKey::Keyword(Keyword("line".into())),
             Value::String("hello".into()),
         );
-        let args = vec![Value::Map(input_map.clone())];
+        let args = vec![Value::Map(input_map.clone().into())];
 
         let result = normalize_args_to_map(args, &schema).unwrap();
 
@@ -389,7 +440,7 @@ mod tests {
         let mut input_map = HashMap::new();
         input_map.insert(MapKey::Keyword(Keyword("foo".into())), Value::Integer(1));
         input_map.insert(MapKey::Keyword(Keyword("bar".into())), Value::Integer(2));
-        let args = vec![Value::Map(input_map)];
+        let args = vec![Value::Map(input_map.into())];
 
         let result = normalize_args_to_map(args, &schema).unwrap();
 
@@ -400,4 +451,30 @@ mod tests {
             _ => panic!("Expected Map with :data key"),
         }
     }
+
+    #[test]
+    fn test_spread_named_args() {
+        let params = vec!["city".to_string(), "units".to_string()];
+        let mut input_map = HashMap::new();
+        input_map.insert(
+            MapKey::Keyword(Keyword("city".into())),
+            Value::String("Paris".into()),
+        );
+        let result = spread_named_args(vec![Value::Map(input_map.into())], &params).unwrap();
+        match result {
+            Value::Map(map) => assert_eq!(
+                map.get(&MapKey::Keyword(Keyword("city".into()))),
+                Some(&Value::String("Paris".into()))
+            ),
+            _ => panic!("Expected Map"),
+        }
+
+        let mut typo_map = HashMap::new();
+        typo_map.insert(MapKey::Keyword(Keyword("cty".into())), Value::Nil);
+        let err = spread_named_args(vec![Value::Map(typo_map.into())], &params).unwrap_err();
+        assert!(err.to_string().contains("Unknown named parameter :cty"));
+
+        let err = spread_named_args(vec![Value::String("Paris".into())], &params).unwrap_err();
+        assert!(err.to_string().contains("named parameters"));
+    }
 }
@@ -102,7 +102,7 @@ pub fn inject_capability<L>(
 where
     L: Fn(&str, &Vec<Value>) -> RuntimeResult<()> + 'static + Send + Sync,
 {
-    let sym = Symbol(symbol_name.to_string());
+    let sym = Symbol(symbol_name.into());
     if let Some(orig_val) = env.lookup(&sym) {
         if let Value::Function(func) = orig_val {
             let cap = Capability {
@@ -1,9 +1,8 @@
 use crate::capability_marketplace::CapabilityMarketplace;
 use rtfs::ast::{Keyword, MapKey};
 use rtfs::runtime::error::{RuntimeError, RuntimeResult};
-use rtfs::runtime::values::Value;
+use rtfs::runtime::values::{Value, ValueMap};
 use std::cmp::Ordering;
-use std::collections::HashMap;
 use std::sync::Arc;
 
 pub async fn register_data_capabilities(marketplace: &CapabilityMarketplace) -> RuntimeResult<()> {
@@ -22,7 +21,7 @@ pub async fn register_data_capabilities(marketplace: &CapabilityMarketplace) ->
                         // Parse JSON string to map - LLMs often pass criteria as JSON strings
                         if let Ok(json) = serde_json::from_str::<serde_json::Value>(&s) {
                             if let Some(obj) = json.as_object() {
-                                let mut map: HashMap<MapKey, Value> = HashMap::new();
+                                let mut map: ValueMap = ValueMap::new();
                                 for (k, v) in obj {
                                     let map_key = rtfs::ast::MapKey::String(k.clone());
                                     let value = match v {
@@ -68,8 +67,8 @@ pub async fn register_data_capabilities(marketplace: &CapabilityMarketplace) ->
                                 // Try direct lookup, then try alternative key type (string↔keyword)
                                 let item_val = item_map.get(k).or_else(|| {
                                     match k {
-                                        MapKey::String(s) => item_map.get(&MapKey::Keyword(Keyword(s.clone()))),
-                                        MapKey::Keyword(kw) => item_map.get(&MapKey::String(kw.0.clone())),
+                                        MapKey::String(s) => item_map.get(&MapKey::Keyword(Keyword::new(s.as_str()))),
+                                        MapKey::Keyword(kw) => item_map.get(&MapKey::String(kw.0.to_string())),
                                         MapKey::Integer(_) => None, // No fallback for integer keys
                                     }
                                 });
@@ -86,10 +85,10 @@ pub async fn register_data_capabilities(marketplace: &CapabilityMarketplace) ->
                             false
                         }
                     }).cloned().collect();
-                    Ok(Value::List(filtered))
+                    Ok(Value::List(filtered.into()))
                 } else {
                     // No criteria, return all
-                    Ok(Value::List(items.clone()))
+                    Ok(Value::List(items.clone().into()))
                 }
             }),
             vec![":compute".to_string()], // Safe effect - pure data transformation
@@ -108,11 +107,11 @@ pub async fn register_data_capabilities(marketplace: &CapabilityMarketplace) ->
 
                 let (key, order) = if let Some(Value::Map(params)) = params_val {
                     let k = params.get(&MapKey::String("key".to_string()))
-                        .or_else(|| params.get(&MapKey::Keyword(Keyword("key".to_string()))))
+                        .or_else(|| params.get(&MapKey::Keyword(Keyword("key".into()))))
                         .map(|v| value_to_clean_string(v));
 
                     let o = params.get(&MapKey::String("order".to_string()))
-                        .or_else(|| params.get(&MapKey::Keyword(Keyword("order".to_string()))))
+                        .or_else(|| params.get(&MapKey::Keyword(Keyword("order".into()))))
                         .map(|v| value_to_clean_string(v).to_lowercase())
                         .unwrap_or("asc".to_string());
 
@@ -156,7 +155,7 @@ pub async fn register_data_capabilities(marketplace: &CapabilityMarketplace) ->
                     sorted.reverse();
                 }
 
-                Ok(Value::List(sorted))
+                Ok(Value::List(sorted.into()))
             }),
             vec![":compute".to_string()], // Safe effect - pure data transformation
         )
@@ -176,7 +175,7 @@ pub async fn register_data_capabilities(marketplace: &CapabilityMarketplace) ->
                 let count = if let Some(Value::Map(params)) = params_val {
                     params
                         .get(&MapKey::String("count".to_string()))
-                        .or_else(|| params.get(&MapKey::Keyword(Keyword("count".to_string()))))
+                        .or_else(|| params.get(&MapKey::Keyword(Keyword("count".into()))))
                         .and_then(|v| match v {
                             Value::Integer(i) => Some(*i as usize),
                             Value::String(s) => s.parse().ok(),
@@ -202,7 +201,7 @@ pub async fn register_data_capabilities(marketplace: &CapabilityMarketplace) ->
                 if count == 1 && selected.len() == 1 {
                     Ok(selected.into_iter().next().unwrap())
                 } else {
-                    Ok(Value::List(selected))
+                    Ok(Value::List(selected.into()))
                 }
             }),
             vec![":compute".to_string()], // Safe effect - pure data transformation
@@ -222,7 +221,7 @@ pub async fn register_data_capabilities(marketplace: &CapabilityMarketplace) ->
 fn extract_list_from_value(data: &Value) -> RuntimeResult<Vec<Value>> {
     // Direct list - return immediately
     if let Value::List(items) | Value::Vector(items) = data {
-        return Ok(items.clone());
+        return Ok(items.iter().cloned().collect());
     }
 
     // If it's a map, try to find a list inside
@@ -231,7 +230,7 @@ fn extract_list_from_value(data: &Value) -> RuntimeResult<Vec<Value>> {
         for (_key, val) in map.iter() {
             match val {
                 Value::List(items) | Value::Vector(items) => {
-                    return Ok(items.clone());
+                    return Ok(items.iter().cloned().collect());
                 }
                 // Recurse into nested maps (e.g., {"content": {"items": [...]}})
                 Value::Map(_) => {
@@ -283,12 +282,12 @@ fn extract_data_and_params<'a>(
             // Get _previous_result first (this is the actual data from pipeline)
             let previous_result = map
                 .get(&MapKey::String("_previous_result".to_string()))
-                .or_else(|| map.get(&MapKey::Keyword(Keyword("_previous_result".to_string()))));
+                .or_else(|| map.get(&MapKey::Keyword(Keyword("_previous_result".into()))));
 
             // Get "data" param (may be actual data or a string reference like "step_0_result")
             let data_param = map
                 .get(&MapKey::String("data".to_string()))
-                .or_else(|| map.get(&MapKey::Keyword(Keyword("data".to_string()))));
+                .or_else(|| map.get(&MapKey::Keyword(Keyword("data".into()))));
 
             // Determine actual data to use:
             // 1. If "data" is a list/map (actual data), use it
@@ -309,7 +308,7 @@ fn extract_data_and_params<'a>(
 
             let params = map
                 .get(&MapKey::String(secondary_param.to_string()))
-                .or_else(|| map.get(&MapKey::Keyword(Keyword(secondary_param.to_string()))));
+                .or_else(|| map.get(&MapKey::Keyword(Keyword(secondary_param.into()))));
 
             if let Some(d) = data {
                 // If params is not found, use the input map itself for param extraction
@@ -339,13 +338,13 @@ fn get_path<'a>(val: &'a Value, path: &str) -> Option<&'a Value> {
     if let Value::Map(m) = val {
         // Try exact match first (both string and keyword)
         m.get(&MapKey::String(path.to_string()))
-            .or_else(|| m.get(&MapKey::Keyword(Keyword(path.to_string()))))
+            .or_else(|| m.get(&MapKey::Keyword(Keyword(path.into()))))
             // Try camelCase conversion if path is snake_case
             .or_else(|| {
                 if path.contains('_') {
                     let camel = snake_to_camel(path);
                     m.get(&MapKey::String(camel.clone()))
-                        .or_else(|| m.get(&MapKey::Keyword(Keyword(camel))))
+                        .or_else(|| m.get(&MapKey::Keyword(Keyword(camel.into()))))
                 } else {
                     None
                 }
@@ -355,7 +354,7 @@ fn get_path<'a>(val: &'a Value, path: &str) -> Option<&'a Value> {
                 let snake = camel_to_snake(path);
                 if snake != path {
                     m.get(&MapKey::String(snake.clone()))
-                        .or_else(|| m.get(&MapKey::Keyword(Keyword(snake))))
+                        .or_else(|| m.get(&MapKey::Keyword(Keyword(snake.into()))))
                 } else {
                     None
                 }
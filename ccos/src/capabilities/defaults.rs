@@ -5,11 +5,12 @@ use crate::utils::schema_cardinality::{
 use crate::utils::value_conversion::rtfs_value_to_json;
 use rtfs::ast::{Keyword, MapKey};
 use rtfs::runtime::error::{RuntimeError, RuntimeResult};
-use rtfs::runtime::values::Value;
+use rtfs::runtime::secure_stdlib::SecureStandardLibrary;
+use rtfs::runtime::values::{Value, ValueMap};
 use std::sync::Arc;
 
-fn get_map_string(map: &std::collections::HashMap<MapKey, Value>, key: &str) -> Option<String> {
-    map.get(&MapKey::Keyword(Keyword(key.to_string())))
+fn get_map_string(map: &ValueMap, key: &str) -> Option<String> {
+    map.get(&MapKey::Keyword(Keyword(key.into())))
         .or_else(|| map.get(&MapKey::String(key.to_string())))
         .and_then(|v| match v {
             Value::String(s) => Some(s.clone()),
@@ -17,31 +18,28 @@ fn get_map_string(map: &std::collections::HashMap<MapKey, Value>, key: &str) ->
         })
 }
 
-fn get_map_value<'a>(
-    map: &'a std::collections::HashMap<MapKey, Value>,
-    key: &str,
-) -> Option<&'a Value> {
-    map.get(&MapKey::Keyword(Keyword(key.to_string())))
+fn get_map_value<'a>(map: &'a ValueMap, key: &str) -> Option<&'a Value> {
+    map.get(&MapKey::Keyword(Keyword(key.into())))
         .or_else(|| map.get(&MapKey::String(key.to_string())))
 }
 
 fn mk_hint(action: &str, reason: &str, param: Option<&str>) -> Value {
     let mut out = std::collections::HashMap::new();
     out.insert(
-        MapKey::Keyword(Keyword("action".to_string())),
-        Value::Keyword(Keyword(action.to_string())),
+        MapKey::Keyword(Keyword("action".into())),
+        Value::Keyword(Keyword(action.into())),
     );
     out.insert(
-        MapKey::Keyword(Keyword("reason".to_string())),
+        MapKey::Keyword(Keyword("reason".into())),
         Value::String(reason.to_string()),
     );
     if let Some(param) = param {
         out.insert(
-            MapKey::Keyword(Keyword("param".to_string())),
+            MapKey::Keyword(Keyword("param".into())),
             Value::String(param.to_string()),
         );
     }
-    Value::Map(out)
+    Value::Map(out.into())
 }
 
 fn cardinality_hint_impl(input: &Value) -> RuntimeResult<Value> {
@@ -121,6 +119,67 @@ fn cardinality_hint_impl(input: &Value) -> RuntimeResult<Value> {
     }
 }
 
+fn math_add_impl(input: &Value) -> RuntimeResult<Value> {
+    match input {
+        // New calling convention: map with :args containing the argument list
+        Value::Map(map) => {
+            if let Some(args_val) = get_map_value(map, "args") {
+                match args_val {
+                    Value::List(args) | Value::Vector(args) => {
+                        SecureStandardLibrary::add(args.iter().cloned().collect())
+                    }
+                    other => Err(RuntimeError::TypeError {
+                        expected: "list".to_string(),
+                        actual: other.type_name().to_string(),
+                        operation: "ccos.math.add".to_string(),
+                    }),
+                }
+            } else {
+                // Fallback: sum all numeric values in the map
+                // This supports { "a": 1, "b": 2 } usage
+                let numbers: Vec<Value> = map
+                    .values()
+                    .filter(|v| matches!(v, Value::Integer(_) | Value::Float(_)))
+                    .cloned()
+                    .collect();
+                if numbers.is_empty() {
+                    Err(RuntimeError::Generic(
+                        "Missing :args or numeric values for ccos.math.add".to_string(),
+                    ))
+                } else {
+                    SecureStandardLibrary::add(numbers)
+                }
+            }
+        }
+        // Backward compatibility: direct list of arguments
+        Value::List(args) | Value::Vector(args) => {
+            SecureStandardLibrary::add(args.iter().cloned().collect())
+        }
+        other => Err(RuntimeError::TypeError {
+            expected: "map or list".to_string(),
+            actual: other.type_name().to_string(),
+            operation: "ccos.math.add".to_string(),
+        }),
+    }
+}
+
+/// Argument list of a `ccos.math.*` call: `{:args [...]}` or a bare list
+fn math_args(input: &Value, operation: &str) -> RuntimeResult<Vec<Value>> {
+    let args = match input {
+        Value::Map(map) => get_map_value(map, "args")
+            .ok_or_else(|| RuntimeError::Generic(format!("Missing :args for {}", operation)))?,
+        other => other,
+    };
+    match args {
+        Value::List(items) | Value::Vector(items) => Ok(items.iter().cloned().collect()),
+        other => Err(RuntimeError::TypeError {
+            expected: "list".to_string(),
+            actual: other.type_name().to_string(),
+            operation: operation.to_string(),
+        }),
+    }
+}
+
 pub async fn register_default_capabilities(
     marketplace: &CapabilityMarketplace,
 ) -> RuntimeResult<()> {
@@ -145,7 +204,7 @@ pub async fn register_default_capabilities(
                     Value::Map(map) => {
                         // Try standard :args convention first
                         let args_val = map
-                            .get(&MapKey::Keyword(Keyword("args".to_string())))
+                            .get(&MapKey::Keyword(Keyword("args".into())))
                             .or_else(|| map.get(&MapKey::String("args".to_string())));
 
                         if let Some(args_val) = args_val {
@@ -173,7 +232,7 @@ pub async fn register_default_capabilities(
                             for key in &["message", "content", "text", "value"] {
                                 if let Some(val) = map
                                     .get(&MapKey::String(key.to_string()))
-                                    .or_else(|| map.get(&MapKey::Keyword(Keyword(key.to_string()))))
+                                    .or_else(|| map.get(&MapKey::Keyword(Keyword::new(key))))
                                 {
                                     return Ok(val.clone());
                                 }
@@ -220,89 +279,48 @@ pub async fn register_default_capabilities(
             "ccos.math.add".to_string(),
             "Math Add Capability".to_string(),
             "Adds numeric values".to_string(),
-            Arc::new(|input| {
-                match input {
-                    // New calling convention: map with :args containing the argument list
-                    Value::Map(map) => {
-                        let args_val = map
-                            .get(&MapKey::Keyword(Keyword("args".to_string())))
-                            .or_else(|| map.get(&MapKey::String("args".to_string())));
-
-                        if let Some(args_val) = args_val {
-                            match args_val {
-                                Value::List(args) | Value::Vector(args) => {
-                                    let mut sum = 0i64;
-                                    for arg in args {
-                                        match arg {
-                                            Value::Integer(i) => sum += i,
-                                            _ => {
-                                                return Err(RuntimeError::TypeError {
-                                                    expected: "integer".to_string(),
-                                                    actual: arg.type_name().to_string(),
-                                                    operation: "ccos.math.add".to_string(),
-                                                })
-                                            }
-                                        }
-                                    }
-                                    Ok(Value::Integer(sum))
-                                }
-                                other => Err(RuntimeError::TypeError {
-                                    expected: "list".to_string(),
-                                    actual: other.type_name().to_string(),
-                                    operation: "ccos.math.add".to_string(),
-                                }),
-                            }
-                        } else {
-                            // Fallback: sum all integer values in the map
-                            // This supports { "a": 1, "b": 2 } usage
-                            let mut sum = 0i64;
-                            let mut found_int = false;
-
-                            for val in map.values() {
-                                if let Value::Integer(i) = val {
-                                    sum += i;
-                                    found_int = true;
-                                }
-                            }
-
-                            if found_int {
-                                Ok(Value::Integer(sum))
-                            } else {
-                                Err(RuntimeError::Generic(
-                                    "Missing :args or integer values for ccos.math.add".to_string(),
-                                ))
-                            }
-                        }
-                    }
-                    // Backward compatibility: direct list of arguments
-                    Value::List(args) | Value::Vector(args) => {
-                        let mut sum = 0i64;
-                        for arg in args {
-                            match arg {
-                                Value::Integer(i) => sum += i,
-                                _ => {
-                                    return Err(RuntimeError::TypeError {
-                                        expected: "integer".to_string(),
-                                        actual: arg.type_name().to_string(),
-                                        operation: "ccos.math.add".to_string(),
-                                    })
-                                }
-                            }
-                        }
-                        Ok(Value::Integer(sum))
-                    }
-                    other => Err(RuntimeError::TypeError {
-                        expected: "map or list".to_string(),
-                        actual: other.type_name().to_string(),
-                        operation: "ccos.math.add".to_string(),
-                    }),
-                }
-            }),
+            Arc::new(math_add_impl),
             vec![":compute".to_string()], // Safe effect - pure computation
         )
         .await
         .map_err(|e| RuntimeError::Generic(format!("Failed to register ccos.math.add: {:?}", e)))?;
 
+    // Register ccos.math.subtract/multiply/divide - pure compute backed by the secure
+    // stdlib operators, so promotion and division-by-zero errors match `-`, `*` and `/`
+    type ArithmeticFn = fn(Vec<Value>) -> RuntimeResult<Value>;
+    let stdlib_math: [(&'static str, &str, &str, ArithmeticFn); 3] = [
+        (
+            "ccos.math.subtract",
+            "Math Subtract Capability",
+            "Subtracts the remaining numeric values from the first",
+            SecureStandardLibrary::subtract,
+        ),
+        (
+            "ccos.math.multiply",
+            "Math Multiply Capability",
+            "Multiplies numeric values",
+            SecureStandardLibrary::multiply,
+        ),
+        (
+            "ccos.math.divide",
+            "Math Divide Capability",
+            "Divides the first numeric value by the remaining ones",
+            SecureStandardLibrary::divide,
+        ),
+    ];
+    for (id, name, description, op) in stdlib_math {
+        marketplace
+            .register_local_capability_with_effects(
+                id.to_string(),
+                name.to_string(),
+                description.to_string(),
+                Arc::new(move |input| op(math_args(input, id)?)),
+                vec![":compute".to_string()], // Safe effect - pure computation
+            )
+            .await
+            .map_err(|e| RuntimeError::Generic(format!("Failed to register {}: {:?}", id, e)))?;
+    }
+
     // Register ccos.schema.cardinality_hint capability - deterministic schema helper
     // Effect: :compute - safe for grounding
     // Returns: {:action :map|:pass|:unknown :reason "..." :param "..."}
@@ -336,7 +354,7 @@ pub async fn register_default_capabilities(
                         Value::Map(map) => {
                             // Try standard :args convention first
                             let args_val = map
-                                .get(&MapKey::Keyword(Keyword("args".to_string())))
+                                .get(&MapKey::Keyword(Keyword("args".into())))
                                 .or_else(|| map.get(&MapKey::String("args".to_string())));
 
                             if let Some(args_val) = args_val {
@@ -358,7 +376,7 @@ pub async fn register_default_capabilities(
                             for key in &["prompt", "question", "message", "text"] {
                                 if let Some(val) = map
                                     .get(&MapKey::String(key.to_string()))
-                                    .or_else(|| map.get(&MapKey::Keyword(Keyword(key.to_string()))))
+                                    .or_else(|| map.get(&MapKey::Keyword(Keyword::new(key))))
                                 {
                                     return Ok(val.to_string());
                                 }
@@ -428,7 +446,7 @@ pub async fn register_default_capabilities(
                 let message = match input {
                     Value::Map(map) => {
                         let args_val = map
-                            .get(&MapKey::Keyword(Keyword("args".to_string())))
+                            .get(&MapKey::Keyword(Keyword("args".into())))
                             .or_else(|| map.get(&MapKey::String("args".to_string())));
 
                         if let Some(args_val) = args_val {
@@ -451,9 +469,7 @@ pub async fn register_default_capabilities(
                             if let Some(val) = map
                                 .get(&MapKey::String("_previous_result".to_string()))
                                 .or_else(|| {
-                                    map.get(&MapKey::Keyword(Keyword(
-                                        "_previous_result".to_string(),
-                                    )))
+                                    map.get(&MapKey::Keyword(Keyword("_previous_result".into())))
                                 })
                             {
                                 msg = Some(match val {
@@ -464,10 +480,9 @@ pub async fn register_default_capabilities(
 
                             if msg.is_none() {
                                 for key in &["message", "content", "text", "value"] {
-                                    if let Some(val) =
-                                        map.get(&MapKey::String(key.to_string())).or_else(|| {
-                                            map.get(&MapKey::Keyword(Keyword(key.to_string())))
-                                        })
+                                    if let Some(val) = map
+                                        .get(&MapKey::String(key.to_string()))
+                                        .or_else(|| map.get(&MapKey::Keyword(Keyword::new(key))))
                                     {
                                         msg = Some(match val {
                                             Value::String(s) => s.clone(),
@@ -523,7 +538,7 @@ pub async fn register_default_capabilities(
                 let message = match input {
                     Value::Map(map) => {
                         let args_val = map
-                            .get(&MapKey::Keyword(Keyword("args".to_string())))
+                            .get(&MapKey::Keyword(Keyword("args".into())))
                             .or_else(|| map.get(&MapKey::String("args".to_string())));
 
                         if let Some(args_val) = args_val {
@@ -544,7 +559,7 @@ pub async fn register_default_capabilities(
                             for key in &["message", "content", "text", "value"] {
                                 if let Some(val) = map
                                     .get(&MapKey::String(key.to_string()))
-                                    .or_else(|| map.get(&MapKey::Keyword(Keyword(key.to_string()))))
+                                    .or_else(|| map.get(&MapKey::Keyword(Keyword::new(key))))
                                 {
                                     msg = Some(match val {
                                         Value::String(s) => s.clone(),
@@ -595,13 +610,13 @@ mod tests {
 
     #[test]
     fn test_cardinality_hint_map_when_source_collection_and_param_scalar() {
-        let input = Value::Map(std::collections::HashMap::from([
+        let input = Value::Map(ValueMap::from_iter([
             (
-                MapKey::Keyword(Keyword("source_rtfs_schema".to_string())),
+                MapKey::Keyword(Keyword("source_rtfs_schema".into())),
                 Value::String("[:vector :string]".to_string()),
             ),
             (
-                MapKey::Keyword(Keyword("consumer_schema".to_string())),
+                MapKey::Keyword(Keyword("consumer_schema".into())),
                 crate::utils::value_conversion::json_to_rtfs_value(&serde_json::json!({
                     "type": "object",
                     "properties": {
@@ -611,7 +626,7 @@ mod tests {
                 .unwrap(),
             ),
             (
-                MapKey::Keyword(Keyword("param".to_string())),
+                MapKey::Keyword(Keyword("param".into())),
                 Value::String("data".to_string()),
             ),
         ]));
@@ -621,20 +636,20 @@ mod tests {
             panic!("expected map");
         };
         assert_eq!(
-            m.get(&MapKey::Keyword(Keyword("action".to_string()))),
-            Some(&Value::Keyword(Keyword("map".to_string())))
+            m.get(&MapKey::Keyword(Keyword("action".into()))),
+            Some(&Value::Keyword(Keyword("map".into())))
         );
     }
 
     #[test]
     fn test_cardinality_hint_pass_when_param_expects_array() {
-        let input = Value::Map(std::collections::HashMap::from([
+        let input = Value::Map(ValueMap::from_iter([
             (
-                MapKey::Keyword(Keyword("source_rtfs_schema".to_string())),
+                MapKey::Keyword(Keyword("source_rtfs_schema".into())),
                 Value::String("[:vector :string]".to_string()),
             ),
             (
-                MapKey::Keyword(Keyword("consumer_schema".to_string())),
+                MapKey::Keyword(Keyword("consumer_schema".into())),
                 crate::utils::value_conversion::json_to_rtfs_value(&serde_json::json!({
                     "type": "object",
                     "properties": {
@@ -644,7 +659,7 @@ mod tests {
                 .unwrap(),
             ),
             (
-                MapKey::Keyword(Keyword("param".to_string())),
+                MapKey::Keyword(Keyword("param".into())),
                 Value::String("data".to_string()),
             ),
         ]));
@@ -654,8 +669,106 @@ mod tests {
             panic!("expected map");
         };
         assert_eq!(
-            m.get(&MapKey::Keyword(Keyword("action".to_string()))),
-            Some(&Value::Keyword(Keyword("pass".to_string())))
+            m.get(&MapKey::Keyword(Keyword("action".into()))),
+            Some(&Value::Keyword(Keyword("pass".into())))
+        );
+    }
+
+    #[test]
+    fn test_math_add_promotes_mixed_numerics_to_float() {
+        let args = Value::Vector(vec![Value::Integer(1), Value::Float(2.5)].into());
+        assert_eq!(math_add_impl(&args).unwrap(), Value::Float(3.5));
+
+        let call = Value::Map(ValueMap::from_iter([(
+            MapKey::Keyword(Keyword("args".into())),
+            Value::List(vec![Value::Float(0.5), Value::Integer(2)].into()),
+        )]));
+        assert_eq!(math_add_impl(&call).unwrap(), Value::Float(2.5));
+    }
+
+    #[test]
+    fn test_math_add_keeps_integer_sums_integer() {
+        let args = Value::Vector(vec![Value::Integer(1), Value::Integer(2)].into());
+        assert_eq!(math_add_impl(&args).unwrap(), Value::Integer(3));
+
+        let overflow = Value::Vector(vec![Value::Integer(i64::MAX), Value::Integer(1)].into());
+        assert!(math_add_impl(&overflow).is_err());
+        let not_a_number = Value::Vector(vec![Value::String("1".to_string())].into());
+        assert!(math_add_impl(&not_a_number).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_stdlib_backed_math_capabilities() {
+        let registry = Arc::new(tokio::sync::RwLock::new(
+            crate::capabilities::registry::CapabilityRegistry::new(),
+        ));
+        let marketplace = CapabilityMarketplace::new(registry);
+        register_default_capabilities(&marketplace).await.unwrap();
+
+        let call = |args: Vec<Value>| {
+            Value::Map(ValueMap::from_iter([(
+                MapKey::Keyword(Keyword("args".into())),
+                Value::Vector(args.into()),
+            )]))
+        };
+        let run = |id: &'static str, args: Vec<Value>| {
+            let input = call(args);
+            let marketplace = &marketplace;
+            async move { marketplace.execute_capability(id, &input).await }
+        };
+
+        assert_eq!(
+            run(
+                "ccos.math.subtract",
+                vec![Value::Integer(10), Value::Integer(3)]
+            )
+            .await,
+            Ok(Value::Integer(7))
+        );
+        assert_eq!(
+            run(
+                "ccos.math.subtract",
+                vec![Value::Integer(1), Value::Float(0.5)]
+            )
+            .await,
+            Ok(Value::Float(0.5))
+        );
+        assert_eq!(
+            run(
+                "ccos.math.multiply",
+                vec![Value::Integer(4), Value::Integer(5)]
+            )
+            .await,
+            Ok(Value::Integer(20))
+        );
+        assert_eq!(
+            run(
+                "ccos.math.multiply",
+                vec![Value::Integer(2), Value::Float(1.5)]
+            )
+            .await,
+            Ok(Value::Float(3.0))
+        );
+        assert_eq!(
+            run(
+                "ccos.math.divide",
+                vec![Value::Integer(7), Value::Integer(2)]
+            )
+            .await,
+            Ok(Value::Float(3.5))
+        );
+        assert_eq!(
+            run(
+                "ccos.math.divide",
+                vec![Value::Integer(1), Value::Integer(0)]
+            )
+            .await,
+            Err(RuntimeError::DivisionByZero)
+        );
+        assert!(
+            run("ccos.math.divide", vec![Value::String("1".to_string())])
+                .await
+                .is_err()
         );
     }
 }
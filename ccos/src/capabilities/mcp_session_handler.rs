@@ -360,7 +360,7 @@ impl MCPSessionHandler {
                                 if s.starts_with(':') {
                                     s[1..].to_string()
                                 } else {
-                                    s.clone()
+                                    s.to_string()
                                 }
                             }
                             MapKey::String(s) => s.clone(),
@@ -643,13 +643,13 @@ fn normalize_map_keys(value: Value) -> Value {
             for (key, val) in map.into_iter() {
                 // Convert string keys to keyword keys
                 let new_key = match key {
-                    MapKey::String(s) => MapKey::Keyword(Keyword(s)),
+                    MapKey::String(s) => MapKey::Keyword(Keyword(s.into())),
                     other => other,
                 };
                 // Recursively normalize nested values
                 new_map.insert(new_key, normalize_map_keys(val));
             }
-            Value::Map(new_map)
+            Value::Map(new_map.into())
         }
         Value::List(list) => Value::List(list.into_iter().map(normalize_map_keys).collect()),
         Value::Vector(vec) => Value::Vector(vec.into_iter().map(normalize_map_keys).collect()),
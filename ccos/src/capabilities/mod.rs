@@ -1,3 +1,4 @@
+pub mod agent_mailbox;
 pub mod agent_ops;
 pub mod arg_normalization;
 pub mod capability;
@@ -6,19 +7,24 @@ pub mod defaults;
 pub mod mcp_session_handler;
 pub mod native_provider;
 pub mod network;
+pub mod output_decoding;
+pub mod plan_ops;
 pub mod provider;
 pub mod providers;
 pub mod registry;
 pub mod sandbox_ops;
 pub mod session_pool;
 
+pub use agent_mailbox::{Agent, AgentEnvelope, AgentMailboxRouter};
 pub use agent_ops::register_agent_ops_capabilities;
-pub use arg_normalization::normalize_args_to_map;
+pub use arg_normalization::{normalize_args_to_map, spread_named_args};
 pub use capability::*;
 pub use defaults::register_default_capabilities;
 pub use mcp_session_handler::*;
 pub use native_provider::NativeCapabilityProvider;
 pub use network::register_network_capabilities;
+pub use output_decoding::decode_json_output;
+pub use plan_ops::register_plan_ops_capabilities;
 pub use provider::*;
 pub use providers::*;
 pub use registry::*;
@@ -7,7 +7,7 @@ use crate::capability_marketplace::types::NativeCapability;
 use crate::ops;
 use futures::future::{BoxFuture, FutureExt};
 use rtfs::runtime::error::{RuntimeError, RuntimeResult};
-use rtfs::runtime::values::Value;
+use rtfs::runtime::values::{Value, ValueMap};
 use std::collections::HashMap;
 use std::path::PathBuf;
 use std::sync::Arc;
@@ -514,9 +514,7 @@ fn get_string_param(inputs: &Value, key: &str) -> Result<String, RuntimeError> {
                 }
             }
             // Try Keyword key (from RTFS parsing: {:prompt "..."})
-            if let Some(v) = map.get(&rtfs::ast::MapKey::Keyword(rtfs::ast::Keyword(
-                key.to_string(),
-            ))) {
+            if let Some(v) = map.get(&rtfs::ast::MapKey::Keyword(rtfs::ast::Keyword(key.into()))) {
                 if let Some(s) = v.as_string() {
                     return Ok(s.to_string());
                 }
@@ -540,9 +538,7 @@ fn get_optional_string_param(inputs: &Value, key: &str) -> Option<String> {
                 }
             }
             // Try Keyword key
-            if let Some(v) = map.get(&rtfs::ast::MapKey::Keyword(rtfs::ast::Keyword(
-                key.to_string(),
-            ))) {
+            if let Some(v) = map.get(&rtfs::ast::MapKey::Keyword(rtfs::ast::Keyword(key.into()))) {
                 if let Some(s) = v.as_string() {
                     return Some(s.to_string());
                 }
@@ -563,9 +559,7 @@ fn get_optional_value_as_string(inputs: &Value, key: &str) -> Option<String> {
                 return Some(format!("{:?}", v));
             }
             // Try Keyword key
-            if let Some(v) = map.get(&rtfs::ast::MapKey::Keyword(rtfs::ast::Keyword(
-                key.to_string(),
-            ))) {
+            if let Some(v) = map.get(&rtfs::ast::MapKey::Keyword(rtfs::ast::Keyword(key.into()))) {
                 return Some(format!("{:?}", v));
             }
             None
@@ -1222,7 +1216,7 @@ fn create_llm_generate_capability() -> NativeCapability {
                         if response.approval_required {
                             // Return a structured response indicating approval needed
                             Ok(Value::Map({
-                                let mut map = std::collections::HashMap::new();
+                                let mut map = ValueMap::new();
                                 map.insert(
                                     rtfs::ast::MapKey::String("approval_required".to_string()),
                                     Value::Boolean(true),
@@ -1310,7 +1304,7 @@ mod tests {
 
         // Test executing a capability (config show)
         // We use config show because it's safe and doesn't require complex inputs
-        let inputs = Value::Map(HashMap::new());
+        let inputs = Value::Map(HashMap::new().into());
 
         // Execute capability via async executor path (emulating ExecutorVariant)
         if let Some(capability) = provider.get_capability("ccos.cli.config.show") {
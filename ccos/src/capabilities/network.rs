@@ -8,13 +8,12 @@ use crate::capability_marketplace::CapabilityMarketplace;
 use futures::future::BoxFuture;
 use rtfs::ast::{Keyword, MapKey, MapTypeEntry, PrimitiveType, TypeExpr};
 use rtfs::runtime::error::{RuntimeError, RuntimeResult};
-use rtfs::runtime::values::Value;
-use std::collections::HashMap;
+use rtfs::runtime::values::{Value, ValueMap};
 use std::sync::Arc;
 use url::Url;
 
-fn get_map_string(map: &HashMap<MapKey, Value>, key: &str) -> Option<String> {
-    map.get(&MapKey::Keyword(Keyword(key.to_string())))
+fn get_map_string(map: &ValueMap, key: &str) -> Option<String> {
+    map.get(&MapKey::Keyword(Keyword(key.into())))
         .or_else(|| map.get(&MapKey::String(key.to_string())))
         .and_then(|v| match v {
             Value::String(s) => Some(s.clone()),
@@ -22,8 +21,8 @@ fn get_map_string(map: &HashMap<MapKey, Value>, key: &str) -> Option<String> {
         })
 }
 
-fn get_map_value<'a>(map: &'a HashMap<MapKey, Value>, key: &str) -> Option<&'a Value> {
-    map.get(&MapKey::Keyword(Keyword(key.to_string())))
+fn get_map_value<'a>(map: &'a ValueMap, key: &str) -> Option<&'a Value> {
+    map.get(&MapKey::Keyword(Keyword(key.into())))
         .or_else(|| map.get(&MapKey::String(key.to_string())))
 }
 
@@ -37,17 +36,17 @@ pub async fn register_network_capabilities(
     let input_schema = TypeExpr::Map {
         entries: vec![
             MapTypeEntry {
-                key: Keyword("url".to_string()),
+                key: Keyword("url".into()),
                 value_type: Box::new(TypeExpr::Primitive(PrimitiveType::String)),
                 optional: false,
             },
             MapTypeEntry {
-                key: Keyword("method".to_string()),
+                key: Keyword("method".into()),
                 value_type: Box::new(TypeExpr::Primitive(PrimitiveType::String)),
                 optional: true,
             },
             MapTypeEntry {
-                key: Keyword("headers".to_string()),
+                key: Keyword("headers".into()),
                 value_type: Box::new(TypeExpr::Map {
                     entries: vec![],
                     wildcard: Some(Box::new(TypeExpr::Primitive(PrimitiveType::String))),
@@ -55,12 +54,12 @@ pub async fn register_network_capabilities(
                 optional: true,
             },
             MapTypeEntry {
-                key: Keyword("body".to_string()),
+                key: Keyword("body".into()),
                 value_type: Box::new(TypeExpr::Primitive(PrimitiveType::String)),
                 optional: true,
             },
             MapTypeEntry {
-                key: Keyword("timeout_ms".to_string()),
+                key: Keyword("timeout_ms".into()),
                 value_type: Box::new(TypeExpr::Primitive(PrimitiveType::Int)),
                 optional: true,
             },
@@ -72,17 +71,17 @@ pub async fn register_network_capabilities(
     let output_schema = TypeExpr::Map {
         entries: vec![
             MapTypeEntry {
-                key: Keyword("status".to_string()),
+                key: Keyword("status".into()),
                 value_type: Box::new(TypeExpr::Primitive(PrimitiveType::Int)),
                 optional: false,
             },
             MapTypeEntry {
-                key: Keyword("body".to_string()),
+                key: Keyword("body".into()),
                 value_type: Box::new(TypeExpr::Primitive(PrimitiveType::String)),
                 optional: false,
             },
             MapTypeEntry {
-                key: Keyword("headers".to_string()),
+                key: Keyword("headers".into()),
                 value_type: Box::new(TypeExpr::Map {
                     entries: vec![],
                     wildcard: Some(Box::new(TypeExpr::Primitive(PrimitiveType::String))),
@@ -90,16 +89,16 @@ pub async fn register_network_capabilities(
                 optional: false,
             },
             MapTypeEntry {
-                key: Keyword("usage".to_string()),
+                key: Keyword("usage".into()),
                 value_type: Box::new(TypeExpr::Map {
                     entries: vec![
                         MapTypeEntry {
-                            key: Keyword("network_egress_bytes".to_string()),
+                            key: Keyword("network_egress_bytes".into()),
                             value_type: Box::new(TypeExpr::Primitive(PrimitiveType::Int)),
                             optional: false,
                         },
                         MapTypeEntry {
-                            key: Keyword("network_ingress_bytes".to_string()),
+                            key: Keyword("network_ingress_bytes".into()),
                             value_type: Box::new(TypeExpr::Primitive(PrimitiveType::Int)),
                             optional: false,
                         },
@@ -141,7 +140,7 @@ pub async fn register_network_capabilities(
                     Value::List(args) | Value::Vector(args) => {
                         // Positional: url, method?, headers?, body?
                         let url = args
-                            .first()
+                            .front()
                             .and_then(|v| v.as_string().map(|s| s.to_string()))
                             .ok_or_else(|| {
                                 RuntimeError::Generic(
@@ -293,7 +292,7 @@ pub async fn register_network_capabilities(
                     for (key, value) in hdrs.iter() {
                         let key_str = match key {
                             MapKey::String(s) => s.clone(),
-                            MapKey::Keyword(k) => k.0.clone(),
+                            MapKey::Keyword(k) => k.0.to_string(),
                             MapKey::Integer(i) => i.to_string(),
                         };
                         if key_str.eq_ignore_ascii_case("content-type") {
@@ -362,12 +361,12 @@ pub async fn register_network_capabilities(
                 let network_ingress_bytes = body_text.len() as u64;
 
                 // Build response map
-                let mut result_map: HashMap<MapKey, Value> = HashMap::new();
+                let mut result_map: ValueMap = ValueMap::new();
                 result_map.insert(MapKey::String("status".to_string()), Value::Integer(status));
                 result_map.insert(MapKey::String("body".to_string()), Value::String(body_text));
 
                 // Convert headers to RTFS map
-                let mut headers_result: HashMap<MapKey, Value> = HashMap::new();
+                let mut headers_result: ValueMap = ValueMap::new();
                 for (key, value) in response_headers.iter() {
                     headers_result.insert(
                         MapKey::String(key.to_string()),
@@ -379,7 +378,7 @@ pub async fn register_network_capabilities(
                     Value::Map(headers_result),
                 );
 
-                let mut usage: HashMap<MapKey, Value> = HashMap::new();
+                let mut usage: ValueMap = ValueMap::new();
                 usage.insert(
                     MapKey::String("network_egress_bytes".to_string()),
                     Value::Integer(network_egress_bytes as i64),
@@ -0,0 +1,153 @@
+//! Output decoding for capabilities that return JSON text.
+//!
+//! A capability declaring JSON output (see `CapabilityManifest::with_json_output`)
+//! has its result parsed by the marketplace, so plans receive structured values
+//! instead of calling `parse-json` themselves. Object keys named by the output
+//! schema become keywords, so `(get result :temperature)` works directly.
+
+use crate::utils::value_conversion::json_to_rtfs_value;
+use rtfs::ast::{MapKey, TypeExpr};
+use rtfs::runtime::error::{RuntimeError, RuntimeResult};
+use rtfs::runtime::Value;
+
+/// Manifest metadata key marking a capability whose result is JSON text.
+pub const JSON_OUTPUT_METADATA_KEY: &str = "output.json";
+
+/// Parse the JSON text a capability returned into a value shaped by `schema`.
+///
+/// The text is either the result itself (a string) or, for HTTP-style results,
+/// the string under its `"body"` key. An HTTP-style result whose `"status"` is
+/// not 2xx is an error rather than a body to decode. Any other result is
+/// returned unchanged.
+pub fn decode_json_output(result: Value, schema: Option<&TypeExpr>) -> RuntimeResult<Value> {
+    let text = match &result {
+        Value::String(text) => text,
+        Value::Map(map) => match map.get(&MapKey::String("body".to_string())) {
+            Some(Value::String(body)) => {
+                if let Some(Value::Integer(status)) = map.get(&MapKey::String("status".to_string()))
+                {
+                    if !(200..300).contains(status) {
+                        return Err(RuntimeError::NetworkError(format!(
+                            "HTTP request failed with status {}: {}",
+                            status, body
+                        )));
+                    }
+                }
+                body
+            }
+            _ => return Ok(result),
+        },
+        _ => return Ok(result),
+    };
+    let json: serde_json::Value = serde_json::from_str(text)
+        .map_err(|e| RuntimeError::JsonError(format!("Invalid JSON capability output: {}", e)))?;
+    let value = json_to_rtfs_value(&json)?;
+    Ok(match schema {
+        Some(schema) => keywordize(value, schema),
+        None => value,
+    })
+}
+
+/// Turn the string keys of maps into the keywords `schema` declares for them,
+/// following nested map, vector and optional types. Undeclared keys stay strings.
+fn keywordize(value: Value, schema: &TypeExpr) -> Value {
+    match (value, schema) {
+        (Value::Map(map), TypeExpr::Map { entries, .. }) => Value::Map(
+            map.into_iter()
+                .map(|(key, value)| {
+                    let entry = match &key {
+                        MapKey::String(name) => entries.iter().find(|e| e.key.0.as_str() == name),
+                        _ => None,
+                    };
+                    match entry {
+                        Some(entry) => (
+                            MapKey::Keyword(entry.key.clone()),
+                            keywordize(value, &entry.value_type),
+                        ),
+                        None => (key, value),
+                    }
+                })
+                .collect(),
+        ),
+        (Value::Vector(items), TypeExpr::Vector(element)) => Value::Vector(
+            items
+                .into_iter()
+                .map(|item| keywordize(item, element))
+                .collect(),
+        ),
+        (value, TypeExpr::Optional(inner)) => keywordize(value, inner),
+        (value, _) => value,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rtfs::ast::Keyword;
+    use rtfs::runtime::values::ValueMap;
+
+    fn kw(name: &str) -> MapKey {
+        MapKey::Keyword(Keyword(name.into()))
+    }
+
+    #[test]
+    fn keywordizes_declared_keys_only() {
+        let schema =
+            TypeExpr::from_str("[:map [:city :string] [:days [:vector [:map [:high :int]]]]]")
+                .unwrap();
+        let decoded = decode_json_output(
+            Value::String(r#"{"city": "Paris", "days": [{"high": 21}], "extra": true}"#.into()),
+            Some(&schema),
+        )
+        .unwrap();
+
+        let Value::Map(map) = decoded else {
+            panic!("expected a map");
+        };
+        assert_eq!(map.get(&kw("city")), Some(&Value::String("Paris".into())));
+        assert_eq!(
+            map.get(&MapKey::String("extra".into())),
+            Some(&Value::Boolean(true))
+        );
+        let Some(Value::Vector(days)) = map.get(&kw("days")) else {
+            panic!("expected days to be a vector");
+        };
+        let Value::Map(day) = &days[0] else {
+            panic!("expected a map, got {}", days[0]);
+        };
+        assert_eq!(day.get(&kw("high")), Some(&Value::Integer(21)));
+    }
+
+    #[test]
+    fn rejects_invalid_json() {
+        let err = decode_json_output(Value::String("not json".into()), None).unwrap_err();
+        assert!(matches!(err, RuntimeError::JsonError(_)), "{:?}", err);
+        // Non-text results pass through untouched
+        assert_eq!(
+            decode_json_output(Value::Integer(1), None).unwrap(),
+            Value::Integer(1)
+        );
+    }
+
+    #[test]
+    fn decodes_only_successful_http_bodies() {
+        let response = |status: i64, body: &str| {
+            let mut map = ValueMap::new();
+            map.insert(MapKey::String("status".into()), Value::Integer(status));
+            map.insert(MapKey::String("body".into()), Value::String(body.into()));
+            Value::Map(map)
+        };
+
+        assert_eq!(
+            decode_json_output(response(201, "[1]"), None).unwrap(),
+            Value::Vector(vec![Value::Integer(1)].into())
+        );
+        let err =
+            decode_json_output(response(404, r#"{"error": "no such city"}"#), None).unwrap_err();
+        assert!(
+            matches!(&err, RuntimeError::NetworkError(msg) if msg.contains("404")),
+            "{:?}",
+            err
+        );
+    }
+}
@@ -0,0 +1,194 @@
+//! Plan composition capabilities
+//!
+//! Provides `ccos.plan.invoke`, which runs another RTFS plan as a subroutine and
+//! returns its result. The child plan is named either by `:plan-id` (looked up in
+//! the plan archive) or given inline as `:rtfs`; entries of the optional `:args`
+//! map are bound as variables in the child plan.
+//!
+//! Child plans run through `GovernanceKernel::validate_and_execute` under the
+//! calling plan's security context. The nesting depth travels with that context
+//! (as the `plan-invoke-depth` plan parameter), so each call chain is counted on
+//! its own, and an invocation that would exceed the configured maximum depth
+//! fails instead of recursing further. A marketplace handler never sees its
+//! caller's context, so the orchestrator hands plan calls to `PlanInvoker`.
+
+use crate::capability_marketplace::CapabilityMarketplace;
+use crate::governance_kernel::GovernanceKernel;
+use crate::orchestrator::Orchestrator;
+use crate::types::Plan;
+use futures::future::BoxFuture;
+use rtfs::ast::MapKey;
+use rtfs::runtime::error::{RuntimeError, RuntimeResult};
+use rtfs::runtime::security::RuntimeContext;
+use rtfs::runtime::values::Value;
+use std::sync::{Arc, Weak};
+
+pub const PLAN_INVOKE_CAPABILITY: &str = "ccos.plan.invoke";
+
+/// Default maximum nesting of `ccos.plan.invoke` calls
+pub const DEFAULT_MAX_PLAN_INVOKE_DEPTH: usize = 8;
+
+/// Plan parameter holding how many `ccos.plan.invoke` calls the running plan is nested in
+pub const PLAN_INVOKE_DEPTH_PARAM: &str = "plan-invoke-depth";
+
+/// Runs the child plans of `ccos.plan.invoke` calls
+pub struct PlanInvoker {
+    /// Weak to avoid an orchestrator -> invoker -> kernel -> orchestrator cycle
+    governance_kernel: Weak<GovernanceKernel>,
+    orchestrator: Weak<Orchestrator>,
+    max_depth: usize,
+}
+
+impl PlanInvoker {
+    /// Run the child plan described by `inputs` for a plan running under `caller`.
+    /// Boxed because the child plan may itself invoke plans.
+    pub fn invoke<'a>(
+        &'a self,
+        inputs: &'a Value,
+        caller: &'a RuntimeContext,
+    ) -> BoxFuture<'a, RuntimeResult<Value>> {
+        Box::pin(async move {
+            let unavailable = || {
+                RuntimeError::Generic(format!(
+                    "{}: governance kernel is no longer available",
+                    PLAN_INVOKE_CAPABILITY
+                ))
+            };
+            let governance_kernel = self.governance_kernel.upgrade().ok_or_else(unavailable)?;
+            let orchestrator = self.orchestrator.upgrade().ok_or_else(unavailable)?;
+
+            let depth = match caller.cross_plan_params.get(PLAN_INVOKE_DEPTH_PARAM) {
+                Some(Value::Integer(depth)) => (*depth).max(0) as usize,
+                _ => 0,
+            };
+            if depth >= self.max_depth {
+                return Err(RuntimeError::Generic(format!(
+                    "{}: maximum plan nesting depth {} exceeded",
+                    PLAN_INVOKE_CAPABILITY, self.max_depth
+                )));
+            }
+
+            let plan_id = input_field(inputs, "plan-id").and_then(|v| v.as_string());
+            let rtfs = input_field(inputs, "rtfs").and_then(|v| v.as_string());
+            let plan = match (plan_id, rtfs) {
+                (Some(plan_id), None) => {
+                    orchestrator.get_plan_by_id(plan_id)?.ok_or_else(|| {
+                        RuntimeError::Generic(format!(
+                            "{}: plan '{}' not found",
+                            PLAN_INVOKE_CAPABILITY, plan_id
+                        ))
+                    })?
+                }
+                (None, Some(rtfs)) => Plan::new_rtfs(rtfs.to_string(), vec![]),
+                _ => {
+                    return Err(RuntimeError::Generic(format!(
+                        "{}: expected exactly one of :plan-id or :rtfs",
+                        PLAN_INVOKE_CAPABILITY
+                    )))
+                }
+            };
+
+            // Same permissions as the caller, but none of its plan variables
+            let mut context = caller.clone();
+            context.cross_plan_params.clear();
+            match input_field(inputs, "args") {
+                None | Some(Value::Nil) => {}
+                Some(Value::Map(args)) => {
+                    for (key, value) in args {
+                        context
+                            .cross_plan_params
+                            .insert(map_key_name(key), value.clone());
+                    }
+                }
+                Some(other) => {
+                    return Err(RuntimeError::Generic(format!(
+                        "{}: :args must be a map, got {}",
+                        PLAN_INVOKE_CAPABILITY,
+                        other.type_name()
+                    )))
+                }
+            }
+            context.cross_plan_params.insert(
+                PLAN_INVOKE_DEPTH_PARAM.to_string(),
+                Value::Integer(depth as i64 + 1),
+            );
+
+            let plan_id = plan.plan_id.clone();
+            let result = governance_kernel
+                .validate_and_execute(plan, &context)
+                .await?;
+            if !result.success {
+                let error = result
+                    .metadata
+                    .get("error")
+                    .and_then(|v| v.as_string())
+                    .unwrap_or("child plan failed");
+                return Err(RuntimeError::Generic(format!(
+                    "{}: plan '{}' failed: {}",
+                    PLAN_INVOKE_CAPABILITY, plan_id, error
+                )));
+            }
+            Ok(result.value)
+        })
+    }
+}
+
+/// Register `ccos.plan.invoke`. Calls made by plans running on `orchestrator`
+/// run their child plan through `governance_kernel`, nested at most
+/// `max_depth` deep; calls from outside a plan are rejected.
+pub async fn register_plan_ops_capabilities(
+    marketplace: Arc<CapabilityMarketplace>,
+    orchestrator: &Arc<Orchestrator>,
+    governance_kernel: &Arc<GovernanceKernel>,
+    max_depth: usize,
+) -> RuntimeResult<()> {
+    orchestrator.set_plan_invoker(Arc::new(PlanInvoker {
+        governance_kernel: Arc::downgrade(governance_kernel),
+        orchestrator: Arc::downgrade(orchestrator),
+        max_depth,
+    }));
+
+    let handler: Arc<dyn Fn(&Value) -> BoxFuture<'static, RuntimeResult<Value>> + Send + Sync> =
+        Arc::new(|_inputs: &Value| {
+            Box::pin(async {
+                Err(RuntimeError::Generic(format!(
+                    "{}: can only be called from a running plan",
+                    PLAN_INVOKE_CAPABILITY
+                )))
+            }) as BoxFuture<'static, RuntimeResult<Value>>
+        });
+
+    marketplace
+        .register_native_capability(
+            PLAN_INVOKE_CAPABILITY.to_string(),
+            "Invoke Plan".to_string(),
+            "Run another RTFS plan (by :plan-id or inline :rtfs) with :args and return its result"
+                .to_string(),
+            handler,
+            "default".to_string(),
+        )
+        .await
+        .map_err(|e| {
+            RuntimeError::Generic(format!(
+                "Failed to register {}: {}",
+                PLAN_INVOKE_CAPABILITY, e
+            ))
+        })
+}
+
+/// Look up `name` in a keyword- or string-keyed input map
+fn input_field<'a>(inputs: &'a Value, name: &str) -> Option<&'a Value> {
+    let Value::Map(map) = inputs else {
+        return None;
+    };
+    map.get(&MapKey::Keyword(rtfs::ast::Keyword(name.into())))
+        .or_else(|| map.get(&MapKey::String(name.to_string())))
+}
+
+fn map_key_name(key: &MapKey) -> String {
+    match key {
+        MapKey::Keyword(k) => k.0.to_string(),
+        MapKey::String(s) => s.clone(),
+        MapKey::Integer(i) => i.to_string(),
+    }
+}
@@ -151,8 +151,8 @@ pub trait ValidatedCapabilityProvider: CapabilityProvider {
             .list_capabilities()
             .into_iter()
             .find(|c| c.id == capability_id)
-            .ok_or_else(|| {
-                RuntimeError::Generic(format!("Capability not found: {}", capability_id))
+            .ok_or_else(|| RuntimeError::CapabilityNotFound {
+                capability_id: capability_id.to_string(),
             })?;
         capability
             .validate_inputs(inputs)
@@ -160,7 +160,7 @@ pub trait ValidatedCapabilityProvider: CapabilityProvider {
         let inputs_value = if inputs.len() == 1 {
             inputs[0].clone()
         } else {
-            Value::Vector(inputs.to_vec())
+            Value::Vector(inputs.to_vec().into())
         };
         let result = self.execute_capability(capability_id, &inputs_value, context)?;
         capability
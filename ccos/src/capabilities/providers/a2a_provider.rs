@@ -203,7 +203,7 @@ impl A2AProvider {
                 for item in arr {
                     result.push(Self::json_to_value(item)?);
                 }
-                Ok(Value::Vector(result))
+                Ok(Value::Vector(result.into()))
             }
             serde_json::Value::Object(map) => {
                 let mut result = std::collections::HashMap::new();
@@ -213,7 +213,7 @@ impl A2AProvider {
                         Self::json_to_value(v)?,
                     );
                 }
-                Ok(Value::Map(result))
+                Ok(Value::Map(result.into()))
             }
         }
     }
@@ -248,7 +248,7 @@ impl A2AProvider {
                 Ok(serde_json::Value::Object(obj))
             }
             Value::Keyword(k) => Ok(serde_json::Value::String(format!(":{}", k.0))),
-            Value::Symbol(s) => Ok(serde_json::Value::String(s.0.clone())),
+            Value::Symbol(s) => Ok(serde_json::Value::String(s.0.to_string())),
             _ => Err(RuntimeError::Generic(format!(
                 "Cannot serialize {} to JSON for A2A communication",
                 value.type_name()
@@ -411,23 +411,22 @@ impl CapabilityProvider for A2AProvider {
         inputs: &Value,
         _context: &ExecutionContext,
     ) -> RuntimeResult<Value> {
-        let args = match inputs {
-            Value::Vector(vec) => vec,
-            Value::List(list) => list,
-            single => std::slice::from_ref(single),
+        let args: Vec<Value> = match inputs {
+            Value::Vector(items) | Value::List(items) => items.iter().cloned().collect(),
+            single => vec![single.clone()],
         };
 
         match capability_id {
-            "ccos.a2a.send" => Self::send_a2a_message(args),
-            "ccos.a2a.query" => Self::query_agent(args),
+            "ccos.a2a.send" => Self::send_a2a_message(&args),
+            "ccos.a2a.query" => Self::query_agent(&args),
             "ccos.a2a.discover" => {
                 // Return mock agent list for now
                 let mut agents_map = std::collections::HashMap::new();
                 agents_map.insert(
                     rtfs::ast::MapKey::String("agents".to_string()),
-                    Value::Vector(vec![]),
+                    Value::Vector(vec![].into()),
                 );
-                Ok(Value::Map(agents_map))
+                Ok(Value::Map(agents_map.into()))
             }
             other => Err(RuntimeError::Generic(format!(
                 "A2AProvider does not support capability {}",
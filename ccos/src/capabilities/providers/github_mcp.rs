@@ -548,7 +548,7 @@ impl CapabilityProvider for GitHubMCPCapability {
                 for (k, v) in map {
                     let key = match k {
                         MapKey::String(s) => s.clone(),
-                        MapKey::Keyword(s) => s.0.clone(),
+                        MapKey::Keyword(s) => s.0.to_string(),
                         MapKey::Integer(i) => i.to_string(),
                     };
                     json_map.insert(key, self.runtime_value_to_json(v)?);
@@ -622,8 +622,8 @@ impl GitHubMCPCapability {
             RuntimeValue::Timestamp(t) => Ok(Value::String(t.clone())),
             RuntimeValue::Uuid(u) => Ok(Value::String(u.clone())),
             RuntimeValue::ResourceHandle(r) => Ok(Value::String(r.clone())),
-            RuntimeValue::Symbol(s) => Ok(Value::String(s.0.clone())),
-            RuntimeValue::Keyword(k) => Ok(Value::String(k.0.clone())),
+            RuntimeValue::Symbol(s) => Ok(Value::String(s.0.to_string())),
+            RuntimeValue::Keyword(k) => Ok(Value::String(k.0.to_string())),
             RuntimeValue::Vector(v) => {
                 let mut json_vec = Vec::new();
                 for item in v {
@@ -643,14 +643,14 @@ impl GitHubMCPCapability {
                 for (k, v) in m {
                     let key = match k {
                         MapKey::String(s) => s.clone(),
-                        MapKey::Keyword(s) => s.0.clone(),
+                        MapKey::Keyword(s) => s.0.to_string(),
                         MapKey::Integer(i) => i.to_string(),
                     };
                     json_map.insert(key, self.runtime_value_to_json(v)?);
                 }
                 Ok(Value::Object(json_map))
             }
-            RuntimeValue::Function(_) => Ok(Value::String("#<function>".to_string())),
+            RuntimeValue::Function(f) => Ok(Value::String(f.to_string())),
             RuntimeValue::FunctionPlaceholder(_) => {
                 Ok(Value::String("#<function-placeholder>".to_string()))
             }
@@ -677,14 +677,14 @@ impl GitHubMCPCapability {
                 for item in a {
                     runtime_vec.push(self.json_to_runtime_value(item)?);
                 }
-                Ok(RuntimeValue::Vector(runtime_vec))
+                Ok(RuntimeValue::Vector(runtime_vec.into()))
             }
             Value::Object(o) => {
                 let mut runtime_map = HashMap::new();
                 for (k, v) in o {
                     runtime_map.insert(MapKey::String(k.clone()), self.json_to_runtime_value(v)?);
                 }
-                Ok(RuntimeValue::Map(runtime_map))
+                Ok(RuntimeValue::Map(runtime_map.into()))
             }
             Value::Null => Ok(RuntimeValue::Nil),
         }
@@ -694,6 +694,7 @@ impl GitHubMCPCapability {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rtfs::runtime::values::ValueMap;
 
     #[tokio::test]
     async fn test_github_mcp_capability_creation() {
@@ -741,7 +742,7 @@ mod tests {
 
         // Test RTFS to JSON conversion
         let rtfs_value = RuntimeValue::Map({
-            let mut map = HashMap::new();
+            let mut map = ValueMap::new();
             map.insert(
                 MapKey::String("test".to_string()),
                 RuntimeValue::String("value".to_string()),
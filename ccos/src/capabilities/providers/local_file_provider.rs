@@ -59,7 +59,7 @@ impl LocalFileProvider {
                 // Try "path" or "key" (for KV-like semantics if used here)
                 for key_name in &["path", "key", "key-path"] {
                     if let Some(val) = map.get(&MapKey::String(key_name.to_string()))
-                        .or_else(|| map.get(&MapKey::Keyword(rtfs::ast::Keyword(key_name.to_string())))) 
+                        .or_else(|| map.get(&MapKey::Keyword(rtfs::ast::Keyword::new(key_name)))) 
                     {
                         if let Some(s) = val.as_string() {
                             return Ok(s.to_string());
@@ -69,7 +69,7 @@ impl LocalFileProvider {
                 
                 // Fallback to "args" if it exists (legacy wrapping)
                 if let Some(args_val) = map.get(&MapKey::String("args".to_string()))
-                    .or_else(|| map.get(&MapKey::Keyword(rtfs::ast::Keyword("args".to_string()))))
+                    .or_else(|| map.get(&MapKey::Keyword(rtfs::ast::Keyword("args".into()))))
                 {
                     return Self::extract_path(args_val);
                 }
@@ -107,7 +107,7 @@ impl LocalFileProvider {
         match input {
             Value::Map(map) => {
                 if let Some(val) = map.get(&MapKey::String(key.to_string()))
-                    .or_else(|| map.get(&MapKey::Keyword(rtfs::ast::Keyword(key.to_string()))))
+                    .or_else(|| map.get(&MapKey::Keyword(rtfs::ast::Keyword(key.into()))))
                 {
                     if let Value::Boolean(b) = val {
                         return *b;
@@ -133,7 +133,7 @@ impl LocalFileProvider {
                 // Try "content", "value", or "data"
                 for key_name in &["content", "value", "data"] {
                     if let Some(val) = map.get(&MapKey::String(key_name.to_string()))
-                        .or_else(|| map.get(&MapKey::Keyword(rtfs::ast::Keyword(key_name.to_string())))) 
+                        .or_else(|| map.get(&MapKey::Keyword(rtfs::ast::Keyword::new(key_name)))) 
                     {
                         if let Some(s) = val.as_string() {
                             return Ok(s.to_string());
@@ -143,7 +143,7 @@ impl LocalFileProvider {
                 
                 // Fallback to "args"
                 if let Some(args_val) = map.get(&MapKey::String("args".to_string()))
-                    .or_else(|| map.get(&MapKey::Keyword(rtfs::ast::Keyword("args".to_string()))))
+                    .or_else(|| map.get(&MapKey::Keyword(rtfs::ast::Keyword("args".into()))))
                 {
                     return Self::extract_content(args_val);
                 }
@@ -180,28 +180,28 @@ impl LocalFileProvider {
         for entry in entries {
             let mut map = std::collections::HashMap::new();
             map.insert(
-                MapKey::Keyword(rtfs::ast::Keyword("name".to_string())),
+                MapKey::Keyword(rtfs::ast::Keyword("name".into())),
                 Value::String(entry.name),
             );
             map.insert(
-                MapKey::Keyword(rtfs::ast::Keyword("path".to_string())),
+                MapKey::Keyword(rtfs::ast::Keyword("path".into())),
                 Value::String(entry.path),
             );
             map.insert(
-                MapKey::Keyword(rtfs::ast::Keyword("is_dir".to_string())),
+                MapKey::Keyword(rtfs::ast::Keyword("is_dir".into())),
                 Value::Boolean(entry.is_dir),
             );
             map.insert(
-                MapKey::Keyword(rtfs::ast::Keyword("is_file".to_string())),
+                MapKey::Keyword(rtfs::ast::Keyword("is_file".into())),
                 Value::Boolean(entry.is_file),
             );
             map.insert(
-                MapKey::Keyword(rtfs::ast::Keyword("size".to_string())),
+                MapKey::Keyword(rtfs::ast::Keyword("size".into())),
                 Value::Integer(entry.size as i64),
             );
-            result.push(Value::Map(map));
+            result.push(Value::Map(map.into()));
         }
-        Ok(Value::Vector(result))
+        Ok(Value::Vector(result.into()))
     }
 
     fn read_file(input: &Value) -> RuntimeResult<Value> {
@@ -225,10 +225,10 @@ impl LocalFileProvider {
         result_map.insert(MapKey::String("ok".to_string()), Value::Boolean(true));
         result_map.insert(
             MapKey::String("usage".to_string()),
-            Value::Map(usage_map),
+            Value::Map(usage_map.into()),
         );
 
-        Ok(Value::Map(result_map))
+        Ok(Value::Map(result_map.into()))
     }
 
     fn delete(input: &Value) -> RuntimeResult<Value> {
@@ -280,10 +280,10 @@ impl LocalFileProvider {
         result_map.insert(MapKey::String("ok".to_string()), Value::Boolean(true));
         result_map.insert(
             MapKey::String("usage".to_string()),
-            Value::Map(usage_map),
+            Value::Map(usage_map.into()),
         );
 
-        Ok(Value::Map(result_map))
+        Ok(Value::Map(result_map.into()))
     }
 }
 
@@ -7,6 +7,7 @@ use crate::capabilities::provider::{
     ProviderConfig, ProviderMetadata, ResourceLimits, SecurityRequirements,
 };
 use rtfs::ast::{MapKey, PrimitiveType, TypeExpr};
+use rtfs::runtime::values::ValueMap;
 use rtfs::runtime::{RuntimeError, RuntimeResult, Value};
 
 #[derive(Debug, Default)]
@@ -78,7 +79,7 @@ impl CapabilityProvider for LocalLlmProvider {
     ) -> RuntimeResult<Value> {
         match capability_id {
             "com.local-llm:v1.synthesize" => {
-                let mut result_map: HashMap<MapKey, Value> = HashMap::new();
+                let mut result_map: ValueMap = ValueMap::new();
                 result_map.insert(
                     MapKey::String("analysis-document".to_string()),
                     Value::String("This is a synthesized analysis.".to_string()),
@@ -92,10 +93,9 @@ impl CapabilityProvider for LocalLlmProvider {
             "com.local-llm:v1.draft-document" => {
                 Ok(Value::String("This is a draft press release.".to_string()))
             }
-            other => Err(RuntimeError::Generic(format!(
-                "Unknown capability: {}",
-                other
-            ))),
+            other => Err(RuntimeError::CapabilityNotFound {
+                capability_id: other.to_string(),
+            }),
         }
     }
 
@@ -179,7 +179,7 @@ impl RemoteRTFSProvider {
                 for item in arr {
                     result.push(Self::json_to_value(item)?);
                 }
-                Ok(Value::Vector(result))
+                Ok(Value::Vector(result.into()))
             }
             serde_json::Value::Object(map) => {
                 let mut result = std::collections::HashMap::new();
@@ -189,7 +189,7 @@ impl RemoteRTFSProvider {
                         Self::json_to_value(v)?,
                     );
                 }
-                Ok(Value::Map(result))
+                Ok(Value::Map(result.into()))
             }
         }
     }
@@ -224,7 +224,7 @@ impl RemoteRTFSProvider {
                 Ok(serde_json::Value::Object(obj))
             }
             Value::Keyword(k) => Ok(serde_json::Value::String(format!(":{}", k.0))),
-            Value::Symbol(s) => Ok(serde_json::Value::String(s.0.clone())),
+            Value::Symbol(s) => Ok(serde_json::Value::String(s.0.to_string())),
             _ => Err(RuntimeError::Generic(format!(
                 "Cannot serialize {} to JSON for remote execution",
                 value.type_name()
@@ -335,14 +335,13 @@ impl CapabilityProvider for RemoteRTFSProvider {
         inputs: &Value,
         _context: &ExecutionContext,
     ) -> RuntimeResult<Value> {
-        let args = match inputs {
-            Value::Vector(vec) => vec,
-            Value::List(list) => list,
-            single => std::slice::from_ref(single),
+        let args: Vec<Value> = match inputs {
+            Value::Vector(items) | Value::List(items) => items.iter().cloned().collect(),
+            single => vec![single.clone()],
         };
 
         match capability_id {
-            "ccos.remote.execute" => Self::execute_rtfs_remote(args),
+            "ccos.remote.execute" => Self::execute_rtfs_remote(&args),
             "ccos.remote.ping" => {
                 if args.len() != 1 {
                     return Err(RuntimeError::ArityMismatch {
@@ -436,7 +435,7 @@ mod tests {
             Value::Integer(42),
         );
 
-        let value = Value::Map(map);
+        let value = Value::Map(map.into());
         let json = RemoteRTFSProvider::value_to_json(&value).unwrap();
 
         assert!(json.is_object());
@@ -205,7 +205,7 @@ impl WeatherMCPCapability {
         context: &RuntimeContext,
     ) -> RuntimeResult<Value> {
         // Validate security context - check if weather queries are allowed
-        if !context.allowed_capabilities.contains("weather.query") {
+        if !context.is_capability_allowed("weather.query") {
             return Err(RuntimeError::SecurityViolation {
                 operation: "weather query".to_string(),
                 capability: tool_name.to_string(),
@@ -216,10 +216,9 @@ impl WeatherMCPCapability {
         match tool_name {
             "get_current_weather" => self.get_current_weather(arguments).await,
             "get_weather_forecast" => self.get_weather_forecast(arguments).await,
-            _ => Err(RuntimeError::UnknownCapability(format!(
-                "Unknown weather tool: {}",
-                tool_name
-            ))),
+            _ => Err(RuntimeError::CapabilityNotFound {
+                capability_id: format!("weather_mcp.{}", tool_name),
+            }),
         }
     }
 
@@ -389,10 +388,9 @@ impl CapabilityProvider for WeatherMCPCapability {
     ) -> RuntimeResult<RuntimeValue> {
         // Extract tool name from capability ID
         let tool_name = capability_id.strip_prefix("weather_mcp.").ok_or_else(|| {
-            RuntimeError::UnknownCapability(format!(
-                "Invalid weather MCP capability ID: {}",
-                capability_id
-            ))
+            RuntimeError::CapabilityNotFound {
+                capability_id: capability_id.to_string(),
+            }
         })?;
 
         // Convert RuntimeValue to serde_json::Value for MCP processing
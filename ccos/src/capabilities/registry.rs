@@ -83,10 +83,10 @@ impl LocalProvider {
             );
             response_map.insert(
                 MapKey::String("headers".to_string()),
-                Value::Map(headers_map),
+                Value::Map(headers_map.into()),
             );
 
-            return Ok(Value::Map(response_map));
+            return Ok(Value::Map(response_map.into()));
         }
 
         // For real HTTP requests, delegate to the registry's implementation
@@ -269,7 +269,7 @@ impl LocalProvider {
 
         let key = match &args[0] {
             Value::String(s) => s.clone(),
-            Value::Keyword(k) => k.0.clone(),
+            Value::Keyword(k) => k.0.to_string(),
             _ => {
                 return Err(RuntimeError::TypeError {
                     expected: "string or keyword".to_string(),
@@ -288,7 +288,7 @@ impl LocalProvider {
         if args.len() == 1 {
             if let Some(Value::Map(map)) = args.first() {
                 let key = map
-                    .get(&MapKey::Keyword(rtfs::ast::Keyword("key".to_string())))
+                    .get(&MapKey::Keyword(rtfs::ast::Keyword("key".into())))
                     .or_else(|| map.get(&MapKey::String("key".to_string())))
                     .and_then(|v| v.as_string())
                     .ok_or_else(|| {
@@ -310,7 +310,7 @@ impl LocalProvider {
 
         let key = match &args[0] {
             Value::String(s) => s.clone(),
-            Value::Keyword(k) => k.0.clone(),
+            Value::Keyword(k) => k.0.to_string(),
             _ => {
                 return Err(RuntimeError::TypeError {
                     expected: "string or keyword".to_string(),
@@ -335,7 +335,7 @@ impl LocalProvider {
 
         let key = match &args[0] {
             Value::String(s) => s.clone(),
-            Value::Keyword(k) => k.0.clone(),
+            Value::Keyword(k) => k.0.to_string(),
             _ => {
                 return Err(RuntimeError::TypeError {
                     expected: "string or keyword".to_string(),
@@ -360,7 +360,7 @@ impl LocalProvider {
 
         let key = match &args[0] {
             Value::String(s) => s.clone(),
-            Value::Keyword(k) => k.0.clone(),
+            Value::Keyword(k) => k.0.to_string(),
             _ => {
                 return Err(RuntimeError::TypeError {
                     expected: "string or keyword".to_string(),
@@ -400,7 +400,7 @@ impl LocalProvider {
 
         let key = match &args[0] {
             Value::String(s) => s.clone(),
-            Value::Keyword(k) => k.0.clone(),
+            Value::Keyword(k) => k.0.to_string(),
             _ => {
                 return Err(RuntimeError::TypeError {
                     expected: "string or keyword".to_string(),
@@ -481,18 +481,18 @@ impl CapabilityProvider for LocalProvider {
         _context: &crate::capabilities::provider::ExecutionContext,
     ) -> RuntimeResult<Value> {
         // Extract args from inputs
-        let args = match inputs {
-            Value::Vector(vec) => vec.clone(),
-            Value::List(list) => list.clone(),
+        let args: Vec<Value> = match inputs {
+            Value::Vector(vec) => vec.iter().cloned().collect(),
+            Value::List(list) => list.iter().cloned().collect(),
             Value::Map(map) => {
                 // If it's a map, check if it's the new calling convention with :args
                 if let Some(args_val) = map
-                    .get(&MapKey::Keyword(rtfs::ast::Keyword("args".to_string())))
+                    .get(&MapKey::Keyword(rtfs::ast::Keyword("args".into())))
                     .or_else(|| map.get(&MapKey::String("args".to_string())))
                 {
                     match args_val {
-                        Value::Vector(vec) => vec.clone(),
-                        Value::List(list) => list.clone(),
+                        Value::Vector(vec) => vec.iter().cloned().collect(),
+                        Value::List(list) => list.iter().cloned().collect(),
                         other => vec![other.clone()],
                     }
                 } else {
@@ -892,12 +892,12 @@ impl CapabilityRegistry {
                 Some(TypeExpr::Map {
                     entries: vec![
                         MapTypeEntry {
-                            key: Keyword("path".to_string()),
+                            key: Keyword("path".into()),
                             value_type: Box::new(TypeExpr::Primitive(PrimitiveType::String)),
                             optional: false,
                         },
                         MapTypeEntry {
-                            key: Keyword("content".to_string()),
+                            key: Keyword("content".into()),
                             value_type: Box::new(TypeExpr::Primitive(PrimitiveType::String)),
                             optional: false,
                         },
@@ -940,12 +940,12 @@ impl CapabilityRegistry {
                 Some(TypeExpr::Map {
                     entries: vec![
                         MapTypeEntry {
-                            key: Keyword("path".to_string()),
+                            key: Keyword("path".into()),
                             value_type: Box::new(TypeExpr::Primitive(PrimitiveType::String)),
                             optional: false,
                         },
                         MapTypeEntry {
-                            key: Keyword("content".to_string()),
+                            key: Keyword("content".into()),
                             value_type: Box::new(TypeExpr::Primitive(PrimitiveType::String)),
                             optional: false,
                         },
@@ -988,12 +988,12 @@ impl CapabilityRegistry {
                 Some(TypeExpr::Map {
                     entries: vec![
                         MapTypeEntry {
-                            key: Keyword("path".to_string()),
+                            key: Keyword("path".into()),
                             value_type: Box::new(TypeExpr::Primitive(PrimitiveType::String)),
                             optional: false,
                         },
                         MapTypeEntry {
-                            key: Keyword("mode".to_string()),
+                            key: Keyword("mode".into()),
                             value_type: Box::new(TypeExpr::Primitive(PrimitiveType::String)),
                             optional: true,
                         },
@@ -1037,12 +1037,12 @@ impl CapabilityRegistry {
                 Some(TypeExpr::Map {
                     entries: vec![
                         MapTypeEntry {
-                            key: Keyword("handle".to_string()),
+                            key: Keyword("handle".into()),
                             value_type: Box::new(TypeExpr::Primitive(PrimitiveType::Int)),
                             optional: false,
                         },
                         MapTypeEntry {
-                            key: Keyword("line".to_string()),
+                            key: Keyword("line".into()),
                             value_type: Box::new(TypeExpr::Primitive(PrimitiveType::String)),
                             optional: false,
                         },
@@ -1085,12 +1085,12 @@ impl CapabilityRegistry {
                 Some(TypeExpr::Map {
                     entries: vec![
                         MapTypeEntry {
-                            key: Keyword("path".to_string()),
+                            key: Keyword("path".into()),
                             value_type: Box::new(TypeExpr::Primitive(PrimitiveType::String)),
                             optional: false,
                         },
                         MapTypeEntry {
-                            key: Keyword("recursive".to_string()),
+                            key: Keyword("recursive".into()),
                             value_type: Box::new(TypeExpr::Primitive(PrimitiveType::Bool)),
                             optional: true,
                         },
@@ -1191,17 +1191,17 @@ impl CapabilityRegistry {
                 Some(TypeExpr::Map {
                     entries: vec![
                         rtfs::ast::MapTypeEntry {
-                            key: rtfs::ast::Keyword("url".to_string()),
+                            key: rtfs::ast::Keyword("url".into()),
                             value_type: Box::new(TypeExpr::Primitive(rtfs::ast::PrimitiveType::String)),
                             optional: false,
                         },
                         rtfs::ast::MapTypeEntry {
-                            key: rtfs::ast::Keyword("method".to_string()),
+                            key: rtfs::ast::Keyword("method".into()),
                             value_type: Box::new(TypeExpr::Primitive(rtfs::ast::PrimitiveType::String)),
                             optional: false,
                         },
                         rtfs::ast::MapTypeEntry {
-                            key: rtfs::ast::Keyword("headers".to_string()),
+                            key: rtfs::ast::Keyword("headers".into()),
                             value_type: Box::new(TypeExpr::Map {
                                 entries: vec![],
                                 wildcard: Some(Box::new(TypeExpr::Primitive(rtfs::ast::PrimitiveType::String))),
@@ -1209,7 +1209,7 @@ impl CapabilityRegistry {
                             optional: true,
                         },
                         rtfs::ast::MapTypeEntry {
-                            key: rtfs::ast::Keyword("body".to_string()),
+                            key: rtfs::ast::Keyword("body".into()),
                             value_type: Box::new(TypeExpr::Primitive(rtfs::ast::PrimitiveType::String)),
                             optional: true,
                         },
@@ -1219,12 +1219,12 @@ impl CapabilityRegistry {
                 Some(TypeExpr::Map {
                     entries: vec![
                         rtfs::ast::MapTypeEntry {
-                            key: rtfs::ast::Keyword("status".to_string()),
+                            key: rtfs::ast::Keyword("status".into()),
                             value_type: Box::new(TypeExpr::Primitive(rtfs::ast::PrimitiveType::Int)),
                             optional: false,
                         },
                         rtfs::ast::MapTypeEntry {
-                            key: rtfs::ast::Keyword("body".to_string()),
+                            key: rtfs::ast::Keyword("body".into()),
                             value_type: Box::new(TypeExpr::Primitive(rtfs::ast::PrimitiveType::String)),
                             optional: false,
                         },
@@ -1387,12 +1387,12 @@ impl CapabilityRegistry {
                 Some(TypeExpr::Map {
                     entries: vec![
                         MapTypeEntry {
-                            key: Keyword("key".to_string()),
+                            key: Keyword("key".into()),
                             value_type: Box::new(TypeExpr::Primitive(PrimitiveType::String)),
                             optional: false,
                         },
                         MapTypeEntry {
-                            key: Keyword("value".to_string()),
+                            key: Keyword("value".into()),
                             value_type: Box::new(TypeExpr::Primitive(PrimitiveType::String)),
                             optional: false,
                         },
@@ -1422,17 +1422,17 @@ impl CapabilityRegistry {
                 Some(TypeExpr::Map {
                     entries: vec![
                         MapTypeEntry {
-                            key: Keyword("key".to_string()),
+                            key: Keyword("key".into()),
                             value_type: Box::new(TypeExpr::Primitive(PrimitiveType::String)),
                             optional: false,
                         },
                         MapTypeEntry {
-                            key: Keyword("expected".to_string()),
+                            key: Keyword("expected".into()),
                             value_type: Box::new(TypeExpr::Primitive(PrimitiveType::String)),
                             optional: false,
                         },
                         MapTypeEntry {
-                            key: Keyword("new".to_string()),
+                            key: Keyword("new".into()),
                             value_type: Box::new(TypeExpr::Primitive(PrimitiveType::String)),
                             optional: false,
                         },
@@ -1460,12 +1460,12 @@ impl CapabilityRegistry {
                 Some(TypeExpr::Map {
                     entries: vec![
                         MapTypeEntry {
-                            key: Keyword("key".to_string()),
+                            key: Keyword("key".into()),
                             value_type: Box::new(TypeExpr::Primitive(PrimitiveType::String)),
                             optional: false,
                         },
                         MapTypeEntry {
-                            key: Keyword("increment".to_string()),
+                            key: Keyword("increment".into()),
                             value_type: Box::new(TypeExpr::Primitive(PrimitiveType::Int)),
                             optional: true, // Default 1
                         },
@@ -1493,12 +1493,12 @@ impl CapabilityRegistry {
                 Some(TypeExpr::Map {
                     entries: vec![
                         MapTypeEntry {
-                            key: Keyword("key".to_string()),
+                            key: Keyword("key".into()),
                             value_type: Box::new(TypeExpr::Primitive(PrimitiveType::String)),
                             optional: false,
                         },
                         MapTypeEntry {
-                            key: Keyword("data".to_string()),
+                            key: Keyword("data".into()),
                             value_type: Box::new(TypeExpr::Primitive(PrimitiveType::String)),
                             optional: false,
                         },
@@ -1680,10 +1680,10 @@ impl CapabilityRegistry {
                 );
                 response_map.insert(
                     MapKey::String("headers".to_string()),
-                    Value::Map(headers_map),
+                    Value::Map(headers_map.into()),
                 );
 
-                return Ok(Value::Map(response_map));
+                return Ok(Value::Map(response_map.into()));
             }
 
             return self.execute_http_fetch(&args, runtime_context);
@@ -1766,7 +1766,7 @@ impl CapabilityRegistry {
                     for (k, vv) in m.iter() {
                         out.insert(k.clone(), sanitize_value(vv));
                     }
-                    Value::Map(out)
+                    Value::Map(out.into())
                 }
                 Value::Vector(vec) => Value::Vector(vec.iter().map(sanitize_value).collect()),
                 Value::List(list) => Value::List(list.iter().map(sanitize_value).collect()),
@@ -1853,7 +1853,7 @@ impl CapabilityRegistry {
                 trace_id: uuid::Uuid::new_v4().to_string(),
                 timeout: std::time::Duration::from_secs(10),
             };
-            provider.execute_capability(capability_id, &Value::Vector(args), &context)
+            provider.execute_capability(capability_id, &Value::Vector(args.into()), &context)
         } else {
             // Runtime trap: Handle missing capability through resolver if available
             if let Some(ref resolver) = self.missing_capability_resolver {
@@ -1916,25 +1916,25 @@ impl CapabilityRegistry {
     #[allow(dead_code)]
     fn discover_agents_capability(_args: Vec<Value>) -> RuntimeResult<Value> {
         // TODO: Implement with proper capability marketplace integration
-        Ok(Value::Vector(vec![]))
+        Ok(Value::Vector(vec![].into()))
     }
 
     #[allow(dead_code)]
     fn task_coordination_capability(_args: Vec<Value>) -> RuntimeResult<Value> {
         // TODO: Implement with proper CCOS task coordination
-        Ok(Value::Map(std::collections::HashMap::new()))
+        Ok(Value::Map(std::collections::HashMap::new().into()))
     }
 
     #[allow(dead_code)]
     fn discover_and_assess_agents_capability(_args: Vec<Value>) -> RuntimeResult<Value> {
         // TODO: Implement with proper agent discovery system
-        Ok(Value::Vector(vec![]))
+        Ok(Value::Vector(vec![].into()))
     }
 
     #[allow(dead_code)]
     fn establish_system_baseline_capability(_args: Vec<Value>) -> RuntimeResult<Value> {
         // TODO: Implement with proper system baseline establishment
-        Ok(Value::Map(std::collections::HashMap::new()))
+        Ok(Value::Map(std::collections::HashMap::new().into()))
     }
 }
 
@@ -2104,7 +2104,7 @@ impl CapabilityRegistry {
 
         response_map.insert(
             MapKey::String("headers".to_string()),
-            Value::Map(headers_map),
+            Value::Map(headers_map.into()),
         );
 
         let mut usage_map = HashMap::new();
@@ -2118,10 +2118,10 @@ impl CapabilityRegistry {
         );
         response_map.insert(
             MapKey::String("usage".to_string()),
-            Value::Map(usage_map),
+            Value::Map(usage_map.into()),
         );
 
-        Ok(Value::Map(response_map))
+        Ok(Value::Map(response_map.into()))
     }
 
     fn execute_http_fetch_via_proxy(
@@ -2205,9 +2205,9 @@ impl CapabilityRegistry {
         for (k, v) in response.headers.iter() {
             headers_map.insert(MapKey::String(k.to_string()), Value::String(v.to_string()));
         }
-        response_map.insert(MapKey::String("headers".to_string()), Value::Map(headers_map));
+        response_map.insert(MapKey::String("headers".to_string()), Value::Map(headers_map.into()));
 
-        Ok(Value::Map(response_map))
+        Ok(Value::Map(response_map.into()))
     }
 
     fn parse_http_request(&self, args: &[Value]) -> RuntimeResult<HttpRequestConfig> {
@@ -2294,7 +2294,7 @@ impl CapabilityRegistry {
         while let (Some(key), Some(value)) = (iter.next(), iter.next()) {
             let key_string = match key {
                 Value::Keyword(k) => k.0.clone(),
-                Value::String(s) => s.clone(),
+                Value::String(s) => s.as_str().into(),
                 other => {
                     return Err(RuntimeError::TypeError {
                         expected: "keyword or string".to_string(),
@@ -2345,7 +2345,7 @@ impl CapabilityRegistry {
 fn extract_plain_string(value: &Value, field: &str) -> RuntimeResult<String> {
     match value {
         Value::String(s) => Ok(s.clone()),
-        Value::Keyword(k) => Ok(k.0.clone()),
+        Value::Keyword(k) => Ok(k.0.to_string()),
         Value::Integer(i) => Ok(i.to_string()),
         Value::Float(f) => Ok(f.to_string()),
         Value::Boolean(b) => Ok(b.to_string()),
@@ -10,7 +10,7 @@ use crate::capability_marketplace::CapabilityMarketplace;
 use crate::utils::value_conversion::rtfs_value_to_json;
 use futures::future::BoxFuture;
 use rtfs::runtime::error::{RuntimeError, RuntimeResult};
-use rtfs::runtime::values::Value;
+use rtfs::runtime::values::{Value, ValueMap};
 use serde::Deserialize;
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -112,7 +112,7 @@ async fn register_sandbox_primitive(
 
                 let result = executor.execute(&provider, &Value::Nil, &context).await?;
                 Ok(Value::Map({
-                    let mut map = HashMap::new();
+                    let mut map = ValueMap::new();
                     map.insert(rtfs::ast::MapKey::String("result".to_string()), result);
                     map
                 }))
@@ -0,0 +1,82 @@
+//! Registering RTFS [`CapabilityBuilder`]s directly into the marketplace
+//!
+//! A builder that carries a handler (see [`CapabilityBuilder::with_handler`])
+//! can be turned into a local capability in one step instead of assembling a
+//! manifest by hand.
+
+use super::types::CapabilityMarketplace;
+use async_trait::async_trait;
+use rtfs::ast::TypeExpr;
+use rtfs::builders::CapabilityBuilder;
+use rtfs::parser::parse_type_expression;
+use rtfs::runtime::error::{RuntimeError, RuntimeResult};
+
+/// Extension for registering an RTFS [`CapabilityBuilder`] as a local capability
+#[async_trait]
+pub trait CapabilityBuilderExt {
+    /// Validate the capability and register it under its name.
+    ///
+    /// Fails if a capability with the same ID is already registered, if no
+    /// handler was set, or if a schema does not survive an RTFS round trip.
+    async fn register_into(self, marketplace: &CapabilityMarketplace) -> RuntimeResult<()>;
+}
+
+#[async_trait]
+impl CapabilityBuilderExt for CapabilityBuilder {
+    async fn register_into(self, marketplace: &CapabilityMarketplace) -> RuntimeResult<()> {
+        let id = self.name().to_string();
+        if id.is_empty() {
+            return Err(RuntimeError::Generic(
+                "Cannot register a capability without a name".to_string(),
+            ));
+        }
+        if marketplace.has_capability(&id).await {
+            return Err(RuntimeError::Generic(format!(
+                "Capability '{}' is already registered",
+                id
+            )));
+        }
+        let handler = self
+            .handler()
+            .cloned()
+            .ok_or_else(|| RuntimeError::Generic(format!("Capability '{}' has no handler", id)))?;
+
+        check_schema(&id, "input", self.input_schema())?;
+        check_schema(&id, "output", self.output_schema())?;
+
+        let mut metadata = self.metadata().clone();
+        let description = metadata
+            .remove("description")
+            .unwrap_or_else(|| format!("Capability {}", id));
+        if let Some(provider) = self.provider() {
+            metadata.insert("provider".to_string(), provider.to_string());
+        }
+
+        marketplace
+            .register_local_capability_with_metadata(
+                id.clone(),
+                id,
+                description,
+                handler,
+                self.input_schema().cloned(),
+                self.output_schema().cloned(),
+                metadata,
+            )
+            .await
+    }
+}
+
+/// Schemas are stored and exchanged as RTFS source, so they must re-parse
+fn check_schema(id: &str, kind: &str, schema: Option<&TypeExpr>) -> RuntimeResult<()> {
+    let Some(schema) = schema else {
+        return Ok(());
+    };
+    parse_type_expression(&schema.to_string())
+        .map(|_| ())
+        .map_err(|e| {
+            RuntimeError::Generic(format!(
+                "Invalid {} schema for capability '{}': {:?}",
+                kind, id, e
+            ))
+        })
+}
@@ -84,6 +84,18 @@ impl CapabilityDiscovery for StaticDiscoveryProvider {
 }
 
 /// File-based manifest discovery provider
+///
+/// `manifest_path` is either a single manifest file or a directory whose
+/// `.json` and `.rtfs` files are all loaded. A JSON manifest is an array of
+/// capability entries (or an object with a `capabilities` array); each entry
+/// has an `id`, an optional `endpoint` (HTTP base URL), optional
+/// `input_schema`/`output_schema` (JSON Schema) and the usual `name`,
+/// `description`, `version`, `effects` and `metadata` fields. RTFS manifests use
+/// the `(capability ...)` format written by capability export.
+///
+/// Malformed entries are logged and skipped so one bad entry never aborts
+/// discovery. Manifests are operator-authored, so their capabilities are
+/// registered as approved.
 pub struct FileManifestDiscoveryProvider {
     manifest_path: String,
 }
@@ -92,29 +104,134 @@ impl FileManifestDiscoveryProvider {
     pub fn new(manifest_path: String) -> Self {
         Self { manifest_path }
     }
-}
 
-#[async_trait::async_trait]
-impl CapabilityDiscovery for FileManifestDiscoveryProvider {
-    async fn discover(
+    fn manifest_files(&self) -> Vec<std::path::PathBuf> {
+        let path = Path::new(&self.manifest_path);
+        if path.is_file() {
+            return vec![path.to_path_buf()];
+        }
+        let mut files: Vec<_> = std::fs::read_dir(path)
+            .map(|entries| {
+                entries
+                    .filter_map(|entry| entry.ok().map(|e| e.path()))
+                    .filter(|p| {
+                        p.is_file()
+                            && matches!(
+                                p.extension().and_then(|ext| ext.to_str()),
+                                Some("json") | Some("rtfs")
+                            )
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        files.sort();
+        files
+    }
+
+    async fn load_json_manifest(&self, path: &Path) -> RuntimeResult<Vec<CapabilityManifest>> {
+        let content = fs::read_to_string(path)
+            .await
+            .map_err(|e| RuntimeError::Generic(format!("Failed to read manifest file: {}", e)))?;
+        let doc: JsonValue = serde_json::from_str(&content).map_err(|e| {
+            RuntimeError::Generic(format!(
+                "Failed to parse manifest file {}: {}",
+                path.display(),
+                e
+            ))
+        })?;
+        let entries = match &doc {
+            JsonValue::Array(items) => items.clone(),
+            JsonValue::Object(obj) => match obj.get("capabilities") {
+                Some(JsonValue::Array(items)) => items.clone(),
+                _ => vec![doc.clone()],
+            },
+            _ => {
+                return Err(RuntimeError::Generic(format!(
+                    "Manifest file {} must contain an object or an array",
+                    path.display()
+                )))
+            }
+        };
+
+        let mut manifests = Vec::new();
+        for (index, entry) in entries.iter().enumerate() {
+            match parse_capability_manifest_from_json(entry).await {
+                Ok(manifest) => manifests.push(manifest),
+                Err(e) => log::error!(
+                    "Skipping malformed capability entry #{} in {}: {}",
+                    index,
+                    path.display(),
+                    e
+                ),
+            }
+        }
+        Ok(manifests)
+    }
+
+    fn load_rtfs_manifest(
         &self,
-        _marketplace: Option<Arc<super::super::capability_marketplace::CapabilityMarketplace>>,
+        path: &Path,
+        marketplace: Option<&super::super::capability_marketplace::CapabilityMarketplace>,
+    ) -> RuntimeResult<Vec<CapabilityManifest>> {
+        let parser = match marketplace {
+            Some(marketplace) => {
+                super::mcp_discovery::MCPDiscoveryProvider::new_with_rtfs_host_factory(
+                    super::mcp_discovery::MCPServerConfig::default(),
+                    marketplace.get_rtfs_host_factory(),
+                )?
+            }
+            None => super::mcp_discovery::MCPDiscoveryProvider::new(
+                super::mcp_discovery::MCPServerConfig::default(),
+            )?,
+        };
+        let module = parser.load_rtfs_capabilities(&path.to_string_lossy())?;
+
+        let mut manifests = Vec::new();
+        for cap_def in &module.capabilities {
+            match parser.rtfs_to_capability_manifest(cap_def) {
+                Ok(manifest) => manifests.push(manifest),
+                Err(e) => log::error!("Skipping malformed capability in {}: {}", path.display(), e),
+            }
+        }
+        Ok(manifests)
+    }
+
+    /// Read every manifest under `manifest_path`, skipping malformed files and entries
+    pub async fn load_manifests(
+        &self,
+        marketplace: Option<&super::super::capability_marketplace::CapabilityMarketplace>,
     ) -> RuntimeResult<Vec<CapabilityManifest>> {
         if !Path::new(&self.manifest_path).exists() {
             return Ok(vec![]); // Return empty if file doesn't exist
         }
 
-        let _content = fs::read_to_string(&self.manifest_path)
-            .await
-            .map_err(|e| RuntimeError::Generic(format!("Failed to read manifest file: {}", e)))?;
+        let mut manifests = Vec::new();
+        for path in self.manifest_files() {
+            let loaded = if path.extension().and_then(|ext| ext.to_str()) == Some("rtfs") {
+                self.load_rtfs_manifest(&path, marketplace)
+            } else {
+                self.load_json_manifest(&path).await
+            };
+            match loaded {
+                Ok(found) => manifests.extend(found),
+                Err(e) => log::error!("Skipping capability manifest {}: {}", path.display(), e),
+            }
+        }
 
-        // For now, return empty since we don't have serde support
-        // In a real implementation, you'd parse JSON manifests here
-        eprintln!(
-            "File manifest discovery not yet implemented for: {}",
-            self.manifest_path
-        );
-        Ok(vec![])
+        for manifest in &mut manifests {
+            manifest.approval_status = ApprovalStatus::Approved;
+        }
+        Ok(manifests)
+    }
+}
+
+#[async_trait::async_trait]
+impl CapabilityDiscovery for FileManifestDiscoveryProvider {
+    async fn discover(
+        &self,
+        marketplace: Option<Arc<super::super::capability_marketplace::CapabilityMarketplace>>,
+    ) -> RuntimeResult<Vec<CapabilityManifest>> {
+        self.load_manifests(marketplace.as_deref()).await
     }
 
     fn name(&self) -> &str {
@@ -563,6 +680,8 @@ async fn parse_capability_manifest_from_json(
         );
     }
     let metadata = extract_metadata_map(cap_json.get("metadata"));
+    let input_schema = parse_json_schema(cap_json.get("input_schema").or(cap_json.get("schema")))?;
+    let output_schema = parse_json_schema(cap_json.get("output_schema"))?;
 
     Ok(CapabilityManifest {
         id,
@@ -570,8 +689,8 @@ async fn parse_capability_manifest_from_json(
         description,
         provider,
         version,
-        input_schema: None,
-        output_schema: None,
+        input_schema,
+        output_schema,
         attestation,
         provenance,
         permissions,
@@ -585,6 +704,15 @@ async fn parse_capability_manifest_from_json(
     })
 }
 
+fn parse_json_schema(value: Option<&JsonValue>) -> RuntimeResult<Option<rtfs::ast::TypeExpr>> {
+    match value {
+        None | Some(JsonValue::Null) => Ok(None),
+        Some(schema) => crate::synthesis::mcp_introspector::MCPIntrospector::new()
+            .json_schema_to_rtfs_type(schema)
+            .map(Some),
+    }
+}
+
 fn extract_string_list(value: Option<&JsonValue>) -> Vec<String> {
     match value {
         Some(JsonValue::Array(items)) => items
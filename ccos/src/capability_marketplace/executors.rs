@@ -12,7 +12,7 @@ use reqwest;
 use reqwest_eventsource::{Event, EventSource};
 use rtfs::ast::MapKey;
 use rtfs::runtime::error::{RuntimeError, RuntimeResult};
-use rtfs::runtime::values::Value;
+use rtfs::runtime::values::{Value, ValueMap};
 use serde::de::DeserializeOwned;
 use serde_json::json;
 use std::any::TypeId;
@@ -94,21 +94,15 @@ impl CapabilityExecutor for MCPExecutor {
 
             // Fallback: Resolve auth token from inputs > provider > context metadata > env
             let auth_token_from_inputs = if let Value::Map(map) = inputs {
-                map.get(&MapKey::Keyword(rtfs::ast::Keyword(
-                    "auth-token".to_string(),
-                )))
-                .or_else(|| {
-                    map.get(&MapKey::Keyword(rtfs::ast::Keyword(
-                        "auth_token".to_string(),
-                    )))
-                })
-                .and_then(|v| {
-                    if let Value::String(s) = v {
-                        Some(s.clone())
-                    } else {
-                        None
-                    }
-                })
+                map.get(&MapKey::Keyword(rtfs::ast::Keyword("auth-token".into())))
+                    .or_else(|| map.get(&MapKey::Keyword(rtfs::ast::Keyword("auth_token".into()))))
+                    .and_then(|v| {
+                        if let Value::String(s) = v {
+                            Some(s.clone())
+                        } else {
+                            None
+                        }
+                    })
             } else {
                 None
             };
@@ -916,7 +910,7 @@ impl OpenApiExecutor {
         }
         response_map.insert(
             MapKey::String("headers".to_string()),
-            Value::Map(headers_map),
+            Value::Map(headers_map.into()),
         );
 
         if !bytes.is_empty() {
@@ -927,7 +921,7 @@ impl OpenApiExecutor {
             }
         }
 
-        Ok(Value::Map(response_map))
+        Ok(Value::Map(response_map.into()))
     }
 
     fn extract_input_map(inputs: &Value) -> RuntimeResult<HashMap<String, Value>> {
@@ -937,7 +931,7 @@ impl OpenApiExecutor {
                 .map(|(k, v)| Ok((Self::map_key_to_string(k)?, v.clone())))
                 .collect(),
             Value::List(list) | Value::Vector(list) => {
-                if let Some(Value::Map(m)) = list.first() {
+                if let Some(Value::Map(m)) = list.front() {
                     m.iter()
                         .map(|(k, v)| Ok((Self::map_key_to_string(k)?, v.clone())))
                         .collect()
@@ -1187,28 +1181,23 @@ impl CapabilityExecutor for HttpExecutor {
                 Value::Map(map) => {
                     let url = map
                         .get(&MapKey::String("url".to_string()))
-                        .or_else(|| {
-                            map.get(&MapKey::Keyword(rtfs::ast::Keyword("url".to_string())))
-                        })
+                        .or_else(|| map.get(&MapKey::Keyword(rtfs::ast::Keyword("url".into()))))
                         .and_then(|v| v.as_string())
                         .map(|s| s.to_string())
                         .unwrap_or_else(|| http.base_url.clone());
 
                     let method = map
                         .get(&MapKey::String("method".to_string()))
-                        .or_else(|| {
-                            map.get(&MapKey::Keyword(rtfs::ast::Keyword("method".to_string())))
-                        })
+                        .or_else(|| map.get(&MapKey::Keyword(rtfs::ast::Keyword("method".into()))))
                         .and_then(|v| v.as_string())
                         .map(|s| s.to_string())
                         .or_else(|| context.metadata.get("method").cloned())
                         .unwrap_or_else(|| "POST".to_string());
 
                     let mut headers = HashMap::new();
-                    if let Some(h_val) =
-                        map.get(&MapKey::String("headers".to_string())).or_else(|| {
-                            map.get(&MapKey::Keyword(rtfs::ast::Keyword("headers".to_string())))
-                        })
+                    if let Some(h_val) = map
+                        .get(&MapKey::String("headers".to_string()))
+                        .or_else(|| map.get(&MapKey::Keyword(rtfs::ast::Keyword("headers".into()))))
                     {
                         if let Value::Map(h_map) = h_val {
                             for (k, v) in h_map {
@@ -1220,10 +1209,10 @@ impl CapabilityExecutor for HttpExecutor {
                         }
                     }
 
-                    let body = if let Some(b_val) =
-                        map.get(&MapKey::String("body".to_string())).or_else(|| {
-                            map.get(&MapKey::Keyword(rtfs::ast::Keyword("body".to_string())))
-                        }) {
+                    let body = if let Some(b_val) = map
+                        .get(&MapKey::String("body".to_string()))
+                        .or_else(|| map.get(&MapKey::Keyword(rtfs::ast::Keyword("body".into()))))
+                    {
                         match b_val {
                             Value::String(s) => s.clone(),
                             _ => A2AExecutor::value_to_json(b_val)
@@ -1235,12 +1224,11 @@ impl CapabilityExecutor for HttpExecutor {
                         // excluding control keys
                         let mut body_map = map.clone();
                         body_map.remove(&MapKey::String("url".to_string()));
-                        body_map.remove(&MapKey::Keyword(rtfs::ast::Keyword("url".to_string())));
+                        body_map.remove(&MapKey::Keyword(rtfs::ast::Keyword("url".into())));
                         body_map.remove(&MapKey::String("method".to_string()));
-                        body_map.remove(&MapKey::Keyword(rtfs::ast::Keyword("method".to_string())));
+                        body_map.remove(&MapKey::Keyword(rtfs::ast::Keyword("method".into())));
                         body_map.remove(&MapKey::String("headers".to_string()));
-                        body_map
-                            .remove(&MapKey::Keyword(rtfs::ast::Keyword("headers".to_string())));
+                        body_map.remove(&MapKey::Keyword(rtfs::ast::Keyword("headers".into())));
 
                         if body_map.is_empty() {
                             String::new()
@@ -1256,7 +1244,7 @@ impl CapabilityExecutor for HttpExecutor {
                     let args = match inputs {
                         Value::List(list) => list.clone(),
                         Value::Vector(vec) => vec.clone(),
-                        v => vec![v.clone()],
+                        v => vec![v.clone()].into(),
                     };
                     let url = args
                         .get(0)
@@ -1457,9 +1445,9 @@ impl CapabilityExecutor for HttpExecutor {
             }
             response_map.insert(
                 MapKey::String("headers".to_string()),
-                Value::Map(headers_map),
+                Value::Map(headers_map.into()),
             );
-            Ok(Value::Map(response_map))
+            Ok(Value::Map(response_map.into()))
         } else {
             Err(RuntimeError::Generic(
                 "ProviderType mismatch for HttpExecutor".to_string(),
@@ -1579,7 +1567,7 @@ fn parse_json_metadata<T: DeserializeOwned>(raw: &str, key: &str) -> RuntimeResu
 }
 
 fn attach_usage(value: Value, metrics: &ResourceMetrics) -> Value {
-    let mut usage_map: HashMap<MapKey, Value> = HashMap::new();
+    let mut usage_map: ValueMap = ValueMap::new();
 
     if metrics.cpu_time_ms > 0 {
         usage_map.insert(
@@ -1636,7 +1624,7 @@ fn attach_usage(value: Value, metrics: &ResourceMetrics) -> Value {
             let mut map = HashMap::new();
             map.insert(MapKey::String("result".to_string()), other);
             map.insert(usage_key, Value::Map(usage_map));
-            Value::Map(map)
+            Value::Map(map.into())
         }
     }
 }
@@ -1704,14 +1692,14 @@ mod tests {
         let mut base_map = HashMap::new();
         base_map.insert(
             MapKey::String("usage".to_string()),
-            Value::Map(existing_usage),
+            Value::Map(existing_usage.into()),
         );
         base_map.insert(
             MapKey::String("payload".to_string()),
             Value::String("ok".to_string()),
         );
 
-        let updated = attach_usage(Value::Map(base_map), &metrics);
+        let updated = attach_usage(Value::Map(base_map.into()), &metrics);
         let Value::Map(updated_map) = updated else {
             panic!("expected map result");
         };
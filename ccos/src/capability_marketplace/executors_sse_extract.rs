@@ -750,7 +750,7 @@ impl OpenApiExecutor {
         }
         response_map.insert(
             MapKey::String("headers".to_string()),
-            Value::Map(headers_map),
+            Value::Map(headers_map.into()),
         );
 
         if !bytes.is_empty() {
@@ -761,7 +761,7 @@ impl OpenApiExecutor {
             }
         }
 
-        Ok(Value::Map(response_map))
+        Ok(Value::Map(response_map.into()))
     }
 
     fn extract_input_map(inputs: &Value) -> RuntimeResult<HashMap<String, Value>> {
@@ -771,7 +771,7 @@ impl OpenApiExecutor {
                 .map(|(k, v)| Ok((Self::map_key_to_string(k)?, v.clone())))
                 .collect(),
             Value::List(list) | Value::Vector(list) => {
-                if let Some(Value::Map(m)) = list.first() {
+                if let Some(Value::Map(m)) = list.front() {
                     m.iter()
                         .map(|(k, v)| Ok((Self::map_key_to_string(k)?, v.clone())))
                         .collect()
@@ -1066,9 +1066,9 @@ impl CapabilityExecutor for HttpExecutor {
             }
             response_map.insert(
                 MapKey::String("headers".to_string()),
-                Value::Map(headers_map),
+                Value::Map(headers_map.into()),
             );
-            Ok(Value::Map(response_map))
+            Ok(Value::Map(response_map.into()))
         } else {
             Err(RuntimeError::Generic(
                 "ProviderType mismatch for HttpExecutor".to_string(),
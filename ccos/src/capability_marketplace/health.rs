@@ -0,0 +1,313 @@
+//! Capability health tracking and circuit breaking for remote providers.
+//!
+//! The marketplace records the outcome of every call to a remote capability
+//! (HTTP, MCP, A2A, OpenAPI, remote RTFS, ...). After `failure_threshold`
+//! consecutive failures the breaker for that capability opens and calls fail
+//! fast without reaching the provider. Once `cooldown` has elapsed a single
+//! probe call is let through (half-open): success closes the breaker, failure
+//! re-opens it for another cooldown. A probe that never reports back (cancelled
+//! or panicked) is given up on after `probe_timeout` and another one is let
+//! through. Only provider and transport failures are recorded; callers' own
+//! access or input validation errors never reach the breaker.
+
+use chrono::{DateTime, Utc};
+use rtfs::runtime::error::{RuntimeError, RuntimeResult};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Circuit breaker thresholds applied to every tracked capability.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CircuitBreakerConfig {
+    /// Consecutive failures that open the breaker
+    pub failure_threshold: u32,
+    /// How long the breaker stays open before letting a probe call through
+    pub cooldown: Duration,
+    /// How long a half-open probe may stay unanswered before another is let through
+    pub probe_timeout: Duration,
+}
+
+impl Default for CircuitBreakerConfig {
+    fn default() -> Self {
+        Self {
+            failure_threshold: 5,
+            cooldown: Duration::from_secs(30),
+            probe_timeout: Duration::from_secs(30),
+        }
+    }
+}
+
+/// Circuit breaker state of a capability.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum CircuitState {
+    /// Calls flow normally
+    Closed,
+    /// Calls fail fast until the cooldown elapses
+    Open,
+    /// Cooldown elapsed; one probe call is in flight
+    HalfOpen,
+}
+
+/// Observable health snapshot of a capability.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CapabilityHealth {
+    pub state: CircuitState,
+    pub consecutive_failures: u32,
+    pub last_success: Option<DateTime<Utc>>,
+    pub last_failure: Option<DateTime<Utc>>,
+    pub last_error: Option<String>,
+}
+
+#[derive(Debug)]
+struct HealthEntry {
+    health: CapabilityHealth,
+    opened_at: Option<Instant>,
+    probe_started_at: Option<Instant>,
+}
+
+impl HealthEntry {
+    fn new() -> Self {
+        Self {
+            health: CapabilityHealth {
+                state: CircuitState::Closed,
+                consecutive_failures: 0,
+                last_success: None,
+                last_failure: None,
+                last_error: None,
+            },
+            opened_at: None,
+            probe_started_at: None,
+        }
+    }
+}
+
+/// Per-capability health registry with circuit breaking.
+#[derive(Debug, Default)]
+pub struct CapabilityHealthRegistry {
+    config: Mutex<CircuitBreakerConfig>,
+    entries: Mutex<HashMap<String, HealthEntry>>,
+}
+
+impl CapabilityHealthRegistry {
+    pub fn new(config: CircuitBreakerConfig) -> Self {
+        Self {
+            config: Mutex::new(config),
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn config(&self) -> CircuitBreakerConfig {
+        self.config.lock().map(|c| c.clone()).unwrap_or_default()
+    }
+
+    pub fn set_config(&self, config: CircuitBreakerConfig) {
+        if let Ok(mut guard) = self.config.lock() {
+            *guard = config;
+        }
+    }
+
+    /// Check whether a call to `id` may proceed.
+    ///
+    /// Returns an error without touching the provider while the breaker is open.
+    /// When the cooldown has elapsed the breaker moves to half-open and exactly
+    /// one caller is let through as a probe, or a replacement probe once the
+    /// previous one has been outstanding for `probe_timeout`.
+    pub fn before_call(&self, id: &str) -> RuntimeResult<()> {
+        let CircuitBreakerConfig {
+            cooldown,
+            probe_timeout,
+            ..
+        } = self.config();
+        let mut entries = self
+            .entries
+            .lock()
+            .map_err(|_| RuntimeError::Generic("Failed to lock health registry".to_string()))?;
+        let Some(entry) = entries.get_mut(id) else {
+            return Ok(());
+        };
+
+        match entry.health.state {
+            CircuitState::Closed => Ok(()),
+            CircuitState::Open => {
+                let elapsed = entry.opened_at.map(|t| t.elapsed()).unwrap_or(cooldown);
+                if elapsed >= cooldown {
+                    entry.health.state = CircuitState::HalfOpen;
+                    entry.probe_started_at = Some(Instant::now());
+                    Ok(())
+                } else {
+                    Err(Self::open_error(id, &entry.health, cooldown - elapsed))
+                }
+            }
+            CircuitState::HalfOpen => {
+                let outstanding = entry
+                    .probe_started_at
+                    .map(|t| t.elapsed())
+                    .unwrap_or(probe_timeout);
+                if outstanding >= probe_timeout {
+                    // The probe was lost without reporting back; send another
+                    entry.probe_started_at = Some(Instant::now());
+                    Ok(())
+                } else {
+                    // A probe is in flight; keep others out until it reports back
+                    Err(Self::open_error(
+                        id,
+                        &entry.health,
+                        probe_timeout - outstanding,
+                    ))
+                }
+            }
+        }
+    }
+
+    /// Record a successful call; closes the breaker.
+    pub fn record_success(&self, id: &str) {
+        if let Ok(mut entries) = self.entries.lock() {
+            let entry = entries
+                .entry(id.to_string())
+                .or_insert_with(HealthEntry::new);
+            entry.health.state = CircuitState::Closed;
+            entry.health.consecutive_failures = 0;
+            entry.health.last_success = Some(Utc::now());
+            entry.opened_at = None;
+            entry.probe_started_at = None;
+        }
+    }
+
+    /// Record a failed call; opens the breaker once the threshold is reached,
+    /// or immediately when a half-open probe fails.
+    pub fn record_failure(&self, id: &str, error: &RuntimeError) {
+        let threshold = self.config().failure_threshold;
+        if let Ok(mut entries) = self.entries.lock() {
+            let entry = entries
+                .entry(id.to_string())
+                .or_insert_with(HealthEntry::new);
+            entry.health.consecutive_failures += 1;
+            entry.health.last_failure = Some(Utc::now());
+            entry.health.last_error = Some(error.to_string());
+
+            let should_open = entry.health.state == CircuitState::HalfOpen
+                || entry.health.consecutive_failures >= threshold;
+            if should_open {
+                entry.health.state = CircuitState::Open;
+                entry.opened_at = Some(Instant::now());
+                entry.probe_started_at = None;
+            }
+        }
+    }
+
+    /// Health snapshot for a capability, if any call to it has been recorded.
+    pub fn get(&self, id: &str) -> Option<CapabilityHealth> {
+        self.entries
+            .lock()
+            .ok()
+            .and_then(|entries| entries.get(id).map(|e| e.health.clone()))
+    }
+
+    /// Health snapshots for all tracked capabilities.
+    pub fn snapshot(&self) -> HashMap<String, CapabilityHealth> {
+        self.entries
+            .lock()
+            .map(|entries| {
+                entries
+                    .iter()
+                    .map(|(id, e)| (id.clone(), e.health.clone()))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    fn open_error(id: &str, health: &CapabilityHealth, retry_in: Duration) -> RuntimeError {
+        RuntimeError::Generic(format!(
+            "Circuit breaker open for capability '{}' after {} consecutive failures (retry in {}ms); last error: {}",
+            id,
+            health.consecutive_failures,
+            retry_in.as_millis(),
+            health.last_error.as_deref().unwrap_or("unknown")
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn registry(cooldown_ms: u64) -> CapabilityHealthRegistry {
+        CapabilityHealthRegistry::new(CircuitBreakerConfig {
+            failure_threshold: 2,
+            cooldown: Duration::from_millis(cooldown_ms),
+            probe_timeout: Duration::from_secs(60),
+        })
+    }
+
+    fn failure() -> RuntimeError {
+        RuntimeError::Generic("boom".to_string())
+    }
+
+    #[test]
+    fn opens_after_consecutive_failures() {
+        let reg = registry(60_000);
+        reg.record_failure("cap", &failure());
+        assert!(reg.before_call("cap").is_ok());
+        reg.record_failure("cap", &failure());
+
+        let health = reg.get("cap").unwrap();
+        assert_eq!(health.state, CircuitState::Open);
+        assert_eq!(health.consecutive_failures, 2);
+        assert_eq!(health.last_error.as_deref(), Some("Runtime error: boom"));
+        assert!(reg.before_call("cap").is_err());
+    }
+
+    #[test]
+    fn success_resets_failure_count() {
+        let reg = registry(60_000);
+        reg.record_failure("cap", &failure());
+        reg.record_success("cap");
+        reg.record_failure("cap", &failure());
+
+        let health = reg.get("cap").unwrap();
+        assert_eq!(health.state, CircuitState::Closed);
+        assert!(health.last_success.is_some());
+    }
+
+    #[test]
+    fn half_open_probe_decides_recovery() {
+        let reg = registry(0);
+        reg.record_failure("cap", &failure());
+        reg.record_failure("cap", &failure());
+
+        // Cooldown elapsed: one probe allowed, concurrent callers still rejected
+        assert!(reg.before_call("cap").is_ok());
+        assert_eq!(reg.get("cap").unwrap().state, CircuitState::HalfOpen);
+        assert!(reg.before_call("cap").is_err());
+
+        // Failed probe re-opens immediately
+        reg.record_failure("cap", &failure());
+        assert_eq!(reg.get("cap").unwrap().state, CircuitState::Open);
+
+        assert!(reg.before_call("cap").is_ok());
+        reg.record_success("cap");
+        assert_eq!(reg.get("cap").unwrap().state, CircuitState::Closed);
+        assert!(reg.before_call("cap").is_ok());
+    }
+
+    #[test]
+    fn lost_probe_is_replaced_after_probe_timeout() {
+        let reg = CapabilityHealthRegistry::new(CircuitBreakerConfig {
+            failure_threshold: 1,
+            cooldown: Duration::ZERO,
+            probe_timeout: Duration::from_millis(20),
+        });
+        reg.record_failure("cap", &failure());
+
+        // The probe is let through but never reports back (e.g. its call was cancelled)
+        assert!(reg.before_call("cap").is_ok());
+        assert!(reg.before_call("cap").is_err());
+
+        std::thread::sleep(Duration::from_millis(30));
+        assert!(reg.before_call("cap").is_ok());
+        assert_eq!(reg.get("cap").unwrap().state, CircuitState::HalfOpen);
+        reg.record_success("cap");
+        assert_eq!(reg.get("cap").unwrap().state, CircuitState::Closed);
+    }
+}
@@ -3,6 +3,7 @@ use super::executors::{
     A2AExecutor, ExecutorVariant, HttpExecutor, LocalExecutor, MCPExecutor, OpenApiExecutor,
     RegistryExecutor, SandboxedExecutor,
 };
+use super::health::{CapabilityHealth, CapabilityHealthRegistry, CircuitBreakerConfig};
 use super::mcp_discovery::{MCPDiscoveryProvider, MCPServerConfig};
 use super::resource_monitor::ResourceMonitor;
 use super::types::*;
@@ -336,6 +337,7 @@ impl CapabilityMarketplace {
             catalog: Arc::new(RwLock::new(None)),
             rtfs_host_factory: Arc::new(std::sync::RwLock::new(None)),
             approval_store: Arc::new(RwLock::new(RuntimeApprovalStore::new())),
+            health: Arc::new(CapabilityHealthRegistry::default()),
         };
         marketplace.executor_registry.insert(
             TypeId::of::<MCPCapability>(),
@@ -648,6 +650,13 @@ impl CapabilityMarketplace {
         }
         */
 
+        // Operator-provided capability manifest (file or directory)
+        if let Ok(manifest_path) = std::env::var("CCOS_CAPABILITY_MANIFEST") {
+            if let Err(e) = self.register_from_manifest(&manifest_path).await {
+                ccos_eprintln!("Capability manifest {} failed: {:?}", manifest_path, e);
+            }
+        }
+
         // Run discovery agents to find additional capabilities
         for agent in &self.discovery_agents {
             match agent.discover(Some(marketplace_arc.clone())).await {
@@ -672,6 +681,32 @@ impl CapabilityMarketplace {
         self.discovery_agents.push(agent);
     }
 
+    /// Register every capability described by a manifest file or directory
+    /// (see `FileManifestDiscoveryProvider` for the format). Malformed entries are
+    /// skipped, and so are entries whose id is already registered: a manifest
+    /// cannot shadow a built-in capability. Each entry goes through
+    /// `register_capability_manifest`, so it is indexed in the catalog and audited.
+    /// Returns the number of capabilities registered.
+    pub async fn register_from_manifest<P: AsRef<Path>>(&self, path: P) -> RuntimeResult<usize> {
+        let provider = super::discovery::FileManifestDiscoveryProvider::new(
+            path.as_ref().to_string_lossy().to_string(),
+        );
+        let manifests = provider.load_manifests(Some(self)).await?;
+        let mut count = 0;
+        for manifest in manifests {
+            if self.has_capability(&manifest.id).await {
+                ccos_eprintln!(
+                    "Capability manifest entry '{}' rejected: a capability with this id is already registered",
+                    manifest.id
+                );
+                continue;
+            }
+            self.register_capability_manifest(manifest).await?;
+            count += 1;
+        }
+        Ok(count)
+    }
+
     // Temporarily disabled to fix resource monitoring tests
     /*
     /// Add a network discovery provider to the marketplace
@@ -885,6 +920,49 @@ impl CapabilityMarketplace {
         description: String,
         handler: Arc<dyn Fn(&Value) -> RuntimeResult<Value> + Send + Sync>,
         effects: Vec<String>,
+    ) -> RuntimeResult<()> {
+        self.register_local_capability_with_effect_type(
+            id,
+            name,
+            description,
+            handler,
+            effects,
+            EffectType::Effectful,
+        )
+        .await
+    }
+
+    /// Register a local capability that has no side effects.
+    ///
+    /// Pure capabilities are eligible for per-plan call memoization when the
+    /// orchestrator has it enabled.
+    pub async fn register_pure_local_capability(
+        &self,
+        id: String,
+        name: String,
+        description: String,
+        handler: Arc<dyn Fn(&Value) -> RuntimeResult<Value> + Send + Sync>,
+    ) -> RuntimeResult<()> {
+        self.register_local_capability_with_effect_type(
+            id,
+            name,
+            description,
+            handler,
+            vec![],
+            EffectType::Pure,
+        )
+        .await
+    }
+
+    /// Register a local capability with explicit effects and effect classification
+    pub async fn register_local_capability_with_effect_type(
+        &self,
+        id: String,
+        name: String,
+        description: String,
+        handler: Arc<dyn Fn(&Value) -> RuntimeResult<Value> + Send + Sync>,
+        effects: Vec<String>,
+        effect_type: EffectType,
     ) -> RuntimeResult<()> {
         let provenance = CapabilityProvenance {
             source: "local".to_string(),
@@ -910,7 +988,7 @@ impl CapabilityMarketplace {
             agent_metadata: None,
             domains: Vec::new(),
             categories: Vec::new(),
-            effect_type: EffectType::Effectful,
+            effect_type,
             approval_status: crate::capability_marketplace::types::ApprovalStatus::Approved,
         };
 
@@ -1784,7 +1862,9 @@ impl CapabilityMarketplace {
         config: &StreamConfig,
     ) -> RuntimeResult<StreamHandle> {
         let capability = self.get_capability(capability_id).await.ok_or_else(|| {
-            RuntimeError::Generic(format!("Capability '{}' not found", capability_id))
+            RuntimeError::CapabilityNotFound {
+                capability_id: capability_id.to_string(),
+            }
         })?;
         if let ProviderType::Stream(stream_impl) = &capability.provider {
             if config.callbacks.is_some() {
@@ -1811,7 +1891,9 @@ impl CapabilityMarketplace {
         config: &StreamConfig,
     ) -> RuntimeResult<StreamHandle> {
         let capability = self.get_capability(capability_id).await.ok_or_else(|| {
-            RuntimeError::Generic(format!("Capability '{}' not found", capability_id))
+            RuntimeError::CapabilityNotFound {
+                capability_id: capability_id.to_string(),
+            }
         })?;
         if let ProviderType::Stream(stream_impl) = &capability.provider {
             if !matches!(stream_impl.stream_type, StreamType::Bidirectional) {
@@ -1856,10 +1938,9 @@ impl CapabilityMarketplace {
             );
             Ok(())
         } else {
-            Err(RuntimeError::Generic(format!(
-                "Capability '{}' not found",
-                id
-            )))
+            Err(RuntimeError::CapabilityNotFound {
+                capability_id: id.to_string(),
+            })
         }
     }
 
@@ -1938,6 +2019,55 @@ impl CapabilityMarketplace {
             .await
     }
 
+    /// Health and circuit breaker state of a capability, if any remote call was recorded.
+    pub fn capability_health(&self, id: &str) -> Option<CapabilityHealth> {
+        self.health.get(id)
+    }
+
+    /// Health and circuit breaker state of all tracked capabilities.
+    pub fn capability_health_snapshot(&self) -> HashMap<String, CapabilityHealth> {
+        self.health.snapshot()
+    }
+
+    /// Replace the circuit breaker thresholds used for remote capabilities.
+    pub fn set_circuit_breaker_config(&self, config: CircuitBreakerConfig) {
+        self.health.set_config(config);
+    }
+
+    /// Remote providers are subject to health tracking and circuit breaking;
+    /// in-process providers (local, native, sandboxed, ...) are not.
+    fn is_remote_provider(provider: &ProviderType) -> bool {
+        matches!(
+            provider,
+            ProviderType::Http(_)
+                | ProviderType::MCP(_)
+                | ProviderType::A2A(_)
+                | ProviderType::OpenApi(_)
+                | ProviderType::RemoteRTFS(_)
+        )
+    }
+
+    /// Run a provider call for `manifest` under its circuit breaker. Remote
+    /// providers fail fast while the breaker is open, and only the provider's own
+    /// outcome is recorded: local access or input validation failures never
+    /// count against the capability's health.
+    async fn call_provider<F>(&self, manifest: &CapabilityManifest, call: F) -> RuntimeResult<Value>
+    where
+        F: std::future::Future<Output = RuntimeResult<Value>>,
+    {
+        if !Self::is_remote_provider(&manifest.provider) {
+            return call.await;
+        }
+
+        self.health.before_call(&manifest.id)?;
+        let result = call.await;
+        match &result {
+            Ok(_) => self.health.record_success(&manifest.id),
+            Err(e) => self.health.record_failure(&manifest.id, e),
+        }
+        result
+    }
+
     async fn execute_capability_with_metadata(
         &self,
         id: &str,
@@ -2045,8 +2175,8 @@ impl CapabilityMarketplace {
 
                     // Extract args for context if possible (best effort)
                     let args = match inputs {
-                        Value::Vector(v) => v.clone(),
-                        Value::List(l) => l.clone(),
+                        Value::Vector(v) => v.iter().cloned().collect(),
+                        Value::List(l) => l.iter().cloned().collect(),
                         _ => vec![],
                     };
 
@@ -2062,7 +2192,9 @@ impl CapabilityMarketplace {
                 }
             }
 
-            return Err(RuntimeError::UnknownCapability(id.to_string()));
+            return Err(RuntimeError::CapabilityNotFound {
+                capability_id: id.to_string(),
+            });
         };
 
         let normalized_inputs = Self::normalize_input_envelope(inputs);
@@ -2092,8 +2224,8 @@ impl CapabilityMarketplace {
 
             if let Some(pool) = pool_opt {
                 let args = match inputs_ref {
-                    Value::List(list) => list.clone(),
-                    Value::Vector(vec) => vec.clone(),
+                    Value::List(list) => list.iter().cloned().collect(),
+                    Value::Vector(vec) => vec.iter().cloned().collect(),
                     other => vec![other.clone()],
                 };
 
@@ -2118,7 +2250,12 @@ impl CapabilityMarketplace {
                         })?;
                 }
 
-                let exec_result = pool.execute_with_session(id, &manifest.metadata, &args)?;
+                let exec_result = self
+                    .call_provider(&manifest, async {
+                        pool.execute_with_session(id, &manifest.metadata, &args)
+                    })
+                    .await?;
+                let exec_result = Self::decode_output(&manifest, exec_result)?;
 
                 if let Some(output_schema) = &manifest.output_schema {
                     self.type_validator
@@ -2152,58 +2289,64 @@ impl CapabilityMarketplace {
         // Execute via executor registry or provider fallback
         let session_pool = self.session_pool.read().await.clone();
         let context = super::executors::ExecutionContext::new(id, &manifest.metadata, session_pool);
-        let exec_result = if let Some(executor) =
-            self.executor_registry.get(&match &manifest.provider {
-                ProviderType::Local(_) => std::any::TypeId::of::<LocalCapability>(),
-                ProviderType::Http(_) => std::any::TypeId::of::<HttpCapability>(),
-                ProviderType::MCP(_) => std::any::TypeId::of::<MCPCapability>(),
-                ProviderType::A2A(_) => std::any::TypeId::of::<A2ACapability>(),
-                ProviderType::OpenApi(_) => std::any::TypeId::of::<OpenApiCapability>(),
-                ProviderType::Plugin(_) => std::any::TypeId::of::<PluginCapability>(),
-                ProviderType::RemoteRTFS(_) => std::any::TypeId::of::<RemoteRTFSCapability>(),
-                ProviderType::Stream(_) => std::any::TypeId::of::<StreamCapabilityImpl>(),
-                ProviderType::Registry(_) => std::any::TypeId::of::<RegistryCapability>(),
-                ProviderType::Native(_) => std::any::TypeId::of::<NativeCapability>(),
-                ProviderType::Sandboxed(_) => std::any::TypeId::of::<SandboxedCapability>(),
-            }) {
-            executor
-                .execute(&manifest.provider, inputs_ref, &context)
-                .await
-        } else {
-            match &manifest.provider {
-                ProviderType::Local(local) => (local.handler)(inputs_ref),
-                ProviderType::Http(http) => self.execute_http_capability(http, inputs_ref).await,
-                ProviderType::OpenApi(_) => {
-                    let executor = OpenApiExecutor;
+        let exec_result = self
+            .call_provider(&manifest, async {
+                if let Some(executor) = self.executor_registry.get(&match &manifest.provider {
+                    ProviderType::Local(_) => std::any::TypeId::of::<LocalCapability>(),
+                    ProviderType::Http(_) => std::any::TypeId::of::<HttpCapability>(),
+                    ProviderType::MCP(_) => std::any::TypeId::of::<MCPCapability>(),
+                    ProviderType::A2A(_) => std::any::TypeId::of::<A2ACapability>(),
+                    ProviderType::OpenApi(_) => std::any::TypeId::of::<OpenApiCapability>(),
+                    ProviderType::Plugin(_) => std::any::TypeId::of::<PluginCapability>(),
+                    ProviderType::RemoteRTFS(_) => std::any::TypeId::of::<RemoteRTFSCapability>(),
+                    ProviderType::Stream(_) => std::any::TypeId::of::<StreamCapabilityImpl>(),
+                    ProviderType::Registry(_) => std::any::TypeId::of::<RegistryCapability>(),
+                    ProviderType::Native(_) => std::any::TypeId::of::<NativeCapability>(),
+                    ProviderType::Sandboxed(_) => std::any::TypeId::of::<SandboxedCapability>(),
+                }) {
                     executor
                         .execute(&manifest.provider, inputs_ref, &context)
                         .await
+                } else {
+                    match &manifest.provider {
+                        ProviderType::Local(local) => (local.handler)(inputs_ref),
+                        ProviderType::Http(http) => {
+                            self.execute_http_capability(http, inputs_ref).await
+                        }
+                        ProviderType::OpenApi(_) => {
+                            let executor = OpenApiExecutor;
+                            executor
+                                .execute(&manifest.provider, inputs_ref, &context)
+                                .await
+                        }
+                        ProviderType::MCP(_mcp) => {
+                            Err(RuntimeError::Generic("MCP not configured".to_string()))
+                        }
+                        ProviderType::A2A(_a2a) => {
+                            Err(RuntimeError::Generic("A2A not configured".to_string()))
+                        }
+                        ProviderType::Plugin(_p) => {
+                            Err(RuntimeError::Generic("Plugin not configured".to_string()))
+                        }
+                        ProviderType::RemoteRTFS(_r) => Err(RuntimeError::Generic(
+                            "Remote RTFS not configured".to_string(),
+                        )),
+                        ProviderType::Stream(stream_impl) => {
+                            self.execute_stream_capability(stream_impl, inputs_ref)
+                                .await
+                        }
+                        ProviderType::Registry(_) => Err(RuntimeError::Generic(
+                            "Registry provider missing executor".to_string(),
+                        )),
+                        ProviderType::Native(native) => (native.handler)(inputs_ref).await,
+                        ProviderType::Sandboxed(_) => Err(RuntimeError::Generic(
+                            "Sandboxed capability executor not found".to_string(),
+                        )),
+                    }
                 }
-                ProviderType::MCP(_mcp) => {
-                    Err(RuntimeError::Generic("MCP not configured".to_string()))
-                }
-                ProviderType::A2A(_a2a) => {
-                    Err(RuntimeError::Generic("A2A not configured".to_string()))
-                }
-                ProviderType::Plugin(_p) => {
-                    Err(RuntimeError::Generic("Plugin not configured".to_string()))
-                }
-                ProviderType::RemoteRTFS(_r) => Err(RuntimeError::Generic(
-                    "Remote RTFS not configured".to_string(),
-                )),
-                ProviderType::Stream(stream_impl) => {
-                    self.execute_stream_capability(stream_impl, inputs_ref)
-                        .await
-                }
-                ProviderType::Registry(_) => Err(RuntimeError::Generic(
-                    "Registry provider missing executor".to_string(),
-                )),
-                ProviderType::Native(native) => (native.handler)(inputs_ref).await,
-                ProviderType::Sandboxed(_) => Err(RuntimeError::Generic(
-                    "Sandboxed capability executor not found".to_string(),
-                )),
-            }
-        }?;
+            })
+            .await?;
+        let exec_result = Self::decode_output(&manifest, exec_result)?;
 
         // Validate outputs if a schema is provided
         if let Some(output_schema) = &manifest.output_schema {
@@ -2223,6 +2366,18 @@ impl CapabilityMarketplace {
         Ok(exec_result)
     }
 
+    /// Parse the result of a capability declaring JSON output, ahead of output validation
+    fn decode_output(manifest: &CapabilityManifest, result: Value) -> RuntimeResult<Value> {
+        if manifest.has_json_output() {
+            crate::capabilities::output_decoding::decode_json_output(
+                result,
+                manifest.output_schema.as_ref(),
+            )
+        } else {
+            Ok(result)
+        }
+    }
+
     fn normalize_input_envelope(inputs: &Value) -> Option<Value> {
         match inputs {
             Value::List(list) if list.len() == 1 => match &list[0] {
@@ -2261,22 +2416,23 @@ impl CapabilityMarketplace {
             Value::Map(map) => {
                 let url = map
                     .get(&MapKey::String("url".to_string()))
-                    .or_else(|| map.get(&MapKey::Keyword(rtfs::ast::Keyword("url".to_string()))))
+                    .or_else(|| map.get(&MapKey::Keyword(rtfs::ast::Keyword("url".into()))))
                     .and_then(|v| v.as_string())
                     .map(|s| s.to_string())
                     .unwrap_or_else(|| http.base_url.clone());
 
                 let method = map
                     .get(&MapKey::String("method".to_string()))
-                    .or_else(|| map.get(&MapKey::Keyword(rtfs::ast::Keyword("method".to_string()))))
+                    .or_else(|| map.get(&MapKey::Keyword(rtfs::ast::Keyword("method".into()))))
                     .and_then(|v| v.as_string())
                     .unwrap_or("POST")
                     .to_string();
 
                 let mut headers = HashMap::new();
-                if let Some(h_val) = map.get(&MapKey::String("headers".to_string())).or_else(|| {
-                    map.get(&MapKey::Keyword(rtfs::ast::Keyword("headers".to_string())))
-                }) {
+                if let Some(h_val) = map
+                    .get(&MapKey::String("headers".to_string()))
+                    .or_else(|| map.get(&MapKey::Keyword(rtfs::ast::Keyword("headers".into()))))
+                {
                     if let Value::Map(h_map) = h_val {
                         for (k, v) in h_map {
                             let ks = crate::utils::value_conversion::map_key_to_string(k);
@@ -2289,7 +2445,7 @@ impl CapabilityMarketplace {
 
                 let body = if let Some(b_val) = map
                     .get(&MapKey::String("body".to_string()))
-                    .or_else(|| map.get(&MapKey::Keyword(rtfs::ast::Keyword("body".to_string()))))
+                    .or_else(|| map.get(&MapKey::Keyword(rtfs::ast::Keyword("body".into()))))
                 {
                     match b_val {
                         Value::String(s) => s.clone(),
@@ -2301,11 +2457,11 @@ impl CapabilityMarketplace {
                     // Treat other keys as body if "body" not explicitly provided
                     let mut body_map = map.clone();
                     body_map.remove(&MapKey::String("url".to_string()));
-                    body_map.remove(&MapKey::Keyword(rtfs::ast::Keyword("url".to_string())));
+                    body_map.remove(&MapKey::Keyword(rtfs::ast::Keyword("url".into())));
                     body_map.remove(&MapKey::String("method".to_string()));
-                    body_map.remove(&MapKey::Keyword(rtfs::ast::Keyword("method".to_string())));
+                    body_map.remove(&MapKey::Keyword(rtfs::ast::Keyword("method".into())));
                     body_map.remove(&MapKey::String("headers".to_string()));
-                    body_map.remove(&MapKey::Keyword(rtfs::ast::Keyword("headers".to_string())));
+                    body_map.remove(&MapKey::Keyword(rtfs::ast::Keyword("headers".into())));
 
                     if body_map.is_empty() {
                         "".to_string()
@@ -2322,7 +2478,7 @@ impl CapabilityMarketplace {
                 let args = match inputs {
                     Value::List(list) => list.clone(),
                     Value::Vector(vec) => vec.clone(),
-                    v => vec![v.clone()],
+                    v => vec![v.clone()].into(),
                 };
                 let url = args
                     .get(0)
@@ -2449,7 +2605,7 @@ impl CapabilityMarketplace {
         }
         response_map.insert(
             MapKey::String("headers".to_string()),
-            Value::Map(headers_map),
+            Value::Map(headers_map.into()),
         );
         let mut usage_map = std::collections::HashMap::new();
         usage_map.insert(
@@ -2460,9 +2616,9 @@ impl CapabilityMarketplace {
             MapKey::String("network_ingress_bytes".to_string()),
             Value::Integer(network_ingress_bytes as i64),
         );
-        response_map.insert(MapKey::String("usage".to_string()), Value::Map(usage_map));
+        response_map.insert(MapKey::String("usage".to_string()), Value::Map(usage_map.into()));
 
-        Ok(Value::Map(response_map))
+        Ok(Value::Map(response_map.into()))
     }
 
     pub async fn execute_with_validation(
@@ -2484,7 +2640,9 @@ impl CapabilityMarketplace {
         let capability = {
             let capabilities = self.capabilities.read().await;
             capabilities.get(capability_id).cloned().ok_or_else(|| {
-                RuntimeError::Generic(format!("Capability not found: {}", capability_id))
+                RuntimeError::CapabilityNotFound {
+                    capability_id: capability_id.to_string(),
+                }
             })?
         };
         let boundary_context = VerificationContext::capability_boundary(capability_id);
@@ -2570,13 +2728,13 @@ impl CapabilityMarketplace {
         let mut map = HashMap::new();
         for (key, value) in params {
             let map_key = if key.starts_with(':') {
-                MapKey::Keyword(rtfs::ast::Keyword(key[1..].to_string()))
+                MapKey::Keyword(rtfs::ast::Keyword(key[1..].into()))
             } else {
                 MapKey::String(key.clone())
             };
             map.insert(map_key, value.clone());
         }
-        Ok(Value::Map(map))
+        Ok(Value::Map(map.into()))
     }
 
     /// Convert JSON to RTFS Value (public API wrapper for backward compatibility)
@@ -12,7 +12,8 @@ use async_trait::async_trait;
 use chrono::Utc;
 use log::debug;
 use rtfs::ast::{
-    Expression, Keyword, Literal, MapKey, MapTypeEntry, PrimitiveType, Symbol, TopLevel, TypeExpr,
+    Expression, Keyword, Literal, MapKey, MapTypeEntry, PrimitiveType, Span, Symbol, TopLevel,
+    TypeExpr,
 };
 use rtfs::runtime::environment::Environment;
 use rtfs::runtime::error::{RuntimeError, RuntimeResult};
@@ -647,19 +648,19 @@ impl MCPDiscoveryProvider {
         let capability = Expression::Map(
             vec![
                 (
-                    MapKey::Keyword(Keyword("type".to_string())),
+                    MapKey::Keyword(Keyword("type".into())),
                     Expression::Literal(Literal::String("ccos.capability:v1".to_string())),
                 ),
                 (
-                    MapKey::Keyword(Keyword("id".to_string())),
+                    MapKey::Keyword(Keyword("id".into())),
                     Expression::Literal(Literal::String(capability_id.clone())),
                 ),
                 (
-                    MapKey::Keyword(Keyword("name".to_string())),
+                    MapKey::Keyword(Keyword("name".into())),
                     Expression::Literal(Literal::String(tool.name.clone())),
                 ),
                 (
-                    MapKey::Keyword(Keyword("description".to_string())),
+                    MapKey::Keyword(Keyword("description".into())),
                     Expression::Literal(Literal::String(
                         tool.description
                             .clone()
@@ -667,33 +668,33 @@ impl MCPDiscoveryProvider {
                     )),
                 ),
                 (
-                    MapKey::Keyword(Keyword("version".to_string())),
+                    MapKey::Keyword(Keyword("version".into())),
                     Expression::Literal(Literal::String("1.0.0".to_string())),
                 ),
                 (
-                    MapKey::Keyword(Keyword("provider".to_string())),
+                    MapKey::Keyword(Keyword("provider".into())),
                     Expression::Map(
                         vec![
                             (
-                                MapKey::Keyword(Keyword("type".to_string())),
+                                MapKey::Keyword(Keyword("type".into())),
                                 Expression::Literal(Literal::String("mcp".to_string())),
                             ),
                             (
-                                MapKey::Keyword(Keyword("server_endpoint".to_string())),
+                                MapKey::Keyword(Keyword("server_endpoint".into())),
                                 Expression::Literal(Literal::String(self.config.endpoint.clone())),
                             ),
                             (
-                                MapKey::Keyword(Keyword("tool_name".to_string())),
+                                MapKey::Keyword(Keyword("tool_name".into())),
                                 Expression::Literal(Literal::String(tool.name.clone())),
                             ),
                             (
-                                MapKey::Keyword(Keyword("timeout_seconds".to_string())),
+                                MapKey::Keyword(Keyword("timeout_seconds".into())),
                                 Expression::Literal(Literal::Integer(
                                     self.config.timeout_seconds as i64,
                                 )),
                             ),
                             (
-                                MapKey::Keyword(Keyword("protocol_version".to_string())),
+                                MapKey::Keyword(Keyword("protocol_version".into())),
                                 Expression::Literal(Literal::String(
                                     self.config.protocol_version.clone(),
                                 )),
@@ -704,13 +705,13 @@ impl MCPDiscoveryProvider {
                     ),
                 ),
                 (
-                    MapKey::Keyword(Keyword("permissions".to_string())),
+                    MapKey::Keyword(Keyword("permissions".into())),
                     Expression::Vector(vec![Expression::Literal(Literal::String(
                         "mcp:tool:execute".to_string(),
                     ))]),
                 ),
                 (
-                    MapKey::Keyword(Keyword("effects".to_string())),
+                    MapKey::Keyword(Keyword("effects".into())),
                     Expression::Vector(
                         effects
                             .iter()
@@ -719,32 +720,32 @@ impl MCPDiscoveryProvider {
                     ),
                 ),
                 (
-                    MapKey::Keyword(Keyword("metadata".to_string())),
+                    MapKey::Keyword(Keyword("metadata".into())),
                     Expression::Map(
                         vec![
                             (
-                                MapKey::Keyword(Keyword("mcp_server".to_string())),
+                                MapKey::Keyword(Keyword("mcp_server".into())),
                                 Expression::Literal(Literal::String(self.config.name.clone())),
                             ),
                             (
-                                MapKey::Keyword(Keyword("mcp_endpoint".to_string())),
+                                MapKey::Keyword(Keyword("mcp_endpoint".into())),
                                 Expression::Literal(Literal::String(self.config.endpoint.clone())),
                             ),
                             // Note: tool_name in metadata duplicates :name in the capability map,
                             // but is kept for introspection/debugging to clearly identify the MCP tool.
                             // The provider map also has tool_name, which is required for MCP protocol calls.
                             (
-                                MapKey::Keyword(Keyword("tool_name".to_string())),
+                                MapKey::Keyword(Keyword("tool_name".into())),
                                 Expression::Literal(Literal::String(tool.name.clone())),
                             ),
                             (
-                                MapKey::Keyword(Keyword("protocol_version".to_string())),
+                                MapKey::Keyword(Keyword("protocol_version".into())),
                                 Expression::Literal(Literal::String(
                                     self.config.protocol_version.clone(),
                                 )),
                             ),
                             (
-                                MapKey::Keyword(Keyword("introspected_at".to_string())),
+                                MapKey::Keyword(Keyword("introspected_at".into())),
                                 Expression::Literal(Literal::String(Utc::now().to_rfc3339())),
                             ),
                             // Note: ccos_effects removed - effects are already in the capability map
@@ -793,7 +794,7 @@ impl MCPDiscoveryProvider {
             if let Ok(parsed) = rtfs::parser::parse(&rtfs_text) {
                 if let Some(TopLevel::Expression(expr)) = parsed.first() {
                     capability_map.insert(
-                        MapKey::Keyword(Keyword("input-schema".to_string())),
+                        MapKey::Keyword(Keyword("input-schema".into())),
                         expr.clone(),
                     );
                     input_schema_added = true;
@@ -808,7 +809,7 @@ impl MCPDiscoveryProvider {
             if let Ok(parsed) = rtfs::parser::parse(&rtfs_text) {
                 if let Some(TopLevel::Expression(expr)) = parsed.first() {
                     capability_map.insert(
-                        MapKey::Keyword(Keyword("output-schema".to_string())),
+                        MapKey::Keyword(Keyword("output-schema".into())),
                         expr.clone(),
                     );
                     output_schema_added = true;
@@ -817,8 +818,8 @@ impl MCPDiscoveryProvider {
         } else {
             // Use :any as default when output schema is unknown
             capability_map.insert(
-                MapKey::Keyword(Keyword("output-schema".to_string())),
-                Expression::Literal(Literal::Keyword(Keyword("any".to_string()))),
+                MapKey::Keyword(Keyword("output-schema".into())),
+                Expression::Literal(Literal::Keyword(Keyword("any".into()))),
             );
             output_schema_added = true;
         }
@@ -999,8 +1000,8 @@ impl MCPDiscoveryProvider {
 
                     // The capability ID is stored in the name field of the definition
                     capability_map.insert(
-                        MapKey::Keyword(Keyword("id".to_string())),
-                        Expression::Literal(Literal::String(cap_def.name.0.clone())),
+                        MapKey::Keyword(Keyword("id".into())),
+                        Expression::Literal(Literal::String(cap_def.name.0.to_string())),
                     );
 
                     for prop in &cap_def.properties {
@@ -1010,11 +1011,11 @@ impl MCPDiscoveryProvider {
 
                     // Extract input/output schemas if present
                     let input_schema = capability_map
-                        .get(&MapKey::Keyword(Keyword("input-schema".to_string())))
+                        .get(&MapKey::Keyword(Keyword("input-schema".into())))
                         .and_then(|e| self.extract_type_expr(e));
 
                     let output_schema = capability_map
-                        .get(&MapKey::Keyword(Keyword("output-schema".to_string())))
+                        .get(&MapKey::Keyword(Keyword("output-schema".into())))
                         .and_then(|e| self.extract_type_expr(e));
 
                     capabilities.push(RTFSCapabilityDefinition {
@@ -1036,7 +1037,10 @@ impl MCPDiscoveryProvider {
                         }
                     }
                     // Handle FunctionCall with "do" as callee (alternative representation)
-                    else if let Expression::FunctionCall { callee, arguments } = expr {
+                    else if let Expression::FunctionCall {
+                        callee, arguments, ..
+                    } = expr
+                    {
                         if let Expression::Symbol(sym) = &**callee {
                             if sym.0 == "do" {
                                 // Handle (do ...) as a function call - capabilities are in arguments
@@ -1103,7 +1107,9 @@ impl MCPDiscoveryProvider {
     ) -> Option<RTFSCapabilityDefinition> {
         match expr {
             // Handle (capability "id" :key value ...) as a FunctionCall
-            Expression::FunctionCall { callee, arguments } => {
+            Expression::FunctionCall {
+                callee, arguments, ..
+            } => {
                 if let Expression::Symbol(sym) = &**callee {
                     if sym.0 == "capability" {
                         let mut items = Vec::with_capacity(arguments.len() + 1);
@@ -1144,7 +1150,7 @@ impl MCPDiscoveryProvider {
         // Parse key-value pairs into a map
         let mut capability_map = HashMap::new();
         capability_map.insert(
-            MapKey::Keyword(Keyword("id".to_string())),
+            MapKey::Keyword(Keyword("id".into())),
             Expression::Literal(Literal::String(capability_id)),
         );
 
@@ -1164,11 +1170,11 @@ impl MCPDiscoveryProvider {
 
         // Extract input/output schemas if present
         let input_schema = capability_map
-            .get(&MapKey::Keyword(Keyword("input-schema".to_string())))
+            .get(&MapKey::Keyword(Keyword("input-schema".into())))
             .and_then(|e| self.extract_type_expr(e));
 
         let output_schema = capability_map
-            .get(&MapKey::Keyword(Keyword("output-schema".to_string())))
+            .get(&MapKey::Keyword(Keyword("output-schema".into())))
             .and_then(|e| self.extract_type_expr(e));
 
         Ok(RTFSCapabilityDefinition {
@@ -1251,48 +1257,48 @@ impl MCPDiscoveryProvider {
         if let Expression::Map(map) = expr {
             // Extract module-type
             if let Some(Expression::Literal(Literal::String(s))) =
-                map.get(&MapKey::Keyword(Keyword("module-type".to_string())))
+                map.get(&MapKey::Keyword(Keyword("module-type".into())))
             {
                 module_type = s.clone();
             }
 
             // Extract generated-at
             if let Some(Expression::Literal(Literal::String(s))) =
-                map.get(&MapKey::Keyword(Keyword("generated-at".to_string())))
+                map.get(&MapKey::Keyword(Keyword("generated-at".into())))
             {
                 generated_at = s.clone();
             }
 
             // Extract server-config
             if let Some(Expression::Map(server_map)) =
-                map.get(&MapKey::Keyword(Keyword("server-config".to_string())))
+                map.get(&MapKey::Keyword(Keyword("server-config".into())))
             {
                 if let Some(Expression::Literal(Literal::String(s))) =
-                    server_map.get(&MapKey::Keyword(Keyword("name".to_string())))
+                    server_map.get(&MapKey::Keyword(Keyword("name".into())))
                 {
                     server_config.name = s.clone();
                 }
 
                 if let Some(Expression::Literal(Literal::String(s))) =
-                    server_map.get(&MapKey::Keyword(Keyword("endpoint".to_string())))
+                    server_map.get(&MapKey::Keyword(Keyword("endpoint".into())))
                 {
                     server_config.endpoint = s.clone();
                 }
 
                 if let Some(Expression::Literal(Literal::String(s))) =
-                    server_map.get(&MapKey::Keyword(Keyword("auth-token".to_string())))
+                    server_map.get(&MapKey::Keyword(Keyword("auth-token".into())))
                 {
                     server_config.auth_token = Some(s.clone());
                 }
 
                 if let Some(Expression::Literal(Literal::Integer(n))) =
-                    server_map.get(&MapKey::Keyword(Keyword("timeout-seconds".to_string())))
+                    server_map.get(&MapKey::Keyword(Keyword("timeout-seconds".into())))
                 {
                     server_config.timeout_seconds = *n as u64;
                 }
 
                 if let Some(Expression::Literal(Literal::String(s))) =
-                    server_map.get(&MapKey::Keyword(Keyword("protocol-version".to_string())))
+                    server_map.get(&MapKey::Keyword(Keyword("protocol-version".into())))
                 {
                     server_config.protocol_version = s.clone();
                 }
@@ -1300,7 +1306,7 @@ impl MCPDiscoveryProvider {
 
             // Extract capabilities array (can be List or Vector)
             let cap_list = map
-                .get(&MapKey::Keyword(Keyword("capabilities".to_string())))
+                .get(&MapKey::Keyword(Keyword("capabilities".into())))
                 .and_then(|expr| match expr {
                     Expression::List(list) => Some(list.as_slice()),
                     Expression::Vector(vec) => Some(vec.as_slice()),
@@ -1311,26 +1317,26 @@ impl MCPDiscoveryProvider {
                 for cap_expr in cap_list {
                     if let Expression::Map(cap_map) = cap_expr {
                         if let Some(capability_expr) =
-                            cap_map.get(&MapKey::Keyword(Keyword("capability".to_string())))
+                            cap_map.get(&MapKey::Keyword(Keyword("capability".into())))
                         {
                             // The :input-schema and :output-schema are INSIDE the :capability map,
                             // not as siblings to it. Extract from capability_expr if it's a Map.
                             let (input_schema, output_schema) =
                                 if let Expression::Map(inner_cap_map) = capability_expr {
                                     let input = inner_cap_map
-                                        .get(&MapKey::Keyword(Keyword("input-schema".to_string())))
+                                        .get(&MapKey::Keyword(Keyword("input-schema".into())))
                                         .and_then(|e| self.extract_type_expr(e));
                                     let output = inner_cap_map
-                                        .get(&MapKey::Keyword(Keyword("output-schema".to_string())))
+                                        .get(&MapKey::Keyword(Keyword("output-schema".into())))
                                         .and_then(|e| self.extract_type_expr(e));
                                     (input, output)
                                 } else {
                                     // Fall back to looking in outer cap_map for backwards compatibility
                                     let input = cap_map
-                                        .get(&MapKey::Keyword(Keyword("input-schema".to_string())))
+                                        .get(&MapKey::Keyword(Keyword("input-schema".into())))
                                         .and_then(|e| self.extract_type_expr(e));
                                     let output = cap_map
-                                        .get(&MapKey::Keyword(Keyword("output-schema".to_string())))
+                                        .get(&MapKey::Keyword(Keyword("output-schema".into())))
                                         .and_then(|e| self.extract_type_expr(e));
                                     (input, output)
                                 };
@@ -1448,7 +1454,7 @@ impl MCPDiscoveryProvider {
                     if let Some(end_quote) = content[value_start + 1..].find('"') {
                         let id_value = &content[value_start + 1..value_start + 1 + end_quote];
                         map.insert(
-                            MapKey::Keyword(Keyword("id".to_string())),
+                            MapKey::Keyword(Keyword("id".into())),
                             Expression::Literal(Literal::String(id_value.to_string())),
                         );
                     }
@@ -1465,7 +1471,7 @@ impl MCPDiscoveryProvider {
                     if let Some(end_quote) = content[value_start + 1..].find('"') {
                         let name_value = &content[value_start + 1..value_start + 1 + end_quote];
                         map.insert(
-                            MapKey::Keyword(Keyword("name".to_string())),
+                            MapKey::Keyword(Keyword("name".into())),
                             Expression::Literal(Literal::String(name_value.to_string())),
                         );
                     }
@@ -1482,7 +1488,7 @@ impl MCPDiscoveryProvider {
                     if let Some(end_quote) = content[value_start + 1..].find('"') {
                         let desc_value = &content[value_start + 1..value_start + 1 + end_quote];
                         map.insert(
-                            MapKey::Keyword(Keyword("description".to_string())),
+                            MapKey::Keyword(Keyword("description".into())),
                             Expression::Literal(Literal::String(desc_value.to_string())),
                         );
                     }
@@ -1499,7 +1505,7 @@ impl MCPDiscoveryProvider {
                     if let Some(end_quote) = content[value_start + 1..].find('"') {
                         let version_value = &content[value_start + 1..value_start + 1 + end_quote];
                         map.insert(
-                            MapKey::Keyword(Keyword("version".to_string())),
+                            MapKey::Keyword(Keyword("version".into())),
                             Expression::Literal(Literal::String(version_value.to_string())),
                         );
                     }
@@ -1510,27 +1516,27 @@ impl MCPDiscoveryProvider {
         // Create a basic provider structure
         let mut provider_map = HashMap::new();
         provider_map.insert(
-            MapKey::Keyword(Keyword("type".to_string())),
+            MapKey::Keyword(Keyword("type".into())),
             Expression::Literal(Literal::String("mcp".to_string())),
         );
         provider_map.insert(
-            MapKey::Keyword(Keyword("server_endpoint".to_string())),
+            MapKey::Keyword(Keyword("server_endpoint".into())),
             Expression::Literal(Literal::String("http://localhost:3000".to_string())),
         );
         provider_map.insert(
-            MapKey::Keyword(Keyword("tool_name".to_string())),
+            MapKey::Keyword(Keyword("tool_name".into())),
             Expression::Literal(Literal::String("echo".to_string())),
         ); // Default, will be overridden if we can parse it
         provider_map.insert(
-            MapKey::Keyword(Keyword("timeout_seconds".to_string())),
+            MapKey::Keyword(Keyword("timeout_seconds".into())),
             Expression::Literal(Literal::Integer(5)),
         );
         provider_map.insert(
-            MapKey::Keyword(Keyword("protocol_version".to_string())),
+            MapKey::Keyword(Keyword("protocol_version".into())),
             Expression::Literal(Literal::String("2024-11-05".to_string())),
         );
         map.insert(
-            MapKey::Keyword(Keyword("provider".to_string())),
+            MapKey::Keyword(Keyword("provider".into())),
             Expression::Map(provider_map),
         );
 
@@ -1539,34 +1545,34 @@ impl MCPDiscoveryProvider {
             "mcp:tool:execute".to_string(),
         ))];
         map.insert(
-            MapKey::Keyword(Keyword("permissions".to_string())),
+            MapKey::Keyword(Keyword("permissions".into())),
             Expression::Vector(permissions),
         );
 
         // For metadata, create a basic structure
         let mut metadata_map = HashMap::new();
         metadata_map.insert(
-            MapKey::Keyword(Keyword("mcp_server".to_string())),
+            MapKey::Keyword(Keyword("mcp_server".into())),
             Expression::Literal(Literal::String("demo_server".to_string())),
         );
         metadata_map.insert(
-            MapKey::Keyword(Keyword("mcp_endpoint".to_string())),
+            MapKey::Keyword(Keyword("mcp_endpoint".into())),
             Expression::Literal(Literal::String("http://localhost:3000".to_string())),
         );
         metadata_map.insert(
-            MapKey::Keyword(Keyword("tool_name".to_string())),
+            MapKey::Keyword(Keyword("tool_name".into())),
             Expression::Literal(Literal::String("echo".to_string())),
         ); // Default
         metadata_map.insert(
-            MapKey::Keyword(Keyword("protocol_version".to_string())),
+            MapKey::Keyword(Keyword("protocol_version".into())),
             Expression::Literal(Literal::String("2024-11-05".to_string())),
         );
         metadata_map.insert(
-            MapKey::Keyword(Keyword("introspected_at".to_string())),
+            MapKey::Keyword(Keyword("introspected_at".into())),
             Expression::Literal(Literal::String(Utc::now().to_rfc3339())),
         );
         map.insert(
-            MapKey::Keyword(Keyword("metadata".to_string())),
+            MapKey::Keyword(Keyword("metadata".into())),
             Expression::Map(metadata_map),
         );
 
@@ -1592,7 +1598,7 @@ impl MCPDiscoveryProvider {
                 Literal::Uuid(uuid) => format!("\"{}\"", uuid),
                 Literal::ResourceHandle(handle) => format!("\"{}\"", handle),
             },
-            Expression::Symbol(sym) => sym.0.clone(),
+            Expression::Symbol(sym) => sym.0.to_string(),
             Expression::List(items) => {
                 if items.is_empty() {
                     "()".to_string()
@@ -1641,7 +1647,9 @@ impl MCPDiscoveryProvider {
                     format!("{{\n{}\n{}}}", entries_str.join("\n"), map_indent)
                 }
             }
-            Expression::FunctionCall { callee, arguments } => {
+            Expression::FunctionCall {
+                callee, arguments, ..
+            } => {
                 let callee_str = self.expression_to_rtfs_text(callee, 0);
                 let args_str: Vec<String> = arguments
                     .iter()
@@ -1845,7 +1853,7 @@ impl MCPDiscoveryProvider {
                             // We'll handle this in the conversion step
                             let mut synthetic_map = std::collections::HashMap::new();
                             synthetic_map.insert(
-                                MapKey::Keyword(Keyword("provider_type".to_string())),
+                                MapKey::Keyword(Keyword("provider_type".into())),
                                 Expression::Literal(Literal::String(provider_str.clone())),
                             );
                             provider_info = Some(synthetic_map);
@@ -1869,7 +1877,7 @@ impl MCPDiscoveryProvider {
                         for effect in effect_vec {
                             let effect_str = match effect {
                                 Expression::Literal(Literal::String(s)) => Some(s.clone()),
-                                Expression::Literal(Literal::Keyword(k)) => Some(k.0.clone()),
+                                Expression::Literal(Literal::Keyword(k)) => Some(k.0.to_string()),
                                 _ => None,
                             };
 
@@ -1911,7 +1919,7 @@ impl MCPDiscoveryProvider {
                                     if k.0 == "ccos_effects" {
                                         serialized_effects = Some(v.clone());
                                     }
-                                    metadata.insert(k.0.clone(), v.clone());
+                                    metadata.insert(k.0.to_string(), v.clone());
                                 }
                                 (MapKey::Keyword(k), Expression::Map(nested_map))
                                     if k.0 == "openapi" =>
@@ -2024,11 +2032,11 @@ impl MCPDiscoveryProvider {
                     if let Some(stdlib) = module_registry.get_module("stdlib") {
                         if let Ok(exports) = stdlib.exports.read() {
                             for (name, export) in exports.iter() {
-                                env.define(&Symbol(name.clone()), export.value.clone());
+                                env.define(&Symbol::new(name.as_str()), export.value.clone());
                             }
                         }
                     }
-                    env.define(&Symbol("input".to_string()), inputs.clone());
+                    env.define(&Symbol("input".into()), inputs.clone());
 
                     let host = (rtfs_host_factory)();
                     // Ensure the host has a minimal execution context to avoid fatal errors
@@ -2049,7 +2057,8 @@ impl MCPDiscoveryProvider {
 
                     let call_expr = Expression::FunctionCall {
                         callee: Box::new(impl_expr_cloned.clone()),
-                        arguments: vec![Expression::Symbol(Symbol("input".to_string()))],
+                        arguments: vec![Expression::Symbol(Symbol("input".into()))],
+                        span: Span::unknown(),
                     };
 
                     runtime.run(&call_expr)
@@ -2125,7 +2134,9 @@ impl MCPDiscoveryProvider {
                                 .find(|(k, _)| matches!(k, MapKey::Keyword(kw) if kw.0 == key))
                                 .and_then(|(_, v)| match v {
                                     Expression::Literal(Literal::String(s)) => Some(s.clone()),
-                                    Expression::Literal(Literal::Keyword(k)) => Some(k.0.clone()),
+                                    Expression::Literal(Literal::Keyword(k)) => {
+                                        Some(k.0.to_string())
+                                    }
                                     _ => None,
                                 })
                         };
@@ -2213,12 +2224,12 @@ impl MCPDiscoveryProvider {
         // Convert input/output schemas - check capability map if struct fields are None
         let mut input_schema = rtfs_def.input_schema.clone().or_else(|| {
             cap_map
-                .get(&MapKey::Keyword(Keyword("input-schema".to_string())))
+                .get(&MapKey::Keyword(Keyword("input-schema".into())))
                 .and_then(|e| self.extract_type_expr(e))
         });
         let mut output_schema = rtfs_def.output_schema.clone().or_else(|| {
             cap_map
-                .get(&MapKey::Keyword(Keyword("output-schema".to_string())))
+                .get(&MapKey::Keyword(Keyword("output-schema".into())))
                 .and_then(|e| self.extract_type_expr(e))
         });
 
@@ -2402,7 +2413,7 @@ impl MCPDiscoveryProvider {
         if let Some(enum_values) = schema.get("enum") {
             if let Some(arr) = enum_values.as_array() {
                 let mut variants = vec![Expression::Literal(Literal::Keyword(Keyword(
-                    "enum".to_string(),
+                    "enum".into(),
                 )))];
                 for val in arr {
                     if let Some(s) = val.as_str() {
@@ -2440,11 +2451,11 @@ impl MCPDiscoveryProvider {
                                     if !required_fields.contains(key) {
                                         rtfs_type = Expression::Vector(vec![
                                             Expression::Literal(Literal::Keyword(Keyword(
-                                                "union".to_string(),
+                                                "union".into(),
                                             ))),
                                             rtfs_type,
                                             Expression::Literal(Literal::Keyword(Keyword(
-                                                "nil".to_string(),
+                                                "nil".into(),
                                             ))),
                                         ]);
                                     }
@@ -2459,41 +2470,33 @@ impl MCPDiscoveryProvider {
                     if let Some(items) = schema.get("items") {
                         if let Ok(item_type) = self.convert_json_schema_to_rtfs(items) {
                             Ok(Expression::Vector(vec![
-                                Expression::Literal(Literal::Keyword(Keyword(
-                                    "vector".to_string(),
-                                ))),
+                                Expression::Literal(Literal::Keyword(Keyword("vector".into()))),
                                 item_type,
                             ]))
                         } else {
                             Ok(Expression::Vector(vec![Expression::Literal(
-                                Literal::Keyword(Keyword("vector".to_string())),
+                                Literal::Keyword(Keyword("vector".into())),
                             )]))
                         }
                     } else {
                         Ok(Expression::Vector(vec![Expression::Literal(
-                            Literal::Keyword(Keyword("vector".to_string())),
+                            Literal::Keyword(Keyword("vector".into())),
                         )]))
                     }
                 }
                 "string" => Ok(Expression::Literal(Literal::Keyword(Keyword(
-                    "string".to_string(),
+                    "string".into(),
                 )))),
                 "number" => Ok(Expression::Literal(Literal::Keyword(Keyword(
-                    "float".to_string(),
-                )))),
-                "integer" => Ok(Expression::Literal(Literal::Keyword(Keyword(
-                    "int".to_string(),
+                    "float".into(),
                 )))),
+                "integer" => Ok(Expression::Literal(Literal::Keyword(Keyword("int".into())))),
                 "boolean" => Ok(Expression::Literal(Literal::Keyword(Keyword(
-                    "bool".to_string(),
-                )))),
-                _ => Ok(Expression::Literal(Literal::Keyword(Keyword(
-                    "any".to_string(),
+                    "bool".into(),
                 )))),
+                _ => Ok(Expression::Literal(Literal::Keyword(Keyword("any".into())))),
             },
-            _ => Ok(Expression::Literal(Literal::Keyword(Keyword(
-                "any".to_string(),
-            )))),
+            _ => Ok(Expression::Literal(Literal::Keyword(Keyword("any".into())))),
         }
     }
 
@@ -2510,7 +2513,7 @@ impl MCPDiscoveryProvider {
             "boolean" | "bool" => Ok(TypeExpr::Primitive(PrimitiveType::Bool)),
             "any" => Ok(TypeExpr::Any),
             _ => Ok(TypeExpr::Primitive(PrimitiveType::Custom(Keyword(
-                s.to_string(),
+                s.into(),
             )))),
         }
     }
@@ -2528,7 +2531,7 @@ impl MCPDiscoveryProvider {
                     if let MapKey::String(key_str) = key {
                         let value_type = self.convert_rtfs_to_type_expr(value)?;
                         entries.push(MapTypeEntry {
-                            key: Keyword(key_str.clone()),
+                            key: Keyword::new(key_str.as_str()),
                             value_type: Box::new(value_type),
                             optional: false, // Default to required for now
                         });
@@ -3011,9 +3014,9 @@ mod tests {
         match &rtfs_cap.capability {
             Expression::Map(map) => {
                 let has_input_schema =
-                    map.contains_key(&MapKey::Keyword(Keyword("input-schema".to_string())));
+                    map.contains_key(&MapKey::Keyword(Keyword("input-schema".into())));
                 let has_output_schema =
-                    map.contains_key(&MapKey::Keyword(Keyword("output-schema".to_string())));
+                    map.contains_key(&MapKey::Keyword(Keyword("output-schema".into())));
                 assert!(
                     has_input_schema,
                     "RTFS capability missing input-schema in map"
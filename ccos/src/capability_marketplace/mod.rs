@@ -1,6 +1,8 @@
+pub mod builder;
 pub mod config_mcp_discovery;
 pub mod discovery;
 pub mod executors;
+pub mod health;
 pub mod marketplace;
 pub mod mcp_discovery;
 pub mod resource_monitor;
@@ -8,6 +10,7 @@ pub mod types;
 pub mod version_store;
 pub mod versioning;
 
+pub use builder::CapabilityBuilderExt;
 // Export types and CapabilityMarketplace struct (but not its impl from types.rs)
 pub use types::{
     CapabilityAttestation, CapabilityDiscovery, CapabilityExecutor, CapabilityIsolationPolicy,
@@ -337,6 +337,106 @@ impl CapabilityManifest {
             .unwrap_or(false)
     }
 
+    /// Check if results of this capability may be memoized within a plan run.
+    /// Only capabilities explicitly registered as `EffectType::Pure` qualify.
+    pub fn is_cacheable(&self) -> bool {
+        self.effect_type == EffectType::Pure
+    }
+
+    /// Declare argument positions and/or map keys whose values must be redacted
+    /// in Causal Chain records and logs (see `security_policies::ArgRedactionPolicy`)
+    pub fn with_sensitive_args<I, S>(mut self, args: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let spec = args
+            .into_iter()
+            .map(Into::into)
+            .collect::<Vec<_>>()
+            .join(",");
+        self.metadata.insert(
+            crate::security_policies::SENSITIVE_ARGS_METADATA_KEY.to_string(),
+            spec,
+        );
+        self
+    }
+
+    /// Declare that the capability takes named parameters: `(call :cap {:a 1 :b 2})`
+    /// is delivered as that map, checked against these names (see
+    /// `arg_normalization::spread_named_args`). Without it args stay positional.
+    pub fn with_named_params<I, S>(mut self, params: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let spec = params
+            .into_iter()
+            .map(Into::into)
+            .collect::<Vec<_>>()
+            .join(",");
+        self.metadata.insert(
+            crate::capabilities::arg_normalization::NAMED_PARAMS_METADATA_KEY.to_string(),
+            spec,
+        );
+        self
+    }
+
+    /// Named parameters declared with `with_named_params`, if any
+    pub fn named_params(&self) -> Option<Vec<String>> {
+        self.metadata
+            .get(crate::capabilities::arg_normalization::NAMED_PARAMS_METADATA_KEY)
+            .map(|spec| {
+                spec.split(',')
+                    .map(str::trim)
+                    .filter(|p| !p.is_empty())
+                    .map(str::to_string)
+                    .collect()
+            })
+    }
+
+    /// Declare that the capability returns JSON text (for HTTP-style results, a
+    /// JSON `"body"`). The marketplace parses it before returning, turning the
+    /// keys the output schema declares into keywords (see
+    /// `output_decoding::decode_json_output`).
+    pub fn with_json_output(mut self) -> Self {
+        self.metadata.insert(
+            crate::capabilities::output_decoding::JSON_OUTPUT_METADATA_KEY.to_string(),
+            "true".to_string(),
+        );
+        self
+    }
+
+    /// Whether the capability declared JSON output with `with_json_output`
+    pub fn has_json_output(&self) -> bool {
+        self.metadata
+            .get(crate::capabilities::output_decoding::JSON_OUTPUT_METADATA_KEY)
+            .is_some_and(|v| v == "true")
+    }
+
+    /// Mark the capability as memorable: successful results are captured into
+    /// Working Memory with the given extra tags (see `wm_integration::CapabilityResultSink`)
+    pub fn with_memory_capture<I, S>(mut self, tags: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let tags = tags
+            .into_iter()
+            .map(Into::into)
+            .collect::<Vec<_>>()
+            .join(",");
+        self.metadata.insert(
+            crate::wm_integration::MEMORABLE_METADATA_KEY.to_string(),
+            "true".to_string(),
+        );
+        self.metadata.insert(
+            crate::wm_integration::MEMORY_TAGS_METADATA_KEY.to_string(),
+            tags,
+        );
+        self
+    }
+
     /// Set agent metadata for this capability
     pub fn with_agent_metadata(mut self, metadata: AgentMetadata) -> Self {
         self.agent_metadata = Some(metadata);
@@ -1452,6 +1552,8 @@ pub struct CapabilityMarketplace {
     /// Optional factory to create a Host for RTFS capability execution (defaults to PureHost)
     pub(crate) rtfs_host_factory:
         Arc<StdRwLock<Option<Arc<dyn Fn() -> Arc<dyn HostInterface + Send + Sync> + Send + Sync>>>>,
+    /// Health and circuit breaker state for remote capabilities
+    pub(crate) health: Arc<super::health::CapabilityHealthRegistry>,
 }
 
 /// Trait for capability discovery providers
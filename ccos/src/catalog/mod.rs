@@ -117,6 +117,82 @@ impl CatalogFilter {
     }
 }
 
+/// Structured query for [`CatalogService::search`]
+///
+/// `text` is matched case-insensitively as a substring of the entry name, id and
+/// description; every tag in `tags` must be present on the entry.
+#[derive(Clone, Debug, Default)]
+pub struct CatalogQuery {
+    pub text: Option<String>,
+    pub tags: Vec<String>,
+    pub filter: CatalogFilter,
+    /// Maximum number of results (0 = unlimited)
+    pub limit: usize,
+}
+
+impl CatalogQuery {
+    /// Create a query matching `text` against name, id and description
+    pub fn text(text: impl Into<String>) -> Self {
+        Self {
+            text: Some(text.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Require a tag on every result
+    pub fn with_tag(mut self, tag: impl Into<String>) -> Self {
+        self.tags.push(tag.into());
+        self
+    }
+
+    /// Restrict results to entries accepted by `filter`
+    pub fn with_filter(mut self, filter: CatalogFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    /// Rank of `entry` for this query, or None when it does not match.
+    /// Name matches outrank id matches, which outrank description matches.
+    fn rank(&self, entry: &CatalogEntry) -> Option<u8> {
+        if !self.filter.matches(entry) {
+            return None;
+        }
+        let has_tags = self.tags.iter().all(|tag| {
+            entry
+                .tags
+                .iter()
+                .any(|entry_tag| entry_tag.eq_ignore_ascii_case(tag))
+        });
+        if !has_tags {
+            return None;
+        }
+
+        let needle = match self.text.as_deref().map(str::trim) {
+            Some(text) if !text.is_empty() => text.to_lowercase(),
+            _ => return Some(0),
+        };
+        let contains =
+            |field: Option<&str>| field.is_some_and(|value| value.to_lowercase().contains(&needle));
+        let name = entry.name.as_deref();
+        if name.is_some_and(|n| n.to_lowercase() == needle) {
+            Some(4)
+        } else if contains(name) {
+            Some(3)
+        } else if contains(Some(&entry.id)) {
+            Some(2)
+        } else if contains(entry.description.as_deref()) {
+            Some(1)
+        } else {
+            None
+        }
+    }
+}
+
 /// A catalog entry describing a plan or capability
 #[derive(Clone, Debug)]
 pub struct CatalogEntry {
@@ -560,6 +636,21 @@ impl CatalogService {
         hits
     }
 
+    /// Substring and tag search, ranked by where the text matched (name, id,
+    /// then description) and then by id for a stable order
+    pub fn search(&self, query: CatalogQuery) -> Vec<CatalogEntry> {
+        let entries = self.entries.read().expect("catalog entries poisoned");
+        let mut ranked: Vec<(u8, &CatalogEntry)> = entries
+            .values()
+            .filter_map(|entry| query.rank(entry).map(|rank| (rank, entry)))
+            .collect();
+        ranked.sort_by(|(rank_a, a), (rank_b, b)| rank_b.cmp(rank_a).then_with(|| a.id.cmp(&b.id)));
+        if query.limit > 0 {
+            ranked.truncate(query.limit);
+        }
+        ranked.into_iter().map(|(_, entry)| entry.clone()).collect()
+    }
+
     /// Semantic search using embeddings
     pub async fn search_semantic(
         &self,
@@ -702,8 +793,8 @@ fn extract_schema_from_value(value: &Value) -> Vec<String> {
     match value {
         Value::Map(map) => map.keys().map(map_key_to_string).collect::<Vec<_>>(),
         Value::Vector(items) => items.iter().flat_map(extract_schema_from_value).collect(),
-        Value::Keyword(k) => vec![k.0.clone()],
-        Value::Symbol(sym) => vec![sym.0.clone()],
+        Value::Keyword(k) => vec![k.0.to_string()],
+        Value::Symbol(sym) => vec![sym.0.to_string()],
         _ => Vec::new(),
     }
 }
@@ -712,7 +803,7 @@ fn extract_schema_fields(schema: Option<&TypeExpr>) -> Vec<String> {
     fn walk(expr: &TypeExpr, acc: &mut Vec<String>) {
         match expr {
             TypeExpr::Primitive(p) => acc.push(format!("{:?}", p)),
-            TypeExpr::Alias(sym) => acc.push(sym.0.clone()),
+            TypeExpr::Alias(sym) => acc.push(sym.0.to_string()),
             TypeExpr::Literal(lit) => acc.push(format!("{:?}", lit)),
             TypeExpr::Vector(inner) | TypeExpr::Optional(inner) => walk(inner, acc),
             TypeExpr::Array { element_type, .. } => walk(element_type, acc),
@@ -726,7 +817,7 @@ fn extract_schema_fields(schema: Option<&TypeExpr>) -> Vec<String> {
                     key, value_type, ..
                 } in entries
                 {
-                    acc.push(key.0.clone());
+                    acc.push(key.0.to_string());
                     walk(value_type, acc);
                 }
                 if let Some(wild) = wildcard {
@@ -828,7 +919,7 @@ fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
 
 fn map_key_to_string(key: &MapKey) -> String {
     match key {
-        MapKey::Keyword(k) => k.0.clone(),
+        MapKey::Keyword(k) => k.0.to_string(),
         MapKey::String(s) => s.clone(),
         MapKey::Integer(i) => i.to_string(),
     }
@@ -837,8 +928,8 @@ fn map_key_to_string(key: &MapKey) -> String {
 fn value_to_string(value: &Value) -> Option<String> {
     match value {
         Value::String(s) => Some(s.clone()),
-        Value::Keyword(k) => Some(k.0.clone()),
-        Value::Symbol(sym) => Some(sym.0.clone()),
+        Value::Keyword(k) => Some(k.0.to_string()),
+        Value::Symbol(sym) => Some(sym.0.to_string()),
         Value::Integer(i) => Some(i.to_string()),
         Value::Float(f) => Some(f.to_string()),
         Value::Boolean(b) => Some(b.to_string()),
@@ -894,4 +985,93 @@ mod tests {
         }
         assert!(!hits.is_empty(), "expected at least one skill hit");
     }
+
+    async fn seeded_catalog() -> CatalogService {
+        let catalog = CatalogService::new();
+        for (id, name, description, tags) in [
+            (
+                "github-issues",
+                "GitHub Issues",
+                "List issues of a repository",
+                vec!["github", "read"],
+            ),
+            (
+                "github-pr",
+                "Open Pull Request",
+                "Create a GitHub pull request",
+                vec!["github", "write"],
+            ),
+            (
+                "weather",
+                "Weather",
+                "Current weather for a city",
+                vec!["http", "read"],
+            ),
+            (
+                "issue-tracker",
+                "Tracker",
+                "Sync issues from Jira",
+                vec!["jira", "read"],
+            ),
+        ] {
+            catalog
+                .register_skill(
+                    id.to_string(),
+                    Some(name.to_string()),
+                    Some(description.to_string()),
+                    None,
+                    tags.into_iter().map(String::from).collect(),
+                    CatalogSource::User,
+                )
+                .await;
+        }
+        catalog
+    }
+
+    fn ids(entries: &[CatalogEntry]) -> Vec<&str> {
+        entries.iter().map(|e| e.id.as_str()).collect()
+    }
+
+    #[tokio::test]
+    async fn test_search_substring_ranks_name_before_description() {
+        let catalog = seeded_catalog().await;
+
+        let hits = catalog.search(CatalogQuery::text("ISSUE"));
+        assert_eq!(ids(&hits), vec!["github-issues", "issue-tracker"]);
+
+        let hits = catalog.search(CatalogQuery::text("github"));
+        assert_eq!(ids(&hits), vec!["github-issues", "github-pr"]);
+
+        assert!(catalog.search(CatalogQuery::text("kubernetes")).is_empty());
+        assert_eq!(
+            catalog
+                .search(CatalogQuery::text("github").with_limit(1))
+                .len(),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn test_search_tag_filters() {
+        let catalog = seeded_catalog().await;
+
+        let hits = catalog.search(CatalogQuery::default().with_tag("read"));
+        assert_eq!(
+            ids(&hits),
+            vec!["github-issues", "issue-tracker", "weather"]
+        );
+
+        let hits = catalog.search(CatalogQuery::default().with_tag("github").with_tag("write"));
+        assert_eq!(ids(&hits), vec!["github-pr"]);
+
+        let hits = catalog.search(CatalogQuery::text("issues").with_tag("jira"));
+        assert_eq!(ids(&hits), vec!["issue-tracker"]);
+
+        let hits = catalog.search(
+            CatalogQuery::default()
+                .with_tag("read")
+                .with_filter(CatalogFilter::for_kind(CatalogEntryKind::Plan)),
+        );
+        assert!(hits.is_empty());
+    }
 }
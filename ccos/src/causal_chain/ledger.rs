@@ -1,8 +1,10 @@
 use super::super::types::{Action, ActionId, ActionType, CapabilityId, ExecutionResult, IntentId, PlanId};
+use super::CausalQuery;
 use crate::utils::value_conversion::{json_to_rtfs_value, rtfs_value_to_json};
 use rtfs::runtime::error::RuntimeError;
 use rtfs::runtime::values::Value;
-use rusqlite::{params, Connection};
+use rusqlite::types::Value as SqlValue;
+use rusqlite::{params, params_from_iter, Connection};
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 use std::collections::HashMap;
@@ -191,6 +193,78 @@ fn load_session_rows(conn: &Connection, session_id: &str) -> Result<Vec<DbRow>,
     .map_err(|e| RuntimeError::Generic(format!("Failed to load session rows: {}", e)))
 }
 
+/// Load the rows matching the SQL-expressible filters of `query`, in
+/// insertion order. `run_id` lives in the `data` JSON blob and is left to the
+/// caller.
+fn query_rows(conn: &Connection, query: &CausalQuery) -> Result<Vec<DbRow>, RuntimeError> {
+    let mut clauses: Vec<&str> = Vec::new();
+    let mut args: Vec<SqlValue> = Vec::new();
+    let text = |s: &str| SqlValue::Text(s.to_string());
+
+    if let Some(ref intent_id) = query.intent_id {
+        clauses.push("intent_id = ?");
+        args.push(text(intent_id));
+    }
+    if let Some(ref plan_id) = query.plan_id {
+        clauses.push("plan_id = ?");
+        args.push(text(plan_id));
+    }
+    if let Some(ref action_type) = query.action_type {
+        clauses.push("action_type = ?");
+        args.push(text(action_type_to_str(action_type)));
+    }
+    if let Some((start, end)) = query.time_range {
+        clauses.push("timestamp BETWEEN ? AND ?");
+        args.push(SqlValue::Integer(start as i64));
+        args.push(SqlValue::Integer(end as i64));
+    }
+    if let Some(ref parent_id) = query.parent_action_id {
+        clauses.push("parent_action_id = ?");
+        args.push(text(parent_id));
+    }
+    if let Some(ref prefix) = query.function_prefix {
+        // substr rather than LIKE so '%' and '_' in the prefix match literally
+        clauses.push("substr(coalesce(function_name, ''), 1, ?) = ?");
+        args.push(SqlValue::Integer(prefix.chars().count() as i64));
+        args.push(text(prefix));
+    }
+    if let Some(ref session_id) = query.session_id {
+        clauses.push("session_id = ?");
+        args.push(text(session_id));
+    }
+
+    let mut sql = "SELECT action_id, action_type, plan_id, intent_id, session_id, \
+                   parent_action_id, function_name, timestamp, data, chain_hash \
+                   FROM causal_chain"
+        .to_string();
+    if !clauses.is_empty() {
+        sql.push_str(" WHERE ");
+        sql.push_str(&clauses.join(" AND "));
+    }
+    sql.push_str(" ORDER BY id ASC");
+
+    let mut stmt = conn
+        .prepare(&sql)
+        .map_err(|e| RuntimeError::Generic(format!("Failed to prepare SELECT: {}", e)))?;
+
+    stmt.query_map(params_from_iter(args), |row| {
+        Ok((
+            row.get::<_, String>(0)?,
+            row.get::<_, String>(1)?,
+            row.get::<_, Option<String>>(2)?,
+            row.get::<_, Option<String>>(3)?,
+            row.get::<_, Option<String>>(4)?,
+            row.get::<_, Option<String>>(5)?,
+            row.get::<_, Option<String>>(6)?,
+            row.get::<_, i64>(7)?,
+            row.get::<_, String>(8)?,
+            row.get::<_, String>(9)?,
+        ))
+    })
+    .and_then(|mapped| mapped.collect::<Result<Vec<_>, _>>())
+    .map_err(|e| RuntimeError::Generic(format!("Failed to query causal chain rows: {}", e)))
+}
+
 fn action_to_data_json(action: &Action) -> Result<String, RuntimeError> {
     let arguments = action
         .arguments
@@ -631,6 +705,56 @@ impl ImmutableLedger {
     // Queries
     // ------------------------------------------------------------------
 
+    /// Run `query` against the full persisted history, filtering in SQL.
+    ///
+    /// Unlike the in-memory queries this also sees sessions that are not
+    /// loaded. Returns `None` for a pure in-memory ledger.
+    pub fn query_db(&self, query: &CausalQuery) -> Result<Option<Vec<Action>>, RuntimeError> {
+        let Some(db) = self.conn.as_ref() else {
+            return Ok(None);
+        };
+        let rows = {
+            let conn = db.0.lock().map_err(|e| {
+                RuntimeError::Generic(format!("Failed to acquire SQLite lock: {}", e))
+            })?;
+            query_rows(&conn, query)?
+        };
+
+        let mut actions = Vec::with_capacity(rows.len());
+        for (
+            action_id,
+            action_type_str,
+            plan_id,
+            intent_id,
+            session_id,
+            parent_action_id,
+            function_name,
+            timestamp,
+            data_json,
+            _chain_hash,
+        ) in rows
+        {
+            let action = action_from_row(
+                action_id,
+                action_type_str,
+                plan_id,
+                intent_id,
+                session_id,
+                parent_action_id,
+                function_name,
+                timestamp,
+                data_json,
+            )?;
+            if let Some(ref run_id) = query.run_id {
+                if action.metadata.get("run_id").and_then(|v| v.as_string()) != Some(run_id) {
+                    continue;
+                }
+            }
+            actions.push(action);
+        }
+        Ok(Some(actions))
+    }
+
     /// Get children actions for a given parent_action_id
     pub fn get_children(&self, parent_id: &ActionId) -> Vec<&Action> {
         self.indices
@@ -134,6 +134,20 @@ impl CausalChain {
         actions
     }
 
+    /// Like `query_actions`, but over the full persisted history when the chain
+    /// is SQLite-backed: the filters run in SQL (on indexed columns) and
+    /// sessions that are not loaded in memory are included. Falls back to the
+    /// in-memory working set for an in-memory chain.
+    pub fn query_persisted_actions(
+        &self,
+        query: &CausalQuery,
+    ) -> Result<Vec<Action>, RuntimeError> {
+        match self.ledger.query_db(query)? {
+            Some(actions) => Ok(actions),
+            None => Ok(self.query_actions(query).into_iter().cloned().collect()),
+        }
+    }
+
     /// Get children actions for a given parent_action_id
     pub fn get_children(&self, parent_id: &ActionId) -> Vec<&Action> {
         self.ledger.get_children(parent_id)
@@ -286,7 +300,7 @@ impl CausalChain {
 
             // Preserve correlation metadata from the call (e.g. run_id/step_id),
             // so result records can be queried without joining to their parent call.
-            for key in ["run_id", "step_id"] {
+            for key in ["run_id", "step_id", "correlation_id"] {
                 if let Some(value) = action.metadata.get(key) {
                     result_action
                         .metadata
@@ -1419,4 +1433,64 @@ mod tests {
             run_id
         );
     }
+
+    #[test]
+    fn test_query_persisted_actions_filters_in_sql_across_sessions() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().join("chain.db");
+        {
+            let mut chain = CausalChain::load_from_db(&db_path).unwrap();
+            let call = |session: &str, intent: &str, name: &str| {
+                Action::new_capability(None, Some(intent.to_string()), name, &[])
+                    .with_session(session)
+            };
+            chain
+                .append(&call("s1", "intent-a", "ccos.io.read"))
+                .unwrap();
+            chain
+                .append(&call("s1", "intent-b", "ccos.io.write").with_metadata("run_id", "run-1"))
+                .unwrap();
+            chain
+                .append(&call("s2", "intent-a", "ccos.net.get"))
+                .unwrap();
+            chain
+                .append(&Action::new_system(ActionType::PlanStarted).with_session("s2"))
+                .unwrap();
+        }
+
+        // Reopened chain: nothing is loaded in memory, the DB still answers
+        let chain = CausalChain::load_from_db(&db_path).unwrap();
+        assert!(chain.query_actions(&CausalQuery::new()).is_empty());
+
+        let by_intent = chain
+            .query_persisted_actions(&CausalQuery {
+                intent_id: Some("intent-a".to_string()),
+                ..Default::default()
+            })
+            .unwrap();
+        let names: Vec<_> = by_intent
+            .iter()
+            .map(|a| a.function_name.clone().unwrap())
+            .collect();
+        assert_eq!(names, vec!["ccos.io.read", "ccos.net.get"]);
+
+        let io_in_s1 = chain
+            .query_persisted_actions(&CausalQuery {
+                session_id: Some("s1".to_string()),
+                function_prefix: Some("ccos.io.".to_string()),
+                action_type: Some(ActionType::CapabilityCall),
+                ..Default::default()
+            })
+            .unwrap();
+        assert_eq!(io_in_s1.len(), 2);
+
+        let by_run = chain
+            .query_persisted_actions(&CausalQuery {
+                run_id: Some("run-1".to_string()),
+                ..Default::default()
+            })
+            .unwrap();
+        assert_eq!(by_run.len(), 1);
+        assert_eq!(by_run[0].intent_id.as_deref(), Some("intent-b"));
+    }
 }
@@ -41,6 +41,7 @@ use rtfs::runtime::host_interface::HostInterface;
 
 use once_cell::sync::Lazy;
 
+use crate::observability::llm_usage::LlmUsageTracker;
 use crate::orchestrator::Orchestrator;
 use crate::plan_archive::PlanArchive;
 
@@ -65,6 +66,8 @@ pub struct CCOS {
     /// Optional debug callback for emitting lifecycle JSON lines (plan generation, execution etc.)
     pub debug_callback: Option<Arc<dyn Fn(String) + Send + Sync>>,
     pub catalog: Arc<CatalogService>,
+    /// LLM token and cost totals accumulated over this run
+    pub llm_usage: LlmUsageTracker,
 }
 
 // tests moved to bottom module
@@ -393,6 +396,16 @@ impl CCOS {
             agent_config.governance.policies.clone(),
         ));
 
+        // Register plan composition (ccos.plan.invoke); child plans are validated by the
+        // kernel and run under the calling plan's security context
+        crate::capabilities::register_plan_ops_capabilities(
+            Arc::clone(&capability_marketplace),
+            &orchestrator,
+            &governance_kernel,
+            crate::capabilities::plan_ops::DEFAULT_MAX_PLAN_INVOKE_DEPTH,
+        )
+        .await?;
+
         // Provide a CCOS-aware host for executing RTFS capabilities loaded into the marketplace
         // This is set after GovernanceKernel creation so all capability calls go through governance
         let rtfs_host_factory = {
@@ -415,6 +428,9 @@ impl CCOS {
         // AgentRegistry migration: Agents are now registered as capabilities with :kind :agent
         // TODO: Migrate delegation feedback tracking to CapabilityMarketplace
 
+        // Token/cost accounting shared by every LLM call of this run
+        let llm_usage = LlmUsageTracker::new();
+
         // Always create delegating arbiter - this is now the primary arbiter
         let delegating_engine = {
             // Try to extract LLM config from agent config first
@@ -640,7 +656,7 @@ impl CCOS {
             };
 
             // Always create delegating arbiter - fail properly if LLM provider is not configured
-            let delegating_engine = crate::cognitive_engine::DelegatingCognitiveEngine::new(
+            let mut delegating_engine = crate::cognitive_engine::DelegatingCognitiveEngine::new(
                 llm_config,
                 delegation_config,
                 Arc::clone(&capability_marketplace),
@@ -655,6 +671,7 @@ impl CCOS {
                     e
                 ))
             })?;
+            delegating_engine.set_usage_accounting(llm_usage.clone(), Arc::clone(&causal_chain));
             Arc::new(delegating_engine)
         };
 
@@ -740,6 +757,7 @@ impl CCOS {
             missing_capability_resolver: Some(missing_capability_resolver),
             debug_callback: debug_callback.clone(),
             catalog: Arc::clone(&catalog_service),
+            llm_usage,
         })
     }
 
@@ -784,7 +802,9 @@ impl CCOS {
                                 walk_expr(v, acc);
                             }
                         }
-                        Expression::FunctionCall { callee, arguments } => {
+                        Expression::FunctionCall {
+                            callee, arguments, ..
+                        } => {
                             // Recognize (call :ccos.cap ...) by structure: callee symbol or list where first symbol is 'call'
                             // Simpler: look for callee == Symbol("call") and first argument is a Literal::Keyword with name starting ccos.
                             match &**callee {
@@ -808,7 +828,7 @@ impl CCOS {
                                                     let trimmed = sym2.0.trim_start_matches(':');
                                                     acc.insert(trimmed.to_string());
                                                 } else if sym2.0.starts_with("ccos.") {
-                                                    acc.insert(sym2.0.clone());
+                                                    acc.insert(sym2.0.to_string());
                                                 }
                                             }
                                             _ => {}
@@ -989,10 +1009,9 @@ impl CCOS {
                         .await
                         .is_none()
                     {
-                        return Err(rtfs::runtime::error::RuntimeError::Generic(format!(
-                            "Unknown capability referenced in plan: {}",
-                            cap
-                        )));
+                        return Err(rtfs::runtime::error::RuntimeError::CapabilityNotFound {
+                            capability_id: cap,
+                        });
                     }
                 }
             } else {
@@ -1047,9 +1066,6 @@ impl CCOS {
 
         // Store the intent in the Intent Graph for later reference
         {
-            let mut ig = self.intent_graph.lock().map_err(|e| {
-                RuntimeError::Generic(format!("Failed to lock intent graph: {}", e))
-            })?;
             let storable_intent = crate::types::StorableIntent {
                 intent_id: intent.intent_id.clone(),
                 name: intent.name.clone(),
@@ -1081,13 +1097,14 @@ impl CCOS {
                 priority: 0,
                 created_at: intent.created_at,
                 updated_at: intent.updated_at,
+                version: 0,
                 metadata: intent
                     .metadata
                     .iter()
                     .map(|(k, v)| (k.clone(), v.to_string()))
                     .collect(),
             };
-            ig.store_intent(storable_intent)?;
+            self.orchestrator.store_intent(storable_intent)?;
         }
 
         let proposed_plan = self.cognitive_engine.intent_to_plan(&intent).await?;
@@ -1199,7 +1216,7 @@ impl CCOS {
             .cognitive_engine
             .natural_language_to_intent(natural_language_request, None)
             .await?;
-        if let Ok(mut ig) = self.intent_graph.lock() {
+        {
             let storable_intent = crate::types::StorableIntent {
                 intent_id: intent.intent_id.clone(),
                 name: intent.name.clone(),
@@ -1231,13 +1248,14 @@ impl CCOS {
                 priority: 0,
                 created_at: intent.created_at,
                 updated_at: intent.updated_at,
+                version: 0,
                 metadata: intent
                     .metadata
                     .iter()
                     .map(|(k, v)| (k.clone(), v.to_string()))
                     .collect(),
             };
-            ig.store_intent(storable_intent)?;
+            self.orchestrator.store_intent(storable_intent)?;
         }
         let proposed_plan = self.cognitive_engine.intent_to_plan(&intent).await?;
         self.emit_debug(|| {
@@ -1339,9 +1357,6 @@ impl CCOS {
 
         // Store the intent in the Intent Graph for later reference
         {
-            let mut ig = self.intent_graph.lock().map_err(|e| {
-                RuntimeError::Generic(format!("Failed to lock intent graph: {}", e))
-            })?;
             let storable_intent = crate::types::StorableIntent {
                 intent_id: intent.intent_id.clone(),
                 name: intent.name.clone(),
@@ -1378,13 +1393,14 @@ impl CCOS {
                 priority: 0,
                 created_at: intent.created_at,
                 updated_at: intent.updated_at,
+                version: 0,
                 metadata: intent
                     .metadata
                     .iter()
                     .map(|(k, v)| (k.clone(), v.to_string()))
                     .collect(),
             };
-            ig.store_intent(storable_intent)?;
+            self.orchestrator.store_intent(storable_intent)?;
         }
 
         let proposed_plan = self.cognitive_engine.intent_to_plan(&intent).await?;
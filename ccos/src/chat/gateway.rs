@@ -3309,7 +3309,7 @@ async fn send_handler(
     let (prepared, _success) = gateway
         .execute_capability(
             "ccos.chat.egress.prepare_outbound",
-            rtfs_value_to_json(&Value::Map(inputs))
+            rtfs_value_to_json(&Value::Map(inputs.into()))
                 .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?,
         )
         .await
@@ -5428,10 +5428,13 @@ async fn agent_log_handler(
                     cap_map.insert(MapKey::String("inputs".to_string()), rtfs_val);
                 }
             }
-            Value::Map(cap_map)
+            Value::Map(cap_map.into())
         })
         .collect();
-    metadata.insert("planned_capabilities".to_string(), Value::Vector(caps));
+    metadata.insert(
+        "planned_capabilities".to_string(),
+        Value::Vector(caps.into()),
+    );
 
     // Add token usage if available
     if let Some(ref usage) = payload.token_usage {
@@ -5448,7 +5451,7 @@ async fn agent_log_handler(
             MapKey::String("total_tokens".to_string()),
             Value::Integer(usage.total_tokens as i64),
         );
-        metadata.insert("token_usage".to_string(), Value::Map(usage_map));
+        metadata.insert("token_usage".to_string(), Value::Map(usage_map.into()));
     }
 
     // Add model if available
@@ -16,7 +16,7 @@ use chrono::Utc;
 use sha2::Digest;
 use rtfs::ast::{Keyword, MapKey};
 use rtfs::runtime::error::{RuntimeError, RuntimeResult};
-use rtfs::runtime::values::Value;
+use rtfs::runtime::values::{Value, ValueMap};
 
 
 use crate::approval::types::{ApprovalCategory, RiskAssessment, RiskLevel};
@@ -180,7 +180,7 @@ pub fn extract_label(value: &Value) -> ChatDataLabel {
 pub fn strip_ccos_meta(value: &Value) -> Value {
     match value {
         Value::Map(map) => {
-            let mut out: HashMap<MapKey, Value> = HashMap::new();
+            let mut out: ValueMap = ValueMap::new();
             for (k, v) in map.iter() {
                 let is_meta = matches!(k, MapKey::String(s) if s == CCOS_META_KEY)
                     || matches!(k, MapKey::Keyword(kw) if kw.0 == CCOS_META_KEY);
@@ -200,14 +200,14 @@ pub fn strip_ccos_meta(value: &Value) -> Value {
 fn get_meta_map(value: &Value) -> Option<HashMap<String, Value>> {
     let Value::Map(map) = value else { return None };
     let meta = map.get(&MapKey::String(CCOS_META_KEY.to_string()))
-        .or_else(|| map.get(&MapKey::Keyword(Keyword(CCOS_META_KEY.to_string()))))?;
+        .or_else(|| map.get(&MapKey::Keyword(Keyword(CCOS_META_KEY.into()))))?;
     let Value::Map(inner) = meta else { return None };
 
     let mut out = HashMap::new();
     for (k, v) in inner.iter() {
         let key = match k {
             MapKey::String(s) => s.clone(),
-            MapKey::Keyword(kw) => kw.0.clone(),
+            MapKey::Keyword(kw) => kw.0.to_string(),
             MapKey::Integer(i) => i.to_string(),
         };
         out.insert(key, v.clone());
@@ -220,28 +220,28 @@ fn with_meta(
     class: ChatDataLabel,
     field_labels: Option<HashMap<String, ChatDataLabel>>,
 ) -> Value {
-    let mut meta_map: HashMap<MapKey, Value> = HashMap::new();
+    let mut meta_map: ValueMap = ValueMap::new();
     meta_map.insert(
         MapKey::String(META_CLASS_KEY.to_string()),
         Value::String(class.as_str().to_string()),
     );
     meta_map.insert(
-        MapKey::Keyword(Keyword(META_CLASS_KEY.to_string())),
+        MapKey::Keyword(Keyword(META_CLASS_KEY.into())),
         Value::String(class.as_str().to_string()),
     );
 
     if let Some(field_labels) = field_labels {
-        let mut labels_map: HashMap<MapKey, Value> = HashMap::new();
+        let mut labels_map: ValueMap = ValueMap::new();
         for (k, lbl) in field_labels {
             labels_map.insert(MapKey::String(k.clone()), Value::String(lbl.as_str().to_string()));
-            labels_map.insert(MapKey::Keyword(Keyword(k)), Value::String(lbl.as_str().to_string()));
+            labels_map.insert(MapKey::Keyword(Keyword(k.into())), Value::String(lbl.as_str().to_string()));
         }
         meta_map.insert(
             MapKey::String(META_FIELD_LABELS_KEY.to_string()),
             Value::Map(labels_map.clone()),
         );
         meta_map.insert(
-            MapKey::Keyword(Keyword(META_FIELD_LABELS_KEY.to_string())),
+            MapKey::Keyword(Keyword(META_FIELD_LABELS_KEY.into())),
             Value::Map(labels_map),
         );
     }
@@ -249,16 +249,16 @@ fn with_meta(
     match &mut value {
         Value::Map(map) => {
             map.insert(MapKey::String(CCOS_META_KEY.to_string()), Value::Map(meta_map.clone()));
-            map.insert(MapKey::Keyword(Keyword(CCOS_META_KEY.to_string())), Value::Map(meta_map));
+            map.insert(MapKey::Keyword(Keyword(CCOS_META_KEY.into())), Value::Map(meta_map));
             value
         }
         other => {
             // For non-map outputs, wrap into a map so metadata is representable.
-            let mut map: HashMap<MapKey, Value> = HashMap::new();
+            let mut map: ValueMap = ValueMap::new();
             map.insert(MapKey::String("value".to_string()), other.clone());
-            map.insert(MapKey::Keyword(Keyword("value".to_string())), other.clone());
+            map.insert(MapKey::Keyword(Keyword("value".into())), other.clone());
             map.insert(MapKey::String(CCOS_META_KEY.to_string()), Value::Map(meta_map.clone()));
-            map.insert(MapKey::Keyword(Keyword(CCOS_META_KEY.to_string())), Value::Map(meta_map));
+            map.insert(MapKey::Keyword(Keyword(CCOS_META_KEY.into())), Value::Map(meta_map));
             Value::Map(map)
         }
     }
@@ -496,17 +496,17 @@ pub async fn register_chat_capabilities(
                         Value::String(summary),
                     );
                     out.insert(
-                        MapKey::Keyword(Keyword("summary".to_string())),
+                        MapKey::Keyword(Keyword("summary".into())),
                         out[&MapKey::String("summary".to_string())].clone(),
                     );
-                    out.insert(MapKey::String("topics".to_string()), Value::Vector(topics));
+                    out.insert(MapKey::String("topics".to_string()), Value::Vector(topics.into()));
                     out.insert(
-                        MapKey::Keyword(Keyword("topics".to_string())),
+                        MapKey::Keyword(Keyword("topics".into())),
                         out[&MapKey::String("topics".to_string())].clone(),
                     );
-                    out.insert(MapKey::String("tasks".to_string()), Value::Vector(tasks));
+                    out.insert(MapKey::String("tasks".to_string()), Value::Vector(tasks.into()));
                     out.insert(
-                        MapKey::Keyword(Keyword("tasks".to_string())),
+                        MapKey::Keyword(Keyword("tasks".into())),
                         out[&MapKey::String("tasks".to_string())].clone(),
                     );
 
@@ -543,7 +543,7 @@ pub async fn register_chat_capabilities(
                         crate::types::ActionType::InternalStep,
                     )?;
 
-                    let val = Value::Map(out);
+                    let val = Value::Map(out.into());
                     Ok(attach_label(val, ChatDataLabel::PiiRedacted, None))
                 })
             }),
@@ -599,14 +599,14 @@ pub async fn register_chat_capabilities(
                     let _ = bytes; // keep raw out of outputs/logs
 
                     let mut out = HashMap::new();
-                    out.insert(MapKey::String("entities".to_string()), Value::Vector(vec![]));
+                    out.insert(MapKey::String("entities".to_string()), Value::Vector(vec![].into()));
                     out.insert(
-                        MapKey::Keyword(Keyword("entities".to_string())),
+                        MapKey::Keyword(Keyword("entities".into())),
                         out[&MapKey::String("entities".to_string())].clone(),
                     );
-                    out.insert(MapKey::String("tasks".to_string()), Value::Vector(vec![]));
+                    out.insert(MapKey::String("tasks".to_string()), Value::Vector(vec![].into()));
                     out.insert(
-                        MapKey::Keyword(Keyword("tasks".to_string())),
+                        MapKey::Keyword(Keyword("tasks".into())),
                         out[&MapKey::String("tasks".to_string())].clone(),
                     );
 
@@ -641,7 +641,7 @@ pub async fn register_chat_capabilities(
                         crate::types::ActionType::InternalStep,
                     )?;
 
-                    Ok(attach_label(Value::Map(out), ChatDataLabel::PiiRedacted, None))
+                    Ok(attach_label(Value::Map(out.into()), ChatDataLabel::PiiRedacted, None))
                 })
             }),
             "low",
@@ -701,15 +701,15 @@ pub async fn register_chat_capabilities(
                         Value::String(redacted),
                     );
                     out.insert(
-                        MapKey::Keyword(Keyword("redacted_text".to_string())),
+                        MapKey::Keyword(Keyword("redacted_text".into())),
                         out[&MapKey::String("redacted_text".to_string())].clone(),
                     );
                     out.insert(
                         MapKey::String("redactions".to_string()),
-                        Value::Vector(vec![]),
+                        Value::Vector(vec![].into()),
                     );
                     out.insert(
-                        MapKey::Keyword(Keyword("redactions".to_string())),
+                        MapKey::Keyword(Keyword("redactions".into())),
                         out[&MapKey::String("redactions".to_string())].clone(),
                     );
 
@@ -744,7 +744,7 @@ pub async fn register_chat_capabilities(
                         crate::types::ActionType::InternalStep,
                     )?;
 
-                    Ok(attach_label(Value::Map(out), ChatDataLabel::PiiRedacted, None))
+                    Ok(attach_label(Value::Map(out.into()), ChatDataLabel::PiiRedacted, None))
                 })
             }),
             "low",
@@ -818,7 +818,7 @@ pub async fn register_chat_capabilities(
                     let mut out = HashMap::new();
                     out.insert(MapKey::String("ok".to_string()), Value::Boolean(ok));
                     out.insert(
-                        MapKey::Keyword(Keyword("ok".to_string())),
+                        MapKey::Keyword(Keyword("ok".into())),
                         out[&MapKey::String("ok".to_string())].clone(),
                     );
                     if !issues.is_empty() {
@@ -827,20 +827,20 @@ pub async fn register_chat_capabilities(
                             Value::Vector(issues.iter().cloned().map(Value::String).collect()),
                         );
                         out.insert(
-                            MapKey::Keyword(Keyword("issues".to_string())),
+                            MapKey::Keyword(Keyword("issues".into())),
                             out[&MapKey::String("issues".to_string())].clone(),
                         );
                     }
                     if ok {
                         out.insert(MapKey::String("text".to_string()), Value::String(text));
                         out.insert(
-                            MapKey::Keyword(Keyword("text".to_string())),
+                            MapKey::Keyword(Keyword("text".into())),
                             out[&MapKey::String("text".to_string())].clone(),
                         );
                     }
 
                     let class = if ok { ChatDataLabel::Public } else { ChatDataLabel::PiiRedacted };
-                    Ok(attach_label(Value::Map(out), class, None))
+                    Ok(attach_label(Value::Map(out.into()), class, None))
                 })
             }),
             "medium",
@@ -1007,7 +1007,7 @@ pub async fn register_chat_capabilities(
                         )));
                     }
 
-                    Ok(Value::Map(HashMap::from([
+                    Ok(Value::Map(ValueMap::from_iter([
                         (MapKey::String("success".to_string()), Value::Boolean(true)),
                         (MapKey::String("message_id".to_string()), 
                          Value::String(result.message_id.unwrap_or_default())),
@@ -1047,7 +1047,7 @@ pub async fn register_chat_capabilities(
 
                     let get_str = |k: &str| {
                         map.get(&MapKey::String(k.to_string()))
-                            .or_else(|| map.get(&MapKey::Keyword(Keyword(k.to_string()))))
+                            .or_else(|| map.get(&MapKey::Keyword(Keyword(k.into()))))
                             .and_then(|v| v.as_string())
                             .map(|s| s.to_string())
                     };
@@ -1075,7 +1075,7 @@ pub async fn register_chat_capabilities(
                     let label = get_str("label");
                     let ttl_seconds = map
                         .get(&MapKey::String("ttl_seconds".to_string()))
-                        .or_else(|| map.get(&MapKey::Keyword(Keyword("ttl_seconds".to_string()))))
+                        .or_else(|| map.get(&MapKey::Keyword(Keyword("ttl_seconds".into()))))
                         .and_then(|v| match v {
                             Value::Integer(i) if *i > 0 => Some(*i as i64),
                             _ => None,
@@ -1103,7 +1103,7 @@ pub async fn register_chat_capabilities(
                                 Value::String("GET".to_string()),
                             );
                             let fetched = marketplace
-                                .execute_capability("ccos.network.http-fetch", &Value::Map(fetch_inputs))
+                                .execute_capability("ccos.network.http-fetch", &Value::Map(fetch_inputs.into()))
                                 .await?;
                             let Value::Map(out) = fetched else {
                                 return Err(RuntimeError::Generic(
@@ -1201,7 +1201,7 @@ pub async fn register_chat_capabilities(
                     let preview: String = content.chars().take(400).collect();
                     let truncated = preview.len() < content.len();
 
-                    Ok(Value::Map(HashMap::from([
+                    Ok(Value::Map(ValueMap::from_iter([
                         (MapKey::String("resource_id".to_string()), Value::String(resource_id)),
                         (MapKey::String("pointer_id".to_string()), Value::String(pointer_id)),
                         (MapKey::String("source".to_string()), Value::String(source_for_response)),
@@ -1240,7 +1240,7 @@ pub async fn register_chat_capabilities(
                     };
                     let get_str = |k: &str| {
                         map.get(&MapKey::String(k.to_string()))
-                            .or_else(|| map.get(&MapKey::Keyword(Keyword(k.to_string()))))
+                            .or_else(|| map.get(&MapKey::Keyword(Keyword(k.into()))))
                             .and_then(|v| v.as_string())
                             .map(|s| s.to_string())
                     };
@@ -1255,7 +1255,7 @@ pub async fn register_chat_capabilities(
 
                     let max_len = map
                         .get(&MapKey::String("max_len".to_string()))
-                        .or_else(|| map.get(&MapKey::Keyword(Keyword("max_len".to_string()))))
+                        .or_else(|| map.get(&MapKey::Keyword(Keyword("max_len".into()))))
                         .and_then(|v| match v {
                             Value::Integer(i) if *i > 0 => Some(*i as usize),
                             _ => None,
@@ -1304,7 +1304,7 @@ pub async fn register_chat_capabilities(
                         crate::types::ActionType::InternalStep,
                     )?;
 
-                    Ok(Value::Map(HashMap::from([
+                    Ok(Value::Map(ValueMap::from_iter([
                         (
                             MapKey::String("resource_id".to_string()),
                             Value::String(resource_id),
@@ -1359,7 +1359,7 @@ pub async fn register_chat_capabilities(
                     };
                     let get_str = |k: &str| {
                         map.get(&MapKey::String(k.to_string()))
-                            .or_else(|| map.get(&MapKey::Keyword(Keyword(k.to_string()))))
+                            .or_else(|| map.get(&MapKey::Keyword(Keyword(k.into()))))
                             .and_then(|v| v.as_string())
                             .map(|s| s.to_string())
                     };
@@ -1384,7 +1384,7 @@ pub async fn register_chat_capabilities(
 
                     let mut out = Vec::new();
                     for r in records {
-                        out.push(Value::Map(HashMap::from([
+                        out.push(Value::Map(ValueMap::from_iter([
                             (MapKey::String("resource_id".to_string()), Value::String(r.id.clone())),
                             (MapKey::String("pointer_id".to_string()), Value::String(r.pointer_id.clone())),
                             (MapKey::String("source".to_string()), Value::String(r.source.clone())),
@@ -1394,7 +1394,7 @@ pub async fn register_chat_capabilities(
                             (MapKey::String("created_at_ms".to_string()), Value::Integer(r.created_at_ms as i64)),
                         ])));
                     }
-                    Ok(Value::Vector(out))
+                    Ok(Value::Vector(out.into()))
                 })
             }),
             "low",
@@ -1420,7 +1420,7 @@ pub async fn register_chat_capabilities(
                 Box::pin(async move {
                     let query = if let Value::Map(ref map) = inputs {
                         map.get(&MapKey::String("query".to_string()))
-                            .or_else(|| map.get(&MapKey::Keyword(Keyword("query".to_string()))))
+                            .or_else(|| map.get(&MapKey::Keyword(Keyword("query".into()))))
                             .and_then(|v| v.as_string())
                             .map(|s| s.to_string())
                     } else {
@@ -1429,7 +1429,7 @@ pub async fn register_chat_capabilities(
 
                     let limit = if let Value::Map(ref map) = inputs {
                         map.get(&MapKey::String("limit".to_string()))
-                            .or_else(|| map.get(&MapKey::Keyword(Keyword("limit".to_string()))))
+                            .or_else(|| map.get(&MapKey::Keyword(Keyword("limit".into()))))
                             .and_then(|v| match v {
                                 Value::Integer(i) => Some(*i as usize),
                                 _ => None,
@@ -1462,16 +1462,16 @@ pub async fn register_chat_capabilities(
                                 }
                             }
                             item.insert(MapKey::String("score".to_string()), Value::Float(hit.score as f64));
-                            results.push(Value::Map(item));
+                            results.push(Value::Map(item.into()));
                         }
 
                         let mut final_map = std::collections::HashMap::new();
-                        final_map.insert(MapKey::String("results".to_string()), Value::Vector(results));
-                        Ok(Value::Map(final_map))
+                        final_map.insert(MapKey::String("results".to_string()), Value::Vector(results.into()));
+                        Ok(Value::Map(final_map.into()))
                     } else {
-                        Ok(Value::Map(std::collections::HashMap::from([(
+                        Ok(Value::Map(ValueMap::from_iter([(
                             MapKey::String("results".to_string()),
-                            Value::Vector(vec![]),
+                            Value::Vector(vec![].into()),
                         )])))
                     }
                 })
@@ -1500,7 +1500,7 @@ pub async fn register_chat_capabilities(
                     // Extract URL from inputs
                     let url = if let Value::Map(ref map) = inputs {
                         map.get(&MapKey::String("url".to_string()))
-                            .or_else(|| map.get(&MapKey::Keyword(Keyword("url".to_string()))))
+                            .or_else(|| map.get(&MapKey::Keyword(Keyword("url".into()))))
                             .and_then(|v| v.as_string())
                             .map(|s| s.to_string())
                     } else {
@@ -1559,7 +1559,7 @@ pub async fn register_chat_capabilities(
                                     // Extract session_id from inputs if available
                                     let session_id = if let Value::Map(ref map) = inputs {
                                         map.get(&MapKey::String("session_id".to_string()))
-                                            .or_else(|| map.get(&MapKey::Keyword(Keyword("session_id".to_string()))))
+                                            .or_else(|| map.get(&MapKey::Keyword(Keyword("session_id".into()))))
                                             .and_then(|v| v.as_string())
                                             .map(|s| s.to_string())
                                     } else {
@@ -1602,7 +1602,7 @@ pub async fn register_chat_capabilities(
                     // Optional safety valve: allow callers to override URL heuristics.
                     let force = if let Value::Map(ref map) = inputs {
                         map.get(&MapKey::String("force".to_string()))
-                            .or_else(|| map.get(&MapKey::Keyword(Keyword("force".to_string()))))
+                            .or_else(|| map.get(&MapKey::Keyword(Keyword("force".into()))))
                             .and_then(|v| match v {
                                 Value::Boolean(b) => Some(*b),
                                 _ => None,
@@ -1647,7 +1647,7 @@ pub async fn register_chat_capabilities(
                             Value::String("GET".to_string()),
                         );
                         let fetched = marketplace
-                            .execute_capability("ccos.network.http-fetch", &Value::Map(fetch_inputs))
+                            .execute_capability("ccos.network.http-fetch", &Value::Map(fetch_inputs.into()))
                             .await?;
                         let Value::Map(map) = fetched else {
                             return Err(RuntimeError::Generic(
@@ -1735,17 +1735,17 @@ pub async fn register_chat_capabilities(
                             .collect();
                         result_map.insert(
                             MapKey::String("registered_capabilities".to_string()),
-                            Value::Vector(caps),
+                            Value::Vector(caps.into()),
                         );
                     } else {
                         // Return empty capabilities vector instead of error
                         result_map.insert(
                             MapKey::String("registered_capabilities".to_string()),
-                            Value::Vector(vec![]),
+                            Value::Vector(vec![].into()),
                         );
                     }
                     
-                    Ok(Value::Map(result_map))
+                    Ok(Value::Map(result_map.into()))
                 })
             }),
             "low",
@@ -1769,15 +1769,15 @@ pub async fn register_chat_capabilities(
                     // Extract skill and operation from inputs
                     let (skill, operation, params) = if let Value::Map(ref map) = inputs {
                         let skill = map.get(&MapKey::String("skill".to_string()))
-                            .or_else(|| map.get(&MapKey::Keyword(Keyword("skill".to_string()))))
+                            .or_else(|| map.get(&MapKey::Keyword(Keyword("skill".into()))))
                             .or_else(|| map.get(&MapKey::String("skill_id".to_string())))
-                            .or_else(|| map.get(&MapKey::Keyword(Keyword("skill_id".to_string()))))
+                            .or_else(|| map.get(&MapKey::Keyword(Keyword("skill_id".into()))))
                             .and_then(|v| v.as_string())
                             .map(|s| s.to_string())
                             .ok_or_else(|| RuntimeError::Generic("Missing skill parameter".to_string()))?;
                         
                         let operation = map.get(&MapKey::String("operation".to_string()))
-                            .or_else(|| map.get(&MapKey::Keyword(Keyword("operation".to_string()))))
+                            .or_else(|| map.get(&MapKey::Keyword(Keyword("operation".into()))))
                             .and_then(|v| v.as_string())
                             .map(|s| s.to_string())
                             .ok_or_else(|| RuntimeError::Generic("Missing operation parameter".to_string()))?;
@@ -1785,12 +1785,12 @@ pub async fn register_chat_capabilities(
                         // Extract parameters (everything except control keys),
                         // and flatten nested "params" / "parameters" / "inputs" maps when present.
                         let mut params_map = HashMap::new();
-                        let mut nested_params: Option<HashMap<MapKey, Value>> = None;
+                        let mut nested_params: Option<ValueMap> = None;
 
                         for (k, v) in map {
                             let key_str = match k {
                                 MapKey::String(s) => s.clone(),
-                                MapKey::Keyword(Keyword(s)) => s.clone(),
+                                MapKey::Keyword(Keyword(s)) => s.to_string(),
                                 _ => continue,
                             };
 
@@ -1823,7 +1823,7 @@ pub async fn register_chat_capabilities(
                             }
                         }
 
-                        Ok((skill, operation, Value::Map(params_map)))
+                        Ok((skill, operation, Value::Map(params_map.into())))
                     } else {
                         Err(RuntimeError::Generic("Expected map inputs".to_string()))
                     }?;
@@ -1880,13 +1880,13 @@ pub async fn register_chat_capabilities(
 
                         let get_str = |k: &str| {
                             map.get(&MapKey::String(k.to_string()))
-                                .or_else(|| map.get(&MapKey::Keyword(Keyword(k.to_string()))))
+                                .or_else(|| map.get(&MapKey::Keyword(Keyword(k.into()))))
                                 .and_then(|v| v.as_string())
                                 .map(|s| s.to_string())
                         };
                             let get_int = |k: &str| {
                                 map.get(&MapKey::String(k.to_string()))
-                                .or_else(|| map.get(&MapKey::Keyword(Keyword(k.to_string()))))
+                                .or_else(|| map.get(&MapKey::Keyword(Keyword(k.into()))))
                                 .and_then(|v| v.as_integer())
                             };
 
@@ -1925,7 +1925,7 @@ pub async fn register_chat_capabilities(
                             body_map.insert("trigger_capability_id".to_string(), Value::String(tcid));
                         }
                         if let Some(ti) = map.get(&MapKey::String("trigger_inputs".to_string()))
-                            .or_else(|| map.get(&MapKey::Keyword(Keyword("trigger_inputs".to_string()))))
+                            .or_else(|| map.get(&MapKey::Keyword(Keyword("trigger_inputs".into()))))
                         {
                             body_map.insert("trigger_inputs".to_string(), ti.clone());
                         }
@@ -1947,10 +1947,10 @@ pub async fn register_chat_capabilities(
                             headers_map.insert(MapKey::String("X-Internal-Secret".to_string()), Value::String(secret.clone()));
                         }
 
-                        fetch_inputs.insert(MapKey::String("headers".to_string()), Value::Map(headers_map));
+                        fetch_inputs.insert(MapKey::String("headers".to_string()), Value::Map(headers_map.into()));
 
                         let fetched = marketplace
-                            .execute_capability("ccos.network.http-fetch", &Value::Map(fetch_inputs))
+                            .execute_capability("ccos.network.http-fetch", &Value::Map(fetch_inputs.into()))
                             .await?;
 
                         let Value::Map(out) = fetched else {
@@ -1978,7 +1978,7 @@ pub async fn register_chat_capabilities(
                             .or_else(|| json_val.get("status").and_then(|v| v.as_str()))
                             .unwrap_or("unknown");
 
-                        Ok(Value::Map(HashMap::from([
+                        Ok(Value::Map(ValueMap::from_iter([
                             (MapKey::String("run_id".to_string()), Value::String(run_id.to_string())),
                             (MapKey::String("status".to_string()), Value::String(run_status.to_string())),
                         ])))
@@ -2012,7 +2012,7 @@ pub async fn register_chat_capabilities(
                         };
 
                         let run_id = map.get(&MapKey::String("run_id".to_string()))
-                            .or_else(|| map.get(&MapKey::Keyword(Keyword("run_id".to_string()))))
+                            .or_else(|| map.get(&MapKey::Keyword(Keyword("run_id".into()))))
                             .and_then(|v| v.as_string())
                             .ok_or_else(|| RuntimeError::Generic("Missing run_id".to_string()))?;
 
@@ -2025,9 +2025,9 @@ pub async fn register_chat_capabilities(
                         if let Some(secret) = &internal_secret {
                             headers_map.insert(MapKey::String("X-Internal-Secret".to_string()), Value::String(secret.clone()));
                         }
-                        fetch_inputs.insert(MapKey::String("headers".to_string()), Value::Map(headers_map));
+                        fetch_inputs.insert(MapKey::String("headers".to_string()), Value::Map(headers_map.into()));
 
-                        let fetched = marketplace.execute_capability("ccos.network.http-fetch", &Value::Map(fetch_inputs)).await?;
+                        let fetched = marketplace.execute_capability("ccos.network.http-fetch", &Value::Map(fetch_inputs.into())).await?;
                         let Value::Map(out) = fetched else {
                             return Err(RuntimeError::Generic("http-fetch returned non-map".to_string()));
                         };
@@ -2078,7 +2078,7 @@ pub async fn register_chat_capabilities(
                         };
 
                         let session_id = map.get(&MapKey::String("session_id".to_string()))
-                            .or_else(|| map.get(&MapKey::Keyword(Keyword("session_id".to_string()))))
+                            .or_else(|| map.get(&MapKey::Keyword(Keyword("session_id".into()))))
                             .and_then(|v| v.as_string())
                             .ok_or_else(|| RuntimeError::Generic("Missing session_id".to_string()))?;
 
@@ -2091,9 +2091,9 @@ pub async fn register_chat_capabilities(
                         if let Some(secret) = &internal_secret {
                             headers_map.insert(MapKey::String("X-Internal-Secret".to_string()), Value::String(secret.clone()));
                         }
-                        fetch_inputs.insert(MapKey::String("headers".to_string()), Value::Map(headers_map));
+                        fetch_inputs.insert(MapKey::String("headers".to_string()), Value::Map(headers_map.into()));
 
-                        let fetched = marketplace.execute_capability("ccos.network.http-fetch", &Value::Map(fetch_inputs)).await?;
+                        let fetched = marketplace.execute_capability("ccos.network.http-fetch", &Value::Map(fetch_inputs.into())).await?;
                         let Value::Map(out) = fetched else {
                              return Err(RuntimeError::Generic("http-fetch returned non-map".to_string()));
                         };
@@ -2144,7 +2144,7 @@ pub async fn register_chat_capabilities(
                         };
 
                         let run_id = map.get(&MapKey::String("run_id".to_string()))
-                            .or_else(|| map.get(&MapKey::Keyword(Keyword("run_id".to_string()))))
+                            .or_else(|| map.get(&MapKey::Keyword(Keyword("run_id".into()))))
                             .and_then(|v| v.as_string())
                             .ok_or_else(|| RuntimeError::Generic("Missing run_id".to_string()))?;
 
@@ -2157,9 +2157,9 @@ pub async fn register_chat_capabilities(
                         if let Some(secret) = &internal_secret {
                             headers_map.insert(MapKey::String("X-Internal-Secret".to_string()), Value::String(secret.clone()));
                         }
-                        fetch_inputs.insert(MapKey::String("headers".to_string()), Value::Map(headers_map));
+                        fetch_inputs.insert(MapKey::String("headers".to_string()), Value::Map(headers_map.into()));
 
-                        let fetched = marketplace.execute_capability("ccos.network.http-fetch", &Value::Map(fetch_inputs)).await?;
+                        let fetched = marketplace.execute_capability("ccos.network.http-fetch", &Value::Map(fetch_inputs.into())).await?;
                         let Value::Map(out) = fetched else {
                              return Err(RuntimeError::Generic("http-fetch returned non-map".to_string()));
                         };
@@ -2210,7 +2210,7 @@ pub async fn register_chat_capabilities(
                         };
 
                         let run_id = map.get(&MapKey::String("run_id".to_string()))
-                            .or_else(|| map.get(&MapKey::Keyword(Keyword("run_id".to_string()))))
+                            .or_else(|| map.get(&MapKey::Keyword(Keyword("run_id".into()))))
                             .and_then(|v| v.as_string())
                             .ok_or_else(|| RuntimeError::Generic("Missing run_id".to_string()))?;
 
@@ -2223,9 +2223,9 @@ pub async fn register_chat_capabilities(
                         if let Some(secret) = &internal_secret {
                             headers_map.insert(MapKey::String("X-Internal-Secret".to_string()), Value::String(secret.clone()));
                         }
-                        fetch_inputs.insert(MapKey::String("headers".to_string()), Value::Map(headers_map));
+                        fetch_inputs.insert(MapKey::String("headers".to_string()), Value::Map(headers_map.into()));
 
-                        let fetched = marketplace.execute_capability("ccos.network.http-fetch", &Value::Map(fetch_inputs)).await?;
+                        let fetched = marketplace.execute_capability("ccos.network.http-fetch", &Value::Map(fetch_inputs.into())).await?;
                         let Value::Map(out) = fetched else {
                              return Err(RuntimeError::Generic("http-fetch returned non-map".to_string()));
                         };
@@ -2361,7 +2361,7 @@ pub async fn register_chat_capabilities(
 
                     // Get task (required)
                     let task = map
-                        .get(&MapKey::Keyword(Keyword("task".to_string())))
+                        .get(&MapKey::Keyword(Keyword("task".into())))
                         .or_else(|| map.get(&MapKey::String("task".to_string())))
                         .and_then(|v| v.as_string())
                         .ok_or_else(|| RuntimeError::Generic("Missing 'task' parameter".to_string()))?
@@ -2369,7 +2369,7 @@ pub async fn register_chat_capabilities(
 
                     // Get language (optional)
                     let language = map
-                        .get(&MapKey::Keyword(Keyword("language".to_string())))
+                        .get(&MapKey::Keyword(Keyword("language".into())))
                         .or_else(|| map.get(&MapKey::String("language".to_string())))
                         .and_then(|v| v.as_string())
                         .map(|s| s.to_string());
@@ -2377,7 +2377,7 @@ pub async fn register_chat_capabilities(
                     // Get inputs (optional)
                     let mut input_files = Vec::new();
                     if let Some(inputs_value) = map
-                        .get(&MapKey::Keyword(Keyword("inputs".to_string())))
+                        .get(&MapKey::Keyword(Keyword("inputs".into())))
                         .or_else(|| map.get(&MapKey::String("inputs".to_string())))
                     {
                         if let Value::Vector(vec) = inputs_value {
@@ -2392,7 +2392,7 @@ pub async fn register_chat_capabilities(
                     // Get outputs (optional)
                     let mut output_files = Vec::new();
                     if let Some(outputs_value) = map
-                        .get(&MapKey::Keyword(Keyword("outputs".to_string())))
+                        .get(&MapKey::Keyword(Keyword("outputs".into())))
                         .or_else(|| map.get(&MapKey::String("outputs".to_string())))
                     {
                         if let Value::Vector(vec) = outputs_value {
@@ -2406,19 +2406,19 @@ pub async fn register_chat_capabilities(
 
                     // Get profile (optional)
                     let profile = map
-                        .get(&MapKey::Keyword(Keyword("profile".to_string())))
+                        .get(&MapKey::Keyword(Keyword("profile".into())))
                         .or_else(|| map.get(&MapKey::String("profile".to_string())))
                         .and_then(|v| v.as_string())
                         .map(|s| s.to_string());
 
                     // Get constraints (optional)
                     let constraints = map
-                        .get(&MapKey::Keyword(Keyword("constraints".to_string())))
+                        .get(&MapKey::Keyword(Keyword("constraints".into())))
                         .or_else(|| map.get(&MapKey::String("constraints".to_string())))
                         .and_then(|v| {
                             if let Value::Map(c) = v {
                                 let max_lines = c
-                                    .get(&MapKey::Keyword(Keyword("max_lines".to_string())))
+                                    .get(&MapKey::Keyword(Keyword("max_lines".into())))
                                     .or_else(|| c.get(&MapKey::String("max_lines".to_string())))
                                     .and_then(|v| match v {
                                         Value::Integer(i) => Some(*i as u32),
@@ -2426,7 +2426,7 @@ pub async fn register_chat_capabilities(
                                         _ => None,
                                     });
                                 let deps_allowed = c
-                                    .get(&MapKey::Keyword(Keyword("dependencies_allowed".to_string())))
+                                    .get(&MapKey::Keyword(Keyword("dependencies_allowed".into())))
                                     .or_else(|| c.get(&MapKey::String("dependencies_allowed".to_string())))
                                     .and_then(|v| match v {
                                         Value::Boolean(b) => Some(*b),
@@ -2466,31 +2466,31 @@ pub async fn register_chat_capabilities(
                     // Build output map
                     let mut output_map = HashMap::new();
                     output_map.insert(
-                        MapKey::Keyword(Keyword("code".to_string())),
+                        MapKey::Keyword(Keyword("code".into())),
                         Value::String(response.code),
                     );
                     output_map.insert(
-                        MapKey::Keyword(Keyword("language".to_string())),
+                        MapKey::Keyword(Keyword("language".into())),
                         Value::String(response.language),
                     );
                     output_map.insert(
-                        MapKey::Keyword(Keyword("dependencies".to_string())),
+                        MapKey::Keyword(Keyword("dependencies".into())),
                         Value::Vector(
                             response.dependencies.into_iter().map(Value::String).collect()
                         ),
                     );
                     output_map.insert(
-                        MapKey::Keyword(Keyword("explanation".to_string())),
+                        MapKey::Keyword(Keyword("explanation".into())),
                         Value::String(response.explanation),
                     );
                     if let Some(tests) = response.tests {
                         output_map.insert(
-                            MapKey::Keyword(Keyword("tests".to_string())),
+                            MapKey::Keyword(Keyword("tests".into())),
                             Value::String(tests),
                         );
                     }
 
-                    Ok(Value::Map(output_map))
+                    Ok(Value::Map(output_map.into()))
                 })
             }),
             "medium",
@@ -2535,17 +2535,17 @@ pub async fn register_chat_capabilities(
 
                     // Extract context identifiers for progress events
                     let session_id = map.get(&MapKey::String("session_id".to_string()))
-                        .or_else(|| map.get(&MapKey::Keyword(Keyword("session_id".to_string()))))
+                        .or_else(|| map.get(&MapKey::Keyword(Keyword("session_id".into()))))
                         .and_then(|v| v.as_string())
                         .unwrap_or("unknown")
                         .to_string();
                     let run_id = map.get(&MapKey::String("run_id".to_string()))
-                        .or_else(|| map.get(&MapKey::Keyword(Keyword("run_id".to_string()))))
+                        .or_else(|| map.get(&MapKey::Keyword(Keyword("run_id".into()))))
                         .and_then(|v| v.as_string())
                         .unwrap_or("unknown")
                         .to_string();
                     let step_id = map.get(&MapKey::String("step_id".to_string()))
-                        .or_else(|| map.get(&MapKey::Keyword(Keyword("step_id".to_string()))))
+                        .or_else(|| map.get(&MapKey::Keyword(Keyword("step_id".into()))))
                         .and_then(|v| v.as_string())
                         .unwrap_or("unknown")
                         .to_string();
@@ -2580,21 +2580,21 @@ pub async fn register_chat_capabilities(
                     };
 
                     let task = map
-                        .get(&MapKey::Keyword(Keyword("task".to_string())))
+                        .get(&MapKey::Keyword(Keyword("task".into())))
                         .or_else(|| map.get(&MapKey::String("task".to_string())))
                         .and_then(|v| v.as_string())
                         .ok_or_else(|| RuntimeError::Generic("Missing 'task' parameter".to_string()))?
                         .to_string();
 
                     let language = map
-                        .get(&MapKey::Keyword(Keyword("language".to_string())))
+                        .get(&MapKey::Keyword(Keyword("language".into())))
                         .or_else(|| map.get(&MapKey::String("language".to_string())))
                         .and_then(|v| v.as_string())
                         .map(|s| s.to_string());
 
                     let mut output_files = Vec::new();
                     if let Some(outputs_value) = map
-                        .get(&MapKey::Keyword(Keyword("outputs".to_string())))
+                        .get(&MapKey::Keyword(Keyword("outputs".into())))
                         .or_else(|| map.get(&MapKey::String("outputs".to_string())))
                     {
                         if let Value::Vector(vec) = outputs_value {
@@ -2608,7 +2608,7 @@ pub async fn register_chat_capabilities(
 
                     let mut input_files = Vec::new();
                     if let Some(inputs_value) = map
-                        .get(&MapKey::Keyword(Keyword("inputs".to_string())))
+                        .get(&MapKey::Keyword(Keyword("inputs".into())))
                         .or_else(|| map.get(&MapKey::String("inputs".to_string())))
                     {
                         if let Value::Vector(vec) = inputs_value {
@@ -2622,7 +2622,7 @@ pub async fn register_chat_capabilities(
                     
                     let mut max_turns = coding_cfg.max_coding_turns;
                     if let Some(turns) = map
-                        .get(&MapKey::Keyword(Keyword("max_turns".to_string())))
+                        .get(&MapKey::Keyword(Keyword("max_turns".into())))
                         .or_else(|| map.get(&MapKey::String("max_turns".to_string())))
                         .and_then(|v| match v {
                             Value::Integer(i) => Some(*i as u32),
@@ -2636,29 +2636,29 @@ pub async fn register_chat_capabilities(
                     // results at predictable keys with known schemas.
                     let mut expected_outputs: Vec<ExpectedOutput> = Vec::new();
                     if let Some(eo_val) = map
-                        .get(&MapKey::Keyword(Keyword("expected_outputs".to_string())))
+                        .get(&MapKey::Keyword(Keyword("expected_outputs".into())))
                         .or_else(|| map.get(&MapKey::String("expected_outputs".to_string())))
-                        .or_else(|| map.get(&MapKey::Keyword(Keyword("expected-outputs".to_string()))))
+                        .or_else(|| map.get(&MapKey::Keyword(Keyword("expected-outputs".into()))))
                     {
                         if let Value::Vector(items) = eo_val {
                             for item in items {
                                 if let Value::Map(item_map) = item {
                                     let key = item_map
-                                        .get(&MapKey::Keyword(Keyword("key".to_string())))
+                                        .get(&MapKey::Keyword(Keyword("key".into())))
                                         .or_else(|| item_map.get(&MapKey::String("key".to_string())))
                                         .and_then(|v| v.as_string())
                                         .unwrap_or_default()
                                         .to_string();
                                     let description = item_map
-                                        .get(&MapKey::Keyword(Keyword("description".to_string())))
+                                        .get(&MapKey::Keyword(Keyword("description".into())))
                                         .or_else(|| item_map.get(&MapKey::String("description".to_string())))
                                         .and_then(|v| v.as_string())
                                         .unwrap_or_default()
                                         .to_string();
                                     let schema_hint = item_map
-                                        .get(&MapKey::Keyword(Keyword("schema_hint".to_string())))
+                                        .get(&MapKey::Keyword(Keyword("schema_hint".into())))
                                         .or_else(|| item_map.get(&MapKey::String("schema_hint".to_string())))
-                                        .or_else(|| item_map.get(&MapKey::Keyword(Keyword("schema-hint".to_string()))))
+                                        .or_else(|| item_map.get(&MapKey::Keyword(Keyword("schema-hint".into()))))
                                         .and_then(|v| v.as_string())
                                         .map(|s| s.to_string());
                                     if !key.is_empty() {
@@ -2863,7 +2863,7 @@ pub async fn register_chat_capabilities(
                             ]);
                         }
 
-                        let exec_result_val = match marketplace.execute_capability("ccos.execute.python", &Value::Map(exec_inputs)).await {
+                        let exec_result_val = match marketplace.execute_capability("ccos.execute.python", &Value::Map(exec_inputs.into())).await {
                             Ok(v) => v,
                             Err(e) => {
                                 // Approval-blocked and infrastructure errors must be surfaced verbatim to the
@@ -2881,12 +2881,12 @@ pub async fn register_chat_capabilities(
                             return Err(RuntimeError::Generic("ccos.execute.python returned non-map".to_string()));
                         };
 
-                        let success = exec_map.get(&MapKey::Keyword(Keyword("success".to_string())))
+                        let success = exec_map.get(&MapKey::Keyword(Keyword("success".into())))
                             .and_then(|v| v.as_bool())
                             .unwrap_or(false);
 
                         // Capture history for this turn
-                        let stderr = exec_map.get(&MapKey::Keyword(Keyword("stderr".to_string())))
+                        let stderr = exec_map.get(&MapKey::Keyword(Keyword("stderr".into())))
                             .and_then(|v| v.as_string())
                             .unwrap_or("");
                         
@@ -2897,12 +2897,12 @@ pub async fn register_chat_capabilities(
                         if !success {
                             history_entry.insert(MapKey::String("error".to_string()), Value::String(stderr.to_string()));
                         }
-                        refinement_history.push(Value::Map(history_entry));
+                        refinement_history.push(Value::Map(history_entry.into()));
 
                         if success {
                             // Capture stdout for the success event
                             let stdout = exec_map
-                                .get(&MapKey::Keyword(Keyword("stdout".to_string())))
+                                .get(&MapKey::Keyword(Keyword("stdout".into())))
                                 .and_then(|v| v.as_string())
                                 .unwrap_or("");
                             const MAX_OUT: usize = 3000;
@@ -2939,23 +2939,23 @@ pub async fn register_chat_capabilities(
                                 ("refinement_cycles".to_string(), Value::Integer(current_attempt as i64)),
                             ]);
                             let mut final_map = exec_map.clone();
-                            final_map.insert(MapKey::Keyword(Keyword("refinement_cycles".to_string())), Value::Integer(current_attempt as i64));
-                            final_map.insert(MapKey::Keyword(Keyword("refinement_history".to_string())), Value::Vector(refinement_history));
-                            final_map.insert(MapKey::Keyword(Keyword("final_code".to_string())), Value::String(response.code));
-                            final_map.insert(MapKey::Keyword(Keyword("explanation".to_string())), Value::String(response.explanation));
+                            final_map.insert(MapKey::Keyword(Keyword("refinement_cycles".into())), Value::Integer(current_attempt as i64));
+                            final_map.insert(MapKey::Keyword(Keyword("refinement_history".into())), Value::Vector(refinement_history.into()));
+                            final_map.insert(MapKey::Keyword(Keyword("final_code".into())), Value::String(response.code));
+                            final_map.insert(MapKey::Keyword(Keyword("explanation".into())), Value::String(response.explanation));
                             // Expose stored_artifacts declared by the code-gen LLM so the parent
                             // agent knows exactly which ccos_sdk.memory keys hold the results.
                             if !response.stored_artifacts.is_empty() {
                                 let artifacts: Vec<Value> = response.stored_artifacts.iter().map(|a| {
                                     let mut m = std::collections::HashMap::new();
-                                    m.insert(MapKey::Keyword(Keyword("key".to_string())), Value::String(a.key.clone()));
-                                    m.insert(MapKey::Keyword(Keyword("description".to_string())), Value::String(a.description.clone()));
+                                    m.insert(MapKey::Keyword(Keyword("key".into())), Value::String(a.key.clone()));
+                                    m.insert(MapKey::Keyword(Keyword("description".into())), Value::String(a.description.clone()));
                                     if let Some(ref sh) = a.schema_hint {
-                                        m.insert(MapKey::Keyword(Keyword("schema_hint".to_string())), Value::String(sh.clone()));
+                                        m.insert(MapKey::Keyword(Keyword("schema_hint".into())), Value::String(sh.clone()));
                                     }
-                                    Value::Map(m)
+                                    Value::Map(m.into())
                                 }).collect();
-                                final_map.insert(MapKey::Keyword(Keyword("stored_artifacts".to_string())), Value::Vector(artifacts));
+                                final_map.insert(MapKey::Keyword(Keyword("stored_artifacts".into())), Value::Vector(artifacts.into()));
                             }
                             return Ok(Value::Map(final_map));
                         }
@@ -2971,7 +2971,7 @@ pub async fn register_chat_capabilities(
                                 response.code.clone()
                             };
                             let stdout_at_failure = exec_map
-                                .get(&MapKey::Keyword(Keyword("stdout".to_string())))
+                                .get(&MapKey::Keyword(Keyword("stdout".into())))
                                 .and_then(|v| v.as_string())
                                 .unwrap_or("");
                             emit_phase("max_turns", format!(
@@ -2992,9 +2992,9 @@ pub async fn register_chat_capabilities(
                             ]);
                             // Max turns reached, return the last failure + history
                             let mut final_map = exec_map.clone();
-                            final_map.insert(MapKey::Keyword(Keyword("refinement_cycles".to_string())), Value::Integer(current_attempt as i64));
-                            final_map.insert(MapKey::Keyword(Keyword("refinement_history".to_string())), Value::Vector(refinement_history));
-                            final_map.insert(MapKey::Keyword(Keyword("error_class".to_string())), Value::String(format!("{:?}", classified.class)));
+                            final_map.insert(MapKey::Keyword(Keyword("refinement_cycles".into())), Value::Integer(current_attempt as i64));
+                            final_map.insert(MapKey::Keyword(Keyword("refinement_history".into())), Value::Vector(refinement_history.into()));
+                            final_map.insert(MapKey::Keyword(Keyword("error_class".into())), Value::String(format!("{:?}", classified.class)));
                             return Ok(Value::Map(final_map));
                         }
 
@@ -3036,7 +3036,7 @@ pub async fn register_chat_capabilities(
                                 })
                                 .unwrap_or_default();
                             let stdout_at_failure = exec_map
-                                .get(&MapKey::Keyword(Keyword("stdout".to_string())))
+                                .get(&MapKey::Keyword(Keyword("stdout".into())))
                                 .and_then(|v| v.as_string())
                                 .unwrap_or("");
                             let auto_deps_str = auto_dependencies.join(", ");
@@ -3090,7 +3090,7 @@ pub async fn register_chat_capabilities(
                     };
 
                     let code = map
-                        .get(&MapKey::Keyword(Keyword("code".to_string())))
+                        .get(&MapKey::Keyword(Keyword("code".into())))
                         .or_else(|| map.get(&MapKey::String("code".to_string())))
                         .and_then(|v| v.as_string())
                         .ok_or_else(|| RuntimeError::Generic("Missing 'code' parameter".to_string()))?
@@ -3317,9 +3317,9 @@ pub async fn filter_mcp_tool_result(
     Ok(strip_ccos_meta(result))
 }
 
-fn get_string_arg(map: &HashMap<MapKey, Value>, key: &str) -> Option<String> {
+fn get_string_arg(map: &ValueMap, key: &str) -> Option<String> {
     map.get(&MapKey::String(key.to_string()))
-        .or_else(|| map.get(&MapKey::Keyword(Keyword(key.to_string()))))
+        .or_else(|| map.get(&MapKey::Keyword(Keyword(key.into()))))
         .and_then(|v| v.as_string().map(|s| s.to_string()))
 }
 
@@ -3352,7 +3352,7 @@ fn parse_verifier_inputs(inputs: &Value) -> RuntimeResult<(String, String, Strin
 
     let max_len = map
         .get(&MapKey::String("max_len".to_string()))
-        .or_else(|| map.get(&MapKey::Keyword(Keyword("max_len".to_string()))))
+        .or_else(|| map.get(&MapKey::Keyword(Keyword("max_len".into()))))
         .and_then(|v| v.as_number())
         .map(|n| n as usize)
         .unwrap_or(280);
@@ -3378,7 +3378,7 @@ fn parse_egress_inputs(
     };
     let content = map
         .get(&MapKey::String("content".to_string()))
-        .or_else(|| map.get(&MapKey::Keyword(Keyword("content".to_string()))))
+        .or_else(|| map.get(&MapKey::Keyword(Keyword("content".into()))))
         .ok_or_else(|| RuntimeError::Generic("Missing content".to_string()))?
         .clone();
     let session_id = get_string_arg(map, "session_id")
@@ -3598,7 +3598,7 @@ mod tests {
                                  "state": "Scheduled"
                              }).to_string();
                              
-                             Ok(Value::Map(HashMap::from([
+                             Ok(Value::Map(ValueMap::from_iter([
                                  (MapKey::String("status".to_string()), Value::Integer(200)),
                                  (MapKey::String("body".to_string()), Value::String(response_body)),
                              ])))
@@ -3629,7 +3629,7 @@ mod tests {
             crate::config::types::CodingAgentsConfig::default(),
         ).await.unwrap();
 
-        let inputs = Value::Map(HashMap::from([
+        let inputs = Value::Map(ValueMap::from_iter([
             (MapKey::String("goal".to_string()), Value::String("Take over the world".to_string())),
             (MapKey::String("session_id".to_string()), Value::String("session-1".to_string())),
             (MapKey::String("schedule".to_string()), Value::String("in 10s".to_string())),
@@ -3700,7 +3700,7 @@ mod tests {
                                 "state": "Scheduled"
                             })
                             .to_string();
-                            Ok(Value::Map(HashMap::from([
+                            Ok(Value::Map(ValueMap::from_iter([
                                 (MapKey::String("status".to_string()), Value::Integer(200)),
                                 (MapKey::String("body".to_string()), Value::String(response_body)),
                             ])))
@@ -3732,14 +3732,14 @@ mod tests {
         .await
         .unwrap();
 
-        let trigger_inputs_val = Value::Map(HashMap::from([
+        let trigger_inputs_val = Value::Map(ValueMap::from_iter([
             (
                 MapKey::String("code".to_string()),
                 Value::String("import ccos_sdk; print('fib')".to_string()),
             ),
         ]));
 
-        let inputs = Value::Map(HashMap::from([
+        let inputs = Value::Map(ValueMap::from_iter([
             (MapKey::String("goal".to_string()), Value::String("Compute Fibonacci".to_string())),
             (MapKey::String("session_id".to_string()), Value::String("session-fib".to_string())),
             (MapKey::String("schedule".to_string()), Value::String("every 15s".to_string())),
@@ -3818,9 +3818,9 @@ mod tests {
         ).await.unwrap();
 
         // ccos.execute.python with unapproved package 'mpmath'
-        let inputs = Value::Map(HashMap::from([
+        let inputs = Value::Map(ValueMap::from_iter([
             (MapKey::String("code".to_string()), Value::String("import mpmath; print(mpmath.pi)".to_string())),
-            (MapKey::String("dependencies".to_string()), Value::Vector(vec![Value::String("mpmath".to_string())])),
+            (MapKey::String("dependencies".to_string()), Value::Vector(vec![Value::String("mpmath".to_string())].into())),
         ]));
 
         let result = marketplace.execute_capability("ccos.execute.python", &inputs).await;
@@ -3889,7 +3889,7 @@ mod tests {
                                  panic!("Unexpected URL: {}", url);
                              };
                              
-                             Ok(Value::Map(HashMap::from([
+                             Ok(Value::Map(ValueMap::from_iter([
                                  (MapKey::String("status".to_string()), Value::Integer(status)),
                                  (MapKey::String("body".to_string()), Value::String(response_body)),
                              ])))
@@ -3919,7 +3919,7 @@ mod tests {
         ).await.unwrap();
 
         // 1. Test ccos.run.get
-        let get_inputs = Value::Map(HashMap::from([
+        let get_inputs = Value::Map(ValueMap::from_iter([
             (MapKey::String("run_id".to_string()), Value::String("run-456".to_string())),
         ]));
         let get_res = marketplace.execute_capability("ccos.run.get", &get_inputs).await.unwrap();
@@ -3927,7 +3927,7 @@ mod tests {
         assert_eq!(get_map.get(&MapKey::String("run_id".to_string())).unwrap().as_string().unwrap(), "run-456");
 
         // 2. Test ccos.run.list
-        let list_inputs = Value::Map(HashMap::from([
+        let list_inputs = Value::Map(ValueMap::from_iter([
             (MapKey::String("session_id".to_string()), Value::String("session-1".to_string())),
         ]));
         let list_res = marketplace.execute_capability("ccos.run.list", &list_inputs).await.unwrap();
@@ -3935,7 +3935,7 @@ mod tests {
         assert_eq!(list_map.get(&MapKey::String("session_id".to_string())).unwrap().as_string().unwrap(), "session-1");
 
         // 3. Test ccos.run.cancel
-        let cancel_inputs = Value::Map(HashMap::from([
+        let cancel_inputs = Value::Map(ValueMap::from_iter([
             (MapKey::String("run_id".to_string()), Value::String("run-456".to_string())),
         ]));
         let cancel_res = marketplace.execute_capability("ccos.run.cancel", &cancel_inputs).await.unwrap();
@@ -3943,14 +3943,14 @@ mod tests {
         assert_eq!(cancel_map.get(&MapKey::String("cancelled".to_string())).unwrap().as_bool().unwrap(), true);
 
         // 4. Test ccos.run.resume
-        let resume_inputs = Value::Map(HashMap::from([
+        let resume_inputs = Value::Map(ValueMap::from_iter([
             (MapKey::String("run_id".to_string()), Value::String("run-456".to_string())),
         ]));
         let resume_res = marketplace.execute_capability("ccos.run.resume", &resume_inputs).await.unwrap();
         assert_eq!(resume_res.as_number().unwrap(), 200.0);
 
         // 5. Test error case: 401 Unauthorized with empty body (reported EOF error)
-        let get_inputs_err = Value::Map(HashMap::from([
+        let get_inputs_err = Value::Map(ValueMap::from_iter([
             (MapKey::String("run_id".to_string()), Value::String("run-unauthorized".to_string())),
         ]));
         let err_res = marketplace.execute_capability("ccos.run.get", &get_inputs_err).await;
@@ -3960,7 +3960,7 @@ mod tests {
         assert!(!err_msg.contains("EOF"));
 
         // 6. Test ephemeral run retrieval (verifying 404 fix)
-        let get_inputs_eph = Value::Map(HashMap::from([
+        let get_inputs_eph = Value::Map(ValueMap::from_iter([
             (MapKey::String("run_id".to_string()), Value::String("chat-run-123".to_string())),
         ]));
         let eph_res = marketplace.execute_capability("ccos.run.get", &get_inputs_eph).await.unwrap();
@@ -76,7 +76,7 @@ impl Predicate {
 
         let actions_clone = actions_arc.clone();
         evaluator.env.define(
-            &Symbol("audit.succeeded?".to_string()),
+            &Symbol("audit.succeeded?".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "audit.succeeded?".to_string(),
                 arity: Arity::Fixed(1),
@@ -93,7 +93,7 @@ impl Predicate {
 
         let actions_clone = actions_arc.clone();
         evaluator.env.define(
-            &Symbol("audit.failed?".to_string()),
+            &Symbol("audit.failed?".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "audit.failed?".to_string(),
                 arity: Arity::Fixed(1),
@@ -110,7 +110,7 @@ impl Predicate {
 
         let actions_clone = actions_arc.clone();
         evaluator.env.define(
-            &Symbol("audit.metadata?".to_string()),
+            &Symbol("audit.metadata?".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "audit.metadata?".to_string(),
                 arity: Arity::Fixed(3),
@@ -134,7 +134,7 @@ impl From<&Action> for ActionView {
                 {
                     // Try both String and Keyword keys
                     let rtfs_key = rtfs::ast::MapKey::String(key.to_string());
-                    let kw_key = rtfs::ast::MapKey::Keyword(rtfs::ast::Keyword(key.to_string()));
+                    let kw_key = rtfs::ast::MapKey::Keyword(rtfs::ast::Keyword::new(key));
                     
                     if let Some(val) = result_map.get(&rtfs_key).or_else(|| result_map.get(&kw_key)) {
                         // Only add if not already in metadata
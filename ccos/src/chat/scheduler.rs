@@ -106,10 +106,10 @@ impl Scheduler {
 
                 let mut inputs = if let Some(json_inputs) = trigger_inputs {
                     crate::utils::value_conversion::json_to_rtfs_value(&json_inputs).unwrap_or(
-                        rtfs::runtime::values::Value::Map(std::collections::HashMap::new()),
+                        rtfs::runtime::values::Value::Map(std::collections::HashMap::new().into()),
                     )
                 } else {
-                    rtfs::runtime::values::Value::Map(std::collections::HashMap::new())
+                    rtfs::runtime::values::Value::Map(std::collections::HashMap::new().into())
                 };
 
                 // Inject session tracking parameters
@@ -160,7 +160,7 @@ impl Scheduler {
                             let stdout = match &result {
                                 rtfs::runtime::values::Value::Map(m) => m
                                     .get(&rtfs::ast::MapKey::Keyword(rtfs::ast::Keyword(
-                                        "stdout".to_string(),
+                                        "stdout".into(),
                                     )))
                                     .or_else(|| {
                                         m.get(&rtfs::ast::MapKey::String("stdout".to_string()))
@@ -172,7 +172,7 @@ impl Scheduler {
                             let success = match &result {
                                 rtfs::runtime::values::Value::Map(m) => m
                                     .get(&rtfs::ast::MapKey::Keyword(rtfs::ast::Keyword(
-                                        "success".to_string(),
+                                        "success".into(),
                                     )))
                                     .or_else(|| {
                                         m.get(&rtfs::ast::MapKey::String("success".to_string()))
@@ -447,7 +447,7 @@ mod tests {
     use crate::chat::{MessageEnvelope, RealTimeTrackingSink};
     use async_trait::async_trait;
     use rtfs::runtime::error::RuntimeResult;
-    use rtfs::runtime::values::Value;
+    use rtfs::runtime::values::{Value, ValueMap};
     use std::collections::{HashMap, VecDeque};
     use std::sync::{Arc, Mutex};
     use tokio::sync::RwLock;
@@ -508,7 +508,7 @@ mod tests {
                 "Test Capability".to_string(),
                 "Returns success".to_string(),
                 Arc::new(|_inputs: &Value| {
-                    Ok(Value::Map(HashMap::from([(
+                    Ok(Value::Map(ValueMap::from_iter([(
                         rtfs::ast::MapKey::String("ok".to_string()),
                         Value::Boolean(true),
                     )])))
@@ -31,6 +31,58 @@ impl Archivable for CheckpointRecord {
     }
 }
 
+/// Summary of a stored checkpoint, without its serialized context
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CheckpointMeta {
+    pub checkpoint_id: String,
+    pub plan_id: String,
+    pub intent_id: String,
+    /// Label given to `(checkpoint "label")`, if any
+    pub label: Option<String>,
+    pub created_at: u64,
+    /// Size of the serialized context in bytes
+    pub size_bytes: usize,
+}
+
+impl From<&CheckpointRecord> for CheckpointMeta {
+    fn from(record: &CheckpointRecord) -> Self {
+        Self {
+            checkpoint_id: record.checkpoint_id.clone(),
+            plan_id: record.plan_id.clone(),
+            intent_id: record.intent_id.clone(),
+            label: record.metadata.get("label").cloned(),
+            created_at: record.created_at,
+            size_bytes: record.serialized_context.len(),
+        }
+    }
+}
+
+/// Retention policy for [`CheckpointArchive::prune`]. Both limits apply per plan;
+/// a checkpoint is dropped when it violates either.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CheckpointPrunePolicy {
+    /// Keep at most this many of the newest checkpoints of each plan
+    pub keep_latest: Option<usize>,
+    /// Drop checkpoints older than this many seconds
+    pub max_age_secs: Option<u64>,
+}
+
+impl CheckpointPrunePolicy {
+    pub fn keep_latest(count: usize) -> Self {
+        Self {
+            keep_latest: Some(count),
+            max_age_secs: None,
+        }
+    }
+
+    pub fn max_age(secs: u64) -> Self {
+        Self {
+            keep_latest: None,
+            max_age_secs: Some(secs),
+        }
+    }
+}
+
 /// In-memory checkpoint archive
 #[derive(Debug, Default)]
 pub struct CheckpointArchive {
@@ -126,6 +178,66 @@ impl CheckpointArchive {
         }
     }
 
+    /// List the checkpoints of a plan, newest first
+    pub fn list_checkpoints(&self, plan_id: &str) -> Vec<CheckpointMeta> {
+        let mut metas: Vec<CheckpointMeta> = self
+            .id_index
+            .lock()
+            .map(|index| {
+                index
+                    .values()
+                    .filter(|record| record.plan_id == plan_id)
+                    .map(CheckpointMeta::from)
+                    .collect()
+            })
+            .unwrap_or_default();
+        metas.sort_by(|a, b| {
+            b.created_at
+                .cmp(&a.created_at)
+                .then_with(|| b.checkpoint_id.cmp(&a.checkpoint_id))
+        });
+        metas
+    }
+
+    /// Drop checkpoints beyond the policy's per-plan count or age, including their
+    /// durable files. Returns the ids of the dropped checkpoints.
+    pub fn prune(&self, policy: &CheckpointPrunePolicy) -> Vec<String> {
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or_default();
+        let plan_ids: std::collections::BTreeSet<String> = match self.id_index.lock() {
+            Ok(index) => index.values().map(|r| r.plan_id.clone()).collect(),
+            Err(_) => return Vec::new(),
+        };
+
+        let mut pruned = Vec::new();
+        for plan_id in plan_ids {
+            for (position, meta) in self.list_checkpoints(&plan_id).into_iter().enumerate() {
+                let beyond_count = policy.keep_latest.is_some_and(|keep| position >= keep);
+                let too_old = policy
+                    .max_age_secs
+                    .is_some_and(|max_age| now.saturating_sub(meta.created_at) > max_age);
+                if (beyond_count || too_old) && self.purge(&meta.checkpoint_id) {
+                    pruned.push(meta.checkpoint_id);
+                }
+            }
+        }
+        pruned
+    }
+
+    /// Remove a checkpoint from the index, the content-addressed store and disk
+    fn purge(&self, checkpoint_id: &str) -> bool {
+        let Some(record) = self.remove_checkpoint(checkpoint_id) else {
+            return false;
+        };
+        let _ = self.storage.delete(&record.content_hash());
+        if let Some(dir) = &self.durable_dir {
+            let _ = fs::remove_file(dir.join(format!("{}.json", checkpoint_id)));
+        }
+        true
+    }
+
     /// Find the most recent checkpoint for a plan/intent pair (by created_at)
     pub fn find_latest_for_plan_intent(
         &self,
@@ -95,7 +95,7 @@ async fn run_rtfs_expr_in_process(expr: &str) -> RuntimeResult<()> {
     if let Some(stdlib) = module_registry.get_module("stdlib") {
         if let Ok(exports) = stdlib.exports.read() {
             for (name, export) in exports.iter() {
-                let sym = Symbol(name.clone());
+                let sym = Symbol::new(name.as_str());
                 env.define(&sym, export.value.clone());
             }
         }
@@ -234,9 +234,9 @@ impl HostInterface for MarketplaceHost {
         let input = if args.len() == 1 {
             args[0].clone()
         } else if args.is_empty() {
-            Value::Map(std::collections::HashMap::new())
+            Value::Map(std::collections::HashMap::new().into())
         } else {
-            Value::Vector(args.to_vec())
+            Value::Vector(args.to_vec().into())
         };
         self.async_ctx.block_on(async {
             self.marketplace
@@ -18,18 +18,22 @@ use super::llm_provider::{LlmProvider, LlmProviderFactory};
 use super::plan_generation::{
     LlmRtfsPlanGenerationProvider, PlanGenerationProvider, PlanGenerationResult,
 };
-use super::prompt::{FilePromptStore, PromptManager};
+use super::prompt::{render_template, FilePromptStore, PromptManager, PromptTemplates};
 
 use crate::capability_marketplace::types::{CapabilityKind, CapabilityManifest, CapabilityQuery};
 use crate::capability_marketplace::CapabilityMarketplace;
+use crate::causal_chain::CausalChain;
 use crate::delegation_keys::{agent, generation};
 use crate::llm::tool_calling::{
     capability_id_to_tool_name, resolve_capability_id, ToolChatMessage, ToolChatRequest,
     ToolDefinition,
 };
+use crate::observability::llm_usage::{LlmUsageTracker, TokenUsage};
 use crate::synthesis::artifact_generator::generate_planner_via_arbiter;
 use crate::synthesis::{schema_builder::ParamSchema, InteractionTurn};
-use crate::types::{ExecutionResult, Intent, IntentStatus, Plan, StorableIntent};
+use crate::types::{
+    Action, ActionType, ExecutionResult, Intent, IntentStatus, Plan, StorableIntent,
+};
 use crate::utils::value_conversion::json_to_rtfs_value;
 
 use rtfs::runtime::error::{RuntimeError, RuntimeResult};
@@ -61,8 +65,14 @@ pub struct DelegatingCognitiveEngine {
     intent_graph: std::sync::Arc<std::sync::Mutex<crate::types::IntentGraph>>,
     delegation_analyzer: DelegationAnalyzer,
     prompt_manager: PromptManager<FilePromptStore>,
+    /// Per-model overrides of the built-in prompts
+    prompt_templates: PromptTemplates,
     /// Optional WorkingMemory for learning-driven plan augmentation
     working_memory: Option<Arc<std::sync::Mutex<crate::working_memory::WorkingMemory>>>,
+    /// Token/cost accumulator for LLM calls made by this arbiter
+    llm_usage: Option<LlmUsageTracker>,
+    /// Causal chain receiving one step per graph/plan generation call
+    causal_chain: Option<Arc<std::sync::Mutex<CausalChain>>>,
 }
 
 impl DelegatingCognitiveEngine {
@@ -164,7 +174,10 @@ impl DelegatingCognitiveEngine {
             intent_graph,
             delegation_analyzer,
             prompt_manager,
+            prompt_templates: PromptTemplates::default(),
             working_memory: None,
+            llm_usage: None,
+            causal_chain: None,
         }
     }
 
@@ -210,7 +223,10 @@ impl DelegatingCognitiveEngine {
             intent_graph,
             delegation_analyzer,
             prompt_manager,
+            prompt_templates: PromptTemplates::default(),
             working_memory: None,
+            llm_usage: None,
+            causal_chain: None,
         })
     }
 
@@ -233,6 +249,80 @@ impl DelegatingCognitiveEngine {
         Ok(arbiter)
     }
 
+    /// Replace the prompt templates, rejecting templates missing a required placeholder
+    pub fn set_prompt_templates(&mut self, templates: PromptTemplates) -> RuntimeResult<()> {
+        templates.validate()?;
+        self.delegation_analyzer
+            .set_prompt_template(templates.delegation_analysis.clone());
+        self.prompt_templates = templates;
+        Ok(())
+    }
+
+    /// Prompt templates currently overriding the built-in prompts
+    pub fn prompt_templates(&self) -> &PromptTemplates {
+        &self.prompt_templates
+    }
+
+    /// Account the token usage of graph and plan generation to `tracker` and
+    /// record each generation call as a step on `causal_chain`
+    pub fn set_usage_accounting(
+        &mut self,
+        tracker: LlmUsageTracker,
+        causal_chain: Arc<std::sync::Mutex<CausalChain>>,
+    ) {
+        self.llm_usage = Some(tracker);
+        self.causal_chain = Some(causal_chain);
+    }
+
+    /// Tracker accumulating this arbiter's LLM usage, if accounting is enabled
+    pub fn llm_usage(&self) -> Option<&LlmUsageTracker> {
+        self.llm_usage.as_ref()
+    }
+
+    /// Record one LLM generation step: accumulate its usage and append an
+    /// `InternalStep` action carrying the token counts and estimated cost
+    fn record_llm_usage(
+        &self,
+        step: &str,
+        usage: TokenUsage,
+        plan_id: Option<String>,
+        intent_id: Option<String>,
+    ) {
+        let Some(tracker) = &self.llm_usage else {
+            return;
+        };
+        let model = self.llm_config.model.as_str();
+        let cost = tracker.record(model, &usage);
+
+        let Some(chain) = &self.causal_chain else {
+            return;
+        };
+        let mut action = Action::new(ActionType::InternalStep, plan_id, intent_id)
+            .with_name(step)
+            .with_metadata("llm.model", model);
+        if let Some(tokens) = usage.prompt_tokens {
+            action.metadata.insert(
+                "llm.prompt_tokens".to_string(),
+                Value::Integer(tokens as i64),
+            );
+        }
+        if let Some(tokens) = usage.completion_tokens {
+            action.metadata.insert(
+                "llm.completion_tokens".to_string(),
+                Value::Integer(tokens as i64),
+            );
+        }
+        if let Some(cost) = cost {
+            action
+                .metadata
+                .insert("llm.estimated_cost_usd".to_string(), Value::Float(cost));
+        }
+        action.cost = cost;
+        if let Ok(mut chain) = chain.lock() {
+            let _ = chain.append(&action);
+        }
+    }
+
     /// Get the LLM configuration used by this arbiter
     pub fn get_llm_config(&self) -> &LlmConfig {
         &self.llm_config
@@ -1053,6 +1143,20 @@ The tool name MUST be one of: {}"#,
             self.generate_direct_plan(intent, context).await?
         };
 
+        let token_count = |key: &str| match plan.metadata.get(key) {
+            Some(Value::Integer(n)) if *n >= 0 => Some(*n as u64),
+            _ => None,
+        };
+        self.record_llm_usage(
+            "arbiter.plan_generation",
+            TokenUsage {
+                prompt_tokens: token_count("llm.prompt_tokens"),
+                completion_tokens: token_count("llm.completion_tokens"),
+            },
+            Some(plan.plan_id.clone()),
+            Some(intent.intent_id.clone()),
+        );
+
         // [Learning Integration] Step 3: Augment plan with learning modifications
         if !pattern_mods.is_empty() {
             let result = super::learning_augmenter::augment_plan_with_learning(plan, &pattern_mods);
@@ -1411,6 +1515,7 @@ The tool name MUST be one of: {}"#,
             priority: 0,
             created_at: intent.created_at,
             updated_at: intent.updated_at,
+            version: 0,
             metadata: {
                 let mut meta = intent
                     .metadata
@@ -1511,6 +1616,7 @@ The tool name MUST be one of: {}"#,
             priority: 0,
             created_at: intent.created_at,
             updated_at: intent.updated_at,
+            version: 0,
             metadata: intent
                 .metadata
                 .iter()
@@ -1525,10 +1631,17 @@ The tool name MUST be one of: {}"#,
                 .collect::<HashMap<String, String>>()
         });
 
-        let plan = self
-            .llm_provider
-            .generate_plan(&storable_intent, string_context)
-            .await?;
+        let plan = match &self.prompt_templates.plan_generation {
+            Some(template) => {
+                self.generate_plan_from_template(template, intent, string_context)
+                    .await?
+            }
+            None => {
+                self.llm_provider
+                    .generate_plan(&storable_intent, string_context)
+                    .await?
+            }
+        };
         // Log provider and result
         let _ = (|| -> Result<(), std::io::Error> {
             let mut f = OpenOptions::new()
@@ -1555,6 +1668,41 @@ The tool name MUST be one of: {}"#,
         Ok(plan)
     }
 
+    /// Generate a plan by sending the `plan_generation` prompt template as raw text
+    async fn generate_plan_from_template(
+        &self,
+        template: &str,
+        intent: &Intent,
+        context: Option<HashMap<String, String>>,
+    ) -> Result<Plan, RuntimeError> {
+        let vars = HashMap::from([
+            (
+                "intent".to_string(),
+                serde_json::to_string(intent).unwrap_or_else(|_| intent.goal.clone()),
+            ),
+            ("goal".to_string(), intent.goal.clone()),
+            (
+                "context".to_string(),
+                serde_json::to_string(&context.unwrap_or_default())
+                    .unwrap_or_else(|_| "{}".to_string()),
+            ),
+        ]);
+        let response = self
+            .llm_provider
+            .generate_text(&render_template(template, &vars))
+            .await?;
+
+        let code = ["(plan", "(do"]
+            .iter()
+            .find_map(|form| {
+                response
+                    .find(form)
+                    .and_then(|start| Self::extract_balanced_from(&response, start))
+            })
+            .unwrap_or_else(|| response.trim().to_string());
+        Ok(Plan::new_rtfs(code, vec![intent.intent_id.clone()]))
+    }
+
     /// Create prompt for intent generation using file-based prompt store
     fn create_intent_prompt(
         &self,
@@ -1990,6 +2138,7 @@ The tool name MUST be one of: {}"#,
             priority: 1,
             created_at: intent.created_at,
             updated_at: intent.updated_at,
+            version: 0,
             metadata: HashMap::new(),
         };
 
@@ -2056,8 +2205,13 @@ impl CognitiveEngine for DelegatingCognitiveEngine {
         natural_language_goal: &str,
     ) -> Result<String, RuntimeError> {
         // Build a precise prompt instructing the model to output a single RTFS (do ...) graph
-        let prompt = format!(
-            r#"You are the CCOS Arbiter. Convert the natural language goal into an RTFS intent graph.
+        let prompt = match &self.prompt_templates.graph_generation {
+            Some(template) => render_template(
+                template,
+                &HashMap::from([("goal".to_string(), natural_language_goal.to_string())]),
+            ),
+            None => format!(
+                r#"You are the CCOS Arbiter. Convert the natural language goal into an RTFS intent graph.
 
 STRICT OUTPUT RULES:
 - Output EXACTLY one well-formed RTFS s-expression starting with (do ...). No prose, comments, or extra blocks.
@@ -2086,8 +2240,9 @@ Tiny example (format to imitate, not content):
 
 Now output ONLY the RTFS (do ...) block for the provided goal:
 "#,
-            goal = natural_language_goal
-        );
+                goal = natural_language_goal
+            ),
+        };
 
         let response = self.llm_provider.generate_text(&prompt).await?;
 
@@ -2113,7 +2268,17 @@ Now output ONLY the RTFS (do ...) block for the provided goal:
         })();
 
         // Reuse the robust RTFS extraction that prefers a balanced (do ...) block
-        let do_block = self.extract_rtfs_from_response(&response)?;
+        let mut do_block = self.extract_rtfs_from_response(&response)?;
+        // The extraction normalizes a bare (do ...) into a (plan ... :body (do ...));
+        // the graph interpreter wants the body back
+        if do_block.trim_start().starts_with("(plan") {
+            if let Some(body) = do_block
+                .find("(do")
+                .and_then(|idx| Self::extract_balanced_from(&do_block, idx))
+            {
+                do_block = body;
+            }
+        }
 
         // Debug: Show extracted RTFS
         println!("🔍 Extracted RTFS from LLM response:");
@@ -2188,6 +2353,14 @@ Now output ONLY the RTFS (do ...) block for the provided goal:
             )?;
             Ok(())
         })();
+
+        // Text generation reports no token counts; the call is still accounted
+        self.record_llm_usage(
+            "arbiter.graph_generation",
+            TokenUsage::default(),
+            None,
+            Some(root_id.clone()),
+        );
         Ok(root_id)
     }
 
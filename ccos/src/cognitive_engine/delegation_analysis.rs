@@ -1,7 +1,7 @@
 use super::config::DelegationConfig;
 use super::intent_parsing::extract_all_json_from_response;
 use super::llm_provider::LlmProvider;
-use super::prompt::{FilePromptStore, PromptManager};
+use super::prompt::{render_template, FilePromptStore, PromptManager};
 use crate::adaptive_threshold::AdaptiveThresholdCalculator;
 use crate::capability_marketplace::types::CapabilityManifest;
 use crate::types::Intent;
@@ -29,6 +29,8 @@ pub struct DelegationAnalyzer {
     prompt_manager: PromptManager<FilePromptStore>,
     config: DelegationConfig,
     adaptive_threshold_calculator: Option<AdaptiveThresholdCalculator>,
+    /// Inline template used instead of the `delegation_analysis` prompt asset
+    prompt_template: Option<String>,
 }
 
 impl DelegationAnalyzer {
@@ -43,9 +45,15 @@ impl DelegationAnalyzer {
             prompt_manager,
             config,
             adaptive_threshold_calculator,
+            prompt_template: None,
         }
     }
 
+    /// Override the delegation analysis prompt (`None` restores the prompt asset)
+    pub fn set_prompt_template(&mut self, template: Option<String>) {
+        self.prompt_template = template;
+    }
+
     /// Analyze whether delegation is needed for this intent
     pub async fn analyze_need(
         &self,
@@ -143,6 +151,11 @@ impl DelegationAnalyzer {
         vars.insert("intent".to_string(), intent_str);
         vars.insert("context".to_string(), context_str);
         vars.insert("available_agents".to_string(), agent_list);
+        vars.insert("goal".to_string(), intent.goal.clone());
+
+        if let Some(template) = &self.prompt_template {
+            return Ok(render_template(template, &vars));
+        }
 
         let agent_list_for_fallback = vars["available_agents"].clone();
 
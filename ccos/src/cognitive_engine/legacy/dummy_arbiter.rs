@@ -453,6 +453,7 @@ impl DummyArbiter {
             priority: 1,
             created_at: now,
             updated_at: now,
+            version: 0,
             metadata: {
                 let mut map = HashMap::new();
                 for (k, v) in &intent.metadata {
@@ -555,6 +556,7 @@ impl DummyArbiter {
             priority: 1,
             created_at: now,
             updated_at: now,
+            version: 0,
             metadata: HashMap::new(),
         };
 
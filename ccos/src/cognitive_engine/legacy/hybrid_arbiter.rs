@@ -78,7 +78,7 @@ fn expr_to_value(expr: &rtfs::ast::Expression) -> Value {
             for (k, v) in m {
                 map.insert(k.clone(), expr_to_value(v));
             }
-            Value::Map(map)
+            Value::Map(map.into())
         }
         E::Vector(vec) | E::List(vec) => {
             let vals = vec.iter().map(expr_to_value).collect();
@@ -89,32 +89,33 @@ fn expr_to_value(expr: &rtfs::ast::Expression) -> Value {
             }
         }
         E::Symbol(s) => Value::Symbol(rtfs::ast::Symbol(s.0.clone())),
-        E::FunctionCall { callee, arguments } => {
+        E::FunctionCall {
+            callee, arguments, ..
+        } => {
             // Convert function calls to a list representation for storage
             let mut func_list = vec![expr_to_value(callee)];
             func_list.extend(arguments.iter().map(expr_to_value));
-            Value::List(func_list)
+            Value::List(func_list.into())
         }
         E::Fn(fn_expr) => {
             // Convert fn expressions to a list representation: (fn params body...)
-            let mut fn_list = vec![Value::Symbol(rtfs::ast::Symbol("fn".to_string()))];
+            let mut fn_list = vec![Value::Symbol(rtfs::ast::Symbol("fn".into()))];
 
             // Add parameters as a vector
             let mut params = Vec::new();
             for param in &fn_expr.params {
-                params.push(Value::Symbol(rtfs::ast::Symbol(format!(
-                    "{:?}",
-                    param.pattern
-                ))));
+                params.push(Value::Symbol(rtfs::ast::Symbol(
+                    format!("{:?}", param.pattern).into(),
+                )));
             }
-            fn_list.push(Value::Vector(params));
+            fn_list.push(Value::Vector(params.into()));
 
             // Add body expressions
             for body_expr in &fn_expr.body {
                 fn_list.push(expr_to_value(body_expr));
             }
 
-            Value::List(fn_list)
+            Value::List(fn_list.into())
         }
         _ => Value::Nil,
     }
@@ -128,7 +129,7 @@ fn map_expr_to_string_value(
         let mut out = std::collections::HashMap::new();
         for (k, v) in m {
             let key_str = match k {
-                MapKey::Keyword(k) => k.0.clone(),
+                MapKey::Keyword(k) => k.0.to_string(),
                 MapKey::String(s) => s.clone(),
                 MapKey::Integer(i) => i.to_string(),
             };
@@ -143,7 +144,10 @@ fn map_expr_to_string_value(
 fn intent_from_function_call(expr: &rtfs::ast::Expression) -> Option<Intent> {
     use rtfs::ast::{Expression as E, Literal, Symbol};
 
-    let E::FunctionCall { callee, arguments } = expr else {
+    let E::FunctionCall {
+        callee, arguments, ..
+    } = expr
+    else {
         return None;
     };
     let E::Symbol(Symbol(sym)) = &**callee else {
@@ -158,7 +162,7 @@ fn intent_from_function_call(expr: &rtfs::ast::Expression) -> Option<Intent> {
 
     // The first argument is the intent name/type, can be either a symbol or string literal
     let name = if let E::Symbol(Symbol(name_sym)) = &arguments[0] {
-        name_sym.clone()
+        name_sym.to_string()
     } else if let E::Literal(Literal::String(name_str)) = &arguments[0] {
         name_str.clone()
     } else {
@@ -696,6 +700,7 @@ Plan:"#,
             priority: 1,
             created_at: intent.created_at,
             updated_at: intent.updated_at,
+            version: 0,
             metadata: HashMap::new(),
         };
 
@@ -220,6 +220,7 @@ impl TemplateArbiter {
             priority: 1,
             created_at: intent.created_at,
             updated_at: intent.updated_at,
+            version: 0,
             metadata: HashMap::new(),
         };
 
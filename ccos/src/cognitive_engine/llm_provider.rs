@@ -1074,6 +1074,7 @@ impl OpenAILlmProvider {
             priority: 0,
             created_at: now,
             updated_at: now,
+            version: 0,
             metadata: HashMap::new(),
         })
     }
@@ -2335,6 +2336,7 @@ impl AnthropicLlmProvider {
             priority: 0,
             created_at: now,
             updated_at: now,
+            version: 0,
             metadata: HashMap::new(),
         })
     }
@@ -2638,6 +2640,7 @@ impl StubLlmProvider {
                 priority: 0,
                 created_at: now,
                 updated_at: now,
+                version: 0,
                 metadata: HashMap::new(),
             }
         } else if lower_nl.contains("optimize") || lower_nl.contains("improve") {
@@ -2664,6 +2667,7 @@ impl StubLlmProvider {
                 priority: 0,
                 created_at: now,
                 updated_at: now,
+                version: 0,
                 metadata: HashMap::new(),
             }
         } else {
@@ -2691,6 +2695,7 @@ impl StubLlmProvider {
                 priority: 0,
                 created_at: now,
                 updated_at: now,
+                version: 0,
                 metadata: HashMap::new(),
             }
         }
@@ -65,7 +65,7 @@ pub use llm_provider::{LlmProvider, LlmProviderConfig, LlmProviderFactory, StubL
 pub use plan_generation::{
     PlanGenerationProvider, PlanGenerationResult, StubPlanGenerationProvider,
 };
-pub use prompt::{PromptConfig, PromptManager};
+pub use prompt::{PromptConfig, PromptManager, PromptTemplates};
 
 // Re-export configuration types
 pub use config::{
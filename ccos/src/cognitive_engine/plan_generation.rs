@@ -251,6 +251,7 @@ impl PlanGenerationProvider for LlmRtfsPlanGenerationProvider {
             priority: 0,
             created_at: now,
             updated_at: now,
+            version: 0,
             metadata: std::collections::HashMap::new(),
         };
 
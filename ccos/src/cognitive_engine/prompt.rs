@@ -24,6 +24,78 @@ impl Default for PromptConfig {
     }
 }
 
+/// Inline templates replacing the built-in prompts of the delegating engine, so
+/// prompts can be tuned per model without recompiling. `None` keeps the
+/// built-in prompt.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct PromptTemplates {
+    /// Natural language goal to intent graph; requires `{goal}`
+    pub graph_generation: Option<String>,
+    /// Intent to plan; requires `{intent}`, `{goal}` and `{context}` are also set
+    pub plan_generation: Option<String>,
+    /// Whether to delegate an intent; requires `{intent}`, `{goal}`, `{context}`
+    /// and `{available_agents}` are also set
+    pub delegation_analysis: Option<String>,
+}
+
+impl PromptTemplates {
+    pub fn with_graph_generation(
+        mut self,
+        template: impl Into<String>,
+    ) -> Result<Self, RuntimeError> {
+        self.graph_generation = Some(template.into());
+        self.validate()?;
+        Ok(self)
+    }
+
+    pub fn with_plan_generation(
+        mut self,
+        template: impl Into<String>,
+    ) -> Result<Self, RuntimeError> {
+        self.plan_generation = Some(template.into());
+        self.validate()?;
+        Ok(self)
+    }
+
+    pub fn with_delegation_analysis(
+        mut self,
+        template: impl Into<String>,
+    ) -> Result<Self, RuntimeError> {
+        self.delegation_analysis = Some(template.into());
+        self.validate()?;
+        Ok(self)
+    }
+
+    /// Check that every template that is set contains its required placeholder
+    pub fn validate(&self) -> Result<(), RuntimeError> {
+        for (kind, template, placeholder) in [
+            ("graph_generation", &self.graph_generation, "{goal}"),
+            ("plan_generation", &self.plan_generation, "{intent}"),
+            ("delegation_analysis", &self.delegation_analysis, "{intent}"),
+        ] {
+            if let Some(template) = template {
+                if !template.contains(placeholder) {
+                    return Err(RuntimeError::Generic(format!(
+                        "{} prompt template must contain the {} placeholder",
+                        kind, placeholder
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Substitute `{var}` placeholders in a prompt template
+pub fn render_template(template: &str, vars: &HashMap<String, String>) -> String {
+    let mut rendered = template.to_string();
+    for (k, v) in vars {
+        let needle = format!("{{{}}}", k);
+        rendered = rendered.replace(&needle, v);
+    }
+    rendered
+}
+
 #[derive(Clone, Debug)]
 pub struct PromptTemplate {
     pub id: String,
@@ -113,12 +185,6 @@ impl<S: PromptStore> PromptManager<S> {
             }
             buf.push('\n');
         }
-        // simple variable substitution: {var}
-        let mut rendered = buf;
-        for (k, v) in vars {
-            let needle = format!("{{{}}}", k);
-            rendered = rendered.replace(&needle, v);
-        }
-        Ok(rendered)
+        Ok(render_template(&buf, vars))
     }
 }
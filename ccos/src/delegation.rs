@@ -12,6 +12,7 @@ use std::sync::{Arc, RwLock};
 
 use crate::caching::l1_delegation::{DelegationPlan, L1DelegationCache};
 use crate::caching::CacheStats;
+use crate::observability::llm_usage::TokenUsage;
 
 /// Where the evaluator should send the execution.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -297,6 +298,14 @@ pub trait ModelProvider: Send + Sync + std::fmt::Debug {
     fn id(&self) -> &'static str;
     /// Perform inference.  Blocking call for now; async wrapper lives above.
     fn infer(&self, prompt: &str) -> Result<String, Box<dyn std::error::Error + Send + Sync>>;
+    /// Perform inference and report token usage. Providers that do not know
+    /// their token counts fall back to `infer` with unknown usage.
+    fn infer_with_usage(
+        &self,
+        prompt: &str,
+    ) -> Result<(String, TokenUsage), Box<dyn std::error::Error + Send + Sync>> {
+        self.infer(prompt).map(|text| (text, TokenUsage::default()))
+    }
 }
 
 /// Simple echo model for testing - just returns the input with a prefix
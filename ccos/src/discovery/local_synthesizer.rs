@@ -543,7 +543,7 @@ impl LocalSynthesizer {
                         rtfs_code
                     )),
                 );
-                Ok(Value::Map(result))
+                Ok(Value::Map(result.into()))
             });
         metadata.insert("synthesis_method".to_string(), "local_rtfs".to_string());
         metadata.insert("operation_type".to_string(), "display".to_string());
@@ -721,7 +721,8 @@ mod tests {
             Value::String("Interpreter bug".to_string()),
         );
 
-        let issues_value = Value::Vector(vec![Value::Map(issue1), Value::Map(issue2)]);
+        let issues_value =
+            Value::Vector(vec![Value::Map(issue1.into()), Value::Map(issue2.into())].into());
 
         let mut input_map = HashMap::new();
         input_map.insert(MapKey::Keyword(Keyword("issues".to_string())), issues_value);
@@ -730,7 +731,7 @@ mod tests {
             Value::String("rust".to_string()),
         );
 
-        let result = (provider.handler)(&Value::Map(input_map))
+        let result = (provider.handler)(&Value::Map(input_map.into()))
             .expect("restricted runtime should execute filter");
 
         let output = match result {
@@ -3,7 +3,7 @@
 use crate::types::Plan;
 use crate::utils::value_conversion;
 use rtfs::ast::TypeExpr;
-use rtfs::runtime::values::Value;
+use rtfs::runtime::values::{Value, ValueMap};
 use serde_json::Value as JsonValue;
 
 /// Represents a needed capability that may not yet exist
@@ -218,7 +218,7 @@ impl CapabilityNeedExtractor {
 
     /// Extract a rationale from plan metadata if available
     fn extract_rationale_from_plan_metadata(
-        map: &std::collections::HashMap<rtfs::ast::MapKey, Value>,
+        map: &ValueMap,
         _capability_class: &str,
     ) -> Option<String> {
         // Try to find description or name fields
@@ -286,13 +286,10 @@ impl CapabilityNeedExtractor {
 }
 
 /// Helper function to get a value from a map (handles both string and keyword keys)
-fn map_get<'a>(
-    map: &'a std::collections::HashMap<rtfs::ast::MapKey, Value>,
-    key: &str,
-) -> Option<&'a Value> {
+fn map_get<'a>(map: &'a ValueMap, key: &str) -> Option<&'a Value> {
     use rtfs::ast::{Keyword, MapKey};
     map.get(&MapKey::String(key.to_string()))
-        .or_else(|| map.get(&MapKey::Keyword(Keyword(key.to_string()))))
+        .or_else(|| map.get(&MapKey::Keyword(Keyword(key.into()))))
 }
 
 // Helper functions for RTFS parsing
@@ -432,18 +429,21 @@ mod tests {
         );
         entry.insert(
             rtfs::ast::MapKey::String("required_inputs".to_string()),
-            Value::Vector(vec![
-                Value::String("origin".to_string()),
-                Value::String("destination".to_string()),
-            ]),
+            Value::Vector(
+                vec![
+                    Value::String("origin".to_string()),
+                    Value::String("destination".to_string()),
+                ]
+                .into(),
+            ),
         );
         entry.insert(
             rtfs::ast::MapKey::String("expected_outputs".to_string()),
-            Value::Vector(vec![Value::String("flight_options".to_string())]),
+            Value::Vector(vec![Value::String("flight_options".to_string())].into()),
         );
         metadata.insert(
             "needs_capabilities".to_string(),
-            Value::Vector(vec![Value::Map(entry)]),
+            Value::Vector(vec![Value::Map(entry.into())].into()),
         );
 
         plan.metadata = metadata;
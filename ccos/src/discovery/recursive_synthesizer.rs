@@ -208,6 +208,7 @@ impl RecursiveSynthesizer {
                 priority: 0,
                 created_at: intent.created_at,
                 updated_at: intent.updated_at,
+                version: 0,
                 metadata: intent
                     .metadata
                     .iter()
@@ -8,7 +8,7 @@
 
 use crate::causal_chain::CausalChain;
 use crate::{capability_marketplace::CapabilityMarketplace, host::RuntimeHost};
-use rtfs::ast::{Expression, TopLevel};
+use rtfs::ast::{Expression, Span, TopLevel};
 use rtfs::parser;
 use rtfs::runtime::host_interface::HostInterface;
 use rtfs::runtime::{
@@ -19,8 +19,11 @@ use rtfs::runtime::{
 };
 use std::sync::Arc;
 // switched to Arc for ModuleRegistry
-use crate::working_memory::{InMemoryJsonlBackend, WorkingMemory, WorkingMemorySink};
+use crate::working_memory::{
+    WorkingMemory, WorkingMemoryConfig, WorkingMemorySink, WorkingMemoryStorage,
+};
 use rtfs::ast::{Keyword, MapKey};
+use rtfs::runtime::values::ValueMap;
 #[allow(unused_imports)]
 use std::cell::RefCell;
 use std::collections::HashMap;
@@ -79,6 +82,8 @@ pub struct CCOSConfig {
     /// Optional WM budgets
     pub wm_max_entries: Option<usize>,
     pub wm_max_tokens: Option<usize>,
+    /// Where Working Memory entries are persisted
+    pub wm_storage: WorkingMemoryStorage,
     /// Preferred microVM provider for sandboxed capabilities
     pub microvm_provider: Option<String>,
     /// Toggle between mock and real HTTP execution
@@ -104,6 +109,7 @@ impl Default for CCOSConfig {
             enable_wm_ingestor: true,
             wm_max_entries: Some(2000),
             wm_max_tokens: Some(200_000),
+            wm_storage: WorkingMemoryStorage::InMemory,
             microvm_provider: None,
             http_mocking_enabled: true,
             http_allow_hosts: None,
@@ -183,8 +189,15 @@ impl CCOSEnvironment {
                 .ok()
                 .and_then(|s| s.parse::<usize>().ok())
                 .or(config.wm_max_tokens);
-            let backend = InMemoryJsonlBackend::new(None, max_entries, max_tokens);
-            let wm = Arc::new(Mutex::new(WorkingMemory::new(Box::new(backend))));
+            let wm_config = WorkingMemoryConfig {
+                storage: config.wm_storage.clone(),
+                max_entries,
+                max_tokens,
+            };
+            let wm = wm_config.build().map_err(|e| {
+                RuntimeError::Generic(format!("Failed to open working memory: {}", e))
+            })?;
+            let wm = Arc::new(Mutex::new(wm));
             // Register sink
             if let Ok(mut chain) = causal_chain.lock() {
                 let sink: Arc<dyn crate::event_sink::CausalChainEventSink> =
@@ -227,12 +240,9 @@ impl CCOSEnvironment {
             let marketplace_for_cap = marketplace.clone();
             let handler = std::sync::Arc::new(move |input: &Value| -> RuntimeResult<Value> {
                 // Closure helpers
-                fn map_get<'a>(
-                    m: &'a std::collections::HashMap<MapKey, Value>,
-                    key: &str,
-                ) -> Option<&'a Value> {
+                fn map_get<'a>(m: &'a ValueMap, key: &str) -> Option<&'a Value> {
                     let k1 = MapKey::String(key.to_string());
-                    let k2 = MapKey::Keyword(Keyword(key.to_string()));
+                    let k2 = MapKey::Keyword(Keyword(key.into()));
                     m.get(&k1).or_else(|| m.get(&k2))
                 }
 
@@ -360,8 +370,9 @@ impl CCOSEnvironment {
                     match input {
                         // New calling convention: { :args [...] , :context ... }
                         Value::Map(m) => {
-                            let args_val =
-                                map_get(m, "args").cloned().unwrap_or(Value::List(vec![]));
+                            let args_val = map_get(m, "args")
+                                .cloned()
+                                .unwrap_or(Value::List(vec![].into()));
                             match args_val {
                                 Value::List(args) => {
                                     // Supported forms:
@@ -505,7 +516,7 @@ impl CCOSEnvironment {
                             MapKey::String("ingested".into()),
                             Value::Integer(ingested as i64),
                         );
-                        Ok(Value::Map(out))
+                        Ok(Value::Map(out.into()))
                     }
                     "replay" => {
                         // Snapshot actions via host and rebuild WM
@@ -574,7 +585,7 @@ impl CCOSEnvironment {
                             MapKey::String("ingested".into()),
                             Value::Integer(records.len() as i64),
                         );
-                        Ok(Value::Map(out))
+                        Ok(Value::Map(out.into()))
                     }
                     _ => Err(RuntimeError::Generic("unreachable mode".into())),
                 }
@@ -770,13 +781,13 @@ impl CCOSEnvironment {
 
                         // Required fields - use Keyword format for consistency with extractor expectations
                         plan_map.insert(
-                            MapKey::Keyword(rtfs::ast::Keyword("type".to_string())),
+                            MapKey::Keyword(rtfs::ast::Keyword("type".into())),
                             Expression::Literal(rtfs::ast::Literal::String("plan".to_string())),
                         );
                         plan_map.insert(
-                            MapKey::Keyword(rtfs::ast::Keyword("name".to_string())),
+                            MapKey::Keyword(rtfs::ast::Keyword("name".into())),
                             Expression::Literal(rtfs::ast::Literal::String(
-                                plan_def.name.0.clone(),
+                                plan_def.name.0.to_string(),
                             )),
                         );
 
@@ -785,7 +796,7 @@ impl CCOSEnvironment {
                         for prop in &plan_def.properties {
                             let mut key_str = prop.key.0.clone();
                             if key_str.starts_with(':') {
-                                key_str = key_str.trim_start_matches(':').to_string();
+                                key_str = key_str.trim_start_matches(':').into();
                             }
                             let map_key = MapKey::Keyword(rtfs::ast::Keyword(key_str));
                             plan_map.insert(map_key, prop.value.clone());
@@ -793,26 +804,20 @@ impl CCOSEnvironment {
 
                         // Ensure :body key exists (fallback to :rtfs-body or :program if provided)
                         let has_body = plan_map
-                            .contains_key(&MapKey::Keyword(rtfs::ast::Keyword("body".to_string())));
+                            .contains_key(&MapKey::Keyword(rtfs::ast::Keyword("body".into())));
                         if !has_body {
                             if let Some(v) = plan_map
-                                .get(&MapKey::Keyword(rtfs::ast::Keyword(
-                                    "rtfs-body".to_string(),
-                                )))
+                                .get(&MapKey::Keyword(rtfs::ast::Keyword("rtfs-body".into())))
                                 .cloned()
                             {
-                                plan_map.insert(
-                                    MapKey::Keyword(rtfs::ast::Keyword("body".to_string())),
-                                    v,
-                                );
+                                plan_map
+                                    .insert(MapKey::Keyword(rtfs::ast::Keyword("body".into())), v);
                             } else if let Some(v) = plan_map
-                                .get(&MapKey::Keyword(rtfs::ast::Keyword("program".to_string())))
+                                .get(&MapKey::Keyword(rtfs::ast::Keyword("program".into())))
                                 .cloned()
                             {
-                                plan_map.insert(
-                                    MapKey::Keyword(rtfs::ast::Keyword("body".to_string())),
-                                    v,
-                                );
+                                plan_map
+                                    .insert(MapKey::Keyword(rtfs::ast::Keyword("body".into())), v);
                             }
                         }
 
@@ -953,7 +958,7 @@ impl CCOSEnvironment {
                                         None
                                     }
                                 })
-                                .unwrap_or_else(|| capability_id.clone());
+                                .unwrap_or_else(|| capability_id.to_string());
                             let cap_desc = description
                                 .unwrap_or_else(|| format!("Loaded capability {}", capability_id));
 
@@ -972,9 +977,9 @@ impl CCOSEnvironment {
                                         let actual_input = match input {
                                             Value::Map(map) => {
                                                 // Check if it's the new calling convention with :args
-                                                if let Some(args_val) = map.get(&MapKey::Keyword(
-                                                    Keyword("args".to_string()),
-                                                )) {
+                                                if let Some(args_val) = map
+                                                    .get(&MapKey::Keyword(Keyword("args".into())))
+                                                {
                                                     match args_val {
                                                         Value::List(args) if args.len() == 1 => {
                                                             // Single argument - unwrap it
@@ -983,7 +988,7 @@ impl CCOSEnvironment {
                                                         Value::List(args) => {
                                                             // Multiple arguments - wrap in a map or use first
                                                             // For now, use first argument (most common case)
-                                                            args.first()
+                                                            args.front()
                                                                 .cloned()
                                                                 .unwrap_or(Value::Nil)
                                                         }
@@ -1013,7 +1018,7 @@ impl CCOSEnvironment {
                                         );
                                         // Provide the actual input under a conventional symbol 'input'
                                         eval.env.define(
-                                            &rtfs::ast::Symbol("input".to_string()),
+                                            &rtfs::ast::Symbol("input".into()),
                                             actual_input,
                                         );
 
@@ -1021,8 +1026,9 @@ impl CCOSEnvironment {
                                         let call_expr = rtfs::ast::Expression::FunctionCall {
                                             callee: Box::new((*impl_expr_arc).clone()),
                                             arguments: vec![rtfs::ast::Expression::Symbol(
-                                                rtfs::ast::Symbol("input".to_string()),
+                                                rtfs::ast::Symbol("input".into()),
                                             )],
+                                            span: Span::unknown(),
                                         };
 
                                         match eval.evaluate(&call_expr) {
@@ -1042,7 +1048,7 @@ impl CCOSEnvironment {
                                 let fut = async move {
                                     marketplace_for_cap
                                         .register_local_capability_with_metadata(
-                                            capability_id,
+                                            capability_id.to_string(),
                                             cap_name,
                                             cap_desc,
                                             handler,
@@ -1277,7 +1283,7 @@ impl CCOSEnvironment {
                         .iter()
                         .map(|(name, export)| {
                             (
-                                rtfs::ast::MapKey::Keyword(rtfs::ast::Keyword(name.clone())),
+                                rtfs::ast::MapKey::Keyword(rtfs::ast::Keyword::new(name.as_str())),
                                 export.value.clone(),
                             )
                         })
@@ -1300,7 +1306,7 @@ impl CCOSEnvironment {
                 // Import specific symbols: (import [module :only [sym1 sym2]])
                 for symbol_ast in only_symbols {
                     let symbol_name = &symbol_ast.0;
-                    if let Some(export) = exports.get(symbol_name) {
+                    if let Some(export) = exports.get(symbol_name.as_str()) {
                         evaluator.env.define(
                             &rtfs::ast::Symbol(symbol_name.clone()),
                             export.value.clone(),
@@ -1324,7 +1330,7 @@ impl CCOSEnvironment {
                 for (export_name, export) in exports.iter() {
                     let qualified_name = format!("{}/{}", module_name, export_name);
                     evaluator.env.define(
-                        &rtfs::ast::Symbol(qualified_name.clone()),
+                        &rtfs::ast::Symbol::new(qualified_name.as_str()),
                         export.value.clone(),
                     );
                     if self.config.verbose {
@@ -1471,7 +1477,7 @@ impl CCOSEnvironment {
         // Create module exports from the environment
         let mut exports = std::collections::HashMap::new();
         for symbol_name in module_env.symbol_names() {
-            if let Some(value) = module_env.lookup(&rtfs::ast::Symbol(symbol_name.clone())) {
+            if let Some(value) = module_env.lookup(&rtfs::ast::Symbol::new(symbol_name.as_str())) {
                 let export = rtfs::runtime::module_runtime::ModuleExport {
                     original_name: symbol_name.clone(),
                     export_name: symbol_name.clone(),
@@ -1554,7 +1560,7 @@ impl CCOSEnvironment {
     ///   "discovery_method" -> "mcp_introspection" }
     /// ```
     fn flatten_metadata_map(
-        map: &std::collections::HashMap<rtfs::ast::MapKey, Value>,
+        map: &ValueMap,
         prefix: &str,
         output: &mut std::collections::HashMap<String, String>,
     ) {
@@ -1567,7 +1573,7 @@ impl CCOSEnvironment {
                     if s.starts_with(':') {
                         s[1..].to_string()
                     } else {
-                        s.clone()
+                        s.to_string()
                     }
                 }
                 rtfs::ast::MapKey::Integer(i) => i.to_string(),
@@ -1640,7 +1646,7 @@ mod tests {
             Value::String("hello".into()),
         );
 
-        let args = vec![Value::String("single".into()), Value::Map(rec_map)];
+        let args = vec![Value::String("single".into()), Value::Map(rec_map.into())];
 
         // Call capability via host
         let out = env
@@ -1687,7 +1693,7 @@ mod tests {
 
         let args = vec![
             Value::String("batch".into()),
-            Value::List(vec![Value::Map(rec1), Value::Map(rec2)]),
+            Value::List(vec![Value::Map(rec1.into()), Value::Map(rec2.into())].into()),
         ];
 
         let out = env
@@ -1727,7 +1733,7 @@ mod tests {
             MapKey::String("content".into()),
             Value::String("payload".into()),
         );
-        let args_single = vec![Value::String("single".into()), Value::Map(rec_map)];
+        let args_single = vec![Value::String("single".into()), Value::Map(rec_map.into())];
         let _ = env
             .host
             .execute_capability("observability.ingestor:v1.ingest", &args_single)
@@ -1777,7 +1783,7 @@ mod tests {
             MapKey::String("content".into()),
             Value::String("payload".into()),
         );
-        let args = vec![Value::String("single".into()), Value::Map(rec_map)];
+        let args = vec![Value::String("single".into()), Value::Map(rec_map.into())];
         let _ = env
             .host
             .execute_capability("observability.ingestor:v1.ingest", &args)
@@ -1810,7 +1816,7 @@ mod tests {
             MapKey::String("content".into()),
             Value::String("payload".into()),
         );
-        let args = vec![Value::String("single".into()), Value::Map(rec_map)];
+        let args = vec![Value::String("single".into()), Value::Map(rec_map.into())];
         let _ = env
             .host
             .execute_capability("observability.ingestor:v1.ingest", &args)
@@ -1914,6 +1920,12 @@ impl CCOSBuilder {
         self
     }
 
+    /// Select where Working Memory entries are persisted
+    pub fn working_memory_storage(mut self, storage: WorkingMemoryStorage) -> Self {
+        self.config.wm_storage = storage;
+        self
+    }
+
     /// Enable or disable HTTP mocking used by the runtime
     pub fn http_mocking(mut self, enabled: bool) -> Self {
         self.config.http_mocking_enabled = enabled;
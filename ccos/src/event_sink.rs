@@ -7,6 +7,7 @@
 use super::causal_chain::CausalChain;
 use crate::types::{Action, IntentId};
 use rtfs::runtime::RuntimeError;
+use serde::Serialize;
 use std::fmt::Debug;
 use std::result::Result;
 use std::sync::{Arc, Mutex};
@@ -98,3 +99,43 @@ pub trait CausalChainEventSink: Debug + Send + Sync {
         false
     }
 }
+
+/// Typed lifecycle events emitted while a request flows through CCOS:
+/// intent creation, governance review, capability calls and plan execution.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum LifecycleEvent {
+    IntentCreated {
+        intent_id: IntentId,
+    },
+    /// A plan was submitted to the governance kernel
+    PlanProposed {
+        plan_id: String,
+        intent_ids: Vec<IntentId>,
+    },
+    /// The governance kernel accepted the plan for execution
+    PlanApproved {
+        plan_id: String,
+    },
+    CapabilityStarted {
+        plan_id: String,
+        capability_id: String,
+    },
+    CapabilityEnded {
+        plan_id: String,
+        capability_id: String,
+        success: bool,
+    },
+    /// Plan execution finished (completed, failed or paused)
+    ExecutionCompleted {
+        plan_id: String,
+        success: bool,
+    },
+}
+
+/// Observer for the lifecycle event stream, configured on the `Orchestrator`.
+/// Lets consumers (e.g. the viewer) subscribe to one stream instead of polling.
+pub trait LifecycleEventSink: Debug + Send + Sync {
+    /// Called synchronously for each event; implementations should not block.
+    fn on_event(&self, event: &LifecycleEvent);
+}
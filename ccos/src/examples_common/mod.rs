@@ -1,3 +1,6 @@
 pub mod builder;
 pub mod capability_helpers;
 pub mod discovery_utils;
+pub mod test_env;
+
+pub use test_env::test_ccos;
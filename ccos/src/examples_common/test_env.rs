@@ -0,0 +1,52 @@
+//! One-line CCOS setup for examples and tests
+//!
+//! [`test_ccos`] builds a CCOS instance whose intent graph and plan archive
+//! live in memory and whose arbiter runs on the deterministic stub LLM
+//! provider, so no config file, API key or network access is needed.
+
+use std::sync::Arc;
+
+use crate::ccos_core::CCOS;
+use crate::config::types::{AgentConfig, LlmProfile, LlmProfilesConfig};
+use crate::intent_graph::IntentGraphConfig;
+use rtfs::runtime::error::RuntimeResult;
+
+/// Name of the LLM profile selected by [`test_ccos`]
+pub const TEST_LLM_PROFILE: &str = "deterministic-stub";
+
+/// Create a ready CCOS backed by in-memory storage and the deterministic arbiter
+pub async fn test_ccos() -> RuntimeResult<Arc<CCOS>> {
+    // The stub provider is refused unless explicitly allowed; this helper is
+    // test-only by construction
+    std::env::set_var("CCOS_ALLOW_STUB_PROVIDER", "1");
+    let ccos = CCOS::new_with_agent_config_and_configs_and_debug_callback(
+        IntentGraphConfig::with_in_memory_storage(),
+        None,
+        Some(test_agent_config()),
+        None,
+    )
+    .await?;
+    Ok(Arc::new(ccos))
+}
+
+/// Agent config whose only LLM profile is the stub provider, so environment
+/// API keys can never switch the arbiter to a live model
+pub fn test_agent_config() -> AgentConfig {
+    AgentConfig {
+        llm_profiles: Some(LlmProfilesConfig {
+            default: Some(TEST_LLM_PROFILE.to_string()),
+            profiles: vec![LlmProfile {
+                name: TEST_LLM_PROFILE.to_string(),
+                provider: "stub".to_string(),
+                model: "deterministic-stub-model".to_string(),
+                base_url: None,
+                api_key_env: None,
+                api_key: None,
+                temperature: Some(0.0),
+                max_tokens: None,
+            }],
+            model_sets: None,
+        }),
+        ..Default::default()
+    }
+}
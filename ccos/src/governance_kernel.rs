@@ -10,22 +10,28 @@
 //! - Verifying capability attestations.
 //! - Logging all decisions and actions to the Causal Chain.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::{Arc, Mutex, RwLock};
 
 use serde::Serialize;
 
 use crate::cognitive_engine::DelegatingCognitiveEngine;
 
+use rtfs::ast::{Expression, Keyword, Literal, MapKey};
 use rtfs::runtime::error::RuntimeResult;
 use rtfs::runtime::security::RuntimeContext;
-use rtfs::runtime::values::Value;
+use rtfs::runtime::values::{Value, ValueMap};
 
+use super::event_sink::LifecycleEvent;
 use super::governance_judge::PlanJudge;
 use super::intent_graph::IntentGraph;
-use super::orchestrator::Orchestrator;
+use super::orchestrator::{
+    publish_intent_result, run_concurrently, seed_plan_inputs, Orchestrator,
+};
 use super::types::Intent; // for delegation validation
-use super::types::{Action, ActionType, ExecutionResult, Plan, PlanBody, StorableIntent};
+use super::types::{
+    Action, ActionType, ExecutionResult, Plan, PlanBody, PlanLanguage, StorableIntent,
+};
 use crate::capability_marketplace::types::ProviderType;
 use rtfs::runtime::error::RuntimeError;
 use rtfs::runtime::execution_outcome::HostCall;
@@ -39,6 +45,24 @@ pub enum RuleAction {
     RequireGuardianApproval,
 }
 
+/// Decision delivered by an external approver for a plan held by the kernel.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ApprovalDecision {
+    Approve,
+    Reject(String), // Reason
+}
+
+/// A plan waiting for external approval because it touches flagged capabilities.
+#[derive(Debug, Clone, Serialize)]
+pub struct PendingPlanApproval {
+    pub approval_id: String,
+    pub plan_id: String,
+    /// Capabilities that matched an approval-requiring constitution rule
+    pub capabilities: Vec<String>,
+    /// Ids of the matching rules
+    pub rule_ids: Vec<String>,
+}
+
 /// Used by GovernanceKernel to gate external capability synthesis.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum SynthesisRisk {
@@ -171,6 +195,8 @@ pub struct Constitution {
     pub semantic_judge_policy: SemanticJudgePolicy,
     /// Governance policies for budget enforcement
     pub budget_policies: HashMap<String, crate::config::types::PolicyConfig>,
+    /// Structural limits on plan size and nesting
+    pub plan_limits: PlanLimitsPolicy,
 }
 
 /// Policy for the semantic plan judge
@@ -221,6 +247,215 @@ impl Default for ExecutionHintPolicies {
     }
 }
 
+/// Structural limits that block runaway (e.g. LLM-generated) plans before execution
+#[derive(Debug, Clone, Serialize)]
+pub struct PlanLimitsPolicy {
+    /// Maximum number of capability call sites (`call` / `pmap-call`) in a plan body
+    pub max_capability_calls: usize,
+    /// Maximum nesting depth of the plan body's expression tree
+    pub max_nesting_depth: usize,
+}
+
+impl Default for PlanLimitsPolicy {
+    fn default() -> Self {
+        Self {
+            max_capability_calls: 100,
+            max_nesting_depth: 64,
+        }
+    }
+}
+
+/// Size measurements of a plan body, as checked against `PlanLimitsPolicy`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct PlanShape {
+    pub capability_calls: usize,
+    pub nesting_depth: usize,
+}
+
+impl PlanShape {
+    /// Measure a parsed plan body: counts capability call sites and the depth of
+    /// the expression tree (a leaf has depth 1).
+    pub fn measure(expr: &Expression) -> Self {
+        let mut capability_calls = 0;
+        let nesting_depth = Self::walk(expr, &mut |_| capability_calls += 1);
+        Self {
+            capability_calls,
+            nesting_depth,
+        }
+    }
+
+    /// Capability ids named literally (keyword, string or symbol) by the call
+    /// sites of a parsed plan body, in order of appearance and deduplicated.
+    pub fn called_capabilities(expr: &Expression) -> Vec<String> {
+        let mut ids: Vec<String> = Vec::new();
+        Self::walk(expr, &mut |args| {
+            let id = match args.first() {
+                Some(Expression::Literal(Literal::Keyword(k))) => k.0.to_string(),
+                Some(Expression::Literal(Literal::String(s))) => s.clone(),
+                Some(Expression::Symbol(sym)) => sym.0.to_string(),
+                _ => return,
+            };
+            if !ids.contains(&id) {
+                ids.push(id);
+            }
+        });
+        ids
+    }
+
+    /// Depth-first walk returning the tree depth; `on_call` receives the
+    /// arguments of every capability call site.
+    fn walk(expr: &Expression, on_call: &mut dyn FnMut(&[Expression])) -> usize {
+        let children: Vec<&Expression> = match expr {
+            Expression::Literal(_) | Expression::Symbol(_) | Expression::ResourceRef(_) => {
+                return 1
+            }
+            Expression::FunctionCall {
+                callee, arguments, ..
+            } => {
+                if matches!(callee.as_ref(), Expression::Symbol(sym) if Self::is_call_form(&sym.0))
+                {
+                    on_call(arguments);
+                }
+                std::iter::once(callee.as_ref()).chain(arguments).collect()
+            }
+            Expression::List(items) => {
+                if matches!(items.first(), Some(Expression::Symbol(sym)) if Self::is_call_form(&sym.0))
+                {
+                    on_call(&items[1..]);
+                }
+                items.iter().collect()
+            }
+            Expression::Vector(items) => items.iter().collect(),
+            Expression::Map(map) => map.values().collect(),
+            Expression::Quasiquote(inner)
+            | Expression::Unquote(inner)
+            | Expression::UnquoteSplicing(inner)
+            | Expression::Deref(inner) => vec![inner.as_ref()],
+            Expression::WithMetadata { expr, .. } => vec![expr.as_ref()],
+            Expression::If(if_expr) => {
+                let mut c = vec![if_expr.condition.as_ref(), if_expr.then_branch.as_ref()];
+                c.extend(if_expr.else_branch.as_deref());
+                c
+            }
+            Expression::Let(let_expr) => let_expr
+                .bindings
+                .iter()
+                .map(|b| b.value.as_ref())
+                .chain(&let_expr.body)
+                .collect(),
+            Expression::Do(do_expr) => do_expr.expressions.iter().collect(),
+            Expression::Fn(fn_expr) => fn_expr.body.iter().collect(),
+            Expression::Def(def_expr) => vec![def_expr.value.as_ref()],
+            Expression::Defn(defn_expr) => defn_expr.body.iter().collect(),
+            Expression::Defmacro(macro_expr) => macro_expr.body.iter().collect(),
+            Expression::Defstruct(_) => return 1,
+            Expression::TryCatch(try_expr) => try_expr
+                .try_body
+                .iter()
+                .chain(try_expr.catch_clauses.iter().flat_map(|c| &c.body))
+                .chain(try_expr.finally_body.iter().flatten())
+                .collect(),
+            Expression::Match(match_expr) => std::iter::once(match_expr.expression.as_ref())
+                .chain(match_expr.clauses.iter().flat_map(|c| {
+                    c.guard
+                        .as_deref()
+                        .into_iter()
+                        .chain(std::iter::once(c.body.as_ref()))
+                }))
+                .collect(),
+            Expression::For(for_expr) => for_expr
+                .bindings
+                .iter()
+                .chain(std::iter::once(for_expr.body.as_ref()))
+                .collect(),
+        };
+        1 + children
+            .into_iter()
+            .map(|child| Self::walk(child, on_call))
+            .max()
+            .unwrap_or(0)
+    }
+
+    fn is_call_form(name: &str) -> bool {
+        name == "call" || name == "pmap-call"
+    }
+}
+
+/// Structured reason for rejecting a plan that exceeds `PlanLimitsPolicy`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub enum PlanLimitViolation {
+    TooManyCapabilityCalls {
+        count: usize,
+        limit: usize,
+    },
+    NestingTooDeep {
+        depth: usize,
+        limit: usize,
+    },
+    /// The RTFS body could not be parsed, so its size cannot be checked
+    UnparseableBody {
+        message: String,
+    },
+}
+
+impl std::fmt::Display for PlanLimitViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PlanLimitViolation::TooManyCapabilityCalls { count, limit } => write!(
+                f,
+                "plan contains {} capability calls, exceeding the limit of {}",
+                count, limit
+            ),
+            PlanLimitViolation::NestingTooDeep { depth, limit } => write!(
+                f,
+                "plan nesting depth {} exceeds the limit of {}",
+                depth, limit
+            ),
+            PlanLimitViolation::UnparseableBody { message } => {
+                write!(f, "plan body does not parse: {}", message)
+            }
+        }
+    }
+}
+
+/// Surfaces a violation as a `:PlanLimitViolation` application error whose data
+/// map (`:kind` plus the measured values) is readable with `ex-data`.
+impl From<PlanLimitViolation> for RuntimeError {
+    fn from(violation: PlanLimitViolation) -> Self {
+        let keyword = |name: &str| MapKey::Keyword(Keyword::new(name));
+        let (kind, details) = match &violation {
+            PlanLimitViolation::TooManyCapabilityCalls { count, limit } => (
+                "too-many-capability-calls",
+                vec![
+                    ("count", Value::Integer(*count as i64)),
+                    ("limit", Value::Integer(*limit as i64)),
+                ],
+            ),
+            PlanLimitViolation::NestingTooDeep { depth, limit } => (
+                "nesting-too-deep",
+                vec![
+                    ("depth", Value::Integer(*depth as i64)),
+                    ("limit", Value::Integer(*limit as i64)),
+                ],
+            ),
+            PlanLimitViolation::UnparseableBody { message } => (
+                "unparseable-body",
+                vec![("message", Value::String(message.clone()))],
+            ),
+        };
+        let mut data = ValueMap::new();
+        data.insert(keyword("kind"), Value::Keyword(Keyword::new(kind)));
+        for (name, value) in details {
+            data.insert(keyword(name), value);
+        }
+        RuntimeError::ApplicationError {
+            error_type: Keyword::new("PlanLimitViolation"),
+            message: format!("Plan rejected by governance limits: {}", violation),
+            data: Some(Value::Map(data)),
+        }
+    }
+}
+
 impl Default for Constitution {
     fn default() -> Self {
         // Default rules as defined in the spec
@@ -257,6 +492,7 @@ impl Default for Constitution {
             hint_policies: ExecutionHintPolicies::default(),
             semantic_judge_policy: SemanticJudgePolicy::default(),
             budget_policies: HashMap::new(),
+            plan_limits: PlanLimitsPolicy::default(),
         }
     }
 }
@@ -269,8 +505,17 @@ pub struct GovernanceKernel {
     constitution: RwLock<Constitution>,
     delegating_arbiter: RwLock<Option<Arc<DelegatingCognitiveEngine>>>,
     plan_judge: PlanJudge,
+    /// External approver notified of plans awaiting approval (human-in-the-loop)
+    approval_hook: Mutex<Option<tokio::sync::mpsc::UnboundedSender<PendingPlanApproval>>>,
+    /// Plans currently blocked on approval, keyed by approval id
+    pending_approvals: Mutex<HashMap<String, tokio::sync::oneshot::Sender<ApprovalDecision>>>,
+    /// How long a held plan waits for a decision before it is rejected
+    approval_timeout: Mutex<std::time::Duration>,
 }
 
+/// Default time a plan waits for an external approval decision
+pub const DEFAULT_PLAN_APPROVAL_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(300);
+
 impl GovernanceKernel {
     /// Creates a new Governance Kernel.
     pub fn new(
@@ -287,6 +532,9 @@ impl GovernanceKernel {
             constitution: RwLock::new(constitution),
             delegating_arbiter: RwLock::new(None),
             plan_judge: PlanJudge::new(),
+            approval_hook: Mutex::new(None),
+            pending_approvals: Mutex::new(HashMap::new()),
+            approval_timeout: Mutex::new(DEFAULT_PLAN_APPROVAL_TIMEOUT),
         }
     }
 
@@ -523,6 +771,210 @@ impl GovernanceKernel {
         }
     }
 
+    /// Add a rule to the constitution (e.g. flag a capability as requiring approval).
+    pub fn add_rule(&self, rule: ConstitutionRule) {
+        if let Ok(mut constitution) = self.constitution.write() {
+            constitution.rules.push(rule);
+        }
+    }
+
+    /// Register an external approver and return the stream of approval requests.
+    ///
+    /// Once registered, plans touching a capability matched by a
+    /// `RequireHumanApproval` / `RequireGuardianApproval` rule are no longer
+    /// rejected: `validate_and_execute` holds them as pending and waits until
+    /// `resolve_plan_approval` delivers a decision. Registering again replaces
+    /// the previous approver.
+    pub fn subscribe_plan_approvals(
+        &self,
+    ) -> tokio::sync::mpsc::UnboundedReceiver<PendingPlanApproval> {
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+        if let Ok(mut hook) = self.approval_hook.lock() {
+            *hook = Some(tx);
+        }
+        rx
+    }
+
+    /// Set how long a held plan waits for a decision; once it elapses the plan
+    /// is rejected (defaults to `DEFAULT_PLAN_APPROVAL_TIMEOUT`).
+    pub fn set_plan_approval_timeout(&self, timeout: std::time::Duration) {
+        if let Ok(mut approval_timeout) = self.approval_timeout.lock() {
+            *approval_timeout = timeout;
+        }
+    }
+
+    /// Deliver the approver's decision for a pending plan.
+    pub fn resolve_plan_approval(
+        &self,
+        approval_id: &str,
+        decision: ApprovalDecision,
+    ) -> RuntimeResult<()> {
+        let sender = self
+            .pending_approvals
+            .lock()
+            .map_err(|_| RuntimeError::Generic("Failed to lock pending approvals".to_string()))?
+            .remove(approval_id)
+            .ok_or_else(|| {
+                RuntimeError::Generic(format!("No pending plan approval '{}'", approval_id))
+            })?;
+        sender.send(decision).map_err(|_| {
+            RuntimeError::Generic(format!(
+                "Plan awaiting approval '{}' is no longer waiting",
+                approval_id
+            ))
+        })
+    }
+
+    fn has_approval_hook(&self) -> bool {
+        self.approval_hook
+            .lock()
+            .map(|hook| hook.is_some())
+            .unwrap_or(false)
+    }
+
+    /// Block until an external approver resolves a plan that touches flagged
+    /// capabilities, rejecting it if no decision arrives within the approval
+    /// timeout. Returns immediately when no approver is registered or no
+    /// flagged capability is involved.
+    async fn await_plan_approval(&self, plan: &Plan) -> RuntimeResult<()> {
+        let Some(hook) = self.approval_hook.lock().ok().and_then(|h| h.clone()) else {
+            return Ok(());
+        };
+
+        let mut capability_ids = plan.capabilities_required.clone();
+        if let PlanBody::Rtfs(body) = &plan.body {
+            if let Ok(expr) = rtfs::parser::parse_expression(body.trim()) {
+                for id in PlanShape::called_capabilities(&expr) {
+                    if !capability_ids.contains(&id) {
+                        capability_ids.push(id);
+                    }
+                }
+            }
+        }
+
+        let (capabilities, rule_ids) = {
+            let constitution = self.constitution.read().map_err(|_| {
+                RuntimeError::Generic("Failed to acquire constitution lock".to_string())
+            })?;
+            let mut capabilities = Vec::new();
+            let mut rule_ids = Vec::new();
+            for capability_id in &capability_ids {
+                for rule in &constitution.rules {
+                    let needs_approval = matches!(
+                        rule.action,
+                        RuleAction::RequireHumanApproval | RuleAction::RequireGuardianApproval
+                    );
+                    if needs_approval && self.matches_pattern(capability_id, &rule.match_pattern) {
+                        if !capabilities.contains(capability_id) {
+                            capabilities.push(capability_id.clone());
+                        }
+                        if !rule_ids.contains(&rule.id) {
+                            rule_ids.push(rule.id.clone());
+                        }
+                    }
+                }
+            }
+            (capabilities, rule_ids)
+        };
+        if capabilities.is_empty() {
+            return Ok(());
+        }
+
+        let approval_id = format!("plan-approval-{}", uuid::Uuid::new_v4());
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        self.pending_approvals
+            .lock()
+            .map_err(|_| RuntimeError::Generic("Failed to lock pending approvals".to_string()))?
+            .insert(approval_id.clone(), tx);
+
+        let request = PendingPlanApproval {
+            approval_id: approval_id.clone(),
+            plan_id: plan.plan_id.clone(),
+            capabilities,
+            rule_ids,
+        };
+        if hook.send(request).is_err() {
+            if let Ok(mut pending) = self.pending_approvals.lock() {
+                pending.remove(&approval_id);
+            }
+            return Err(RuntimeError::Generic(format!(
+                "Plan {} requires approval but the approver is no longer listening",
+                plan.plan_id
+            )));
+        }
+
+        let timeout = self
+            .approval_timeout
+            .lock()
+            .map(|t| *t)
+            .unwrap_or(DEFAULT_PLAN_APPROVAL_TIMEOUT);
+        let Ok(decision) = tokio::time::timeout(timeout, rx).await else {
+            if let Ok(mut pending) = self.pending_approvals.lock() {
+                pending.remove(&approval_id);
+            }
+            return Err(RuntimeError::Timeout {
+                operation: format!("Approval for plan {}", plan.plan_id),
+                timeout_ms: timeout.as_millis() as u64,
+            });
+        };
+        match decision {
+            Ok(ApprovalDecision::Approve) => Ok(()),
+            Ok(ApprovalDecision::Reject(reason)) => Err(RuntimeError::Generic(format!(
+                "Plan rejected by approver: {}",
+                reason
+            ))),
+            Err(_) => Err(RuntimeError::Generic(format!(
+                "Approval for plan {} was abandoned",
+                plan.plan_id
+            ))),
+        }
+    }
+
+    /// Configure the structural plan limits (capability call count, nesting depth).
+    pub fn set_plan_limits(&self, limits: PlanLimitsPolicy) {
+        if let Ok(mut constitution) = self.constitution.write() {
+            constitution.plan_limits = limits;
+        }
+    }
+
+    /// Checks an RTFS plan body against the configured `PlanLimitsPolicy`.
+    ///
+    /// Returns the measured shape when the plan is within limits. Both `Rtfs`
+    /// bodies and RTFS `Source` bodies are measured, and a body that does not
+    /// parse is rejected rather than let through unmeasured. Plans in other
+    /// languages are not measured (`Ok(None)`).
+    pub fn check_plan_limits(&self, plan: &Plan) -> Result<Option<PlanShape>, PlanLimitViolation> {
+        let body = match (&plan.body, &plan.language) {
+            (PlanBody::Rtfs(body), _) | (PlanBody::Source(body), PlanLanguage::Rtfs20) => body,
+            _ => return Ok(None),
+        };
+        let expr = rtfs::parser::parse_expression(body.trim()).map_err(|e| {
+            PlanLimitViolation::UnparseableBody {
+                message: format!("{:?}", e),
+            }
+        })?;
+        let limits = self
+            .constitution
+            .read()
+            .map(|c| c.plan_limits.clone())
+            .unwrap_or_default();
+
+        let shape = PlanShape::measure(&expr);
+        if shape.capability_calls > limits.max_capability_calls {
+            return Err(PlanLimitViolation::TooManyCapabilityCalls {
+                count: shape.capability_calls,
+                limit: limits.max_capability_calls,
+            });
+        }
+        if shape.nesting_depth > limits.max_nesting_depth {
+            return Err(PlanLimitViolation::NestingTooDeep {
+                depth: shape.nesting_depth,
+                limit: limits.max_nesting_depth,
+            });
+        }
+        Ok(Some(shape))
+    }
+
     /// Performs a semantic judgment of the plan using an LLM.
     /// This acts as a "common sense" check to ensure the plan aligns with the goal.
     pub async fn judge_plan_semantically(
@@ -621,6 +1073,9 @@ impl GovernanceKernel {
     }
 
     /// Convenience wrapper for validate_and_execute_with_policy using default PolicyConfig.
+    ///
+    /// A plan with no `intent_ids` needs no stored intent, so this also runs a
+    /// single standalone plan, e.g. to exercise a capability.
     pub async fn validate_and_execute(
         &self,
         plan: Plan,
@@ -642,6 +1097,12 @@ impl GovernanceKernel {
         context: &RuntimeContext,
         budget_policy: crate::config::types::PolicyConfig,
     ) -> RuntimeResult<ExecutionResult> {
+        self.orchestrator
+            .emit_lifecycle_event(LifecycleEvent::PlanProposed {
+                plan_id: plan.plan_id.clone(),
+                intent_ids: plan.intent_ids.clone(),
+            });
+
         // --- 1. Intent Sanitization (SEP-012) ---
         // For capability-internal plans, intent may be None. Only sanitize if present.
         let intent_opt = self.get_intent(&plan)?;
@@ -661,6 +1122,9 @@ impl GovernanceKernel {
         // Pass execution mode to validation logic
         self.validate_against_constitution(&safe_plan, &execution_mode)?;
 
+        // --- 4b. Plan Size Limits ---
+        self.check_plan_limits(&safe_plan)?;
+
         // --- 5. Semantic Judgment (New Step) ---
         self.judge_plan_semantically(&safe_plan, intent_opt.as_ref())
             .await?;
@@ -673,6 +1137,15 @@ impl GovernanceKernel {
         // TODO: Verify the cryptographic attestations of all capabilities
         // called within the plan.
 
+        // --- 7b. External Approval (human-in-the-loop) ---
+        // Holds the plan until a registered approver resolves it.
+        self.await_plan_approval(&safe_plan).await?;
+
+        self.orchestrator
+            .emit_lifecycle_event(LifecycleEvent::PlanApproved {
+                plan_id: safe_plan.plan_id.clone(),
+            });
+
         // Store execution mode in context for RuntimeHost to access
         let mut context_with_mode = context.clone();
         context_with_mode.cross_plan_params.insert(
@@ -1048,7 +1521,9 @@ Respond with ONLY the corrected RTFS plan code, no explanations."#,
                             // Modes "require-approval", "safe-only" (if deemed unsafe), and "dry-run" are acceptable.
                             // "full" mode is rejected if approval is required.
 
-                            if execution_mode == "full" {
+                            // With an external approver registered, the plan is held for
+                            // approval instead (see await_plan_approval).
+                            if execution_mode == "full" && !self.has_approval_hook() {
                                 return Err(RuntimeError::Generic(format!(
                                     "Plan requires human approval for capability '{}' (rule '{}'), but execution mode is 'full'. Use 'require-approval' mode.",
                                     capability_id, rule.id
@@ -1154,8 +1629,8 @@ Respond with ONLY the corrected RTFS plan code, no explanations."#,
         if let Value::Map(map) = value {
             for (k, v) in map {
                 let key_str = match k {
-                    rtfs::ast::MapKey::Keyword(kw) => &kw.0,
-                    rtfs::ast::MapKey::String(s) => s,
+                    rtfs::ast::MapKey::Keyword(kw) => kw.0.as_str(),
+                    rtfs::ast::MapKey::String(s) => s.as_str(),
                     _ => continue,
                 };
                 if key_str == key {
@@ -1175,8 +1650,8 @@ Respond with ONLY the corrected RTFS plan code, no explanations."#,
         if let Value::Map(map) = value {
             for (k, v) in map {
                 let key_str = match k {
-                    rtfs::ast::MapKey::Keyword(kw) => &kw.0,
-                    rtfs::ast::MapKey::String(s) => s,
+                    rtfs::ast::MapKey::Keyword(kw) => kw.0.as_str(),
+                    rtfs::ast::MapKey::String(s) => s.as_str(),
                     _ => continue,
                 };
                 if key_str == key {
@@ -1196,8 +1671,8 @@ Respond with ONLY the corrected RTFS plan code, no explanations."#,
         if let Value::Map(map) = value {
             for (k, v) in map {
                 let key_str = match k {
-                    rtfs::ast::MapKey::Keyword(kw) => &kw.0,
-                    rtfs::ast::MapKey::String(s) => s,
+                    rtfs::ast::MapKey::Keyword(kw) => kw.0.as_str(),
+                    rtfs::ast::MapKey::String(s) => s.as_str(),
                     _ => continue,
                 };
                 if key_str == key {
@@ -1217,8 +1692,8 @@ Respond with ONLY the corrected RTFS plan code, no explanations."#,
         if let Value::Map(map) = value {
             for (k, v) in map {
                 let key_str = match k {
-                    rtfs::ast::MapKey::Keyword(kw) => &kw.0,
-                    rtfs::ast::MapKey::String(s) => s,
+                    rtfs::ast::MapKey::Keyword(kw) => kw.0.as_str(),
+                    rtfs::ast::MapKey::String(s) => s.as_str(),
                     _ => continue,
                 };
                 if key_str == key {
@@ -1604,6 +2079,23 @@ Respond with ONLY the corrected RTFS plan code, no explanations."#,
         &self,
         root_intent_id: &str,
         initial_context: &RuntimeContext,
+    ) -> RuntimeResult<ExecutionResult> {
+        self.execute_intent_graph_governed_with_inputs(
+            root_intent_id,
+            initial_context,
+            &ValueMap::new(),
+        )
+        .await
+    }
+
+    /// Execute an intent graph through the governance pipeline, giving every
+    /// plan access to the caller-provided `inputs` map through the `inputs`
+    /// variable, e.g. `(get inputs :threshold)`.
+    pub async fn execute_intent_graph_governed_with_inputs(
+        &self,
+        root_intent_id: &str,
+        initial_context: &RuntimeContext,
+        inputs: &ValueMap,
     ) -> RuntimeResult<ExecutionResult> {
         // First validate that the root intent exists and can be executed
         let intent_id = root_intent_id.to_string();
@@ -1631,18 +2123,52 @@ Respond with ONLY the corrected RTFS plan code, no explanations."#,
 
         // Execute each child intent through governance
         let mut enhanced_context = initial_context.clone();
-        enhanced_context.cross_plan_params.clear();
+        seed_plan_inputs(&mut enhanced_context, inputs);
 
+        // Children run in waves of independent intents (see
+        // `Orchestrator::next_wave`); exports merge back in child order
         let mut child_results = Vec::new();
-        for child_intent in children {
-            if let Some(child_plan) = self.get_plan_for_intent(&child_intent.intent_id)? {
-                // Execute each child plan through governance
-                let child_result = self
-                    .validate_and_execute(child_plan, &enhanced_context)
-                    .await?;
+        let mut skipped = Vec::new();
+        let siblings: HashSet<String> = children.iter().map(|c| c.intent_id.clone()).collect();
+        let mut outcomes = HashMap::new();
+        let mut pending: Vec<String> = children.into_iter().map(|c| c.intent_id).collect();
+        loop {
+            let wave = self.orchestrator.next_wave(
+                &siblings,
+                &mut pending,
+                &mut outcomes,
+                &mut skipped,
+            )?;
+            if wave.is_empty() {
+                break;
+            }
+            let wave_context = &enhanced_context.clone();
+            let wave_results = run_concurrently(wave, |child_id| async move {
+                let child_result = match self.get_plan_for_intent(&child_id)? {
+                    // Execute each child plan through governance
+                    Some(child_plan) => {
+                        Some(self.validate_and_execute(child_plan, wave_context).await?)
+                    }
+                    None => None,
+                };
+                Ok::<_, RuntimeError>((child_id, child_result))
+            })
+            .await;
+
+            for wave_result in wave_results {
+                let (child_id, child_result) = wave_result?;
+                let Some(child_result) = child_result else {
+                    outcomes.insert(child_id, None);
+                    continue;
+                };
                 let exported = self.extract_exported_variables(&child_result);
                 enhanced_context.cross_plan_params.extend(exported);
-                child_results.push((child_intent.intent_id.clone(), child_result));
+                publish_intent_result(&mut enhanced_context, &child_id, &child_result);
+                outcomes.insert(
+                    child_id.clone(),
+                    child_result.success.then(|| child_result.value.clone()),
+                );
+                child_results.push((child_id, child_result));
             }
         }
 
@@ -1666,6 +2192,10 @@ Respond with ONLY the corrected RTFS plan code, no explanations."#,
             }
         }
 
+        for child_id in &skipped {
+            result_summary.push(format!("{}: skipped", child_id));
+        }
+
         if let Some(ref root) = root_result {
             if root.success {
                 result_summary.push(format!("root: {}", root.value));
@@ -1766,14 +2296,14 @@ Respond with ONLY the corrected RTFS plan code, no explanations."#,
                 JsonValue::String(s) => Value::String(s),
                 JsonValue::Array(arr) => {
                     let runtime_vec: Vec<Value> = arr.into_iter().map(convert_json_value).collect();
-                    Value::Vector(runtime_vec)
+                    Value::Vector(runtime_vec.into())
                 }
                 JsonValue::Object(obj) => {
                     let mut runtime_map = std::collections::HashMap::new();
                     for (k, v) in obj {
                         runtime_map.insert(rtfs::ast::MapKey::String(k), convert_json_value(v));
                     }
-                    Value::Map(runtime_map)
+                    Value::Map(runtime_map.into())
                 }
             }
         }
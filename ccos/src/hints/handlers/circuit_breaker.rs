@@ -127,8 +127,8 @@ impl CircuitBreakerHintHandler {
         if let Value::Map(map) = value {
             for (k, v) in map {
                 let key_str = match k {
-                    rtfs::ast::MapKey::Keyword(kw) => &kw.0,
-                    rtfs::ast::MapKey::String(s) => s,
+                    rtfs::ast::MapKey::Keyword(kw) => kw.0.as_str(),
+                    rtfs::ast::MapKey::String(s) => s.as_str(),
                     _ => continue,
                 };
                 if key_str == key {
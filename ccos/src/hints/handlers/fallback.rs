@@ -30,8 +30,8 @@ impl FallbackHintHandler {
         if let Value::Map(map) = value {
             for (k, v) in map {
                 let key_str = match k {
-                    rtfs::ast::MapKey::Keyword(kw) => &kw.0,
-                    rtfs::ast::MapKey::String(s) => s,
+                    rtfs::ast::MapKey::Keyword(kw) => kw.0.as_str(),
+                    rtfs::ast::MapKey::String(s) => s.as_str(),
                     _ => continue,
                 };
                 if key_str == key {
@@ -126,7 +126,7 @@ impl HintHandler for FallbackHintHandler {
             ctx.capability_marketplace
                 .execute_capability_enhanced(
                     &fallback_capability,
-                    &Value::List(host_call.args.clone()),
+                    &Value::List(host_call.args.clone().into()),
                     host_call.metadata.as_ref(),
                 )
                 .await
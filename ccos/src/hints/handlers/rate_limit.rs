@@ -86,8 +86,8 @@ impl RateLimitHintHandler {
         if let Value::Map(map) = value {
             for (k, v) in map {
                 let key_str = match k {
-                    rtfs::ast::MapKey::Keyword(kw) => &kw.0,
-                    rtfs::ast::MapKey::String(s) => s,
+                    rtfs::ast::MapKey::Keyword(kw) => kw.0.as_str(),
+                    rtfs::ast::MapKey::String(s) => s.as_str(),
                     _ => continue,
                 };
                 if key_str == key {
@@ -179,10 +179,10 @@ impl HintHandler for RateLimitHintHandler {
                 let mut buckets = self.buckets.lock().unwrap();
                 if let Some(bucket) = buckets.get_mut(&capability_id) {
                     if !bucket.try_consume() {
-                        return Err(RuntimeError::Generic(format!(
-                            "Rate limit exceeded for capability '{}'",
-                            host_call.capability_id
-                        )));
+                        return Err(RuntimeError::RateLimited {
+                            capability_id: host_call.capability_id.clone(),
+                            retry_after_ms: Some(bucket.wait_time().as_millis() as u64),
+                        });
                     }
                 }
             }
@@ -9,6 +9,8 @@ use rtfs::runtime::{RuntimeError, RuntimeResult};
 /// Handler for the `runtime.learning.retry` hint.
 ///
 /// Retries failed capability executions with exponential backoff.
+/// Once the first attempt fails, every attempt is recorded in the causal chain
+/// as a child of the capability call, tagged with its `correlation_id`.
 ///
 /// # Hint Format
 /// ```rtfs
@@ -30,8 +32,8 @@ impl RetryHintHandler {
         if let Value::Map(map) = value {
             for (k, v) in map {
                 let key_str = match k {
-                    rtfs::ast::MapKey::Keyword(kw) => &kw.0,
-                    rtfs::ast::MapKey::String(s) => s,
+                    rtfs::ast::MapKey::Keyword(kw) => kw.0.as_str(),
+                    rtfs::ast::MapKey::String(s) => s.as_str(),
                     _ => continue,
                 };
                 if key_str == key {
@@ -83,6 +85,46 @@ impl HintHandler for RetryHintHandler {
             let max_retries = Self::extract_u64_from_map(hint_value, "max-retries").unwrap_or(3);
             let backoff_ms = Self::extract_u64_from_map(hint_value, "backoff-ms").unwrap_or(100);
 
+            // Attempts are grouped under the correlation id of the capability call
+            let correlation_id = host_call
+                .metadata
+                .as_ref()
+                .and_then(|m| m.context.get("correlation_id"))
+                .cloned();
+            let record_attempt = |attempt: u64, error: Option<&RuntimeError>| {
+                if let Ok(mut chain) = ctx.causal_chain.lock() {
+                    let mut action = Action::new(
+                        ActionType::HintApplied,
+                        format!("capability:{}", host_call.capability_id),
+                        String::new(),
+                    )
+                    .with_parent(correlation_id.clone())
+                    .with_name(&host_call.capability_id)
+                    .with_metadata(
+                        "hint",
+                        &format!("retry:attempt_{}_of_{}", attempt, max_retries + 1),
+                    )
+                    .with_metadata(
+                        "outcome",
+                        if error.is_some() {
+                            "failed"
+                        } else {
+                            "succeeded"
+                        },
+                    );
+                    action
+                        .metadata
+                        .insert("attempt".to_string(), Value::Integer(attempt as i64));
+                    if let Some(id) = &correlation_id {
+                        action = action.with_metadata("correlation_id", id);
+                    }
+                    if let Some(e) = error {
+                        action = action.with_metadata("error", &e.to_string());
+                    }
+                    let _ = chain.append(&action);
+                }
+            };
+
             // First attempt - consume the original next
             let first_result = next().await;
 
@@ -90,24 +132,10 @@ impl HintHandler for RetryHintHandler {
                 Ok(value) => return Ok(value),
                 Err(e) => {
                     let mut last_error = e;
+                    record_attempt(1, Some(&last_error));
 
                     // Retry attempts
                     for attempt in 1..=max_retries {
-                        // Log retry attempt
-                        if let Ok(mut chain) = ctx.causal_chain.lock() {
-                            let _ = chain.append(
-                                &Action::new(
-                                    ActionType::HintApplied,
-                                    format!("capability:{}", host_call.capability_id),
-                                    String::new(),
-                                )
-                                .with_metadata(
-                                    "hint",
-                                    &format!("retry:attempt_{}_of_{}", attempt, max_retries),
-                                ),
-                            );
-                        }
-
                         // Exponential backoff
                         let delay = backoff_ms * (1 << (attempt - 1));
                         tokio::time::sleep(std::time::Duration::from_millis(delay)).await;
@@ -117,14 +145,20 @@ impl HintHandler for RetryHintHandler {
                             .capability_marketplace
                             .execute_capability_enhanced(
                                 &host_call.capability_id,
-                                &Value::List(host_call.args.clone()),
+                                &Value::List(host_call.args.clone().into()),
                                 host_call.metadata.as_ref(),
                             )
                             .await;
 
                         match retry_result {
-                            Ok(value) => return Ok(value),
-                            Err(e) => last_error = e,
+                            Ok(value) => {
+                                record_attempt(attempt + 1, None);
+                                return Ok(value);
+                            }
+                            Err(e) => {
+                                record_attempt(attempt + 1, Some(&e));
+                                last_error = e;
+                            }
                         }
                     }
 
@@ -29,8 +29,8 @@ impl TimeoutHintHandler {
         if let Value::Map(map) = value {
             for (k, v) in map {
                 let key_str = match k {
-                    rtfs::ast::MapKey::Keyword(kw) => &kw.0,
-                    rtfs::ast::MapKey::String(s) => s,
+                    rtfs::ast::MapKey::Keyword(kw) => kw.0.as_str(),
+                    rtfs::ast::MapKey::String(s) => s.as_str(),
                     _ => continue,
                 };
                 if key_str == key {
@@ -96,10 +96,10 @@ impl HintHandler for TimeoutHintHandler {
             // Apply timeout to the next executor
             match tokio::time::timeout(std::time::Duration::from_millis(timeout_ms), next()).await {
                 Ok(result) => result,
-                Err(_) => Err(RuntimeError::Generic(format!(
-                    "Capability '{}' timed out after {}ms",
-                    host_call.capability_id, timeout_ms
-                ))),
+                Err(_) => Err(RuntimeError::Timeout {
+                    operation: format!("Capability '{}'", host_call.capability_id),
+                    timeout_ms,
+                }),
             }
         })
     }
@@ -115,4 +115,56 @@ mod tests {
         assert_eq!(handler.hint_key(), "runtime.learning.timeout");
         assert_eq!(handler.priority(), 20);
     }
+
+    #[tokio::test]
+    async fn test_slow_call_fails_with_timeout() {
+        use crate::capabilities::registry::CapabilityRegistry;
+        use crate::capability_marketplace::CapabilityMarketplace;
+        use crate::causal_chain::CausalChain;
+        use rtfs::runtime::security::RuntimeContext;
+        use std::sync::{Arc, Mutex};
+
+        let ctx = ExecutionContext::new(
+            Arc::new(CapabilityMarketplace::new(Arc::new(
+                tokio::sync::RwLock::new(CapabilityRegistry::new()),
+            ))),
+            Arc::new(Mutex::new(CausalChain::new().unwrap())),
+        );
+        let host_call = HostCall {
+            capability_id: "test.slow".to_string(),
+            args: vec![],
+            security_context: RuntimeContext::full(),
+            causal_context: None,
+            metadata: None,
+        };
+        let hint = Value::Map(
+            [(
+                rtfs::ast::MapKey::Keyword(rtfs::ast::Keyword::new("absolute-ms")),
+                Value::Integer(10),
+            )]
+            .into_iter()
+            .collect(),
+        );
+
+        let result = TimeoutHintHandler::new()
+            .apply(
+                &host_call,
+                &hint,
+                &ctx,
+                Box::new(|| {
+                    Box::pin(async {
+                        tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+                        Ok(Value::Nil)
+                    })
+                }),
+            )
+            .await;
+        assert_eq!(
+            result,
+            Err(RuntimeError::Timeout {
+                operation: "Capability 'test.slow'".to_string(),
+                timeout_ms: 10,
+            })
+        );
+    }
 }
@@ -136,7 +136,7 @@ impl HintHandlerRegistry {
                 .capability_marketplace
                 .execute_capability_enhanced(
                     &host_call.capability_id,
-                    &Value::List(host_call.args.clone()),
+                    &Value::List(host_call.args.clone().into()),
                     host_call.metadata.as_ref(),
                 )
                 .await;
@@ -166,7 +166,7 @@ fn execute_handler_chain<'a>(
                 .capability_marketplace
                 .execute_capability_enhanced(
                     &host_call.capability_id,
-                    &Value::List(host_call.args.clone()),
+                    &Value::List(host_call.args.clone().into()),
                     host_call.metadata.as_ref(),
                 )
                 .await;
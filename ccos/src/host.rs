@@ -7,22 +7,25 @@ use std::collections::HashMap;
 use std::sync::{Arc, Mutex, MutexGuard};
 
 use crate::budget::{BudgetCheckResult, BudgetContext, ExhaustionPolicy, StepConsumption};
+use crate::capability_marketplace::types::CapabilityManifest;
 use crate::capability_marketplace::CapabilityMarketplace;
 use crate::causal_chain::CausalChain;
+use crate::event_sink::LifecycleEvent;
 use crate::approval::storage_file::FileApprovalStorage;
 use crate::approval::types::ApprovalCategory;
 use crate::approval::{RiskAssessment, RiskLevel, UnifiedApprovalQueue};
 use crate::governance_kernel::GovernanceKernel;
 use crate::sandbox::ResourceMetrics;
+use crate::security_policies::ArgRedactionPolicy;
 use crate::orchestrator::Orchestrator;
 use crate::types::{Action, ActionType, ExecutionResult};
 use crate::utils::fs::get_workspace_root;
 use crate::utils::value_conversion::{map_key_to_string, rtfs_value_to_json};
 use rtfs::runtime::error::{RuntimeError, RuntimeResult};
 use rtfs::runtime::execution_outcome::{CallMetadata, CausalContext, HostCall};
-use rtfs::runtime::host_interface::HostInterface;
+use rtfs::runtime::host_interface::{HostInterface, WorkerScope};
 use rtfs::runtime::security::{default_effects_for_capability, RuntimeContext};
-use rtfs::runtime::values::Value;
+use rtfs::runtime::values::{Value, ValueMap};
 // futures::executor used via fully qualified path below
 use rtfs::ast::{MapKey, TypeExpr};
 use sha2::{Digest, Sha256};
@@ -64,6 +67,8 @@ pub struct RuntimeHost {
     orchestrator: Option<Arc<Orchestrator>>,
     // Budget context for resource governance
     budget_context: Mutex<Option<Arc<Mutex<BudgetContext>>>>,
+    // Per-plan memo of pure capability results keyed by (capability, args-hash); None when disabled
+    call_memo: Option<Mutex<HashMap<(String, String), Value>>>,
 }
 
 impl RuntimeHost {
@@ -82,6 +87,7 @@ impl RuntimeHost {
             governance_kernel: None,
             orchestrator: None,
             budget_context: Mutex::new(None),
+            call_memo: None,
         }
     }
 
@@ -100,6 +106,13 @@ impl RuntimeHost {
         self
     }
 
+    /// Enables memoization of pure capability calls for the lifetime of this host.
+    /// Since a host is created per plan execution, cached results never leak across plans.
+    pub fn with_call_memoization(mut self, enabled: bool) -> Self {
+        self.call_memo = enabled.then(|| Mutex::new(HashMap::new()));
+        self
+    }
+
     /// Get a snapshot of capability metrics for a given capability id, if available.
     /// Returns a cloned `CapabilityMetrics` to avoid holding locks or lifetimes.
     pub fn get_capability_metrics(
@@ -624,7 +637,7 @@ impl RuntimeHost {
         args: &[Value],
         result: &RuntimeResult<Value>,
     ) -> (u64, u64) {
-        let input_tokens = Self::estimate_tokens_for_value(&Value::List(args.to_vec()));
+        let input_tokens = Self::estimate_tokens_for_value(&Value::List(args.to_vec().into()));
         let output_tokens = match result {
             Ok(value) => Self::estimate_tokens_for_value(value),
             Err(_) => 0,
@@ -804,7 +817,7 @@ impl RuntimeHost {
             Value::String(hash),
         );
         if !plan_ctx_owned.step_context.is_empty() {
-            let step_map: std::collections::HashMap<MapKey, Value> = plan_ctx_owned
+            let step_map: ValueMap = plan_ctx_owned
                 .step_context
                 .iter()
                 .map(|(k, v)| (MapKey::String(k.clone()), v.clone()))
@@ -832,7 +845,40 @@ impl RuntimeHost {
                 });
             }
         }
-        Some(Value::Map(map))
+        Some(Value::Map(map.into()))
+    }
+
+    /// Copy of `args` suitable for the Causal Chain: values the capability manifest
+    /// declares sensitive are replaced by `***`.
+    fn redacted_args(&self, name: &str, args: &[Value]) -> Vec<Value> {
+        let policy = futures::executor::block_on(async {
+            self.capability_marketplace
+                .get_capability(name)
+                .await
+                .and_then(|manifest| ArgRedactionPolicy::from_manifest(&manifest))
+        });
+        match policy {
+            Some(policy) => policy.redact_args(args),
+            None => args.to_vec(),
+        }
+    }
+
+    /// Computes the memo key for a capability call, or None when the call must not be memoized
+    /// (memoization disabled, capability not registered as pure, or args not hashable).
+    fn memo_key(&self, name: &str, args: &[Value]) -> Option<(String, String)> {
+        self.call_memo.as_ref()?;
+        let cacheable = futures::executor::block_on(async {
+            self.capability_marketplace
+                .get_capability(name)
+                .await
+                .map(|manifest| manifest.is_cacheable())
+        });
+        if cacheable != Some(true) {
+            return None;
+        }
+        let args_json = rtfs_value_to_json(&Value::List(args.to_vec().into())).ok()?;
+        let digest = Sha256::digest(args_json.to_string().as_bytes());
+        Some((name.to_string(), format!("{:x}", digest)))
     }
 
     /// Sets the context for a new plan execution.
@@ -943,23 +989,65 @@ impl RuntimeHost {
     }
 }
 
-impl HostInterface for RuntimeHost {
-    fn execute_capability(&self, name: &str, args: &[Value]) -> RuntimeResult<Value> {
+impl RuntimeHost {
+    /// Emit a lifecycle event through the orchestrator's sink (no-op without orchestrator).
+    fn emit_lifecycle_event(&self, event: LifecycleEvent) {
+        if let Some(orchestrator) = &self.orchestrator {
+            orchestrator.emit_lifecycle_event(event);
+        }
+    }
+
+    /// Look up a registered capability's manifest from synchronous host code.
+    fn get_capability_manifest(&self, name: &str) -> Option<CapabilityManifest> {
+        let marketplace = self.capability_marketplace.clone();
+        let name_owned = name.to_string();
+
+        let runtime_handle = tokio::runtime::Handle::try_current().ok();
+
+        std::thread::spawn(move || {
+            let fut = async move {
+                let caps = marketplace.capabilities.read().await;
+                caps.get(&name_owned).cloned()
+            };
+
+            if let Some(handle) = runtime_handle {
+                handle.block_on(fut)
+            } else {
+                futures::executor::block_on(fut)
+            }
+        })
+        .join()
+        .ok()
+        .flatten()
+    }
+
+    /// Capability execution proper; `execute_capability` wraps it with lifecycle events.
+    fn execute_capability_unobserved(&self, name: &str, args: &[Value]) -> RuntimeResult<Value> {
         // --- Resource Budget Enforcement ---
         self.check_budget_pre_call()?;
         let step_start_time = std::time::Instant::now();
 
         // 1. Security Validation
         if !self.security_context.is_capability_allowed(name) {
-            return Err(RuntimeError::SecurityViolation {
-                operation: "call".to_string(),
-                capability: name.to_string(),
-                context: format!("{:?}", self.security_context),
+            return Err(RuntimeError::CapabilityNotPermitted {
+                capability_id: name.to_string(),
+                reason: format!(
+                    "not allowed by the {:?} security level",
+                    self.security_context.security_level
+                ),
             });
         }
 
-        // Apply RTFS-level argument normalization if schema is available
-        let normalized_args = if let Some(schema) = self.get_capability_input_schema(name) {
+        // Apply RTFS-level argument normalization: named-parameter capabilities take
+        // a single map, otherwise positional args are mapped onto the input schema
+        let manifest = self.get_capability_manifest(name);
+        let named_params = manifest.as_ref().and_then(|m| m.named_params());
+        let normalized_args = if let Some(params) = named_params {
+            vec![crate::capabilities::arg_normalization::spread_named_args(
+                args.to_vec(),
+                &params,
+            )?]
+        } else if let Some(schema) = manifest.and_then(|m| m.input_schema) {
             let normalized = crate::capabilities::arg_normalization::normalize_args_to_map(
                 args.to_vec(),
                 &schema,
@@ -994,16 +1082,16 @@ impl HostInterface for RuntimeHost {
 
         let context = self.get_context()?;
         // New calling convention: provide :args plus optional :context snapshot
-        let mut call_map: std::collections::HashMap<MapKey, Value> =
-            std::collections::HashMap::new();
+        let mut call_map: ValueMap =
+            ValueMap::new();
         call_map.insert(
-            MapKey::Keyword(rtfs::ast::Keyword("args".to_string())),
-            Value::List(args.to_vec()),
+            MapKey::Keyword(rtfs::ast::Keyword("args".into())),
+            Value::List(args.to_vec().into()),
         );
         let snapshot = self.build_context_snapshot(name, args, name);
         if let Some(snapshot_value) = snapshot.clone() {
             call_map.insert(
-                MapKey::Keyword(rtfs::ast::Keyword("context".to_string())),
+                MapKey::Keyword(rtfs::ast::Keyword("context".into())),
                 snapshot_value,
             );
         }
@@ -1039,7 +1127,19 @@ impl HostInterface for RuntimeHost {
         )
         .with_parent(Some(context.parent_action_id.clone()))
         .with_name(name)
-        .with_arguments(&args);
+        .with_arguments(&self.redacted_args(name, args));
+
+        // Every attempt at this call (including retries) shares the call's id
+        action.metadata.insert(
+            "correlation_id".to_string(),
+            Value::String(action.action_id.clone()),
+        );
+        let call_metadata = call_metadata.map(|mut metadata| {
+            metadata
+                .context
+                .insert("correlation_id".to_string(), action.action_id.clone());
+            metadata
+        });
 
         // Add execution mode metadata to action
         if should_simulate {
@@ -1083,6 +1183,22 @@ impl HostInterface for RuntimeHost {
             return Ok(simulated_result);
         }
 
+        // 3b. Reuse the result of an identical earlier call to a pure capability
+        let memo_key = self.memo_key(name, args);
+        if let (Some(key), Some(memo)) = (&memo_key, &self.call_memo) {
+            let cached = memo.lock().ok().and_then(|guard| guard.get(key).cloned());
+            if let Some(value) = cached {
+                let execution_result = ExecutionResult {
+                    success: true,
+                    value: value.clone(),
+                    metadata: HashMap::from([("memoized".to_string(), Value::Boolean(true))]),
+                };
+                self.get_causal_chain()?
+                    .record_result(action, execution_result)?;
+                return Ok(value);
+            }
+        }
+
         // 4. Execute the capability - route through Orchestrator for unified governance, or fallback to Marketplace
         let name_owned = name.to_string();
         let args_owned: Vec<Value> = args.to_vec();
@@ -1151,7 +1267,7 @@ impl HostInterface for RuntimeHost {
 
             std::thread::spawn(move || {
                 let fut = async move {
-                    let args_value = Value::List(args_owned);
+                    let args_value = Value::List(args_owned.into());
                     let meta_ref = call_metadata_owned.as_ref();
                     marketplace
                         .execute_capability_enhanced(&name_owned, &args_value, meta_ref)
@@ -1194,34 +1310,39 @@ impl HostInterface for RuntimeHost {
         self.get_causal_chain()?
             .record_result(action, execution_result)?;
 
+        if let (Some(key), Some(memo), Ok(value)) = (memo_key, &self.call_memo, &result) {
+            if let Ok(mut guard) = memo.lock() {
+                guard.insert(key, value.clone());
+            }
+        }
+
         // --- Resource Budget Metering ---
         let duration_ms = step_start_time.elapsed().as_millis() as u64;
         self.record_budget_consumption(name, duration_ms, args, &result);
 
         result
     }
+}
 
-    fn get_capability_input_schema(&self, name: &str) -> Option<TypeExpr> {
-        let marketplace = self.capability_marketplace.clone();
-        let name_owned = name.to_string();
-
-        let runtime_handle = tokio::runtime::Handle::try_current().ok();
-
-        std::thread::spawn(move || {
-            let fut = async move {
-                let caps = marketplace.capabilities.read().await;
-                caps.get(&name_owned).and_then(|m| m.input_schema.clone())
-            };
+impl HostInterface for RuntimeHost {
+    fn execute_capability(&self, name: &str, args: &[Value]) -> RuntimeResult<Value> {
+        let plan_id = self.get_context().map(|ctx| ctx.plan_id).unwrap_or_default();
+        self.emit_lifecycle_event(LifecycleEvent::CapabilityStarted {
+            plan_id: plan_id.clone(),
+            capability_id: name.to_string(),
+        });
+        let result = self.execute_capability_unobserved(name, args);
+        self.emit_lifecycle_event(LifecycleEvent::CapabilityEnded {
+            plan_id,
+            capability_id: name.to_string(),
+            success: result.is_ok(),
+        });
+        result
+    }
 
-            if let Some(handle) = runtime_handle {
-                handle.block_on(fut)
-            } else {
-                futures::executor::block_on(fut)
-            }
-        })
-        .join()
-        .ok()
-        .flatten()
+    fn get_capability_input_schema(&self, name: &str) -> Option<TypeExpr> {
+        self.get_capability_manifest(name)
+            .and_then(|manifest| manifest.input_schema)
     }
 
     fn notify_step_started(&self, step_name: &str) -> RuntimeResult<String> {
@@ -1331,6 +1452,54 @@ impl HostInterface for RuntimeHost {
         }
     }
 
+    fn create_checkpoint(&self, label: &str, bindings: Value) -> RuntimeResult<String> {
+        let orchestrator = self.orchestrator.as_ref().ok_or_else(|| {
+            RuntimeError::Generic("checkpoint requires an orchestrator-backed host".to_string())
+        })?;
+        let ctx = self.get_context()?;
+        let bindings = match bindings {
+            Value::Map(map) => map
+                .into_iter()
+                .map(|(k, v)| (map_key_to_string(&k), v))
+                .collect(),
+            other => {
+                return Err(RuntimeError::TypeError {
+                    expected: "map".to_string(),
+                    actual: other.type_name().to_string(),
+                    operation: "checkpoint".to_string(),
+                })
+            }
+        };
+        orchestrator.checkpoint_bindings(
+            &ctx.plan_id,
+            ctx.intent_ids.first().map(String::as_str).unwrap_or(""),
+            label,
+            &bindings,
+            &ctx.step_context,
+        )
+    }
+
+    fn restore_checkpoint(&self, checkpoint_id: &str) -> RuntimeResult<Value> {
+        let orchestrator = self.orchestrator.as_ref().ok_or_else(|| {
+            RuntimeError::Generic("restore requires an orchestrator-backed host".to_string())
+        })?;
+        let ctx = self.get_context()?;
+        let (bindings, context) = orchestrator.restore_checkpoint_bindings(
+            &ctx.plan_id,
+            ctx.intent_ids.first().map(String::as_str).unwrap_or(""),
+            checkpoint_id,
+        )?;
+        for (key, value) in context {
+            self.set_step_context_value(key, value)?;
+        }
+        Ok(Value::Map(
+            bindings
+                .into_iter()
+                .map(|(k, v)| (MapKey::Keyword(rtfs::ast::Keyword(k.into())), v))
+                .collect(),
+        ))
+    }
+
     fn set_execution_hint(&self, key: &str, value: Value) -> RuntimeResult<()> {
         if let Ok(mut guard) = self.execution_hints.lock() {
             guard.insert(key.to_string(), value);
@@ -1351,6 +1520,16 @@ impl HostInterface for RuntimeHost {
         }
         Ok(())
     }
+
+    fn concurrent_worker_scope(&self) -> WorkerScope {
+        // Capability calls drive marketplace, HTTP and MCP futures on the Tokio
+        // runtime found via `Handle::try_current`, so workers must enter it too
+        let runtime_handle = tokio::runtime::Handle::try_current().ok();
+        Box::new(move |work| {
+            let _guard = runtime_handle.as_ref().map(|handle| handle.enter());
+            work()
+        })
+    }
 }
 
 impl RuntimeHost {
@@ -1437,7 +1616,7 @@ impl RuntimeHost {
         // Generate appropriate mock based on capability type
         if id_lower.contains("list") || id_lower.contains("get") || id_lower.contains("fetch") {
             // Read operations: return empty list or empty map
-            Ok(Value::List(vec![]))
+            Ok(Value::List(vec![].into()))
         } else if id_lower.contains("create") || id_lower.contains("write") {
             // Write operations: return success indicator
             let mut map = std::collections::HashMap::new();
@@ -1446,7 +1625,7 @@ impl RuntimeHost {
                 MapKey::String("id".to_string()),
                 Value::String("simulated-id".to_string()),
             );
-            Ok(Value::Map(map))
+            Ok(Value::Map(map.into()))
         } else if id_lower.contains("delete") || id_lower.contains("remove") {
             // Delete operations: return success
             Ok(Value::Boolean(true))
@@ -1461,7 +1640,7 @@ impl RuntimeHost {
                 MapKey::String("status".to_string()),
                 Value::String("simulated".to_string()),
             );
-            Ok(Value::Map(map))
+            Ok(Value::Map(map.into()))
         } else {
             // Default: return nil
             Ok(Value::Nil)
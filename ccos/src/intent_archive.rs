@@ -63,6 +63,53 @@ pub trait IntentArchive: ContentAddressableArchive<Intent> {
         }
         out
     }
+
+    /// Case-insensitive search over intent goals, most relevant first.
+    ///
+    /// The query is split into alphanumeric tokens; an intent matches when its
+    /// goal contains at least one token. Intents are ranked by the number of
+    /// distinct tokens matched, then by whether the goal contains the whole
+    /// query, then newest first.
+    fn search_goals(&self, query: &str) -> Vec<Arc<Intent>> {
+        self.search_goals_page(query, 0, usize::MAX)
+    }
+
+    /// Page through [`IntentArchive::search_goals`] results
+    fn search_goals_page(&self, query: &str, offset: usize, limit: usize) -> Vec<Arc<Intent>> {
+        let phrase = query.trim().to_lowercase();
+        let mut tokens: Vec<&str> = phrase
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|t| !t.is_empty())
+            .collect();
+        tokens.sort_unstable();
+        tokens.dedup();
+        if tokens.is_empty() {
+            return Vec::new();
+        }
+
+        let mut scored = Vec::new();
+        for hash in self.list_hashes() {
+            if let Ok(Some(ent)) = self.retrieve(&hash) {
+                let goal = ent.goal.to_lowercase();
+                let hits = tokens.iter().filter(|t| goal.contains(**t)).count();
+                if hits > 0 {
+                    scored.push((hits, goal.contains(&phrase), ent));
+                }
+            }
+        }
+        scored.sort_by(|a, b| {
+            b.0.cmp(&a.0)
+                .then(b.1.cmp(&a.1))
+                .then(b.2.created_at.cmp(&a.2.created_at))
+                .then(a.2.intent_id.cmp(&b.2.intent_id))
+        });
+        scored
+            .into_iter()
+            .skip(offset)
+            .take(limit)
+            .map(|(_, _, ent)| Arc::new(ent))
+            .collect()
+    }
 }
 
 /// In-memory implementation of IntentArchive
@@ -0,0 +1,77 @@
+//! Building storable intents together with their graph edges
+//!
+//! The RTFS [`IntentBuilder`] only knows about intent IDs. This module turns
+//! a builder into a [`StorableIntent`] plus the [`Edge`]s declared with
+//! `.depends_on()`, `.subgoal_of()` and `.conflicts_with()`, ready to be
+//! handed to [`IntentGraph::load_subgraph`](super::IntentGraph::load_subgraph).
+
+use super::storage::Edge;
+use crate::types::{EdgeType, StorableIntent};
+use rtfs::builders::{BuilderError, IntentBuilder, IntentRelation, ObjectBuilder, Priority};
+
+/// Extension for building CCOS intents from an RTFS [`IntentBuilder`]
+pub trait IntentBuilderExt {
+    /// Build the intent and the edges from it to every related intent
+    fn build_with_edges(self) -> Result<(StorableIntent, Vec<Edge>), BuilderError>;
+}
+
+impl IntentBuilderExt for IntentBuilder {
+    fn build_with_edges(self) -> Result<(StorableIntent, Vec<Edge>), BuilderError> {
+        self.validate()
+            .map_err(|errors| BuilderError::Validation(errors.join(", ")))?;
+        let goal = self
+            .goal()
+            .ok_or_else(|| BuilderError::MissingField("goal".to_string()))?;
+
+        let mut intent = StorableIntent::new(goal.to_string());
+        intent.name = Some(self.name().to_string());
+        intent.rtfs_intent_source = self.to_rtfs()?;
+        intent.priority = self.priority().map_or(0, priority_rank);
+        intent.constraints = self
+            .constraints()
+            .iter()
+            .map(|c| (c.name().to_string(), c.value()))
+            .collect();
+        intent.child_intents = self.child_intents().to_vec();
+        intent.metadata = self.metadata().clone();
+
+        // A declared subgoal relationship doubles as the parent link
+        intent.parent_intent = self.parent_intent().map(str::to_string).or_else(|| {
+            self.relations()
+                .iter()
+                .find(|(relation, _)| *relation == IntentRelation::SubgoalOf)
+                .map(|(_, id)| id.clone())
+        });
+
+        let edges = self
+            .relations()
+            .iter()
+            .map(|(relation, target)| {
+                Edge::new(
+                    intent.intent_id.clone(),
+                    target.clone(),
+                    edge_type(*relation),
+                )
+            })
+            .collect();
+
+        Ok((intent, edges))
+    }
+}
+
+fn edge_type(relation: IntentRelation) -> EdgeType {
+    match relation {
+        IntentRelation::DependsOn => EdgeType::DependsOn,
+        IntentRelation::SubgoalOf => EdgeType::IsSubgoalOf,
+        IntentRelation::ConflictsWith => EdgeType::ConflictsWith,
+    }
+}
+
+fn priority_rank(priority: &Priority) -> u32 {
+    match priority {
+        Priority::Low => 0,
+        Priority::Medium => 1,
+        Priority::High => 2,
+        Priority::Critical => 3,
+    }
+}
@@ -30,6 +30,12 @@ impl IntentGraphConfig {
         }
     }
 
+    pub fn with_sqlite_storage(path: PathBuf) -> Self {
+        Self {
+            storage_config: StorageConfig::Sqlite { path },
+        }
+    }
+
     pub fn with_in_memory_storage() -> Self {
         Self {
             storage_config: StorageConfig::InMemory,
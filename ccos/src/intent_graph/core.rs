@@ -27,6 +27,14 @@ pub struct IntentGraph {
     pub rt: tokio::runtime::Handle,
 }
 
+/// Drive a future to completion from inside a Tokio task without blocking on
+/// the runtime. Tokio's cooperative budget is lifted for the future: once the
+/// enclosing task has used its budget up, Tokio locks would otherwise keep
+/// returning `Pending` and the executor would spin forever.
+fn block_on_in_task<F: std::future::Future>(fut: F) -> F::Output {
+    futures::executor::block_on(tokio::task::unconstrained(fut))
+}
+
 impl std::fmt::Debug for IntentGraph {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("IntentGraph")
@@ -48,7 +56,7 @@ impl IntentGraph {
         F: std::future::Future<Output = T>,
     {
         if tokio::runtime::Handle::try_current().is_ok() {
-            futures::executor::block_on(fut)
+            block_on_in_task(fut)
         } else {
             self.rt.block_on(fut)
         }
@@ -93,7 +101,7 @@ impl IntentGraph {
         // If we are already inside a Tokio runtime, avoid blocking the worker thread directly.
         let storage = if tokio::runtime::Handle::try_current().is_ok() {
             // Use a lightweight futures executor which is safe even on current-thread runtimes
-            futures::executor::block_on(async { IntentGraphStorage::new(config).await })
+            block_on_in_task(async { IntentGraphStorage::new(config).await })
         } else {
             rt.block_on(async { IntentGraphStorage::new(config).await })
         };
@@ -135,7 +143,7 @@ impl IntentGraph {
         // If we're already inside a Tokio runtime, avoid block_in_place which requires multi-thread flavor.
         // Instead, use a lightweight futures executor to drive the future to completion.
         if tokio::runtime::Handle::try_current().is_ok() {
-            futures::executor::block_on(async {
+            block_on_in_task(async {
                 self.storage.store_intent(intent).await?;
                 self.lifecycle.infer_edges(&mut self.storage).await?;
                 Ok(())
@@ -152,9 +160,7 @@ impl IntentGraph {
     /// Get an intent by ID
     pub fn get_intent(&self, intent_id: &IntentId) -> Option<StorableIntent> {
         if tokio::runtime::Handle::try_current().is_ok() {
-            futures::executor::block_on(async {
-                self.storage.get_intent(intent_id).await.unwrap_or(None)
-            })
+            block_on_in_task(async { self.storage.get_intent(intent_id).await.unwrap_or(None) })
         } else {
             self.rt
                 .block_on(async { self.storage.get_intent(intent_id).await.unwrap_or(None) })
@@ -171,7 +177,7 @@ impl IntentGraph {
         let event_sink = self.intent_event_sink.clone();
 
         if tokio::runtime::Handle::try_current().is_ok() {
-            futures::executor::block_on(async {
+            block_on_in_task(async {
                 self.lifecycle
                     .complete_intent(&mut self.storage, event_sink.as_ref(), &intent_id, result)
                     .await
@@ -209,7 +215,7 @@ impl IntentGraph {
 
         // Persist
         if tokio::runtime::Handle::try_current().is_ok() {
-            futures::executor::block_on(async { self.storage.update_intent(&intent).await })?;
+            block_on_in_task(async { self.storage.update_intent(&intent).await })?;
         } else {
             self.rt
                 .block_on(async { self.storage.update_intent(&intent).await })?;
@@ -237,9 +243,7 @@ impl IntentGraph {
     ) -> Result<(), RuntimeError> {
         // Fetch current intent
         let maybe_intent = if tokio::runtime::Handle::try_current().is_ok() {
-            futures::executor::block_on(async {
-                self.storage.get_intent(intent_id).await.unwrap_or(None)
-            })
+            block_on_in_task(async { self.storage.get_intent(intent_id).await.unwrap_or(None) })
         } else {
             self.rt
                 .block_on(async { self.storage.get_intent(intent_id).await.unwrap_or(None) })
@@ -251,7 +255,7 @@ impl IntentGraph {
                 .unwrap()
                 .as_secs();
             if tokio::runtime::Handle::try_current().is_ok() {
-                futures::executor::block_on(async { self.storage.update_intent(&intent).await })
+                block_on_in_task(async { self.storage.update_intent(&intent).await })
             } else {
                 self.rt
                     .block_on(async { self.storage.update_intent(&intent).await })
@@ -274,9 +278,7 @@ impl IntentGraph {
     ) -> Result<(), RuntimeError> {
         // Fetch current intent
         let maybe_intent = if tokio::runtime::Handle::try_current().is_ok() {
-            futures::executor::block_on(async {
-                self.storage.get_intent(intent_id).await.unwrap_or(None)
-            })
+            block_on_in_task(async { self.storage.get_intent(intent_id).await.unwrap_or(None) })
         } else {
             self.rt
                 .block_on(async { self.storage.get_intent(intent_id).await.unwrap_or(None) })
@@ -292,7 +294,7 @@ impl IntentGraph {
 
             // Persist
             if tokio::runtime::Handle::try_current().is_ok() {
-                futures::executor::block_on(async { self.storage.update_intent(&intent).await })?;
+                block_on_in_task(async { self.storage.update_intent(&intent).await })?;
             } else {
                 self.rt
                     .block_on(async { self.storage.update_intent(&intent).await })?;
@@ -320,7 +322,7 @@ impl IntentGraph {
     /// Find relevant intents for a query
     pub fn find_relevant_intents(&self, query: &str) -> Vec<StorableIntent> {
         if tokio::runtime::Handle::try_current().is_ok() {
-            futures::executor::block_on(async {
+            block_on_in_task(async {
                 let filter = IntentFilter {
                     goal_contains: Some(query.to_string()),
                     ..Default::default()
@@ -341,7 +343,7 @@ impl IntentGraph {
     /// Load context window for a set of intent IDs
     pub fn load_context_window(&self, intent_ids: &[IntentId]) -> Vec<StorableIntent> {
         if tokio::runtime::Handle::try_current().is_ok() {
-            futures::executor::block_on(async {
+            block_on_in_task(async {
                 let mut context_intents = Vec::new();
                 let mut loaded_ids = HashSet::new();
 
@@ -560,6 +562,16 @@ impl IntentGraph {
         })
     }
 
+    /// Get all intents regardless of status
+    pub fn get_all_intents(&self) -> Vec<StorableIntent> {
+        self.block_on_runtime(async {
+            self.storage
+                .list_intents(IntentFilter::default())
+                .await
+                .unwrap_or_default()
+        })
+    }
+
     /// Get intent count by status
     pub fn get_intent_count_by_status(&self) -> HashMap<IntentStatus, usize> {
         self.block_on_runtime(async {
@@ -589,7 +601,7 @@ impl IntentGraph {
         let in_rt = tokio::runtime::Handle::try_current().is_ok();
         let handle = self.rt.clone();
         if in_rt {
-            futures::executor::block_on(async { self.storage.store_edge(edge).await })
+            block_on_in_task(async { self.storage.store_edge(edge).await })
         } else {
             handle.block_on(async { self.storage.store_edge(edge).await })
         }
@@ -610,7 +622,25 @@ impl IntentGraph {
         let in_rt = tokio::runtime::Handle::try_current().is_ok();
         let handle = self.rt.clone();
         if in_rt {
-            futures::executor::block_on(async { self.storage.store_edge(edge).await })
+            block_on_in_task(async { self.storage.store_edge(edge).await })
+        } else {
+            handle.block_on(async { self.storage.store_edge(edge).await })
+        }
+    }
+
+    /// Create a conditional `Enables` edge: `to_intent` is only executed when
+    /// `predicate` holds over the result of `from_intent` (bound to `result`)
+    pub fn create_conditional_edge(
+        &mut self,
+        from_intent: IntentId,
+        to_intent: IntentId,
+        predicate: impl Into<String>,
+    ) -> Result<(), RuntimeError> {
+        let edge = Edge::new(from_intent, to_intent, EdgeType::Enables).with_condition(predicate);
+        let in_rt = tokio::runtime::Handle::try_current().is_ok();
+        let handle = self.rt.clone();
+        if in_rt {
+            block_on_in_task(async { self.storage.store_edge(edge).await })
         } else {
             handle.block_on(async { self.storage.store_edge(edge).await })
         }
@@ -828,7 +858,7 @@ impl IntentGraph {
         let in_rt = tokio::runtime::Handle::try_current().is_ok();
         let handle = self.rt.clone();
         if in_rt {
-            futures::executor::block_on(async { self.storage.restore(path).await })
+            block_on_in_task(async { self.storage.restore(path).await })
         } else {
             handle.block_on(async { self.storage.restore(path).await })
         }
@@ -845,7 +875,7 @@ impl IntentGraph {
             let in_rt = tokio::runtime::Handle::try_current().is_ok();
             let handle = self.rt.clone();
             if in_rt {
-                futures::executor::block_on(async {
+                block_on_in_task(async {
                     // Get the root intent
                     let root_intent = self.storage.get_intent(root_intent_id).await?;
                     if root_intent.is_none() {
@@ -959,7 +989,7 @@ impl IntentGraph {
             let in_rt = tokio::runtime::Handle::try_current().is_ok();
             let handle = self.rt.clone();
             if in_rt {
-                futures::executor::block_on(async {
+                block_on_in_task(async {
                     // Get the child intent
                     let child_intent = self.storage.get_intent(child_intent_id).await?;
                     if child_intent.is_none() {
@@ -1069,7 +1099,7 @@ impl IntentGraph {
             let in_rt = tokio::runtime::Handle::try_current().is_ok();
             let handle = self.rt.clone();
             if in_rt {
-                futures::executor::block_on(async {
+                block_on_in_task(async {
                     // Read and deserialize the backup data
                     let content = tokio::fs::read_to_string(path)
                         .await
@@ -1120,6 +1150,32 @@ impl IntentGraph {
         }
     }
 
+    /// Load intents and edges built in memory, e.g. with
+    /// [`IntentBuilderExt::build_with_edges`](super::builder::IntentBuilderExt::build_with_edges).
+    /// Intents are stored before edges so every edge source exists.
+    pub fn load_subgraph(
+        &mut self,
+        intents: Vec<StorableIntent>,
+        edges: Vec<Edge>,
+    ) -> Result<(), RuntimeError> {
+        let in_rt = tokio::runtime::Handle::try_current().is_ok();
+        let handle = self.rt.clone();
+        let load = async {
+            for intent in intents {
+                self.storage.store_intent(intent).await?;
+            }
+            for edge in edges {
+                self.storage.store_edge(edge).await?;
+            }
+            Ok(())
+        };
+        if in_rt {
+            block_on_in_task(load)
+        } else {
+            handle.block_on(load)
+        }
+    }
+
     /// Helper method to collect all descendants of a root intent
     async fn collect_subgraph_recursive(
         &self,
@@ -1238,7 +1294,7 @@ impl IntentGraph {
         let in_rt = tokio::runtime::Handle::try_current().is_ok();
         let handle = self.rt.clone();
         if in_rt {
-            futures::executor::block_on(async {
+            block_on_in_task(async {
                 self.lifecycle
                     .archive_completed_intents(&mut self.storage, event_sink.as_ref())
                     .await
@@ -1262,7 +1318,7 @@ impl IntentGraph {
         let in_rt = tokio::runtime::Handle::try_current().is_ok();
         let handle = self.rt.clone();
         if in_rt {
-            futures::executor::block_on(async {
+            block_on_in_task(async {
                 self.lifecycle
                     .complete_intent(&mut self.storage, event_sink.as_ref(), intent_id, result)
                     .await
@@ -1286,7 +1342,7 @@ impl IntentGraph {
         let in_rt = tokio::runtime::Handle::try_current().is_ok();
         let handle = self.rt.clone();
         if in_rt {
-            futures::executor::block_on(async {
+            block_on_in_task(async {
                 self.lifecycle
                     .fail_intent(
                         &mut self.storage,
@@ -1320,7 +1376,7 @@ impl IntentGraph {
         let in_rt = tokio::runtime::Handle::try_current().is_ok();
         let handle = self.rt.clone();
         if in_rt {
-            futures::executor::block_on(async {
+            block_on_in_task(async {
                 self.lifecycle
                     .suspend_intent(&mut self.storage, event_sink.as_ref(), intent_id, reason)
                     .await
@@ -1344,7 +1400,7 @@ impl IntentGraph {
         let in_rt = tokio::runtime::Handle::try_current().is_ok();
         let handle = self.rt.clone();
         if in_rt {
-            futures::executor::block_on(async {
+            block_on_in_task(async {
                 self.lifecycle
                     .resume_intent(&mut self.storage, event_sink.as_ref(), intent_id, reason)
                     .await
@@ -1368,7 +1424,7 @@ impl IntentGraph {
         let in_rt = tokio::runtime::Handle::try_current().is_ok();
         let handle = self.rt.clone();
         if in_rt {
-            futures::executor::block_on(async {
+            block_on_in_task(async {
                 self.lifecycle
                     .archive_intent(&mut self.storage, event_sink.as_ref(), intent_id, reason)
                     .await
@@ -1392,7 +1448,7 @@ impl IntentGraph {
         let in_rt = tokio::runtime::Handle::try_current().is_ok();
         let handle = self.rt.clone();
         if in_rt {
-            futures::executor::block_on(async {
+            block_on_in_task(async {
                 self.lifecycle
                     .reactivate_intent(&mut self.storage, event_sink.as_ref(), intent_id, reason)
                     .await
@@ -1462,7 +1518,7 @@ impl IntentGraph {
         let in_rt = tokio::runtime::Handle::try_current().is_ok();
         let handle = self.rt.clone();
         if in_rt {
-            futures::executor::block_on(async {
+            block_on_in_task(async {
                 self.storage.clear_all().await.map_err(|e| {
                     RuntimeError::Generic(format!("Failed to clear intent graph: {}", e))
                 })
@@ -1487,7 +1543,7 @@ impl IntentGraph {
         let in_rt = tokio::runtime::Handle::try_current().is_ok();
         let handle = self.rt.clone();
         if in_rt {
-            futures::executor::block_on(async {
+            block_on_in_task(async {
                 self.lifecycle
                     .bulk_transition_intents(
                         &mut self.storage,
@@ -3,6 +3,7 @@
 //! This module implements the Living Intent Graph - a dynamic, multi-layered data structure
 //! that stores and manages user intents with their relationships and lifecycle.
 
+pub mod builder;
 pub mod config;
 pub mod core;
 pub mod processing;
@@ -12,6 +13,7 @@ pub mod storage;
 pub mod virtualization;
 
 // Re-export main types for convenience
+pub use builder::IntentBuilderExt;
 pub use config::*;
 pub use core::*;
 pub use processing::*;
@@ -1,6 +1,6 @@
 //! Storage layer for Intent Graph
 
-use super::super::intent_storage::{IntentFilter, IntentStorage, StorageFactory};
+use super::super::intent_storage::{IntentFilter, IntentStorage, StorageError, StorageFactory};
 use super::super::types::{EdgeType, IntentId, StorableIntent};
 use super::config::IntentGraphConfig;
 use rtfs::runtime::error::RuntimeError;
@@ -57,20 +57,37 @@ impl IntentGraphStorage {
 
     pub async fn update_intent(&mut self, intent: &StorableIntent) -> Result<(), RuntimeError> {
         self.storage
-            .update_intent(intent.clone())
+            .update_intent(intent.clone(), None)
             .await
             .map_err(|e| RuntimeError::StorageError(e.to_string()))?;
+        self.touch_metadata(&intent.intent_id);
+        Ok(())
+    }
+
+    /// Update an intent only if its stored revision still equals `expected_version`.
+    /// The storage error is returned as-is so callers can match on
+    /// `StorageError::Conflict`, re-read the intent and retry.
+    pub async fn update_intent_if_version(
+        &mut self,
+        intent: &StorableIntent,
+        expected_version: u64,
+    ) -> Result<(), StorageError> {
+        self.storage
+            .update_intent(intent.clone(), Some(expected_version))
+            .await?;
+        self.touch_metadata(&intent.intent_id);
+        Ok(())
+    }
 
+    fn touch_metadata(&mut self, intent_id: &IntentId) {
         // Update metadata if it exists
-        if let Some(metadata) = self.metadata.get_mut(&intent.intent_id) {
+        if let Some(metadata) = self.metadata.get_mut(intent_id) {
             metadata.last_accessed = SystemTime::now()
                 .duration_since(UNIX_EPOCH)
                 .unwrap()
                 .as_secs();
             metadata.access_count += 1;
         }
-
-        Ok(())
     }
 
     pub async fn store_edge(&mut self, edge: Edge) -> Result<(), RuntimeError> {
@@ -412,8 +429,28 @@ impl Edge {
         self.metadata = Some(metadata);
         self
     }
+
+    /// Make this a conditional edge: `to` is only taken when the RTFS
+    /// `predicate` holds, with the result of `from` bound to `result`
+    pub fn with_condition(mut self, predicate: impl Into<String>) -> Self {
+        self.metadata
+            .get_or_insert_with(HashMap::new)
+            .insert(EDGE_CONDITION_KEY.to_string(), predicate.into());
+        self
+    }
+
+    /// Predicate of a conditional edge, if any
+    pub fn condition(&self) -> Option<&str> {
+        self.metadata
+            .as_ref()
+            .and_then(|m| m.get(EDGE_CONDITION_KEY))
+            .map(String::as_str)
+    }
 }
 
+/// Edge metadata key holding the predicate of a conditional edge
+pub const EDGE_CONDITION_KEY: &str = "condition";
+
 impl crate::storage::Archivable for Edge {
     fn entity_id(&self) -> String {
         format!("edge-{}-{}-{:?}", self.from, self.to, self.edge_type)
@@ -6,6 +6,7 @@
 use super::intent_graph::Edge;
 use super::storage::ContentAddressableArchive;
 use super::storage_backends::file_archive::FileArchive;
+use super::storage_backends::sqlite_intent_storage::SqliteIntentStorage;
 use super::types::{IntentId, IntentStatus, StorableIntent};
 use rtfs::runtime::values::Value;
 use serde::{Deserialize, Serialize};
@@ -24,6 +25,8 @@ pub enum StorageConfig {
     File { path: PathBuf },
     /// File-based storage using content-addressable archive (sharded files)
     FileArchive { base_dir: PathBuf },
+    /// SQLite database with indexed intent and edge tables
+    Sqlite { path: PathBuf },
 }
 
 /// Storage-safe version of Value that excludes non-serializable types
@@ -84,8 +87,16 @@ pub trait IntentStorage: Send + Sync {
     /// Retrieve an intent by ID
     async fn get_intent(&self, id: &IntentId) -> Result<Option<StorableIntent>, StorageError>;
 
-    /// Update an existing intent
-    async fn update_intent(&mut self, intent: StorableIntent) -> Result<(), StorageError>;
+    /// Update an existing intent, bumping its stored `version`.
+    ///
+    /// With `Some(expected_version)` the update is only applied if the stored
+    /// revision still matches; otherwise `StorageError::Conflict` is returned so
+    /// the caller can re-read the intent and retry. `None` always overwrites.
+    async fn update_intent(
+        &mut self,
+        intent: StorableIntent,
+        expected_version: Option<u64>,
+    ) -> Result<(), StorageError>;
 
     /// Delete an intent by ID
     async fn delete_intent(&mut self, id: &IntentId) -> Result<(), StorageError>;
@@ -158,6 +169,29 @@ pub enum StorageError {
 
     #[error("Storage error: {0}")]
     Storage(String),
+
+    #[error("Version conflict for intent {intent_id}: expected {expected}, found {actual}")]
+    Conflict {
+        intent_id: IntentId,
+        expected: u64,
+        actual: u64,
+    },
+}
+
+/// Check `expected_version` against the stored revision and return the
+/// revision the updated intent should be written with.
+pub(crate) fn next_version(
+    stored: &StorableIntent,
+    expected_version: Option<u64>,
+) -> Result<u64, StorageError> {
+    match expected_version {
+        Some(expected) if expected != stored.version => Err(StorageError::Conflict {
+            intent_id: stored.intent_id.clone(),
+            expected,
+            actual: stored.version,
+        }),
+        _ => Ok(stored.version + 1),
+    }
 }
 
 /// In-memory storage implementation
@@ -188,9 +222,14 @@ impl IntentStorage for InMemoryStorage {
         Ok(intents.get(id).cloned())
     }
 
-    async fn update_intent(&mut self, intent: StorableIntent) -> Result<(), StorageError> {
+    async fn update_intent(
+        &mut self,
+        mut intent: StorableIntent,
+        expected_version: Option<u64>,
+    ) -> Result<(), StorageError> {
         let mut intents = self.intents.write().await;
-        if intents.contains_key(&intent.intent_id) {
+        if let Some(stored) = intents.get(&intent.intent_id) {
+            intent.version = next_version(stored, expected_version)?;
             intents.insert(intent.intent_id.clone(), intent);
             Ok(())
         } else {
@@ -296,30 +335,7 @@ impl IntentStorage for InMemoryStorage {
 
         let json = serde_json::to_string_pretty(&backup_data)
             .map_err(|e| StorageError::Serialization(e.to_string()))?;
-        // Atomic write
-        if let Some(parent) = path.parent() {
-            fs::create_dir_all(parent)?;
-        }
-        let mut tmp = path.to_path_buf();
-        let tmp_name = format!(
-            "{}.tmp-{}",
-            path.file_name()
-                .and_then(|s| s.to_str())
-                .unwrap_or("backup.json"),
-            std::process::id()
-        );
-        tmp.set_file_name(tmp_name);
-        if let Some(dir) = path.parent() {
-            tmp = dir.join(tmp.file_name().unwrap());
-        }
-        {
-            let mut f = fs::File::create(&tmp)?;
-            use std::io::Write as _;
-            f.write_all(json.as_bytes())?;
-            f.sync_all()?;
-        }
-        fs::rename(&tmp, path)?;
-        Ok(())
+        write_atomically(path, json.as_bytes(), true)
     }
 
     async fn restore(&mut self, path: &Path) -> Result<(), StorageError> {
@@ -354,9 +370,13 @@ impl IntentStorage for InMemoryStorage {
 }
 
 /// File-based storage implementation
+///
+/// Every mutation rewrites the file through [`write_atomically`], so a crash
+/// mid-write leaves the previous good snapshot in place.
 pub struct FileStorage {
     in_memory: InMemoryStorage,
     file_path: PathBuf,
+    fsync: bool,
 }
 
 impl FileStorage {
@@ -371,6 +391,7 @@ impl FileStorage {
         let mut storage = Self {
             in_memory: InMemoryStorage::new(),
             file_path: path.clone(),
+            fsync: true,
         };
 
         // Try to load existing data
@@ -381,6 +402,13 @@ impl FileStorage {
         Ok(storage)
     }
 
+    /// Enable or disable fsync of the temp file before it is renamed into place
+    /// (on by default; turning it off trades crash durability for write speed)
+    pub fn with_fsync(mut self, fsync: bool) -> Self {
+        self.fsync = fsync;
+        self
+    }
+
     async fn save_to_file(&self) -> Result<(), StorageError> {
         let intents = self.in_memory.intents.read().await;
         let edges = self.in_memory.edges.read().await;
@@ -389,31 +417,8 @@ impl FileStorage {
 
         let json = serde_json::to_string_pretty(&backup_data)
             .map_err(|e| StorageError::Serialization(e.to_string()))?;
-        // Atomic write: write to temp file in same dir then rename
-        if let Some(parent) = self.file_path.parent() {
-            fs::create_dir_all(parent)?;
-        }
-        let mut tmp = self.file_path.clone();
-        let tmp_name = format!(
-            "{}.tmp-{}",
-            self.file_path
-                .file_name()
-                .and_then(|s| s.to_str())
-                .unwrap_or("storage.json"),
-            std::process::id()
-        );
-        tmp.set_file_name(tmp_name);
-        if let Some(dir) = self.file_path.parent() {
-            tmp = dir.join(tmp.file_name().unwrap());
-        }
-        {
-            let mut f = fs::File::create(&tmp)?;
-            use std::io::Write as _;
-            f.write_all(json.as_bytes())?;
-            f.sync_all()?;
-        }
-        fs::rename(&tmp, &self.file_path)?;
-        Ok(())
+        // Atomic write: readers only ever see the previous or the new complete file
+        write_atomically(&self.file_path, json.as_bytes(), self.fsync)
     }
 
     async fn load_from_file(&mut self) -> Result<(), StorageError> {
@@ -443,8 +448,14 @@ impl IntentStorage for FileStorage {
         self.in_memory.get_intent(id).await
     }
 
-    async fn update_intent(&mut self, intent: StorableIntent) -> Result<(), StorageError> {
-        self.in_memory.update_intent(intent).await?;
+    async fn update_intent(
+        &mut self,
+        intent: StorableIntent,
+        expected_version: Option<u64>,
+    ) -> Result<(), StorageError> {
+        self.in_memory
+            .update_intent(intent, expected_version)
+            .await?;
         self.save_to_file().await?;
         Ok(())
     }
@@ -673,15 +684,32 @@ impl IntentStorage for FileArchiveStorage {
         }
     }
 
-    async fn update_intent(&mut self, intent: StorableIntent) -> Result<(), StorageError> {
+    async fn update_intent(
+        &mut self,
+        mut intent: StorableIntent,
+        expected_version: Option<u64>,
+    ) -> Result<(), StorageError> {
         let intent_id = intent.intent_id.clone();
         println!(
             "🔄 FileArchiveStorage::update_intent called for intent: {}",
             intent_id
         );
 
-        // Remove old version from index and delete the file
         let mut intent_id_to_hash = self.intent_id_to_hash.write().await;
+        let stored = match intent_id_to_hash.get(&intent_id) {
+            Some(hash) => self
+                .intent_archive
+                .retrieve(hash)
+                .map_err(|e| StorageError::Storage(format!("Failed to retrieve intent: {}", e)))?,
+            None => None,
+        };
+        match stored {
+            Some(stored) => intent.version = next_version(&stored, expected_version)?,
+            None if expected_version.is_some() => return Err(StorageError::NotFound(intent_id)),
+            None => {}
+        }
+
+        // Remove old version from index and delete the file
         let old_hash = intent_id_to_hash.remove(&intent_id);
         if let Some(old_hash) = old_hash {
             println!("🗑️ Removing old version with hash: {}", old_hash);
@@ -854,30 +882,7 @@ impl IntentStorage for FileArchiveStorage {
         let json = serde_json::to_string_pretty(&backup_data)
             .map_err(|e| StorageError::Serialization(e.to_string()))?;
 
-        // Atomic write
-        if let Some(parent) = path.parent() {
-            fs::create_dir_all(parent)?;
-        }
-        let mut tmp = path.to_path_buf();
-        let tmp_name = format!(
-            "{}.tmp-{}",
-            path.file_name()
-                .and_then(|s| s.to_str())
-                .unwrap_or("backup.json"),
-            std::process::id()
-        );
-        tmp.set_file_name(tmp_name);
-        if let Some(dir) = path.parent() {
-            tmp = dir.join(tmp.file_name().unwrap());
-        }
-        {
-            let mut f = fs::File::create(&tmp)?;
-            use std::io::Write as _;
-            f.write_all(json.as_bytes())?;
-            f.sync_all()?;
-        }
-        fs::rename(&tmp, path)?;
-        Ok(())
+        write_atomically(path, json.as_bytes(), true)
     }
 
     async fn restore(&mut self, path: &Path) -> Result<(), StorageError> {
@@ -925,6 +930,47 @@ impl IntentStorage for FileArchiveStorage {
     }
 }
 
+/// Write `data` to `path` atomically: the bytes go to a temp file in the same
+/// directory which is then renamed over `path`, so readers never observe a
+/// partially written file. With `fsync` the temp file is flushed to disk first.
+pub(crate) fn write_atomically(path: &Path, data: &[u8], fsync: bool) -> Result<(), StorageError> {
+    write_atomically_with(path, data, fsync, |tmp, path| fs::rename(tmp, path))
+}
+
+fn write_atomically_with(
+    path: &Path,
+    data: &[u8],
+    fsync: bool,
+    commit: impl FnOnce(&Path, &Path) -> std::io::Result<()>,
+) -> Result<(), StorageError> {
+    use std::io::Write as _;
+
+    let dir = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent.to_path_buf(),
+        _ => PathBuf::from("."),
+    };
+    fs::create_dir_all(&dir)?;
+    let tmp = dir.join(format!(
+        ".{}.tmp-{}",
+        path.file_name()
+            .and_then(|s| s.to_str())
+            .unwrap_or("storage.json"),
+        uuid::Uuid::new_v4()
+    ));
+    {
+        let mut f = fs::File::create(&tmp)?;
+        f.write_all(data)?;
+        if fsync {
+            f.sync_all()?;
+        }
+    }
+    if let Err(e) = commit(&tmp, path) {
+        let _ = fs::remove_file(&tmp);
+        return Err(e.into());
+    }
+    Ok(())
+}
+
 /// Backup data structure for serialization
 #[derive(Debug, Serialize, Deserialize)]
 struct StorageBackupData {
@@ -1045,6 +1091,13 @@ impl StorageFactory {
                     Self::with_fallback()
                 }
             },
+            StorageConfig::Sqlite { path } => match Self::sqlite(path) {
+                Ok(storage) => storage,
+                Err(e) => {
+                    eprintln!("Note: Using in-memory storage for fallback strategy. SQLite storage failed: {}", e);
+                    Self::with_fallback()
+                }
+            },
         }
     }
 
@@ -1065,6 +1118,11 @@ impl StorageFactory {
         Ok(Box::new(FileArchiveStorage::new(base_dir).await?))
     }
 
+    /// Create a SQLite storage backend
+    pub fn sqlite<P: AsRef<Path>>(path: P) -> Result<Box<dyn IntentStorage>, StorageError> {
+        Ok(Box::new(SqliteIntentStorage::new(path.as_ref())?))
+    }
+
     /// Create storage with fallback strategy (starts as in-memory, can be upgraded later)
     pub fn with_fallback() -> Box<dyn IntentStorage> {
         // For now, just return in-memory since async construction in sync context is complex
@@ -1103,6 +1161,59 @@ mod tests {
         assert_eq!(all_intents.len(), 1);
     }
 
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_concurrent_stale_update_is_rejected() {
+        let mut storage = InMemoryStorage::new();
+        let intent = create_test_intent("Shared intent");
+        let intent_id = intent.intent_id.clone();
+        storage.store_intent(intent).await.unwrap();
+
+        // Both writers read revision 0 before either writes
+        let read = storage.get_intent(&intent_id).await.unwrap().unwrap();
+        let storage = Arc::new(tokio::sync::Mutex::new(storage));
+        let writers = ["first", "second"].map(|tag| {
+            let storage = storage.clone();
+            let mut update = read.clone();
+            update
+                .metadata
+                .insert("writer".to_string(), tag.to_string());
+            tokio::spawn(async move {
+                let expected = update.version;
+                storage
+                    .lock()
+                    .await
+                    .update_intent(update, Some(expected))
+                    .await
+            })
+        });
+        let mut results = Vec::new();
+        for writer in writers {
+            results.push(writer.await.unwrap());
+        }
+
+        assert_eq!(results.iter().filter(|r| r.is_ok()).count(), 1);
+        let rejected = results.into_iter().find_map(Result::err).unwrap();
+        assert!(matches!(
+            rejected,
+            StorageError::Conflict {
+                expected: 0,
+                actual: 1,
+                ..
+            }
+        ));
+
+        // The winning write is intact and carries the bumped revision
+        let stored = storage
+            .lock()
+            .await
+            .get_intent(&intent_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored.version, 1);
+        assert!(stored.metadata.contains_key("writer"));
+    }
+
     #[tokio::test]
     async fn test_file_storage() {
         let temp_dir = tempdir().unwrap();
@@ -1125,6 +1236,30 @@ mod tests {
         assert_eq!(retrieved.unwrap().goal, "File storage test");
     }
 
+    #[tokio::test]
+    async fn test_file_storage_survives_crash_before_rename() {
+        let temp_dir = tempdir().unwrap();
+        let storage_path = temp_dir.path().join("graph.json");
+
+        let mut storage = FileStorage::new(storage_path.clone()).await.unwrap();
+        let intent = create_test_intent("Committed before the crash");
+        let intent_id = intent.intent_id.clone();
+        storage.store_intent(intent).await.unwrap();
+        let good_state = fs::read_to_string(&storage_path).unwrap();
+
+        // Simulate a crash after the temp file is written but before the rename
+        let result = write_atomically_with(&storage_path, b"{\"intents\": {", true, |_, _| {
+            Err(std::io::Error::other("simulated crash"))
+        });
+        assert!(result.is_err());
+
+        // The previous good state is untouched and still loads
+        assert_eq!(fs::read_to_string(&storage_path).unwrap(), good_state);
+        let reopened = FileStorage::new(storage_path).await.unwrap();
+        let retrieved = reopened.get_intent(&intent_id).await.unwrap();
+        assert_eq!(retrieved.unwrap().goal, "Committed before the crash");
+    }
+
     #[tokio::test]
     async fn test_intent_filter() {
         let mut storage = InMemoryStorage::new();
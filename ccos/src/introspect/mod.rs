@@ -667,13 +667,13 @@ fn collect_calls_from_rtfs(rtfs_src: &str) -> RuntimeResult<Vec<String>> {
 fn collect_calls_from_expr(expr: &rtfs::ast::Expression, out: &mut Vec<String>) {
     use rtfs::ast::{Expression, Literal};
     match expr {
-        Expression::FunctionCall { callee, arguments } => {
+        Expression::FunctionCall { callee, arguments, .. } => {
             if matches!(**callee, Expression::Symbol(ref s) if s.0 == "call") {
                 if let Some(first_arg) = arguments.get(0) {
                     match first_arg {
                         Expression::Literal(Literal::String(s)) => out.push(s.clone()),
-                        Expression::Literal(Literal::Keyword(k)) => out.push(k.0.clone()),
-                        Expression::Symbol(sym) => out.push(sym.0.clone()),
+                        Expression::Literal(Literal::Keyword(k)) => out.push(k.0.to_string()),
+                        Expression::Symbol(sym) => out.push(sym.0.to_string()),
                         _ => {}
                     }
                 }
@@ -102,8 +102,8 @@ fn ir_to_type_expr(ir: &rtfs::ir::core::IrType) -> TypeExpr {
         IT::Intersection(types) => {
             TypeExpr::Intersection(types.iter().map(ir_to_type_expr).collect())
         }
-        IT::Resource(sym) => TypeExpr::Resource(rtfs::ast::Symbol(sym.clone())),
-        IT::TypeRef(sym) => TypeExpr::Alias(rtfs::ast::Symbol(sym.clone())),
+        IT::Resource(sym) => TypeExpr::Resource(rtfs::ast::Symbol::new(sym.as_str())),
+        IT::TypeRef(sym) => TypeExpr::Alias(rtfs::ast::Symbol::new(sym.as_str())),
         IT::LiteralValue(lit) => TypeExpr::Literal(lit.clone()),
     }
 }
@@ -36,7 +36,7 @@ pub async fn register_mcp_capabilities(marketplace: &CapabilityMarketplace) -> R
                         Value::Map(m) => {
                             // We need to extract the "input" field from the inputs map
                             if let Some(val) = m.get(&rtfs::ast::MapKey::Keyword(
-                                rtfs::ast::Keyword("input".to_string()),
+                                rtfs::ast::Keyword("input".into()),
                             )) {
                                 val.clone()
                             } else if let Some(val) =
@@ -149,7 +149,7 @@ fn get_string_param(inputs: &Value, name: &str) -> RuntimeResult<String> {
                         name
                     ))),
                 }
-            } else if let Some(val) = m.get(&MapKey::Keyword(Keyword(name.to_string()))) {
+            } else if let Some(val) = m.get(&MapKey::Keyword(Keyword(name.into()))) {
                 // Check for keyword key
                 match val {
                     Value::String(s) => Ok(s.clone()),
@@ -0,0 +1,203 @@
+//! LLM token and cost accounting.
+//!
+//! Every metered LLM call reports its prompt/completion token counts (when the
+//! provider returns them) to an [`LlmUsageTracker`], which accumulates totals per
+//! model for the lifetime of a CCOS run and prices them with per-model rates.
+//!
+//! Metric names:
+//! - `ccos_llm_calls_total`, `ccos_llm_calls_without_usage_total`
+//! - `ccos_llm_prompt_tokens_total`, `ccos_llm_completion_tokens_total`
+//! - `ccos_llm_estimated_cost_usd_total`
+//!
+//! All series are labeled `model="<model>"`.
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Mutex};
+
+/// Token counts reported for a single LLM call; `None` when the provider does not say
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TokenUsage {
+    pub prompt_tokens: Option<u64>,
+    pub completion_tokens: Option<u64>,
+}
+
+impl TokenUsage {
+    pub fn new(prompt_tokens: u64, completion_tokens: u64) -> Self {
+        Self {
+            prompt_tokens: Some(prompt_tokens),
+            completion_tokens: Some(completion_tokens),
+        }
+    }
+
+    /// True when the provider reported no token counts at all
+    pub fn is_unknown(&self) -> bool {
+        self.prompt_tokens.is_none() && self.completion_tokens.is_none()
+    }
+}
+
+/// Price of a model in USD per 1000 tokens
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ModelPricing {
+    pub prompt_per_1k_usd: f64,
+    pub completion_per_1k_usd: f64,
+}
+
+impl ModelPricing {
+    pub fn new(prompt_per_1k_usd: f64, completion_per_1k_usd: f64) -> Self {
+        Self {
+            prompt_per_1k_usd,
+            completion_per_1k_usd,
+        }
+    }
+
+    /// Estimated cost of a call, counting unreported tokens as zero
+    pub fn cost(&self, usage: &TokenUsage) -> Option<f64> {
+        if usage.is_unknown() {
+            return None;
+        }
+        Some(
+            usage.prompt_tokens.unwrap_or(0) as f64 / 1000.0 * self.prompt_per_1k_usd
+                + usage.completion_tokens.unwrap_or(0) as f64 / 1000.0 * self.completion_per_1k_usd,
+        )
+    }
+}
+
+/// Accumulated usage of one model (or of all models)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LlmUsageTotals {
+    pub calls: u64,
+    /// Calls for which the provider reported no token counts
+    pub calls_without_usage: u64,
+    pub prompt_tokens: u64,
+    pub completion_tokens: u64,
+    pub estimated_cost_usd: f64,
+}
+
+impl LlmUsageTotals {
+    fn add(&mut self, other: &LlmUsageTotals) {
+        self.calls += other.calls;
+        self.calls_without_usage += other.calls_without_usage;
+        self.prompt_tokens += other.prompt_tokens;
+        self.completion_tokens += other.completion_tokens;
+        self.estimated_cost_usd += other.estimated_cost_usd;
+    }
+}
+
+/// Metric name, type, help text and value extractor
+type MetricSeries = (
+    &'static str,
+    &'static str,
+    &'static str,
+    fn(&LlmUsageTotals) -> String,
+);
+
+#[derive(Debug, Default)]
+struct TrackerState {
+    pricing: HashMap<String, ModelPricing>,
+    by_model: BTreeMap<String, LlmUsageTotals>,
+}
+
+/// Shared, cloneable accumulator of LLM usage for a CCOS run
+#[derive(Debug, Clone, Default)]
+pub struct LlmUsageTracker {
+    state: Arc<Mutex<TrackerState>>,
+}
+
+impl LlmUsageTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Price calls to `model`; unpriced models accumulate tokens but no cost
+    pub fn set_pricing(&self, model: impl Into<String>, pricing: ModelPricing) {
+        if let Ok(mut state) = self.state.lock() {
+            state.pricing.insert(model.into(), pricing);
+        }
+    }
+
+    /// Record one call and return its estimated cost, if the model is priced
+    pub fn record(&self, model: &str, usage: &TokenUsage) -> Option<f64> {
+        let mut state = self.state.lock().ok()?;
+        let cost = state
+            .pricing
+            .get(model)
+            .and_then(|pricing| pricing.cost(usage));
+        let totals = state.by_model.entry(model.to_string()).or_default();
+        totals.calls += 1;
+        if usage.is_unknown() {
+            totals.calls_without_usage += 1;
+        }
+        totals.prompt_tokens += usage.prompt_tokens.unwrap_or(0);
+        totals.completion_tokens += usage.completion_tokens.unwrap_or(0);
+        totals.estimated_cost_usd += cost.unwrap_or(0.0);
+        cost
+    }
+
+    /// Totals across all models
+    pub fn totals(&self) -> LlmUsageTotals {
+        let mut totals = LlmUsageTotals::default();
+        for model_totals in self.by_model().values() {
+            totals.add(model_totals);
+        }
+        totals
+    }
+
+    /// Totals per model, sorted by model name
+    pub fn by_model(&self) -> BTreeMap<String, LlmUsageTotals> {
+        self.state
+            .lock()
+            .map(|state| state.by_model.clone())
+            .unwrap_or_default()
+    }
+
+    /// Render the totals in Prometheus exposition format
+    pub fn render_prometheus_text(&self) -> String {
+        let by_model = self.by_model();
+        let mut out = String::new();
+        let series: [MetricSeries; 5] = [
+            (
+                "ccos_llm_calls_total",
+                "counter",
+                "LLM calls per model",
+                |t| t.calls.to_string(),
+            ),
+            (
+                "ccos_llm_calls_without_usage_total",
+                "counter",
+                "LLM calls whose provider reported no token counts",
+                |t| t.calls_without_usage.to_string(),
+            ),
+            (
+                "ccos_llm_prompt_tokens_total",
+                "counter",
+                "Prompt tokens per model",
+                |t| t.prompt_tokens.to_string(),
+            ),
+            (
+                "ccos_llm_completion_tokens_total",
+                "counter",
+                "Completion tokens per model",
+                |t| t.completion_tokens.to_string(),
+            ),
+            (
+                "ccos_llm_estimated_cost_usd_total",
+                "counter",
+                "Estimated LLM cost in USD per model",
+                |t| t.estimated_cost_usd.to_string(),
+            ),
+        ];
+        for (name, kind, help, value) in series {
+            out.push_str(&format!("# HELP {} {}\n", name, help));
+            out.push_str(&format!("# TYPE {} {}\n", name, kind));
+            for (model, totals) in &by_model {
+                out.push_str(&format!(
+                    "{}{{model=\"{}\"}} {}\n",
+                    name,
+                    model.replace('\\', "\\\\").replace('"', "\\\""),
+                    value(totals)
+                ));
+            }
+        }
+        out
+    }
+}
@@ -1,14 +1,29 @@
-//! Minimal, test-only Prometheus-like metrics exposition.
+//! Minimal Prometheus-like metrics exposition.
 //!
 //! Feature-gated behind `metrics_exporter` to avoid pulling server deps.
 //! We expose text in Prometheus exposition format from in-memory metrics.
+//!
+//! Metric names are stable; per-capability series are labeled `id="<capability id>"`:
+//! - `ccos_capability_calls_total`, `ccos_capability_errors_total`,
+//!   `ccos_capability_duration_ms` (histogram)
+//! - `ccos_plans_total{outcome=...}`, `ccos_plan_duration_ms` (histogram)
+//! - `ccos_active_intents`, `ccos_intents{status=...}` (when an intent graph is attached)
 
 use std::io::{Read, Write};
 use std::net::{TcpListener, TcpStream};
 use std::sync::{Arc, Mutex};
 use std::thread;
+use std::time::Duration;
 
 use crate::causal_chain::CausalChain;
+use crate::intent_graph::IntentGraph;
+use crate::types::{ActionType, IntentStatus};
+
+const DURATION_BUCKETS_MS: [u64; 11] = [5, 10, 25, 50, 100, 250, 500, 1000, 2500, 5000, 10000];
+
+/// Read/write timeout for a scrape connection, so a stalled client cannot hold
+/// its handler thread forever
+const CLIENT_IO_TIMEOUT: Duration = Duration::from_secs(5);
 
 fn escape_label_value(s: &str) -> String {
     let mut out = String::with_capacity(s.len() + 8);
@@ -23,6 +38,36 @@ fn escape_label_value(s: &str) -> String {
     out
 }
 
+fn push_histogram(out: &mut String, name: &str, labels: &str, durations: &[u64]) {
+    let sep = if labels.is_empty() { "" } else { "," };
+    for b in DURATION_BUCKETS_MS.iter() {
+        let c = durations.iter().filter(|v| **v <= *b).count();
+        out.push_str(&format!(
+            "{}_bucket{{{}{}le=\"{}\"}} {}\n",
+            name, labels, sep, b, c
+        ));
+    }
+    out.push_str(&format!(
+        "{}_bucket{{{}{}le=\"+Inf\"}} {}\n",
+        name,
+        labels,
+        sep,
+        durations.len()
+    ));
+    let labels = if labels.is_empty() {
+        String::new()
+    } else {
+        format!("{{{}}}", labels)
+    };
+    out.push_str(&format!(
+        "{}_sum{} {}\n",
+        name,
+        labels,
+        durations.iter().sum::<u64>()
+    ));
+    out.push_str(&format!("{}_count{} {}\n", name, labels, durations.len()));
+}
+
 /// Render a minimal Prometheus-like text format from capability and function metrics.
 pub fn render_prometheus_text(chain: &CausalChain) -> String {
     let mut out = String::new();
@@ -74,35 +119,79 @@ pub fn render_prometheus_text(chain: &CausalChain) -> String {
         }
         // Histogram exposition (cumulative buckets)
         if let Some(durs) = cap_durations.get(&cap) {
-            let buckets: [u64; 11] = [5, 10, 25, 50, 100, 250, 500, 1000, 2500, 5000, 10000];
-            let mut counts: Vec<u64> = Vec::with_capacity(buckets.len());
-            for b in buckets.iter() {
-                let c = durs.iter().filter(|v| **v <= *b).count() as u64;
-                counts.push(c);
+            push_histogram(
+                &mut out,
+                "ccos_capability_duration_ms",
+                &format!("id=\"{}\"", cap_lbl),
+                durs,
+            );
+        }
+    }
+
+    // Failed capability results, reported for every capability that was called
+    out.push_str("# HELP ccos_capability_errors_total Failed calls per capability id\n");
+    out.push_str("# TYPE ccos_capability_errors_total counter\n");
+    let mut cap_errors: std::collections::BTreeMap<String, u64> = std::collections::BTreeMap::new();
+    for a in chain.get_all_actions() {
+        let Some(fn_name) = &a.function_name else {
+            continue;
+        };
+        match a.action_type {
+            ActionType::CapabilityCall => {
+                cap_errors.entry(fn_name.clone()).or_insert(0);
             }
-            let sum: u64 = durs.iter().copied().sum();
-            let count_total = durs.len() as u64;
-            for (i, b) in buckets.iter().enumerate() {
-                out.push_str(&format!(
-                    "ccos_capability_duration_ms_bucket{{id=\"{}\",le=\"{}\"}} {}\n",
-                    cap_lbl, b, counts[i]
-                ));
+            ActionType::CapabilityResult if a.result.as_ref().is_some_and(|r| !r.success) => {
+                *cap_errors.entry(fn_name.clone()).or_insert(0) += 1;
             }
-            // +Inf bucket equals total count
-            out.push_str(&format!(
-                "ccos_capability_duration_ms_bucket{{id=\"{}\",le=\"+Inf\"}} {}\n",
-                cap_lbl, count_total
-            ));
-            out.push_str(&format!(
-                "ccos_capability_duration_ms_sum{{id=\"{}\"}} {}\n",
-                cap_lbl, sum
-            ));
-            out.push_str(&format!(
-                "ccos_capability_duration_ms_count{{id=\"{}\"}} {}\n",
-                cap_lbl, count_total
-            ));
+            _ => {}
+        }
+    }
+    for (cap, errors) in cap_errors {
+        out.push_str(&format!(
+            "ccos_capability_errors_total{{id=\"{}\"}} {}\n",
+            escape_label_value(&cap),
+            errors
+        ));
+    }
+
+    // Plan outcomes and wall-clock durations (PlanStarted -> PlanCompleted/PlanAborted)
+    let mut plan_started: std::collections::HashMap<&str, u64> = std::collections::HashMap::new();
+    let mut plan_durations: Vec<u64> = Vec::new();
+    let (mut completed, mut aborted) = (0u64, 0u64);
+    for a in chain.get_all_actions() {
+        let Some(plan_id) = a.plan_id.as_deref() else {
+            continue;
+        };
+        match a.action_type {
+            ActionType::PlanStarted => {
+                plan_started.entry(plan_id).or_insert(a.timestamp);
+            }
+            ActionType::PlanCompleted | ActionType::PlanAborted => {
+                if a.action_type == ActionType::PlanCompleted {
+                    completed += 1;
+                } else {
+                    aborted += 1;
+                }
+                if let Some(start) = plan_started.get(plan_id) {
+                    plan_durations.push(a.timestamp.saturating_sub(*start));
+                }
+            }
+            _ => {}
         }
     }
+    out.push_str("# HELP ccos_plans_total Finished plans by outcome\n");
+    out.push_str("# TYPE ccos_plans_total counter\n");
+    out.push_str(&format!(
+        "ccos_plans_total{{outcome=\"completed\"}} {}\n",
+        completed
+    ));
+    out.push_str(&format!(
+        "ccos_plans_total{{outcome=\"aborted\"}} {}\n",
+        aborted
+    ));
+    out.push_str("# HELP ccos_plan_duration_ms Plan duration histogram in milliseconds\n");
+    out.push_str("# TYPE ccos_plan_duration_ms histogram\n");
+    push_histogram(&mut out, "ccos_plan_duration_ms", "", &plan_durations);
 
     out.push_str("# HELP ccos_function_calls_total Total calls per function name\n");
     out.push_str("# TYPE ccos_function_calls_total counter\n");
@@ -145,32 +234,12 @@ pub fn render_prometheus_text(chain: &CausalChain) -> String {
         }
         // Histogram exposition
         if let Some(durs) = fn_durations.get(&f) {
-            let buckets: [u64; 11] = [5, 10, 25, 50, 100, 250, 500, 1000, 2500, 5000, 10000];
-            let mut counts: Vec<u64> = Vec::with_capacity(buckets.len());
-            for b in buckets.iter() {
-                let c = durs.iter().filter(|v| **v <= *b).count() as u64;
-                counts.push(c);
-            }
-            let sum: u64 = durs.iter().copied().sum();
-            let count_total = durs.len() as u64;
-            for (i, b) in buckets.iter().enumerate() {
-                out.push_str(&format!(
-                    "ccos_function_duration_ms_bucket{{name=\"{}\",le=\"{}\"}} {}\n",
-                    f_lbl, b, counts[i]
-                ));
-            }
-            out.push_str(&format!(
-                "ccos_function_duration_ms_bucket{{name=\"{}\",le=\"+Inf\"}} {}\n",
-                f_lbl, count_total
-            ));
-            out.push_str(&format!(
-                "ccos_function_duration_ms_sum{{name=\"{}\"}} {}\n",
-                f_lbl, sum
-            ));
-            out.push_str(&format!(
-                "ccos_function_duration_ms_count{{name=\"{}\"}} {}\n",
-                f_lbl, count_total
-            ));
+            push_histogram(
+                &mut out,
+                "ccos_function_duration_ms",
+                &format!("name=\"{}\"", f_lbl),
+                durs,
+            );
         }
     }
 
@@ -180,6 +249,67 @@ pub fn render_prometheus_text(chain: &CausalChain) -> String {
     out
 }
 
+/// Render intent gauges: active intents plus a per-status breakdown.
+pub fn render_intent_metrics(graph: &IntentGraph) -> String {
+    let counts = graph.get_intent_count_by_status();
+    let mut out = String::new();
+    out.push_str("# HELP ccos_active_intents Intents currently active\n");
+    out.push_str("# TYPE ccos_active_intents gauge\n");
+    out.push_str(&format!(
+        "ccos_active_intents {}\n",
+        counts.get(&IntentStatus::Active).copied().unwrap_or(0)
+    ));
+    out.push_str("# HELP ccos_intents Intents per status\n");
+    out.push_str("# TYPE ccos_intents gauge\n");
+    let mut by_status: Vec<(String, usize)> = counts
+        .iter()
+        .map(|(status, count)| (format!("{:?}", status).to_lowercase(), *count))
+        .collect();
+    by_status.sort();
+    for (status, count) in by_status {
+        out.push_str(&format!(
+            "ccos_intents{{status=\"{}\"}} {}\n",
+            escape_label_value(&status),
+            count
+        ));
+    }
+    out
+}
+
+/// Serve `/metrics` on `addr` until the process exits, for Prometheus (or a sidecar)
+/// to scrape. Intent gauges are included when an intent graph is given. Each
+/// connection is handled on its own thread, so a slow client does not hold up
+/// other scrapes.
+pub fn serve_metrics(
+    chain: Arc<Mutex<CausalChain>>,
+    intent_graph: Option<Arc<Mutex<IntentGraph>>>,
+    addr: &str,
+) -> std::io::Result<(thread::JoinHandle<()>, std::net::SocketAddr)> {
+    let listener = TcpListener::bind(addr)?;
+    let local_addr = listener.local_addr()?;
+    let handle = thread::spawn(move || {
+        let render = Arc::new(move || {
+            let mut text = chain
+                .lock()
+                .map(|guard| render_prometheus_text(&guard))
+                .unwrap_or_default();
+            if let Some(graph) = &intent_graph {
+                if let Ok(graph) = graph.lock() {
+                    text.push_str(&render_intent_metrics(&graph));
+                }
+            }
+            text
+        });
+        for mut stream in listener.incoming().flatten() {
+            let render = Arc::clone(&render);
+            thread::spawn(move || {
+                let _ = handle_client(&mut stream, &*render);
+            });
+        }
+    });
+    Ok((handle, local_addr))
+}
+
 /// Start a tiny, blocking HTTP server serving `/metrics` with the text.
 /// Returns a join handle; stop by dropping the listener thread (tests should run it briefly).
 pub fn start_metrics_server(
@@ -191,25 +321,27 @@ pub fn start_metrics_server(
     let handle = thread::spawn(move || {
         // Handle a single request then exit (test-friendly)
         if let Ok((mut stream, _addr)) = listener.accept() {
-            let _ = handle_client(&mut stream, &chain);
+            let _ = handle_client(&mut stream, &|| {
+                chain
+                    .lock()
+                    .map(|guard| render_prometheus_text(&guard))
+                    .unwrap_or_default()
+            });
         }
     });
     Ok((handle, local_addr))
 }
 
-fn handle_client(stream: &mut TcpStream, chain: &Arc<Mutex<CausalChain>>) -> std::io::Result<()> {
+fn handle_client(stream: &mut TcpStream, render: &dyn Fn() -> String) -> std::io::Result<()> {
+    stream.set_read_timeout(Some(CLIENT_IO_TIMEOUT))?;
+    stream.set_write_timeout(Some(CLIENT_IO_TIMEOUT))?;
     let mut buf = [0u8; 512];
     let _ = stream.read(&mut buf)?;
     // Minimal parse: if request starts with GET /metrics
     let req = String::from_utf8_lossy(&buf);
     let is_metrics = req.starts_with("GET /metrics ");
     let (status, body) = if is_metrics {
-        let text = if let Ok(guard) = chain.lock() {
-            render_prometheus_text(&*guard)
-        } else {
-            String::new()
-        };
-        ("200 OK", text)
+        ("200 OK", render())
     } else {
         ("404 Not Found", String::from("not found"))
     };
@@ -228,7 +360,6 @@ mod tests {
     use crate::capability_marketplace::CapabilityMarketplace;
     use crate::host::RuntimeHost;
     use rtfs::runtime::security::RuntimeContext;
-    use std::time::Duration;
 
     #[test]
     fn test_render_and_server_smoke() {
@@ -270,4 +401,84 @@ mod tests {
         // Cleanup
         handle.thread().unpark();
     }
+
+    #[test]
+    fn test_scrape_reports_errors_plans_and_active_intents() {
+        use crate::types::{Action, ExecutionResult, StorableIntent};
+
+        let chain = Arc::new(Mutex::new(CausalChain::new().unwrap()));
+        {
+            let mut c = chain.lock().unwrap();
+            let call = Action::new(ActionType::CapabilityCall, "plan-1".to_string(), None)
+                .with_name("demo.fetch");
+            c.append(&call).unwrap();
+            c.record_result(
+                call,
+                ExecutionResult {
+                    success: false,
+                    value: rtfs::runtime::values::Value::Nil,
+                    metadata: std::collections::HashMap::new(),
+                },
+            )
+            .unwrap();
+
+            let mut started = Action::new(ActionType::PlanStarted, "plan-1".to_string(), None);
+            started.timestamp = 1_000;
+            let mut completed = Action::new(ActionType::PlanCompleted, "plan-1".to_string(), None);
+            completed.timestamp = 1_040;
+            c.append(&started).unwrap();
+            c.append(&completed).unwrap();
+        }
+
+        let graph = Arc::new(Mutex::new(IntentGraph::new().unwrap()));
+        graph
+            .lock()
+            .unwrap()
+            .store_intent(StorableIntent::new("watch prices".to_string()))
+            .unwrap();
+
+        let (_handle, local_addr) =
+            serve_metrics(chain.clone(), Some(graph), "127.0.0.1:0").unwrap();
+        // The endpoint keeps serving: scrape it twice
+        for _ in 0..2 {
+            let mut stream = std::net::TcpStream::connect(local_addr).unwrap();
+            stream
+                .write_all(b"GET /metrics HTTP/1.1\r\nHost: localhost\r\n\r\n")
+                .unwrap();
+            let mut resp = String::new();
+            stream.read_to_string(&mut resp).unwrap();
+            assert!(resp.contains("200 OK"));
+            for line in [
+                "ccos_capability_calls_total{id=\"demo.fetch\"}",
+                "ccos_capability_errors_total{id=\"demo.fetch\"} 1",
+                "ccos_plans_total{outcome=\"completed\"} 1",
+                "ccos_plan_duration_ms_bucket{le=\"50\"} 1",
+                "ccos_plan_duration_ms_sum 40",
+                "ccos_active_intents 1",
+                "ccos_intents{status=\"active\"} 1",
+            ] {
+                assert!(resp.contains(line), "missing `{}` in:\n{}", line, resp);
+            }
+        }
+    }
+
+    #[test]
+    fn test_idle_client_does_not_block_scrapes() {
+        let chain = Arc::new(Mutex::new(CausalChain::new().unwrap()));
+        let (_handle, local_addr) = serve_metrics(chain, None, "127.0.0.1:0").unwrap();
+
+        // Connects but never sends a request
+        let _idle = std::net::TcpStream::connect(local_addr).unwrap();
+
+        let mut stream = std::net::TcpStream::connect(local_addr).unwrap();
+        stream
+            .set_read_timeout(Some(CLIENT_IO_TIMEOUT / 2))
+            .unwrap();
+        stream
+            .write_all(b"GET /metrics HTTP/1.1\r\nHost: localhost\r\n\r\n")
+            .unwrap();
+        let mut resp = String::new();
+        stream.read_to_string(&mut resp).unwrap();
+        assert!(resp.contains("200 OK"));
+    }
 }
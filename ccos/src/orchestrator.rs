@@ -23,6 +23,7 @@ use crate::capability_marketplace::types::CapabilityManifest;
 use crate::config::types::PolicyConfig;
 use crate::execution_context::IsolationLevel;
 use crate::host::RuntimeHost;
+use crate::prelude::Prelude;
 use rtfs::ast::MapKey;
 use rtfs::parser::parse_expression;
 use rtfs::runtime::error::{RuntimeError, RuntimeResult};
@@ -30,20 +31,21 @@ use rtfs::runtime::evaluator::Evaluator;
 use rtfs::runtime::execution_outcome::ExecutionOutcome;
 use rtfs::runtime::microvm::config::{FileSystemPolicy, MicroVMConfig, NetworkPolicy};
 use rtfs::runtime::security::RuntimeContext;
-use rtfs::runtime::values::Value;
-use crate::utils::value_conversion::rtfs_value_to_json;
+use rtfs::runtime::values::{Value, ValueMap};
+use crate::utils::value_conversion::{json_to_rtfs_value, rtfs_value_to_json};
 use serde_json::{self, Value as JsonValue};
 use std::sync::{Arc, Mutex};
 
 use super::causal_chain::CausalChain;
-use super::intent_graph::IntentGraph;
+use super::intent_graph::{Edge, IntentGraph};
 use super::types::{
-    Action, ActionType, ExecutionResult, IntentId, IntentStatus, Plan, PlanBody, PlanId,
+    Action, ActionType, EdgeType, ExecutionResult, IntentId, IntentStatus, Plan, PlanBody, PlanId,
     PlanLanguage,
 };
 use rtfs::ast::{Expression, Literal};
 
 use super::checkpoint_archive::{CheckpointArchive, CheckpointRecord};
+use super::event_sink::{LifecycleEvent, LifecycleEventSink};
 use super::plan_archive::PlanArchive;
 use super::types::StorableIntent;
 use chrono;
@@ -51,7 +53,7 @@ use rtfs::runtime::host_interface::HostInterface;
 use rtfs::runtime::module_runtime::ModuleRegistry;
 use rtfs::runtime::values::Value as RtfsValue;
 use sha2::{Digest, Sha256};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 /// Full execution context reconstructed from causal chain for replay
 /// Contains the plan, all referenced intents, and all actions in chronological order
@@ -599,6 +601,16 @@ pub struct Orchestrator {
     current_step_profile: Option<StepProfile>,
     /// Modular hint handler registry for extensible hint processing
     hint_registry: Arc<crate::hints::HintHandlerRegistry>,
+    /// Reuse results of identical pure capability calls within a single plan run (opt-in)
+    memoize_pure_calls: bool,
+    /// Optional observer for typed lifecycle events
+    lifecycle_sink: std::sync::RwLock<Option<Arc<dyn LifecycleEventSink>>>,
+    /// Helpers bound into each plan's environment on top of the secure stdlib
+    prelude: Prelude,
+    /// Most independent intents of a graph executed at the same time
+    max_parallelism: usize,
+    /// Runs `ccos.plan.invoke` calls, which need the calling plan's context
+    plan_invoker: std::sync::RwLock<Option<Arc<crate::capabilities::plan_ops::PlanInvoker>>>,
 }
 
 impl Orchestrator {
@@ -636,9 +648,79 @@ impl Orchestrator {
             plan_archive,
             current_step_profile: None,
             hint_registry: Arc::new(crate::hints::HintHandlerRegistry::with_defaults()),
+            memoize_pure_calls: false,
+            lifecycle_sink: std::sync::RwLock::new(None),
+            prelude: Prelude::default(),
+            max_parallelism: 1,
+            plan_invoker: std::sync::RwLock::new(None),
         }
     }
 
+    /// Enables per-plan memoization of calls to capabilities registered as pure.
+    /// Repeated calls with identical args within one plan execution reuse the first result.
+    pub fn with_call_memoization(mut self, enabled: bool) -> Self {
+        self.memoize_pure_calls = enabled;
+        self
+    }
+
+    /// Selects the prelude loaded for plan execution. With [`Prelude::secure_only`]
+    /// effectful helpers are left unbound and calling one is an undefined-symbol error.
+    pub fn with_prelude(mut self, prelude: Prelude) -> Self {
+        self.prelude = prelude;
+        self
+    }
+
+    /// Lets intent graph execution run up to `max` independent intents at once.
+    /// The default of 1 executes them one after the other.
+    pub fn with_max_parallelism(mut self, max: usize) -> Self {
+        self.max_parallelism = max.max(1);
+        self
+    }
+
+    pub fn max_parallelism(&self) -> usize {
+        self.max_parallelism
+    }
+
+    /// Set the sink receiving lifecycle events (intent, governance, capability and execution).
+    pub fn set_lifecycle_sink(&self, sink: Arc<dyn LifecycleEventSink>) {
+        if let Ok(mut guard) = self.lifecycle_sink.write() {
+            *guard = Some(sink);
+        }
+    }
+
+    /// Set the invoker receiving `ccos.plan.invoke` calls made by running plans.
+    pub(crate) fn set_plan_invoker(
+        &self,
+        invoker: Arc<crate::capabilities::plan_ops::PlanInvoker>,
+    ) {
+        if let Ok(mut guard) = self.plan_invoker.write() {
+            *guard = Some(invoker);
+        }
+    }
+
+    /// Forward a lifecycle event to the configured sink, if any.
+    pub fn emit_lifecycle_event(&self, event: LifecycleEvent) {
+        let sink = self
+            .lifecycle_sink
+            .read()
+            .ok()
+            .and_then(|guard| guard.clone());
+        if let Some(sink) = sink {
+            sink.on_event(&event);
+        }
+    }
+
+    /// Store a newly created intent in the IntentGraph and emit IntentCreated.
+    pub fn store_intent(&self, intent: StorableIntent) -> RuntimeResult<()> {
+        let intent_id = intent.intent_id.clone();
+        self.intent_graph
+            .lock()
+            .map_err(|_| RuntimeError::Generic("Failed to lock IntentGraph".to_string()))?
+            .store_intent(intent)?;
+        self.emit_lifecycle_event(LifecycleEvent::IntentCreated { intent_id });
+        Ok(())
+    }
+
     /// Creates a new Orchestrator with custom policies.
     /// Initializes a BudgetContext for a plan execution based on its execution mode and governance policies.
     pub(crate) fn initialize_budget_context(
@@ -791,6 +873,23 @@ impl Orchestrator {
         &self,
         host_call: &rtfs::runtime::execution_outcome::HostCall,
     ) -> RuntimeResult<Value> {
+        // Child plans inherit the caller's security context, which the
+        // marketplace does not pass to capability handlers
+        if host_call.capability_id == crate::capabilities::plan_ops::PLAN_INVOKE_CAPABILITY {
+            let invoker = self
+                .plan_invoker
+                .read()
+                .ok()
+                .and_then(|guard| guard.clone());
+            if let Some(invoker) = invoker {
+                let inputs = match host_call.args.as_slice() {
+                    [inputs] => inputs.clone(),
+                    args => Value::List(args.to_vec().into()),
+                };
+                return invoker.invoke(&inputs, &host_call.security_context).await;
+            }
+        }
+
         let host_call = self.enrich_host_call_with_sandbox_metadata(host_call);
 
         // Extract execution hints
@@ -921,8 +1020,8 @@ impl Orchestrator {
         if let Value::Map(map) = value {
             for (k, v) in map {
                 let key_str = match k {
-                    rtfs::ast::MapKey::Keyword(kw) => &kw.0,
-                    rtfs::ast::MapKey::String(s) => s,
+                    rtfs::ast::MapKey::Keyword(kw) => kw.0.as_str(),
+                    rtfs::ast::MapKey::String(s) => s.as_str(),
                     _ => continue,
                 };
                 if key_str == key {
@@ -942,7 +1041,7 @@ impl Orchestrator {
         &self,
         host_call: &rtfs::runtime::execution_outcome::HostCall,
     ) -> RuntimeResult<Value> {
-        let args_value = Value::Vector(host_call.args.clone());
+        let args_value = Value::Vector(host_call.args.clone().into());
         self.capability_marketplace
             .execute_capability_enhanced(
                 &host_call.capability_id,
@@ -952,10 +1051,13 @@ impl Orchestrator {
             .await
     }
 
-    /// Internal convenience method for plan execution with default policy.
-    /// Used for internal orchestration (e.g., orchestrate_intent_tree) where
-    /// no explicit governance policy is provided.
-    #[allow(dead_code)]
+    /// Runs a single plan with the default policy, without an intent graph.
+    ///
+    /// The plan body is evaluated with the capability access granted by
+    /// `context`, and its actions are recorded in the CausalChain. Used by
+    /// internal orchestration (e.g. `execute_intent_graph`); the plan is not
+    /// validated, so external callers run standalone plans through
+    /// `GovernanceKernel::validate_and_execute` instead.
     pub(crate) async fn execute_plan(
         self: &Arc<Self>,
         plan: &Plan,
@@ -1053,7 +1155,8 @@ impl Orchestrator {
                 context.clone(),
             )
             .with_orchestrator(Arc::clone(self))
-            .with_budget(budget_context.clone()),
+            .with_budget(budget_context.clone())
+            .with_call_memoization(self.memoize_pure_calls),
         );
         host.set_execution_context(
             plan_id.clone(),
@@ -1068,13 +1171,13 @@ impl Orchestrator {
             host_iface.clone(),
             rtfs::compiler::expander::MacroExpander::default(),
         );
-        // Load CCOS prelude (effectful helpers) into the evaluator's environment
-        crate::prelude::load_prelude(&mut evaluator.env);
+        // Load CCOS prelude (effectful helpers unless secure-only) into the evaluator's environment
+        self.prelude.load(&mut evaluator.env);
 
         // Bind cross-plan parameters to the evaluator environment
         // This makes plan inputs (like owner, repository, language) available as variables
         for (key, value) in &context.cross_plan_params {
-            let symbol = rtfs::ast::Symbol(key.clone());
+            let symbol = rtfs::ast::Symbol::new(key.as_str());
             evaluator.env.define(&symbol, value.clone());
         }
 
@@ -1307,6 +1410,11 @@ impl Orchestrator {
             }
         }
 
+        self.emit_lifecycle_event(LifecycleEvent::ExecutionCompleted {
+            plan_id: plan_id.clone(),
+            success: execution_result.success,
+        });
+
         // Propagate original error after updating intent status
         if let Some(err) = error_opt {
             Err(err)
@@ -1326,6 +1434,20 @@ impl Orchestrator {
         self: &Arc<Self>,
         root_intent_id: &str,
         initial_context: &RuntimeContext,
+    ) -> RuntimeResult<ExecutionResult> {
+        self.execute_intent_graph_with_inputs(root_intent_id, initial_context, &ValueMap::new())
+            .await
+    }
+
+    /// Like `execute_intent_graph`, but every plan of the graph can read the
+    /// caller-provided `inputs` through the `inputs` variable, e.g.
+    /// `(get inputs :threshold)`.
+    #[allow(dead_code)]
+    pub(crate) async fn execute_intent_graph_with_inputs(
+        self: &Arc<Self>,
+        root_intent_id: &str,
+        initial_context: &RuntimeContext,
+        inputs: &ValueMap,
     ) -> RuntimeResult<ExecutionResult> {
         // Debug logging
         eprintln!(
@@ -1333,28 +1455,58 @@ impl Orchestrator {
             root_intent_id
         );
 
-        // 1. Start with an empty cross-plan param bag
+        // 1. Start with a cross-plan param bag holding only the caller's inputs
         let mut enhanced_context = initial_context.clone();
-        enhanced_context.cross_plan_params.clear();
+        seed_plan_inputs(&mut enhanced_context, inputs);
 
-        // 2. Execute children and merge exported vars
+        // 2. Execute children in waves of up to `max_parallelism` intents whose
+        // dependencies and branch conditions have settled. Each intent of a wave
+        // sees the context as of the wave start; exports merge in child order
         let mut child_results = Vec::new();
+        let mut skipped = Vec::new();
         let children = self.get_children_order(root_intent_id)?;
         eprintln!("DEBUG: Found {} children: {:?}", children.len(), children);
 
-        for child_id in children {
-            eprintln!("DEBUG: Looking for plan for child_id: {}", child_id);
-            if let Some(child_plan) = self.get_plan_for_intent(&child_id)? {
-                eprintln!(
-                    "DEBUG: Found plan for child_id {}: {:?}",
-                    child_id, child_plan.plan_id
-                );
-                let child_result = self.execute_plan(&child_plan, &enhanced_context).await?;
+        let siblings: HashSet<IntentId> = children.iter().cloned().collect();
+        let mut outcomes = HashMap::new();
+        let mut pending = children;
+        loop {
+            let wave = self.next_wave(&siblings, &mut pending, &mut outcomes, &mut skipped)?;
+            if wave.is_empty() {
+                break;
+            }
+            let wave_context = &enhanced_context.clone();
+            let wave_results = run_concurrently(wave, |child_id| async move {
+                eprintln!("DEBUG: Looking for plan for child_id: {}", child_id);
+                let child_result = match self.get_plan_for_intent(&child_id)? {
+                    Some(child_plan) => {
+                        eprintln!(
+                            "DEBUG: Found plan for child_id {}: {:?}",
+                            child_id, child_plan.plan_id
+                        );
+                        Some(self.execute_plan(&child_plan, wave_context).await?)
+                    }
+                    None => None,
+                };
+                Ok::<_, RuntimeError>((child_id, child_result))
+            })
+            .await;
+
+            for wave_result in wave_results {
+                let (child_id, child_result) = wave_result?;
+                let Some(child_result) = child_result else {
+                    eprintln!("DEBUG: No plan found for child_id: {}", child_id);
+                    outcomes.insert(child_id, None);
+                    continue;
+                };
                 let exported = self.extract_exported_variables(&child_result);
                 enhanced_context.cross_plan_params.extend(exported);
-                child_results.push((child_id.clone(), child_result));
-            } else {
-                eprintln!("DEBUG: No plan found for child_id: {}", child_id);
+                publish_intent_result(&mut enhanced_context, &child_id, &child_result);
+                outcomes.insert(
+                    child_id.clone(),
+                    child_result.success.then(|| child_result.value.clone()),
+                );
+                child_results.push((child_id, child_result));
             }
         }
 
@@ -1380,6 +1532,10 @@ impl Orchestrator {
             }
         }
 
+        for child_id in &skipped {
+            result_summary.push(format!("{}: skipped", child_id));
+        }
+
         // Add root result if any
         if let Some(ref root) = root_result {
             if root.success {
@@ -1419,6 +1575,136 @@ impl Orchestrator {
         }
     }
 
+    /// Take the next batch of children to run out of `pending`: up to
+    /// `max_parallelism` intents whose sibling dependencies and branch
+    /// conditions have settled, in child order. Children on a branch not taken
+    /// are moved to `skipped` instead. Returns an empty batch once `pending` is empty.
+    pub(crate) fn next_wave(
+        &self,
+        siblings: &HashSet<IntentId>,
+        pending: &mut Vec<IntentId>,
+        outcomes: &mut HashMap<IntentId, Option<Value>>,
+        skipped: &mut Vec<IntentId>,
+    ) -> RuntimeResult<Vec<IntentId>> {
+        while !pending.is_empty() {
+            let mut wave = Vec::new();
+            let mut settled_any = false;
+            let mut i = 0;
+            while i < pending.len() && wave.len() < self.max_parallelism {
+                let ready = self.dependencies_settled(&pending[i], siblings, outcomes)?;
+                match (ready, self.branch_taken(&pending[i], outcomes)?) {
+                    (_, Some(false)) => {
+                        let child_id = pending.remove(i);
+                        outcomes.insert(child_id.clone(), None);
+                        skipped.push(child_id);
+                        settled_any = true;
+                    }
+                    (true, Some(true)) => wave.push(pending.remove(i)),
+                    _ => i += 1,
+                }
+            }
+            if !wave.is_empty() {
+                return Ok(wave);
+            }
+            if !settled_any {
+                // Nothing can progress (a dependency cycle, or a condition on an
+                // intent outside this graph): run the first child if its branch
+                // is taken, otherwise skip it
+                let child_id = pending.remove(0);
+                if self.branch_taken(&child_id, outcomes)? == Some(true) {
+                    return Ok(vec![child_id]);
+                }
+                outcomes.insert(child_id.clone(), None);
+                skipped.push(child_id);
+            }
+        }
+        Ok(Vec::new())
+    }
+
+    /// Whether every sibling that `intent_id` depends on has settled
+    fn dependencies_settled(
+        &self,
+        intent_id: &str,
+        siblings: &HashSet<IntentId>,
+        outcomes: &HashMap<IntentId, Option<Value>>,
+    ) -> RuntimeResult<bool> {
+        let graph = self
+            .intent_graph
+            .lock()
+            .map_err(|_| RuntimeError::Generic("Failed to lock IntentGraph".to_string()))?;
+        Ok(graph
+            .get_edges_for_intent(&intent_id.to_string())
+            .iter()
+            .filter(|edge| edge.from == intent_id && edge.edge_type == EdgeType::DependsOn)
+            .filter(|edge| edge.to != intent_id && siblings.contains(&edge.to))
+            .all(|edge| outcomes.contains_key(&edge.to)))
+    }
+
+    /// Whether `intent_id` lies on a taken branch, given the outcomes of the
+    /// intents settled so far (`Some(value)` if it ran, `None` if it was
+    /// skipped or failed).
+    ///
+    /// Intents without incoming conditional edges are always taken. Otherwise
+    /// the intent is taken when any conditional edge's predicate holds over the
+    /// result of its source; `None` means a source has not settled yet.
+    pub(crate) fn branch_taken(
+        &self,
+        intent_id: &str,
+        outcomes: &HashMap<IntentId, Option<Value>>,
+    ) -> RuntimeResult<Option<bool>> {
+        let gates: Vec<Edge> = {
+            let graph = self
+                .intent_graph
+                .lock()
+                .map_err(|_| RuntimeError::Generic("Failed to lock IntentGraph".to_string()))?;
+            graph
+                .get_edges_for_intent(&intent_id.to_string())
+                .into_iter()
+                .filter(|edge| edge.to == intent_id && edge.condition().is_some())
+                .collect()
+        };
+        if gates.is_empty() {
+            return Ok(Some(true));
+        }
+
+        let mut taken = false;
+        for gate in &gates {
+            match outcomes.get(&gate.from) {
+                None => return Ok(None),
+                Some(Some(result)) if !taken => {
+                    let predicate = gate.condition().unwrap_or_default();
+                    taken = Self::evaluate_branch_condition(predicate, result)?;
+                }
+                Some(_) => {}
+            }
+        }
+        Ok(Some(taken))
+    }
+
+    /// Evaluate a conditional edge predicate with `result` bound to the
+    /// predecessor's result. Predicates run in a pure environment.
+    fn evaluate_branch_condition(predicate: &str, result: &Value) -> RuntimeResult<bool> {
+        let expr = parse_expression(predicate).map_err(|e| {
+            RuntimeError::Generic(format!("Invalid branch condition '{}': {:?}", predicate, e))
+        })?;
+        let mut evaluator = Evaluator::new(
+            Arc::new(ModuleRegistry::new()),
+            RuntimeContext::pure(),
+            rtfs::runtime::pure_host::create_pure_host(),
+            rtfs::compiler::expander::MacroExpander::default(),
+        );
+        evaluator
+            .env
+            .define(&rtfs::ast::Symbol("result".into()), result.clone());
+        match evaluator.evaluate(&expr)? {
+            ExecutionOutcome::Complete(value) => Ok(value.is_truthy()),
+            ExecutionOutcome::RequiresHost(_) => Err(RuntimeError::Generic(format!(
+                "Branch condition '{}' must be pure",
+                predicate
+            ))),
+        }
+    }
+
     /// Simple method to get children order
     #[allow(dead_code)]
     fn get_children_order(&self, root_id: &str) -> RuntimeResult<Vec<String>> {
@@ -1460,14 +1746,14 @@ impl Orchestrator {
                     .into_iter()
                     .map(Self::json_value_to_runtime_value)
                     .collect();
-                Value::Vector(runtime_vec)
+                Value::Vector(runtime_vec.into())
             }
             JsonValue::Object(obj) => {
                 let mut runtime_map = std::collections::HashMap::new();
                 for (k, v) in obj {
                     runtime_map.insert(MapKey::String(k), Self::json_value_to_runtime_value(v));
                 }
-                Value::Map(runtime_map)
+                Value::Map(runtime_map.into())
             }
         }
     }
@@ -1881,6 +2167,138 @@ impl Orchestrator {
         self.resume_plan(plan_id, intent_id, evaluator, &rec.serialized_context)
     }
 
+    /// Create an explicit checkpoint for the RTFS `(checkpoint "label")` form.
+    ///
+    /// Persists the data bindings visible at the call site together with the
+    /// step-scoped execution context. Values are stored in their JSON form, so
+    /// keywords nested inside values come back as strings. Returns the checkpoint id.
+    pub fn checkpoint_bindings(
+        &self,
+        plan_id: &str,
+        intent_id: &str,
+        label: &str,
+        bindings: &HashMap<String, Value>,
+        context: &HashMap<String, Value>,
+    ) -> RuntimeResult<String> {
+        let to_json =
+            |values: &HashMap<String, Value>| -> RuntimeResult<serde_json::Map<String, JsonValue>> {
+                let mut out = serde_json::Map::new();
+                for (name, value) in values {
+                    let json = rtfs_value_to_json(value).map_err(|e| {
+                        RuntimeError::Generic(format!("Cannot checkpoint '{}': {}", name, e))
+                    })?;
+                    out.insert(name.clone(), json);
+                }
+                Ok(out)
+            };
+        let payload = serde_json::json!({
+            "label": label,
+            "plan_id": plan_id,
+            "bindings": JsonValue::Object(to_json(bindings)?),
+            "context": JsonValue::Object(to_json(context)?),
+        });
+        let serialized = serde_json::to_string(&payload)
+            .map_err(|e| RuntimeError::Generic(format!("Failed to serialize checkpoint: {}", e)))?;
+        let mut hasher = Sha256::new();
+        hasher.update(serialized.as_bytes());
+        let checkpoint_id = format!("cp-{:x}", hasher.finalize());
+
+        {
+            let mut chain = self
+                .causal_chain
+                .lock()
+                .map_err(|_| RuntimeError::Generic("Failed to lock CausalChain".to_string()))?;
+            let action = Action::new(
+                super::types::ActionType::InternalStep,
+                plan_id.to_string(),
+                intent_id.to_string(),
+            )
+            .with_name("checkpoint")
+            .with_args(vec![
+                RtfsValue::String(label.to_string()),
+                RtfsValue::String(checkpoint_id.clone()),
+            ]);
+            let _ = chain.append(&action)?;
+        }
+
+        let mut metadata = HashMap::new();
+        metadata.insert("label".to_string(), label.to_string());
+        let record = CheckpointRecord {
+            checkpoint_id: checkpoint_id.clone(),
+            plan_id: plan_id.to_string(),
+            intent_id: intent_id.to_string(),
+            serialized_context: serialized,
+            created_at: std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_secs(),
+            metadata,
+            missing_capabilities: Vec::new(),
+            auto_resume_enabled: false,
+        };
+        self.checkpoint_archive
+            .store(record)
+            .map_err(|e| RuntimeError::Generic(format!("Failed to store checkpoint: {}", e)))?;
+
+        Ok(checkpoint_id)
+    }
+
+    /// Load a checkpoint created by `checkpoint_bindings` for the RTFS `(restore id)` form.
+    ///
+    /// The checkpoint must belong to the same plan/intent. Logs PlanResumed and
+    /// returns the saved `(bindings, context)`.
+    pub fn restore_checkpoint_bindings(
+        &self,
+        plan_id: &str,
+        intent_id: &str,
+        checkpoint_id: &str,
+    ) -> RuntimeResult<(HashMap<String, Value>, HashMap<String, Value>)> {
+        let rec = if let Some(rec) = self.checkpoint_archive.get_by_id(checkpoint_id) {
+            rec
+        } else {
+            self.checkpoint_archive
+                .load_from_disk(checkpoint_id)
+                .ok_or_else(|| {
+                    RuntimeError::Generic(format!("Checkpoint not found: {}", checkpoint_id))
+                })?
+        };
+        if rec.plan_id != plan_id || rec.intent_id != intent_id {
+            return Err(RuntimeError::Generic(
+                "Checkpoint does not match plan/intent".to_string(),
+            ));
+        }
+
+        let payload: JsonValue = serde_json::from_str(&rec.serialized_context).map_err(|e| {
+            RuntimeError::Generic(format!("Corrupt checkpoint {}: {}", checkpoint_id, e))
+        })?;
+        let from_json = |field: &str| -> RuntimeResult<HashMap<String, Value>> {
+            let mut out = HashMap::new();
+            if let Some(obj) = payload.get(field).and_then(|v| v.as_object()) {
+                for (name, json) in obj {
+                    out.insert(name.clone(), json_to_rtfs_value(json)?);
+                }
+            }
+            Ok(out)
+        };
+        let bindings = from_json("bindings")?;
+        let context = from_json("context")?;
+
+        let mut chain = self
+            .causal_chain
+            .lock()
+            .map_err(|_| RuntimeError::Generic("Failed to lock CausalChain".to_string()))?;
+        let action = Action::new(
+            super::types::ActionType::PlanResumed,
+            plan_id.to_string(),
+            intent_id.to_string(),
+        )
+        .with_name("restore_checkpoint")
+        .with_args(vec![RtfsValue::String(checkpoint_id.to_string())]);
+        let _ = chain.append(&action)?;
+
+        Ok((bindings, context))
+    }
+
     /// Find the newest checkpoint for a plan/intent pair.
     pub fn find_latest_checkpoint_for_plan_intent(
         &self,
@@ -1965,15 +2383,16 @@ impl Orchestrator {
                 context.clone(),
             )
             .with_orchestrator(Arc::clone(self))
-            .with_budget(budget_context.clone()),
+            .with_budget(budget_context.clone())
+            .with_call_memoization(self.memoize_pure_calls),
         );
         host.set_execution_context(plan_id.clone(), plan.intent_ids.clone(), "".to_string());
 
         if let Some(extensions) = budget_extensions {
-            let mut map: std::collections::HashMap<MapKey, Value> = std::collections::HashMap::new();
+            let mut map: ValueMap = ValueMap::new();
             for (dimension, additional) in extensions {
                 map.insert(
-                    MapKey::Keyword(rtfs::ast::Keyword(dimension)),
+                    MapKey::Keyword(rtfs::ast::Keyword(dimension.into())),
                     Value::Float(additional),
                 );
             }
@@ -1988,8 +2407,8 @@ impl Orchestrator {
             host_iface,
             rtfs::compiler::expander::MacroExpander::default(),
         );
-        // Load CCOS prelude (effectful helpers) into the evaluator's environment
-        crate::prelude::load_prelude(&mut evaluator.env);
+        // Load CCOS prelude (effectful helpers unless secure-only) into the evaluator's environment
+        self.prelude.load(&mut evaluator.env);
 
         // ContextManager removed from RTFS - step lifecycle now handled by host
         // Resumed context initialization is managed through set_execution_context
@@ -2124,6 +2543,11 @@ impl Orchestrator {
             }
         }
 
+        self.emit_lifecycle_event(LifecycleEvent::ExecutionCompleted {
+            plan_id: plan_id.clone(),
+            success: execution_result.success,
+        });
+
         if let Some(err) = error_opt {
             Err(err)
         } else {
@@ -2226,6 +2650,99 @@ impl Orchestrator {
     }
 }
 
+/// Plan variable holding the results of the intents completed so far in a
+/// graph, each under `:results/<intent-id>`: a dependent plan reads
+/// `(get results :results/<intent-id>)`. The namespace keeps the key a valid
+/// keyword literal even for intent ids that start with a digit.
+pub const INTENT_RESULTS_PARAM: &str = "results";
+
+/// Plan variable holding the inputs the caller passed when executing a graph,
+/// read as `(get inputs :name)`
+pub const PLAN_INPUTS_PARAM: &str = "inputs";
+
+/// Reset the cross-plan params of a graph execution to just the caller's inputs
+pub(crate) fn seed_plan_inputs(context: &mut RuntimeContext, inputs: &ValueMap) {
+    context.cross_plan_params.clear();
+    context
+        .cross_plan_params
+        .insert(PLAN_INPUTS_PARAM.to_string(), Value::Map(inputs.clone()));
+}
+
+/// Make a completed intent's result visible to the plans executed after it
+pub(crate) fn publish_intent_result(
+    context: &mut RuntimeContext,
+    intent_id: &str,
+    result: &ExecutionResult,
+) {
+    if !result.success {
+        return;
+    }
+    let results = context
+        .cross_plan_params
+        .entry(INTENT_RESULTS_PARAM.to_string())
+        .or_insert_with(|| Value::Map(HashMap::new().into()));
+    if let Value::Map(results) = results {
+        results.insert(
+            MapKey::Keyword(rtfs::ast::Keyword::new(&format!(
+                "{}/{}",
+                INTENT_RESULTS_PARAM, intent_id
+            ))),
+            result.value.clone(),
+        );
+    }
+}
+
+/// Run `job` over every item and return the outputs in input order.
+///
+/// Plan evaluation blocks the thread it runs on, so on a multi-threaded
+/// runtime each item gets its own scoped thread driving its job on the current
+/// runtime. Outside a Tokio runtime (e.g. under `futures::executor::block_on`),
+/// on a current-thread runtime, or for a single item, jobs simply run one after
+/// another.
+pub(crate) async fn run_concurrently<I, T, F, Fut>(items: Vec<I>, job: F) -> Vec<T>
+where
+    I: Send,
+    T: Send,
+    F: Fn(I) -> Fut + Sync,
+    Fut: std::future::Future<Output = T>,
+{
+    let handle = match tokio::runtime::Handle::try_current() {
+        Ok(handle)
+            if items.len() >= 2
+                && handle.runtime_flavor() == tokio::runtime::RuntimeFlavor::MultiThread =>
+        {
+            handle
+        }
+        _ => {
+            let mut outputs = Vec::with_capacity(items.len());
+            for item in items {
+                outputs.push(job(item).await);
+            }
+            return outputs;
+        }
+    };
+
+    tokio::task::block_in_place(|| {
+        std::thread::scope(|scope| {
+            let workers: Vec<_> = items
+                .into_iter()
+                .map(|item| {
+                    let (job, handle) = (&job, &handle);
+                    scope.spawn(move || handle.block_on(job(item)))
+                })
+                .collect();
+            workers
+                .into_iter()
+                .map(|worker| {
+                    worker
+                        .join()
+                        .unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+                })
+                .collect()
+        })
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -2345,6 +2862,147 @@ mod tests {
         );
     }
 
+    #[tokio::test(flavor = "multi_thread")]
+    async fn memoizes_pure_capability_calls_within_plan() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let chain = Arc::new(Mutex::new(CausalChain::new().expect("chain")));
+        let graph = make_graph_with_sink(Arc::clone(&chain));
+        let marketplace = Arc::new(CapabilityMarketplace::new(Arc::new(
+            tokio::sync::RwLock::new(crate::capabilities::registry::CapabilityRegistry::new()),
+        )));
+
+        let pure_calls = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&pure_calls);
+        marketplace
+            .register_pure_local_capability(
+                "test.pure-answer".to_string(),
+                "Pure answer".to_string(),
+                "Always returns the same value".to_string(),
+                Arc::new(move |_args: &Value| {
+                    counter.fetch_add(1, Ordering::SeqCst);
+                    Ok(Value::Integer(42))
+                }),
+            )
+            .await
+            .expect("register pure capability");
+
+        let impure_calls = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&impure_calls);
+        marketplace
+            .register_local_capability(
+                "test.impure-tick".to_string(),
+                "Impure tick".to_string(),
+                "Counts invocations".to_string(),
+                Arc::new(move |_args: &Value| {
+                    Ok(Value::Integer(counter.fetch_add(1, Ordering::SeqCst) as i64))
+                }),
+            )
+            .await
+            .expect("register impure capability");
+
+        let orchestrator = Arc::new(
+            Orchestrator::new(
+                Arc::clone(&chain),
+                Arc::clone(&graph),
+                Arc::clone(&marketplace),
+                Arc::new(PlanArchive::new()),
+            )
+            .with_call_memoization(true),
+        );
+
+        let mut plan = Plan::new_rtfs(
+            r#"(do
+                 (call :test.pure-answer 21)
+                 (call :test.pure-answer 21)
+                 (call :test.impure-tick 1)
+                 (call :test.impure-tick 1))"#
+                .to_string(),
+            vec![],
+        );
+        plan.status = PlanStatus::Active;
+
+        let result = orchestrator
+            .execute_plan(&plan, &RuntimeContext::full())
+            .await
+            .expect("exec ok");
+        assert!(result.success, "plan failed: {:?}", result.value);
+        assert_eq!(pure_calls.load(Ordering::SeqCst), 1);
+        assert_eq!(impure_calls.load(Ordering::SeqCst), 2);
+
+        // The memo is scoped to a single plan execution
+        orchestrator
+            .execute_plan(&plan, &RuntimeContext::full())
+            .await
+            .expect("exec ok");
+        assert_eq!(pure_calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn checkpoint_and_restore_preserve_let_bindings() {
+        let chain = Arc::new(Mutex::new(CausalChain::new().expect("chain")));
+        let graph = make_graph_with_sink(Arc::clone(&chain));
+        let marketplace = Arc::new(CapabilityMarketplace::new(Arc::new(
+            tokio::sync::RwLock::new(crate::capabilities::registry::CapabilityRegistry::new()),
+        )));
+        let orchestrator = Arc::new(Orchestrator::new(
+            Arc::clone(&chain),
+            Arc::clone(&graph),
+            Arc::clone(&marketplace),
+            Arc::new(PlanArchive::new()),
+        ));
+
+        let mut plan = Plan::new_rtfs(
+            r#"(let [x 42 greeting "hello"] (checkpoint "after-x"))"#.to_string(),
+            vec![],
+        );
+        plan.status = PlanStatus::Active;
+        let result = orchestrator
+            .execute_plan(&plan, &RuntimeContext::full())
+            .await
+            .expect("exec ok");
+        assert!(result.success, "checkpoint plan failed: {:?}", result.value);
+        let checkpoint_id = match result.value {
+            Value::String(id) => id,
+            other => panic!("expected checkpoint id, got {:?}", other),
+        };
+        assert!(checkpoint_id.starts_with("cp-"));
+        let record = orchestrator
+            .checkpoint_archive
+            .get_by_id(&checkpoint_id)
+            .expect("checkpoint stored");
+        assert_eq!(
+            record.metadata.get("label").map(String::as_str),
+            Some("after-x")
+        );
+
+        // A later run of the same plan resumes from the checkpoint: the let-bound
+        // values are available again both as bindings and in the returned map
+        let mut resumed = Plan::new_rtfs(
+            format!(r#"(do (restore "{}") [(+ x 1) greeting])"#, checkpoint_id),
+            vec![],
+        );
+        resumed.plan_id = plan.plan_id.clone();
+        resumed.status = PlanStatus::Active;
+        let result = orchestrator
+            .execute_plan(&resumed, &RuntimeContext::full())
+            .await
+            .expect("exec ok");
+        assert!(result.success, "restore plan failed: {:?}", result.value);
+        assert_eq!(
+            result.value,
+            Value::Vector(vec![Value::Integer(43), Value::String("hello".to_string())].into())
+        );
+
+        // Checkpoints are scoped to the plan that created them
+        let mut other = Plan::new_rtfs(format!(r#"(restore "{}")"#, checkpoint_id), vec![]);
+        other.status = PlanStatus::Active;
+        let result = orchestrator
+            .execute_plan(&other, &RuntimeContext::full())
+            .await;
+        assert!(!matches!(result, Ok(ref r) if r.success));
+    }
+
     #[tokio::test]
     async fn orchestrator_emits_failed_on_error() {
         let chain = Arc::new(Mutex::new(CausalChain::new().expect("chain")));
@@ -2445,10 +3103,10 @@ mod tests {
 
         // Test pure function call - should get Inherit isolation
         let pure_expr = Expression::List(vec![
-            Expression::Symbol(Symbol("call".to_string())),
-            Expression::Symbol(Symbol("math.add".to_string())),
+            Expression::Symbol(Symbol("call".into())),
+            Expression::Symbol(Symbol("math.add".into())),
             Expression::List(vec![
-                Expression::Symbol(Symbol("values".to_string())),
+                Expression::Symbol(Symbol("values".into())),
                 Expression::Literal(Literal::Integer(1)),
                 Expression::Literal(Literal::Integer(2)),
             ]),
@@ -2482,10 +3140,10 @@ mod tests {
 
         // Test network operation - should get Isolated isolation
         let network_expr = Expression::List(vec![
-            Expression::Symbol(Symbol("call".to_string())),
-            Expression::Symbol(Symbol("http.fetch".to_string())),
+            Expression::Symbol(Symbol("call".into())),
+            Expression::Symbol(Symbol("http.fetch".into())),
             Expression::List(vec![
-                Expression::Symbol(Symbol("values".to_string())),
+                Expression::Symbol(Symbol("values".into())),
                 Expression::Literal(Literal::String("https://api.example.com/data".to_string())),
             ]),
         ]);
@@ -2534,10 +3192,10 @@ mod tests {
 
         // Test file operation - should get Isolated isolation
         let file_expr = Expression::List(vec![
-            Expression::Symbol(Symbol("call".to_string())),
-            Expression::Symbol(Symbol("file.read".to_string())),
+            Expression::Symbol(Symbol("call".into())),
+            Expression::Symbol(Symbol("file.read".into())),
             Expression::List(vec![
-                Expression::Symbol(Symbol("values".to_string())),
+                Expression::Symbol(Symbol("values".into())),
                 Expression::Literal(Literal::String("/data/input.txt".to_string())),
             ]),
         ]);
@@ -2581,10 +3239,10 @@ mod tests {
 
         // Test system operation - should get Sandboxed isolation
         let system_expr = Expression::List(vec![
-            Expression::Symbol(Symbol("call".to_string())),
-            Expression::Symbol(Symbol("system.execute".to_string())),
+            Expression::Symbol(Symbol("call".into())),
+            Expression::Symbol(Symbol("system.execute".into())),
             Expression::List(vec![
-                Expression::Symbol(Symbol("values".to_string())),
+                Expression::Symbol(Symbol("values".into())),
                 Expression::Literal(Literal::String("ls -la".to_string())),
             ]),
         ]);
@@ -2617,11 +3275,11 @@ mod tests {
 
         // Test computationally intensive operation gets higher limits
         let intensive_expr = Expression::List(vec![
-            Expression::Symbol(Symbol("call".to_string())),
-            Expression::Symbol(Symbol("compute.analyze".to_string())),
+            Expression::Symbol(Symbol("call".into())),
+            Expression::Symbol(Symbol("compute.analyze".into())),
             Expression::List(vec![
-                Expression::Symbol(Symbol("values".to_string())),
-                Expression::Symbol(Symbol("big_dataset".to_string())),
+                Expression::Symbol(Symbol("values".into())),
+                Expression::Symbol(Symbol("big_dataset".into())),
             ]),
         ]);
 
@@ -2654,10 +3312,10 @@ mod tests {
 
         // Test that runtime context constraints are respected
         let file_expr = Expression::List(vec![
-            Expression::Symbol(Symbol("call".to_string())),
-            Expression::Symbol(Symbol("file.read".to_string())),
+            Expression::Symbol(Symbol("call".into())),
+            Expression::Symbol(Symbol("file.read".into())),
             Expression::List(vec![
-                Expression::Symbol(Symbol("values".to_string())),
+                Expression::Symbol(Symbol("values".into())),
                 Expression::Literal(Literal::String("/data/input.txt".to_string())),
             ]),
         ]);
@@ -2689,10 +3347,10 @@ mod tests {
         );
 
         let network_expr = Expression::List(vec![
-            Expression::Symbol(Symbol("call".to_string())),
-            Expression::Symbol(Symbol("http.fetch".to_string())),
+            Expression::Symbol(Symbol("call".into())),
+            Expression::Symbol(Symbol("http.fetch".into())),
             Expression::List(vec![
-                Expression::Symbol(Symbol("values".to_string())),
+                Expression::Symbol(Symbol("values".into())),
                 Expression::Literal(Literal::String("https://api.example.com/data".to_string())),
             ]),
         ]);
@@ -2734,10 +3392,10 @@ mod tests {
         );
 
         let expr = Expression::List(vec![
-            Expression::Symbol(Symbol("call".to_string())),
-            Expression::Symbol(Symbol("math.add".to_string())),
+            Expression::Symbol(Symbol("call".into())),
+            Expression::Symbol(Symbol("math.add".into())),
             Expression::List(vec![
-                Expression::Symbol(Symbol("values".to_string())),
+                Expression::Symbol(Symbol("values".into())),
                 Expression::Literal(Literal::Integer(1)),
                 Expression::Literal(Literal::Integer(2)),
             ]),
@@ -2776,10 +3434,10 @@ mod tests {
 
         // Test that dangerous operations get comprehensive security flags
         let dangerous_expr = Expression::List(vec![
-            Expression::Symbol(Symbol("call".to_string())),
-            Expression::Symbol(Symbol("system.execute".to_string())),
+            Expression::Symbol(Symbol("call".into())),
+            Expression::Symbol(Symbol("system.execute".into())),
             Expression::List(vec![
-                Expression::Symbol(Symbol("values".to_string())),
+                Expression::Symbol(Symbol("values".into())),
                 Expression::Literal(Literal::String("sudo rm -rf /".to_string())),
             ]),
         ]);
@@ -2813,10 +3471,10 @@ mod tests {
 
         // Test that network operations get appropriate bandwidth limits
         let network_expr = Expression::List(vec![
-            Expression::Symbol(Symbol("call".to_string())),
-            Expression::Symbol(Symbol("http.download".to_string())),
+            Expression::Symbol(Symbol("call".into())),
+            Expression::Symbol(Symbol("http.download".into())),
             Expression::List(vec![
-                Expression::Symbol(Symbol("values".to_string())),
+                Expression::Symbol(Symbol("values".into())),
                 Expression::Literal(Literal::String(
                     "https://example.com/large-file.zip".to_string(),
                 )),
@@ -2850,10 +3508,10 @@ mod tests {
 
         // Test that data operations are marked as deterministic
         let data_expr = Expression::List(vec![
-            Expression::Symbol(Symbol("call".to_string())),
-            Expression::Symbol(Symbol("data.parse-json".to_string())),
+            Expression::Symbol(Symbol("call".into())),
+            Expression::Symbol(Symbol("data.parse-json".into())),
             Expression::List(vec![
-                Expression::Symbol(Symbol("values".to_string())),
+                Expression::Symbol(Symbol("values".into())),
                 Expression::Literal(Literal::String("{\"key\": \"value\"}".to_string())),
             ]),
         ]);
@@ -2865,4 +3523,11 @@ mod tests {
         assert_eq!(profile.deterministic, true);
         assert_eq!(profile.isolation_level, IsolationLevel::Inherit);
     }
+
+    #[test]
+    fn run_concurrently_runs_sequentially_outside_a_tokio_runtime() {
+        let outputs =
+            futures::executor::block_on(run_concurrently(vec![1, 2, 3], |n| async move { n * 10 }));
+        assert_eq!(outputs, vec![10, 20, 30]);
+    }
 }
@@ -568,8 +568,8 @@ Instructions:
                 let cap = marketplace_clone
                     .get_capability(&cap_id)
                     .await
-                    .ok_or_else(|| {
-                        RuntimeError::Generic(format!("Capability {} not found", cap_id))
+                    .ok_or_else(|| RuntimeError::CapabilityNotFound {
+                        capability_id: cap_id.clone(),
                     })?;
 
                 // We need to execute the handler.
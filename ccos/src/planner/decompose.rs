@@ -0,0 +1,244 @@
+//! Deterministic goal decomposition.
+//!
+//! A model-free heuristic that splits a goal into an ordered list of steps so tests
+//! and offline runs can plan without an LLM:
+//! - sequencing words (`then`, `and then`, `after that`, `afterwards`, `finally`, `;`,
+//!   sentence breaks) start a new stage that depends on every step of the previous one
+//! - `and` between two action clauses splits a stage into sibling steps; a sibling that
+//!   refers back to earlier output (`it`, `them`, `the result`, ...) depends on the
+//!   step before it, otherwise it shares the stage's dependencies
+//!
+//! Dependencies are indices into the returned list and map directly onto
+//! `DependsOn` edges in the intent graph (see [`Planner::record_in_graph`]).
+
+use crate::intent_graph::IntentGraph;
+use crate::types::{EdgeType, IntentId, StorableIntent};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use rtfs::runtime::error::RuntimeError;
+
+static SEQUENCE_SPLIT: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?i)\s*[;.]\s+|\s*[;.]\s*$|,?\s+(?:and\s+)?then\s+|,?\s+after\s+that,?\s+|,?\s+afterwards,?\s+|,?\s+(?:and\s+)?finally,?\s+",
+    )
+    .unwrap()
+});
+
+static CONJUNCTION_SPLIT: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?i),?\s+and\s+").unwrap());
+
+static LEADING_MARKER: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?i)^(?:first(?:ly)?|then|finally|next|and),?\s+").unwrap());
+
+static BACK_REFERENCE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?i)\b(?:it|its|them|their|that|those|these|the\s+(?:results?|output|data|response))\b",
+    )
+    .unwrap()
+});
+
+/// Verbs that mark the start of an action clause after `and`; anything else is
+/// treated as part of the previous clause ("fetch users and orders").
+const ACTION_VERBS: &[&str] = &[
+    "analyse",
+    "analyze",
+    "ask",
+    "build",
+    "calculate",
+    "check",
+    "compare",
+    "compute",
+    "count",
+    "create",
+    "delete",
+    "download",
+    "email",
+    "extract",
+    "fetch",
+    "filter",
+    "find",
+    "generate",
+    "get",
+    "group",
+    "list",
+    "load",
+    "notify",
+    "parse",
+    "plot",
+    "post",
+    "publish",
+    "read",
+    "render",
+    "report",
+    "retrieve",
+    "save",
+    "search",
+    "send",
+    "share",
+    "sort",
+    "store",
+    "summarise",
+    "summarize",
+    "translate",
+    "update",
+    "upload",
+    "validate",
+    "write",
+];
+
+/// One step of a decomposed goal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlannedStep {
+    /// What this step should accomplish
+    pub description: String,
+    /// Indices of steps (in the same list) that must complete first
+    pub dependencies: Vec<usize>,
+}
+
+/// Heuristic, LLM-free planner.
+pub struct Planner;
+
+impl Planner {
+    /// Decompose `goal` into dependency-ordered steps. Every dependency index is
+    /// lower than the index of the step declaring it, so the list is already a valid
+    /// execution order. A goal with no recognised structure yields a single step.
+    pub fn decompose(goal: &str) -> Vec<PlannedStep> {
+        let mut steps: Vec<PlannedStep> = Vec::new();
+        let mut previous_stage: Vec<usize> = Vec::new();
+
+        for stage in SEQUENCE_SPLIT.split(goal.trim()) {
+            let stage = strip_marker(stage);
+            if stage.is_empty() {
+                continue;
+            }
+
+            let mut current_stage = Vec::new();
+            for clause in split_clauses(stage) {
+                let dependencies = match current_stage.last() {
+                    Some(&sibling) if BACK_REFERENCE.is_match(&clause) => vec![sibling],
+                    _ => previous_stage.clone(),
+                };
+                current_stage.push(steps.len());
+                steps.push(PlannedStep {
+                    description: clause,
+                    dependencies,
+                });
+            }
+            previous_stage = current_stage;
+        }
+
+        steps
+    }
+
+    /// `(dependent, dependency)` index pairs, one per `DependsOn` edge.
+    pub fn dependency_edges(steps: &[PlannedStep]) -> Vec<(usize, usize)> {
+        steps
+            .iter()
+            .enumerate()
+            .flat_map(|(index, step)| step.dependencies.iter().map(move |&dep| (index, dep)))
+            .collect()
+    }
+
+    /// Store each step as an intent and link it to its dependencies with
+    /// `DependsOn` edges. Returns the intent ids in step order.
+    pub fn record_in_graph(
+        graph: &mut IntentGraph,
+        steps: &[PlannedStep],
+    ) -> Result<Vec<IntentId>, RuntimeError> {
+        let mut ids = Vec::with_capacity(steps.len());
+        for step in steps {
+            let intent = StorableIntent::new(step.description.clone());
+            ids.push(intent.intent_id.clone());
+            graph.store_intent(intent)?;
+        }
+        for (dependent, dependency) in Self::dependency_edges(steps) {
+            graph.create_edge(
+                ids[dependent].clone(),
+                ids[dependency].clone(),
+                EdgeType::DependsOn,
+            )?;
+        }
+        Ok(ids)
+    }
+}
+
+fn strip_marker(text: &str) -> &str {
+    let text = text.trim();
+    match LEADING_MARKER.find(text) {
+        Some(marker) => text[marker.end()..].trim(),
+        None => text,
+    }
+}
+
+/// Split a stage on `and` only where the right-hand side starts a new action.
+fn split_clauses(stage: &str) -> Vec<String> {
+    let mut clauses: Vec<String> = Vec::new();
+    let mut rest = stage;
+    let mut start = 0;
+    for conjunction in CONJUNCTION_SPLIT.find_iter(stage) {
+        let next = &stage[conjunction.end()..];
+        let first_word = next
+            .split(|c: char| !c.is_alphanumeric())
+            .next()
+            .unwrap_or("")
+            .to_lowercase();
+        if ACTION_VERBS.contains(&first_word.as_str()) {
+            clauses.push(stage[start..conjunction.start()].trim().to_string());
+            start = conjunction.end();
+            rest = next;
+        }
+    }
+    clauses.push(rest.trim().to_string());
+    clauses.retain(|c| !c.is_empty());
+    clauses
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn descriptions(steps: &[PlannedStep]) -> Vec<&str> {
+        steps.iter().map(|s| s.description.as_str()).collect()
+    }
+
+    #[test]
+    fn sequencing_word_creates_ordered_dependent_steps() {
+        let steps = Planner::decompose("fetch data then analyze it");
+        assert_eq!(descriptions(&steps), vec!["fetch data", "analyze it"]);
+        assert!(steps[0].dependencies.is_empty());
+        assert_eq!(steps[1].dependencies, vec![0]);
+        assert_eq!(Planner::dependency_edges(&steps), vec![(1, 0)]);
+    }
+
+    #[test]
+    fn conjunctions_split_only_between_actions() {
+        let steps = Planner::decompose(
+            "First fetch users and orders, then count them and email the report",
+        );
+        assert_eq!(
+            descriptions(&steps),
+            vec!["fetch users and orders", "count them", "email the report"]
+        );
+        assert_eq!(steps[1].dependencies, vec![0]);
+        // Independent sibling: shares the stage's dependency instead of chaining
+        assert_eq!(steps[2].dependencies, vec![0]);
+
+        let steps = Planner::decompose("download the file and summarize it");
+        assert_eq!(steps[1].dependencies, vec![0]);
+
+        let single = Planner::decompose("book a table for two");
+        assert_eq!(descriptions(&single), vec!["book a table for two"]);
+        assert!(single[0].dependencies.is_empty());
+    }
+
+    #[test]
+    fn steps_are_recorded_with_depends_on_edges() {
+        let mut graph = IntentGraph::new().unwrap();
+        let steps = Planner::decompose("fetch data then analyze it");
+        let ids = Planner::record_in_graph(&mut graph, &steps).unwrap();
+
+        let edges = graph.get_edges_for_intent(&ids[1]);
+        assert!(edges
+            .iter()
+            .any(|e| e.from == ids[1] && e.to == ids[0] && e.edge_type == EdgeType::DependsOn));
+    }
+}
@@ -192,7 +192,7 @@ fn insert_entry(
     let annotated_key = if is_function_type {
         format!("{} (function - cannot be passed directly)", key)
     } else {
-        key.clone()
+        key.to_string()
     };
 
     let is_optional =
@@ -212,10 +212,10 @@ fn insert_entry(
         eprintln!("DEBUG: value_type_debug={:?}", entry.value_type);
     }
     if is_optional {
-        required.remove(&key);
+        required.remove(key.as_str());
         optional.insert(annotated_key);
     } else {
-        optional.remove(&key);
+        optional.remove(key.as_str());
         required.insert(annotated_key);
     }
 }
@@ -253,7 +253,7 @@ fn collect_output_keys(schema: &TypeExpr, outputs: &mut BTreeSet<String>) {
     match schema {
         TypeExpr::Map { entries, .. } => {
             for entry in entries {
-                outputs.insert(entry.key.0.clone());
+                outputs.insert(entry.key.0.to_string());
                 collect_output_keys(entry.value_type.as_ref(), outputs);
             }
         }
@@ -8,6 +8,7 @@ pub mod capabilities;
 pub mod capabilities_v2;
 pub mod catalog_adapter;
 pub mod coverage;
+pub mod decompose;
 pub mod dialogue_planner;
 pub mod menu;
 pub mod modular_planner;
@@ -15,4 +16,5 @@ pub mod resolution;
 pub mod signals;
 
 pub use catalog_adapter::CcosCatalogAdapter;
+pub use decompose::{PlannedStep, Planner};
 pub use dialogue_planner::DialoguePlanner;
@@ -206,7 +206,9 @@ pub fn detect_unresolved_capabilities(expr: &str) -> Vec<String> {
 
     fn collect_unresolved(expr: &Expression, acc: &mut Vec<String>) {
         match expr {
-            Expression::FunctionCall { callee, arguments } => {
+            Expression::FunctionCall {
+                callee, arguments, ..
+            } => {
                 // Check if this is a (call "generated/..." ...) or (call "pending/..." ...)
                 if let Expression::Symbol(Symbol(sym)) = callee.as_ref() {
                     if sym == "call" {
@@ -1309,6 +1311,7 @@ impl ModularPlanner {
                     priority: idx as u32,
                     created_at: now,
                     updated_at: now,
+                    version: 0,
                     metadata: {
                         let mut meta = HashMap::new();
                         meta.insert(
@@ -1374,6 +1377,7 @@ impl ModularPlanner {
             priority: 0,
             created_at: now,
             updated_at: now,
+            version: 0,
             metadata: HashMap::new(),
         };
 
@@ -1429,6 +1433,7 @@ impl ModularPlanner {
                 priority: idx as u32,
                 created_at: now,
                 updated_at: now,
+                version: 0,
                 metadata: {
                     let mut meta = HashMap::new();
                     meta.insert(
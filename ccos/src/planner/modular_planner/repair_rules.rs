@@ -4,7 +4,7 @@
 //! in RTFS expressions. Rules are generic and not specific to any particular
 //! capability or data structure.
 
-use rtfs::ast::{Expression, Literal, Symbol};
+use rtfs::ast::{Expression, Literal, Span, Symbol};
 use std::collections::HashMap;
 
 /// Context for attempting expression repair
@@ -97,7 +97,9 @@ fn unwrap_get_on_vector(expr: &str) -> Option<String> {
 
     fn find_and_unwrap_get(expr: Expression) -> (Expression, bool) {
         match expr {
-            Expression::FunctionCall { callee, arguments } => {
+            Expression::FunctionCall {
+                callee, arguments, ..
+            } => {
                 if let Expression::Symbol(Symbol(sym)) = callee.as_ref() {
                     if sym == "get" && arguments.len() >= 2 {
                         // Check if second arg is a keyword
@@ -127,6 +129,7 @@ fn unwrap_get_on_vector(expr: &str) -> Option<String> {
                     Expression::FunctionCall {
                         callee: Box::new(new_callee),
                         arguments: new_args,
+                        span: Span::unknown(),
                     },
                     changed,
                 )
@@ -204,12 +207,14 @@ pub fn validate_get_expression(
         var_schemas: &HashMap<String, String>,
     ) -> (Expression, bool) {
         match expr {
-            Expression::FunctionCall { callee, arguments } => {
+            Expression::FunctionCall {
+                callee, arguments, ..
+            } => {
                 if let Expression::Symbol(Symbol(sym)) = callee.as_ref() {
                     if sym == "get" && arguments.len() >= 2 {
                         // Check if first arg is a symbol with a vector schema
                         if let Expression::Symbol(Symbol(var_name)) = &arguments[0] {
-                            if let Some(schema) = var_schemas.get(var_name) {
+                            if let Some(schema) = var_schemas.get(var_name.as_str()) {
                                 if schema.starts_with("[:vector")
                                     || schema.starts_with("[\"vector\"")
                                 {
@@ -245,6 +250,7 @@ pub fn validate_get_expression(
                     Expression::FunctionCall {
                         callee: Box::new(new_callee),
                         arguments: new_args,
+                        span: Span::unknown(),
                     },
                     changed,
                 )
@@ -323,7 +323,7 @@ impl SafeCapabilityExecutor {
 
             // Insert both string and keyword keys for compatibility
             map.insert(MapKey::String(k.clone()), rtfs_val.clone());
-            map.insert(MapKey::Keyword(Keyword(k.clone())), rtfs_val);
+            map.insert(MapKey::Keyword(Keyword::new(k.as_str())), rtfs_val);
         }
 
         // Inject _previous_result for data pipeline support
@@ -335,7 +335,7 @@ impl SafeCapabilityExecutor {
 
             map.insert(MapKey::String("_previous_result".to_string()), prev.clone());
             map.insert(
-                MapKey::Keyword(Keyword("_previous_result".to_string())),
+                MapKey::Keyword(Keyword("_previous_result".into())),
                 prev.clone(),
             );
 
@@ -345,7 +345,7 @@ impl SafeCapabilityExecutor {
             );
         }
 
-        let input = Value::Map(map);
+        let input = Value::Map(map.into());
 
         let result = self
             .marketplace
@@ -242,6 +242,7 @@ pub async fn step_store_intents(
         priority: 0,
         created_at: now,
         updated_at: now,
+        version: 0,
         metadata: HashMap::new(),
     };
 
@@ -296,6 +297,7 @@ pub async fn step_store_intents(
             priority: idx as u32,
             created_at: now,
             updated_at: now,
+            version: 0,
             metadata: {
                 let mut meta = HashMap::new();
                 meta.insert(
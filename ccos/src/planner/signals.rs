@@ -511,7 +511,7 @@ fn value_to_lowercase_string(value: &Value) -> String {
             for (key, val) in map {
                 let key_str = match key {
                     rtfs::ast::MapKey::String(s) => s.clone(),
-                    rtfs::ast::MapKey::Keyword(k) => k.0.clone(),
+                    rtfs::ast::MapKey::Keyword(k) => k.0.to_string(),
                     rtfs::ast::MapKey::Integer(i) => i.to_string(),
                 };
                 parts.push(format!(
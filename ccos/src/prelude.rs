@@ -11,12 +11,48 @@ use rtfs::runtime::execution_outcome::ExecutionOutcome;
 use rtfs::runtime::values::{Arity, BuiltinFunctionWithContext, Function, Value};
 use rtfs::runtime::Environment;
 
+/// Which CCOS helpers get bound into a plan's environment
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Prelude {
+    effectful: bool,
+}
+
+impl Prelude {
+    /// The secure stdlib plus every effectful CCOS helper (the default)
+    pub fn full() -> Self {
+        Self { effectful: true }
+    }
+
+    /// Only the pure secure stdlib. Effectful helpers such as `tool/http-fetch`
+    /// are never bound, so untrusted code cannot even resolve them.
+    pub fn secure_only() -> Self {
+        Self { effectful: false }
+    }
+
+    pub fn is_secure_only(&self) -> bool {
+        !self.effectful
+    }
+
+    /// Bind this prelude's helpers into the given environment
+    pub fn load(&self, env: &mut Environment) {
+        if self.effectful {
+            load_prelude(env);
+        }
+    }
+}
+
+impl Default for Prelude {
+    fn default() -> Self {
+        Self::full()
+    }
+}
+
 /// Load CCOS-provided prelude into the given environment.
 /// Registers effectful helpers that delegate to host capabilities via evaluator.host.
 pub fn load_prelude(env: &mut Environment) {
     // Logging
     env.define(
-        &Symbol("tool/log".to_string()),
+        &Symbol("tool/log".into()),
         Value::Function(Function::BuiltinWithContext(BuiltinFunctionWithContext {
             name: "tool/log".to_string(),
             arity: Arity::Variadic(1),
@@ -28,7 +64,7 @@ pub fn load_prelude(env: &mut Environment) {
         })),
     );
     env.define(
-        &Symbol("tool.log".to_string()),
+        &Symbol("tool.log".into()),
         Value::Function(Function::BuiltinWithContext(BuiltinFunctionWithContext {
             name: "tool.log".to_string(),
             arity: Arity::Variadic(1),
@@ -55,18 +91,18 @@ pub fn load_prelude(env: &mut Environment) {
                 let key_val = args.pop().unwrap();
                 let key = match key_val {
                     Value::String(s) => s,
-                    Value::Keyword(k) => k.0,
-                    Value::Symbol(sym) => sym.0,
+                    Value::Keyword(k) => k.0.to_string(),
+                    Value::Symbol(sym) => sym.0.to_string(),
                     other => other.to_string(),
                 };
                 evaluator.set_context_value(key, value).map(|_| Value::Nil)
             },
         ),
     }));
-    env.define(&Symbol("ccos.context/set".to_string()), context_set.clone());
-    env.define(&Symbol("context/set".to_string()), context_set);
+    env.define(&Symbol("ccos.context/set".into()), context_set.clone());
+    env.define(&Symbol("context/set".into()), context_set);
     env.define(
-        &Symbol("log".to_string()),
+        &Symbol("log".into()),
         Value::Function(Function::BuiltinWithContext(BuiltinFunctionWithContext {
             name: "log".to_string(),
             arity: Arity::Variadic(0),
@@ -80,7 +116,7 @@ pub fn load_prelude(env: &mut Environment) {
 
     // Printing
     env.define(
-        &Symbol("println".to_string()),
+        &Symbol("println".into()),
         Value::Function(Function::BuiltinWithContext(BuiltinFunctionWithContext {
             name: "println".to_string(),
             arity: Arity::Variadic(0),
@@ -102,7 +138,7 @@ pub fn load_prelude(env: &mut Environment) {
 
     // System time
     env.define(
-        &Symbol("tool/time-ms".to_string()),
+        &Symbol("tool/time-ms".into()),
         Value::Function(Function::BuiltinWithContext(BuiltinFunctionWithContext {
             name: "tool/time-ms".to_string(),
             arity: Arity::Fixed(0),
@@ -116,7 +152,7 @@ pub fn load_prelude(env: &mut Environment) {
         })),
     );
     env.define(
-        &Symbol("tool.time-ms".to_string()),
+        &Symbol("tool.time-ms".into()),
         Value::Function(Function::BuiltinWithContext(BuiltinFunctionWithContext {
             name: "tool.time-ms".to_string(),
             arity: Arity::Fixed(0),
@@ -130,7 +166,7 @@ pub fn load_prelude(env: &mut Environment) {
         })),
     );
     env.define(
-        &Symbol("current-time-millis".to_string()),
+        &Symbol("current-time-millis".into()),
         Value::Function(Function::BuiltinWithContext(BuiltinFunctionWithContext {
             name: "current-time-millis".to_string(),
             arity: Arity::Fixed(0),
@@ -146,7 +182,7 @@ pub fn load_prelude(env: &mut Environment) {
 
     // Env and Files
     env.define(
-        &Symbol("get-env".to_string()),
+        &Symbol("get-env".into()),
         Value::Function(Function::BuiltinWithContext(BuiltinFunctionWithContext {
             name: "get-env".to_string(),
             arity: Arity::Fixed(1),
@@ -160,7 +196,7 @@ pub fn load_prelude(env: &mut Environment) {
         })),
     );
     env.define(
-        &Symbol("file-exists?".to_string()),
+        &Symbol("file-exists?".into()),
         Value::Function(Function::BuiltinWithContext(BuiltinFunctionWithContext {
             name: "file-exists?".to_string(),
             arity: Arity::Fixed(1),
@@ -174,7 +210,7 @@ pub fn load_prelude(env: &mut Environment) {
         })),
     );
     env.define(
-        &Symbol("tool/open-file".to_string()),
+        &Symbol("tool/open-file".into()),
         Value::Function(Function::BuiltinWithContext(BuiltinFunctionWithContext {
             name: "tool/open-file".to_string(),
             arity: Arity::Fixed(1),
@@ -190,7 +226,7 @@ pub fn load_prelude(env: &mut Environment) {
 
     // HTTP
     env.define(
-        &Symbol("tool/http-fetch".to_string()),
+        &Symbol("tool/http-fetch".into()),
         Value::Function(Function::BuiltinWithContext(BuiltinFunctionWithContext {
             name: "tool/http-fetch".to_string(),
             arity: Arity::Fixed(1),
@@ -204,7 +240,7 @@ pub fn load_prelude(env: &mut Environment) {
         })),
     );
     env.define(
-        &Symbol("http-fetch".to_string()),
+        &Symbol("http-fetch".into()),
         Value::Function(Function::BuiltinWithContext(BuiltinFunctionWithContext {
             name: "http-fetch".to_string(),
             arity: Arity::Fixed(1),
@@ -220,7 +256,7 @@ pub fn load_prelude(env: &mut Environment) {
 
     // Thread sleep
     env.define(
-        &Symbol("thread/sleep".to_string()),
+        &Symbol("thread/sleep".into()),
         Value::Function(Function::BuiltinWithContext(BuiltinFunctionWithContext {
             name: "thread/sleep".to_string(),
             arity: Arity::Fixed(1),
@@ -236,7 +272,7 @@ pub fn load_prelude(env: &mut Environment) {
 
     // Step (debug)
     env.define(
-        &Symbol("step".to_string()),
+        &Symbol("step".into()),
         Value::Function(Function::BuiltinWithContext(BuiltinFunctionWithContext {
             name: "step".to_string(),
             arity: Arity::Variadic(1),
@@ -250,7 +286,7 @@ pub fn load_prelude(env: &mut Environment) {
 
     // KV convenience helpers (effectful): assoc!/dissoc!/conj!
     env.define(
-        &Symbol("kv/assoc!".to_string()),
+        &Symbol("kv/assoc!".into()),
         Value::Function(Function::BuiltinWithContext(BuiltinFunctionWithContext {
             name: "kv/assoc!".to_string(),
             arity: Arity::Variadic(3),
@@ -262,7 +298,7 @@ pub fn load_prelude(env: &mut Environment) {
         })),
     );
     env.define(
-        &Symbol("kv/dissoc!".to_string()),
+        &Symbol("kv/dissoc!".into()),
         Value::Function(Function::BuiltinWithContext(BuiltinFunctionWithContext {
             name: "kv/dissoc!".to_string(),
             arity: Arity::Variadic(2),
@@ -274,7 +310,7 @@ pub fn load_prelude(env: &mut Environment) {
         })),
     );
     env.define(
-        &Symbol("kv/conj!".to_string()),
+        &Symbol("kv/conj!".into()),
         Value::Function(Function::BuiltinWithContext(BuiltinFunctionWithContext {
             name: "kv/conj!".to_string(),
             arity: Arity::Variadic(2),
@@ -288,7 +324,7 @@ pub fn load_prelude(env: &mut Environment) {
 
     // Capability loading
     env.define(
-        &Symbol("load-capability".to_string()),
+        &Symbol("load-capability".into()),
         Value::Function(Function::BuiltinWithContext(BuiltinFunctionWithContext {
             name: "load-capability".to_string(),
             arity: Arity::Fixed(1),
@@ -322,11 +358,11 @@ fn kv_assoc_bang(
         .execute_capability("ccos.state.kv.get", &[kv_key.clone()])
         .unwrap_or(Value::Nil);
     let base = match current {
-        Value::Nil => Value::Map(HashMap::new()),
+        Value::Nil => Value::Map(HashMap::new().into()),
         other => other,
     };
 
-    let assoc_sym = Symbol("assoc".to_string());
+    let assoc_sym = Symbol("assoc".into());
     let assoc_fn = env
         .lookup(&assoc_sym)
         .ok_or_else(|| RuntimeError::Generic("assoc not found".into()))?;
@@ -370,11 +406,11 @@ fn kv_dissoc_bang(
         .execute_capability("ccos.state.kv.get", &[kv_key.clone()])
         .unwrap_or(Value::Nil);
     let base = match current {
-        Value::Nil => Value::Map(HashMap::new()),
+        Value::Nil => Value::Map(HashMap::new().into()),
         other => other,
     };
 
-    let dissoc_sym = Symbol("dissoc".to_string());
+    let dissoc_sym = Symbol("dissoc".into());
     let dissoc_fn = env
         .lookup(&dissoc_sym)
         .ok_or_else(|| RuntimeError::Generic("dissoc not found".into()))?;
@@ -418,11 +454,11 @@ fn kv_conj_bang(
         .execute_capability("ccos.state.kv.get", &[kv_key.clone()])
         .unwrap_or(Value::Nil);
     let base = match current {
-        Value::Nil => Value::Vector(Vec::new()),
+        Value::Nil => Value::Vector(Vec::new().into()),
         other => other,
     };
 
-    let conj_sym = Symbol("conj".to_string());
+    let conj_sym = Symbol("conj".into());
     let conj_fn = env
         .lookup(&conj_sym)
         .ok_or_else(|| RuntimeError::Generic("conj not found".into()))?;
@@ -494,7 +530,7 @@ fn load_capability(args: Vec<Value>, _evaluator: &Evaluator) -> RuntimeResult<Va
     let mut registered_ids = Vec::new();
     for top_level in parsed {
         if let rtfs::ast::TopLevel::Capability(cap_def) = top_level {
-            registered_ids.push(cap_def.name.0.clone());
+            registered_ids.push(cap_def.name.0.to_string());
         }
     }
 
@@ -5,6 +5,7 @@
 //! trait for seamless integration with the delegation engine.
 
 use crate::delegation::ModelProvider;
+use crate::observability::llm_usage::{LlmUsageTracker, TokenUsage};
 use futures::executor::block_on;
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
@@ -135,6 +136,26 @@ struct OpenAIMessage {
 #[derive(Deserialize)]
 struct OpenAIResponse {
     choices: Vec<OpenAIChoice>,
+    #[serde(default)]
+    usage: Option<OpenAIUsage>,
+}
+
+#[derive(Deserialize)]
+struct OpenAIUsage {
+    prompt_tokens: Option<u64>,
+    completion_tokens: Option<u64>,
+}
+
+impl OpenAIResponse {
+    fn token_usage(&self) -> TokenUsage {
+        self.usage
+            .as_ref()
+            .map(|u| TokenUsage {
+                prompt_tokens: u.prompt_tokens,
+                completion_tokens: u.completion_tokens,
+            })
+            .unwrap_or_default()
+    }
 }
 
 #[derive(Deserialize)]
@@ -148,6 +169,13 @@ impl ModelProvider for OpenAIModel {
     }
 
     fn infer(&self, prompt: &str) -> Result<String, Box<dyn std::error::Error + Send + Sync>> {
+        self.infer_with_usage(prompt).map(|(text, _)| text)
+    }
+
+    fn infer_with_usage(
+        &self,
+        prompt: &str,
+    ) -> Result<(String, TokenUsage), Box<dyn std::error::Error + Send + Sync>> {
         let request = OpenAIRequest {
             model: self.base.config.model_name.clone(),
             messages: vec![OpenAIMessage {
@@ -174,7 +202,8 @@ impl ModelProvider for OpenAIModel {
                 .await
         })?;
 
-        Ok(response.choices[0].message.content.clone())
+        let usage = response.token_usage();
+        Ok((response.choices[0].message.content.clone(), usage))
     }
 }
 
@@ -231,6 +260,16 @@ struct GeminiGenerationConfig {
 #[derive(Deserialize)]
 struct GeminiResponse {
     candidates: Vec<GeminiCandidate>,
+    #[serde(default, rename = "usageMetadata")]
+    usage_metadata: Option<GeminiUsageMetadata>,
+}
+
+#[derive(Deserialize)]
+struct GeminiUsageMetadata {
+    #[serde(rename = "promptTokenCount")]
+    prompt_token_count: Option<u64>,
+    #[serde(rename = "candidatesTokenCount")]
+    candidates_token_count: Option<u64>,
 }
 
 #[derive(Deserialize)]
@@ -244,6 +283,13 @@ impl ModelProvider for GeminiModel {
     }
 
     fn infer(&self, prompt: &str) -> Result<String, Box<dyn std::error::Error + Send + Sync>> {
+        self.infer_with_usage(prompt).map(|(text, _)| text)
+    }
+
+    fn infer_with_usage(
+        &self,
+        prompt: &str,
+    ) -> Result<(String, TokenUsage), Box<dyn std::error::Error + Send + Sync>> {
         let request = GeminiRequest {
             contents: vec![GeminiContent {
                 parts: vec![GeminiPart {
@@ -273,7 +319,15 @@ impl ModelProvider for GeminiModel {
                 .await
         })?;
 
-        Ok(response.candidates[0].content.parts[0].text.clone())
+        let usage = response
+            .usage_metadata
+            .as_ref()
+            .map(|u| TokenUsage {
+                prompt_tokens: u.prompt_token_count,
+                completion_tokens: u.candidates_token_count,
+            })
+            .unwrap_or_default();
+        Ok((response.candidates[0].content.parts[0].text.clone(), usage))
     }
 }
 
@@ -329,6 +383,14 @@ struct ClaudeMessage {
 #[derive(Deserialize)]
 struct ClaudeResponse {
     content: Vec<ClaudeContent>,
+    #[serde(default)]
+    usage: Option<ClaudeUsage>,
+}
+
+#[derive(Deserialize)]
+struct ClaudeUsage {
+    input_tokens: Option<u64>,
+    output_tokens: Option<u64>,
 }
 
 #[derive(Deserialize)]
@@ -342,6 +404,13 @@ impl ModelProvider for ClaudeModel {
     }
 
     fn infer(&self, prompt: &str) -> Result<String, Box<dyn std::error::Error + Send + Sync>> {
+        self.infer_with_usage(prompt).map(|(text, _)| text)
+    }
+
+    fn infer_with_usage(
+        &self,
+        prompt: &str,
+    ) -> Result<(String, TokenUsage), Box<dyn std::error::Error + Send + Sync>> {
         let request = ClaudeRequest {
             model: self.base.config.model_name.clone(),
             max_tokens: self.base.config.max_tokens,
@@ -366,7 +435,15 @@ impl ModelProvider for ClaudeModel {
                 .await
         })?;
 
-        Ok(response.content[0].text.clone())
+        let usage = response
+            .usage
+            .as_ref()
+            .map(|u| TokenUsage {
+                prompt_tokens: u.input_tokens,
+                completion_tokens: u.output_tokens,
+            })
+            .unwrap_or_default();
+        Ok((response.content[0].text.clone(), usage))
     }
 }
 
@@ -418,6 +495,13 @@ impl ModelProvider for OpenRouterModel {
     }
 
     fn infer(&self, prompt: &str) -> Result<String, Box<dyn std::error::Error + Send + Sync>> {
+        self.infer_with_usage(prompt).map(|(text, _)| text)
+    }
+
+    fn infer_with_usage(
+        &self,
+        prompt: &str,
+    ) -> Result<(String, TokenUsage), Box<dyn std::error::Error + Send + Sync>> {
         // OpenRouter uses the same API as OpenAI
         let request = OpenAIRequest {
             model: self.base.config.model_name.clone(),
@@ -473,7 +557,49 @@ impl ModelProvider for OpenRouterModel {
                 .map_err(|e| Box::<dyn std::error::Error + Send + Sync>::from(e))
         })?;
 
-        Ok(response.choices[0].message.content.clone())
+        let usage = response.token_usage();
+        Ok((response.choices[0].message.content.clone(), usage))
+    }
+}
+
+/// Wraps a model provider and reports the token usage of every call to an
+/// [`LlmUsageTracker`], keyed by the wrapped provider's id.
+#[derive(Debug)]
+pub struct MeteredModel<M: ModelProvider> {
+    inner: M,
+    tracker: LlmUsageTracker,
+}
+
+impl<M: ModelProvider> MeteredModel<M> {
+    pub fn new(inner: M, tracker: LlmUsageTracker) -> Self {
+        Self { inner, tracker }
+    }
+
+    pub fn tracker(&self) -> &LlmUsageTracker {
+        &self.tracker
+    }
+
+    pub fn into_inner(self) -> M {
+        self.inner
+    }
+}
+
+impl<M: ModelProvider> ModelProvider for MeteredModel<M> {
+    fn id(&self) -> &'static str {
+        self.inner.id()
+    }
+
+    fn infer(&self, prompt: &str) -> Result<String, Box<dyn std::error::Error + Send + Sync>> {
+        self.infer_with_usage(prompt).map(|(text, _)| text)
+    }
+
+    fn infer_with_usage(
+        &self,
+        prompt: &str,
+    ) -> Result<(String, TokenUsage), Box<dyn std::error::Error + Send + Sync>> {
+        let (text, usage) = self.inner.infer_with_usage(prompt)?;
+        self.tracker.record(self.inner.id(), &usage);
+        Ok((text, usage))
     }
 }
 
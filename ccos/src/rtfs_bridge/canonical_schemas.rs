@@ -5,8 +5,7 @@
 //! normalize to these canonical map structures.
 
 use rtfs::ast::{Expression, MapKey};
-use rtfs::runtime::values::Value;
-use std::collections::HashMap;
+use rtfs::runtime::values::{Value, ValueMap};
 
 /// Canonical Plan map schema
 ///
@@ -39,7 +38,7 @@ impl CanonicalPlanSchema {
     ];
 
     /// Validate that a map represents a canonical Plan structure
-    pub fn validate(plan_map: &HashMap<MapKey, Value>) -> Result<(), String> {
+    pub fn validate(plan_map: &ValueMap) -> Result<(), String> {
         // Check required fields
         for field in Self::REQUIRED_FIELDS {
             let key = MapKey::String(field.to_string());
@@ -110,7 +109,7 @@ impl CanonicalPlanSchema {
     pub fn from_function_call(
         callee: &Expression,
         arguments: &[Expression],
-    ) -> Result<HashMap<MapKey, Value>, String> {
+    ) -> Result<ValueMap, String> {
         // Check callee is "plan" or "ccos/plan"
         let callee_name = match callee {
             Expression::Symbol(s) => &s.0,
@@ -133,7 +132,7 @@ impl CanonicalPlanSchema {
             };
 
         // Build canonical map
-        let mut plan_map = HashMap::new();
+        let mut plan_map = ValueMap::new();
         plan_map.insert(
             MapKey::String(":type".to_string()),
             Value::String("plan".to_string()),
@@ -206,7 +205,7 @@ impl CanonicalCapabilitySchema {
     ];
 
     /// Validate that a map represents a canonical Capability structure
-    pub fn validate(cap_map: &HashMap<MapKey, Value>) -> Result<(), String> {
+    pub fn validate(cap_map: &ValueMap) -> Result<(), String> {
         // Check required fields
         for field in Self::REQUIRED_FIELDS {
             let key = MapKey::String(field.to_string());
@@ -261,7 +260,7 @@ impl CanonicalCapabilitySchema {
             let lang_str = match lang_val {
                 Value::String(s) => Some(s.clone()),
                 Value::Keyword(k) => Some(format!(":{}", k.0)),
-                Value::Symbol(rtfs::ast::Symbol(s)) => Some(s.clone()),
+                Value::Symbol(rtfs::ast::Symbol(s)) => Some(s.to_string()),
                 _ => {
                     return Err(
                         "Capability :language must be string, symbol, or keyword".to_string()
@@ -285,7 +284,7 @@ impl CanonicalCapabilitySchema {
             let provider = match provider_val {
                 Value::String(s) => s.clone(),
                 Value::Keyword(k) => format!(":{}", k.0),
-                Value::Symbol(rtfs::ast::Symbol(s)) => s.clone(),
+                Value::Symbol(rtfs::ast::Symbol(s)) => s.to_string(),
                 _ => "".to_string(),
             };
 
@@ -316,7 +315,7 @@ mod tests {
 
     #[test]
     fn test_canonical_plan_schema_validation() {
-        let mut plan_map = HashMap::new();
+        let mut plan_map = ValueMap::new();
         plan_map.insert(
             MapKey::String(":type".to_string()),
             Value::String("plan".to_string()),
@@ -335,7 +334,7 @@ mod tests {
 
     #[test]
     fn test_canonical_plan_missing_required_field() {
-        let mut plan_map = HashMap::new();
+        let mut plan_map = ValueMap::new();
         plan_map.insert(
             MapKey::String(":type".to_string()),
             Value::String("plan".to_string()),
@@ -350,7 +349,7 @@ mod tests {
         use rtfs::ast::MapKey;
         use rtfs::runtime::values::Value;
 
-        let mut cap_map = HashMap::new();
+        let mut cap_map = ValueMap::new();
         cap_map.insert(
             MapKey::String(":id".to_string()),
             Value::String("test.cap".to_string()),
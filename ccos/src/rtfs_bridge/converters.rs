@@ -1,6 +1,6 @@
 use super::errors::RtfsBridgeError;
 use crate::types::{Intent, Plan};
-use rtfs::ast::{Expression, Literal, MapKey, Symbol};
+use rtfs::ast::{Expression, Literal, MapKey, Span, Symbol};
 use rtfs::runtime::values::Value;
 use std::collections::HashMap;
 
@@ -63,8 +63,9 @@ pub fn intent_to_rtfs_function_call(intent: &Intent) -> Result<Expression, RtfsB
     args.push(Expression::Map(keyword_args));
 
     Ok(Expression::FunctionCall {
-        callee: Box::new(Expression::Symbol(Symbol("intent".to_string()))), // Use simpler name for LLM compatibility
+        callee: Box::new(Expression::Symbol(Symbol("intent".into()))), // Use simpler name for LLM compatibility
         arguments: args,
+        span: Span::unknown(),
     })
 }
 
@@ -147,7 +147,7 @@ fn extract_capability_ids_from_plan(plan: &Plan) -> Result<Vec<String>, RtfsBrid
 /// Extract capability IDs from RTFS code string using regex
 ///
 /// This finds all patterns like `(call :capability.id ...)` and extracts the capability ID.
-fn extract_capability_ids_from_rtfs_string(
+pub(crate) fn extract_capability_ids_from_rtfs_string(
     rtfs_code: &str,
 ) -> Result<Vec<String>, RtfsBridgeError> {
     use regex::Regex;
@@ -199,10 +199,10 @@ fn extract_capability_ids_from_expression(
                     let mut cap_id = String::new();
                     match cap_id_expr {
                         Expression::Literal(Literal::Keyword(keyword)) => {
-                            cap_id = keyword.0.clone();
+                            cap_id = keyword.0.to_string();
                         }
                         Expression::Symbol(Symbol(symbol)) => {
-                            cap_id = symbol.clone();
+                            cap_id = symbol.to_string();
                         }
                         _ => {
                             // Not a recognizable capability ID format
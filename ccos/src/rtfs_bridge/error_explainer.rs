@@ -46,14 +46,19 @@ impl RtfsErrorExplainer {
                     hints: base,
                 })
             }
-            RuntimeError::UndefinedSymbol(symbol) => Some(RtfsErrorDiagnostics {
-                summary: format!("Undefined symbol `{}` encountered during execution.", symbol.0),
-                snippet: None,
-                hints: vec![
-                    "Ensure every symbol is defined before use (e.g. via `let` or as a capability output).".to_string(),
-                    "Verify that previous steps expose the expected output keys.".to_string(),
-                ],
-            }),
+            RuntimeError::UndefinedSymbol { symbol, suggestion } => {
+                let mut hints = Vec::new();
+                if let Some(suggestion) = suggestion {
+                    hints.push(format!("Did you mean `{}`?", suggestion));
+                }
+                hints.push("Ensure every symbol is defined before use (e.g. via `let` or as a capability output).".to_string());
+                hints.push("Verify that previous steps expose the expected output keys.".to_string());
+                Some(RtfsErrorDiagnostics {
+                    summary: format!("Undefined symbol `{}` encountered during execution.", symbol.0),
+                    snippet: None,
+                    hints,
+                })
+            }
             RuntimeError::SymbolNotFound(symbol) => Some(RtfsErrorDiagnostics {
                 summary: format!("Symbol `{}` was not found in the current scope.", symbol),
                 snippet: None,
@@ -12,9 +12,9 @@ use std::collections::HashMap;
 /// and map format: `{:type "intent" :name "..." :goal "..."}`
 pub fn extract_intent_from_rtfs(expr: &Expression) -> Result<Intent, RtfsBridgeError> {
     match expr {
-        Expression::FunctionCall { callee, arguments } => {
-            extract_intent_from_function_call(callee, arguments)
-        }
+        Expression::FunctionCall {
+            callee, arguments, ..
+        } => extract_intent_from_function_call(callee, arguments),
         Expression::Map(map) => extract_intent_from_map(map),
         _ => Err(RtfsBridgeError::InvalidObjectFormat {
             message: format!(
@@ -576,7 +576,7 @@ fn extract_plan_from_map(map: &HashMap<MapKey, Expression>) -> Result<Plan, Rtfs
 fn map_key_to_string(key: &MapKey) -> String {
     match key {
         MapKey::String(s) => s.clone(),
-        MapKey::Keyword(s) => s.0.clone(),
+        MapKey::Keyword(s) => s.0.to_string(),
         MapKey::Integer(i) => i.to_string(),
     }
 }
@@ -584,7 +584,7 @@ fn map_key_to_string(key: &MapKey) -> String {
 fn get_string_from_map(map: &HashMap<MapKey, Expression>, key: &str) -> Option<String> {
     map_get(map, key).and_then(|expr| match expr {
         Expression::Literal(Literal::String(s)) => Some(s.clone()),
-        Expression::Symbol(s) => Some(s.0.clone()),
+        Expression::Symbol(s) => Some(s.0.to_string()),
         _ => None,
     })
 }
@@ -596,7 +596,7 @@ fn map_get<'a>(map: &'a HashMap<MapKey, Expression>, key: &str) -> Option<&'a Ex
         // Try exact string (with colon), then plain string (no colon), then keyword form
         .get(&MapKey::String(kstr))
         .or_else(|| map.get(&MapKey::String(trimmed.to_string())))
-        .or_else(|| map.get(&MapKey::Keyword(Keyword(trimmed.to_string()))))
+        .or_else(|| map.get(&MapKey::Keyword(Keyword(trimmed.into()))))
 }
 
 fn expression_to_value(expr: &Expression) -> Value {
@@ -632,7 +632,7 @@ pub fn capability_def_to_rtfs_string(cap: &rtfs::ast::CapabilityDefinition) -> S
     // Serialize properties as keyword-value pairs
     for prop in &cap.properties {
         // property.key is a Keyword (wrapped struct) - we want the raw string without leading ':'
-        let key = prop.key.0.clone();
+        let key = prop.key.0.to_string();
         // Ensure keyword is prefixed with ':' for canonical form
         let key_formatted = if key.starts_with(':') {
             key
@@ -88,6 +88,7 @@ pub fn build_graph_from_rtfs(
             priority: 1,
             created_at: now,
             updated_at: now,
+            version: 0,
             metadata: HashMap::new(),
         };
         let id = storable.intent_id.clone();
@@ -101,7 +102,9 @@ pub fn build_graph_from_rtfs(
     // Helper: parse an edge Expression and buffer until all intents are inserted
     let mut parse_edge_expr = |expr: &Expression| -> Result<(), RuntimeError> {
         match expr {
-            Expression::FunctionCall { callee, arguments } => {
+            Expression::FunctionCall {
+                callee, arguments, ..
+            } => {
                 // Expect callee "edge" and either map form or (:Type from to)
                 let cname = if let Expression::Symbol(sym) = &**callee {
                     sym.0.as_str()
@@ -235,7 +238,7 @@ fn is_intent_form(expr: &Expression) -> bool {
             // Support both string and keyword keys for :type
             match map
                 .get(&MapKey::String(":type".to_string()))
-                .or_else(|| map.get(&MapKey::Keyword(Keyword("type".to_string()))))
+                .or_else(|| map.get(&MapKey::Keyword(Keyword("type".into()))))
             {
                 Some(Expression::Literal(Literal::String(t))) if t == "intent" => true,
                 _ => false,
@@ -258,14 +261,14 @@ fn map_get<'a>(map: &'a HashMap<MapKey, Expression>, key: &str) -> Option<&'a Ex
     let kstr = key.to_string();
     let trimmed = key.trim_start_matches(':');
     map.get(&MapKey::String(kstr))
-        .or_else(|| map.get(&MapKey::Keyword(Keyword(trimmed.to_string()))))
+        .or_else(|| map.get(&MapKey::Keyword(Keyword(trimmed.into()))))
 }
 
 fn expr_to_string(e: &Expression) -> String {
     match e {
         Expression::Literal(Literal::String(s)) => s.clone(),
-        Expression::Symbol(s) => s.0.clone(),
-        Expression::Literal(Literal::Keyword(Keyword(k))) => k.clone(),
+        Expression::Symbol(s) => s.0.to_string(),
+        Expression::Literal(Literal::Keyword(Keyword(k))) => k.to_string(),
         _ => format!("{}", format_expr_for_debug(e)),
     }
 }
@@ -294,7 +297,7 @@ fn format_expr_for_debug(e: &Expression) -> String {
         Expression::Literal(Literal::Float(f)) => f.to_string(),
         Expression::Literal(Literal::Boolean(b)) => b.to_string(),
         Expression::Literal(Literal::Keyword(Keyword(k))) => format!(":{}", k),
-        Expression::Symbol(s) => s.0.clone(),
+        Expression::Symbol(s) => s.0.to_string(),
         _ => "<expr>".to_string(),
     }
 }
@@ -6,7 +6,7 @@
 use super::errors::RtfsBridgeError;
 use crate::types::PlanLanguage;
 use rtfs::ast::{MapKey, Symbol};
-use rtfs::runtime::values::Value;
+use rtfs::runtime::values::{Value, ValueMap};
 
 /// Canonical language identifier strings
 pub mod canonical_languages {
@@ -45,16 +45,14 @@ pub fn parse_language_string(lang: &str) -> Result<PlanLanguage, RtfsBridgeError
 /// Extract language from a capability map
 ///
 /// Returns the language string if present, or None if not found.
-pub fn extract_language_from_capability_map(
-    cap_map: &std::collections::HashMap<MapKey, Value>,
-) -> Option<String> {
+pub fn extract_language_from_capability_map(cap_map: &ValueMap) -> Option<String> {
     let lang_key = MapKey::String(":language".to_string());
 
     if let Some(lang_val) = cap_map.get(&lang_key) {
         match lang_val {
             Value::String(s) => Some(s.clone()),
             Value::Keyword(k) => Some(format!(":{}", k.0)),
-            Value::Symbol(Symbol(s)) => Some(s.clone()),
+            Value::Symbol(Symbol(s)) => Some(s.to_string()),
             _ => None,
         }
     } else {
@@ -63,16 +61,14 @@ pub fn extract_language_from_capability_map(
 }
 
 /// Extract provider from a capability map
-pub fn extract_provider_from_capability_map(
-    cap_map: &std::collections::HashMap<MapKey, Value>,
-) -> Option<String> {
+pub fn extract_provider_from_capability_map(cap_map: &ValueMap) -> Option<String> {
     let provider_key = MapKey::String(":provider".to_string());
 
     if let Some(provider_val) = cap_map.get(&provider_key) {
         match provider_val {
             Value::String(s) => Some(s.clone()),
             Value::Keyword(k) => Some(format!(":{}", k.0)),
-            Value::Symbol(Symbol(s)) => Some(s.clone()),
+            Value::Symbol(Symbol(s)) => Some(s.to_string()),
             _ => None,
         }
     } else {
@@ -106,9 +102,7 @@ pub fn validate_language_string(lang: &str) -> Result<(), RtfsBridgeError> {
 ///
 /// Local capabilities MUST have a `:language` field to indicate how to execute
 /// the implementation.
-pub fn validate_local_capability_has_language(
-    cap_map: &std::collections::HashMap<MapKey, Value>,
-) -> Result<(), RtfsBridgeError> {
+pub fn validate_local_capability_has_language(cap_map: &ValueMap) -> Result<(), RtfsBridgeError> {
     let provider =
         extract_provider_from_capability_map(cap_map).unwrap_or_else(|| "Local".to_string());
 
@@ -129,7 +123,7 @@ pub fn validate_local_capability_has_language(
 /// If a local capability doesn't have a language, this function will attempt
 /// to infer it from the implementation or set a default.
 pub fn ensure_language_for_local_capability(
-    cap_map: &mut std::collections::HashMap<MapKey, Value>,
+    cap_map: &mut ValueMap,
     default_language: Option<&str>,
 ) -> Result<(), RtfsBridgeError> {
     let provider =
@@ -157,7 +151,6 @@ mod tests {
     use super::*;
     use rtfs::ast::MapKey;
     use rtfs::runtime::values::Value;
-    use std::collections::HashMap;
 
     #[test]
     fn test_plan_language_to_string() {
@@ -198,7 +191,7 @@ mod tests {
 
     #[test]
     fn test_validate_local_capability_has_language() {
-        let mut cap_map = HashMap::new();
+        let mut cap_map = ValueMap::new();
         cap_map.insert(
             MapKey::String(":provider".to_string()),
             Value::String("Local".to_string()),
@@ -217,7 +210,7 @@ mod tests {
 
     #[test]
     fn test_ensure_language_for_local_capability() {
-        let mut cap_map = HashMap::new();
+        let mut cap_map = ValueMap::new();
         cap_map.insert(
             MapKey::String(":provider".to_string()),
             Value::String("Local".to_string()),
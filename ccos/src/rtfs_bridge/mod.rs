@@ -14,6 +14,7 @@ pub mod graph_interpreter;
 pub mod language_utils;
 pub mod normalizer;
 pub mod plan_as_capability;
+pub mod plan_diff;
 pub mod pretty_printer;
 pub mod validators;
 
@@ -27,5 +28,6 @@ pub use graph_interpreter::*;
 pub use language_utils::*;
 pub use normalizer::*;
 pub use plan_as_capability::*;
+pub use plan_diff::*;
 pub use pretty_printer::*;
 pub use validators::*;
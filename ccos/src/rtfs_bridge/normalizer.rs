@@ -7,7 +7,7 @@
 use super::canonical_schemas::{CanonicalCapabilitySchema, CanonicalPlanSchema};
 use super::errors::RtfsBridgeError;
 use rtfs::ast::{Expression, Literal, MapKey};
-use rtfs::runtime::values::Value;
+use rtfs::runtime::values::{Value, ValueMap, ValueVector};
 use std::collections::HashMap;
 
 /// Configuration for normalization behavior
@@ -39,7 +39,9 @@ pub fn normalize_plan_to_map(
     config: NormalizationConfig,
 ) -> Result<Expression, RtfsBridgeError> {
     match expr {
-        Expression::FunctionCall { callee, arguments } => {
+        Expression::FunctionCall {
+            callee, arguments, ..
+        } => {
             // Emit deprecation warning if enabled
             if config.warn_on_function_call {
                 eprintln!(
@@ -131,7 +133,7 @@ pub fn normalize_plan_to_map(
             // Validate if enabled
             if config.validate_after_normalization {
                 // Convert Expression map to Value map for validation
-                let value_map: HashMap<MapKey, Value> = plan_map
+                let value_map: ValueMap = plan_map
                     .iter()
                     .filter_map(|(k, v)| Some((k.clone(), expression_to_value_simple(v)?)))
                     .collect();
@@ -170,7 +172,7 @@ pub fn normalize_plan_to_map(
 
             // Validate if enabled
             if config.validate_after_normalization {
-                let value_map: HashMap<MapKey, Value> = normalized_map
+                let value_map: ValueMap = normalized_map
                     .iter()
                     .filter_map(|(k, v)| Some((k.clone(), expression_to_value_simple(v)?)))
                     .collect();
@@ -202,7 +204,9 @@ pub fn normalize_capability_to_map(
     config: NormalizationConfig,
 ) -> Result<Expression, RtfsBridgeError> {
     match expr {
-        Expression::FunctionCall { callee, arguments } => {
+        Expression::FunctionCall {
+            callee, arguments, ..
+        } => {
             // Emit deprecation warning if enabled
             if config.warn_on_function_call {
                 eprintln!(
@@ -294,7 +298,7 @@ pub fn normalize_capability_to_map(
 
             // Validate if enabled
             if config.validate_after_normalization {
-                let value_map: HashMap<MapKey, Value> = cap_map
+                let value_map: ValueMap = cap_map
                     .iter()
                     .filter_map(|(k, v)| Some((k.clone(), expression_to_value_simple(v)?)))
                     .collect();
@@ -333,7 +337,7 @@ pub fn normalize_capability_to_map(
 
             // Validate if enabled
             if config.validate_after_normalization {
-                let value_map: HashMap<MapKey, Value> = normalized_map
+                let value_map: ValueMap = normalized_map
                     .iter()
                     .filter_map(|(k, v)| Some((k.clone(), expression_to_value_simple(v)?)))
                     .collect();
@@ -381,11 +385,11 @@ pub fn expression_to_value_simple(expr: &Expression) -> Option<Value> {
         },
         Expression::Symbol(s) => Some(Value::Symbol(s.clone())),
         Expression::Vector(vec) => {
-            let values: Option<Vec<Value>> = vec.iter().map(expression_to_value_simple).collect();
+            let values: Option<ValueVector> = vec.iter().map(expression_to_value_simple).collect();
             values.map(Value::Vector)
         }
         Expression::Map(map) => {
-            let value_map: Option<HashMap<MapKey, Value>> = map
+            let value_map: Option<ValueMap> = map
                 .iter()
                 .map(|(k, v)| expression_to_value_simple(v).map(|val| (k.clone(), val)))
                 .collect();
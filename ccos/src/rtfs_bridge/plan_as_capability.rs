@@ -16,7 +16,7 @@ use super::language_utils::{
 use crate::capability_marketplace::CapabilityManifest;
 use crate::types::Plan;
 use rtfs::ast::{Expression, Literal, MapKey};
-use rtfs::runtime::values::Value;
+use rtfs::runtime::values::{Value, ValueMap};
 use std::collections::HashMap;
 
 /// Adapter configuration for plan-as-capability conversion
@@ -207,16 +207,16 @@ pub fn plan_to_capability_map(
     // The body is a vector of expressions, with the plan body wrapped in a let binding
     let implementation = Expression::Fn(rtfs::ast::FnExpr {
         params: vec![rtfs::ast::ParamDef {
-            pattern: rtfs::ast::Pattern::Symbol(rtfs::ast::Symbol("input".to_string())),
+            pattern: rtfs::ast::Pattern::Symbol(rtfs::ast::Symbol("input".into())),
             type_annotation: None,
         }],
         variadic_param: None,
         return_type: None,
         body: vec![Expression::Let(rtfs::ast::LetExpr {
             bindings: vec![rtfs::ast::LetBinding {
-                pattern: rtfs::ast::Pattern::Symbol(rtfs::ast::Symbol("input".to_string())),
+                pattern: rtfs::ast::Pattern::Symbol(rtfs::ast::Symbol("input".into())),
                 type_annotation: None,
-                value: Box::new(Expression::Symbol(rtfs::ast::Symbol("input".to_string()))),
+                value: Box::new(Expression::Symbol(rtfs::ast::Symbol("input".into()))),
             }],
             body: vec![body_expr.clone()],
         })],
@@ -322,7 +322,7 @@ pub fn plan_to_capability_map(
 
     // Validate that local capabilities have language (should already be set above)
     // Convert to Value::Map for validation
-    let mut cap_map_value = HashMap::new();
+    let mut cap_map_value = ValueMap::new();
     for (k, v) in &cap_map {
         // For validation, we need to convert Expression to Value
         // This is a simplified check - full validation happens elsewhere
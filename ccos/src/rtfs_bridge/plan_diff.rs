@@ -0,0 +1,193 @@
+//! Structural diff between two plans
+//!
+//! Plan bodies are parsed into their top-level forms (the children of a top-level
+//! `(do ...)` block), each rendered canonically so that whitespace and formatting
+//! differences do not count as changes. The two form sequences are aligned with a
+//! longest-common-subsequence pass; a removed form directly replaced by an added
+//! one is reported as a change.
+
+use super::effects_propagation::extract_capability_ids_from_rtfs_string;
+use super::pretty_printer::expression_to_rtfs_string;
+use crate::types::{Plan, PlanBody};
+use rtfs::ast::{Expression, Symbol, TopLevel};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+
+/// One top-level form that differs between two plans. Indices are positions in
+/// the flattened form list of the old and new plan respectively.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FormChange {
+    Added {
+        new_index: usize,
+        form: String,
+    },
+    Removed {
+        old_index: usize,
+        form: String,
+    },
+    Changed {
+        old_index: usize,
+        new_index: usize,
+        old_form: String,
+        new_form: String,
+    },
+}
+
+/// Differences between two plan bodies
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlanDiff {
+    /// Form-level changes in body order
+    pub changes: Vec<FormChange>,
+    /// Capabilities called by the new plan but not the old one, sorted
+    pub added_capabilities: Vec<String>,
+    /// Capabilities called by the old plan but not the new one, sorted
+    pub removed_capabilities: Vec<String>,
+}
+
+impl PlanDiff {
+    /// True when both plans have the same forms
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+/// Compute the structural diff from `old` to `new`
+pub fn diff_plans(old: &Plan, new: &Plan) -> PlanDiff {
+    let old_forms = plan_forms(&old.body);
+    let new_forms = plan_forms(&new.body);
+    let old_caps = plan_capabilities(&old.body);
+    let new_caps = plan_capabilities(&new.body);
+
+    PlanDiff {
+        changes: align_forms(&old_forms, &new_forms),
+        added_capabilities: new_caps.difference(&old_caps).cloned().collect(),
+        removed_capabilities: old_caps.difference(&new_caps).cloned().collect(),
+    }
+}
+
+/// Canonical renderings of the top-level forms of a plan body
+fn plan_forms(body: &PlanBody) -> Vec<String> {
+    let source = match body {
+        PlanBody::Rtfs(code) | PlanBody::Source(code) => code,
+        PlanBody::Binary(bytes) | PlanBody::Wasm(bytes) => {
+            return vec![format!(
+                "<binary {} bytes {:x}>",
+                bytes.len(),
+                fingerprint(bytes)
+            )]
+        }
+    };
+    let Ok(items) = rtfs::parser::parse(source) else {
+        // Unparseable bodies are compared line by line
+        return source
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(str::to_string)
+            .collect();
+    };
+
+    let exprs: Vec<Expression> = items
+        .into_iter()
+        .filter_map(|item| match item {
+            TopLevel::Expression(expr) => Some(expr),
+            _ => None,
+        })
+        .collect();
+    let exprs = match exprs.as_slice() {
+        [Expression::Do(do_expr)] => do_expr.expressions.clone(),
+        [Expression::List(items)] if matches!(items.first(), Some(Expression::Symbol(Symbol(s))) if s == "do") => {
+            items[1..].to_vec()
+        }
+        _ => exprs,
+    };
+    exprs.iter().map(expression_to_rtfs_string).collect()
+}
+
+fn plan_capabilities(body: &PlanBody) -> BTreeSet<String> {
+    match body {
+        PlanBody::Rtfs(code) | PlanBody::Source(code) => {
+            extract_capability_ids_from_rtfs_string(code)
+                .unwrap_or_default()
+                .into_iter()
+                .collect()
+        }
+        PlanBody::Binary(_) | PlanBody::Wasm(_) => BTreeSet::new(),
+    }
+}
+
+/// Cheap content fingerprint so differing binary bodies render differently
+fn fingerprint(bytes: &[u8]) -> u64 {
+    use std::hash::{Hash, Hasher};
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    bytes.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Align two form sequences with an LCS table and report the differences
+fn align_forms(old: &[String], new: &[String]) -> Vec<FormChange> {
+    let (n, m) = (old.len(), new.len());
+    // lcs[i][j] = length of the LCS of old[i..] and new[j..]
+    let mut lcs = vec![vec![0usize; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut changes = Vec::new();
+    let (mut removed, mut added) = (Vec::new(), Vec::new());
+    let (mut i, mut j) = (0, 0);
+    while i < n || j < m {
+        if i < n && j < m && old[i] == new[j] {
+            flush_hunk(&mut changes, &mut removed, &mut added, old, new);
+            i += 1;
+            j += 1;
+        } else if j < m && (i == n || lcs[i][j + 1] >= lcs[i + 1][j]) {
+            added.push(j);
+            j += 1;
+        } else {
+            removed.push(i);
+            i += 1;
+        }
+    }
+    flush_hunk(&mut changes, &mut removed, &mut added, old, new);
+    changes
+}
+
+/// Emit a run of removals/additions, pairing them up as changes where possible
+fn flush_hunk(
+    changes: &mut Vec<FormChange>,
+    removed: &mut Vec<usize>,
+    added: &mut Vec<usize>,
+    old: &[String],
+    new: &[String],
+) {
+    let paired = removed.len().min(added.len());
+    for (&old_index, &new_index) in removed.iter().zip(added.iter()) {
+        changes.push(FormChange::Changed {
+            old_index,
+            new_index,
+            old_form: old[old_index].clone(),
+            new_form: new[new_index].clone(),
+        });
+    }
+    for &old_index in &removed[paired..] {
+        changes.push(FormChange::Removed {
+            old_index,
+            form: old[old_index].clone(),
+        });
+    }
+    for &new_index in &added[paired..] {
+        changes.push(FormChange::Added {
+            new_index,
+            form: new[new_index].clone(),
+        });
+    }
+    removed.clear();
+    added.clear();
+}
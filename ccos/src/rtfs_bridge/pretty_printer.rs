@@ -38,7 +38,7 @@ pub fn expression_to_inline_string(expr: &Expression) -> String {
 /// Convert a binding pattern into RTFS syntax.
 pub(crate) fn pattern_to_rtfs_string(pat: &Pattern) -> String {
     match pat {
-        Pattern::Symbol(s) => s.0.clone(),
+        Pattern::Symbol(s) => s.0.to_string(),
         Pattern::Wildcard => "_".to_string(),
         Pattern::VectorDestructuring {
             elements,
@@ -171,13 +171,13 @@ impl RtfsPrettyPrinter {
                 Literal::Boolean(b) => b.to_string(),
                 Literal::Nil => "nil".to_string(),
                 Literal::Keyword(k) => format!(":{}", k.0),
-                Literal::Symbol(s) => s.0.clone(),
+                Literal::Symbol(s) => s.0.to_string(),
                 _ => self.format_debug(expr),
             },
-            Expression::Symbol(s) => s.0.clone(),
-            Expression::FunctionCall { callee, arguments } => {
-                self.format_function_call(callee, arguments, depth)
-            }
+            Expression::Symbol(s) => s.0.to_string(),
+            Expression::FunctionCall {
+                callee, arguments, ..
+            } => self.format_function_call(callee, arguments, depth),
             Expression::Do(do_expr) => self.format_block("do", &do_expr.expressions, depth),
             Expression::Vector(vec) => self.format_sequence("[", "]", vec, depth),
             Expression::List(list) => self.format_sequence("(", ")", list, depth),
@@ -287,15 +287,15 @@ mod tests {
     fn test_validate_type_expr_schema_with_keywords() {
         let mut schema = HashMap::new();
         schema.insert(
-            MapKey::Keyword(Keyword("input".to_string())),
-            Value::Keyword(Keyword("string".to_string())),
+            MapKey::Keyword(Keyword("input".into())),
+            Value::Keyword(Keyword("string".into())),
         );
         schema.insert(
-            MapKey::Keyword(Keyword("count".to_string())),
-            Value::Keyword(Keyword("int".to_string())),
+            MapKey::Keyword(Keyword("count".into())),
+            Value::Keyword(Keyword("int".into())),
         );
 
-        let schema_value = Value::Map(schema);
+        let schema_value = Value::Map(schema.into());
         assert!(validate_type_expr_schema(&schema_value, "input-schema").is_ok());
     }
 
@@ -303,11 +303,11 @@ mod tests {
     fn test_validate_type_expr_schema_with_strings() {
         let mut schema = HashMap::new();
         schema.insert(
-            MapKey::Keyword(Keyword("input".to_string())),
+            MapKey::Keyword(Keyword("input".into())),
             Value::String(":string".to_string()),
         );
 
-        let schema_value = Value::Map(schema);
+        let schema_value = Value::Map(schema.into());
         assert!(validate_type_expr_schema(&schema_value, "input-schema").is_ok());
     }
 
@@ -331,10 +331,10 @@ mod tests {
         // Add valid input schema
         let mut input_schema = HashMap::new();
         input_schema.insert(
-            MapKey::Keyword(Keyword("input".to_string())),
-            Value::Keyword(Keyword("string".to_string())),
+            MapKey::Keyword(Keyword("input".into())),
+            Value::Keyword(Keyword("string".into())),
         );
-        plan.input_schema = Some(Value::Map(input_schema));
+        plan.input_schema = Some(Value::Map(input_schema.into()));
 
         assert!(validate_plan(&plan).is_ok());
     }
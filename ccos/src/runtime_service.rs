@@ -225,7 +225,10 @@ pub fn default_controlled_context() -> RuntimeContext {
         allowed_capabilities: [
             "ccos.echo".to_string(),
             "ccos.math.add".to_string(), // offline
-                                         // Avoid online/LLM capabilities by default in demos
+            "ccos.math.subtract".to_string(),
+            "ccos.math.multiply".to_string(),
+            "ccos.math.divide".to_string(),
+            // Avoid online/LLM capabilities by default in demos
         ]
         .into_iter()
         .collect::<HashSet<_>>(),
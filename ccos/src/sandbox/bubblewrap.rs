@@ -756,15 +756,15 @@ impl SandboxRuntime for BubblewrapSandbox {
             if let Value::Map(m) = val {
                 if let Some(files_value) = m
                     .get(&rtfs::ast::MapKey::Keyword(rtfs::ast::Keyword(
-                        "input_files".to_string(),
+                        "input_files".into(),
                     )))
                     .or_else(|| m.get(&rtfs::ast::MapKey::String("input_files".to_string())))
                 {
                     if let Value::Map(files_map) = files_value {
                         for (key, path_val) in files_map {
                             let name = match key {
-                                rtfs::ast::MapKey::String(s)
-                                | rtfs::ast::MapKey::Keyword(rtfs::ast::Keyword(s)) => s.clone(),
+                                rtfs::ast::MapKey::String(s) => s.clone(),
+                                rtfs::ast::MapKey::Keyword(rtfs::ast::Keyword(s)) => s.to_string(),
                                 rtfs::ast::MapKey::Integer(i) => i.to_string(),
                             };
                             if let Some(path) = path_val.as_string() {
@@ -780,7 +780,7 @@ impl SandboxRuntime for BubblewrapSandbox {
                 // If the capability manifest provides 'dependencies' metadata or parameter:
                 if let Some(deps_value) = m
                     .get(&rtfs::ast::MapKey::Keyword(rtfs::ast::Keyword(
-                        "dependencies".to_string(),
+                        "dependencies".into(),
                     )))
                     .or_else(|| m.get(&rtfs::ast::MapKey::String("dependencies".to_string())))
                 {
@@ -3,12 +3,25 @@
 //! Predefined security policies for common use cases with CCOS-specific capability IDs.
 //! These policies use RTFS RuntimeContext but contain CCOS-specific capability ID mappings.
 
+use crate::capability_marketplace::types::CapabilityManifest;
+use rtfs::ast::MapKey;
 use rtfs::runtime::security::RuntimeContext;
+use rtfs::runtime::values::Value;
+use std::collections::HashSet;
+
+/// Placeholder recorded in place of sensitive capability arguments
+pub const REDACTED: &str = "***";
+
+/// Manifest metadata key listing sensitive argument positions and/or keys
+/// (comma separated, e.g. `"0,api_key,token"`)
+pub const SENSITIVE_ARGS_METADATA_KEY: &str = "security.sensitive_args";
 
 /// Predefined security policies for common use cases
 ///
 /// These policies provide presets for common execution scenarios in CCOS.
 /// They configure RuntimeContext with appropriate capability allowlists for CCOS capabilities.
+/// Presets list explicit capability ids; a preset can be narrowed with
+/// `RuntimeContext::with_capability_denies`, where the most specific matching rule wins.
 pub struct SecurityPolicies;
 
 impl SecurityPolicies {
@@ -115,3 +128,86 @@ impl SecurityPolicies {
         ctx
     }
 }
+
+/// Redaction policy for capability arguments declared sensitive by a manifest
+///
+/// Only the copies written to the Causal Chain and logs are redacted; the
+/// capability itself always receives the original values.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ArgRedactionPolicy {
+    /// Positional argument indices to redact
+    pub positions: HashSet<usize>,
+    /// Map keys (keyword or string, without leading `:`) to redact at any depth
+    pub keys: HashSet<String>,
+}
+
+impl ArgRedactionPolicy {
+    /// Parse a comma-separated spec: numeric entries are positions, others are keys
+    pub fn parse(spec: &str) -> Self {
+        let mut policy = Self::default();
+        for entry in spec.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+            match entry.parse::<usize>() {
+                Ok(position) => {
+                    policy.positions.insert(position);
+                }
+                Err(_) => {
+                    policy
+                        .keys
+                        .insert(entry.trim_start_matches(':').to_string());
+                }
+            }
+        }
+        policy
+    }
+
+    /// Policy declared by a capability manifest, if any
+    pub fn from_manifest(manifest: &CapabilityManifest) -> Option<Self> {
+        let policy = Self::parse(manifest.metadata.get(SENSITIVE_ARGS_METADATA_KEY)?);
+        (!policy.is_empty()).then_some(policy)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.positions.is_empty() && self.keys.is_empty()
+    }
+
+    /// Copy of `args` with sensitive positions and keyed entries replaced by [`REDACTED`]
+    pub fn redact_args(&self, args: &[Value]) -> Vec<Value> {
+        args.iter()
+            .enumerate()
+            .map(|(position, arg)| {
+                if self.positions.contains(&position) {
+                    Value::String(REDACTED.to_string())
+                } else {
+                    self.redact_value(arg)
+                }
+            })
+            .collect()
+    }
+
+    fn redact_value(&self, value: &Value) -> Value {
+        match value {
+            Value::Map(map) => Value::Map(
+                map.iter()
+                    .map(|(key, entry)| {
+                        let key_name = match key {
+                            MapKey::Keyword(k) => Some(k.0.as_str()),
+                            MapKey::String(s) => Some(s.as_str()),
+                            MapKey::Integer(_) => None,
+                        };
+                        let redacted = if key_name.is_some_and(|k| self.keys.contains(k)) {
+                            Value::String(REDACTED.to_string())
+                        } else {
+                            self.redact_value(entry)
+                        };
+                        (key.clone(), redacted)
+                    })
+                    .collect(),
+            ),
+            Value::Vector(items) => {
+                Value::Vector(items.iter().map(|v| self.redact_value(v)).collect())
+            }
+            Value::List(items) => Value::List(items.iter().map(|v| self.redact_value(v)).collect()),
+            other => other.clone(),
+        }
+    }
+}
@@ -219,7 +219,7 @@ impl SkillMapper {
             ),
         );
 
-        Ok(Value::Map(map))
+        Ok(Value::Map(map.into()))
     }
 
     /// Generate a prompt for LLM skill interpretation
@@ -433,7 +433,8 @@ impl SkillMapper {
                                             other => rtfs_value_to_json(&other)?.to_string(),
                                         };
 
-                                        let parse_args = Value::List(vec![Value::String(body)]);
+                                        let parse_args =
+                                            Value::List(vec![Value::String(body)].into());
                                         marketplace
                                             .execute_capability("ccos.json.parse", &parse_args)
                                             .await
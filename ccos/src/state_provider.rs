@@ -3,10 +3,17 @@
 //! This module provides a trait-based abstraction for state providers,
 //! enabling easy integration with different backend systems (Redis, databases, etc.)
 //! while maintaining the current mock implementations.
+//!
+//! [`snapshot`] and [`diff`] capture the intents and capability registrations
+//! of a running CCOS and compare them across time, e.g. before and after a plan.
 
+use crate::capability_marketplace::CapabilityMarketplace;
+use crate::intent_graph::IntentGraph;
+use crate::types::{IntentId, IntentStatus};
 use rtfs::runtime::values::Value;
 use rtfs::runtime::RuntimeError;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Mutex;
 
 /// Result type for state provider operations
 pub type StateResult<T> = Result<T, RuntimeError>;
@@ -181,6 +188,133 @@ impl Default for StateProviderRegistry {
     }
 }
 
+/// The parts of an intent that a snapshot tracks
+#[derive(Debug, Clone, PartialEq)]
+pub struct IntentState {
+    pub goal: String,
+    pub status: IntentStatus,
+    pub version: u64,
+    pub updated_at: u64,
+}
+
+/// The parts of a capability registration that a snapshot tracks
+#[derive(Debug, Clone, PartialEq)]
+pub struct CapabilityState {
+    pub version: String,
+    pub content_hash: Option<String>,
+}
+
+/// Point-in-time view of the intents and capabilities known to CCOS
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StateSnapshot {
+    pub intents: BTreeMap<IntentId, IntentState>,
+    pub capabilities: BTreeMap<String, CapabilityState>,
+}
+
+/// Differences between two snapshots, as produced by [`diff`]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StateDiff {
+    pub added_intents: Vec<IntentId>,
+    pub removed_intents: Vec<IntentId>,
+    pub changed_intents: Vec<IntentId>,
+    pub added_capabilities: Vec<String>,
+    pub removed_capabilities: Vec<String>,
+    pub changed_capabilities: Vec<String>,
+}
+
+impl StateDiff {
+    /// True when the two snapshots were identical
+    pub fn is_empty(&self) -> bool {
+        self.added_intents.is_empty()
+            && self.removed_intents.is_empty()
+            && self.changed_intents.is_empty()
+            && self.added_capabilities.is_empty()
+            && self.removed_capabilities.is_empty()
+            && self.changed_capabilities.is_empty()
+    }
+}
+
+/// Capture the current intents and capability registrations
+pub async fn snapshot(
+    intent_graph: &Mutex<IntentGraph>,
+    marketplace: &CapabilityMarketplace,
+) -> StateResult<StateSnapshot> {
+    // The graph lock is released before awaiting the marketplace
+    let intents = intent_graph
+        .lock()
+        .map_err(|_| RuntimeError::Generic("Failed to lock intent graph".to_string()))?
+        .get_all_intents()
+        .into_iter()
+        .map(|intent| {
+            let state = IntentState {
+                goal: intent.goal,
+                status: intent.status,
+                version: intent.version,
+                updated_at: intent.updated_at,
+            };
+            (intent.intent_id, state)
+        })
+        .collect();
+
+    let capabilities = marketplace
+        .list_capabilities()
+        .await
+        .into_iter()
+        .map(|manifest| {
+            let state = CapabilityState {
+                version: manifest.version,
+                content_hash: manifest.provenance.map(|p| p.content_hash),
+            };
+            (manifest.id, state)
+        })
+        .collect();
+
+    Ok(StateSnapshot {
+        intents,
+        capabilities,
+    })
+}
+
+/// Compare two snapshots, `before` then `after`; IDs in each list are sorted
+pub fn diff(before: &StateSnapshot, after: &StateSnapshot) -> StateDiff {
+    let (added_intents, removed_intents, changed_intents) =
+        diff_maps(&before.intents, &after.intents);
+    let (added_capabilities, removed_capabilities, changed_capabilities) =
+        diff_maps(&before.capabilities, &after.capabilities);
+    StateDiff {
+        added_intents,
+        removed_intents,
+        changed_intents,
+        added_capabilities,
+        removed_capabilities,
+        changed_capabilities,
+    }
+}
+
+type KeyChanges = (Vec<String>, Vec<String>, Vec<String>);
+
+fn diff_maps<V: PartialEq>(
+    before: &BTreeMap<String, V>,
+    after: &BTreeMap<String, V>,
+) -> KeyChanges {
+    let added = after
+        .keys()
+        .filter(|k| !before.contains_key(*k))
+        .cloned()
+        .collect();
+    let removed = before
+        .keys()
+        .filter(|k| !after.contains_key(*k))
+        .cloned()
+        .collect();
+    let changed = after
+        .iter()
+        .filter(|(k, v)| before.get(*k).is_some_and(|old| old != *v))
+        .map(|(k, _)| k.clone())
+        .collect();
+    (added, removed, changed)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,4 +1,5 @@
 pub mod file_archive;
 pub mod sqlite_archive;
+pub mod sqlite_intent_storage;
 
 // Future backends: s3, etc.
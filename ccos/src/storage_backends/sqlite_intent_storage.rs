@@ -0,0 +1,422 @@
+//! SQLite-backed Intent Graph storage
+//!
+//! Stores each intent as a JSON payload next to indexed columns (id, status,
+//! priority, timestamps) so lookups and `IntentFilter` queries run in SQL rather
+//! than scanning every record. The same database file can also host a
+//! [`SqliteArchive`](super::sqlite_archive::SqliteArchive) for plans and the
+//! causal chain (`CausalChain::load_from_db`, whose
+//! `query_persisted_actions` filters in SQL), since they use disjoint tables.
+
+use crate::intent_graph::Edge;
+use crate::intent_storage::{
+    next_version, InMemoryStorage, IntentFilter, IntentStorage, StorageError,
+};
+use crate::types::{IntentId, IntentStatus, StorableIntent};
+use rusqlite::types::Value as SqlValue;
+use rusqlite::{params, params_from_iter, Connection, OptionalExtension};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+const SCHEMA: &str = "BEGIN;
+CREATE TABLE IF NOT EXISTS intents(
+    intent_id TEXT PRIMARY KEY,
+    status TEXT NOT NULL,
+    name TEXT,
+    goal TEXT NOT NULL,
+    priority INTEGER NOT NULL,
+    created_at INTEGER NOT NULL,
+    updated_at INTEGER NOT NULL,
+    payload TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS idx_intents_status ON intents(status);
+CREATE INDEX IF NOT EXISTS idx_intents_created_at ON intents(created_at);
+CREATE INDEX IF NOT EXISTS idx_intents_updated_at ON intents(updated_at);
+CREATE TABLE IF NOT EXISTS intent_edges(
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    from_id TEXT NOT NULL,
+    to_id TEXT NOT NULL,
+    payload TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS idx_intent_edges_from ON intent_edges(from_id);
+CREATE INDEX IF NOT EXISTS idx_intent_edges_to ON intent_edges(to_id);
+COMMIT;";
+
+/// Intent Graph storage persisted in a single SQLite database
+#[derive(Debug)]
+pub struct SqliteIntentStorage {
+    conn: Arc<Mutex<Connection>>,
+    #[allow(dead_code)]
+    db_path: PathBuf,
+}
+
+impl SqliteIntentStorage {
+    pub fn new<P: Into<PathBuf>>(path: P) -> Result<Self, StorageError> {
+        let db_path = path.into();
+        if let Some(parent) = db_path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let conn = Connection::open(&db_path).map_err(sql_err)?;
+        conn.execute_batch(SCHEMA).map_err(sql_err)?;
+        Ok(Self {
+            conn: Arc::new(Mutex::new(conn)),
+            db_path,
+        })
+    }
+
+    /// Intents updated at or after `since` (seconds), oldest first
+    pub fn intents_updated_since(&self, since: u64) -> Result<Vec<StorableIntent>, StorageError> {
+        let conn = self.lock()?;
+        let mut stmt = conn
+            .prepare("SELECT payload FROM intents WHERE updated_at >= ?1 ORDER BY updated_at")
+            .map_err(sql_err)?;
+        let rows = stmt
+            .query_map(params![since as i64], |row| row.get::<_, String>(0))
+            .map_err(sql_err)?;
+        rows.map(|row| decode_intent(&row.map_err(sql_err)?))
+            .collect()
+    }
+
+    fn lock(&self) -> Result<std::sync::MutexGuard<'_, Connection>, StorageError> {
+        self.conn
+            .lock()
+            .map_err(|_| StorageError::Storage("connection lock poisoned".to_string()))
+    }
+
+    fn upsert_intent(conn: &Connection, intent: &StorableIntent) -> Result<(), StorageError> {
+        let payload = serde_json::to_string(intent)
+            .map_err(|e| StorageError::Serialization(e.to_string()))?;
+        conn.execute(
+            "INSERT OR REPLACE INTO intents(intent_id, status, name, goal, priority, created_at, updated_at, payload)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+            params![
+                intent.intent_id,
+                status_key(&intent.status),
+                intent.name,
+                intent.goal,
+                intent.priority as i64,
+                intent.created_at as i64,
+                intent.updated_at as i64,
+                payload
+            ],
+        )
+        .map_err(sql_err)?;
+        Ok(())
+    }
+
+    fn query_edges(
+        &self,
+        sql: &str,
+        args: &[&dyn rusqlite::ToSql],
+    ) -> Result<Vec<(i64, Edge)>, StorageError> {
+        let conn = self.lock()?;
+        let mut stmt = conn.prepare(sql).map_err(sql_err)?;
+        let rows = stmt
+            .query_map(args, |row| {
+                Ok((row.get::<_, i64>(0)?, row.get::<_, String>(1)?))
+            })
+            .map_err(sql_err)?;
+        rows.map(|row| {
+            let (id, payload) = row.map_err(sql_err)?;
+            let edge = serde_json::from_str(&payload)
+                .map_err(|e| StorageError::Deserialization(e.to_string()))?;
+            Ok((id, edge))
+        })
+        .collect()
+    }
+}
+
+fn sql_err(e: rusqlite::Error) -> StorageError {
+    StorageError::Storage(format!("SQLite error: {}", e))
+}
+
+fn status_key(status: &IntentStatus) -> String {
+    format!("{:?}", status)
+}
+
+fn decode_intent(payload: &str) -> Result<StorableIntent, StorageError> {
+    serde_json::from_str(payload).map_err(|e| StorageError::Deserialization(e.to_string()))
+}
+
+#[async_trait::async_trait]
+impl IntentStorage for SqliteIntentStorage {
+    async fn store_intent(&mut self, intent: StorableIntent) -> Result<IntentId, StorageError> {
+        let conn = self.lock()?;
+        Self::upsert_intent(&conn, &intent)?;
+        Ok(intent.intent_id)
+    }
+
+    async fn get_intent(&self, id: &IntentId) -> Result<Option<StorableIntent>, StorageError> {
+        let conn = self.lock()?;
+        let payload: Option<String> = conn
+            .query_row(
+                "SELECT payload FROM intents WHERE intent_id = ?1",
+                params![id],
+                |row| row.get(0),
+            )
+            .optional()
+            .map_err(sql_err)?;
+        payload.as_deref().map(decode_intent).transpose()
+    }
+
+    async fn update_intent(
+        &mut self,
+        mut intent: StorableIntent,
+        expected_version: Option<u64>,
+    ) -> Result<(), StorageError> {
+        let conn = self.lock()?;
+        let payload: Option<String> = conn
+            .query_row(
+                "SELECT payload FROM intents WHERE intent_id = ?1",
+                params![intent.intent_id],
+                |row| row.get(0),
+            )
+            .optional()
+            .map_err(sql_err)?;
+        let Some(payload) = payload else {
+            return Err(StorageError::NotFound(intent.intent_id.clone()));
+        };
+        intent.version = next_version(&decode_intent(&payload)?, expected_version)?;
+        Self::upsert_intent(&conn, &intent)
+    }
+
+    async fn delete_intent(&mut self, id: &IntentId) -> Result<(), StorageError> {
+        let conn = self.lock()?;
+        let deleted = conn
+            .execute("DELETE FROM intents WHERE intent_id = ?1", params![id])
+            .map_err(sql_err)?;
+        if deleted == 0 {
+            return Err(StorageError::NotFound(id.clone()));
+        }
+        Ok(())
+    }
+
+    async fn list_intents(
+        &self,
+        filter: IntentFilter,
+    ) -> Result<Vec<StorableIntent>, StorageError> {
+        // Substring filters use instr() to stay case-sensitive like the other backends
+        let mut sql = "SELECT payload FROM intents WHERE 1 = 1".to_string();
+        let mut args: Vec<SqlValue> = Vec::new();
+        if let Some(status) = &filter.status {
+            sql.push_str(" AND status = ?");
+            args.push(SqlValue::Text(status_key(status)));
+        }
+        if let Some(name) = filter.name_contains {
+            sql.push_str(" AND instr(name, ?) > 0");
+            args.push(SqlValue::Text(name));
+        }
+        if let Some(goal) = filter.goal_contains {
+            sql.push_str(" AND instr(goal, ?) > 0");
+            args.push(SqlValue::Text(goal));
+        }
+        if let Some(min) = filter.priority_min {
+            sql.push_str(" AND priority >= ?");
+            args.push(SqlValue::Integer(min as i64));
+        }
+        if let Some(max) = filter.priority_max {
+            sql.push_str(" AND priority <= ?");
+            args.push(SqlValue::Integer(max as i64));
+        }
+        sql.push_str(" ORDER BY created_at, intent_id");
+
+        let conn = self.lock()?;
+        let mut stmt = conn.prepare(&sql).map_err(sql_err)?;
+        let rows = stmt
+            .query_map(params_from_iter(args), |row| row.get::<_, String>(0))
+            .map_err(sql_err)?;
+        rows.map(|row| decode_intent(&row.map_err(sql_err)?))
+            .collect()
+    }
+
+    async fn store_edge(&mut self, edge: &Edge) -> Result<(), StorageError> {
+        let payload =
+            serde_json::to_string(edge).map_err(|e| StorageError::Serialization(e.to_string()))?;
+        let conn = self.lock()?;
+        conn.execute(
+            "INSERT INTO intent_edges(from_id, to_id, payload) VALUES (?1, ?2, ?3)",
+            params![edge.from, edge.to, payload],
+        )
+        .map_err(sql_err)?;
+        Ok(())
+    }
+
+    async fn get_edges(&self) -> Result<Vec<Edge>, StorageError> {
+        let edges = self.query_edges("SELECT id, payload FROM intent_edges ORDER BY id", &[])?;
+        Ok(edges.into_iter().map(|(_, edge)| edge).collect())
+    }
+
+    async fn get_edges_for_intent(&self, intent_id: &IntentId) -> Result<Vec<Edge>, StorageError> {
+        let edges = self.query_edges(
+            "SELECT id, payload FROM intent_edges WHERE from_id = ?1 OR to_id = ?1 ORDER BY id",
+            &[intent_id],
+        )?;
+        Ok(edges.into_iter().map(|(_, edge)| edge).collect())
+    }
+
+    async fn delete_edge(&mut self, edge: &Edge) -> Result<(), StorageError> {
+        let candidates = self.query_edges(
+            "SELECT id, payload FROM intent_edges WHERE from_id = ?1 AND to_id = ?2 ORDER BY id",
+            &[&edge.from, &edge.to],
+        )?;
+        let row_id = candidates
+            .into_iter()
+            .find(|(_, stored)| stored == edge)
+            .map(|(id, _)| id)
+            .ok_or_else(|| StorageError::Storage("Edge not found".to_string()))?;
+        let conn = self.lock()?;
+        conn.execute("DELETE FROM intent_edges WHERE id = ?1", params![row_id])
+            .map_err(sql_err)?;
+        Ok(())
+    }
+
+    async fn backup(&self, path: &Path) -> Result<(), StorageError> {
+        // Reuse the shared JSON+RTFS backup format
+        let mut snapshot = InMemoryStorage::new();
+        for intent in self.list_intents(IntentFilter::default()).await? {
+            snapshot.store_intent(intent).await?;
+        }
+        for edge in self.get_edges().await? {
+            snapshot.store_edge(&edge).await?;
+        }
+        snapshot.backup(path).await
+    }
+
+    async fn restore(&mut self, path: &Path) -> Result<(), StorageError> {
+        let mut snapshot = InMemoryStorage::new();
+        snapshot.restore(path).await?;
+        let intents = snapshot.list_intents(IntentFilter::default()).await?;
+        let edges = snapshot.get_edges().await?;
+
+        self.clear_all().await?;
+        for intent in intents {
+            self.store_intent(intent).await?;
+        }
+        for edge in &edges {
+            self.store_edge(edge).await?;
+        }
+        Ok(())
+    }
+
+    async fn health_check(&self) -> Result<(), StorageError> {
+        let conn = self.lock()?;
+        conn.query_row("SELECT 1", [], |row| row.get::<_, i64>(0))
+            .map_err(sql_err)?;
+        Ok(())
+    }
+
+    async fn clear_all(&mut self) -> Result<(), StorageError> {
+        let conn = self.lock()?;
+        conn.execute_batch("DELETE FROM intents; DELETE FROM intent_edges;")
+            .map_err(sql_err)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::EdgeType;
+    use tempfile::NamedTempFile;
+
+    fn intent(goal: &str, priority: u32, updated_at: u64) -> StorableIntent {
+        let mut intent = StorableIntent::new(goal.to_string());
+        intent.priority = priority;
+        intent.updated_at = updated_at;
+        intent
+    }
+
+    #[tokio::test]
+    async fn test_sqlite_intent_round_trip_and_query_by_id() {
+        let tmp = NamedTempFile::new().unwrap();
+        let mut storage = SqliteIntentStorage::new(tmp.path()).expect("sqlite storage");
+
+        let mut first = intent("Analyze sales data", 1, 100);
+        first.name = Some("sales".to_string());
+        first
+            .metadata
+            .insert("owner".to_string(), "ops".to_string());
+        let second = intent("Write quarterly report", 5, 200);
+        let first_id = storage.store_intent(first.clone()).await.unwrap();
+        storage.store_intent(second.clone()).await.unwrap();
+
+        let loaded = storage.get_intent(&first_id).await.unwrap().unwrap();
+        assert_eq!(loaded.goal, first.goal);
+        assert_eq!(loaded.name, first.name);
+        assert_eq!(loaded.metadata, first.metadata);
+        assert!(storage
+            .get_intent(&"missing".to_string())
+            .await
+            .unwrap()
+            .is_none());
+
+        // Data survives reopening the database
+        drop(storage);
+        let mut storage = SqliteIntentStorage::new(tmp.path()).expect("reopen");
+        assert!(storage
+            .get_intent(&second.intent_id)
+            .await
+            .unwrap()
+            .is_some());
+
+        let mut updated = second.clone();
+        updated.status = IntentStatus::Completed;
+        storage.update_intent(updated, Some(0)).await.unwrap();
+        let completed = storage
+            .list_intents(IntentFilter {
+                status: Some(IntentStatus::Completed),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(completed.len(), 1);
+        assert_eq!(completed[0].intent_id, second.intent_id);
+        assert_eq!(completed[0].version, 1);
+        assert!(matches!(
+            storage.update_intent(second.clone(), Some(0)).await,
+            Err(StorageError::Conflict { actual: 1, .. })
+        ));
+
+        let by_goal = storage
+            .list_intents(IntentFilter {
+                goal_contains: Some("sales".to_string()),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(by_goal.len(), 1);
+        assert_eq!(by_goal[0].intent_id, first_id);
+
+        let recent = storage.intents_updated_since(150).unwrap();
+        assert_eq!(recent.len(), 1);
+
+        storage.delete_intent(&first_id).await.unwrap();
+        assert!(matches!(
+            storage.delete_intent(&first_id).await,
+            Err(StorageError::NotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_sqlite_edges() {
+        let tmp = NamedTempFile::new().unwrap();
+        let mut storage = SqliteIntentStorage::new(tmp.path()).expect("sqlite storage");
+
+        let edge = Edge::new("a".to_string(), "b".to_string(), EdgeType::DependsOn);
+        let other = Edge::new("b".to_string(), "c".to_string(), EdgeType::IsSubgoalOf);
+        storage.store_edge(&edge).await.unwrap();
+        storage.store_edge(&other).await.unwrap();
+
+        assert_eq!(storage.get_edges().await.unwrap().len(), 2);
+        assert_eq!(
+            storage
+                .get_edges_for_intent(&"b".to_string())
+                .await
+                .unwrap()
+                .len(),
+            2
+        );
+        storage.delete_edge(&edge).await.unwrap();
+        assert_eq!(storage.get_edges().await.unwrap(), vec![other]);
+        assert!(storage.delete_edge(&edge).await.is_err());
+    }
+}
@@ -1,7 +1,7 @@
 use crate::streaming::{McpStreamingProvider, StreamTransport, StreamTransportArgs};
 use async_trait::async_trait;
 use rtfs::runtime::error::{RuntimeError, RuntimeResult};
-use rtfs::runtime::values::Value;
+use rtfs::runtime::values::{Value, ValueMap};
 use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
 use std::time::Duration;
@@ -153,7 +153,7 @@ pub async fn run_mock_stream_loop(
 ) -> RuntimeResult<()> {
     for i in 0..total {
         let chunk = Value::Map({
-            let mut m = std::collections::HashMap::new();
+            let mut m = ValueMap::new();
             m.insert(
                 rtfs::ast::MapKey::Keyword(rtfs::ast::Keyword("seq".into())),
                 Value::Integer(i as i64),
@@ -164,7 +164,7 @@ pub async fn run_mock_stream_loop(
             );
             m
         });
-        let meta = Value::Map(std::collections::HashMap::new());
+        let meta = Value::Map(std::collections::HashMap::new().into());
         provider.process_chunk(&stream_id, chunk, meta).await?;
         sleep(Duration::from_millis(25)).await;
     }
@@ -56,7 +56,7 @@ pub async fn register_mcp_streaming_capability(
 
                 if let Value::Map(map) = params {
                     let lookup = |key: &str| -> Option<&Value> {
-                        let keyword_key = MapKey::Keyword(Keyword(key.to_string()));
+                        let keyword_key = MapKey::Keyword(Keyword(key.into()));
                         map.get(&keyword_key)
                             .or_else(|| map.get(&MapKey::String(key.to_string())))
                     };
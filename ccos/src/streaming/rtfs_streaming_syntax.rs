@@ -75,7 +75,7 @@ pub fn maybe_lower_mcp_stream_macro(expr: &Expression) -> Expression {
     // Internal helper to extract symbol name
     fn symbol_name(e: &Expression) -> Option<String> {
         if let Expression::Symbol(Symbol(s)) = e {
-            Some(s.clone())
+            Some(s.to_string())
         } else {
             None
         }
@@ -95,12 +95,12 @@ pub fn maybe_lower_mcp_stream_macro(expr: &Expression) -> Expression {
                     // Endpoint literal (symbol or string literal currently represented as Literal::String)
                     let endpoint = match &items[1] {
                         Expression::Literal(Literal::String(s)) => s.clone(),
-                        Expression::Symbol(Symbol(s)) => s.clone(),
+                        Expression::Symbol(Symbol(s)) => s.to_string(),
                         _ => return expr.clone(),
                     };
                     let processor = match &items[2] {
                         Expression::Symbol(Symbol(s)) => s.clone(),
-                        Expression::Literal(Literal::String(s)) => s.clone(),
+                        Expression::Literal(Literal::String(s)) => s.as_str().into(),
                         _ => return expr.clone(),
                     };
                     let initial_state = if items.len() > 3 {
@@ -112,24 +112,23 @@ pub fn maybe_lower_mcp_stream_macro(expr: &Expression) -> Expression {
                     // Build map with keyword keys (without leading ':') because MapKey::Keyword wraps raw value
                     let mut m = std::collections::HashMap::new();
                     m.insert(
-                        MapKey::Keyword(Keyword("endpoint".to_string())),
+                        MapKey::Keyword(Keyword("endpoint".into())),
                         Expression::Literal(Literal::String(endpoint)),
                     );
                     m.insert(
-                        MapKey::Keyword(Keyword("processor".to_string())),
-                        Expression::Literal(Literal::String(processor)),
+                        MapKey::Keyword(Keyword("processor".into())),
+                        Expression::Literal(Literal::String(processor.to_string())),
                     );
                     m.insert(
-                        MapKey::Keyword(Keyword("initial-state".to_string())),
+                        MapKey::Keyword(Keyword("initial-state".into())),
                         initial_state,
                     );
                     let map_expr = Expression::Map(m);
 
                     // Form: (call :mcp.stream.start { ... })
-                    let call_sym = Expression::Symbol(Symbol("call".to_string()));
-                    let capability_kw = Expression::Literal(Literal::Keyword(Keyword(
-                        "mcp.stream.start".to_string(),
-                    )));
+                    let call_sym = Expression::Symbol(Symbol("call".into()));
+                    let capability_kw =
+                        Expression::Literal(Literal::Keyword(Keyword("mcp.stream.start".into())));
                     return Expression::List(vec![call_sym, capability_kw, map_expr]);
                 }
             }
@@ -12,11 +12,11 @@ use rtfs::ast::{Keyword, MapKey, TypeExpr};
 use rtfs::runtime::{
     error::{RuntimeError, RuntimeResult},
     type_validator::{TypeCheckingConfig, TypeValidator, VerificationContext},
-    values::Value,
+    values::{Value, ValueVector},
 };
 use serde::{Deserialize, Serialize};
 use serde_json::Value as JsonValue;
-use std::collections::{hash_map::Entry, HashMap, HashSet, VecDeque};
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::env;
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
@@ -624,7 +624,7 @@ impl McpStreamingProvider {
                 .map(Value::Integer)
                 .unwrap_or(Value::Nil),
         );
-        Value::Map(map)
+        Value::Map(map.into())
     }
 
     fn status_to_value(status: &StreamStatus) -> Value {
@@ -640,7 +640,7 @@ impl McpStreamingProvider {
         };
         map.insert(
             MapKey::Keyword(Keyword("state".into())),
-            Value::Keyword(Keyword(status_keyword.to_string())),
+            Value::Keyword(Keyword(status_keyword.into())),
         );
         if let StreamStatus::Error(err) = status {
             map.insert(
@@ -648,7 +648,7 @@ impl McpStreamingProvider {
                 Value::String(err.clone()),
             );
         }
-        Value::Map(map)
+        Value::Map(map.into())
     }
 
     fn queued_item_to_value(item: &QueuedItem) -> Value {
@@ -663,7 +663,7 @@ impl McpStreamingProvider {
             MapKey::Keyword(Keyword("waiting-ms".into())),
             Value::Integer(item.enqueued_at.elapsed().as_millis() as i64),
         );
-        Value::Map(map)
+        Value::Map(map.into())
     }
 
     pub fn inspect_stream(
@@ -756,7 +756,7 @@ impl McpStreamingProvider {
         );
         result.insert(
             MapKey::Keyword(Keyword("transport".into())),
-            Value::Map(transport_map),
+            Value::Map(transport_map.into()),
         );
 
         if options.include_state {
@@ -774,11 +774,11 @@ impl McpStreamingProvider {
         if options.include_queue {
             result.insert(
                 MapKey::Keyword(Keyword("queue".into())),
-                Value::Vector(queue_snapshot),
+                Value::Vector(queue_snapshot.into()),
             );
         }
 
-        Ok(Value::Map(result))
+        Ok(Value::Map(result.into()))
     }
 
     pub fn inspect_streams(&self, options: StreamInspectOptions) -> Value {
@@ -818,10 +818,10 @@ impl McpStreamingProvider {
         );
         map.insert(
             MapKey::Keyword(Keyword("streams".into())),
-            Value::Vector(streams),
+            Value::Vector(streams.into()),
         );
 
-        Value::Map(map)
+        Value::Map(map.into())
     }
 
     /// Process a stream chunk by resuming RTFS execution
@@ -1014,7 +1014,7 @@ impl McpStreamingProvider {
             let mut new_state = registration.current_state.clone();
             if let Value::Map(m) = &mut new_state {
                 use rtfs::ast::{Keyword, MapKey};
-                let count_key = MapKey::Keyword(Keyword("count".to_string()));
+                let count_key = MapKey::Keyword(Keyword("count".into()));
                 let current = m
                     .get(&count_key)
                     .and_then(|v| {
@@ -1027,37 +1027,31 @@ impl McpStreamingProvider {
                     .unwrap_or(0);
                 m.insert(count_key, Value::Integer(current + 1));
 
-                let last_chunk_key = MapKey::Keyword(Keyword("last-chunk".to_string()));
+                let last_chunk_key = MapKey::Keyword(Keyword("last-chunk".into()));
                 m.insert(last_chunk_key, chunk.clone());
 
-                let last_metadata_key = MapKey::Keyword(Keyword("last-metadata".to_string()));
+                let last_metadata_key = MapKey::Keyword(Keyword("last-metadata".into()));
                 m.insert(last_metadata_key, metadata.clone());
 
-                let messages_key = MapKey::Keyword(Keyword("messages".to_string()));
-                match m.entry(messages_key) {
-                    Entry::Occupied(mut entry) => {
-                        if let Value::Vector(vec) = entry.get_mut() {
-                            vec.push(chunk.clone());
-                        } else {
-                            *entry.get_mut() = Value::Vector(vec![chunk.clone()]);
-                        }
-                    }
-                    Entry::Vacant(entry) => {
-                        entry.insert(Value::Vector(vec![chunk.clone()]));
+                let messages_key = MapKey::Keyword(Keyword("messages".into()));
+                match m.get_mut(&messages_key) {
+                    Some(Value::Vector(vec)) => vec.push_back(chunk.clone()),
+                    _ => {
+                        m.insert(
+                            messages_key,
+                            Value::Vector(ValueVector::unit(chunk.clone())),
+                        );
                     }
                 }
 
-                let metadata_key = MapKey::Keyword(Keyword("metadata".to_string()));
-                match m.entry(metadata_key) {
-                    Entry::Occupied(mut entry) => {
-                        if let Value::Vector(vec) = entry.get_mut() {
-                            vec.push(metadata.clone());
-                        } else {
-                            *entry.get_mut() = Value::Vector(vec![metadata.clone()]);
-                        }
-                    }
-                    Entry::Vacant(entry) => {
-                        entry.insert(Value::Vector(vec![metadata.clone()]));
+                let metadata_key = MapKey::Keyword(Keyword("metadata".into()));
+                match m.get_mut(&metadata_key) {
+                    Some(Value::Vector(vec)) => vec.push_back(metadata.clone()),
+                    _ => {
+                        m.insert(
+                            metadata_key,
+                            Value::Vector(ValueVector::unit(metadata.clone())),
+                        );
                     }
                 }
             }
@@ -1065,7 +1059,7 @@ impl McpStreamingProvider {
 
             if let Value::Map(m) = &chunk {
                 use rtfs::ast::{Keyword, MapKey};
-                let action_key = MapKey::Keyword(Keyword("action".to_string()));
+                let action_key = MapKey::Keyword(Keyword("action".into()));
                 if let Some(Value::Keyword(Keyword(action_kw))) = m.get(&action_key) {
                     self.apply_action_directive(registration, action_kw);
                 }
@@ -1096,7 +1090,7 @@ impl McpStreamingProvider {
             return Err(RuntimeError::Generic(message));
         };
 
-        let output_key = MapKey::Keyword(Keyword("output".to_string()));
+        let output_key = MapKey::Keyword(Keyword("output".into()));
         let output_val = map.get(&output_key).ok_or_else(|| {
             let message = format!(
                 "Processor '{}' must return :output when schema is declared",
@@ -1135,9 +1129,9 @@ impl McpStreamingProvider {
         use rtfs::ast::{Keyword, MapKey};
         match result_val {
             Value::Map(m) => {
-                let state_key = MapKey::Keyword(Keyword("state".to_string()));
-                let action_key = MapKey::Keyword(Keyword("action".to_string()));
-                let output_key = MapKey::Keyword(Keyword("output".to_string()));
+                let state_key = MapKey::Keyword(Keyword("state".into()));
+                let action_key = MapKey::Keyword(Keyword("action".into()));
+                let output_key = MapKey::Keyword(Keyword("output".into()));
                 let mut recognized = false;
                 if let Some(new_state) = m.get(&state_key) {
                     registration.current_state = new_state.clone();
@@ -1188,9 +1182,9 @@ impl McpStreamingProvider {
     fn extract_action(chunk: &Value) -> Option<String> {
         if let Value::Map(m) = chunk {
             use rtfs::ast::{Keyword, MapKey};
-            let action_key = MapKey::Keyword(Keyword("action".to_string()));
+            let action_key = MapKey::Keyword(Keyword("action".into()));
             if let Some(Value::Keyword(Keyword(action_kw))) = m.get(&action_key) {
-                return Some(action_kw.clone());
+                return Some(action_kw.to_string());
             }
         }
         None
@@ -1428,7 +1422,7 @@ impl SseStreamTransport {
             Value::String(origin.to_string()),
         );
 
-        (chunk_value, Value::Map(meta_map))
+        (chunk_value, Value::Map(meta_map.into()))
     }
 
     fn extract_followup_target(message: &SseMessage) -> Option<String> {
@@ -1515,7 +1509,7 @@ impl SseStreamTransport {
             Value::String(kind.to_string()),
         );
 
-        Value::Map(meta_map)
+        Value::Map(meta_map.into())
     }
 
     fn build_followup_error_chunk(target: &str, error: &RuntimeError) -> Value {
@@ -1530,7 +1524,7 @@ impl SseStreamTransport {
             Value::String(error.to_string()),
         );
 
-        Value::Map(map)
+        Value::Map(map.into())
     }
 
     fn resolve_followup_url(base_url: &str, target: &str) -> RuntimeResult<String> {
@@ -1616,7 +1610,7 @@ impl StreamingCapability for McpStreamingProvider {
         };
         use rtfs::ast::{Keyword, MapKey};
         let lookup = |k: &str| -> Option<&Value> {
-            let kw = MapKey::Keyword(Keyword(k.to_string()));
+            let kw = MapKey::Keyword(Keyword(k.into()));
             map.get(&kw)
                 .or_else(|| map.get(&MapKey::String(k.to_string())))
         };
@@ -1642,7 +1636,7 @@ impl StreamingCapability for McpStreamingProvider {
             .unwrap_or_default();
         let initial_state = lookup("initial-state")
             .cloned()
-            .unwrap_or(Value::Map(std::collections::HashMap::new()));
+            .unwrap_or(Value::Map(std::collections::HashMap::new().into()));
         let queue_capacity = lookup("queue-capacity").and_then(|v| {
             if let Value::Integer(i) = v {
                 Some(*i as usize)
@@ -0,0 +1,358 @@
+//! Example-driven capability synthesis
+//!
+//! Derives an RTFS function from input/output example pairs using a small set of
+//! templates, tried in order:
+//! - identity: every output equals its input
+//! - field projection: every output is the value found at one fixed key path of its
+//!   input map (`get` / `get-in`)
+//! - map projection: every output is a map whose entries each come from a fixed key
+//!   path of the input (select/rename keys)
+//! - constant: every output is the same value
+//!
+//! The candidate implementation is replayed against all examples in the restricted
+//! RTFS runtime before it is returned, so a synthesized capability always reproduces
+//! the examples it was derived from.
+
+use super::synthesized_capability_storage::SynthesizedCapability;
+use crate::capability_marketplace::CapabilityMarketplace;
+use crate::synthesis::primitives::RestrictedRtfsExecutor;
+use rtfs::ast::MapKey;
+use rtfs::runtime::error::{RuntimeError, RuntimeResult};
+use rtfs::runtime::values::Value;
+use std::sync::Arc;
+
+/// Maximum depth of nested maps searched for projected fields
+const MAX_PATH_DEPTH: usize = 3;
+
+/// Synthesize a capability implementing the mapping shown by `examples`
+/// (pairs of input and expected output).
+pub fn synthesize_capability(
+    examples: Vec<(Value, Value)>,
+) -> RuntimeResult<SynthesizedCapability> {
+    if examples.is_empty() {
+        return Err(RuntimeError::Generic(
+            "Cannot synthesize a capability without examples".to_string(),
+        ));
+    }
+
+    let (template, description, implementation) = identity(&examples)
+        .or_else(|| field_projection(&examples))
+        .or_else(|| map_projection(&examples))
+        .or_else(|| constant(&examples))
+        .ok_or_else(|| {
+            RuntimeError::Generic("No synthesis template reproduces the given examples".to_string())
+        })?;
+
+    let executor = RestrictedRtfsExecutor::new();
+    for (input, expected) in &examples {
+        let actual = executor.evaluate(&implementation, input.clone())?;
+        if &actual != expected {
+            return Err(RuntimeError::Generic(format!(
+                "Synthesized {} implementation returned {} instead of {} for input {}",
+                template, actual, expected, input
+            )));
+        }
+    }
+
+    Ok(SynthesizedCapability::new(&description, &implementation)
+        .with_metadata("synthesis_template", template)
+        .with_metadata("synthesis_examples", &examples.len().to_string()))
+}
+
+/// Register a synthesized capability as a pure local capability backed by the
+/// restricted RTFS runtime. Plan calls pass their arguments as a list; a single
+/// argument is unwrapped before it reaches the synthesized function.
+pub async fn register_synthesized_capability(
+    marketplace: &CapabilityMarketplace,
+    capability: &SynthesizedCapability,
+) -> RuntimeResult<()> {
+    let implementation = capability.implementation.clone();
+    marketplace
+        .register_pure_local_capability(
+            capability.id.clone(),
+            capability.description.clone(),
+            capability.description.clone(),
+            Arc::new(move |args: &Value| {
+                let input = match args {
+                    Value::List(items) if items.len() == 1 => items[0].clone(),
+                    other => other.clone(),
+                };
+                RestrictedRtfsExecutor::new().evaluate(&implementation, input)
+            }),
+        )
+        .await
+}
+
+type Candidate = (&'static str, String, String);
+
+fn identity(examples: &[(Value, Value)]) -> Option<Candidate> {
+    examples
+        .iter()
+        .all(|(input, output)| input == output)
+        .then(|| {
+            (
+                "identity",
+                "Return the input unchanged".to_string(),
+                "(fn [input] input)".to_string(),
+            )
+        })
+}
+
+fn constant(examples: &[(Value, Value)]) -> Option<Candidate> {
+    let first = &examples[0].1;
+    examples.iter().all(|(_, output)| output == first).then(|| {
+        (
+            "constant",
+            format!("Always return {}", first),
+            format!("(fn [input] {})", first),
+        )
+    })
+}
+
+fn field_projection(examples: &[(Value, Value)]) -> Option<Candidate> {
+    let path = common_path(examples, |(_, output)| output)?;
+    Some((
+        "field-projection",
+        format!("Project {} from the input", render_path(&path)),
+        format!("(fn [input] {})", path_lookup(&path)),
+    ))
+}
+
+fn map_projection(examples: &[(Value, Value)]) -> Option<Candidate> {
+    let Value::Map(first_output) = &examples[0].1 else {
+        return None;
+    };
+    if first_output.is_empty() {
+        return None;
+    }
+
+    let mut keys: Vec<&MapKey> = first_output.keys().collect();
+    keys.sort_by_key(|k| format!("{}", k));
+    let mut entries = Vec::new();
+    for key in keys {
+        let same_shape = examples.iter().all(|(_, output)| match output {
+            Value::Map(map) => map.len() == first_output.len() && map.contains_key(key),
+            _ => false,
+        });
+        if !same_shape {
+            return None;
+        }
+        let path = common_path(examples, |(_, output)| match output {
+            Value::Map(map) => &map[key],
+            _ => unreachable!("checked above"),
+        })?;
+        entries.push((key.clone(), path));
+    }
+
+    let description = entries
+        .iter()
+        .map(|(key, path)| format!("{} <- {}", key, render_path(path)))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let body = entries
+        .iter()
+        .map(|(key, path)| format!("{} {}", key, path_lookup(path)))
+        .collect::<Vec<_>>()
+        .join(" ");
+    Some((
+        "map-projection",
+        format!("Build a map from input fields ({})", description),
+        format!("(fn [input] {{{}}})", body),
+    ))
+}
+
+/// Shortest key path whose value in each example input equals `target(example)`
+fn common_path<'a>(
+    examples: &'a [(Value, Value)],
+    target: impl Fn(&'a (Value, Value)) -> &'a Value,
+) -> Option<Vec<MapKey>> {
+    let mut candidates = Vec::new();
+    collect_paths(
+        &examples[0].0,
+        target(&examples[0]),
+        &mut Vec::new(),
+        &mut candidates,
+    );
+    candidates.sort_by_key(|path| path.len());
+    candidates.into_iter().find(|path| {
+        examples
+            .iter()
+            .all(|example| lookup(&example.0, path) == Some(target(example)))
+    })
+}
+
+fn collect_paths(
+    value: &Value,
+    target: &Value,
+    prefix: &mut Vec<MapKey>,
+    out: &mut Vec<Vec<MapKey>>,
+) {
+    let Value::Map(map) = value else {
+        return;
+    };
+    if prefix.len() >= MAX_PATH_DEPTH {
+        return;
+    }
+    for (key, child) in map {
+        prefix.push(key.clone());
+        if child == target {
+            out.push(prefix.clone());
+        }
+        collect_paths(child, target, prefix, out);
+        prefix.pop();
+    }
+}
+
+fn lookup<'a>(value: &'a Value, path: &[MapKey]) -> Option<&'a Value> {
+    path.iter().try_fold(value, |current, key| match current {
+        Value::Map(map) => map.get(key),
+        _ => None,
+    })
+}
+
+fn render_path(path: &[MapKey]) -> String {
+    path.iter()
+        .map(|key| format!("{}", key))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn path_lookup(path: &[MapKey]) -> String {
+    match path {
+        [key] => format!("(get input {})", key),
+        _ => format!("(get-in input [{}])", render_path(path)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::capabilities::registry::CapabilityRegistry;
+    use rtfs::ast::Keyword;
+    use rtfs::runtime::values::ValueMap;
+    use std::collections::HashMap;
+    use tokio::sync::RwLock;
+
+    fn kw(name: &str) -> MapKey {
+        MapKey::Keyword(Keyword(name.into()))
+    }
+
+    fn user(name: &str, age: i64, city: &str) -> Value {
+        Value::Map(ValueMap::from_iter([
+            (kw("name"), Value::String(name.to_string())),
+            (kw("age"), Value::Integer(age)),
+            (
+                kw("address"),
+                Value::Map(HashMap::from([(kw("city"), Value::String(city.to_string()))]).into()),
+            ),
+        ]))
+    }
+
+    #[test]
+    fn field_projection_reproduces_examples() {
+        let examples = vec![
+            (user("Ada", 36, "London"), Value::String("Ada".to_string())),
+            (
+                user("Alan", 41, "Wilmslow"),
+                Value::String("Alan".to_string()),
+            ),
+        ];
+        let capability = synthesize_capability(examples.clone()).unwrap();
+        assert_eq!(capability.implementation, "(fn [input] (get input :name))");
+        assert_eq!(
+            capability
+                .metadata
+                .get("synthesis_template")
+                .map(String::as_str),
+            Some("field-projection")
+        );
+
+        let executor = RestrictedRtfsExecutor::new();
+        for (input, output) in examples {
+            assert_eq!(
+                executor
+                    .evaluate(&capability.implementation, input)
+                    .unwrap(),
+                output
+            );
+        }
+
+        // Nested fields resolve through get-in
+        let nested = synthesize_capability(vec![
+            (
+                user("Ada", 36, "London"),
+                Value::String("London".to_string()),
+            ),
+            (
+                user("Alan", 41, "Wilmslow"),
+                Value::String("Wilmslow".to_string()),
+            ),
+        ])
+        .unwrap();
+        assert_eq!(
+            nested.implementation,
+            "(fn [input] (get-in input [:address :city]))"
+        );
+    }
+
+    #[test]
+    fn map_projection_identity_and_constant_templates() {
+        let renamed = |name: &str, age: i64| {
+            Value::Map(ValueMap::from_iter([
+                (kw("who"), Value::String(name.to_string())),
+                (kw("years"), Value::Integer(age)),
+            ]))
+        };
+        let capability = synthesize_capability(vec![
+            (user("Ada", 36, "London"), renamed("Ada", 36)),
+            (user("Alan", 41, "Wilmslow"), renamed("Alan", 41)),
+        ])
+        .unwrap();
+        assert_eq!(
+            capability
+                .metadata
+                .get("synthesis_template")
+                .map(String::as_str),
+            Some("map-projection")
+        );
+
+        let identity = synthesize_capability(vec![(Value::Integer(1), Value::Integer(1))]).unwrap();
+        assert_eq!(identity.implementation, "(fn [input] input)");
+
+        let constant = synthesize_capability(vec![
+            (Value::Integer(1), Value::Boolean(true)),
+            (Value::Integer(2), Value::Boolean(true)),
+        ])
+        .unwrap();
+        assert_eq!(constant.implementation, "(fn [input] true)");
+
+        assert!(synthesize_capability(vec![
+            (Value::Integer(1), Value::Integer(2)),
+            (Value::Integer(2), Value::Integer(5)),
+        ])
+        .is_err());
+    }
+
+    #[tokio::test]
+    async fn synthesized_capability_is_callable_from_the_marketplace() {
+        let capability = synthesize_capability(vec![
+            (user("Ada", 36, "London"), Value::Integer(36)),
+            (user("Alan", 41, "Wilmslow"), Value::Integer(41)),
+        ])
+        .unwrap();
+        let marketplace =
+            CapabilityMarketplace::new(Arc::new(RwLock::new(CapabilityRegistry::new())));
+        register_synthesized_capability(&marketplace, &capability)
+            .await
+            .unwrap();
+
+        let result = marketplace
+            .execute_capability(
+                &capability.id,
+                &Value::List(vec![user("Grace", 85, "NYC")].into()),
+            )
+            .await
+            .unwrap();
+        assert_eq!(result, Value::Integer(85));
+    }
+}
@@ -1052,7 +1052,7 @@ impl MissingCapabilityResolver {
             Expression::Literal(Literal::Keyword(k)) => Some(value_conversion::map_key_to_string(
                 &rtfs::ast::MapKey::Keyword(k.clone()),
             )),
-            Expression::Literal(Literal::Symbol(symbol)) => Some(symbol.0.clone()),
+            Expression::Literal(Literal::Symbol(symbol)) => Some(symbol.0.to_string()),
             Expression::Literal(Literal::Nil) => None,
             _ => None,
         }
@@ -3925,7 +3925,8 @@ fn expression_ast_to_type_expr(expr: &Expression) -> Result<TypeExpr, String> {
                     Ok(kind)
                 } else {
                     Ok(TypeExpr::Primitive(PrimitiveType::Custom(RtfsKeyword(
-                        value_conversion::map_key_to_string(&rtfs::ast::MapKey::Keyword(k.clone())),
+                        value_conversion::map_key_to_string(&rtfs::ast::MapKey::Keyword(k.clone()))
+                            .into(),
                     ))))
                 }
             }
@@ -4019,7 +4020,7 @@ fn sequence_type_expr(items: &[Expression]) -> Result<TypeExpr, String> {
                 .ok_or_else(|| "Resource type missing identifier".to_string())?;
             let name = expr_to_symbol_name(target)
                 .ok_or_else(|| "Resource identifier must be a symbol".to_string())?;
-            Ok(TypeExpr::Resource(RtfsSymbol(name)))
+            Ok(TypeExpr::Resource(RtfsSymbol(name.into())))
         }
         _ => expression_ast_to_type_expr(&items[0]),
     }
@@ -4062,7 +4063,7 @@ fn parse_map_entries(
         }
 
         result.push(MapTypeEntry {
-            key: RtfsKeyword(key_name),
+            key: RtfsKeyword(key_name.into()),
             value_type: Box::new(value_type),
             optional,
         });
@@ -4084,7 +4085,7 @@ fn expr_to_symbol_name(expr: &Expression) -> Option<String> {
         Expression::Literal(Literal::Keyword(k)) => Some(value_conversion::map_key_to_string(
             &rtfs::ast::MapKey::Keyword(k.clone()),
         )),
-        Expression::Literal(Literal::Symbol(s)) => Some(s.0.clone()),
+        Expression::Literal(Literal::Symbol(s)) => Some(s.0.to_string()),
         _ => None,
     }
 }
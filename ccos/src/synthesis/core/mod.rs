@@ -6,8 +6,10 @@
 //! - Feature flags for capability resolution
 //! - Dependency extraction from generated artifacts
 //! - Synthesized capability storage for reusable inline RTFS
+//! - Example-driven synthesis of simple capabilities
 
 pub mod dependency_extractor;
+pub mod example_synthesizer;
 pub mod feature_flags;
 pub mod missing_capability_resolver;
 pub mod missing_capability_strategies;
@@ -16,6 +18,7 @@ pub mod synth_or_enqueue;
 pub mod synthesized_capability_storage;
 
 // Re-export commonly used types
+pub use example_synthesizer::{register_synthesized_capability, synthesize_capability};
 pub use feature_flags::{
     FeatureFlagChecker, MissingCapabilityConfig, MissingCapabilityFeatureFlags,
 };
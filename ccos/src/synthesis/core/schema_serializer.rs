@@ -137,12 +137,12 @@ mod tests {
         let map_type = TypeExpr::Map {
             entries: vec![
                 MapTypeEntry {
-                    key: Keyword("userId".to_string()),
+                    key: Keyword("userId".into()),
                     value_type: Box::new(TypeExpr::Primitive(PrimitiveType::String)),
                     optional: false,
                 },
                 MapTypeEntry {
-                    key: Keyword("expand".to_string()),
+                    key: Keyword("expand".into()),
                     value_type: Box::new(TypeExpr::Primitive(PrimitiveType::Bool)),
                     optional: true,
                 },
@@ -13,7 +13,7 @@ use crate::synthesis::status::{STATUS_PROCESSING, STATUS_READY_FOR_EXECUTION};
 use crate::synthesis::InteractionTurn;
 use rtfs::ast::{
     CapabilityDefinition, DoExpr, Expression, Keyword, LetBinding, LetExpr, Literal, MapKey,
-    Property, Symbol,
+    Property, Span, Symbol,
 };
 use std::collections::HashMap;
 
@@ -38,9 +38,10 @@ pub fn generate_collector(schema: &ParamSchema, domain: &str) -> String {
                 Expression::Symbol(Symbol("ccos.user.ask".into())),
                 Expression::Literal(Literal::String(prompt_sane)),
             ],
+            span: Span::unknown(),
         };
         bindings.push(LetBinding {
-            pattern: rtfs::ast::Pattern::Symbol(Symbol(var.clone())),
+            pattern: rtfs::ast::Pattern::Symbol(Symbol::new(var.as_str())),
             type_annotation: None,
             value: Box::new(call),
         });
@@ -51,8 +52,8 @@ pub fn generate_collector(schema: &ParamSchema, domain: &str) -> String {
     for (i, (_k, meta)) in schema.params.iter().enumerate() {
         let var = format!("p{}", i + 1);
         context_map.insert(
-            MapKey::Keyword(Keyword(meta.key.clone())),
-            Expression::Symbol(Symbol(var)),
+            MapKey::Keyword(Keyword::new(meta.key.as_str())),
+            Expression::Symbol(Symbol(var.into())),
         );
     }
 
@@ -84,7 +85,7 @@ pub fn generate_collector(schema: &ParamSchema, domain: &str) -> String {
 
     // Build CapabilityDefinition using AST types
     let cap_def = CapabilityDefinition {
-        name: Symbol(format!("{}.collector.v1", domain)),
+        name: Symbol(format!("{}.collector.v1", domain).into()),
         properties: vec![
             Property {
                 key: Keyword("description".into()),
@@ -113,7 +114,7 @@ pub fn generate_planner(schema: &ParamSchema, history: &[InteractionTurn], domai
     // Build expects expression capturing required context keys (sorted for determinism)
     let mut expects_keys: Vec<Expression> = Vec::new();
     for (key, _meta) in ordered_params.iter() {
-        expects_keys.push(Expression::Symbol(Symbol(format!(":{}", key))));
+        expects_keys.push(Expression::Symbol(Symbol(format!(":{}", key).into())));
     }
     let expects_expr = if expects_keys.is_empty() {
         Expression::List(vec![
@@ -133,7 +134,7 @@ pub fn generate_planner(schema: &ParamSchema, history: &[InteractionTurn], domai
     let required_keys_vec = Expression::Vector(
         ordered_params
             .iter()
-            .map(|(key, _)| Expression::Literal(Literal::Keyword(Keyword((*key).clone()))))
+            .map(|(key, _)| Expression::Literal(Literal::Keyword(Keyword::new((*key).as_str()))))
             .collect(),
     );
 
@@ -164,7 +165,7 @@ pub fn generate_planner(schema: &ParamSchema, history: &[InteractionTurn], domai
                 let mut param_map = HashMap::new();
                 param_map.insert(
                     MapKey::Keyword(Keyword("key".into())),
-                    Expression::Literal(Literal::Keyword(Keyword((*key).clone()))),
+                    Expression::Literal(Literal::Keyword(Keyword::new((*key).as_str()))),
                 );
                 let prompt = if meta.prompt.is_empty() {
                     (*key).clone()
@@ -232,7 +233,7 @@ pub fn generate_planner(schema: &ParamSchema, history: &[InteractionTurn], domai
     );
     plan_map.insert(
         MapKey::Keyword(Keyword("generated-capability".into())),
-        Expression::Literal(Literal::Keyword(Keyword(capability_id.clone()))),
+        Expression::Literal(Literal::Keyword(Keyword::new(capability_id.as_str()))),
     );
     plan_map.insert(
         MapKey::Keyword(Keyword("required-keys".into())),
@@ -310,7 +311,7 @@ pub fn generate_planner(schema: &ParamSchema, history: &[InteractionTurn], domai
     });
 
     let cap_def = CapabilityDefinition {
-        name: Symbol(format!("{}.planner.v1", domain)),
+        name: Symbol(format!("{}.planner.v1", domain).into()),
         properties,
     };
 
@@ -526,7 +526,7 @@ impl APIIntrospector {
 
                     // Add to schema map
                     map_entries.push(MapTypeEntry {
-                        key: Keyword(param_info.name.clone()),
+                        key: Keyword::new(param_info.name.as_str()),
                         value_type: Box::new(param_info.param_type.clone()),
                         optional: !param_info.required,
                     });
@@ -558,7 +558,7 @@ impl APIIntrospector {
                         }
                     } else {
                         map_entries.push(MapTypeEntry {
-                            key: Keyword("body".to_string()),
+                            key: Keyword("body".into()),
                             value_type: Box::new(body_type),
                             optional: !request_body
                                 .get("required")
@@ -830,7 +830,7 @@ impl APIIntrospector {
                             };
 
                             entries.push(MapTypeEntry {
-                                key: Keyword(key.clone()),
+                                key: Keyword::new(key.as_str()),
                                 value_type: Box::new(prop_type),
                                 optional: !required_fields.contains(key) || is_prop_nullable,
                             });
@@ -1166,14 +1166,14 @@ impl APIIntrospector {
                     input_schema: Some(TypeExpr::Map {
                         entries: vec![
                             MapTypeEntry {
-                                key: Keyword("userId".to_string()),
+                                key: Keyword("userId".into()),
                                 value_type: Box::new(TypeExpr::Primitive(
                                     rtfs::ast::PrimitiveType::String,
                                 )),
                                 optional: false,
                             },
                             MapTypeEntry {
-                                key: Keyword("expand".to_string()),
+                                key: Keyword("expand".into()),
                                 value_type: Box::new(TypeExpr::Primitive(
                                     rtfs::ast::PrimitiveType::Bool,
                                 )),
@@ -1185,21 +1185,21 @@ impl APIIntrospector {
                     output_schema: Some(TypeExpr::Map {
                         entries: vec![
                             MapTypeEntry {
-                                key: Keyword("id".to_string()),
+                                key: Keyword("id".into()),
                                 value_type: Box::new(TypeExpr::Primitive(
                                     rtfs::ast::PrimitiveType::String,
                                 )),
                                 optional: false,
                             },
                             MapTypeEntry {
-                                key: Keyword("name".to_string()),
+                                key: Keyword("name".into()),
                                 value_type: Box::new(TypeExpr::Primitive(
                                     rtfs::ast::PrimitiveType::String,
                                 )),
                                 optional: false,
                             },
                             MapTypeEntry {
-                                key: Keyword("email".to_string()),
+                                key: Keyword("email".into()),
                                 value_type: Box::new(TypeExpr::Primitive(
                                     rtfs::ast::PrimitiveType::String,
                                 )),
@@ -1234,7 +1234,7 @@ impl APIIntrospector {
                     path: "/v1/activities".to_string(),
                     input_schema: Some(TypeExpr::Map {
                         entries: vec![MapTypeEntry {
-                            key: Keyword("events".to_string()),
+                            key: Keyword("events".into()),
                             value_type: Box::new(TypeExpr::Vector(Box::new(TypeExpr::Map {
                                 entries: vec![],
                                 wildcard: None,
@@ -1246,14 +1246,14 @@ impl APIIntrospector {
                     output_schema: Some(TypeExpr::Map {
                         entries: vec![
                             MapTypeEntry {
-                                key: Keyword("id".to_string()),
+                                key: Keyword("id".into()),
                                 value_type: Box::new(TypeExpr::Primitive(
                                     rtfs::ast::PrimitiveType::String,
                                 )),
                                 optional: false,
                             },
                             MapTypeEntry {
-                                key: Keyword("status".to_string()),
+                                key: Keyword("status".into()),
                                 value_type: Box::new(TypeExpr::Primitive(
                                     rtfs::ast::PrimitiveType::String,
                                 )),
@@ -1307,27 +1307,27 @@ impl APIIntrospector {
                     input_schema: Some(TypeExpr::Map {
                         entries: vec![
                             MapTypeEntry {
-                                key: Keyword("q".to_string()),
+                                key: Keyword("q".into()),
                                 value_type: Box::new(TypeExpr::Primitive(rtfs::ast::PrimitiveType::String)),
                                 optional: true,
                             },
                             MapTypeEntry {
-                                key: Keyword("lat".to_string()),
+                                key: Keyword("lat".into()),
                                 value_type: Box::new(TypeExpr::Primitive(rtfs::ast::PrimitiveType::Float)),
                                 optional: true,
                             },
                             MapTypeEntry {
-                                key: Keyword("lon".to_string()),
+                                key: Keyword("lon".into()),
                                 value_type: Box::new(TypeExpr::Primitive(rtfs::ast::PrimitiveType::Float)),
                                 optional: true,
                             },
                             MapTypeEntry {
-                                key: Keyword("units".to_string()),
+                                key: Keyword("units".into()),
                                 value_type: Box::new(TypeExpr::Primitive(rtfs::ast::PrimitiveType::String)),
                                 optional: true,
                             },
                             MapTypeEntry {
-                                key: Keyword("lang".to_string()),
+                                key: Keyword("lang".into()),
                                 value_type: Box::new(TypeExpr::Primitive(rtfs::ast::PrimitiveType::String)),
                                 optional: true,
                             },
@@ -1337,16 +1337,16 @@ impl APIIntrospector {
                     output_schema: Some(TypeExpr::Map {
                         entries: vec![
                             MapTypeEntry {
-                                key: Keyword("coord".to_string()),
+                                key: Keyword("coord".into()),
                                 value_type: Box::new(TypeExpr::Map {
                                     entries: vec![
                                         MapTypeEntry {
-                                            key: Keyword("lon".to_string()),
+                                            key: Keyword("lon".into()),
                                             value_type: Box::new(TypeExpr::Primitive(rtfs::ast::PrimitiveType::Float)),
                                             optional: false,
                                         },
                                         MapTypeEntry {
-                                            key: Keyword("lat".to_string()),
+                                            key: Keyword("lat".into()),
                                             value_type: Box::new(TypeExpr::Primitive(rtfs::ast::PrimitiveType::Float)),
                                             optional: false,
                                         },
@@ -1356,7 +1356,7 @@ impl APIIntrospector {
                                 optional: false,
                             },
                             MapTypeEntry {
-                                key: Keyword("weather".to_string()),
+                                key: Keyword("weather".into()),
                                 value_type: Box::new(TypeExpr::Vector(Box::new(TypeExpr::Map {
                                     entries: vec![],
                                     wildcard: None,
@@ -1364,16 +1364,16 @@ impl APIIntrospector {
                                 optional: false,
                             },
                             MapTypeEntry {
-                                key: Keyword("main".to_string()),
+                                key: Keyword("main".into()),
                                 value_type: Box::new(TypeExpr::Map {
                                     entries: vec![
                                         MapTypeEntry {
-                                            key: Keyword("temp".to_string()),
+                                            key: Keyword("temp".into()),
                                             value_type: Box::new(TypeExpr::Primitive(rtfs::ast::PrimitiveType::Float)),
                                             optional: false,
                                         },
                                         MapTypeEntry {
-                                            key: Keyword("humidity".to_string()),
+                                            key: Keyword("humidity".into()),
                                             value_type: Box::new(TypeExpr::Primitive(rtfs::ast::PrimitiveType::Int)),
                                             optional: false,
                                         },
@@ -1383,7 +1383,7 @@ impl APIIntrospector {
                                 optional: false,
                             },
                             MapTypeEntry {
-                                key: Keyword("name".to_string()),
+                                key: Keyword("name".into()),
                                 value_type: Box::new(TypeExpr::Primitive(rtfs::ast::PrimitiveType::String)),
                                 optional: false,
                             },
@@ -1432,22 +1432,22 @@ impl APIIntrospector {
                     input_schema: Some(TypeExpr::Map {
                         entries: vec![
                             MapTypeEntry {
-                                key: Keyword("q".to_string()),
+                                key: Keyword("q".into()),
                                 value_type: Box::new(TypeExpr::Primitive(rtfs::ast::PrimitiveType::String)),
                                 optional: true,
                             },
                             MapTypeEntry {
-                                key: Keyword("lat".to_string()),
+                                key: Keyword("lat".into()),
                                 value_type: Box::new(TypeExpr::Primitive(rtfs::ast::PrimitiveType::Float)),
                                 optional: true,
                             },
                             MapTypeEntry {
-                                key: Keyword("lon".to_string()),
+                                key: Keyword("lon".into()),
                                 value_type: Box::new(TypeExpr::Primitive(rtfs::ast::PrimitiveType::Float)),
                                 optional: true,
                             },
                             MapTypeEntry {
-                                key: Keyword("cnt".to_string()),
+                                key: Keyword("cnt".into()),
                                 value_type: Box::new(TypeExpr::Primitive(rtfs::ast::PrimitiveType::Int)),
                                 optional: true,
                             },
@@ -1457,12 +1457,12 @@ impl APIIntrospector {
                     output_schema: Some(TypeExpr::Map {
                         entries: vec![
                             MapTypeEntry {
-                                key: Keyword("cnt".to_string()),
+                                key: Keyword("cnt".into()),
                                 value_type: Box::new(TypeExpr::Primitive(rtfs::ast::PrimitiveType::Int)),
                                 optional: false,
                             },
                             MapTypeEntry {
-                                key: Keyword("list".to_string()),
+                                key: Keyword("list".into()),
                                 value_type: Box::new(TypeExpr::Vector(Box::new(TypeExpr::Map {
                                     entries: vec![],
                                     wildcard: None,
@@ -1470,11 +1470,11 @@ impl APIIntrospector {
                                 optional: false,
                             },
                             MapTypeEntry {
-                                key: Keyword("city".to_string()),
+                                key: Keyword("city".into()),
                                 value_type: Box::new(TypeExpr::Map {
                                     entries: vec![
                                         MapTypeEntry {
-                                            key: Keyword("name".to_string()),
+                                            key: Keyword("name".into()),
                                             value_type: Box::new(TypeExpr::Primitive(rtfs::ast::PrimitiveType::String)),
                                             optional: false,
                                         },
@@ -1,4 +1,4 @@
-use rtfs::ast::{Expression, Keyword, Literal};
+use rtfs::ast::{Expression, Keyword, Literal, Span};
 use rtfs::runtime::error::{RuntimeError, RuntimeResult};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -123,24 +123,25 @@ impl AuthInjector {
         // Generate RTFS: (call :ccos.auth.inject {:provider "github" :type :bearer :token token_param})
         let call_args = vec![
             (
-                rtfs::ast::MapKey::Keyword(Keyword("provider".to_string())),
+                rtfs::ast::MapKey::Keyword(Keyword("provider".into())),
                 Expression::Literal(Literal::String(provider.to_string())),
             ),
             (
-                rtfs::ast::MapKey::Keyword(Keyword("type".to_string())),
-                Expression::Literal(Literal::Keyword(Keyword(auth_type.to_string()))),
+                rtfs::ast::MapKey::Keyword(Keyword("type".into())),
+                Expression::Literal(Literal::Keyword(Keyword::new(&auth_type.to_string()))),
             ),
             (
-                rtfs::ast::MapKey::Keyword(Keyword("token".to_string())),
-                Expression::Symbol(rtfs::ast::Symbol(token_param_name.to_string())),
+                rtfs::ast::MapKey::Keyword(Keyword("token".into())),
+                Expression::Symbol(rtfs::ast::Symbol(token_param_name.into())),
             ),
         ];
 
         Ok(Expression::FunctionCall {
             callee: Box::new(Expression::Literal(Literal::Keyword(Keyword(
-                "ccos.auth.inject".to_string(),
+                "ccos.auth.inject".into(),
             )))),
             arguments: vec![Expression::Map(call_args.into_iter().collect())],
+            span: Span::unknown(),
         })
     }
 
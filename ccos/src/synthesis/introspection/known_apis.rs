@@ -275,7 +275,7 @@ impl KnownApisRegistry {
             .params
             .iter()
             .map(|p| MapTypeEntry {
-                key: Keyword(p.name.clone()),
+                key: Keyword::new(p.name.as_str()),
                 value_type: Box::new(self.parse_type(&p.param_type)),
                 optional: !p.required,
             })
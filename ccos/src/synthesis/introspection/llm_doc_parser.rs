@@ -621,7 +621,7 @@ Respond with ONLY the JSON object, no additional text."#,
             .parameters
             .iter()
             .map(|p| MapTypeEntry {
-                key: Keyword(p.name.clone()),
+                key: Keyword::new(p.name.as_str()),
                 value_type: Box::new(self.parse_type(&p.param_type)),
                 optional: !p.required,
             })
@@ -642,7 +642,7 @@ Respond with ONLY the JSON object, no additional text."#,
                 let output_entries: Vec<MapTypeEntry> = props
                     .iter()
                     .map(|p| MapTypeEntry {
-                        key: Keyword(p.name.clone()),
+                        key: Keyword::new(p.name.as_str()),
                         value_type: Box::new(self.parse_type(&p.prop_type)),
                         optional: !p.required,
                     })
@@ -309,7 +309,7 @@ impl MCPIntrospector {
                             };
 
                             entries.push(MapTypeEntry {
-                                key: Keyword(key.clone()),
+                                key: Keyword::new(key.as_str()),
                                 value_type: Box::new(prop_type),
                                 optional: !required_fields.contains(key) || is_prop_nullable,
                             });
@@ -704,7 +704,7 @@ impl MCPIntrospector {
                 for (key, val) in obj {
                     let value_type = self.infer_type_from_json_value(val)?;
                     entries.push(MapTypeEntry {
-                        key: Keyword(key.clone()),
+                        key: Keyword::new(key.as_str()),
                         value_type: Box::new(value_type),
                         optional: false, // Can't determine optionality from a single sample
                     });
@@ -1049,7 +1049,7 @@ impl MCPIntrospector {
                 description: Some("Example MCP tool".to_string()),
                 input_schema: Some(TypeExpr::Map {
                     entries: vec![MapTypeEntry {
-                        key: Keyword("query".to_string()),
+                        key: Keyword("query".into()),
                         value_type: Box::new(TypeExpr::Primitive(rtfs::ast::PrimitiveType::String)),
                         optional: false,
                     }],
@@ -1057,7 +1057,7 @@ impl MCPIntrospector {
                 }),
                 output_schema: Some(TypeExpr::Map {
                     entries: vec![MapTypeEntry {
-                        key: Keyword("result".to_string()),
+                        key: Keyword("result".into()),
                         value_type: Box::new(TypeExpr::Primitive(rtfs::ast::PrimitiveType::String)),
                         optional: false,
                     }],
@@ -1081,35 +1081,35 @@ impl MCPIntrospector {
                     input_schema: Some(TypeExpr::Map {
                         entries: vec![
                             MapTypeEntry {
-                                key: Keyword("owner".to_string()),
+                                key: Keyword("owner".into()),
                                 value_type: Box::new(TypeExpr::Primitive(
                                     rtfs::ast::PrimitiveType::String,
                                 )),
                                 optional: false,
                             },
                             MapTypeEntry {
-                                key: Keyword("repo".to_string()),
+                                key: Keyword("repo".into()),
                                 value_type: Box::new(TypeExpr::Primitive(
                                     rtfs::ast::PrimitiveType::String,
                                 )),
                                 optional: false,
                             },
                             MapTypeEntry {
-                                key: Keyword("title".to_string()),
+                                key: Keyword("title".into()),
                                 value_type: Box::new(TypeExpr::Primitive(
                                     rtfs::ast::PrimitiveType::String,
                                 )),
                                 optional: false,
                             },
                             MapTypeEntry {
-                                key: Keyword("body".to_string()),
+                                key: Keyword("body".into()),
                                 value_type: Box::new(TypeExpr::Primitive(
                                     rtfs::ast::PrimitiveType::String,
                                 )),
                                 optional: true,
                             },
                             MapTypeEntry {
-                                key: Keyword("labels".to_string()),
+                                key: Keyword("labels".into()),
                                 value_type: Box::new(TypeExpr::Vector(Box::new(
                                     TypeExpr::Primitive(rtfs::ast::PrimitiveType::String),
                                 ))),
@@ -1121,21 +1121,21 @@ impl MCPIntrospector {
                     output_schema: Some(TypeExpr::Map {
                         entries: vec![
                             MapTypeEntry {
-                                key: Keyword("number".to_string()),
+                                key: Keyword("number".into()),
                                 value_type: Box::new(TypeExpr::Primitive(
                                     rtfs::ast::PrimitiveType::Int,
                                 )),
                                 optional: false,
                             },
                             MapTypeEntry {
-                                key: Keyword("url".to_string()),
+                                key: Keyword("url".into()),
                                 value_type: Box::new(TypeExpr::Primitive(
                                     rtfs::ast::PrimitiveType::String,
                                 )),
                                 optional: false,
                             },
                             MapTypeEntry {
-                                key: Keyword("state".to_string()),
+                                key: Keyword("state".into()),
                                 value_type: Box::new(TypeExpr::Primitive(
                                     rtfs::ast::PrimitiveType::String,
                                 )),
@@ -1152,21 +1152,21 @@ impl MCPIntrospector {
                     input_schema: Some(TypeExpr::Map {
                         entries: vec![
                             MapTypeEntry {
-                                key: Keyword("owner".to_string()),
+                                key: Keyword("owner".into()),
                                 value_type: Box::new(TypeExpr::Primitive(
                                     rtfs::ast::PrimitiveType::String,
                                 )),
                                 optional: false,
                             },
                             MapTypeEntry {
-                                key: Keyword("repo".to_string()),
+                                key: Keyword("repo".into()),
                                 value_type: Box::new(TypeExpr::Primitive(
                                     rtfs::ast::PrimitiveType::String,
                                 )),
                                 optional: false,
                             },
                             MapTypeEntry {
-                                key: Keyword("state".to_string()),
+                                key: Keyword("state".into()),
                                 value_type: Box::new(TypeExpr::Primitive(
                                     rtfs::ast::PrimitiveType::String,
                                 )),
@@ -5,8 +5,8 @@
 //! for synthesized capabilities.
 
 use rtfs::ast::MapKey;
-use rtfs::runtime::values::Value;
-use std::collections::{HashMap, HashSet};
+use rtfs::runtime::values::{Value, ValueMap, ValueVector};
+use std::collections::HashSet;
 
 /// Infer an RTFS schema string from a runtime Value.
 ///
@@ -48,7 +48,7 @@ fn infer_schema_impl(value: &Value, depth: usize) -> String {
 }
 
 /// Infer schema for a vector by analyzing element types.
-fn infer_vector_schema(vec: &[Value], depth: usize) -> String {
+fn infer_vector_schema(vec: &ValueVector, depth: usize) -> String {
     if vec.is_empty() {
         return "[:vector :any]".to_string();
     }
@@ -72,7 +72,7 @@ fn infer_vector_schema(vec: &[Value], depth: usize) -> String {
 }
 
 /// Infer schema for a map by analyzing key-value pairs.
-fn infer_map_schema(map: &HashMap<MapKey, Value>, depth: usize) -> String {
+fn infer_map_schema(map: &ValueMap, depth: usize) -> String {
     if map.is_empty() {
         return "[:map]".to_string();
     }
@@ -95,7 +95,7 @@ fn infer_map_schema(map: &HashMap<MapKey, Value>, depth: usize) -> String {
 /// Convert a MapKey to a string suitable for schema representation.
 fn map_key_to_string(key: &MapKey) -> String {
     match key {
-        MapKey::Keyword(kw) => kw.0.clone(),
+        MapKey::Keyword(kw) => kw.0.to_string(),
         MapKey::String(s) => s.clone(),
         MapKey::Integer(i) => i.to_string(),
     }
@@ -163,24 +163,21 @@ mod tests {
 
     #[test]
     fn test_infer_vector() {
-        let vec = Value::Vector(vec![
-            Value::Integer(1),
-            Value::Integer(2),
-            Value::Integer(3),
-        ]);
+        let vec =
+            Value::Vector(vec![Value::Integer(1), Value::Integer(2), Value::Integer(3)].into());
         assert_eq!(infer_schema_from_value(&vec), "[:vector int?]");
     }
 
     #[test]
     fn test_infer_empty_vector() {
-        let vec = Value::Vector(vec![]);
+        let vec = Value::Vector(vec![].into());
         assert_eq!(infer_schema_from_value(&vec), "[:vector :any]");
     }
 
     #[test]
     fn test_infer_map() {
         use rtfs::ast::Keyword;
-        let mut map = HashMap::new();
+        let mut map = ValueMap::new();
         map.insert(MapKey::Keyword(Keyword("count".into())), Value::Integer(5));
         map.insert(
             MapKey::Keyword(Keyword("name".into())),
@@ -283,7 +283,7 @@ mod tests {
 
     #[test]
     fn test_infer_output_schema() {
-        let output = Value::Map(std::collections::HashMap::new());
+        let output = Value::Map(std::collections::HashMap::new().into());
         let result = infer_output_schema_from_result("test.cap", &output, Some(":any"));
         assert!(result.was_updated || result.inferred_output_schema == "[:map]");
     }
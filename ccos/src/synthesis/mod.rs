@@ -45,6 +45,7 @@ mod runtime_integration_tests;
 
 // Core modules
 pub use core::dependency_extractor;
+pub use core::example_synthesizer::{register_synthesized_capability, synthesize_capability};
 pub use core::feature_flags;
 pub use core::missing_capability_resolver;
 pub use core::missing_capability_strategies;
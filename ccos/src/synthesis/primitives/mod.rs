@@ -212,7 +212,7 @@ fn lookup_binding<'a>(
 }
 
 fn keyword_for(binding: &str) -> Keyword {
-    Keyword(binding.trim_start_matches(':').to_string())
+    Keyword(binding.trim_start_matches(':').into())
 }
 
 fn map_type_from(bindings: &HashMap<String, TypeExpr>) -> TypeExpr {
@@ -459,7 +459,7 @@ impl RegistrationFlow {
             self.generate_test_value_from_type_expr(input_schema)
         } else {
             // No schema - return empty map
-            Ok(Value::Map(HashMap::new()))
+            Ok(Value::Map(HashMap::new().into()))
         }
     }
 
@@ -482,7 +482,7 @@ impl RegistrationFlow {
             TypeExpr::Vector(inner) => {
                 // Generate a single-element array for testing
                 let element = self.generate_test_value_from_type_expr(inner)?;
-                Ok(Value::Vector(vec![element]))
+                Ok(Value::Vector(vec![element].into()))
             }
             TypeExpr::Map {
                 entries,
@@ -495,7 +495,7 @@ impl RegistrationFlow {
                     let value = self.generate_test_value_from_type_expr(&entry.value_type)?;
                     map.insert(key, value);
                 }
-                Ok(Value::Map(map))
+                Ok(Value::Map(map.into()))
             }
             TypeExpr::Any => Ok(Value::String("test".to_string())), // Fallback for :any
             TypeExpr::Never => Ok(Value::Nil),                      // :never - use nil as fallback
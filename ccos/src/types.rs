@@ -290,6 +290,10 @@ pub struct StorableIntent {
     pub priority: u32,
     pub created_at: u64,
     pub updated_at: u64,
+    /// Revision maintained by intent storage, bumped on every update and used
+    /// for optimistic concurrency checks
+    #[serde(default)]
+    pub version: u64,
     pub metadata: HashMap<String, String>, // Simple string metadata
 }
 
@@ -402,6 +406,12 @@ impl Intent {
 }
 
 impl Plan {
+    /// Structural diff of this plan's body against `other`, e.g. to review how a
+    /// regenerated plan differs from its previous version
+    pub fn diff(&self, other: &Plan) -> crate::rtfs_bridge::plan_diff::PlanDiff {
+        crate::rtfs_bridge::plan_diff::diff_plans(self, other)
+    }
+
     pub fn new_rtfs(rtfs_code: String, intent_ids: Vec<IntentId>) -> Self {
         Self {
             plan_id: format!("plan-{}", Uuid::new_v4()),
@@ -630,6 +640,7 @@ impl StorableIntent {
             priority: 0,
             created_at: now,
             updated_at: now,
+            version: 0,
             metadata: HashMap::new(),
         }
     }
@@ -737,6 +748,7 @@ impl RuntimeIntent {
             priority: self.priority,
             created_at: self.created_at,
             updated_at: self.updated_at,
+            version: 0,
             metadata,
         })
     }
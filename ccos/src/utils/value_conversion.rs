@@ -27,10 +27,10 @@ pub fn rtfs_value_to_json(value: &Value) -> RuntimeResult<serde_json::Value> {
             Ok(serde_json::Value::String(if s.starts_with(':') {
                 s[1..].to_string()
             } else {
-                s.clone()
+                s.to_string()
             }))
         }
-        Value::Symbol(s) => Ok(serde_json::Value::String(s.0.clone())),
+        Value::Symbol(s) => Ok(serde_json::Value::String(s.0.to_string())),
         Value::Vector(v) => {
             let json_array: Result<Vec<serde_json::Value>, RuntimeError> =
                 v.iter().map(rtfs_value_to_json).collect();
@@ -88,7 +88,7 @@ pub fn json_to_rtfs_value(json: &serde_json::Value) -> RuntimeResult<Value> {
         serde_json::Value::Array(arr) => {
             let values: Result<Vec<Value>, RuntimeError> =
                 arr.iter().map(json_to_rtfs_value).collect();
-            Ok(Value::Vector(values?))
+            Ok(Value::Vector(values?.into()))
         }
         serde_json::Value::Object(obj) => {
             let mut map = HashMap::new();
@@ -97,13 +97,13 @@ pub fn json_to_rtfs_value(json: &serde_json::Value) -> RuntimeResult<Value> {
                 // Otherwise use string key
                 let key_str = strip_leading_colon(k);
                 let map_key = if k.starts_with(':') {
-                    MapKey::Keyword(Keyword(key_str))
+                    MapKey::Keyword(Keyword(key_str.into()))
                 } else {
                     MapKey::String(k.clone())
                 };
                 map.insert(map_key, json_to_rtfs_value(v)?);
             }
-            Ok(Value::Map(map))
+            Ok(Value::Map(map.into()))
         }
     }
 }
@@ -120,7 +120,7 @@ pub fn map_key_to_string(key: &MapKey) -> String {
             if s.starts_with(':') {
                 s[1..].to_string()
             } else {
-                s.clone()
+                s.to_string()
             }
         }
         MapKey::Integer(i) => i.to_string(),
@@ -159,14 +159,14 @@ mod tests {
             MapKey::String("key".to_string()),
             Value::String("value".to_string()),
         );
-        let rtfs_val = Value::Map(map);
+        let rtfs_val = Value::Map(map.into());
         let json_val = rtfs_value_to_json(&rtfs_val).unwrap();
         assert_eq!(json_val["key"], "value");
     }
 
     #[test]
     fn test_keyword_stripping() {
-        let keyword = Value::Keyword(Keyword(":test".to_string()));
+        let keyword = Value::Keyword(Keyword(":test".into()));
         let json_val = rtfs_value_to_json(&keyword).unwrap();
         assert_eq!(json_val.as_str(), Some("test"));
     }
@@ -3,13 +3,27 @@
 //! Bridges Causal Chain actions to Working Memory using the existing MemoryIngestor.
 //! Maps Action objects to ActionRecord format for seamless integration.
 
+use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
 
+use super::capability_marketplace::CapabilityMarketplace;
 use super::event_sink::CausalChainEventSink;
-use super::types::Action;
+use super::types::{Action, ActionId, ActionType};
 use super::working_memory::facade::WorkingMemory;
 use super::working_memory::ingestor::{ActionRecord, MemoryIngestor};
+use super::working_memory::types::{WorkingMemoryEntry, WorkingMemoryMeta};
 use rtfs::runtime::values::Value;
+use sha2::{Digest, Sha256};
+
+/// Manifest metadata key marking a capability whose results are captured into Working Memory
+pub const MEMORABLE_METADATA_KEY: &str = "working_memory.memorable";
+/// Manifest metadata key holding comma-separated extra tags for captured entries
+pub const MEMORY_TAGS_METADATA_KEY: &str = "working_memory.tags";
+/// Tag carried by every captured capability result entry
+pub const CAPABILITY_RESULT_TAG: &str = "capability-result";
+
+/// Maximum length of the result preview stored in a captured entry
+const RESULT_PREVIEW_CHARS: usize = 256;
 
 /// Working Memory ingestion sink that subscribes to Causal Chain events
 pub struct WmIngestionSink {
@@ -95,6 +109,156 @@ impl CausalChainEventSink for WmIngestionSink {
     }
 }
 
+/// Captures successful calls to memorable capabilities into Working Memory.
+///
+/// Register on the Causal Chain next to (or instead of) `WmIngestionSink`. Calls to
+/// capabilities whose manifest was built `with_memory_capture` produce one summarized
+/// entry per successful result (capability id, args digest, result digest, timestamp,
+/// tags) so the arbiter can recall prior results; other capabilities are ignored.
+pub struct CapabilityResultSink {
+    wm: Arc<Mutex<WorkingMemory>>,
+    marketplace: Arc<CapabilityMarketplace>,
+    /// Memorable calls awaiting their result: call action id -> (args digest, tags)
+    pending: Mutex<HashMap<ActionId, (String, Vec<String>)>>,
+}
+
+impl std::fmt::Debug for CapabilityResultSink {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CapabilityResultSink")
+            .field("wm", &"Arc<Mutex<WorkingMemory>>")
+            .finish()
+    }
+}
+
+impl CapabilityResultSink {
+    pub fn new(wm: Arc<Mutex<WorkingMemory>>, marketplace: Arc<CapabilityMarketplace>) -> Self {
+        Self {
+            wm,
+            marketplace,
+            pending: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Extra tags for a memorable capability, or None if its results are not captured.
+    ///
+    /// Runs while the Causal Chain is locked, so it never waits on the marketplace:
+    /// a call made while the capability table is being written is not captured.
+    fn memory_tags(&self, capability_id: &str) -> Option<Vec<String>> {
+        let capabilities = self.marketplace.capabilities.try_read().ok()?;
+        let manifest = capabilities.get(capability_id)?;
+        if manifest
+            .metadata
+            .get(MEMORABLE_METADATA_KEY)
+            .map(String::as_str)
+            != Some("true")
+        {
+            return None;
+        }
+        Some(
+            manifest
+                .metadata
+                .get(MEMORY_TAGS_METADATA_KEY)
+                .map(|tags| {
+                    tags.split(',')
+                        .map(str::trim)
+                        .filter(|t| !t.is_empty())
+                        .map(String::from)
+                        .collect()
+                })
+                .unwrap_or_default(),
+        )
+    }
+
+    /// Build the Working Memory entry for a capability result action
+    pub fn entry_for_result(
+        result_action: &Action,
+        args_digest: &str,
+        extra_tags: &[String],
+    ) -> Option<WorkingMemoryEntry> {
+        let capability_id = result_action.function_name.clone()?;
+        let result = result_action.result.as_ref().filter(|r| r.success)?;
+
+        let rendered = format!("{:?}", result.value);
+        let result_digest = digest(&rendered);
+        let mut preview: String = rendered.chars().take(RESULT_PREVIEW_CHARS).collect();
+        if preview.len() < rendered.len() {
+            preview.push('…');
+        }
+
+        let mut tags = vec![CAPABILITY_RESULT_TAG.to_string(), capability_id.clone()];
+        tags.extend(extra_tags.iter().cloned());
+
+        let meta = WorkingMemoryMeta {
+            action_id: Some(result_action.action_id.clone()),
+            plan_id: result_action.plan_id.clone(),
+            intent_id: result_action.intent_id.clone(),
+            step_id: None,
+            provider: Some(capability_id.clone()),
+            attestation_hash: None,
+            content_hash: Some(result_digest.clone()),
+            extra: HashMap::from([
+                ("args_digest".to_string(), args_digest.to_string()),
+                ("result_digest".to_string(), result_digest.clone()),
+            ]),
+        };
+
+        Some(WorkingMemoryEntry::new_with_estimate(
+            format!("wm:capability:{}", result_action.action_id),
+            capability_id.clone(),
+            format!(
+                "capability={}; args_digest={}; result_digest={}; result={}",
+                capability_id, args_digest, result_digest, preview
+            ),
+            tags,
+            result_action.timestamp / 1000,
+            meta,
+        ))
+    }
+}
+
+impl CausalChainEventSink for CapabilityResultSink {
+    fn on_action_appended(&self, action: &Action) {
+        match action.action_type {
+            ActionType::CapabilityCall => {
+                let Some(tags) = action
+                    .function_name
+                    .as_deref()
+                    .and_then(|name| self.memory_tags(name))
+                else {
+                    return;
+                };
+                // Arguments on the call record are already redacted for sensitive capabilities
+                let args_digest = digest(&format!("{:?}", action.arguments));
+                if let Ok(mut pending) = self.pending.lock() {
+                    pending.insert(action.action_id.clone(), (args_digest, tags));
+                }
+            }
+            ActionType::CapabilityResult => {
+                let Some(call_id) = &action.parent_action_id else {
+                    return;
+                };
+                let Some((args_digest, tags)) =
+                    self.pending.lock().ok().and_then(|mut p| p.remove(call_id))
+                else {
+                    return;
+                };
+                if let Some(entry) = Self::entry_for_result(action, &args_digest, &tags) {
+                    if let Ok(mut wm) = self.wm.lock() {
+                        let _ = wm.append(entry);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn digest(s: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(s.as_bytes());
+    format!("{:x}", hasher.finalize())
+}
+
 /// Rebuild Working Memory from Causal Chain ledger (replay utility)
 pub fn rebuild_working_memory_from_ledger(
     wm: &mut WorkingMemory,
@@ -234,4 +398,22 @@ mod tests {
             assert!(entry.tags.contains("wisdom"));
         }
     }
+
+    #[test]
+    fn test_capability_result_sink_does_not_wait_on_a_locked_marketplace() {
+        use crate::capabilities::registry::CapabilityRegistry;
+
+        let marketplace = Arc::new(CapabilityMarketplace::new(Arc::new(
+            tokio::sync::RwLock::new(CapabilityRegistry::new()),
+        )));
+        let backend = InMemoryJsonlBackend::new(None, Some(10), Some(10_000));
+        let wm = Arc::new(Mutex::new(WorkingMemory::new(Box::new(backend))));
+        let sink = CapabilityResultSink::new(wm, marketplace.clone());
+
+        // A registration in flight holds the capability table for writing
+        let _writer = marketplace.capabilities.try_write().unwrap();
+        let call = create_test_action(ActionType::CapabilityCall, Some("test.cap".to_string()));
+        sink.on_action_appended(&call);
+        assert!(sink.pending.lock().unwrap().is_empty());
+    }
 }
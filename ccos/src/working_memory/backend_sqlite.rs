@@ -0,0 +1,125 @@
+//! SQLite-persisted Working Memory backend.
+//!
+//! Entries are written through to a single `working_memory` table and served
+//! from an [`InMemoryJsonlBackend`] index, so queries, ordering and budget
+//! eviction match the default backend exactly. Like the JSONL file, the table
+//! is durable history: budget eviction only drops entries from memory, and
+//! `load` replays the table oldest first so budgets keep the most recent ones.
+
+use crate::working_memory::backend::{
+    QueryParams, QueryResult, WorkingMemoryBackend, WorkingMemoryError,
+};
+use crate::working_memory::backend_inmemory::InMemoryJsonlBackend;
+use crate::working_memory::types::{WorkingMemoryEntry, WorkingMemoryId};
+use rusqlite::{params, Connection};
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+const SCHEMA: &str = "CREATE TABLE IF NOT EXISTS working_memory(
+    id TEXT PRIMARY KEY,
+    timestamp_s INTEGER NOT NULL,
+    payload TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS idx_working_memory_timestamp ON working_memory(timestamp_s);";
+
+/// Working Memory backend persisted in a SQLite database
+pub struct SqliteBackend {
+    // `Connection` is `Send` but not `Sync`
+    conn: Mutex<Connection>,
+    index: InMemoryJsonlBackend,
+    max_entries_in_memory: Option<usize>,
+    max_tokens_in_memory: Option<usize>,
+}
+
+impl SqliteBackend {
+    /// Open (or create) the database at `path`. Call `load` to restore entries.
+    pub fn new(
+        path: PathBuf,
+        max_entries_in_memory: Option<usize>,
+        max_tokens_in_memory: Option<usize>,
+    ) -> Result<Self, WorkingMemoryError> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let conn = Connection::open(&path).map_err(sql_err)?;
+        conn.execute_batch(SCHEMA).map_err(sql_err)?;
+        Ok(Self {
+            conn: Mutex::new(conn),
+            index: InMemoryJsonlBackend::new(None, max_entries_in_memory, max_tokens_in_memory),
+            max_entries_in_memory,
+            max_tokens_in_memory,
+        })
+    }
+
+    fn lock(&self) -> Result<std::sync::MutexGuard<'_, Connection>, WorkingMemoryError> {
+        self.conn
+            .lock()
+            .map_err(|_| WorkingMemoryError::Other("connection lock poisoned".to_string()))
+    }
+}
+
+fn sql_err(e: rusqlite::Error) -> WorkingMemoryError {
+    WorkingMemoryError::Io(format!("SQLite error: {}", e))
+}
+
+impl WorkingMemoryBackend for SqliteBackend {
+    fn append(&mut self, entry: WorkingMemoryEntry) -> Result<(), WorkingMemoryError> {
+        let payload = serde_json::to_string(&entry)?;
+        self.lock()?
+            .execute(
+                "INSERT OR REPLACE INTO working_memory(id, timestamp_s, payload) VALUES (?1, ?2, ?3)",
+                params![entry.id, entry.timestamp_s as i64, payload],
+            )
+            .map_err(sql_err)?;
+        self.index.append(entry)
+    }
+
+    fn get(&self, id: &WorkingMemoryId) -> Result<Option<WorkingMemoryEntry>, WorkingMemoryError> {
+        self.index.get(id)
+    }
+
+    fn query(&self, params: &QueryParams) -> Result<QueryResult, WorkingMemoryError> {
+        self.index.query(params)
+    }
+
+    fn prune(
+        &mut self,
+        max_entries: Option<usize>,
+        max_tokens: Option<usize>,
+    ) -> Result<(), WorkingMemoryError> {
+        if max_entries.is_some() {
+            self.max_entries_in_memory = max_entries;
+        }
+        if max_tokens.is_some() {
+            self.max_tokens_in_memory = max_tokens;
+        }
+        self.index.prune(max_entries, max_tokens)
+    }
+
+    fn load(&mut self) -> Result<(), WorkingMemoryError> {
+        let entries = {
+            let conn = self.lock()?;
+            let mut stmt = conn
+                .prepare("SELECT payload FROM working_memory ORDER BY timestamp_s, id")
+                .map_err(sql_err)?;
+            let rows = stmt
+                .query_map([], |row| row.get::<_, String>(0))
+                .map_err(sql_err)?;
+            rows.map(|row| Ok(serde_json::from_str(&row.map_err(sql_err)?)?))
+                .collect::<Result<Vec<WorkingMemoryEntry>, WorkingMemoryError>>()?
+        };
+        // Rebuild off to the side so a failed load leaves the current state intact
+        let mut index =
+            InMemoryJsonlBackend::new(None, self.max_entries_in_memory, self.max_tokens_in_memory);
+        for entry in entries {
+            index.append(entry)?;
+        }
+        self.index = index;
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<(), WorkingMemoryError> {
+        // Every append is committed immediately
+        Ok(())
+    }
+}
@@ -0,0 +1,85 @@
+//! Configuration types for Working Memory
+//!
+//! Selects the storage backend the same way `IntentGraphConfig` does, so
+//! entries can outlive a CCOS restart. Queries behave identically whichever
+//! backend is chosen: every backend answers from the same in-memory indices.
+
+use crate::working_memory::backend::{WorkingMemoryBackend, WorkingMemoryError};
+use crate::working_memory::backend_inmemory::InMemoryJsonlBackend;
+use crate::working_memory::backend_sqlite::SqliteBackend;
+use crate::working_memory::facade::WorkingMemory;
+use std::path::PathBuf;
+
+/// Where Working Memory entries are persisted
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum WorkingMemoryStorage {
+    /// Entries are lost on shutdown
+    #[default]
+    InMemory,
+    /// Append-only JSONL file
+    File { path: PathBuf },
+    /// SQLite database
+    Sqlite { path: PathBuf },
+}
+
+/// Configuration for Working Memory storage and budgets
+#[derive(Debug, Clone, Default)]
+pub struct WorkingMemoryConfig {
+    pub storage: WorkingMemoryStorage,
+    pub max_entries: Option<usize>,
+    pub max_tokens: Option<usize>,
+}
+
+impl WorkingMemoryConfig {
+    pub fn with_file_storage(path: PathBuf) -> Self {
+        Self {
+            storage: WorkingMemoryStorage::File { path },
+            ..Self::default()
+        }
+    }
+
+    pub fn with_sqlite_storage(path: PathBuf) -> Self {
+        Self {
+            storage: WorkingMemoryStorage::Sqlite { path },
+            ..Self::default()
+        }
+    }
+
+    pub fn with_in_memory_storage() -> Self {
+        Self::default()
+    }
+
+    pub fn with_budgets(mut self, max_entries: Option<usize>, max_tokens: Option<usize>) -> Self {
+        self.max_entries = max_entries;
+        self.max_tokens = max_tokens;
+        self
+    }
+
+    /// Create the configured backend and load any previously persisted entries.
+    pub fn build(&self) -> Result<WorkingMemory, WorkingMemoryError> {
+        let mut backend: Box<dyn WorkingMemoryBackend> = match &self.storage {
+            WorkingMemoryStorage::InMemory => Box::new(InMemoryJsonlBackend::new(
+                None,
+                self.max_entries,
+                self.max_tokens,
+            )),
+            WorkingMemoryStorage::File { path } => {
+                if let Some(parent) = path.parent() {
+                    std::fs::create_dir_all(parent)?;
+                }
+                Box::new(InMemoryJsonlBackend::new(
+                    Some(path.clone()),
+                    self.max_entries,
+                    self.max_tokens,
+                ))
+            }
+            WorkingMemoryStorage::Sqlite { path } => Box::new(SqliteBackend::new(
+                path.clone(),
+                self.max_entries,
+                self.max_tokens,
+            )?),
+        };
+        backend.load()?;
+        Ok(WorkingMemory::new(backend))
+    }
+}
@@ -9,6 +9,8 @@
 //! - types.rs: shared types (ids, entries, metadata), small and documented
 //! - backend.rs: WorkingMemoryBackend trait and error types
 //! - backend_inmemory.rs: default in-memory + JSONL append-only backend
+//! - backend_sqlite.rs: SQLite-persisted backend sharing the in-memory indices
+//! - config.rs: WorkingMemoryConfig selecting the storage backend and budgets
 //! - facade.rs: thin facade wrapping a boxed backend (stable surface for callers)
 //! - ingestor.rs: MemoryIngestor skeleton (subscribe/replay + derivation)
 //! - boundaries.rs: Boundary, BoundaryType, ReductionStrategy for CH integration
@@ -20,7 +22,9 @@
 pub mod agent_memory;
 pub mod backend;
 pub mod backend_inmemory;
+pub mod backend_sqlite;
 pub mod boundaries;
+pub mod config;
 pub mod facade;
 pub mod ingestor;
 pub mod types;
@@ -28,7 +32,9 @@ pub mod types;
 pub use agent_memory::{AgentMemory, LearnedPattern};
 pub use backend::{QueryParams, QueryResult, WorkingMemoryBackend, WorkingMemoryError};
 pub use backend_inmemory::InMemoryJsonlBackend;
+pub use backend_sqlite::SqliteBackend;
 pub use boundaries::{Boundary, BoundaryType, ReductionStrategy};
+pub use config::{WorkingMemoryConfig, WorkingMemoryStorage};
 pub use facade::WorkingMemory;
 pub use ingestor::{DerivedEntry, MemoryIngestor, WorkingMemorySink};
 pub use types::{WorkingMemoryEntry, WorkingMemoryId, WorkingMemoryMeta};
@@ -0,0 +1,70 @@
+use ccos::capabilities::registry::CapabilityRegistry;
+use ccos::capability_marketplace::{CapabilityBuilderExt, CapabilityMarketplace};
+use rtfs::ast::TypeExpr;
+use rtfs::builders::CapabilityBuilder;
+use rtfs::runtime::error::RuntimeError;
+use rtfs::runtime::values::Value;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+fn marketplace() -> CapabilityMarketplace {
+    CapabilityMarketplace::new(Arc::new(RwLock::new(CapabilityRegistry::new())))
+}
+
+fn shout() -> CapabilityBuilder {
+    CapabilityBuilder::new("text.shout")
+        .with_provider("local")
+        .with_metadata("description", "Upper-cases a string")
+        .with_input_schema(TypeExpr::from_str(":string").unwrap())
+        .with_output_schema(TypeExpr::from_str(":string").unwrap())
+        .with_handler(|input| match input {
+            Value::String(s) => Ok(Value::String(s.to_uppercase())),
+            other => Err(RuntimeError::Generic(format!(
+                "expected a string, got {}",
+                other
+            ))),
+        })
+}
+
+#[tokio::test]
+async fn registered_builder_is_callable_through_marketplace() {
+    let marketplace = marketplace();
+    shout().register_into(&marketplace).await.unwrap();
+
+    let manifest = marketplace.get_capability("text.shout").await.unwrap();
+    assert_eq!(manifest.description, "Upper-cases a string");
+    assert_eq!(
+        manifest.metadata.get("provider").map(String::as_str),
+        Some("local")
+    );
+
+    let result = marketplace
+        .execute_capability("text.shout", &Value::String("hello".to_string()))
+        .await
+        .unwrap();
+    assert_eq!(result, Value::String("HELLO".to_string()));
+}
+
+#[tokio::test]
+async fn duplicate_id_is_rejected() {
+    let marketplace = marketplace();
+    shout().register_into(&marketplace).await.unwrap();
+
+    let error = shout().register_into(&marketplace).await.unwrap_err();
+    assert!(
+        error.to_string().contains("already registered"),
+        "{}",
+        error
+    );
+}
+
+#[tokio::test]
+async fn builder_without_handler_is_rejected() {
+    let marketplace = marketplace();
+    let result = CapabilityBuilder::new("text.noop")
+        .register_into(&marketplace)
+        .await;
+
+    assert!(result.is_err());
+    assert!(!marketplace.has_capability("text.noop").await);
+}
@@ -0,0 +1,176 @@
+use ccos::capabilities::registry::CapabilityRegistry;
+use ccos::capability_marketplace::health::{CircuitBreakerConfig, CircuitState};
+use ccos::capability_marketplace::CapabilityMarketplace;
+use rtfs::ast::TypeExpr;
+use rtfs::runtime::values::Value;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use tokio::sync::RwLock;
+
+/// Minimal HTTP server answering 500 while `healthy` is false and 200 afterwards.
+async fn spawn_flaky_server(healthy: Arc<AtomicBool>, hits: Arc<AtomicUsize>) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        loop {
+            let Ok((mut socket, _)) = listener.accept().await else {
+                break;
+            };
+            hits.fetch_add(1, Ordering::SeqCst);
+            let healthy = healthy.load(Ordering::SeqCst);
+            tokio::spawn(async move {
+                let mut buf = [0u8; 4096];
+                let _ = socket.read(&mut buf).await;
+                let response = if healthy {
+                    "HTTP/1.1 200 OK\r\nContent-Length: 2\r\nConnection: close\r\n\r\nok"
+                } else {
+                    "HTTP/1.1 500 Internal Server Error\r\nContent-Length: 4\r\nConnection: close\r\n\r\nfail"
+                };
+                let _ = socket.write_all(response.as_bytes()).await;
+                let _ = socket.shutdown().await;
+            });
+        }
+    });
+    format!("http://{}", addr)
+}
+
+async fn call(marketplace: &CapabilityMarketplace, url: &str) -> Result<Value, String> {
+    let args = Value::List(
+        vec![
+            Value::String(url.to_string()),
+            Value::String("GET".to_string()),
+        ]
+        .into(),
+    );
+    marketplace
+        .execute_capability("test.flaky", &args)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tokio::test]
+async fn breaker_opens_fast_fails_and_recovers_after_cooldown() {
+    let healthy = Arc::new(AtomicBool::new(false));
+    let hits = Arc::new(AtomicUsize::new(0));
+    let url = spawn_flaky_server(healthy.clone(), hits.clone()).await;
+
+    let marketplace = CapabilityMarketplace::new(Arc::new(RwLock::new(CapabilityRegistry::new())));
+    marketplace.set_circuit_breaker_config(CircuitBreakerConfig {
+        failure_threshold: 3,
+        cooldown: Duration::from_millis(200),
+        ..CircuitBreakerConfig::default()
+    });
+    marketplace
+        .register_http_capability(
+            "test.flaky".to_string(),
+            "Flaky".to_string(),
+            "Remote capability that fails until told otherwise".to_string(),
+            url.clone(),
+            None,
+        )
+        .await
+        .unwrap();
+
+    // Drive the capability through consecutive failures to open the breaker
+    for _ in 0..3 {
+        let err = call(&marketplace, &url).await.unwrap_err();
+        assert!(err.contains("status 500"), "unexpected error: {}", err);
+    }
+    let health = marketplace.capability_health("test.flaky").unwrap();
+    assert_eq!(health.state, CircuitState::Open);
+    assert_eq!(health.consecutive_failures, 3);
+    assert!(health.last_failure.is_some());
+    assert!(health.last_success.is_none());
+
+    // While open, calls fail fast without reaching the provider
+    let err = call(&marketplace, &url).await.unwrap_err();
+    assert!(
+        err.contains("Circuit breaker open"),
+        "unexpected error: {}",
+        err
+    );
+    assert_eq!(hits.load(Ordering::SeqCst), 3);
+
+    // After the cooldown a probe goes through; success closes the breaker
+    healthy.store(true, Ordering::SeqCst);
+    tokio::time::sleep(Duration::from_millis(250)).await;
+    call(&marketplace, &url).await.unwrap();
+    assert_eq!(hits.load(Ordering::SeqCst), 4);
+
+    let health = marketplace.capability_health("test.flaky").unwrap();
+    assert_eq!(health.state, CircuitState::Closed);
+    assert_eq!(health.consecutive_failures, 0);
+    assert!(health.last_success.is_some());
+    assert!(marketplace
+        .capability_health_snapshot()
+        .contains_key("test.flaky"));
+}
+
+#[tokio::test]
+async fn local_capabilities_are_not_circuit_broken() {
+    let marketplace = CapabilityMarketplace::new(Arc::new(RwLock::new(CapabilityRegistry::new())));
+    marketplace.set_circuit_breaker_config(CircuitBreakerConfig {
+        failure_threshold: 1,
+        cooldown: Duration::from_secs(60),
+        ..CircuitBreakerConfig::default()
+    });
+    marketplace
+        .register_local_capability(
+            "test.local-fail".to_string(),
+            "Local fail".to_string(),
+            "Always fails".to_string(),
+            Arc::new(|_| Err(rtfs::runtime::error::RuntimeError::Generic("nope".into()))),
+        )
+        .await
+        .unwrap();
+
+    for _ in 0..3 {
+        let err = marketplace
+            .execute_capability("test.local-fail", &Value::List(vec![].into()))
+            .await
+            .unwrap_err();
+        assert!(!err.to_string().contains("Circuit breaker"));
+    }
+    assert!(marketplace.capability_health("test.local-fail").is_none());
+}
+
+#[tokio::test]
+async fn invalid_inputs_do_not_count_against_remote_health() {
+    let healthy = Arc::new(AtomicBool::new(true));
+    let hits = Arc::new(AtomicUsize::new(0));
+    let url = spawn_flaky_server(healthy, hits.clone()).await;
+
+    let marketplace = CapabilityMarketplace::new(Arc::new(RwLock::new(CapabilityRegistry::new())));
+    marketplace.set_circuit_breaker_config(CircuitBreakerConfig {
+        failure_threshold: 1,
+        cooldown: Duration::from_secs(60),
+        ..CircuitBreakerConfig::default()
+    });
+    marketplace
+        .register_http_capability_with_schema(
+            "test.flaky".to_string(),
+            "Flaky".to_string(),
+            "Remote capability expecting a query map".to_string(),
+            url.clone(),
+            None,
+            Some(TypeExpr::from_str("[:map [:query :string]]").unwrap()),
+            None,
+        )
+        .await
+        .unwrap();
+
+    // One caller's bad input is rejected locally and must not open the breaker
+    for _ in 0..3 {
+        let err = call(&marketplace, &url).await.unwrap_err();
+        assert!(
+            err.contains("Input validation failed"),
+            "unexpected error: {}",
+            err
+        );
+    }
+    assert_eq!(hits.load(Ordering::SeqCst), 0);
+    assert!(marketplace.capability_health("test.flaky").is_none());
+}
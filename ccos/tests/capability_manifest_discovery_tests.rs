@@ -0,0 +1,168 @@
+use ccos::capabilities::registry::CapabilityRegistry;
+use ccos::capability_marketplace::CapabilityMarketplace;
+use rtfs::ast::MapKey;
+use rtfs::runtime::values::{Value, ValueMap};
+use std::sync::{Arc, Mutex};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use tokio::sync::RwLock;
+
+/// Minimal HTTP server answering every request with its own request path.
+async fn spawn_path_echo_server() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        loop {
+            let Ok((mut socket, _)) = listener.accept().await else {
+                break;
+            };
+            tokio::spawn(async move {
+                let mut buf = [0u8; 4096];
+                let n = socket.read(&mut buf).await.unwrap_or(0);
+                let request = String::from_utf8_lossy(&buf[..n]);
+                let path = request.split_whitespace().nth(1).unwrap_or("/").to_string();
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    path.len(),
+                    path
+                );
+                let _ = socket.write_all(response.as_bytes()).await;
+                let _ = socket.shutdown().await;
+            });
+        }
+    });
+    format!("http://{}", addr)
+}
+
+async fn call_body(marketplace: &CapabilityMarketplace, id: &str, args: Value) -> Value {
+    let result = marketplace.execute_capability(id, &args).await.unwrap();
+    match result {
+        Value::Map(map) => map
+            .get(&MapKey::String("body".to_string()))
+            .cloned()
+            .unwrap(),
+        other => panic!("unexpected HTTP result: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn manifest_capabilities_are_registered_and_callable() {
+    let base_url = spawn_path_echo_server().await;
+    let dir = tempfile::tempdir().unwrap();
+    let manifest_path = dir.path().join("capabilities.json");
+    let manifest = serde_json::json!({
+        "capabilities": [
+            {
+                "id": "demo.weather",
+                "name": "Weather",
+                "description": "Current weather for a city",
+                "endpoint": format!("{}/weather", base_url),
+                "input_schema": {
+                    "type": "object",
+                    "properties": { "city": { "type": "string" } },
+                    "required": ["city"]
+                }
+            },
+            {
+                "id": "demo.news",
+                "endpoint": format!("{}/news", base_url)
+            },
+            { "name": "entry without an id" }
+        ]
+    });
+    std::fs::write(&manifest_path, manifest.to_string()).unwrap();
+
+    let marketplace = CapabilityMarketplace::new(Arc::new(RwLock::new(CapabilityRegistry::new())));
+    let registered = marketplace
+        .register_from_manifest(&manifest_path)
+        .await
+        .unwrap();
+    assert_eq!(registered, 2);
+
+    let weather = marketplace.get_capability("demo.weather").await.unwrap();
+    assert_eq!(weather.name, "Weather");
+    assert!(weather.input_schema.is_some());
+
+    assert_eq!(
+        call_body(
+            &marketplace,
+            "demo.weather",
+            Value::Map(ValueMap::from_iter([(
+                MapKey::String("city".to_string()),
+                Value::String("Paris".to_string()),
+            )])),
+        )
+        .await,
+        Value::String("/weather".to_string())
+    );
+    assert_eq!(
+        call_body(&marketplace, "demo.news", Value::List(vec![].into())).await,
+        Value::String("/news".to_string())
+    );
+}
+
+#[tokio::test]
+async fn malformed_manifest_files_are_skipped_in_directories() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("broken.json"), "{ not json").unwrap();
+    std::fs::write(
+        dir.path().join("good.json"),
+        serde_json::json!([{ "id": "demo.ping", "endpoint": "http://127.0.0.1:1/ping" }])
+            .to_string(),
+    )
+    .unwrap();
+
+    let marketplace = CapabilityMarketplace::new(Arc::new(RwLock::new(CapabilityRegistry::new())));
+    let registered = marketplace
+        .register_from_manifest(dir.path())
+        .await
+        .unwrap();
+    assert_eq!(registered, 1);
+    assert!(marketplace.has_capability("demo.ping").await);
+}
+
+#[tokio::test]
+async fn manifest_entries_are_audited_and_cannot_shadow_existing_capabilities() {
+    let dir = tempfile::tempdir().unwrap();
+    let manifest_path = dir.path().join("capabilities.json");
+    std::fs::write(
+        &manifest_path,
+        serde_json::json!([
+            { "id": "ccos.echo", "endpoint": "http://127.0.0.1:1/echo" },
+            { "id": "demo.ping", "endpoint": "http://127.0.0.1:1/ping" }
+        ])
+        .to_string(),
+    )
+    .unwrap();
+
+    let audit_log = Arc::new(Mutex::new(Vec::new()));
+    let mut marketplace =
+        CapabilityMarketplace::new(Arc::new(RwLock::new(CapabilityRegistry::new())));
+    let sink = audit_log.clone();
+    marketplace.set_debug_callback(move |message| sink.lock().unwrap().push(message));
+    marketplace
+        .register_local_capability(
+            "ccos.echo".to_string(),
+            "Echo".to_string(),
+            "Built-in echo".to_string(),
+            Arc::new(|args: &Value| Ok(args.clone())),
+        )
+        .await
+        .unwrap();
+
+    let registered = marketplace
+        .register_from_manifest(&manifest_path)
+        .await
+        .unwrap();
+    assert_eq!(registered, 1);
+
+    // The built-in keeps its local provider
+    let echo = marketplace.get_capability("ccos.echo").await.unwrap();
+    assert_eq!(echo.name, "Echo");
+    assert!(marketplace.has_capability("demo.ping").await);
+
+    let audit_log = audit_log.lock().unwrap();
+    assert!(audit_log
+        .iter()
+        .any(|m| m.contains("capability_registered") && m.contains("demo.ping")));
+}
@@ -39,11 +39,11 @@ fn write_line_uses_map_schema_with_normalization() {
     match &normalized {
         Value::Map(map) => {
             assert_eq!(
-                map.get(&MapKey::Keyword(Keyword("handle".to_string()))),
+                map.get(&MapKey::Keyword(Keyword("handle".into()))),
                 Some(&Value::Integer(123))
             );
             assert_eq!(
-                map.get(&MapKey::Keyword(Keyword("line".to_string()))),
+                map.get(&MapKey::Keyword(Keyword("line".into()))),
                 Some(&Value::String("hello".to_string()))
             );
         }
@@ -58,14 +58,14 @@ fn write_line_uses_map_schema_with_normalization() {
     // Test 2: Map input → passthrough → validates
     let mut map = std::collections::HashMap::new();
     map.insert(
-        MapKey::Keyword(Keyword("handle".to_string())),
+        MapKey::Keyword(Keyword("handle".into())),
         Value::Integer(123),
     );
     map.insert(
-        MapKey::Keyword(Keyword("line".to_string())),
+        MapKey::Keyword(Keyword("line".into())),
         Value::String("hello".to_string()),
     );
-    let map_args = vec![Value::Map(map)];
+    let map_args = vec![Value::Map(map.into())];
     let passthrough =
         normalize_args_to_map(map_args, schema).expect("map passthrough should succeed");
 
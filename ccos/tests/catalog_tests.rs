@@ -75,7 +75,7 @@ fn sample_plan(plan_id: &str) -> Plan {
         status: PlanStatus::Draft,
         created_at: 0,
         metadata,
-        input_schema: Some(Value::Map(input_schema)),
+        input_schema: Some(Value::Map(input_schema.into())),
         output_schema: None,
         policies: HashMap::new(),
         capabilities_required: vec![
@@ -187,7 +187,7 @@ fn menu_extracts_optional_inputs_from_alias_symbol() {
         entries: vec![
             MapTypeEntry {
                 key: Keyword::new("after"),
-                value_type: Box::new(TypeExpr::Alias(Symbol("string?".to_string()))),
+                value_type: Box::new(TypeExpr::Alias(Symbol("string?".into()))),
                 optional: false,
             },
             MapTypeEntry {
@@ -30,7 +30,7 @@ fn test_label_join_with_field_labels() {
         Value::String("ok".to_string()),
     );
     data.insert(
-        MapKey::Keyword(Keyword("a".to_string())),
+        MapKey::Keyword(Keyword("a".into())),
         Value::String("ok".to_string()),
     );
     data.insert(
@@ -38,7 +38,7 @@ fn test_label_join_with_field_labels() {
         Value::String("secret".to_string()),
     );
     data.insert(
-        MapKey::Keyword(Keyword("b".to_string())),
+        MapKey::Keyword(Keyword("b".into())),
         Value::String("secret".to_string()),
     );
 
@@ -47,7 +47,7 @@ fn test_label_join_with_field_labels() {
     field_labels.insert("b".to_string(), ChatDataLabel::PiiChatMessage);
 
     let labeled = attach_label(
-        Value::Map(data),
+        Value::Map(data.into()),
         ChatDataLabel::PiiRedacted,
         Some(field_labels),
     );
@@ -58,7 +58,7 @@ fn test_label_join_with_field_labels() {
         for key in map.keys() {
             let key_str = match key {
                 MapKey::String(s) => s.clone(),
-                MapKey::Keyword(k) => k.0.clone(),
+                MapKey::Keyword(k) => k.0.to_string(),
                 MapKey::Integer(i) => i.to_string(),
             };
             assert_ne!(key_str, ccos::chat::CCOS_META_KEY);
@@ -133,7 +133,10 @@ async fn test_prepare_outbound_and_redacted_approval() {
     );
 
     let result = marketplace
-        .execute_capability("ccos.chat.egress.prepare_outbound", &Value::Map(inputs))
+        .execute_capability(
+            "ccos.chat.egress.prepare_outbound",
+            &Value::Map(inputs.into()),
+        )
         .await;
     assert!(result.is_ok());
 
@@ -164,7 +167,7 @@ async fn test_prepare_outbound_and_redacted_approval() {
     let denied = marketplace
         .execute_capability(
             "ccos.chat.egress.prepare_outbound",
-            &Value::Map(inputs2.clone()),
+            &Value::Map(inputs2.clone().into()),
         )
         .await;
     assert!(denied.is_err());
@@ -188,7 +191,10 @@ async fn test_prepare_outbound_and_redacted_approval() {
     .expect("approve");
 
     let allowed = marketplace
-        .execute_capability("ccos.chat.egress.prepare_outbound", &Value::Map(inputs2))
+        .execute_capability(
+            "ccos.chat.egress.prepare_outbound",
+            &Value::Map(inputs2.into()),
+        )
         .await;
     assert!(allowed.is_ok());
 }
@@ -268,7 +274,7 @@ async fn test_verify_redaction_requires_approval() {
     let denied = marketplace
         .execute_capability(
             "ccos.chat.transform.verify_redaction",
-            &Value::Map(inputs.clone()),
+            &Value::Map(inputs.clone().into()),
         )
         .await;
     assert!(denied.is_err());
@@ -295,7 +301,10 @@ async fn test_verify_redaction_requires_approval() {
     .expect("approve");
 
     let allowed = marketplace
-        .execute_capability("ccos.chat.transform.verify_redaction", &Value::Map(inputs))
+        .execute_capability(
+            "ccos.chat.transform.verify_redaction",
+            &Value::Map(inputs.into()),
+        )
         .await
         .expect("verify");
 
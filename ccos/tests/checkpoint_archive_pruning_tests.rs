@@ -0,0 +1,101 @@
+use ccos::checkpoint_archive::{CheckpointArchive, CheckpointPrunePolicy, CheckpointRecord};
+use std::collections::HashMap;
+
+fn now() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+}
+
+fn record(id: &str, plan_id: &str, created_at: u64, label: Option<&str>) -> CheckpointRecord {
+    CheckpointRecord {
+        checkpoint_id: id.to_string(),
+        plan_id: plan_id.to_string(),
+        intent_id: "intent-1".to_string(),
+        serialized_context: format!("{{\"checkpoint\":\"{}\"}}", id),
+        created_at,
+        metadata: label
+            .map(|l| HashMap::from([("label".to_string(), l.to_string())]))
+            .unwrap_or_default(),
+        missing_capabilities: Vec::new(),
+        auto_resume_enabled: false,
+    }
+}
+
+fn ids(archive: &CheckpointArchive, plan_id: &str) -> Vec<String> {
+    archive
+        .list_checkpoints(plan_id)
+        .into_iter()
+        .map(|m| m.checkpoint_id)
+        .collect()
+}
+
+#[test]
+fn list_checkpoints_returns_plan_checkpoints_newest_first() {
+    let archive = CheckpointArchive::new();
+    let t = now();
+    archive
+        .store(record("cp-b", "plan-1", t - 20, None))
+        .unwrap();
+    archive
+        .store(record("cp-c", "plan-1", t - 10, Some("after-fetch")))
+        .unwrap();
+    archive
+        .store(record("cp-a", "plan-1", t - 30, None))
+        .unwrap();
+    archive
+        .store(record("cp-other", "plan-2", t, None))
+        .unwrap();
+
+    let metas = archive.list_checkpoints("plan-1");
+    assert_eq!(ids(&archive, "plan-1"), vec!["cp-c", "cp-b", "cp-a"]);
+    assert_eq!(metas[0].label.as_deref(), Some("after-fetch"));
+    assert_eq!(metas[0].created_at, t - 10);
+    assert_eq!(metas[0].size_bytes, "{\"checkpoint\":\"cp-c\"}".len());
+    assert!(archive.list_checkpoints("plan-unknown").is_empty());
+}
+
+#[test]
+fn prune_keeps_only_configured_number_per_plan() {
+    let dir = tempfile::tempdir().unwrap();
+    let archive = CheckpointArchive::new().with_durable_dir(dir.path());
+    let t = now();
+    for n in 0..5u64 {
+        archive
+            .store(record(&format!("cp-{}", n), "plan-1", t - 50 + n, None))
+            .unwrap();
+    }
+    archive
+        .store(record("cp-solo", "plan-2", t - 100, None))
+        .unwrap();
+
+    let mut pruned = archive.prune(&CheckpointPrunePolicy::keep_latest(2));
+    pruned.sort();
+    assert_eq!(pruned, vec!["cp-0", "cp-1", "cp-2"]);
+    assert_eq!(ids(&archive, "plan-1"), vec!["cp-4", "cp-3"]);
+    assert_eq!(ids(&archive, "plan-2"), vec!["cp-solo"]);
+    assert!(archive.get_by_id("cp-0").is_none());
+    assert!(!dir.path().join("cp-0.json").exists());
+    assert!(dir.path().join("cp-4.json").exists());
+}
+
+#[test]
+fn prune_drops_checkpoints_older_than_max_age() {
+    let archive = CheckpointArchive::new();
+    let t = now();
+    archive
+        .store(record("cp-old", "plan-1", t - 3600, None))
+        .unwrap();
+    archive.store(record("cp-new", "plan-1", t, None)).unwrap();
+
+    assert_eq!(
+        archive.prune(&CheckpointPrunePolicy::max_age(60)),
+        vec!["cp-old"]
+    );
+    assert_eq!(ids(&archive, "plan-1"), vec!["cp-new"]);
+    // Nothing left to prune
+    assert!(archive
+        .prune(&CheckpointPrunePolicy::max_age(60))
+        .is_empty());
+}
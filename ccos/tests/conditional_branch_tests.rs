@@ -0,0 +1,136 @@
+use ccos::capabilities::registry::CapabilityRegistry;
+use ccos::capability_marketplace::types::{
+    ApprovalStatus, CapabilityManifest, LocalCapability, ProviderType,
+};
+use ccos::capability_marketplace::CapabilityMarketplace;
+use ccos::causal_chain::CausalChain;
+use ccos::governance_kernel::GovernanceKernel;
+use ccos::intent_graph::IntentGraph;
+use ccos::orchestrator::Orchestrator;
+use ccos::plan_archive::PlanArchive;
+use ccos::types::{EdgeType, Plan, StorableIntent};
+use rtfs::runtime::security::RuntimeContext;
+use rtfs::runtime::values::Value;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use tokio::sync::RwLock;
+
+async fn register(
+    marketplace: &CapabilityMarketplace,
+    id: &str,
+    calls: Arc<Mutex<Vec<String>>>,
+    result: Value,
+) {
+    let name = id.to_string();
+    let mut manifest = CapabilityManifest::new(
+        id.to_string(),
+        id.to_string(),
+        "Records that it was called".to_string(),
+        ProviderType::Local(LocalCapability {
+            handler: Arc::new(move |_args: &Value| {
+                calls.lock().unwrap().push(name.clone());
+                Ok(result.clone())
+            }),
+        }),
+        "1.0.0".to_string(),
+    );
+    manifest.approval_status = ApprovalStatus::Approved;
+    marketplace
+        .register_capability_manifest(manifest)
+        .await
+        .unwrap();
+}
+
+fn add_intent(graph: &mut IntentGraph, goal: &str) -> String {
+    let intent = StorableIntent::new(goal.to_string());
+    let id = intent.intent_id.clone();
+    graph.store_intent(intent).unwrap();
+    id
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn capability_result_selects_branch_a_over_branch_b() {
+    let intent_graph = Arc::new(Mutex::new(IntentGraph::new().unwrap()));
+    let marketplace = Arc::new(CapabilityMarketplace::new(Arc::new(RwLock::new(
+        CapabilityRegistry::new(),
+    ))));
+    let calls = Arc::new(Mutex::new(Vec::new()));
+    register(
+        &marketplace,
+        "test.decide",
+        calls.clone(),
+        Value::String("a".to_string()),
+    )
+    .await;
+    register(
+        &marketplace,
+        "test.branch-a",
+        calls.clone(),
+        Value::Integer(1),
+    )
+    .await;
+    register(
+        &marketplace,
+        "test.branch-b",
+        calls.clone(),
+        Value::Integer(2),
+    )
+    .await;
+
+    let (root, decide, branch_a, branch_b) = {
+        let mut graph = intent_graph.lock().unwrap();
+        let root = add_intent(&mut graph, "Handle the request");
+        let decide = add_intent(&mut graph, "Decide which way to go");
+        let branch_a = add_intent(&mut graph, "Take branch A");
+        let branch_b = add_intent(&mut graph, "Take branch B");
+        // Branches are listed before the decision so they have to wait for it
+        for child in [&branch_a, &branch_b, &decide] {
+            graph
+                .create_edge(child.clone(), root.clone(), EdgeType::IsSubgoalOf)
+                .unwrap();
+        }
+        graph
+            .create_conditional_edge(decide.clone(), branch_a.clone(), r#"(= result "a")"#)
+            .unwrap();
+        graph
+            .create_conditional_edge(decide.clone(), branch_b.clone(), r#"(= result "b")"#)
+            .unwrap();
+        (root, decide, branch_a, branch_b)
+    };
+
+    let orchestrator = Orchestrator::for_test(
+        Arc::new(Mutex::new(CausalChain::new().unwrap())),
+        intent_graph.clone(),
+        marketplace,
+        Arc::new(PlanArchive::new()),
+    );
+    for (intent_id, capability) in [
+        (&decide, "test.decide"),
+        (&branch_a, "test.branch-a"),
+        (&branch_b, "test.branch-b"),
+    ] {
+        let plan = Plan::new_rtfs(
+            format!("(call :{} {{}})", capability),
+            vec![intent_id.clone()],
+        );
+        orchestrator.store_plan(&plan).unwrap();
+    }
+    let kernel = GovernanceKernel::new(Arc::new(orchestrator), intent_graph, HashMap::new());
+
+    let result = kernel
+        .execute_intent_graph_governed(&root, &RuntimeContext::full())
+        .await
+        .unwrap();
+
+    assert!(result.success, "graph failed: {:?}", result.value);
+    assert_eq!(
+        *calls.lock().unwrap(),
+        vec!["test.decide".to_string(), "test.branch-a".to_string()]
+    );
+    let summary = result.value.to_string();
+    assert!(
+        summary.contains(&format!("{}: skipped", branch_b)),
+        "{}",
+        summary
+    );
+}
@@ -0,0 +1,83 @@
+use ccos::capabilities::register_default_capabilities;
+use ccos::capabilities::registry::CapabilityRegistry;
+use ccos::capability_marketplace::CapabilityMarketplace;
+use ccos::causal_chain::CausalChain;
+use ccos::governance_kernel::GovernanceKernel;
+use ccos::intent_graph::IntentGraph;
+use ccos::orchestrator::Orchestrator;
+use ccos::plan_archive::PlanArchive;
+use ccos::types::{ActionType, Plan};
+use rtfs::ast::Keyword;
+use rtfs::runtime::error::RuntimeError;
+use rtfs::runtime::security::RuntimeContext;
+use rtfs::runtime::values::Value;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use tokio::sync::RwLock;
+
+/// Kernel over an empty intent graph, so plans reference no stored intent
+fn kernel_for(
+    causal_chain: Arc<Mutex<CausalChain>>,
+    marketplace: Arc<CapabilityMarketplace>,
+) -> GovernanceKernel {
+    let intent_graph = Arc::new(Mutex::new(IntentGraph::new().unwrap()));
+    let orchestrator = Arc::new(Orchestrator::for_test(
+        causal_chain,
+        intent_graph.clone(),
+        marketplace,
+        Arc::new(PlanArchive::new()),
+    ));
+    GovernanceKernel::new(orchestrator, intent_graph, HashMap::new())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn executes_standalone_plan_without_intent_graph() {
+    let causal_chain = Arc::new(Mutex::new(CausalChain::new().unwrap()));
+    let registry = Arc::new(RwLock::new(CapabilityRegistry::new()));
+    let marketplace = Arc::new(CapabilityMarketplace::new(registry));
+    register_default_capabilities(&marketplace).await.unwrap();
+
+    let kernel = kernel_for(causal_chain.clone(), marketplace);
+
+    // No intent is stored: the plan references none
+    let plan = Plan::new_rtfs(r#"(call :ccos.echo "hello")"#.to_string(), vec![]);
+    let plan_id = plan.plan_id.clone();
+    let result = kernel
+        .validate_and_execute(plan, &RuntimeContext::full())
+        .await
+        .unwrap();
+    assert!(result.success, "plan failed: {:?}", result.value);
+    assert_eq!(result.value, Value::String("hello".to_string()));
+
+    let chain = causal_chain.lock().unwrap();
+    let actions = chain.get_actions_for_plan(&plan_id);
+    assert!(actions
+        .iter()
+        .any(|a| a.action_type == ActionType::PlanStarted));
+    assert!(actions.iter().any(|a| {
+        a.action_type == ActionType::CapabilityCall
+            && a.function_name.as_deref() == Some("ccos.echo")
+    }));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn calling_a_missing_capability_fails_with_capability_not_found() {
+    let registry = Arc::new(RwLock::new(CapabilityRegistry::new()));
+    let kernel = kernel_for(
+        Arc::new(Mutex::new(CausalChain::new().unwrap())),
+        Arc::new(CapabilityMarketplace::new(registry)),
+    );
+
+    let plan = Plan::new_rtfs("(call :test.missing 1)".to_string(), vec![]);
+    let err = kernel
+        .validate_and_execute(plan, &RuntimeContext::full())
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.without_location(),
+        &RuntimeError::CapabilityNotFound {
+            capability_id: "test.missing".to_string()
+        }
+    );
+    assert_eq!(err.error_type(), Keyword::new("CapabilityNotFound"));
+}
@@ -0,0 +1,149 @@
+use ccos::capabilities::registry::CapabilityRegistry;
+use ccos::capability_marketplace::CapabilityMarketplace;
+use ccos::causal_chain::CausalChain;
+use ccos::governance_kernel::{ApprovalDecision, ConstitutionRule, GovernanceKernel, RuleAction};
+use ccos::intent_graph::IntentGraph;
+use ccos::orchestrator::Orchestrator;
+use ccos::plan_archive::PlanArchive;
+use ccos::types::Plan;
+use rtfs::runtime::error::RuntimeError;
+use rtfs::runtime::security::RuntimeContext;
+use rtfs::runtime::values::Value;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::RwLock;
+
+/// Kernel with a `test.sensitive` capability flagged as requiring human approval.
+async fn kernel_with_sensitive_capability(calls: Arc<AtomicUsize>) -> Arc<GovernanceKernel> {
+    let causal_chain = Arc::new(Mutex::new(CausalChain::new().unwrap()));
+    let intent_graph = Arc::new(Mutex::new(IntentGraph::new().unwrap()));
+    let registry = Arc::new(RwLock::new(CapabilityRegistry::new()));
+    let marketplace = Arc::new(CapabilityMarketplace::new(registry));
+    marketplace
+        .register_local_capability(
+            "test.sensitive".to_string(),
+            "Sensitive".to_string(),
+            "Counts invocations".to_string(),
+            Arc::new(move |_args: &Value| {
+                calls.fetch_add(1, Ordering::SeqCst);
+                Ok(Value::String("done".to_string()))
+            }),
+        )
+        .await
+        .unwrap();
+
+    let orchestrator = Arc::new(Orchestrator::for_test(
+        causal_chain,
+        intent_graph.clone(),
+        marketplace,
+        Arc::new(PlanArchive::new()),
+    ));
+    let kernel = GovernanceKernel::new(orchestrator, intent_graph, HashMap::new());
+    kernel.add_rule(ConstitutionRule {
+        id: "sensitive-needs-approval".to_string(),
+        description: "Sensitive capabilities need a human".to_string(),
+        match_pattern: "test.sensitive".to_string(),
+        action: RuleAction::RequireHumanApproval,
+    });
+    Arc::new(kernel)
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn flagged_plan_blocks_until_approved() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let kernel = kernel_with_sensitive_capability(calls.clone()).await;
+    let mut approvals = kernel.subscribe_plan_approvals();
+
+    let plan = Plan::new_rtfs("(call :test.sensitive 1)".to_string(), vec![]);
+    let plan_id = plan.plan_id.clone();
+    let runner = Arc::clone(&kernel);
+    let execution = tokio::spawn(async move {
+        runner
+            .validate_and_execute(plan, &RuntimeContext::full())
+            .await
+    });
+
+    let pending = tokio::time::timeout(Duration::from_secs(5), approvals.recv())
+        .await
+        .expect("approval request")
+        .expect("approval channel open");
+    assert_eq!(pending.plan_id, plan_id);
+    assert_eq!(pending.capabilities, vec!["test.sensitive".to_string()]);
+    assert_eq!(
+        pending.rule_ids,
+        vec!["sensitive-needs-approval".to_string()]
+    );
+
+    // Still waiting: nothing executed yet
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    assert!(!execution.is_finished());
+    assert_eq!(calls.load(Ordering::SeqCst), 0);
+
+    kernel
+        .resolve_plan_approval(&pending.approval_id, ApprovalDecision::Approve)
+        .unwrap();
+    let result = execution.await.unwrap().unwrap();
+    assert!(result.success, "plan failed: {:?}", result.value);
+    assert_eq!(result.value, Value::String("done".to_string()));
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn rejected_plan_never_executes() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let kernel = kernel_with_sensitive_capability(calls.clone()).await;
+    let mut approvals = kernel.subscribe_plan_approvals();
+
+    let plan = Plan::new_rtfs("(call :test.sensitive 1)".to_string(), vec![]);
+    let runner = Arc::clone(&kernel);
+    let execution = tokio::spawn(async move {
+        runner
+            .validate_and_execute(plan, &RuntimeContext::full())
+            .await
+    });
+
+    let pending = approvals.recv().await.unwrap();
+    kernel
+        .resolve_plan_approval(
+            &pending.approval_id,
+            ApprovalDecision::Reject("not today".to_string()),
+        )
+        .unwrap();
+
+    let err = execution.await.unwrap().unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("Plan rejected by approver: not today"));
+    assert_eq!(calls.load(Ordering::SeqCst), 0);
+    assert!(kernel
+        .resolve_plan_approval(&pending.approval_id, ApprovalDecision::Approve)
+        .is_err());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn unanswered_plan_is_rejected_after_the_approval_timeout() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let kernel = kernel_with_sensitive_capability(calls.clone()).await;
+    kernel.set_plan_approval_timeout(Duration::from_millis(50));
+    let mut approvals = kernel.subscribe_plan_approvals();
+
+    let plan = Plan::new_rtfs("(call :test.sensitive 1)".to_string(), vec![]);
+    let err = kernel
+        .validate_and_execute(plan, &RuntimeContext::full())
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(err, RuntimeError::Timeout { timeout_ms: 50, .. }),
+        "unexpected error: {}",
+        err
+    );
+    assert_eq!(calls.load(Ordering::SeqCst), 0);
+
+    // The timed-out request can no longer be resolved
+    let pending = approvals.recv().await.unwrap();
+    assert!(kernel
+        .resolve_plan_approval(&pending.approval_id, ApprovalDecision::Approve)
+        .is_err());
+}
@@ -0,0 +1,161 @@
+use ccos::capabilities::registry::CapabilityRegistry;
+use ccos::capability_marketplace::CapabilityMarketplace;
+use ccos::causal_chain::CausalChain;
+use ccos::governance_kernel::{GovernanceKernel, PlanLimitViolation, PlanLimitsPolicy, PlanShape};
+use ccos::intent_graph::IntentGraph;
+use ccos::orchestrator::Orchestrator;
+use ccos::plan_archive::PlanArchive;
+use ccos::types::{Plan, PlanBody};
+use rtfs::ast::{Keyword, MapKey};
+use rtfs::runtime::error::RuntimeError;
+use rtfs::runtime::security::RuntimeContext;
+use rtfs::runtime::values::Value;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use tokio::sync::RwLock;
+
+async fn kernel_with_echo(limits: PlanLimitsPolicy) -> GovernanceKernel {
+    let causal_chain = Arc::new(Mutex::new(CausalChain::new().unwrap()));
+    let intent_graph = Arc::new(Mutex::new(IntentGraph::new().unwrap()));
+    let registry = Arc::new(RwLock::new(CapabilityRegistry::new()));
+    let marketplace = Arc::new(CapabilityMarketplace::new(registry));
+    marketplace
+        .register_local_capability(
+            "test.echo".to_string(),
+            "Echo".to_string(),
+            "Returns its input".to_string(),
+            Arc::new(|args: &Value| Ok(args.clone())),
+        )
+        .await
+        .unwrap();
+
+    let orchestrator = Arc::new(Orchestrator::for_test(
+        causal_chain,
+        intent_graph.clone(),
+        marketplace,
+        Arc::new(PlanArchive::new()),
+    ));
+    let kernel = GovernanceKernel::new(orchestrator, intent_graph, HashMap::new());
+    kernel.set_plan_limits(limits);
+    kernel
+}
+
+fn three_call_plan() -> Plan {
+    Plan::new_rtfs(
+        "(do (call :test.echo 1) (call :test.echo 2) (call :test.echo 3))".to_string(),
+        vec![],
+    )
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn plan_under_capability_limit_executes() {
+    let kernel = kernel_with_echo(PlanLimitsPolicy {
+        max_capability_calls: 3,
+        max_nesting_depth: 64,
+    })
+    .await;
+
+    let plan = three_call_plan();
+    assert_eq!(
+        kernel.check_plan_limits(&plan),
+        Ok(Some(PlanShape {
+            capability_calls: 3,
+            nesting_depth: 3,
+        }))
+    );
+
+    let result = kernel
+        .validate_and_execute(plan, &RuntimeContext::full())
+        .await
+        .unwrap();
+    assert!(result.success, "plan failed: {:?}", result.value);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn plan_over_capability_limit_is_rejected() {
+    let kernel = kernel_with_echo(PlanLimitsPolicy {
+        max_capability_calls: 2,
+        max_nesting_depth: 64,
+    })
+    .await;
+
+    let plan = three_call_plan();
+    assert_eq!(
+        kernel.check_plan_limits(&plan),
+        Err(PlanLimitViolation::TooManyCapabilityCalls { count: 3, limit: 2 })
+    );
+
+    let err = kernel
+        .validate_and_execute(plan, &RuntimeContext::full())
+        .await
+        .unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("Plan rejected by governance limits: plan contains 3 capability calls"),
+        "unexpected error: {}",
+        err
+    );
+    assert_eq!(err.error_type(), Keyword::new("PlanLimitViolation"));
+    let RuntimeError::ApplicationError {
+        data: Some(Value::Map(data)),
+        ..
+    } = &err
+    else {
+        panic!("violation carries no data: {:?}", err);
+    };
+    assert_eq!(
+        data.get(&MapKey::Keyword(Keyword::new("kind"))),
+        Some(&Value::Keyword(Keyword::new("too-many-capability-calls")))
+    );
+    assert_eq!(
+        data.get(&MapKey::Keyword(Keyword::new("count"))),
+        Some(&Value::Integer(3))
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn source_body_is_measured_like_an_rtfs_body() {
+    let kernel = kernel_with_echo(PlanLimitsPolicy {
+        max_capability_calls: 2,
+        max_nesting_depth: 64,
+    })
+    .await;
+
+    let mut plan = three_call_plan();
+    plan.body = PlanBody::Source(
+        "(do (call :test.echo 1) (call :test.echo 2) (call :test.echo 3))".to_string(),
+    );
+    assert_eq!(
+        kernel.check_plan_limits(&plan),
+        Err(PlanLimitViolation::TooManyCapabilityCalls { count: 3, limit: 2 })
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn unparseable_body_is_rejected() {
+    let kernel = kernel_with_echo(PlanLimitsPolicy::default()).await;
+
+    let plan = Plan::new_rtfs("(do (call :test.echo 1)".to_string(), vec![]);
+    assert!(matches!(
+        kernel.check_plan_limits(&plan),
+        Err(PlanLimitViolation::UnparseableBody { .. })
+    ));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn plan_over_nesting_limit_is_rejected() {
+    let kernel = kernel_with_echo(PlanLimitsPolicy {
+        max_capability_calls: 100,
+        max_nesting_depth: 4,
+    })
+    .await;
+
+    let plan = Plan::new_rtfs(
+        "(let [x (if true (+ 1 (* 2 (- 3 1))) 0)] x)".to_string(),
+        vec![],
+    );
+    assert!(matches!(
+        kernel.check_plan_limits(&plan),
+        Err(PlanLimitViolation::NestingTooDeep { limit: 4, .. })
+    ));
+}
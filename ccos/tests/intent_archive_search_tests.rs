@@ -0,0 +1,70 @@
+use ccos::intent_archive::{create_in_memory_intent_archive, IntentArchive};
+use ccos::types::{Intent, IntentStatus};
+use std::collections::HashMap;
+
+fn intent(id: &str, goal: &str, created_at: u64) -> Intent {
+    Intent {
+        intent_id: id.to_string(),
+        name: None,
+        original_request: goal.to_string(),
+        goal: goal.to_string(),
+        constraints: HashMap::new(),
+        preferences: HashMap::new(),
+        success_criteria: None,
+        status: IntentStatus::Completed,
+        created_at,
+        updated_at: created_at,
+        metadata: HashMap::new(),
+    }
+}
+
+fn ids(intents: &[std::sync::Arc<Intent>]) -> Vec<&str> {
+    intents.iter().map(|i| i.intent_id.as_str()).collect()
+}
+
+#[test]
+fn search_goals_ranks_matches_by_relevance() {
+    let archive = create_in_memory_intent_archive();
+    for entry in [
+        intent("backup-db", "Nightly database backup to S3", 1),
+        intent("restore-db", "Restore the Database from last BACKUP", 2),
+        intent("weather", "Fetch the weather forecast for Paris", 3),
+        intent("db-report", "Email a database usage report", 4),
+    ] {
+        archive.archive_intent(entry).unwrap();
+    }
+
+    // Two tokens beat one; among equal scores the goal containing the whole
+    // query wins, then the newest intent
+    let results = archive.search_goals("database BACKUP");
+    assert_eq!(ids(&results), vec!["backup-db", "restore-db", "db-report"]);
+
+    let results = archive.search_goals("weather");
+    assert_eq!(ids(&results), vec!["weather"]);
+
+    assert!(archive.search_goals("kubernetes").is_empty());
+    assert!(archive.search_goals("  ").is_empty());
+}
+
+#[test]
+fn search_goals_page_applies_offset_and_limit() {
+    let archive = create_in_memory_intent_archive();
+    for n in 0..5u64 {
+        archive
+            .archive_intent(intent(
+                &format!("deploy-{}", n),
+                &format!("Deploy service {}", n),
+                n,
+            ))
+            .unwrap();
+    }
+
+    let all = archive.search_goals("deploy");
+    assert_eq!(
+        ids(&all),
+        vec!["deploy-4", "deploy-3", "deploy-2", "deploy-1", "deploy-0"]
+    );
+    let page = archive.search_goals_page("deploy", 1, 2);
+    assert_eq!(ids(&page), vec!["deploy-3", "deploy-2"]);
+    assert!(archive.search_goals_page("deploy", 5, 2).is_empty());
+}
@@ -0,0 +1,62 @@
+use ccos::intent_graph::{IntentBuilderExt, IntentGraph};
+use ccos::types::{EdgeType, StorableIntent};
+use rtfs::builders::{IntentBuilder, Priority};
+
+#[test]
+fn build_with_edges_maps_relations_to_edge_types() {
+    let (intent, edges) = IntentBuilder::new("deploy")
+        .with_goal("Deploy the service")
+        .with_priority(Priority::High)
+        .depends_on("build")
+        .subgoal_of("release")
+        .conflicts_with("freeze")
+        .build_with_edges()
+        .unwrap();
+
+    assert_eq!(intent.goal, "Deploy the service");
+    assert_eq!(intent.name.as_deref(), Some("deploy"));
+    assert_eq!(intent.parent_intent.as_deref(), Some("release"));
+    assert!(intent
+        .rtfs_intent_source
+        .contains("depends-on: [\"build\"]"));
+
+    let summary: Vec<_> = edges
+        .iter()
+        .map(|e| (e.from.as_str(), e.to.as_str(), e.edge_type.clone()))
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            (intent.intent_id.as_str(), "build", EdgeType::DependsOn),
+            (intent.intent_id.as_str(), "release", EdgeType::IsSubgoalOf),
+            (intent.intent_id.as_str(), "freeze", EdgeType::ConflictsWith),
+        ]
+    );
+}
+
+#[test]
+fn build_with_edges_requires_a_goal() {
+    assert!(IntentBuilder::new("no-goal")
+        .depends_on("other")
+        .build_with_edges()
+        .is_err());
+}
+
+#[test]
+fn built_subgraph_loads_into_the_intent_graph() {
+    let release = StorableIntent::new("Ship the release".to_string());
+    let (deploy, edges) = IntentBuilder::new("deploy")
+        .with_goal("Deploy the service")
+        .subgoal_of(&release.intent_id)
+        .build_with_edges()
+        .unwrap();
+    let (release_id, deploy_id) = (release.intent_id.clone(), deploy.intent_id.clone());
+
+    let mut graph = IntentGraph::new().unwrap();
+    graph.load_subgraph(vec![release, deploy], edges).unwrap();
+
+    let stored = graph.get_edges_for_intent(&deploy_id);
+    assert_eq!(stored.len(), 1);
+    assert_eq!(stored[0].to, release_id);
+    assert_eq!(stored[0].edge_type, EdgeType::IsSubgoalOf);
+}
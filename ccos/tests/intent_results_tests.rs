@@ -0,0 +1,75 @@
+use ccos::capabilities::registry::CapabilityRegistry;
+use ccos::capability_marketplace::CapabilityMarketplace;
+use ccos::causal_chain::CausalChain;
+use ccos::governance_kernel::GovernanceKernel;
+use ccos::intent_graph::IntentGraph;
+use ccos::orchestrator::Orchestrator;
+use ccos::plan_archive::PlanArchive;
+use ccos::types::{EdgeType, Plan, StorableIntent};
+use rtfs::runtime::security::RuntimeContext;
+use rtfs::runtime::values::Value;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use tokio::sync::RwLock;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn dependent_intent_computes_on_its_dependency_result() {
+    let intent_graph = Arc::new(Mutex::new(IntentGraph::new().unwrap()));
+    let marketplace = Arc::new(CapabilityMarketplace::new(Arc::new(RwLock::new(
+        CapabilityRegistry::new(),
+    ))));
+
+    let (root, producer, consumer) = {
+        let mut graph = intent_graph.lock().unwrap();
+        let mut add = |goal: &str| {
+            let intent = StorableIntent::new(goal.to_string());
+            let id = intent.intent_id.clone();
+            graph.store_intent(intent).unwrap();
+            id
+        };
+        let (root, producer, consumer) = (add("Report"), add("Count"), add("Double"));
+        // The consumer is listed first so it has to wait for its dependency
+        for child in [&consumer, &producer] {
+            graph
+                .create_edge(child.clone(), root.clone(), EdgeType::IsSubgoalOf)
+                .unwrap();
+        }
+        graph
+            .create_edge(consumer.clone(), producer.clone(), EdgeType::DependsOn)
+            .unwrap();
+        (root, producer, consumer)
+    };
+
+    let orchestrator = Orchestrator::for_test(
+        Arc::new(Mutex::new(CausalChain::new().unwrap())),
+        intent_graph.clone(),
+        marketplace,
+        Arc::new(PlanArchive::new()),
+    );
+    for (intent_id, body) in [
+        (&producer, "(+ 10 11)".to_string()),
+        (
+            &consumer,
+            format!("(* 2 (get results :results/{}))", producer),
+        ),
+    ] {
+        orchestrator
+            .store_plan(&Plan::new_rtfs(body, vec![intent_id.clone()]))
+            .unwrap();
+    }
+    let kernel = GovernanceKernel::new(Arc::new(orchestrator), intent_graph, HashMap::new());
+
+    let result = kernel
+        .execute_intent_graph_governed(&root, &RuntimeContext::full())
+        .await
+        .unwrap();
+
+    assert!(result.success, "graph failed: {:?}", result.value);
+    assert_eq!(
+        result.value,
+        Value::String(format!(
+            "Governed orchestration of 2 plans: {}: 21, {}: 42",
+            producer, consumer
+        ))
+    );
+}
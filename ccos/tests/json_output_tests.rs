@@ -0,0 +1,75 @@
+use ccos::capabilities::registry::CapabilityRegistry;
+use ccos::capability_marketplace::types::{
+    ApprovalStatus, CapabilityManifest, HttpCapability, ProviderType,
+};
+use ccos::capability_marketplace::CapabilityMarketplace;
+use rtfs::ast::{Keyword, MapKey, TypeExpr};
+use rtfs::runtime::values::{Value, ValueMap};
+use std::sync::Arc;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use tokio::sync::RwLock;
+
+/// Minimal HTTP server answering every request with `body` as JSON.
+async fn spawn_json_server(body: &'static str) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        while let Ok((mut socket, _)) = listener.accept().await {
+            tokio::spawn(async move {
+                let mut buf = [0u8; 4096];
+                let _ = socket.read(&mut buf).await;
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+                let _ = socket.write_all(response.as_bytes()).await;
+                let _ = socket.shutdown().await;
+            });
+        }
+    });
+    format!("http://{}", addr)
+}
+
+#[tokio::test]
+async fn http_json_response_is_parsed_into_keyword_map() {
+    let url = spawn_json_server(r#"{"city": "Paris", "temperature": 21}"#).await;
+
+    let marketplace = CapabilityMarketplace::new(Arc::new(RwLock::new(CapabilityRegistry::new())));
+    let mut manifest = CapabilityManifest::new(
+        "test.weather".to_string(),
+        "Weather".to_string(),
+        "Current weather as JSON".to_string(),
+        ProviderType::Http(HttpCapability {
+            base_url: url.clone(),
+            auth_token: None,
+            timeout_ms: 5000,
+        }),
+        "1.0.0".to_string(),
+    )
+    .with_json_output();
+    manifest.output_schema =
+        Some(TypeExpr::from_str("[:map [:city :string] [:temperature :int]]").unwrap());
+    manifest.approval_status = ApprovalStatus::Approved;
+    marketplace
+        .register_capability_manifest(manifest)
+        .await
+        .unwrap();
+
+    let args =
+        Value::List(vec![Value::String(url.clone()), Value::String("GET".to_string())].into());
+    let result = marketplace
+        .execute_capability("test.weather", &args)
+        .await
+        .unwrap();
+
+    let kw = |name: &str| MapKey::Keyword(Keyword(name.into()));
+    assert_eq!(
+        result,
+        Value::Map(ValueMap::from_iter([
+            (kw("city"), Value::String("Paris".to_string())),
+            (kw("temperature"), Value::Integer(21)),
+        ]))
+    );
+}
@@ -0,0 +1,94 @@
+use ccos::capabilities::registry::CapabilityRegistry;
+use ccos::capability_marketplace::CapabilityMarketplace;
+use ccos::causal_chain::CausalChain;
+use ccos::event_sink::{LifecycleEvent, LifecycleEventSink};
+use ccos::governance_kernel::GovernanceKernel;
+use ccos::intent_graph::IntentGraph;
+use ccos::orchestrator::Orchestrator;
+use ccos::plan_archive::PlanArchive;
+use ccos::types::{Plan, StorableIntent};
+use rtfs::runtime::security::RuntimeContext;
+use rtfs::runtime::values::Value;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use tokio::sync::RwLock;
+
+#[derive(Debug, Default)]
+struct RecordingSink {
+    events: Mutex<Vec<LifecycleEvent>>,
+}
+
+impl LifecycleEventSink for RecordingSink {
+    fn on_event(&self, event: &LifecycleEvent) {
+        self.events.lock().unwrap().push(event.clone());
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn simple_plan_emits_full_lifecycle_sequence() {
+    let causal_chain = Arc::new(Mutex::new(CausalChain::new().unwrap()));
+    let intent_graph = Arc::new(Mutex::new(IntentGraph::new().unwrap()));
+    let registry = Arc::new(RwLock::new(CapabilityRegistry::new()));
+    let marketplace = Arc::new(CapabilityMarketplace::new(registry));
+    marketplace
+        .register_local_capability(
+            "test.echo".to_string(),
+            "Echo".to_string(),
+            "Returns its input".to_string(),
+            Arc::new(|args: &Value| Ok(args.clone())),
+        )
+        .await
+        .unwrap();
+
+    let orchestrator = Arc::new(Orchestrator::for_test(
+        causal_chain,
+        intent_graph.clone(),
+        marketplace,
+        Arc::new(PlanArchive::new()),
+    ));
+    let sink = Arc::new(RecordingSink::default());
+    orchestrator.set_lifecycle_sink(sink.clone());
+    let kernel = GovernanceKernel::new(orchestrator.clone(), intent_graph, HashMap::new());
+
+    let intent = StorableIntent::new("Echo a value".to_string());
+    let intent_id = intent.intent_id.clone();
+    orchestrator.store_intent(intent).unwrap();
+
+    let plan = Plan::new_rtfs("(call :test.echo 1)".to_string(), vec![intent_id.clone()]);
+    let plan_id = plan.plan_id.clone();
+    let result = kernel
+        .validate_and_execute(plan, &RuntimeContext::full())
+        .await
+        .unwrap();
+    assert!(result.success, "plan failed: {:?}", result.value);
+
+    let events = sink.events.lock().unwrap().clone();
+    assert_eq!(
+        events,
+        vec![
+            LifecycleEvent::IntentCreated {
+                intent_id: intent_id.clone()
+            },
+            LifecycleEvent::PlanProposed {
+                plan_id: plan_id.clone(),
+                intent_ids: vec![intent_id],
+            },
+            LifecycleEvent::PlanApproved {
+                plan_id: plan_id.clone()
+            },
+            LifecycleEvent::CapabilityStarted {
+                plan_id: plan_id.clone(),
+                capability_id: "test.echo".to_string(),
+            },
+            LifecycleEvent::CapabilityEnded {
+                plan_id: plan_id.clone(),
+                capability_id: "test.echo".to_string(),
+                success: true,
+            },
+            LifecycleEvent::ExecutionCompleted {
+                plan_id,
+                success: true,
+            },
+        ]
+    );
+}
@@ -0,0 +1,99 @@
+//! Token/cost accounting of LLM calls through `remote_models::MeteredModel`.
+
+use ccos::delegation::ModelProvider;
+use ccos::observability::llm_usage::{LlmUsageTracker, ModelPricing, TokenUsage};
+use ccos::remote_models::MeteredModel;
+
+/// Provider reporting fixed token counts for every call
+#[derive(Debug)]
+struct CountingModel {
+    usage: TokenUsage,
+}
+
+impl ModelProvider for CountingModel {
+    fn id(&self) -> &'static str {
+        "counting-model"
+    }
+
+    fn infer(&self, prompt: &str) -> Result<String, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(format!("echo: {}", prompt))
+    }
+
+    fn infer_with_usage(
+        &self,
+        prompt: &str,
+    ) -> Result<(String, TokenUsage), Box<dyn std::error::Error + Send + Sync>> {
+        Ok((self.infer(prompt)?, self.usage))
+    }
+}
+
+/// Provider that only implements `infer` and so reports no token counts
+#[derive(Debug)]
+struct OpaqueModel;
+
+impl ModelProvider for OpaqueModel {
+    fn id(&self) -> &'static str {
+        "opaque-model"
+    }
+
+    fn infer(&self, _prompt: &str) -> Result<String, Box<dyn std::error::Error + Send + Sync>> {
+        Ok("ok".to_string())
+    }
+}
+
+#[test]
+fn metered_calls_accumulate_tokens_and_cost() {
+    let tracker = LlmUsageTracker::new();
+    tracker.set_pricing("counting-model", ModelPricing::new(0.01, 0.03));
+    let model = MeteredModel::new(
+        CountingModel {
+            usage: TokenUsage::new(1000, 500),
+        },
+        tracker.clone(),
+    );
+
+    assert_eq!(model.infer("first").unwrap(), "echo: first");
+    let (_, usage) = model.infer_with_usage("second").unwrap();
+    assert_eq!(usage, TokenUsage::new(1000, 500));
+
+    let totals = tracker.totals();
+    assert_eq!(totals.calls, 2);
+    assert_eq!(totals.calls_without_usage, 0);
+    assert_eq!(totals.prompt_tokens, 2000);
+    assert_eq!(totals.completion_tokens, 1000);
+    // 2 * (1k prompt * $0.01 + 0.5k completion * $0.03)
+    assert!((totals.estimated_cost_usd - 0.05).abs() < 1e-9);
+
+    let text = tracker.render_prometheus_text();
+    assert!(text.contains("ccos_llm_calls_total{model=\"counting-model\"} 2"));
+    assert!(text.contains("ccos_llm_prompt_tokens_total{model=\"counting-model\"} 2000"));
+}
+
+#[test]
+fn providers_without_token_info_are_still_counted() {
+    let tracker = LlmUsageTracker::new();
+    let opaque = MeteredModel::new(OpaqueModel, tracker.clone());
+    let counting = MeteredModel::new(
+        CountingModel {
+            usage: TokenUsage {
+                prompt_tokens: Some(12),
+                completion_tokens: None,
+            },
+        },
+        tracker.clone(),
+    );
+
+    opaque.infer("a").unwrap();
+    counting.infer("b").unwrap();
+
+    let by_model = tracker.by_model();
+    assert_eq!(by_model["opaque-model"].calls, 1);
+    assert_eq!(by_model["opaque-model"].calls_without_usage, 1);
+    assert_eq!(by_model["counting-model"].prompt_tokens, 12);
+    assert_eq!(by_model["counting-model"].completion_tokens, 0);
+
+    let totals = tracker.totals();
+    assert_eq!(totals.calls, 2);
+    // Neither model is priced
+    assert_eq!(totals.estimated_cost_usd, 0.0);
+}
@@ -0,0 +1,105 @@
+use ccos::capabilities::registry::CapabilityRegistry;
+use ccos::capability_marketplace::types::{
+    ApprovalStatus, CapabilityManifest, LocalCapability, ProviderType,
+};
+use ccos::capability_marketplace::CapabilityMarketplace;
+use ccos::causal_chain::CausalChain;
+use ccos::governance_kernel::GovernanceKernel;
+use ccos::intent_graph::IntentGraph;
+use ccos::orchestrator::Orchestrator;
+use ccos::plan_archive::PlanArchive;
+use ccos::types::{ExecutionResult, Plan};
+use rtfs::ast::{Keyword, MapKey};
+use rtfs::runtime::error::RuntimeError;
+use rtfs::runtime::security::RuntimeContext;
+use rtfs::runtime::values::Value;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use tokio::sync::RwLock;
+
+/// Formats a forecast from the named `:city` and `:units` parameters
+fn forecast_manifest() -> CapabilityManifest {
+    let mut manifest = CapabilityManifest::new(
+        "test.forecast".to_string(),
+        "test.forecast".to_string(),
+        "Formats a forecast request".to_string(),
+        ProviderType::Local(LocalCapability {
+            handler: Arc::new(|args: &Value| {
+                let params = match args {
+                    Value::Vector(items) if items.len() == 1 => items[0].clone(),
+                    other => other.clone(),
+                };
+                let Value::Map(params) = params else {
+                    return Err(RuntimeError::Generic(format!(
+                        "expected named params, got {}",
+                        params
+                    )));
+                };
+                let get = |name: &str| {
+                    params
+                        .get(&MapKey::Keyword(Keyword(name.into())))
+                        .and_then(Value::as_string)
+                        .unwrap_or("?")
+                        .to_string()
+                };
+                Ok(Value::String(format!(
+                    "{} in {}",
+                    get("city"),
+                    get("units")
+                )))
+            }),
+        }),
+        "1.0.0".to_string(),
+    )
+    .with_named_params(["city", "units"]);
+    manifest.approval_status = ApprovalStatus::Approved;
+    manifest
+}
+
+async fn run_plan(source: &str) -> Result<ExecutionResult, RuntimeError> {
+    let causal_chain = Arc::new(Mutex::new(CausalChain::new().unwrap()));
+    let intent_graph = Arc::new(Mutex::new(IntentGraph::new().unwrap()));
+    let registry = Arc::new(RwLock::new(CapabilityRegistry::new()));
+    let marketplace = Arc::new(CapabilityMarketplace::new(registry));
+    marketplace
+        .register_capability_manifest(forecast_manifest())
+        .await
+        .unwrap();
+
+    let orchestrator = Arc::new(Orchestrator::for_test(
+        causal_chain,
+        intent_graph.clone(),
+        marketplace,
+        Arc::new(PlanArchive::new()),
+    ));
+    let kernel = GovernanceKernel::new(orchestrator, intent_graph, HashMap::new());
+    kernel
+        .validate_and_execute(
+            Plan::new_rtfs(source.to_string(), vec![]),
+            &RuntimeContext::full(),
+        )
+        .await
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn named_params_capability_receives_map_by_name() {
+    let result = run_plan(r#"(call :test.forecast {:units "metric" :city "Paris"})"#)
+        .await
+        .unwrap();
+    assert!(result.success, "plan failed: {:?}", result.value);
+    assert_eq!(result.value, Value::String("Paris in metric".to_string()));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn named_params_capability_rejects_unknown_and_positional_args() {
+    for source in [
+        r#"(call :test.forecast {:city "Paris" :unit "metric"})"#,
+        r#"(call :test.forecast "Paris" "metric")"#,
+    ] {
+        let failed = match run_plan(source).await {
+            Ok(result) => !result.success,
+            Err(_) => true,
+        };
+        assert!(failed, "expected {} to fail", source);
+    }
+}
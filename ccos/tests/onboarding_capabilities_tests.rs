@@ -20,7 +20,7 @@ use ccos::secrets::SecretStore;
 use ccos::skills::onboarding_capabilities::register_onboarding_capabilities;
 use ccos::working_memory::{InMemoryJsonlBackend, WorkingMemory};
 use rtfs::ast::MapKey;
-use rtfs::runtime::values::Value;
+use rtfs::runtime::values::{Value, ValueMap};
 use tokio::sync::RwLock;
 
 /// Helper to create Value from JSON-like structure
@@ -42,7 +42,7 @@ fn json_to_value(json: serde_json::Value) -> Value {
             Value::Vector(arr.into_iter().map(json_to_value).collect())
         }
         serde_json::Value::Object(obj) => {
-            let mut map = HashMap::new();
+            let mut map = ValueMap::new();
             for (k, v) in obj {
                 map.insert(MapKey::String(k), json_to_value(v));
             }
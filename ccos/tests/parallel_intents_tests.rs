@@ -0,0 +1,104 @@
+use ccos::capabilities::registry::CapabilityRegistry;
+use ccos::capability_marketplace::types::{
+    ApprovalStatus, CapabilityManifest, LocalCapability, ProviderType,
+};
+use ccos::capability_marketplace::CapabilityMarketplace;
+use ccos::causal_chain::CausalChain;
+use ccos::governance_kernel::GovernanceKernel;
+use ccos::intent_graph::IntentGraph;
+use ccos::orchestrator::Orchestrator;
+use ccos::plan_archive::PlanArchive;
+use ccos::types::{EdgeType, Plan, StorableIntent};
+use rtfs::runtime::security::RuntimeContext;
+use rtfs::runtime::values::Value;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::sync::RwLock;
+
+const LATENCY: Duration = Duration::from_millis(500);
+
+async fn register_slow(marketplace: &CapabilityMarketplace, id: &str, result: i64) {
+    let mut manifest = CapabilityManifest::new(
+        id.to_string(),
+        id.to_string(),
+        "Sleeps before answering".to_string(),
+        ProviderType::Local(LocalCapability {
+            handler: Arc::new(move |_args: &Value| {
+                std::thread::sleep(LATENCY);
+                Ok(Value::Integer(result))
+            }),
+        }),
+        "1.0.0".to_string(),
+    );
+    manifest.approval_status = ApprovalStatus::Approved;
+    marketplace
+        .register_capability_manifest(manifest)
+        .await
+        .unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn independent_slow_intents_complete_in_parallel() {
+    let intent_graph = Arc::new(Mutex::new(IntentGraph::new().unwrap()));
+    let marketplace = Arc::new(CapabilityMarketplace::new(Arc::new(RwLock::new(
+        CapabilityRegistry::new(),
+    ))));
+    register_slow(&marketplace, "test.slow-a", 1).await;
+    register_slow(&marketplace, "test.slow-b", 2).await;
+
+    let (root, slow_a, slow_b) = {
+        let mut graph = intent_graph.lock().unwrap();
+        let mut add = |goal: &str| {
+            let intent = StorableIntent::new(goal.to_string());
+            let id = intent.intent_id.clone();
+            graph.store_intent(intent).unwrap();
+            id
+        };
+        let (root, slow_a, slow_b) = (add("Gather"), add("Fetch A"), add("Fetch B"));
+        for child in [&slow_a, &slow_b] {
+            graph
+                .create_edge(child.clone(), root.clone(), EdgeType::IsSubgoalOf)
+                .unwrap();
+        }
+        (root, slow_a, slow_b)
+    };
+
+    let orchestrator = Orchestrator::for_test(
+        Arc::new(Mutex::new(CausalChain::new().unwrap())),
+        intent_graph.clone(),
+        marketplace,
+        Arc::new(PlanArchive::new()),
+    )
+    .with_max_parallelism(2);
+    for (intent_id, capability) in [(&slow_a, "test.slow-a"), (&slow_b, "test.slow-b")] {
+        let plan = Plan::new_rtfs(
+            format!("(call :{} {{}})", capability),
+            vec![intent_id.clone()],
+        );
+        orchestrator.store_plan(&plan).unwrap();
+    }
+    let kernel = GovernanceKernel::new(Arc::new(orchestrator), intent_graph, HashMap::new());
+
+    let started = Instant::now();
+    let result = kernel
+        .execute_intent_graph_governed(&root, &RuntimeContext::full())
+        .await
+        .unwrap();
+    let elapsed = started.elapsed();
+
+    assert!(result.success, "graph failed: {:?}", result.value);
+    assert!(
+        elapsed < LATENCY * 2 - Duration::from_millis(150),
+        "intents ran sequentially: {:?}",
+        elapsed
+    );
+    // Results merge in child order regardless of which finished first
+    assert_eq!(
+        result.value,
+        Value::String(format!(
+            "Governed orchestration of 2 plans: {}: 1, {}: 2",
+            slow_a, slow_b
+        ))
+    );
+}
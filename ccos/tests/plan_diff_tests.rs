@@ -0,0 +1,67 @@
+use ccos::rtfs_bridge::plan_diff::FormChange;
+use ccos::types::Plan;
+
+#[test]
+fn diff_pinpoints_the_replaced_capability_call() {
+    let old = Plan::new_rtfs(
+        r#"(do
+  (let [data (call :http.fetch {:url "https://example.com"})]
+    data)
+  (call :ccos.echo {:message "fetched"})
+  (call :storage.put {:key "report" :value "x"}))"#
+            .to_string(),
+        vec![],
+    );
+    // Same plan, reformatted, with the storage call swapped for a publish call
+    let new = Plan::new_rtfs(
+        r#"(do (let [data (call :http.fetch {:url "https://example.com"})] data)
+   (call :ccos.echo {:message "fetched"})
+   (call :slack.post {:channel "reports" :value "x"}))"#
+            .to_string(),
+        vec![],
+    );
+
+    let diff = old.diff(&new);
+
+    assert_eq!(diff.changes.len(), 1, "unexpected diff: {:#?}", diff);
+    match &diff.changes[0] {
+        FormChange::Changed {
+            old_index,
+            new_index,
+            old_form,
+            new_form,
+        } => {
+            assert_eq!((*old_index, *new_index), (2, 2));
+            assert!(old_form.contains(":storage.put"), "{}", old_form);
+            assert!(new_form.contains(":slack.post"), "{}", new_form);
+        }
+        other => panic!("expected a changed form, got {:?}", other),
+    }
+    assert_eq!(diff.added_capabilities, vec!["slack.post"]);
+    assert_eq!(diff.removed_capabilities, vec!["storage.put"]);
+}
+
+#[test]
+fn diff_reports_added_and_removed_forms() {
+    let old = Plan::new_rtfs(
+        "(do (call :a.one {:x 1}) (call :b.two {:x 2}))".to_string(),
+        vec![],
+    );
+    let new = Plan::new_rtfs(
+        "(do (call :b.two {:x 2}) (call :c.three {:x 3}))".to_string(),
+        vec![],
+    );
+
+    let diff = old.diff(&new);
+
+    assert_eq!(diff.changes.len(), 2, "unexpected diff: {:#?}", diff);
+    assert!(matches!(
+        &diff.changes[0],
+        FormChange::Removed { old_index: 0, form } if form.contains(":a.one")
+    ));
+    assert!(matches!(
+        &diff.changes[1],
+        FormChange::Added { new_index: 1, form } if form.contains(":c.three")
+    ));
+    assert!(old.diff(&old.clone()).is_empty());
+}
@@ -0,0 +1,67 @@
+use ccos::capabilities::registry::CapabilityRegistry;
+use ccos::capability_marketplace::CapabilityMarketplace;
+use ccos::causal_chain::CausalChain;
+use ccos::governance_kernel::GovernanceKernel;
+use ccos::intent_graph::IntentGraph;
+use ccos::orchestrator::Orchestrator;
+use ccos::plan_archive::PlanArchive;
+use ccos::types::{ExecutionResult, Plan, StorableIntent};
+use rtfs::ast::{Keyword, MapKey};
+use rtfs::runtime::security::RuntimeContext;
+use rtfs::runtime::values::{Value, ValueMap};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use tokio::sync::RwLock;
+
+/// Runs a one-intent graph whose plan branches on the injected `:threshold`
+async fn classify(threshold: i64) -> ExecutionResult {
+    let intent_graph = Arc::new(Mutex::new(IntentGraph::new().unwrap()));
+    let marketplace = Arc::new(CapabilityMarketplace::new(Arc::new(RwLock::new(
+        CapabilityRegistry::new(),
+    ))));
+
+    let root = {
+        let intent = StorableIntent::new("Classify the load".to_string());
+        let id = intent.intent_id.clone();
+        intent_graph.lock().unwrap().store_intent(intent).unwrap();
+        id
+    };
+
+    let orchestrator = Orchestrator::for_test(
+        Arc::new(Mutex::new(CausalChain::new().unwrap())),
+        intent_graph.clone(),
+        marketplace,
+        Arc::new(PlanArchive::new()),
+    );
+    orchestrator
+        .store_plan(&Plan::new_rtfs(
+            r#"(if (> (get inputs :threshold) 10) "high" "low")"#.to_string(),
+            vec![root.clone()],
+        ))
+        .unwrap();
+    let kernel = GovernanceKernel::new(Arc::new(orchestrator), intent_graph, HashMap::new());
+
+    let inputs = ValueMap::from_iter([(
+        MapKey::Keyword(Keyword("threshold".into())),
+        Value::Integer(threshold),
+    )]);
+    kernel
+        .execute_intent_graph_governed_with_inputs(&root, &RuntimeContext::full(), &inputs)
+        .await
+        .unwrap()
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn plan_branches_on_injected_input() {
+    for (threshold, expected) in [(20, "high"), (5, "low")] {
+        let result = classify(threshold).await;
+        assert!(result.success, "graph failed: {:?}", result.value);
+        assert_eq!(
+            result.value,
+            Value::String(format!(
+                "Governed orchestration of 0 plans: root: \"{}\"",
+                expected
+            ))
+        );
+    }
+}
@@ -0,0 +1,201 @@
+use ccos::capabilities::plan_ops::register_plan_ops_capabilities;
+use ccos::capabilities::registry::CapabilityRegistry;
+use ccos::capability_marketplace::CapabilityMarketplace;
+use ccos::causal_chain::CausalChain;
+use ccos::governance_kernel::{GovernanceKernel, PlanLimitsPolicy};
+use ccos::intent_graph::IntentGraph;
+use ccos::orchestrator::Orchestrator;
+use ccos::plan_archive::PlanArchive;
+use ccos::types::{Plan, StorableIntent};
+use rtfs::runtime::security::RuntimeContext;
+use rtfs::runtime::values::Value;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::RwLock;
+
+/// Kernel whose marketplace offers `ccos.plan.invoke` and a `test.echo` capability,
+/// plus a stored intent for parent plans
+async fn setup(max_depth: usize) -> (Arc<GovernanceKernel>, Arc<Orchestrator>, String) {
+    let intent_graph = Arc::new(Mutex::new(IntentGraph::new().unwrap()));
+    let marketplace = Arc::new(CapabilityMarketplace::new(Arc::new(RwLock::new(
+        CapabilityRegistry::new(),
+    ))));
+    marketplace
+        .register_local_capability(
+            "test.echo".to_string(),
+            "Echo".to_string(),
+            "Returns its input after a short pause".to_string(),
+            Arc::new(|args: &Value| {
+                std::thread::sleep(Duration::from_millis(100));
+                Ok(args.clone())
+            }),
+        )
+        .await
+        .unwrap();
+    let intent = StorableIntent::new("Compose plans".to_string());
+    let intent_id = intent.intent_id.clone();
+    intent_graph.lock().unwrap().store_intent(intent).unwrap();
+
+    let orchestrator = Arc::new(Orchestrator::for_test(
+        Arc::new(Mutex::new(CausalChain::new().unwrap())),
+        intent_graph.clone(),
+        marketplace.clone(),
+        Arc::new(PlanArchive::new()),
+    ));
+    let kernel = Arc::new(GovernanceKernel::new(
+        orchestrator.clone(),
+        intent_graph,
+        HashMap::new(),
+    ));
+    register_plan_ops_capabilities(marketplace, &orchestrator, &kernel, max_depth)
+        .await
+        .unwrap();
+    (kernel, orchestrator, intent_id)
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn parent_plan_uses_child_plan_result() {
+    let (kernel, orchestrator, intent_id) = setup(4).await;
+    let child = Plan::new_rtfs("(+ x 1)".to_string(), vec![]);
+    orchestrator.store_plan(&child).unwrap();
+
+    let parent = Plan::new_rtfs(
+        format!(
+            "(* 2 (call :ccos.plan.invoke {{:plan-id \"{}\" :args {{:x 20}}}}))",
+            child.plan_id
+        ),
+        vec![intent_id],
+    );
+    let result = kernel
+        .validate_and_execute(parent, &RuntimeContext::full())
+        .await
+        .unwrap();
+
+    assert!(result.success, "parent failed: {:?}", result.value);
+    assert_eq!(result.value, Value::Integer(42));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn inline_child_plan_runs_with_args() {
+    let (kernel, _orchestrator, intent_id) = setup(4).await;
+    let parent = Plan::new_rtfs(
+        "(call :ccos.plan.invoke {:rtfs \"(str greeting \\\", world\\\")\" :args {:greeting \"hello\"}})"
+            .to_string(),
+        vec![intent_id],
+    );
+    let result = kernel
+        .validate_and_execute(parent, &RuntimeContext::full())
+        .await
+        .unwrap();
+
+    assert!(result.success, "parent failed: {:?}", result.value);
+    assert_eq!(result.value, Value::String("hello, world".to_string()));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn self_invoking_plan_stops_at_max_depth() {
+    let (kernel, orchestrator, intent_id) = setup(3).await;
+    let mut recursive = Plan::new_rtfs(
+        "(call :ccos.plan.invoke {:plan-id \"recursive-plan\"})".to_string(),
+        vec![intent_id],
+    );
+    recursive.plan_id = "recursive-plan".to_string();
+    orchestrator.store_plan(&recursive).unwrap();
+
+    let outcome = kernel
+        .validate_and_execute(recursive, &RuntimeContext::full())
+        .await;
+
+    let message = match outcome {
+        Ok(result) => {
+            assert!(!result.success, "recursion should not succeed");
+            format!("{:?}", result)
+        }
+        Err(err) => err.to_string(),
+    };
+    assert!(
+        message.contains("maximum plan nesting depth 3 exceeded"),
+        "unexpected outcome: {}",
+        message
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn concurrent_call_chains_are_counted_separately() {
+    let (kernel, _orchestrator, intent_id) = setup(1).await;
+
+    // Each parent nests one child; run more of them at once than the depth limit
+    let parents = (0..4).map(|i| {
+        let kernel = Arc::clone(&kernel);
+        let parent = Plan::new_rtfs(
+            format!(
+                "(call :ccos.plan.invoke {{:rtfs \"(call :test.echo n)\" :args {{:n {}}}}})",
+                i
+            ),
+            vec![intent_id.clone()],
+        );
+        tokio::spawn(async move {
+            kernel
+                .validate_and_execute(parent, &RuntimeContext::full())
+                .await
+        })
+    });
+    for parent in futures::future::join_all(parents).await {
+        let result = parent.unwrap().unwrap();
+        assert!(result.success, "parent failed: {:?}", result.value);
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn child_plan_inherits_the_callers_security_context() {
+    let (kernel, _orchestrator, intent_id) = setup(4).await;
+    let parent = Plan::new_rtfs(
+        "(call :ccos.plan.invoke {:rtfs \"(call :test.echo 1)\"})".to_string(),
+        vec![intent_id],
+    );
+    let caller = RuntimeContext::controlled(vec!["ccos.plan.invoke".to_string()]);
+
+    let message = match kernel.validate_and_execute(parent, &caller).await {
+        Ok(result) => {
+            assert!(!result.success, "child escaped the caller's context");
+            format!("{:?}", result)
+        }
+        Err(err) => err.to_string(),
+    };
+    assert!(
+        message.contains("test.echo"),
+        "unexpected outcome: {}",
+        message
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn child_plan_is_validated_by_the_governance_kernel() {
+    let (kernel, _orchestrator, intent_id) = setup(4).await;
+    kernel.set_plan_limits(PlanLimitsPolicy {
+        max_capability_calls: 1,
+        max_nesting_depth: 64,
+    });
+    let parent = Plan::new_rtfs(
+        "(call :ccos.plan.invoke {:rtfs \"(do (call :test.echo 1) (call :test.echo 2))\"})"
+            .to_string(),
+        vec![intent_id],
+    );
+
+    let message = match kernel
+        .validate_and_execute(parent, &RuntimeContext::full())
+        .await
+    {
+        Ok(result) => {
+            assert!(!result.success, "child should be rejected");
+            format!("{:?}", result)
+        }
+        Err(err) => err.to_string(),
+    };
+    assert!(
+        message.contains("Plan rejected by governance limits"),
+        "unexpected outcome: {}",
+        message
+    );
+}
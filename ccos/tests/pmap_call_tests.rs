@@ -0,0 +1,56 @@
+use ccos::capabilities::registry::CapabilityRegistry;
+use ccos::capability_marketplace::CapabilityMarketplace;
+use ccos::causal_chain::CausalChain;
+use ccos::host::RuntimeHost;
+use rtfs::parser::parse_expression;
+use rtfs::runtime::evaluator::Evaluator;
+use rtfs::runtime::execution_outcome::ExecutionOutcome;
+use rtfs::runtime::module_runtime::ModuleRegistry;
+use rtfs::runtime::security::RuntimeContext;
+use rtfs::runtime::values::Value;
+use std::sync::{Arc, Mutex};
+use tokio::sync::RwLock;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn pmap_call_workers_run_capabilities_inside_the_tokio_runtime() {
+    let registry = Arc::new(RwLock::new(CapabilityRegistry::new()));
+    let marketplace = Arc::new(CapabilityMarketplace::new(registry));
+    // Stands in for HTTP/MCP providers, which need Tokio's reactor
+    marketplace
+        .register_local_capability(
+            "test.in_tokio".to_string(),
+            "In Tokio".to_string(),
+            "Reports whether it runs inside a Tokio runtime".to_string(),
+            Arc::new(|_| {
+                Ok(Value::Boolean(
+                    tokio::runtime::Handle::try_current().is_ok(),
+                ))
+            }),
+        )
+        .await
+        .unwrap();
+
+    let host = Arc::new(RuntimeHost::new(
+        Arc::new(Mutex::new(CausalChain::new().unwrap())),
+        marketplace,
+        RuntimeContext::full(),
+    ));
+    host.set_execution_context(
+        "pmap-plan".to_string(),
+        vec!["pmap-intent".to_string()],
+        "root-action".to_string(),
+    );
+    let evaluator = Evaluator::new_with_defaults(
+        Arc::new(ModuleRegistry::new()),
+        host,
+        rtfs::compiler::expander::MacroExpander::default(),
+    );
+
+    let expr = parse_expression("(pmap-call :test.in_tokio [1 2 3 4] 4)").unwrap();
+    // Plan evaluation blocks its thread, as it does under the orchestrator
+    let outcome = tokio::task::block_in_place(|| evaluator.evaluate(&expr)).unwrap();
+    let ExecutionOutcome::Complete(result) = outcome else {
+        panic!("unexpected host yield");
+    };
+    assert_eq!(result, Value::Vector(vec![Value::Boolean(true); 4].into()));
+}
@@ -3,7 +3,7 @@ use ccos::synthesis::primitives::{
     executor::RestrictedRtfsExecutor, PrimitiveContext, PrimitiveRegistry, PrimitiveTemplateId,
 };
 use rtfs::ast::{Keyword, MapKey, MapTypeEntry, PrimitiveType, TypeExpr};
-use rtfs::runtime::values::Value;
+use rtfs::runtime::values::{Value, ValueMap};
 use serde_json::json;
 
 #[test]
@@ -106,32 +106,33 @@ fn filter_primitive_executes_in_restricted_runtime() {
 
     let executor = RestrictedRtfsExecutor::new();
 
-    let mut issue_rtfs = std::collections::HashMap::new();
+    let mut issue_rtfs = ValueMap::new();
     issue_rtfs.insert(
-        MapKey::Keyword(Keyword("title".to_string())),
+        MapKey::Keyword(Keyword("title".into())),
         Value::String("Learning RTFS primitives".to_string()),
     );
     issue_rtfs.insert(
-        MapKey::Keyword(Keyword("body".to_string())),
+        MapKey::Keyword(Keyword("body".into())),
         Value::String("All about schema-aware primitives".to_string()),
     );
 
-    let mut unrelated_issue = std::collections::HashMap::new();
+    let mut unrelated_issue = ValueMap::new();
     unrelated_issue.insert(
-        MapKey::Keyword(Keyword("title".to_string())),
+        MapKey::Keyword(Keyword("title".into())),
         Value::String("Weekend plans".to_string()),
     );
     unrelated_issue.insert(
-        MapKey::Keyword(Keyword("body".to_string())),
+        MapKey::Keyword(Keyword("body".into())),
         Value::String("Discuss hiking".to_string()),
     );
 
-    let issues_value = Value::Vector(vec![Value::Map(issue_rtfs), Value::Map(unrelated_issue)]);
+    let issues_value =
+        Value::Vector(vec![Value::Map(issue_rtfs), Value::Map(unrelated_issue)].into());
 
-    let mut input_map = std::collections::HashMap::new();
-    input_map.insert(MapKey::Keyword(Keyword("issues".to_string())), issues_value);
+    let mut input_map = ValueMap::new();
+    input_map.insert(MapKey::Keyword(Keyword("issues".into())), issues_value);
     input_map.insert(
-        MapKey::Keyword(Keyword("topic".to_string())),
+        MapKey::Keyword(Keyword("topic".into())),
         Value::String("rtfs".to_string()),
     );
 
@@ -141,7 +142,7 @@ fn filter_primitive_executes_in_restricted_runtime() {
 
     let filtered_items = match result {
         Value::Map(map) => map
-            .get(&MapKey::Keyword(Keyword("filtered".to_string())))
+            .get(&MapKey::Keyword(Keyword("filtered".into())))
             .cloned()
             .expect("filtered key present"),
         _ => panic!("Expected map output"),
@@ -156,7 +157,7 @@ fn filter_primitive_executes_in_restricted_runtime() {
     match &vector[0] {
         Value::Map(map) => {
             let title = map
-                .get(&MapKey::Keyword(Keyword("title".to_string())))
+                .get(&MapKey::Keyword(Keyword("title".into())))
                 .and_then(|v| match v {
                     Value::String(s) => Some(s.clone()),
                     _ => None,
@@ -300,7 +301,7 @@ fn vector_of_map_with_fields(fields: Vec<(&str, TypeExpr)>) -> TypeExpr {
         entries: fields
             .into_iter()
             .map(|(name, ty)| MapTypeEntry {
-                key: Keyword(name.trim_start_matches(':').to_string()),
+                key: Keyword(name.trim_start_matches(':').into()),
                 value_type: Box::new(ty),
                 optional: false,
             })
@@ -398,22 +399,22 @@ fn reduce_primitive_executes_in_restricted_runtime() {
 
     let executor = RestrictedRtfsExecutor::new();
 
-    let mut item_one = std::collections::HashMap::new();
+    let mut item_one = ValueMap::new();
     item_one.insert(
-        MapKey::Keyword(Keyword("estimate".to_string())),
+        MapKey::Keyword(Keyword("estimate".into())),
         Value::Integer(3),
     );
 
-    let mut item_two = std::collections::HashMap::new();
+    let mut item_two = ValueMap::new();
     item_two.insert(
-        MapKey::Keyword(Keyword("estimate".to_string())),
+        MapKey::Keyword(Keyword("estimate".into())),
         Value::Integer(5),
     );
 
-    let items_value = Value::Vector(vec![Value::Map(item_one), Value::Map(item_two)]);
+    let items_value = Value::Vector(vec![Value::Map(item_one), Value::Map(item_two)].into());
 
-    let mut input_map = std::collections::HashMap::new();
-    input_map.insert(MapKey::Keyword(Keyword("items".to_string())), items_value);
+    let mut input_map = ValueMap::new();
+    input_map.insert(MapKey::Keyword(Keyword("items".into())), items_value);
 
     let result = executor
         .evaluate(&primitive.rtfs_code, Value::Map(input_map))
@@ -421,7 +422,7 @@ fn reduce_primitive_executes_in_restricted_runtime() {
 
     let total_value = match result {
         Value::Map(map) => map
-            .get(&MapKey::Keyword(Keyword("total".to_string())))
+            .get(&MapKey::Keyword(Keyword("total".into())))
             .cloned()
             .expect("total key present"),
         _ => panic!("expected map result"),
@@ -601,7 +602,7 @@ fn context_from_type_schemas_populates_binding_map() {
     let manifest_input = TypeExpr::Map {
         entries: vec![
             MapTypeEntry {
-                key: Keyword("issues".to_string()),
+                key: Keyword("issues".into()),
                 value_type: Box::new(vector_of_map_with_fields(vec![(
                     ":estimate",
                     TypeExpr::Primitive(PrimitiveType::Int),
@@ -609,7 +610,7 @@ fn context_from_type_schemas_populates_binding_map() {
                 optional: false,
             },
             MapTypeEntry {
-                key: Keyword("notes".to_string()),
+                key: Keyword("notes".into()),
                 value_type: Box::new(TypeExpr::Primitive(PrimitiveType::String)),
                 optional: true,
             },
@@ -619,7 +620,7 @@ fn context_from_type_schemas_populates_binding_map() {
 
     let manifest_output = TypeExpr::Map {
         entries: vec![MapTypeEntry {
-            key: Keyword("total".to_string()),
+            key: Keyword("total".into()),
             value_type: Box::new(TypeExpr::Primitive(PrimitiveType::Int)),
             optional: false,
         }],
@@ -0,0 +1,105 @@
+use async_trait::async_trait;
+use ccos::capabilities::registry::CapabilityRegistry;
+use ccos::capability_marketplace::CapabilityMarketplace;
+use ccos::cognitive_engine::llm_provider::{LlmProviderInfo, ValidationResult};
+use ccos::cognitive_engine::{
+    CognitiveEngine, DelegatingCognitiveEngine, LlmProvider, PromptTemplates,
+};
+use ccos::intent_graph::IntentGraph;
+use ccos::types::{Plan, StorableIntent};
+use rtfs::runtime::error::RuntimeError;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use tokio::sync::RwLock;
+
+/// Records every prompt and answers with a one-intent graph
+struct RecordingProvider {
+    prompts: Arc<Mutex<Vec<String>>>,
+}
+
+#[async_trait]
+impl LlmProvider for RecordingProvider {
+    async fn generate_intent(
+        &self,
+        _prompt: &str,
+        _context: Option<HashMap<String, String>>,
+    ) -> Result<StorableIntent, RuntimeError> {
+        Err(RuntimeError::Generic("not used".to_string()))
+    }
+
+    async fn generate_plan(
+        &self,
+        _intent: &StorableIntent,
+        _context: Option<HashMap<String, String>>,
+    ) -> Result<Plan, RuntimeError> {
+        Err(RuntimeError::Generic("not used".to_string()))
+    }
+
+    async fn validate_plan(&self, _plan_content: &str) -> Result<ValidationResult, RuntimeError> {
+        Err(RuntimeError::Generic("not used".to_string()))
+    }
+
+    async fn generate_text(&self, prompt: &str) -> Result<String, RuntimeError> {
+        self.prompts.lock().unwrap().push(prompt.to_string());
+        Ok(r#"(do (intent "backup" :goal "Back up the photos"))"#.to_string())
+    }
+
+    fn get_info(&self) -> LlmProviderInfo {
+        LlmProviderInfo {
+            name: "RecordingProvider".to_string(),
+            version: "test".to_string(),
+            model: "test".to_string(),
+            capabilities: vec![],
+        }
+    }
+}
+
+#[test]
+fn templates_missing_their_placeholder_are_rejected() {
+    let error = PromptTemplates::default()
+        .with_graph_generation("Build a graph for the goal")
+        .unwrap_err();
+    assert!(error.to_string().contains("{goal}"), "{}", error);
+    assert!(PromptTemplates::default()
+        .with_plan_generation("Plan for {goal}")
+        .is_err());
+    assert!(PromptTemplates::default()
+        .with_delegation_analysis("Delegate {intent}?")
+        .is_ok());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn graph_generation_uses_the_overridden_template() {
+    let prompts = Arc::new(Mutex::new(Vec::new()));
+    let marketplace = Arc::new(CapabilityMarketplace::new(Arc::new(RwLock::new(
+        CapabilityRegistry::new(),
+    ))));
+    let intent_graph = Arc::new(Mutex::new(IntentGraph::new().unwrap()));
+    let mut engine = DelegatingCognitiveEngine::for_test(
+        Box::new(RecordingProvider {
+            prompts: prompts.clone(),
+        }),
+        marketplace,
+        intent_graph,
+    );
+    engine
+        .set_prompt_templates(
+            PromptTemplates::default()
+                .with_graph_generation("[terse model] Emit an RTFS (do ...) graph for: {goal}")
+                .unwrap(),
+        )
+        .unwrap();
+
+    engine
+        .natural_language_to_graph("Back up my photos every night")
+        .await
+        .unwrap();
+
+    assert_eq!(
+        *prompts.lock().unwrap(),
+        vec![
+            "[terse model] Emit an RTFS (do ...) graph for: Back up my photos every night"
+                .to_string()
+        ]
+    );
+}
@@ -0,0 +1,128 @@
+use ccos::capabilities::registry::CapabilityRegistry;
+use ccos::capability_marketplace::types::{
+    ApprovalStatus, CapabilityManifest, LocalCapability, ProviderType,
+};
+use ccos::capability_marketplace::CapabilityMarketplace;
+use ccos::causal_chain::CausalChain;
+use ccos::governance_kernel::GovernanceKernel;
+use ccos::intent_graph::IntentGraph;
+use ccos::orchestrator::Orchestrator;
+use ccos::plan_archive::PlanArchive;
+use ccos::types::{ActionType, Plan};
+use rtfs::runtime::error::RuntimeError;
+use rtfs::runtime::security::RuntimeContext;
+use rtfs::runtime::values::Value;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use tokio::sync::RwLock;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn retried_attempts_share_the_call_correlation_id() {
+    let marketplace = Arc::new(CapabilityMarketplace::new(Arc::new(RwLock::new(
+        CapabilityRegistry::new(),
+    ))));
+    let calls = Arc::new(AtomicUsize::new(0));
+    let counter = calls.clone();
+    let mut manifest = CapabilityManifest::new(
+        "test.flaky".to_string(),
+        "test.flaky".to_string(),
+        "Fails on its first call".to_string(),
+        ProviderType::Local(LocalCapability {
+            handler: Arc::new(move |_args: &Value| {
+                if counter.fetch_add(1, Ordering::SeqCst) == 0 {
+                    Err(RuntimeError::Generic("transient failure".to_string()))
+                } else {
+                    Ok(Value::Integer(7))
+                }
+            }),
+        }),
+        "1.0.0".to_string(),
+    );
+    manifest.approval_status = ApprovalStatus::Approved;
+    marketplace
+        .register_capability_manifest(manifest)
+        .await
+        .unwrap();
+
+    let causal_chain = Arc::new(Mutex::new(CausalChain::new().unwrap()));
+    let intent_graph = Arc::new(Mutex::new(IntentGraph::new().unwrap()));
+    let orchestrator = Orchestrator::for_test(
+        causal_chain.clone(),
+        intent_graph.clone(),
+        marketplace,
+        Arc::new(PlanArchive::new()),
+    );
+    let kernel = GovernanceKernel::new(Arc::new(orchestrator), intent_graph, HashMap::new());
+
+    let plan = Plan::new_rtfs(
+        "^{:runtime.learning.retry {:max-retries 2 :backoff-ms 1}} (call :test.flaky {})"
+            .to_string(),
+        vec![],
+    );
+    let result = kernel
+        .validate_and_execute(plan, &RuntimeContext::full())
+        .await
+        .unwrap();
+    assert!(result.success, "plan failed: {:?}", result.value);
+    assert_eq!(result.value, Value::Integer(7));
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+    let chain = causal_chain.lock().unwrap();
+    let actions = chain.get_all_actions();
+    let of_flaky = |action_type: ActionType| {
+        actions
+            .iter()
+            .filter(|a| {
+                a.action_type == action_type && a.function_name.as_deref() == Some("test.flaky")
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let call = of_flaky(ActionType::CapabilityCall);
+    assert_eq!(call.len(), 1);
+    let correlation_id = Value::String(call[0].action_id.clone());
+    assert_eq!(
+        call[0].metadata.get("correlation_id"),
+        Some(&correlation_id)
+    );
+
+    let attempts = of_flaky(ActionType::HintApplied);
+    let outcomes: Vec<_> = attempts
+        .iter()
+        .map(|a| {
+            assert_eq!(a.metadata.get("correlation_id"), Some(&correlation_id));
+            assert_eq!(a.parent_action_id.as_ref(), Some(&call[0].action_id));
+            (
+                a.metadata.get("attempt").cloned(),
+                a.metadata.get("outcome").cloned(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        outcomes,
+        vec![
+            (
+                Some(Value::Integer(1)),
+                Some(Value::String("failed".to_string()))
+            ),
+            (
+                Some(Value::Integer(2)),
+                Some(Value::String("succeeded".to_string()))
+            ),
+        ]
+    );
+
+    // The final outcome is linked to the same call
+    let outcome = of_flaky(ActionType::CapabilityResult);
+    assert_eq!(outcome.len(), 1);
+    assert_eq!(
+        outcome[0].parent_action_id.as_ref(),
+        Some(&call[0].action_id)
+    );
+    assert_eq!(
+        outcome[0].metadata.get("correlation_id"),
+        Some(&correlation_id)
+    );
+    assert!(outcome[0].result.as_ref().unwrap().success);
+}
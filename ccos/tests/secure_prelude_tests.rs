@@ -0,0 +1,60 @@
+use ccos::capabilities::registry::CapabilityRegistry;
+use ccos::capability_marketplace::CapabilityMarketplace;
+use ccos::causal_chain::CausalChain;
+use ccos::governance_kernel::GovernanceKernel;
+use ccos::intent_graph::IntentGraph;
+use ccos::orchestrator::Orchestrator;
+use ccos::plan_archive::PlanArchive;
+use ccos::prelude::Prelude;
+use ccos::types::Plan;
+use rtfs::runtime::error::RuntimeError;
+use rtfs::runtime::security::RuntimeContext;
+use rtfs::runtime::values::Value;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use tokio::sync::RwLock;
+
+fn secure_kernel() -> GovernanceKernel {
+    let intent_graph = Arc::new(Mutex::new(IntentGraph::new().unwrap()));
+    let marketplace = Arc::new(CapabilityMarketplace::new(Arc::new(RwLock::new(
+        CapabilityRegistry::new(),
+    ))));
+    let orchestrator = Orchestrator::for_test(
+        Arc::new(Mutex::new(CausalChain::new().unwrap())),
+        intent_graph.clone(),
+        marketplace,
+        Arc::new(PlanArchive::new()),
+    )
+    .with_prelude(Prelude::secure_only());
+    GovernanceKernel::new(Arc::new(orchestrator), intent_graph, HashMap::new())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn http_fetch_is_unbound_under_secure_only_prelude() {
+    let plan = Plan::new_rtfs(
+        r#"(tool/http-fetch "https://example.com")"#.to_string(),
+        vec![],
+    );
+    let error = secure_kernel()
+        .validate_and_execute(plan, &RuntimeContext::full())
+        .await
+        .expect_err("effectful helper must not resolve");
+
+    assert!(
+        matches!(&error, RuntimeError::UndefinedSymbol { symbol, .. } if symbol.0 == "tool/http-fetch"),
+        "unexpected error: {:?}",
+        error
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn pure_stdlib_still_works_under_secure_only_prelude() {
+    let plan = Plan::new_rtfs("(str \"a\" (+ 1 2))".to_string(), vec![]);
+    let result = secure_kernel()
+        .validate_and_execute(plan, &RuntimeContext::full())
+        .await
+        .unwrap();
+
+    assert!(result.success);
+    assert_eq!(result.value, Value::String("a3".to_string()));
+}
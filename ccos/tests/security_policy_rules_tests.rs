@@ -0,0 +1,43 @@
+use ccos::security_policies::SecurityPolicies;
+use rtfs::runtime::security::RuntimeContext;
+
+#[test]
+fn namespace_rules_allow_and_deny_capabilities() {
+    let ctx = RuntimeContext::controlled(vec!["ccos.math.*".to_string()])
+        .with_capability_denies(&["tool.*"]);
+
+    assert!(ctx.is_capability_allowed("ccos.math.add"));
+    assert!(ctx.is_capability_allowed("ccos.math.divide"));
+    assert!(!ctx.is_capability_allowed("tool/http-fetch"));
+    assert!(!ctx.is_capability_allowed("tool.search"));
+    // A namespace only matches at a separator, and unlisted ids stay denied
+    assert!(!ctx.is_capability_allowed("ccos.mathematics"));
+    assert!(!ctx.is_capability_allowed("ccos.network.http-fetch"));
+}
+
+#[test]
+fn presets_grant_only_their_listed_capabilities() {
+    let ctx = SecurityPolicies::data_processing().with_capability_denies(&["tool.*"]);
+    assert!(ctx.is_capability_allowed("ccos.math.add"));
+    assert!(!ctx.is_capability_allowed("ccos.math.divide"));
+    assert!(!ctx.is_capability_allowed("tool.search"));
+}
+
+#[test]
+fn most_specific_capability_rule_wins() {
+    let mut ctx =
+        RuntimeContext::controlled(vec!["tool.*".to_string(), "tool.admin.reset".to_string()])
+            .with_capability_denies(&["tool.admin.*"]);
+    assert!(ctx.is_capability_allowed("tool.search"));
+    assert!(!ctx.is_capability_allowed("tool.admin.purge"));
+    // An exact allow beats a namespace deny
+    assert!(ctx.is_capability_allowed("tool.admin.reset"));
+    // Deny wins when equally specific
+    ctx.deny_capability("tool.admin.reset");
+    assert!(!ctx.is_capability_allowed("tool.admin.reset"));
+
+    // Deny rules also narrow full access
+    let full = RuntimeContext::full().with_capability_denies(&["tool.*"]);
+    assert!(full.is_capability_allowed("ccos.echo"));
+    assert!(!full.is_capability_allowed("tool/http-fetch"));
+}
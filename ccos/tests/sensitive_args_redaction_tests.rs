@@ -0,0 +1,134 @@
+use ccos::capabilities::registry::CapabilityRegistry;
+use ccos::capability_marketplace::types::{
+    ApprovalStatus, CapabilityManifest, LocalCapability, ProviderType,
+};
+use ccos::capability_marketplace::CapabilityMarketplace;
+use ccos::causal_chain::CausalChain;
+use ccos::governance_kernel::GovernanceKernel;
+use ccos::intent_graph::IntentGraph;
+use ccos::orchestrator::Orchestrator;
+use ccos::plan_archive::PlanArchive;
+use ccos::security_policies::{ArgRedactionPolicy, REDACTED};
+use ccos::types::{ActionType, Plan};
+use rtfs::ast::{Keyword, MapKey};
+use rtfs::runtime::security::RuntimeContext;
+use rtfs::runtime::values::{Value, ValueMap};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use tokio::sync::RwLock;
+
+fn kw(name: &str) -> MapKey {
+    MapKey::Keyword(Keyword(name.into()))
+}
+
+fn string(s: &str) -> Value {
+    Value::String(s.to_string())
+}
+
+#[test]
+fn policy_parses_positions_and_keys() {
+    let policy = ArgRedactionPolicy::parse("1, :api_key,token");
+    assert!(policy.positions.contains(&1));
+    assert!(policy.keys.contains("api_key"));
+    assert!(policy.keys.contains("token"));
+
+    let nested = Value::Map(ValueMap::from_iter([(
+        kw("auth"),
+        Value::Map(HashMap::from([(MapKey::String("token".into()), string("t"))]).into()),
+    )]));
+    let redacted = policy.redact_args(&[string("a"), string("b"), nested]);
+    assert_eq!(redacted[0], string("a"));
+    assert_eq!(redacted[1], string(REDACTED));
+    assert_eq!(
+        redacted[2],
+        Value::Map(ValueMap::from_iter([(
+            kw("auth"),
+            Value::Map(HashMap::from([(MapKey::String("token".into()), string(REDACTED))]).into()),
+        )]))
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn sensitive_args_are_redacted_in_causal_chain_only() {
+    let causal_chain = Arc::new(Mutex::new(CausalChain::new().unwrap()));
+    let intent_graph = Arc::new(Mutex::new(IntentGraph::new().unwrap()));
+    let registry = Arc::new(RwLock::new(CapabilityRegistry::new()));
+    let marketplace = Arc::new(CapabilityMarketplace::new(registry));
+
+    let received: Arc<Mutex<Vec<Value>>> = Arc::new(Mutex::new(Vec::new()));
+    let sink = received.clone();
+    let mut manifest = CapabilityManifest::new(
+        "test.login".to_string(),
+        "Login".to_string(),
+        "Records the arguments it receives".to_string(),
+        ProviderType::Local(LocalCapability {
+            handler: Arc::new(move |args: &Value| {
+                sink.lock().unwrap().push(args.clone());
+                Ok(string("ok"))
+            }),
+        }),
+        "1.0.0".to_string(),
+    )
+    .with_sensitive_args(["1", "api_key"]);
+    manifest.approval_status = ApprovalStatus::Approved;
+    marketplace
+        .register_capability_manifest(manifest)
+        .await
+        .unwrap();
+
+    let orchestrator = Arc::new(Orchestrator::for_test(
+        causal_chain.clone(),
+        intent_graph.clone(),
+        marketplace,
+        Arc::new(PlanArchive::new()),
+    ));
+    let kernel = GovernanceKernel::new(orchestrator, intent_graph, HashMap::new());
+
+    let plan = Plan::new_rtfs(
+        r#"(call :test.login "alice" "s3cret" {:api_key "k-123" :region "eu"})"#.to_string(),
+        vec![],
+    );
+    let result = kernel
+        .validate_and_execute(plan, &RuntimeContext::full())
+        .await
+        .unwrap();
+    assert!(result.success, "plan failed: {:?}", result.value);
+
+    // The capability saw the real values
+    let received = received.lock().unwrap().clone();
+    assert_eq!(received.len(), 1);
+    let debug = format!("{:?}", received[0]);
+    assert!(debug.contains("s3cret"), "capability args: {}", debug);
+    assert!(debug.contains("k-123"), "capability args: {}", debug);
+
+    // The recorded action only carries the placeholder
+    let chain = causal_chain.lock().unwrap();
+    let call = chain
+        .get_all_actions()
+        .iter()
+        .find(|a| {
+            a.action_type == ActionType::CapabilityCall
+                && a.function_name.as_deref() == Some("test.login")
+        })
+        .expect("capability call recorded");
+    let args = call.arguments.clone().unwrap();
+    assert_eq!(args[0], string("alice"));
+    assert_eq!(args[1], string(REDACTED));
+    assert_eq!(
+        args[2],
+        Value::Map(ValueMap::from_iter([
+            (kw("api_key"), string(REDACTED)),
+            (kw("region"), string("eu")),
+        ]))
+    );
+    // No capability-call record (including the result) leaks the secrets
+    let recorded: Vec<_> = chain
+        .get_all_actions()
+        .iter()
+        .filter(|a| a.function_name.as_deref() == Some("test.login"))
+        .collect();
+    assert!(!recorded.is_empty());
+    let recorded = format!("{:?}", recorded);
+    assert!(!recorded.contains("s3cret"));
+    assert!(!recorded.contains("k-123"));
+}
@@ -0,0 +1,26 @@
+use ccos::examples_common::test_ccos;
+use ccos::state_provider::{diff, snapshot};
+use ccos::types::StorableIntent;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn diff_reports_exactly_the_created_intent() {
+    let ccos = test_ccos().await.unwrap();
+    let graph = ccos.get_intent_graph();
+    let marketplace = ccos.get_capability_marketplace();
+
+    let before = snapshot(&graph, &marketplace).await.unwrap();
+    assert!(diff(&before, &before).is_empty());
+
+    let intent = StorableIntent::new("Summarize the quarterly report".to_string());
+    let intent_id = intent.intent_id.clone();
+    graph.lock().unwrap().store_intent(intent).unwrap();
+
+    let after = snapshot(&graph, &marketplace).await.unwrap();
+    let changes = diff(&before, &after);
+    assert_eq!(changes.added_intents, vec![intent_id]);
+    assert!(changes.removed_intents.is_empty());
+    assert!(changes.changed_intents.is_empty());
+    assert!(changes.added_capabilities.is_empty());
+    assert!(changes.removed_capabilities.is_empty());
+    assert!(changes.changed_capabilities.is_empty());
+}
@@ -56,7 +56,7 @@ fn test_a2a_discover_capability() {
 
     let result = provider.execute_capability(
         "ccos.a2a.discover",
-        &Value::Vector(vec![Value::String("*".to_string())]),
+        &Value::Vector(vec![Value::String("*".to_string())].into()),
         &exec_context,
     );
 
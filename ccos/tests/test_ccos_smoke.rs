@@ -0,0 +1,31 @@
+use ccos::cognitive_engine::CognitiveEngine;
+use ccos::examples_common::test_ccos;
+use ccos::types::Plan;
+use rtfs::runtime::security::RuntimeContext;
+use rtfs::runtime::values::Value;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_ccos_generates_a_graph_and_runs_a_plan() {
+    let ccos = test_ccos().await.expect("in-memory CCOS should start");
+
+    let engine = ccos
+        .get_delegating_engine()
+        .expect("test CCOS has an arbiter");
+    let root_id = engine
+        .natural_language_to_graph("Say hi and add two numbers")
+        .await
+        .expect("stub arbiter should produce a graph");
+
+    let graph = ccos.get_intent_graph();
+    let intents = graph.lock().unwrap().get_active_intents();
+    assert_eq!(intents.len(), 3);
+    assert!(intents.iter().any(|i| i.intent_id == root_id));
+
+    let plan = Plan::new_rtfs("(+ 1 2)".to_string(), vec![root_id]);
+    let result = ccos
+        .validate_and_execute_plan(plan, &RuntimeContext::full())
+        .await
+        .expect("trivial plan should execute");
+    assert!(result.success);
+    assert_eq!(result.value, Value::Integer(3));
+}
@@ -37,7 +37,7 @@ fn json_capabilities_parse_and_stringify() {
             (MapKey::String("ok".to_string()), Value::Boolean(true)),
             (
                 MapKey::String("items".to_string()),
-                Value::Vector(vec![Value::Integer(1), Value::Integer(2)]),
+                Value::Vector(vec![Value::Integer(1), Value::Integer(2)].into()),
             ),
         ]
         .into_iter()
@@ -90,7 +90,7 @@ fn json_capabilities_legacy_aliases_still_work() {
     let serialized = registry
         .execute_capability_with_microvm(
             "ccos.data.serialize-json",
-            vec![Value::Vector(vec![Value::Integer(1)])],
+            vec![Value::Vector(vec![Value::Integer(1)].into())],
             Some(&context),
         )
         .expect("serialize");
@@ -52,7 +52,7 @@ fn test_remote_rtfs_ping_capability() {
 
     let result = provider.execute_capability(
         "ccos.remote.ping",
-        &Value::Vector(vec![Value::String("http://localhost:8080".to_string())]),
+        &Value::Vector(vec![Value::String("http://localhost:8080".to_string())].into()),
         &exec_context,
     );
 
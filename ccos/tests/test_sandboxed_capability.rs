@@ -76,7 +76,7 @@ else:
         rtfs::ast::MapKey::String("message".to_string()),
         Value::String("hello from test".to_string()),
     );
-    let inputs = Value::Map(input_map);
+    let inputs = Value::Map(input_map.into());
 
     let metadata = HashMap::new();
     let context = ExecutionContext::new("test.sandboxed", &metadata, None);
@@ -154,7 +154,7 @@ else:
         rtfs::ast::MapKey::String("b".to_string()),
         Value::Integer(3),
     );
-    let inputs = Value::Map(input_map);
+    let inputs = Value::Map(input_map.into());
 
     let metadata = HashMap::new();
     let context = ExecutionContext::new("test.sandboxed", &metadata, None);
@@ -367,7 +367,7 @@ print(json.dumps({"ok": true}))
     );
 
     let exec_result = marketplace
-        .execute_capability("ccos.sandbox.python", &Value::Map(input_map))
+        .execute_capability("ccos.sandbox.python", &Value::Map(input_map.into()))
         .await;
 
     match exec_result {
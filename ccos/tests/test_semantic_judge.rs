@@ -107,7 +107,7 @@ async fn test_semantic_judge_blocks_bad_plan() {
     kernel.set_arbiter(arbiter);
 
     let mut plan = Plan::new_rtfs(
-        "call :system.read_file { path: \"/etc/passwd\" }".to_string(),
+        "(call :system.read_file {:path \"/etc/passwd\"})".to_string(),
         vec![],
     );
 
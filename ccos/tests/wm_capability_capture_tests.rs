@@ -0,0 +1,105 @@
+use ccos::capabilities::registry::CapabilityRegistry;
+use ccos::capability_marketplace::types::{
+    ApprovalStatus, CapabilityManifest, LocalCapability, ProviderType,
+};
+use ccos::capability_marketplace::CapabilityMarketplace;
+use ccos::causal_chain::CausalChain;
+use ccos::governance_kernel::GovernanceKernel;
+use ccos::intent_graph::IntentGraph;
+use ccos::orchestrator::Orchestrator;
+use ccos::plan_archive::PlanArchive;
+use ccos::types::Plan;
+use ccos::wm_integration::{CapabilityResultSink, CAPABILITY_RESULT_TAG};
+use ccos::working_memory::{InMemoryJsonlBackend, QueryParams, WorkingMemory};
+use rtfs::runtime::security::RuntimeContext;
+use rtfs::runtime::values::Value;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use tokio::sync::RwLock;
+
+fn echo_manifest(id: &str) -> CapabilityManifest {
+    let mut manifest = CapabilityManifest::new(
+        id.to_string(),
+        id.to_string(),
+        "Returns its input".to_string(),
+        ProviderType::Local(LocalCapability {
+            handler: Arc::new(|args: &Value| Ok(args.clone())),
+        }),
+        "1.0.0".to_string(),
+    );
+    manifest.approval_status = ApprovalStatus::Approved;
+    manifest
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn memorable_capability_results_land_in_working_memory() {
+    let causal_chain = Arc::new(Mutex::new(CausalChain::new().unwrap()));
+    let intent_graph = Arc::new(Mutex::new(IntentGraph::new().unwrap()));
+    let registry = Arc::new(RwLock::new(CapabilityRegistry::new()));
+    let marketplace = Arc::new(CapabilityMarketplace::new(registry));
+    marketplace
+        .register_capability_manifest(
+            echo_manifest("test.lookup").with_memory_capture(["geo", "lookup"]),
+        )
+        .await
+        .unwrap();
+    marketplace
+        .register_capability_manifest(echo_manifest("test.echo"))
+        .await
+        .unwrap();
+
+    let wm = Arc::new(Mutex::new(WorkingMemory::new(Box::new(
+        InMemoryJsonlBackend::new(None, Some(100), Some(100_000)),
+    ))));
+    causal_chain
+        .lock()
+        .unwrap()
+        .register_event_sink(Arc::new(CapabilityResultSink::new(
+            wm.clone(),
+            marketplace.clone(),
+        )));
+
+    let orchestrator = Arc::new(Orchestrator::for_test(
+        causal_chain,
+        intent_graph.clone(),
+        marketplace,
+        Arc::new(PlanArchive::new()),
+    ));
+    let kernel = GovernanceKernel::new(orchestrator, intent_graph, HashMap::new());
+    let plan = Plan::new_rtfs(
+        r#"(do (call :test.lookup "Paris") (call :test.echo "not kept"))"#.to_string(),
+        vec![],
+    );
+    let result = kernel
+        .validate_and_execute(plan, &RuntimeContext::full())
+        .await
+        .unwrap();
+    assert!(result.success, "plan failed: {:?}", result.value);
+
+    let wm = wm.lock().unwrap();
+    let captured = wm
+        .query(&QueryParams::with_tags([CAPABILITY_RESULT_TAG]))
+        .unwrap()
+        .entries;
+    assert_eq!(captured.len(), 1, "captured: {:?}", captured);
+    let entry = &captured[0];
+    assert_eq!(entry.title, "test.lookup");
+    assert!(entry.tags.contains("geo") && entry.tags.contains("lookup"));
+    assert!(entry.content.contains("Paris"));
+    assert!(entry.meta.extra.contains_key("args_digest"));
+    assert!(entry.meta.extra.contains_key("result_digest"));
+
+    // Recallable by capability id, and nothing was captured for the non-memorable call
+    assert_eq!(
+        wm.query(&QueryParams::with_tags(["test.lookup"]))
+            .unwrap()
+            .entries
+            .len(),
+        1
+    );
+    assert!(wm
+        .query(&QueryParams::with_tags(["test.echo"]))
+        .unwrap()
+        .entries
+        .is_empty());
+}
@@ -0,0 +1,75 @@
+use ccos::working_memory::{
+    QueryParams, WorkingMemoryConfig, WorkingMemoryEntry, WorkingMemoryMeta,
+};
+
+fn entry(id: &str, timestamp_s: u64, tags: &[&str]) -> WorkingMemoryEntry {
+    WorkingMemoryEntry {
+        id: id.to_string(),
+        title: format!("title-{}", id),
+        content: format!("content-{}", id),
+        tags: tags.iter().map(|t| t.to_string()).collect(),
+        timestamp_s,
+        approx_tokens: 4,
+        meta: WorkingMemoryMeta::default(),
+    }
+}
+
+fn entries_survive_restart(config: WorkingMemoryConfig) {
+    let query = QueryParams::with_tags(["wisdom"]).with_limit(Some(10));
+    let before = {
+        let mut wm = config.build().unwrap();
+        wm.append(entry("a", 100, &["wisdom"])).unwrap();
+        wm.append(entry("b", 200, &["wisdom", "x"])).unwrap();
+        wm.append(entry("c", 300, &["other"])).unwrap();
+        wm.flush().unwrap();
+        wm.query(&query).unwrap()
+    };
+
+    let wm = config.build().unwrap();
+    let after = wm.query(&query).unwrap();
+    let ids: Vec<_> = after.entries.iter().map(|e| e.id.as_str()).collect();
+    assert_eq!(ids, vec!["b", "a"]);
+    assert_eq!(after, before);
+    assert_eq!(
+        wm.get(&"c".to_string()).unwrap(),
+        Some(entry("c", 300, &["other"]))
+    );
+}
+
+#[test]
+fn file_backed_entries_survive_restart() {
+    let dir = tempfile::tempdir().unwrap();
+    entries_survive_restart(WorkingMemoryConfig::with_file_storage(
+        dir.path().join("wm.jsonl"),
+    ));
+}
+
+#[test]
+fn sqlite_backed_entries_survive_restart() {
+    let dir = tempfile::tempdir().unwrap();
+    entries_survive_restart(WorkingMemoryConfig::with_sqlite_storage(
+        dir.path().join("wm.db"),
+    ));
+}
+
+#[test]
+fn sqlite_reload_applies_budgets_to_the_oldest_entries() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("wm.db");
+    {
+        let mut wm = WorkingMemoryConfig::with_sqlite_storage(path.clone())
+            .build()
+            .unwrap();
+        for (i, id) in ["a", "b", "c"].iter().enumerate() {
+            wm.append(entry(id, 100 * (i as u64 + 1), &["w"])).unwrap();
+        }
+    }
+
+    let wm = WorkingMemoryConfig::with_sqlite_storage(path)
+        .with_budgets(Some(2), None)
+        .build()
+        .unwrap();
+    let result = wm.query(&QueryParams::default()).unwrap();
+    let ids: Vec<_> = result.entries.iter().map(|e| e.id.as_str()).collect();
+    assert_eq!(ids, vec!["c", "b"]);
+}
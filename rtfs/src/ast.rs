@@ -5,7 +5,7 @@ impl MapKey {
     }
 }
 use crate::runtime::error::RuntimeError;
-use crate::runtime::values::Value;
+use crate::runtime::values::{InternedStr, Value};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -30,11 +30,11 @@ pub enum Literal {
 
 #[derive(Debug, PartialEq, Clone, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 #[schemars(transparent)]
-pub struct Symbol(pub String);
+pub struct Symbol(pub InternedStr);
 
 impl Symbol {
     pub fn new(s: &str) -> Self {
-        Symbol(s.to_string())
+        Symbol(InternedStr::new(s))
     }
 }
 
@@ -46,11 +46,11 @@ impl std::fmt::Display for Symbol {
 
 #[derive(Debug, PartialEq, Clone, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 #[schemars(transparent)]
-pub struct Keyword(pub String);
+pub struct Keyword(pub InternedStr);
 
 impl Keyword {
     pub fn new(s: &str) -> Self {
-        Keyword(s.to_string())
+        Keyword(InternedStr::new(s))
     }
 }
 
@@ -290,7 +290,7 @@ impl TypeExpr {
                     ":never" => Ok(TypeExpr::Never),
                     _ => {
                         // For other types, treat as alias
-                        Ok(TypeExpr::Alias(Symbol(s.to_string())))
+                        Ok(TypeExpr::Alias(Symbol(s.into())))
                     }
                 }
             }
@@ -424,10 +424,10 @@ impl TypeExpr {
 
                 for entry in entries {
                     let key_name = entry.key.0.clone();
-                    properties.insert(key_name.clone(), entry.value_type.to_json()?);
+                    properties.insert(key_name.to_string(), entry.value_type.to_json()?);
 
                     if !entry.optional {
-                        required.push(serde_json::Value::String(key_name));
+                        required.push(serde_json::Value::String(key_name.to_string()));
                     }
                 }
 
@@ -598,6 +598,35 @@ pub struct LetBinding {
     pub value: Box<Expression>,
 }
 
+/// Where a form starts in the parsed source (1-based line and column).
+///
+/// Spans are informational only: they never take part in equality, so a parsed
+/// AST still compares equal to the same AST built by hand.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Span(Option<(usize, usize)>);
+
+impl Span {
+    pub fn new(line: usize, column: usize) -> Self {
+        Span(Some((line, column)))
+    }
+
+    /// Span of a form that did not come from source (built by hand or generated).
+    pub fn unknown() -> Self {
+        Span(None)
+    }
+
+    /// `(line, column)` of the form, if known.
+    pub fn position(&self) -> Option<(usize, usize)> {
+        self.0
+    }
+}
+
+impl PartialEq for Span {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
 // Represents the main expression types
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
@@ -616,6 +645,10 @@ pub enum Expression {
         callee: Box<Expression>, // Added this field
         #[validate(nested)]
         arguments: Vec<Expression>,
+        /// Position of the call in the source, when parsed from text
+        #[serde(skip)]
+        #[schemars(skip)]
+        span: Span,
     },
     If(#[validate] IfExpr),
     Let(#[validate] LetExpr),
@@ -655,7 +688,9 @@ impl Validate for Expression {
             Expression::Quasiquote(expr) => expr.validate(),
             Expression::Unquote(expr) => expr.validate(),
             Expression::UnquoteSplicing(expr) => expr.validate(),
-            Expression::FunctionCall { callee, arguments } => {
+            Expression::FunctionCall {
+                callee, arguments, ..
+            } => {
                 callee.validate()?;
                 for arg in arguments {
                     arg.validate()?;
@@ -3,6 +3,7 @@
 
 use clap::{Parser, ValueEnum};
 use std::fs;
+use std::io::IsTerminal;
 use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::Instant;
@@ -18,6 +19,7 @@ use rtfs::{
     ir::enhanced_optimizer::{EnhancedOptimizationPipeline, OptimizationLevel},
     ir::type_checker,
     parser::parse_with_enhanced_errors,
+    parser_error_reporter::{ColorChoice, ErrorFormat, ParserErrorReporter},
     runtime::module_runtime::ModuleRegistry,
     runtime::{Runtime, RuntimeStrategy},
     validator::SchemaValidator,
@@ -123,6 +125,14 @@ struct Args {
     /// Disable IR type checking (skip type validation)
     #[arg(long, conflicts_with = "type_check")]
     no_type_check: bool,
+
+    /// Parse error output: human-readable text or JSON for tools
+    #[arg(long, value_enum, default_value_t = ErrorFormat::Text)]
+    error_format: ErrorFormat,
+
+    /// Colorize parse errors (auto: only when stderr is a terminal)
+    #[arg(long, value_enum, default_value_t = ColorChoice::Auto)]
+    color: ColorChoice,
 }
 
 #[derive(Clone, ValueEnum, Debug)]
@@ -243,7 +253,10 @@ fn main() {
     ) {
         Ok(items) => items,
         Err(e) => {
-            eprintln!("{}", e);
+            let reporter = ParserErrorReporter::new()
+                .with_format(args.error_format)
+                .with_colors(args.color.use_colors(std::io::stderr().is_terminal()));
+            eprintln!("{}", reporter.format_errors(&[e]));
             std::process::exit(1);
         }
     };
@@ -874,7 +887,7 @@ fn infer_expression_type(expr: &rtfs::ast::Expression) -> String {
                 // Try to infer return type based on function name
                 match s.0.as_str() {
                     "+" | "-" | "*" | "/" | "mod" => "Number".to_string(),
-                    "=" | "<" | ">" | "<=" | ">=" | "and" | "or" | "not" => "Boolean".to_string(),
+                    "=" | "<" | ">" | "<=" | ">=" | "not" => "Boolean".to_string(),
                     "str" | "concat" => "String".to_string(),
                     "list" => "List<Any>".to_string(),
                     "map" => "Map<Any, Any>".to_string(),
@@ -909,7 +922,7 @@ fn describe_expression(expr: &rtfs::ast::Expression) -> String {
 
     match expr {
         Expression::Literal(lit) => format!("{:?}", lit),
-        Expression::Symbol(s) => s.0.clone(),
+        Expression::Symbol(s) => s.0.to_string(),
         Expression::List(items) if !items.is_empty() => {
             if let Expression::Symbol(s) = &items[0] {
                 format!("({}...)", s.0)
@@ -1030,7 +1043,9 @@ fn audit_expression(
 
     match expr {
         // Handle FunctionCall variant (modern AST structure)
-        Expression::FunctionCall { callee, arguments } => {
+        Expression::FunctionCall {
+            callee, arguments, ..
+        } => {
             if let Expression::Symbol(func) = callee.as_ref() {
                 let arg_count = arguments.len();
                 match func.0.as_str() {
@@ -12,7 +12,9 @@ extern crate rtfs;
 use rtfs::{
     ast::TopLevel, // Add TopLevel for RTFS 2.0 objects
     bytecode::BytecodeBackend,
-    input_handling::{read_input_content, validate_input_args, InputConfig, InputSource},
+    input_handling::{
+        read_input_content, stdin_forms, validate_input_args, InputConfig, InputSource,
+    },
     ir::converter::IrConverter, // Fix import path
     ir::enhanced_optimizer::{EnhancedOptimizationPipeline, OptimizationLevel},
     ir::type_checker,
@@ -89,7 +91,38 @@ fn main() {
         InputSource::Interactive => {
             run_interactive_repl(&mut runtime, &mut ir_converter, &mut optimizer);
         }
-        InputSource::String | InputSource::File | InputSource::Pipe => {
+        InputSource::Pipe => {
+            if args.verbose {
+                println!("📥 Streaming forms from stdin pipe");
+            }
+
+            let mut state = ReplState {
+                last_input: String::new(),
+                last_result: None,
+                last_ir: None,
+                show_types: false,
+                show_ir: false,
+                show_timing: false,
+            };
+
+            // Evaluate each top-level form as soon as it is complete
+            for form in stdin_forms() {
+                match form {
+                    Ok(form) => process_rtfs_input_with_state(
+                        &form,
+                        &mut runtime,
+                        &mut ir_converter,
+                        &mut optimizer,
+                        &mut state,
+                    ),
+                    Err(e) => {
+                        eprintln!("{}", e);
+                        std::process::exit(1);
+                    }
+                }
+            }
+        }
+        InputSource::String | InputSource::File => {
             // Convert args to PathBuf for file path
             let file_path = args.file.map(std::path::PathBuf::from);
 
@@ -109,8 +142,7 @@ fn main() {
                     let content = args.string.expect("String content should be validated");
                     InputConfig::from_string(content, args.verbose)
                 }
-                InputSource::Pipe => InputConfig::from_pipe(args.verbose),
-                InputSource::Interactive => unreachable!(),
+                InputSource::Pipe | InputSource::Interactive => unreachable!(),
             };
 
             // Read input content
@@ -767,7 +799,9 @@ fn print_ast_tree(item: &TopLevel, prefix: &str, is_last: bool) {
             Expression::Symbol(s) => {
                 println!("{}└─ 🏷️  {}", prefix, s.0);
             }
-            Expression::FunctionCall { callee, arguments } => {
+            Expression::FunctionCall {
+                callee, arguments, ..
+            } => {
                 println!("{}└─ ⚙️  Function Call", prefix);
                 if let Expression::Symbol(func) = callee.as_ref() {
                     println!("{}   ├─ Function: {}", prefix, func.0);
@@ -880,7 +914,9 @@ fn explain_expression(expr: &rtfs::ast::Expression, prefix: &str) {
                 prefix, s.0
             );
         }
-        Expression::FunctionCall { callee, arguments } => {
+        Expression::FunctionCall {
+            callee, arguments, ..
+        } => {
             if let Expression::Symbol(func) = callee.as_ref() {
                 match func.0.as_str() {
                     "+" => println!("{}This adds {} numbers together", prefix, arguments.len()),
@@ -982,7 +1018,9 @@ fn scan_for_capabilities(
     use rtfs::ast::Expression;
 
     match expr {
-        Expression::FunctionCall { callee, arguments } => {
+        Expression::FunctionCall {
+            callee, arguments, ..
+        } => {
             if let Expression::Symbol(func) = callee.as_ref() {
                 match func.0.as_str() {
                     "read-file" | "write-file" | "delete-file" => {
@@ -1157,8 +1195,10 @@ fn format_expression_friendly(expr: &rtfs::ast::Expression, indent: usize) -> St
 
     match expr {
         Expression::Literal(lit) => format!("{:?}", lit),
-        Expression::Symbol(s) => s.0.clone(),
-        Expression::FunctionCall { callee, arguments } => {
+        Expression::Symbol(s) => s.0.to_string(),
+        Expression::FunctionCall {
+            callee, arguments, ..
+        } => {
             if let Expression::Symbol(func) = callee.as_ref() {
                 let args_str: Vec<String> = arguments
                     .iter()
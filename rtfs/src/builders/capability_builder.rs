@@ -2,10 +2,16 @@ use super::{BuilderError, ObjectBuilder};
 use crate::ast::{
     CapabilityDefinition, Expression, Keyword, Literal, MapKey, Property, Symbol, TypeExpr,
 };
+use crate::runtime::error::RuntimeResult;
+use crate::runtime::values::Value;
 use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Rust implementation backing a locally registered capability
+pub type CapabilityHandler = Arc<dyn Fn(&Value) -> RuntimeResult<Value> + Send + Sync>;
 
 /// Fluent interface builder for RTFS 2.0 Capability objects
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct CapabilityBuilder {
     name: String,
     provider: Option<String>,
@@ -16,6 +22,24 @@ pub struct CapabilityBuilder {
     pricing: Option<Pricing>,
     examples: Vec<Example>,
     metadata: HashMap<String, String>,
+    handler: Option<CapabilityHandler>,
+}
+
+impl std::fmt::Debug for CapabilityBuilder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CapabilityBuilder")
+            .field("name", &self.name)
+            .field("provider", &self.provider)
+            .field("function_signature", &self.function_signature)
+            .field("input_schema", &self.input_schema)
+            .field("output_schema", &self.output_schema)
+            .field("sla", &self.sla)
+            .field("pricing", &self.pricing)
+            .field("examples", &self.examples)
+            .field("metadata", &self.metadata)
+            .field("handler", &self.handler.as_ref().map(|_| "<function>"))
+            .finish()
+    }
 }
 
 /// Function signature for capabilities
@@ -77,6 +101,7 @@ impl CapabilityBuilder {
             pricing: None,
             examples: Vec::new(),
             metadata: HashMap::new(),
+            handler: None,
         }
     }
 
@@ -164,6 +189,45 @@ impl CapabilityBuilder {
         self
     }
 
+    /// Set the Rust handler that implements the capability locally
+    pub fn with_handler<F>(mut self, handler: F) -> Self
+    where
+        F: Fn(&Value) -> RuntimeResult<Value> + Send + Sync + 'static,
+    {
+        self.handler = Some(Arc::new(handler));
+        self
+    }
+
+    /// The capability name, used as its ID when registered
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The provider, if set
+    pub fn provider(&self) -> Option<&str> {
+        self.provider.as_deref()
+    }
+
+    /// The input schema, if set
+    pub fn input_schema(&self) -> Option<&TypeExpr> {
+        self.input_schema.as_ref()
+    }
+
+    /// The output schema, if set
+    pub fn output_schema(&self) -> Option<&TypeExpr> {
+        self.output_schema.as_ref()
+    }
+
+    /// Metadata added so far
+    pub fn metadata(&self) -> &HashMap<String, String> {
+        &self.metadata
+    }
+
+    /// The local handler, if set
+    pub fn handler(&self) -> Option<&CapabilityHandler> {
+        self.handler.as_ref()
+    }
+
     /// Get suggestions for completing the capability
     pub fn suggest_completion(&self) -> Vec<String> {
         let mut suggestions = Vec::new();
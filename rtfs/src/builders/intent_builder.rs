@@ -6,6 +6,33 @@ use crate::ast::{Expression, IntentDefinition, Keyword, Literal, MapKey, Propert
 use chrono::{DateTime, Utc};
 use std::collections::HashMap;
 
+/// Relationship from the intent being built to another intent
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IntentRelation {
+    /// This intent cannot start before the other one completes
+    DependsOn,
+    /// This intent is a subgoal of the other one
+    SubgoalOf,
+    /// This intent and the other one cannot both be satisfied
+    ConflictsWith,
+}
+
+impl IntentRelation {
+    pub fn name(&self) -> &'static str {
+        match self {
+            IntentRelation::DependsOn => "depends-on",
+            IntentRelation::SubgoalOf => "subgoal-of",
+            IntentRelation::ConflictsWith => "conflicts-with",
+        }
+    }
+
+    const ALL: [IntentRelation; 3] = [
+        IntentRelation::DependsOn,
+        IntentRelation::SubgoalOf,
+        IntentRelation::ConflictsWith,
+    ];
+}
+
 /// Fluent interface builder for RTFS 2.0 Intent objects
 pub struct IntentBuilder {
     name: String,
@@ -15,6 +42,7 @@ pub struct IntentBuilder {
     success_criteria: Option<SuccessCriteria>,
     parent_intent: Option<String>,
     child_intents: Vec<String>,
+    relations: Vec<(IntentRelation, String)>,
     metadata: HashMap<String, String>,
     created_at: Option<DateTime<Utc>>,
     created_by: Option<String>,
@@ -31,6 +59,7 @@ impl std::fmt::Debug for IntentBuilder {
             .field("success_criteria", &"<function>")
             .field("parent_intent", &self.parent_intent)
             .field("child_intents", &self.child_intents)
+            .field("relations", &self.relations)
             .field("metadata", &self.metadata)
             .field("created_at", &self.created_at)
             .field("created_by", &self.created_by)
@@ -49,6 +78,7 @@ impl Clone for IntentBuilder {
             success_criteria: None, // Cannot clone function pointers
             parent_intent: self.parent_intent.clone(),
             child_intents: self.child_intents.clone(),
+            relations: self.relations.clone(),
             metadata: self.metadata.clone(),
             created_at: self.created_at,
             created_by: self.created_by.clone(),
@@ -68,6 +98,7 @@ impl IntentBuilder {
             success_criteria: None,
             parent_intent: None,
             child_intents: Vec::new(),
+            relations: Vec::new(),
             metadata: HashMap::new(),
             created_at: None,
             created_by: None,
@@ -132,6 +163,27 @@ impl IntentBuilder {
         self
     }
 
+    /// Declare that this intent depends on another intent
+    pub fn depends_on(mut self, intent_id: &str) -> Self {
+        self.relations
+            .push((IntentRelation::DependsOn, intent_id.to_string()));
+        self
+    }
+
+    /// Declare this intent as a subgoal of another intent
+    pub fn subgoal_of(mut self, intent_id: &str) -> Self {
+        self.relations
+            .push((IntentRelation::SubgoalOf, intent_id.to_string()));
+        self
+    }
+
+    /// Declare that this intent conflicts with another intent
+    pub fn conflicts_with(mut self, intent_id: &str) -> Self {
+        self.relations
+            .push((IntentRelation::ConflictsWith, intent_id.to_string()));
+        self
+    }
+
     /// Add metadata key-value pair
     pub fn with_metadata(mut self, key: &str, value: &str) -> Self {
         self.metadata.insert(key.to_string(), value.to_string());
@@ -205,6 +257,55 @@ impl IntentBuilder {
         Ok(builder)
     }
 
+    /// The intent name
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The goal, if set
+    pub fn goal(&self) -> Option<&str> {
+        self.goal.as_deref()
+    }
+
+    /// The priority, if set
+    pub fn priority(&self) -> Option<&Priority> {
+        self.priority.as_ref()
+    }
+
+    /// Constraints added so far
+    pub fn constraints(&self) -> &[Constraint] {
+        &self.constraints
+    }
+
+    /// The parent intent ID, if set
+    pub fn parent_intent(&self) -> Option<&str> {
+        self.parent_intent.as_deref()
+    }
+
+    /// Child intent IDs added so far
+    pub fn child_intents(&self) -> &[String] {
+        &self.child_intents
+    }
+
+    /// Metadata added so far
+    pub fn metadata(&self) -> &HashMap<String, String> {
+        &self.metadata
+    }
+
+    /// Relationships declared so far, in declaration order
+    pub fn relations(&self) -> &[(IntentRelation, String)] {
+        &self.relations
+    }
+
+    /// Target intent IDs declared for one kind of relationship
+    fn relation_targets(&self, relation: IntentRelation) -> Vec<&String> {
+        self.relations
+            .iter()
+            .filter(|(r, _)| *r == relation)
+            .map(|(_, id)| id)
+            .collect()
+    }
+
     /// Get suggestions for completing the intent
     pub fn suggest_completion(&self) -> Vec<String> {
         let mut suggestions = Vec::new();
@@ -274,6 +375,22 @@ impl ObjectBuilder<IntentDefinition> for IntentBuilder {
             return Err(BuilderError::MissingField("goal".to_string()));
         }
 
+        // One vector property per declared relationship kind
+        let relation_properties: Vec<Property> = IntentRelation::ALL
+            .into_iter()
+            .filter_map(|relation| {
+                let targets: Vec<Expression> = self
+                    .relation_targets(relation)
+                    .into_iter()
+                    .map(|id| Expression::Literal(Literal::String(id.clone())))
+                    .collect();
+                (!targets.is_empty()).then(|| Property {
+                    key: Keyword::new(relation.name()),
+                    value: Expression::Vector(targets),
+                })
+            })
+            .collect();
+
         // Convert constraints to properties
         let mut properties = vec![
             Property {
@@ -331,6 +448,8 @@ impl ObjectBuilder<IntentDefinition> for IntentBuilder {
             });
         }
 
+        properties.extend(relation_properties);
+
         if !self.metadata.is_empty() {
             let metadata_props: Vec<Property> = self
                 .metadata
@@ -435,6 +554,18 @@ impl ObjectBuilder<IntentDefinition> for IntentBuilder {
             rtfs.push_str(&format!("  child-intents: [{}]\n", children_str));
         }
 
+        for relation in IntentRelation::ALL {
+            let targets = self.relation_targets(relation);
+            if !targets.is_empty() {
+                let targets_str = targets
+                    .iter()
+                    .map(|id| format!("\"{}\"", id))
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                rtfs.push_str(&format!("  {}: [{}]\n", relation.name(), targets_str));
+            }
+        }
+
         if !self.metadata.is_empty() {
             rtfs.push_str("  metadata: {\n");
             for (key, value) in &self.metadata {
@@ -515,6 +646,30 @@ mod tests {
         assert!(rtfs.contains("priority: :medium"));
     }
 
+    #[test]
+    fn test_relations_accumulate_in_order() {
+        let builder = IntentBuilder::new("deploy")
+            .with_goal("Deploy the service")
+            .depends_on("build")
+            .subgoal_of("release")
+            .conflicts_with("freeze")
+            .depends_on("test");
+
+        assert_eq!(
+            builder.relations(),
+            &[
+                (IntentRelation::DependsOn, "build".to_string()),
+                (IntentRelation::SubgoalOf, "release".to_string()),
+                (IntentRelation::ConflictsWith, "freeze".to_string()),
+                (IntentRelation::DependsOn, "test".to_string()),
+            ]
+        );
+
+        let rtfs = builder.to_rtfs().unwrap();
+        assert!(rtfs.contains("depends-on: [\"build\" \"test\"]"));
+        assert!(rtfs.contains("subgoal-of: [\"release\"]"));
+    }
+
     #[test]
     fn test_validation() {
         let result = IntentBuilder::new("")
@@ -9,8 +9,8 @@ pub mod plan_builder;
 pub mod resource_builder;
 
 pub use action_builder::ActionBuilder;
-pub use capability_builder::CapabilityBuilder;
-pub use intent_builder::IntentBuilder;
+pub use capability_builder::{CapabilityBuilder, CapabilityHandler};
+pub use intent_builder::{IntentBuilder, IntentRelation};
 pub use module_builder::ModuleBuilder;
 pub use plan_builder::PlanBuilder;
 pub use resource_builder::ResourceBuilder;
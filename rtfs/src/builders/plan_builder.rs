@@ -10,6 +10,8 @@ pub struct PlanBuilder {
     name: String,
     intent_id: Option<String>,
     steps: Vec<PlanStep>,
+    body: Option<String>,
+    capabilities: Vec<String>,
     priority: Option<Priority>,
     constraints: Vec<Constraint>,
     estimated_cost: Option<f64>,
@@ -45,6 +47,8 @@ impl PlanBuilder {
             name: name.to_string(),
             intent_id: None,
             steps: Vec::new(),
+            body: None,
+            capabilities: Vec::new(),
             priority: None,
             constraints: Vec::new(),
             estimated_cost: None,
@@ -72,6 +76,24 @@ impl PlanBuilder {
         self
     }
 
+    /// Set the RTFS source of the plan body
+    pub fn with_body(mut self, rtfs_source: &str) -> Self {
+        self.body = Some(rtfs_source.to_string());
+        self
+    }
+
+    /// Declare a capability the plan body requires
+    pub fn with_capability(mut self, capability_id: &str) -> Self {
+        self.capabilities.push(capability_id.to_string());
+        self
+    }
+
+    /// Declare multiple required capabilities
+    pub fn with_capabilities(mut self, capability_ids: Vec<String>) -> Self {
+        self.capabilities.extend(capability_ids);
+        self
+    }
+
     /// Set the priority level
     pub fn with_priority(mut self, priority: Priority) -> Self {
         self.priority = Some(priority);
@@ -155,6 +177,34 @@ impl PlanBuilder {
         }
     }
 
+    /// Build the plan after checking that its RTFS body parses.
+    ///
+    /// Unlike [`ObjectBuilder::build`], this requires a body, an intent ID and
+    /// at least one declared capability, so a plan that could never execute
+    /// is rejected here rather than at run time.
+    pub fn build_validated(self) -> Result<PlanDefinition, BuilderError> {
+        if self.intent_id.is_none() {
+            return Err(BuilderError::MissingField("intent_id".to_string()));
+        }
+
+        if self.capabilities.is_empty() {
+            return Err(BuilderError::MissingField("capabilities".to_string()));
+        }
+
+        let body = self
+            .body
+            .as_deref()
+            .ok_or_else(|| BuilderError::MissingField("body".to_string()))?;
+        crate::parser::parse(body).map_err(|e| {
+            BuilderError::InvalidValue(
+                "body".to_string(),
+                format!("RTFS body does not parse: {}", e),
+            )
+        })?;
+
+        self.build()
+    }
+
     /// Get suggestions for completing the plan
     pub fn suggest_completion(&self) -> Vec<String> {
         let mut suggestions = Vec::new();
@@ -259,7 +309,8 @@ impl ObjectBuilder<PlanDefinition> for PlanBuilder {
             return Err(BuilderError::MissingField("intent_id".to_string()));
         }
 
-        if self.steps.is_empty() {
+        // A plan either lists its steps or carries an RTFS body
+        if self.steps.is_empty() && self.body.is_none() {
             return Err(BuilderError::MissingField("steps".to_string()));
         }
 
@@ -361,10 +412,32 @@ impl ObjectBuilder<PlanDefinition> for PlanBuilder {
             })
             .collect();
 
-        properties.push(Property {
-            key: Keyword::new("steps"),
-            value: Expression::Vector(step_expressions.into_iter().collect()),
-        });
+        if !step_expressions.is_empty() {
+            properties.push(Property {
+                key: Keyword::new("steps"),
+                value: Expression::Vector(step_expressions.into_iter().collect()),
+            });
+        }
+
+        if let Some(body) = &self.body {
+            properties.push(Property {
+                key: Keyword::new("body"),
+                value: Expression::Literal(Literal::String(body.clone())),
+            });
+        }
+
+        if !self.capabilities.is_empty() {
+            let capability_values: Vec<Expression> = self
+                .capabilities
+                .iter()
+                .map(|c| Expression::Literal(Literal::String(c.clone())))
+                .collect();
+
+            properties.push(Property {
+                key: Keyword::new("capabilities"),
+                value: Expression::Vector(capability_values),
+            });
+        }
 
         // Add optional properties
         if let Some(priority) = self.priority {
@@ -450,7 +523,7 @@ impl ObjectBuilder<PlanDefinition> for PlanBuilder {
             errors.push("Intent ID is required".to_string());
         }
 
-        if self.steps.is_empty() {
+        if self.steps.is_empty() && self.body.is_none() {
             errors.push("At least one step is required".to_string());
         }
 
@@ -531,6 +604,20 @@ impl ObjectBuilder<PlanDefinition> for PlanBuilder {
         }
         rtfs.push_str("  ]\n");
 
+        if let Some(body) = &self.body {
+            rtfs.push_str(&format!("  body: {}\n", body));
+        }
+
+        if !self.capabilities.is_empty() {
+            let caps_str = self
+                .capabilities
+                .iter()
+                .map(|cap| format!("\"{}\"", cap))
+                .collect::<Vec<_>>()
+                .join(" ");
+            rtfs.push_str(&format!("  capabilities: [{}]\n", caps_str));
+        }
+
         // Optional fields
         if let Some(priority) = &self.priority {
             rtfs.push_str(&format!("  priority: :{}\n", priority));
@@ -635,6 +722,55 @@ mod tests {
         assert!(rtfs.contains("action-id: \"action1\""));
     }
 
+    #[test]
+    fn test_build_validated_accepts_parsing_body() {
+        let plan = PlanBuilder::new("fetch-plan")
+            .for_intent("intent-42")
+            .with_body("(do (step \"fetch\" (call :http.get {:url \"https://example.com\"})))")
+            .with_capability("http.get")
+            .build_validated()
+            .unwrap();
+
+        let keys: Vec<_> = plan.properties.iter().map(|p| p.key.0.as_str()).collect();
+        assert_eq!(keys, vec!["name", "intent-id", "body", "capabilities"]);
+    }
+
+    #[test]
+    fn test_build_validated_rejects_broken_body() {
+        let result = PlanBuilder::new("broken-plan")
+            .for_intent("intent-42")
+            .with_body("(do (step \"fetch\" (call :http.get {:url \"x\"})")
+            .with_capability("http.get")
+            .build_validated();
+
+        match result {
+            Err(BuilderError::InvalidValue(field, message)) => {
+                assert_eq!(field, "body");
+                assert!(message.starts_with("RTFS body does not parse"));
+            }
+            other => panic!("expected a body parse error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_build_validated_requires_intent_and_capabilities() {
+        let body = "(call :echo \"hi\")";
+
+        let no_intent = PlanBuilder::new("p")
+            .with_body(body)
+            .with_capability("echo")
+            .build_validated();
+        assert!(matches!(no_intent, Err(BuilderError::MissingField(f)) if f == "intent_id"));
+
+        let no_capabilities = PlanBuilder::new("p")
+            .for_intent("intent-42")
+            .with_body(body)
+            .build_validated();
+        assert!(
+            matches!(no_capabilities, Err(BuilderError::MissingField(f)) if f == "capabilities")
+        );
+    }
+
     #[test]
     fn test_validation() {
         let result = PlanBuilder::new("").for_intent("test-intent").validate();
@@ -1,3 +1,9 @@
+mod serialization;
+
+pub use serialization::{
+    compile_to_bytes, encode_program, load_from_bytes, BYTECODE_FORMAT_VERSION, BYTECODE_MAGIC,
+};
+
 use crate::ir::core::IrNode;
 use crate::runtime::{RuntimeError, RuntimeResult, Value};
 #[cfg(feature = "wasm-runtime")]
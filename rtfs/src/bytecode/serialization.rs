@@ -0,0 +1,97 @@
+//! Versioned on-disk format for compiled RTFS programs.
+//!
+//! A compiled program is the macro-expanded, arity-checked IR `Program` node,
+//! encoded with bincode behind a small header:
+//!
+//! ```text
+//! b"RTBC" | format version (u32, little endian) | bincode(IrNode)
+//! ```
+//!
+//! Reloading skips parsing, macro expansion and IR lowering. Bytes written by
+//! another format version are rejected instead of being misread.
+
+use crate::ast::{DoExpr, Expression, TopLevel};
+use crate::ir::core::IrNode;
+use crate::parser;
+use crate::runtime::ir_runtime::IrStrategy;
+use crate::runtime::module_runtime::ModuleRegistry;
+use crate::runtime::{RuntimeError, RuntimeResult};
+use std::sync::Arc;
+
+/// Leading bytes of every compiled RTFS program.
+pub const BYTECODE_MAGIC: &[u8; 4] = b"RTBC";
+
+/// Version of the serialized IR layout. Bump it whenever `IrNode` (or any
+/// type it contains) changes shape, so stale caches fail to load.
+pub const BYTECODE_FORMAT_VERSION: u32 = 1;
+
+const HEADER_LEN: usize = BYTECODE_MAGIC.len() + 4;
+
+/// Compile RTFS source to bytecode. Several top-level expressions are compiled
+/// as one `do` block, so later forms see earlier definitions.
+pub fn compile_to_bytes(source: &str) -> RuntimeResult<Vec<u8>> {
+    let mut forms = Vec::new();
+    for item in
+        parser::parse(source).map_err(|e| RuntimeError::Generic(format!("Parse error: {}", e)))?
+    {
+        match item {
+            TopLevel::Expression(expr) => forms.push(expr),
+            _ => {
+                return Err(RuntimeError::InvalidProgram(
+                    "only expressions can be compiled to bytecode".to_string(),
+                ))
+            }
+        }
+    }
+    let program = if forms.len() == 1 {
+        forms.remove(0)
+    } else {
+        Expression::Do(DoExpr { expressions: forms })
+    };
+
+    let mut strategy = IrStrategy::new(Arc::new(ModuleRegistry::new()));
+    encode_program(&strategy.compile(&program)?)
+}
+
+/// Serialize an IR `Program` node (see [`IrStrategy::compile`]) to bytecode.
+pub fn encode_program(program: &IrNode) -> RuntimeResult<Vec<u8>> {
+    if !matches!(program, IrNode::Program { .. }) {
+        return Err(RuntimeError::InvalidProgram(
+            "only IR Program nodes can be encoded as bytecode".to_string(),
+        ));
+    }
+    let mut bytes = Vec::with_capacity(HEADER_LEN);
+    bytes.extend_from_slice(BYTECODE_MAGIC);
+    bytes.extend_from_slice(&BYTECODE_FORMAT_VERSION.to_le_bytes());
+    bincode::serialize_into(&mut bytes, program)
+        .map_err(|e| RuntimeError::Generic(format!("Bytecode encoding failed: {}", e)))?;
+    Ok(bytes)
+}
+
+/// Load bytecode produced by [`compile_to_bytes`], returning the IR `Program`
+/// node to pass to [`IrStrategy::run_compiled`].
+pub fn load_from_bytes(bytes: &[u8]) -> RuntimeResult<IrNode> {
+    if bytes.len() < HEADER_LEN || &bytes[..BYTECODE_MAGIC.len()] != BYTECODE_MAGIC {
+        return Err(RuntimeError::InvalidProgram(
+            "not RTFS bytecode (missing RTBC header)".to_string(),
+        ));
+    }
+    let mut version = [0u8; 4];
+    version.copy_from_slice(&bytes[BYTECODE_MAGIC.len()..HEADER_LEN]);
+    let version = u32::from_le_bytes(version);
+    if version != BYTECODE_FORMAT_VERSION {
+        return Err(RuntimeError::InvalidProgram(format!(
+            "bytecode format version {} is not supported (expected {}); recompile the program",
+            version, BYTECODE_FORMAT_VERSION
+        )));
+    }
+
+    let program: IrNode = bincode::deserialize(&bytes[HEADER_LEN..])
+        .map_err(|e| RuntimeError::InvalidProgram(format!("corrupt bytecode: {}", e)))?;
+    match program {
+        IrNode::Program { .. } => Ok(program),
+        _ => Err(RuntimeError::InvalidProgram(
+            "bytecode does not contain an IR program".to_string(),
+        )),
+    }
+}
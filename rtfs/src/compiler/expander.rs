@@ -1,4 +1,4 @@
-use crate::ast::{Expression, Literal, Symbol};
+use crate::ast::{Expression, Literal, Span, Symbol};
 use crate::compiler::macro_def::MacroDef;
 use std::collections::HashMap;
 
@@ -224,7 +224,11 @@ impl MacroExpander {
                     delegation_hint: fn_expr.delegation_hint.clone(),
                 }))
             }
-            Expression::FunctionCall { callee, arguments } => {
+            Expression::FunctionCall {
+                callee,
+                arguments,
+                span,
+            } => {
                 if quasiquote_level == 0 {
                     if let Expression::Symbol(symbol) = callee.as_ref() {
                         if self.macros.contains_key(symbol) {
@@ -260,6 +264,7 @@ impl MacroExpander {
                 Ok(Expression::FunctionCall {
                     callee: Box::new(expanded_callee),
                     arguments: expanded_arguments,
+                    span: *span,
                 })
             }
             _ => Ok(expression.clone()),
@@ -347,13 +352,7 @@ impl MacroExpander {
                         if quasiquote_level == 1 {
                             let substituted_expr =
                                 self.substitute(spliced_expr, bindings, quasiquote_level - 1)?;
-                            if let Expression::List(spliced_list) = substituted_expr {
-                                new_list.extend(spliced_list);
-                            } else {
-                                return Err(
-                                    "Unquote-splicing can only be used on a list".to_string()
-                                );
-                            }
+                            new_list.extend(splice_items(substituted_expr)?);
                         } else {
                             new_list.push(self.substitute(expr, bindings, quasiquote_level)?);
                         }
@@ -478,12 +477,7 @@ impl MacroExpander {
                 for e in exprs {
                     if let Expression::UnquoteSplicing(inner) = e {
                         // If the spliced expression resolves to a list, splice it
-                        let replaced = self.replace_unquotes(inner, bindings)?;
-                        if let Expression::List(items) = replaced {
-                            out.extend(items);
-                        } else {
-                            return Err("Unquote-splicing can only be used on a list".to_string());
-                        }
+                        out.extend(splice_items(self.replace_unquotes(inner, bindings)?)?);
                     } else {
                         out.push(self.replace_unquotes(e, bindings)?);
                     }
@@ -497,7 +491,9 @@ impl MacroExpander {
                 }
                 Ok(Expression::Vector(newv))
             }
-            Expression::FunctionCall { callee, arguments } => {
+            Expression::FunctionCall {
+                callee, arguments, ..
+            } => {
                 let new_callee = Box::new(self.replace_unquotes(callee, bindings)?);
                 let mut new_args = Vec::new();
                 for a in arguments {
@@ -506,6 +502,7 @@ impl MacroExpander {
                 Ok(Expression::FunctionCall {
                     callee: new_callee,
                     arguments: new_args,
+                    span: Span::unknown(),
                 })
             }
             Expression::Map(map) => {
@@ -570,3 +567,16 @@ impl MacroExpander {
         }
     }
 }
+
+/// Elements spliced in place of `~@form`: the items of a list or vector, or a
+/// call form's callee followed by its arguments (macro arguments arrive
+/// unevaluated, so `(list 2 3)` is a call form rather than a list)
+fn splice_items(expr: Expression) -> Result<Vec<Expression>, String> {
+    match expr {
+        Expression::List(items) | Expression::Vector(items) => Ok(items),
+        Expression::FunctionCall {
+            callee, arguments, ..
+        } => Ok(std::iter::once(*callee).chain(arguments).collect()),
+        _ => Err("Unquote-splicing can only be used on a list".to_string()),
+    }
+}
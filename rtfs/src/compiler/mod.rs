@@ -1,2 +1,3 @@
 pub mod expander;
 pub mod macro_def;
+pub mod threading;
@@ -0,0 +1,45 @@
+use crate::ast::{Expression, Span};
+
+/// Rewrite a threading form into nested calls.
+///
+/// `(-> x (f a) g)` becomes `(g (f x a))` and `(->> x (f a) g)` becomes
+/// `(g (f a x))`. A step that is not a call form (a bare symbol, keyword or
+/// other expression) is applied to the threaded value with no extra args.
+/// `args` are the operands of the form, i.e. the initial value followed by
+/// the steps.
+pub fn expand_threading(form: &str, args: &[Expression]) -> Result<Expression, String> {
+    let thread_last = match form {
+        "->" => false,
+        "->>" => true,
+        other => return Err(format!("{} is not a threading form", other)),
+    };
+    let Some((initial, steps)) = args.split_first() else {
+        return Err(format!("{} requires an initial value", form));
+    };
+
+    let mut threaded = initial.clone();
+    for step in steps {
+        let (callee, mut arguments, span) = match step {
+            Expression::FunctionCall {
+                callee,
+                arguments,
+                span,
+            } => ((**callee).clone(), arguments.clone(), *span),
+            Expression::List(items) if !items.is_empty() => {
+                (items[0].clone(), items[1..].to_vec(), Span::unknown())
+            }
+            other => (other.clone(), Vec::new(), Span::unknown()),
+        };
+        if thread_last {
+            arguments.push(threaded);
+        } else {
+            arguments.insert(0, threaded);
+        }
+        threaded = Expression::FunctionCall {
+            callee: Box::new(callee),
+            arguments,
+            span,
+        };
+    }
+    Ok(threaded)
+}
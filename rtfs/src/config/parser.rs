@@ -51,7 +51,9 @@ impl AgentConfigParser {
                     "Expected (agent.config ...) form".to_string(),
                 ))
             }
-            Expression::FunctionCall { callee, arguments } => {
+            Expression::FunctionCall {
+                callee, arguments, ..
+            } => {
                 // Check if this is an agent.config function call
                 if let Expression::Symbol(sym) = &**callee {
                     if sym.0 == "agent.config" {
@@ -264,7 +266,7 @@ impl AgentConfigParser {
 
                 for (key, value) in map_expr {
                     let mount_name = match key {
-                        MapKey::Keyword(k) => k.0.clone(),
+                        MapKey::Keyword(k) => k.0.to_string(),
                         MapKey::String(s) => s.clone(),
                         _ => continue,
                     };
@@ -724,7 +726,7 @@ impl AgentConfigParser {
     fn extract_string(expr: &Expression) -> RuntimeResult<String> {
         match expr {
             Expression::Literal(Literal::String(s)) => Ok(s.clone()),
-            Expression::Literal(Literal::Keyword(k)) => Ok(k.0.clone()),
+            Expression::Literal(Literal::Keyword(k)) => Ok(k.0.to_string()),
             _ => Err(RuntimeError::Generic(
                 "Expected string or keyword".to_string(),
             )),
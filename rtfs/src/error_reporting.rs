@@ -300,15 +300,23 @@ impl EnhancedRuntimeError {
     }
 }
 
+/// The known symbol closest to `target` by edit distance, if any is close enough
+pub fn closest_symbol(target: &str, available: &[String]) -> Option<String> {
+    find_similar_symbols(target, available).into_iter().next()
+}
+
 /// Find similar symbols using Levenshtein distance
 fn find_similar_symbols(target: &str, available: &[String]) -> Vec<String> {
     let mut candidates: Vec<_> = available
         .iter()
+        .filter(|s| s.as_str() != target)
         .map(|s| (s, levenshtein_distance(target, s)))
         .filter(|(_, dist)| *dist <= 3 && *dist < target.len()) // Only suggest if reasonable distance
         .collect();
 
-    candidates.sort_by_key(|(_, dist)| *dist);
+    // Closest first; ties broken by name so suggestions are deterministic
+    candidates.sort_by(|(a, da), (b, db)| da.cmp(db).then_with(|| a.cmp(b)));
+    candidates.dedup();
 
     candidates
         .into_iter()
@@ -427,6 +435,27 @@ impl DiagnosticFormatter {
         output
     }
 
+    /// Format several diagnostics as one report, followed by an error count
+    pub fn format_diagnostics(&self, diagnostics: &[DiagnosticInfo]) -> String {
+        let mut output = diagnostics
+            .iter()
+            .map(|d| self.format_diagnostic(d))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let errors = diagnostics
+            .iter()
+            .filter(|d| d.severity == ErrorSeverity::Error)
+            .count();
+        if errors > 0 {
+            output.push_str(&format!(
+                "\nerror: found {} error{}\n",
+                errors,
+                if errors == 1 { "" } else { "s" }
+            ));
+        }
+        output
+    }
+
     fn format_source_span(&self, span: &SourceSpan, label: &str) -> String {
         let mut output = String::new();
 
@@ -558,9 +587,15 @@ impl From<crate::runtime::error::RuntimeError> for EnhancedRuntimeError {
                     diagnostic,
                 }
             }
-            crate::runtime::error::RuntimeError::UndefinedSymbol(symbol) => {
-                let diagnostic =
+            crate::runtime::error::RuntimeError::UndefinedSymbol { symbol, suggestion } => {
+                let mut diagnostic =
                     DiagnosticInfo::error("E001", &format!("Undefined symbol: {}", symbol.0));
+                if let Some(suggestion) = suggestion {
+                    diagnostic = diagnostic.with_hint(
+                        ErrorHint::new(&format!("Did you mean `{}`?", suggestion))
+                            .with_suggestion(&suggestion),
+                    );
+                }
 
                 Self::UndefinedSymbol { symbol, diagnostic }
             }
@@ -575,6 +610,115 @@ impl From<crate::runtime::error::RuntimeError> for EnhancedRuntimeError {
     }
 }
 
+/// Check a whole program and report every independent error at once instead of
+/// stopping at the first: syntax errors for each malformed top-level form, then
+/// undefined symbols and builtin arity mismatches across the forms that parsed.
+/// The program is not run; an empty result means no error was found.
+pub fn check_program(source: &str, file_path: Option<&str>) -> Vec<DiagnosticInfo> {
+    use crate::ast::{DoExpr, Expression, TopLevel};
+    use crate::compiler::expander::MacroExpander;
+    use crate::ir::arity_checker::{builtin_arities, collect_builtin_arity_errors};
+    use crate::ir::converter::{IrConversionError, IrConverter};
+    use crate::runtime::module_runtime::ModuleRegistry;
+    use crate::runtime::values::Arity;
+
+    let (items, parse_errors) = crate::parser::parse_collecting_errors(source, file_path);
+    let mut diagnostics: Vec<DiagnosticInfo> =
+        parse_errors.into_iter().map(|e| e.diagnostic).collect();
+
+    let forms: Vec<Expression> = items
+        .into_iter()
+        .filter_map(|item| match item {
+            TopLevel::Expression(expr) => Some(expr),
+            _ => None,
+        })
+        .collect();
+    if forms.is_empty() {
+        return diagnostics;
+    }
+    let program =
+        match MacroExpander::default().expand(&Expression::Do(DoExpr { expressions: forms }), 0) {
+            Ok(program) => program,
+            Err(e) => {
+                diagnostics.push(DiagnosticInfo::error(
+                    "E999",
+                    &format!("Macro expansion error: {}", e),
+                ));
+                return diagnostics;
+            }
+        };
+
+    let registry = ModuleRegistry::new();
+    let _ = crate::runtime::stdlib::load_stdlib(&registry);
+    let known_symbols: Vec<String> = registry
+        .get_module("stdlib")
+        .and_then(|stdlib| {
+            stdlib
+                .exports
+                .read()
+                .ok()
+                .map(|exports| exports.keys().cloned().collect())
+        })
+        .unwrap_or_default();
+    let span_at = |location: &Option<crate::ir::core::SourceLocation>| {
+        location.as_ref().map(|loc| {
+            let span =
+                SourceSpan::single_point(loc.line, loc.column).with_source_text(source.to_string());
+            match file_path {
+                Some(path) => span.with_file(path.to_string()),
+                None => span,
+            }
+        })
+    };
+    let conversion_diagnostic = |error: IrConversionError| match error {
+        IrConversionError::UndefinedSymbol { symbol, location } => match span_at(&location) {
+            Some(span) => {
+                EnhancedRuntimeError::undefined_symbol(&Symbol(symbol.into()), span, &known_symbols)
+                    .diagnostic()
+                    .clone()
+            }
+            None => DiagnosticInfo::error("E001", &format!("Undefined symbol `{}`", symbol)),
+        },
+        other => DiagnosticInfo::error("E999", &format!("IR conversion error: {:?}", other)),
+    };
+
+    let mut converter = IrConverter::with_module_registry(&registry)
+        .strict()
+        .collecting();
+    let converted = converter.convert_expression(program);
+    diagnostics.extend(
+        converter
+            .take_collected_errors()
+            .into_iter()
+            .map(&conversion_diagnostic),
+    );
+    match converted {
+        Ok(ir) => {
+            for error in collect_builtin_arity_errors(&ir, &builtin_arities(&registry)) {
+                let (min, max) = match error.expected {
+                    Arity::Fixed(n) => (n, Some(n)),
+                    Arity::Variadic(n) => (n, None),
+                    Arity::Range(min, max) => (min, Some(max)),
+                };
+                diagnostics.push(match span_at(&error.location) {
+                    Some(span) => EnhancedRuntimeError::arity_mismatch(
+                        &error.function,
+                        min,
+                        max,
+                        error.actual,
+                        span,
+                    )
+                    .diagnostic()
+                    .clone(),
+                    None => DiagnosticInfo::error("E003", &error.to_string()),
+                });
+            }
+        }
+        Err(e) => diagnostics.push(conversion_diagnostic(e)),
+    }
+    diagnostics
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
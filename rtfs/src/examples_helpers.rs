@@ -1,9 +1,7 @@
-use crate::ast::MapKey;
-use crate::runtime::values::Value;
-use std::collections::HashMap;
+use crate::runtime::values::{Value, ValueMap};
 
 /// Helper: get a string value for a key from a runtime Value::Map whose keys are MapKey.
-pub fn get_map_string_value(map: &HashMap<MapKey, Value>, key: &str) -> Option<String> {
+pub fn get_map_string_value(map: &ValueMap, key: &str) -> Option<String> {
     for (k, v) in map.iter() {
         let k_str = k.to_string();
         let k_trim = k_str.trim_start_matches(':');
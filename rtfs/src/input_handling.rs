@@ -3,7 +3,8 @@
 
 use clap::ValueEnum;
 use std::fs;
-use std::io::{self, BufRead};
+use std::io::{self, BufRead, StdinLock};
+use std::ops::Range;
 use std::path::PathBuf;
 
 /// Input source types supported by RTFS binaries
@@ -164,6 +165,234 @@ pub fn read_input_content(config: &InputConfig) -> Result<InputContent, InputErr
     }
 }
 
+/// Streaming reader yielding one complete top-level RTFS form at a time.
+///
+/// Input is consumed line by line, so each form can be evaluated as soon as it
+/// is complete instead of after the whole input has been buffered. A form that
+/// spans several lines is held back until its closing delimiter arrives; input
+/// that ends in the middle of a form yields `InputError::IncompleteForm`.
+pub struct FormReader<R: BufRead> {
+    reader: R,
+    buffer: String,
+    eof: bool,
+}
+
+impl<R: BufRead> FormReader<R> {
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            buffer: String::new(),
+            eof: false,
+        }
+    }
+}
+
+/// Stream top-level forms from stdin (pipe-driven REPL / batch mode)
+pub fn stdin_forms() -> FormReader<StdinLock<'static>> {
+    FormReader::new(io::stdin().lock())
+}
+
+impl<R: BufRead> Iterator for FormReader<R> {
+    type Item = Result<String, InputError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match scan_form(&self.buffer) {
+                FormScan::Complete(start, end) | FormScan::Malformed(start, end) => {
+                    let form = self.buffer[start..end].to_string();
+                    self.buffer.drain(..end);
+                    return Some(Ok(form));
+                }
+                FormScan::Empty if self.eof => return None,
+                FormScan::Partial if self.eof => {
+                    let rest = std::mem::take(&mut self.buffer);
+                    return Some(Err(InputError::IncompleteForm(rest.trim().to_string())));
+                }
+                FormScan::Empty | FormScan::Partial => {}
+            }
+
+            match self.reader.read_line(&mut self.buffer) {
+                // A trailing newline terminates a final atom with no line break
+                Ok(0) => {
+                    self.eof = true;
+                    self.buffer.push('\n');
+                }
+                Ok(_) => {}
+                Err(e) => {
+                    self.eof = true;
+                    self.buffer.clear();
+                    return Some(Err(InputError::StdinReadError(e)));
+                }
+            }
+        }
+    }
+}
+
+/// Byte ranges of the top-level forms in `source`, leading comments excluded.
+/// A malformed or unterminated form extends to the next line that starts with
+/// an opening delimiter, where the following top-level form most likely begins.
+pub(crate) fn top_level_form_ranges(source: &str) -> Vec<Range<usize>> {
+    let mut ranges = Vec::new();
+    let mut offset = 0;
+    loop {
+        match scan_form(&source[offset..]) {
+            FormScan::Complete(start, end) => {
+                ranges.push(offset + start..offset + end);
+                offset += end;
+            }
+            FormScan::Malformed(start, end) => {
+                let resync = next_form_line(source, offset + end);
+                ranges.push(offset + start..resync);
+                offset = resync;
+            }
+            FormScan::Partial => {
+                let rest = &source[offset..];
+                let start = offset + (rest.len() - rest.trim_start().len());
+                let resync = next_form_line(source, start);
+                ranges.push(start..resync);
+                offset = resync;
+            }
+            FormScan::Empty => return ranges,
+        }
+    }
+}
+
+/// Offset of the first line after `from` that starts with an opening delimiter
+fn next_form_line(source: &str, from: usize) -> usize {
+    source[from..]
+        .match_indices('\n')
+        .map(|(i, _)| from + i + 1)
+        .find(|&line| source[line..].starts_with(['(', '[', '{']))
+        .unwrap_or(source.len())
+}
+
+enum FormScan {
+    /// A whole form spans these byte offsets (leading comments excluded)
+    Complete(usize, usize),
+    /// A form ended early on a mismatched or stray closing delimiter
+    Malformed(usize, usize),
+    /// A form has started but is not closed yet
+    Partial,
+    /// Only whitespace and comments so far
+    Empty,
+}
+
+/// Find the end of the first top-level form in `source`, skipping strings
+/// and comments when balancing delimiters.
+fn scan_form(source: &str) -> FormScan {
+    // Closing delimiters expected by the open forms, innermost last
+    let mut open: Vec<char> = Vec::new();
+    let mut in_string = false;
+    let mut escaped = false;
+    let mut in_line_comment = false;
+    let mut in_block_comment = false;
+    // Top-level atom in progress, and whether it is only reader prefixes so far ('`~@^#)
+    let mut in_atom = false;
+    let mut prefix_only = false;
+    let mut start = 0;
+
+    let mut chars = source.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        if in_string {
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == '"' {
+                in_string = false;
+                if open.is_empty() {
+                    return FormScan::Complete(start, i + 1);
+                }
+            }
+            continue;
+        }
+        if in_line_comment {
+            in_line_comment = c != '\n';
+            continue;
+        }
+        if in_block_comment {
+            if c == '|' && matches!(chars.peek(), Some((_, '#'))) {
+                chars.next();
+                in_block_comment = false;
+            }
+            continue;
+        }
+
+        let atom_ends = open.is_empty() && in_atom && !prefix_only;
+        let starts_form = open.is_empty() && !in_atom && !c.is_whitespace() && c != ',';
+        if starts_form && c != ';' && !(c == '#' && matches!(chars.peek(), Some((_, '|')))) {
+            start = i;
+        }
+        match c {
+            ';' => {
+                if atom_ends {
+                    return FormScan::Complete(start, i);
+                }
+                in_line_comment = true;
+            }
+            '#' if matches!(chars.peek(), Some((_, '|'))) => {
+                if atom_ends {
+                    return FormScan::Complete(start, i);
+                }
+                chars.next();
+                in_block_comment = true;
+            }
+            '"' => {
+                if atom_ends {
+                    return FormScan::Complete(start, i);
+                }
+                in_string = true;
+                in_atom = false;
+            }
+            '(' | '[' | '{' => {
+                if atom_ends {
+                    return FormScan::Complete(start, i);
+                }
+                open.push(match c {
+                    '(' => ')',
+                    '[' => ']',
+                    _ => '}',
+                });
+                in_atom = false;
+            }
+            ')' | ']' | '}' => {
+                if atom_ends {
+                    return FormScan::Complete(start, i);
+                }
+                // Stray and mismatched closers are handed to the parser to report
+                if open.pop() != Some(c) {
+                    return FormScan::Malformed(start, i + 1);
+                }
+                if open.is_empty() {
+                    return FormScan::Complete(start, i + 1);
+                }
+            }
+            c if c.is_whitespace() || c == ',' => {
+                if atom_ends {
+                    return FormScan::Complete(start, i);
+                }
+            }
+            _ => {
+                if open.is_empty() {
+                    if !in_atom {
+                        in_atom = true;
+                        prefix_only = true;
+                    }
+                    if !matches!(c, '\'' | '`' | '~' | '@' | '^' | '#') {
+                        prefix_only = false;
+                    }
+                }
+            }
+        }
+    }
+
+    if open.is_empty() && !in_string && !in_block_comment && !in_atom {
+        FormScan::Empty
+    } else {
+        FormScan::Partial
+    }
+}
+
 /// Validate input arguments for a given source type
 pub fn validate_input_args(
     source: InputSource,
@@ -231,6 +460,8 @@ pub enum InputError {
     },
     /// Error reading from stdin
     StdinReadError(std::io::Error),
+    /// Input ended in the middle of a top-level form
+    IncompleteForm(String),
     /// Interactive mode not supported in this context
     InteractiveNotSupported,
     /// No input source detected
@@ -260,6 +491,13 @@ impl std::fmt::Display for InputError {
             InputError::StdinReadError(error) => {
                 write!(f, "❌ Error reading from stdin: {}", error)
             }
+            InputError::IncompleteForm(form) => {
+                write!(
+                    f,
+                    "❌ Error: input ended inside an incomplete form: {}",
+                    form
+                )
+            }
             InputError::InteractiveNotSupported => {
                 write!(
                     f,
@@ -322,4 +560,47 @@ mod tests {
         // Valid pipe input
         assert!(validate_input_args(InputSource::Pipe, &None, &None).is_ok());
     }
+
+    #[test]
+    fn test_form_reader_evaluates_forms_as_they_are_read() {
+        use crate::runtime::execution_outcome::ExecutionOutcome;
+        use crate::runtime::ir_runtime::IrStrategy;
+        use crate::runtime::module_runtime::ModuleRegistry;
+        use crate::runtime::values::Value;
+        use crate::runtime::RuntimeStrategy;
+        use std::sync::Arc;
+
+        // The second form spans several lines, so the reader has to wait for it
+        let input = "(def greeting \"hi; there\") ; trailing comment\n(str greeting\n     \" \"\n     [1 2])";
+        let mut strategy = IrStrategy::new(Arc::new(ModuleRegistry::new()));
+        strategy.enable_persistent_env().unwrap();
+
+        let mut results = Vec::new();
+        for form in FormReader::new(io::Cursor::new(input)) {
+            let expr = crate::parser::parse_expression(&form.unwrap()).unwrap();
+            match strategy.run(&expr).unwrap() {
+                ExecutionOutcome::Complete(value) => results.push(value),
+                other => panic!("unexpected outcome {:?}", other),
+            }
+        }
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[1], Value::String("hi; there [1 2]".to_string()));
+    }
+
+    #[test]
+    fn test_form_reader_splits_atoms_and_reports_incomplete_forms() {
+        let forms: Vec<_> = FormReader::new(io::Cursor::new("1 :a 'sym #| note |# \"s\""))
+            .map(|f| f.unwrap())
+            .collect();
+        assert_eq!(forms, vec!["1", ":a", "'sym", "\"s\""]);
+
+        let mut reader = FormReader::new(io::Cursor::new("(+ 1 2)\n(let [x 1]\n"));
+        assert_eq!(reader.next().unwrap().unwrap(), "(+ 1 2)");
+        assert!(matches!(
+            reader.next(),
+            Some(Err(InputError::IncompleteForm(rest))) if rest == "(let [x 1]"
+        ));
+        assert!(reader.next().is_none());
+    }
 }
@@ -0,0 +1,335 @@
+//! Compile-time arity validation for calls to builtin functions.
+//!
+//! Walks converted IR and checks every call whose callee is a known builtin
+//! against the `Arity` it was registered with, so a plan calling `(inc 1 2)`
+//! is rejected before it runs. Names rebound anywhere in the program (let,
+//! fn params, def/defn, match patterns) are skipped rather than risk a false
+//! positive on a user function that shadows a builtin.
+
+use crate::ir::core::{IrNode, IrPattern, SourceLocation};
+use crate::runtime::module_runtime::ModuleRegistry;
+use crate::runtime::values::{Arity, Function, Value};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArityCheckError {
+    pub function: String,
+    pub expected: Arity,
+    pub actual: usize,
+    /// Span of the offending call, when the converter recorded one
+    pub location: Option<SourceLocation>,
+}
+
+impl fmt::Display for ArityCheckError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Arity mismatch in call to '{}': expected {}, got {}",
+            self.function,
+            self.expected.describe(),
+            self.actual
+        )?;
+        if let Some(loc) = &self.location {
+            match &loc.file {
+                Some(file) => write!(f, " at {}:{}:{}", file, loc.line, loc.column)?,
+                None => write!(f, " at {}:{}", loc.line, loc.column)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ArityCheckError {}
+
+/// Registered arities of the builtins exported by the registry's `stdlib` module.
+pub fn builtin_arities(module_registry: &ModuleRegistry) -> HashMap<String, Arity> {
+    let mut arities = HashMap::new();
+    if let Some(stdlib) = module_registry.get_module("stdlib") {
+        if let Ok(exports) = stdlib.exports.read() {
+            for (name, export) in exports.iter() {
+                let arity = match &export.value {
+                    Value::Function(Function::Builtin(f)) => &f.arity,
+                    Value::Function(Function::BuiltinWithContext(f)) => &f.arity,
+                    _ => continue,
+                };
+                arities.insert(name.clone(), arity.clone());
+            }
+        }
+    }
+    arities
+}
+
+/// Check every builtin call in `node` against `arities`.
+pub fn check_builtin_arities(
+    node: &IrNode,
+    arities: &HashMap<String, Arity>,
+) -> Result<(), ArityCheckError> {
+    match collect_builtin_arity_errors(node, arities)
+        .into_iter()
+        .next()
+    {
+        Some(error) => Err(error),
+        None => Ok(()),
+    }
+}
+
+/// Every arity mismatch in `node`, in source order, rather than just the first.
+pub fn collect_builtin_arity_errors(
+    node: &IrNode,
+    arities: &HashMap<String, Arity>,
+) -> Vec<ArityCheckError> {
+    let mut rebound = HashSet::new();
+    collect_bound_names(node, &mut rebound);
+    let mut errors = Vec::new();
+    check_node(node, arities, &rebound, &mut errors);
+    errors
+}
+
+fn check_node(
+    node: &IrNode,
+    arities: &HashMap<String, Arity>,
+    rebound: &HashSet<String>,
+    errors: &mut Vec<ArityCheckError>,
+) {
+    if let IrNode::Apply {
+        function,
+        arguments,
+        source_location,
+        ..
+    } = node
+    {
+        let callee = match function.as_ref() {
+            IrNode::VariableRef { name, .. } if !rebound.contains(name) => Some(name),
+            IrNode::QualifiedSymbolRef { module, symbol, .. } if module == "stdlib" => Some(symbol),
+            _ => None,
+        };
+        if let Some(name) = callee {
+            if let Some(arity) = arities.get(name) {
+                if !arity.accepts(arguments.len()) {
+                    errors.push(ArityCheckError {
+                        function: name.clone(),
+                        expected: arity.clone(),
+                        actual: arguments.len(),
+                        location: source_location.clone(),
+                    });
+                }
+            }
+        }
+    }
+    for child in children(node) {
+        check_node(child, arities, rebound, errors);
+    }
+}
+
+fn collect_bound_names(node: &IrNode, names: &mut HashSet<String>) {
+    match node {
+        IrNode::VariableBinding { name, .. }
+        | IrNode::VariableDef { name, .. }
+        | IrNode::FunctionDef { name, .. } => {
+            names.insert(name.clone());
+        }
+        IrNode::Destructure { pattern, .. } => collect_pattern_names(pattern, names),
+        IrNode::Match { clauses, .. } => {
+            for clause in clauses {
+                collect_pattern_names(&clause.pattern, names);
+            }
+        }
+        IrNode::TryCatch { catch_clauses, .. } => {
+            for clause in catch_clauses {
+                collect_pattern_names(&clause.error_pattern, names);
+                if let Some(binding) = &clause.binding {
+                    names.insert(binding.clone());
+                }
+            }
+        }
+        _ => {}
+    }
+    for child in children(node) {
+        collect_bound_names(child, names);
+    }
+}
+
+fn collect_pattern_names(pattern: &IrPattern, names: &mut HashSet<String>) {
+    match pattern {
+        IrPattern::Variable(name) => {
+            names.insert(name.clone());
+        }
+        IrPattern::Vector { elements, rest } => {
+            for element in elements {
+                collect_pattern_names(element, names);
+            }
+            names.extend(rest.iter().cloned());
+        }
+        IrPattern::Map { entries, rest } => {
+            for entry in entries {
+                collect_pattern_names(&entry.pattern, names);
+            }
+            names.extend(rest.iter().cloned());
+        }
+        IrPattern::Literal(_) | IrPattern::Wildcard | IrPattern::Type(_) => {}
+    }
+}
+
+fn children(node: &IrNode) -> Vec<&IrNode> {
+    match node {
+        IrNode::Program { forms, .. } => forms.iter().collect(),
+        IrNode::Literal { .. }
+        | IrNode::VariableBinding { .. }
+        | IrNode::VariableRef { .. }
+        | IrNode::ResourceRef { .. }
+        | IrNode::QualifiedSymbolRef { .. }
+        | IrNode::Import { .. } => Vec::new(),
+        IrNode::VariableDef { init_expr, .. } => vec![init_expr],
+        IrNode::Apply {
+            function,
+            arguments,
+            ..
+        } => std::iter::once(function.as_ref())
+            .chain(arguments.iter())
+            .collect(),
+        IrNode::Lambda {
+            params,
+            variadic_param,
+            body,
+            ..
+        } => params
+            .iter()
+            .chain(variadic_param.as_deref())
+            .chain(body.iter())
+            .collect(),
+        IrNode::Param { binding, .. } => vec![binding],
+        IrNode::If {
+            condition,
+            then_branch,
+            else_branch,
+            ..
+        } => [Some(condition.as_ref()), Some(then_branch.as_ref())]
+            .into_iter()
+            .flatten()
+            .chain(else_branch.as_deref())
+            .collect(),
+        IrNode::Let { bindings, body, .. } => bindings
+            .iter()
+            .flat_map(|b| [&b.pattern, &b.init_expr])
+            .chain(body.iter())
+            .collect(),
+        IrNode::Do { expressions, .. } => expressions.iter().collect(),
+        IrNode::Match {
+            expression,
+            clauses,
+            ..
+        } => std::iter::once(expression.as_ref())
+            .chain(
+                clauses
+                    .iter()
+                    .flat_map(|c| c.guard.iter().chain(std::iter::once(&c.body))),
+            )
+            .collect(),
+        IrNode::TryCatch {
+            try_body,
+            catch_clauses,
+            finally_body,
+            ..
+        } => try_body
+            .iter()
+            .chain(catch_clauses.iter().flat_map(|c| c.body.iter()))
+            .chain(finally_body.iter().flatten())
+            .collect(),
+        IrNode::Parallel { bindings, .. } => bindings
+            .iter()
+            .flat_map(|b| [&b.binding, &b.init_expr])
+            .collect(),
+        IrNode::WithResource {
+            binding,
+            init_expr,
+            body,
+            ..
+        } => [binding.as_ref(), init_expr.as_ref()]
+            .into_iter()
+            .chain(body.iter())
+            .collect(),
+        IrNode::LogStep { values, .. } => values.iter().collect(),
+        IrNode::Step {
+            expose_override,
+            context_keys_override,
+            params,
+            body,
+            ..
+        } => expose_override
+            .as_deref()
+            .into_iter()
+            .chain(context_keys_override.as_deref())
+            .chain(params.as_deref())
+            .chain(body.iter())
+            .collect(),
+        IrNode::Module { definitions, .. } => definitions.iter().collect(),
+        IrNode::FunctionDef { lambda, .. } => vec![lambda],
+        IrNode::Task {
+            metadata,
+            intent,
+            contracts,
+            plan,
+            execution_trace,
+            ..
+        } => metadata
+            .values()
+            .chain([intent.as_ref(), contracts.as_ref(), plan.as_ref()])
+            .chain(execution_trace.iter())
+            .collect(),
+        IrNode::DiscoverAgents { criteria, .. } => vec![criteria],
+        IrNode::Vector { elements, .. } => elements.iter().collect(),
+        IrNode::Map { entries, .. } => entries.iter().flat_map(|e| [&e.key, &e.value]).collect(),
+        IrNode::Destructure { value, .. } => vec![value],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::converter::IrConverter;
+    use crate::parser::parse_expression;
+    use crate::runtime::stdlib::load_stdlib;
+
+    fn check(source: &str) -> Result<(), ArityCheckError> {
+        let registry = ModuleRegistry::new();
+        load_stdlib(&registry).expect("stdlib should load");
+        let expr = parse_expression(source).expect("parse failed");
+        let ir = IrConverter::with_module_registry(&registry)
+            .convert_expression(expr)
+            .expect("conversion failed");
+        check_builtin_arities(&ir, &builtin_arities(&registry))
+    }
+
+    #[test]
+    fn test_fixed_arity_violation_is_reported() {
+        let err = check("(inc 1 2)").unwrap_err();
+        assert_eq!(err.function, "inc");
+        assert_eq!(err.expected, Arity::Fixed(1));
+        assert_eq!(err.actual, 2);
+        assert!(err.to_string().contains("expected 1, got 2"));
+
+        // Variadic minimums and ranges are bounds-checked
+        assert!(check("(+)").is_err());
+        assert!(check("(reduce +)").is_err());
+        assert!(check("(reduce + 0 [1] [2])").is_err());
+
+        // Nested calls are checked too
+        assert!(check("(let [x 1] (if true (+ x (count [1] [2])) 0))").is_err());
+    }
+
+    #[test]
+    fn test_variadic_and_valid_calls_pass() {
+        assert_eq!(check("(+ 1 2 3)"), Ok(()));
+        assert_eq!(check("(+ 1)"), Ok(()));
+        assert_eq!(check("(reduce + [1 2])"), Ok(()));
+        assert_eq!(check("(reduce + 0 [1 2])"), Ok(()));
+        assert_eq!(check("(inc (count [1 2]))"), Ok(()));
+    }
+
+    #[test]
+    fn test_shadowed_builtins_are_not_checked() {
+        assert_eq!(check("(let [inc (fn [a b] (+ a b))] (inc 1 2))"), Ok(()));
+        assert_eq!(check("((fn [count] (count 1 2)) +)"), Ok(()));
+    }
+}
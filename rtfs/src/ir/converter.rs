@@ -33,6 +33,10 @@ pub enum IrConversionError {
         form: String,
         message: String,
     },
+    /// Valid RTFS that the IR pipeline cannot lower yet; the AST evaluator handles it
+    Unsupported {
+        construct: String,
+    },
     InternalError {
         message: String,
     },
@@ -115,6 +119,10 @@ pub struct IrConverter<'a> {
     module_registry: Option<&'a ModuleRegistry>,
     /// When true, unknown symbols cause a conversion error instead of deferring to runtime
     strict_unknown_symbols: bool,
+    /// When set, undefined symbols are recorded here and conversion carries on
+    collected_errors: Option<Vec<IrConversionError>>,
+    /// Source position of the innermost call being converted
+    current_location: Option<SourceLocation>,
 }
 
 impl<'a> IrConverter<'a> {
@@ -129,6 +137,8 @@ impl<'a> IrConverter<'a> {
             capture_analysis: HashMap::new(),
             module_registry: None,
             strict_unknown_symbols: false,
+            collected_errors: None,
+            current_location: None,
         };
 
         // Add built-in functions to global scope
@@ -146,6 +156,8 @@ impl<'a> IrConverter<'a> {
             capture_analysis: HashMap::new(),
             module_registry: Some(registry),
             strict_unknown_symbols: false,
+            collected_errors: None,
+            current_location: None,
         };
 
         // Add built-in functions to global scope
@@ -159,6 +171,36 @@ impl<'a> IrConverter<'a> {
         self
     }
 
+    /// Record undefined symbols instead of stopping at the first one; retrieve
+    /// them with [`Self::take_collected_errors`] after conversion
+    pub fn collecting(mut self) -> Self {
+        self.collected_errors = Some(Vec::new());
+        self
+    }
+
+    /// Errors recorded so far in collecting mode, in source order
+    pub fn take_collected_errors(&mut self) -> Vec<IrConversionError> {
+        self.collected_errors
+            .as_mut()
+            .map(std::mem::take)
+            .unwrap_or_default()
+    }
+
+    /// Report an undefined symbol: an error, or a recorded one in collecting mode
+    fn undefined_symbol(&mut self, symbol: String) -> IrConversionResult<()> {
+        let error = IrConversionError::UndefinedSymbol {
+            symbol,
+            location: self.current_location.clone(),
+        };
+        match self.collected_errors.as_mut() {
+            Some(errors) => {
+                errors.push(error);
+                Ok(())
+            }
+            None => Err(error),
+        }
+    }
+
     pub fn next_id(&mut self) -> NodeId {
         let id = self.next_node_id;
         self.next_node_id += 1;
@@ -1020,8 +1062,28 @@ impl<'a> IrConverter<'a> {
         match expr {
             Expression::Literal(lit) => self.convert_literal(lit),
             Expression::Symbol(sym) => self.convert_symbol_ref(sym),
-            Expression::FunctionCall { callee, arguments } => {
-                self.convert_function_call(*callee, arguments)
+            Expression::FunctionCall {
+                callee,
+                arguments,
+                span,
+            } => {
+                let location = span.position().map(|(line, column)| SourceLocation {
+                    line,
+                    column,
+                    file: None,
+                });
+                let enclosing = self.current_location.clone();
+                if location.is_some() {
+                    self.current_location = location.clone();
+                }
+                let converted = self.convert_function_call(*callee, arguments);
+                self.current_location = enclosing;
+                let mut node = converted?;
+                // Keep the call's source position so runtime errors can point back to it
+                if let Some(location) = location {
+                    node.source_location_mut().get_or_insert(location);
+                }
+                Ok(node)
             }
             Expression::If(if_expr) => self.convert_if(if_expr),
             Expression::Let(let_expr) => self.convert_let(let_expr),
@@ -1039,8 +1101,9 @@ impl<'a> IrConverter<'a> {
             Expression::Deref(expr) => {
                 // @atom-name desugars to (deref atom-name)
                 let deref_call = Expression::FunctionCall {
-                    callee: Box::new(Expression::Symbol(Symbol("deref".to_string()))),
+                    callee: Box::new(Expression::Symbol(Symbol("deref".into()))),
                     arguments: vec![*expr],
+                    span: Span::unknown(),
                 };
                 self.convert_expression(deref_call)
             }
@@ -1069,14 +1132,18 @@ impl<'a> IrConverter<'a> {
                 // Metadata handling is done at the Evaluator level for learning hints.
                 self.convert_expression(*expr)
             }
-            // Macro-related expressions should have been expanded away before IR conversion
-            Expression::Quasiquote(_)
-            | Expression::Unquote(_)
-            | Expression::UnquoteSplicing(_)
-            | Expression::Defmacro(_) => Err(IrConversionError::InternalError {
-                message: "Macro-related expressions should have been expanded before IR conversion"
-                    .to_string(),
+            // Quasiquotes outside macro bodies are evaluated at runtime, by the AST evaluator only
+            Expression::Quasiquote(_) => Err(IrConversionError::Unsupported {
+                construct: "quasiquote".to_string(),
             }),
+            // Macro-related expressions should have been expanded away before IR conversion
+            Expression::Unquote(_) | Expression::UnquoteSplicing(_) | Expression::Defmacro(_) => {
+                Err(IrConversionError::InternalError {
+                    message:
+                        "Macro-related expressions should have been expanded before IR conversion"
+                            .to_string(),
+                })
+            }
         }
     }
 
@@ -1137,9 +1204,13 @@ impl<'a> IrConverter<'a> {
                 }
 
                 // If not found in module registry, it's an error
-                return Err(IrConversionError::UndefinedSymbol {
-                    symbol: name,
-                    location: None, // TODO: Add source location
+                self.undefined_symbol(name.to_string())?;
+                return Ok(IrNode::VariableRef {
+                    id,
+                    name: name.to_string(),
+                    binding_id: 0,
+                    ir_type: IrType::Any,
+                    source_location: None,
                 });
             }
         }
@@ -1148,7 +1219,7 @@ impl<'a> IrConverter<'a> {
         match self.lookup_symbol(&name) {
             Some(binding_info) => Ok(IrNode::VariableRef {
                 id,
-                name,
+                name: name.to_string(),
                 binding_id: binding_info.binding_id,
                 ir_type: binding_info.ir_type.clone(),
                 source_location: None,
@@ -1157,11 +1228,11 @@ impl<'a> IrConverter<'a> {
                 // Check if it's available in the stdlib module
                 if let Some(registry) = self.module_registry {
                     if let Some(stdlib_module) = registry.get_module("stdlib") {
-                        if let Some(export) = stdlib_module.exports.read().unwrap().get(&name) {
+                        if let Some(export) = stdlib_module.exports.read().unwrap().get(name.as_str()) {
                             return Ok(IrNode::QualifiedSymbolRef {
                                 id,
                                 module: "stdlib".to_string(),
-                                symbol: name,
+                                symbol: name.to_string(),
                                 ir_type: export.ir_type.clone(),
                                 source_location: None,
                             });
@@ -1172,15 +1243,12 @@ impl<'a> IrConverter<'a> {
                 }
                 // If in strict mode, error on unknown symbols at conversion time
                 if self.strict_unknown_symbols {
-                    return Err(IrConversionError::UndefinedSymbol {
-                        symbol: name,
-                        location: None,
-                    });
+                    self.undefined_symbol(name.to_string())?;
                 }
                 // Fallback to a dynamic variable ref to be resolved at runtime
                 Ok(IrNode::VariableRef {
                     id,
-                    name,
+                    name: name.to_string(),
                     binding_id: 0,
                     ir_type: IrType::Any,
                     source_location: None,
@@ -1219,6 +1287,20 @@ impl<'a> IrConverter<'a> {
                 "dotimes" => {
                     return self.convert_dotimes_special_form(arguments);
                 }
+                "when" | "when-not" => {
+                    return self.convert_when_special_form(name, arguments);
+                }
+                "and" | "or" => {
+                    return self.convert_short_circuit_special_form(name, arguments);
+                }
+                "->" | "->>" => {
+                    let expanded = crate::compiler::threading::expand_threading(name, &arguments)
+                        .map_err(|message| IrConversionError::InvalidSpecialForm {
+                            form: name.to_string(),
+                            message,
+                        })?;
+                    return self.convert_expression(expanded);
+                }
                 _ => {}
             }
         }
@@ -1284,7 +1366,7 @@ impl<'a> IrConverter<'a> {
             arguments: vec![
                 IrNode::VariableRef {
                     id: self.next_id(),
-                    name: sym,
+                    name: sym.to_string(),
                     binding_id: 0, // Will be resolved during execution
                     ir_type: IrType::Any,
                     source_location: None,
@@ -1326,19 +1408,19 @@ impl<'a> IrConverter<'a> {
 
                     // Add parameter to scope
                     let binding_info = BindingInfo {
-                        name: param_name.clone(),
+                        name: param_name.to_string(),
                         binding_id: param_id,
                         ir_type: param_type.clone(),
                         kind: BindingKind::Parameter,
                     };
-                    self.define_binding(param_name.clone(), binding_info);
+                    self.define_binding(param_name.to_string(), binding_info);
 
                     // Create parameter node
                     params.push(IrNode::Param {
                         id: param_id,
                         binding: Box::new(IrNode::VariableBinding {
                             id: param_id,
-                            name: param_name.clone(),
+                            name: param_name.to_string(),
                             ir_type: param_type.clone(),
                             source_location: None,
                         }),
@@ -1617,6 +1699,79 @@ impl<'a> IrConverter<'a> {
         })
     }
 
+    /// Convert (when test body...) / (when-not test body...) into an `if`
+    /// whose skipped branch is nil and whose taken branch is a `do` block.
+    fn convert_when_special_form(
+        &mut self,
+        form: &str,
+        arguments: Vec<Expression>,
+    ) -> IrConversionResult<IrNode> {
+        let mut arguments = arguments.into_iter();
+        let Some(test) = arguments.next() else {
+            return Err(IrConversionError::InvalidSpecialForm {
+                form: form.to_string(),
+                message: format!("{} requires a test expression", form),
+            });
+        };
+        let body = Box::new(Expression::Do(DoExpr {
+            expressions: arguments.collect(),
+        }));
+        let nil = Box::new(Expression::Literal(Literal::Nil));
+        let (then_branch, else_branch) = if form == "when" {
+            (body, nil)
+        } else {
+            (nil, body)
+        };
+        self.convert_expression(Expression::If(IfExpr {
+            condition: Box::new(test),
+            then_branch,
+            else_branch: Some(else_branch),
+        }))
+    }
+
+    /// Convert (and a b ...) / (or a b ...) into nested `if`s so later
+    /// arguments only run when needed. Each tested value is bound once and
+    /// returned as-is, so `(or nil :x)` yields `:x` rather than `true`.
+    fn convert_short_circuit_special_form(
+        &mut self,
+        form: &str,
+        arguments: Vec<Expression>,
+    ) -> IrConversionResult<IrNode> {
+        let mut arguments = arguments.into_iter();
+        let Some(first) = arguments.next() else {
+            return self.convert_expression(Expression::Literal(Literal::Boolean(form == "and")));
+        };
+        let rest: Vec<Expression> = arguments.collect();
+        if rest.is_empty() {
+            return self.convert_expression(first);
+        }
+
+        let tested = Symbol::new(&format!("__{}_value_{}", form, self.next_id()));
+        let tested_expr = Box::new(Expression::Symbol(tested.clone()));
+        let rest_expr = Box::new(Expression::FunctionCall {
+            callee: Box::new(Expression::Symbol(Symbol::new(form))),
+            arguments: rest,
+            span: Span::unknown(),
+        });
+        let (then_branch, else_branch) = if form == "and" {
+            (rest_expr, tested_expr.clone())
+        } else {
+            (tested_expr.clone(), rest_expr)
+        };
+        self.convert_expression(Expression::Let(LetExpr {
+            bindings: vec![LetBinding {
+                pattern: Pattern::Symbol(tested),
+                type_annotation: None,
+                value: Box::new(first),
+            }],
+            body: vec![Expression::If(IfExpr {
+                condition: tested_expr,
+                then_branch,
+                else_branch: Some(else_branch),
+            })],
+        }))
+    }
+
     /// Convert dotimes special form: (dotimes [i n] body)
     fn convert_dotimes_special_form(
         &mut self,
@@ -1674,7 +1829,7 @@ impl<'a> IrConverter<'a> {
             arguments: vec![
                 IrNode::VariableRef {
                     id: self.next_id(),
-                    name: loop_var.0,
+                    name: loop_var.0.to_string(),
                     binding_id: 0,
                     ir_type: IrType::Any,
                     source_location: None,
@@ -1729,9 +1884,10 @@ impl<'a> IrConverter<'a> {
         // Enter new scope for let bindings
         self.enter_scope();
 
-        // Two-pass approach for recursive function bindings (similar to letrec)
-        let mut function_bindings = Vec::new();
-        let mut other_bindings = Vec::new();
+        // Two-pass approach for recursive function bindings (similar to letrec):
+        // function names are in scope from the start, but bindings are still
+        // converted in source order so each one sees the bindings before it.
+        let mut ordered_bindings = Vec::new();
 
         // Pass 1: Identify function bindings and create placeholders
         for binding in let_expr.bindings {
@@ -1741,7 +1897,7 @@ impl<'a> IrConverter<'a> {
 
                     // Create placeholder binding info for the function
                     let binding_info = BindingInfo {
-                        name: symbol.0.clone(),
+                        name: symbol.0.to_string(),
                         binding_id,
                         ir_type: IrType::Function {
                             param_types: vec![IrType::Any], // Will be refined later
@@ -1752,18 +1908,22 @@ impl<'a> IrConverter<'a> {
                     };
 
                     // Add placeholder to scope immediately
-                    self.define_binding(symbol.0.clone(), binding_info);
-                    function_bindings.push((binding, binding_id));
+                    self.define_binding(symbol.0.to_string(), binding_info);
+                    ordered_bindings.push((binding, Some(binding_id)));
                 } else {
-                    other_bindings.push(binding);
+                    ordered_bindings.push((binding, None));
                 }
             } else {
-                other_bindings.push(binding);
+                ordered_bindings.push((binding, None));
             }
         }
 
-        // Pass 2: Process function bindings with placeholders in scope
-        for (binding, binding_id) in function_bindings {
+        // Pass 2: Convert bindings in order with function placeholders in scope
+        for (binding, function_binding_id) in ordered_bindings {
+            let Some(binding_id) = function_binding_id else {
+                bindings.push(self.convert_let_value_binding(binding)?);
+                continue;
+            };
             // Do NOT enter a new scope here; the placeholder is already in the current scope
             let init_expr = match *binding.value {
                 Expression::Fn(fn_expr) => {
@@ -1781,17 +1941,17 @@ impl<'a> IrConverter<'a> {
                                     p_def.type_annotation.clone(),
                                 )?;
                                 let binding_info = BindingInfo {
-                                    name: s.0.clone(),
+                                    name: s.0.to_string(),
                                     binding_id: param_id,
                                     ir_type: param_type.clone().unwrap_or(IrType::Any),
                                     kind: BindingKind::Parameter,
                                 };
-                                self.define_binding(s.0.clone(), binding_info);
+                                self.define_binding(s.0.to_string(), binding_info);
                                 params.push(IrNode::Param {
                                     id: param_id,
                                     binding: Box::new(IrNode::VariableBinding {
                                         id: param_id,
-                                        name: s.0,
+                                        name: s.0.to_string(),
                                         ir_type: param_type.clone().unwrap_or(IrType::Any),
                                         source_location: None,
                                     }),
@@ -1874,18 +2034,18 @@ impl<'a> IrConverter<'a> {
 
                             // Define the variadic binding in current scope
                             let binding_info = BindingInfo {
-                                name: s.0.clone(),
+                                name: s.0.to_string(),
                                 binding_id: var_param_id,
                                 ir_type: var_param_type.clone().unwrap_or(IrType::Any),
                                 kind: BindingKind::Parameter,
                             };
-                            self.define_binding(s.0.clone(), binding_info);
+                            self.define_binding(s.0.to_string(), binding_info);
 
                             Some(Box::new(IrNode::Param {
                                 id: var_param_id,
                                 binding: Box::new(IrNode::VariableBinding {
                                     id: var_param_id,
-                                    name: s.0,
+                                    name: s.0.to_string(),
                                     ir_type: var_param_type.clone().unwrap_or(IrType::Any),
                                     source_location: None,
                                 }),
@@ -1951,44 +2111,6 @@ impl<'a> IrConverter<'a> {
             });
         }
 
-        // Process non-function bindings sequentially
-        for binding in other_bindings {
-            let binding_id = self.next_id();
-            let pattern_clone = binding.pattern.clone();
-            let init_expr = self.convert_expression(*binding.value)?;
-            // If a type annotation exists, treat it as the binding's type in the environment.
-            // This allows `(let [x :T (call ...)] ...)` to give `x` the static type `T`,
-            // while runtime performs the checked-cast when executing the binding.
-            let type_annotation_ir: Option<IrType> = binding
-                .type_annotation
-                .clone()
-                .map(|t| self.convert_type_annotation(t))
-                .transpose()?;
-            let inferred_type = init_expr.ir_type().cloned().unwrap_or(IrType::Any);
-            let binding_type = type_annotation_ir.clone().unwrap_or(inferred_type);
-            let pattern_node =
-                self.convert_pattern(binding.pattern, binding_id, binding_type.clone())?;
-
-            // Add all symbols from the pattern to scope after converting init expression
-            let pattern_symbols = self.extract_pattern_symbols(&pattern_clone);
-            for symbol_name in pattern_symbols {
-                let symbol_binding_id = self.next_id();
-                let binding_info = BindingInfo {
-                    name: symbol_name.clone(),
-                    binding_id: symbol_binding_id,
-                    ir_type: binding_type.clone(),
-                    kind: BindingKind::Variable,
-                };
-                self.define_binding(symbol_name, binding_info);
-            }
-
-            bindings.push(IrLetBinding {
-                pattern: pattern_node,
-                type_annotation: type_annotation_ir,
-                init_expr,
-            });
-        }
-
         // Convert body expressions in the new scope
         let mut body_exprs = Vec::new();
         for body_expr in let_expr.body {
@@ -2014,6 +2136,45 @@ impl<'a> IrConverter<'a> {
         })
     }
 
+    /// Convert a non-function let binding, bringing its pattern symbols into
+    /// scope only after the init expression so it cannot see itself.
+    fn convert_let_value_binding(&mut self, binding: LetBinding) -> IrConversionResult<IrLetBinding> {
+        let binding_id = self.next_id();
+        let pattern_clone = binding.pattern.clone();
+        let init_expr = self.convert_expression(*binding.value)?;
+        // If a type annotation exists, treat it as the binding's type in the environment.
+        // This allows `(let [x :T (call ...)] ...)` to give `x` the static type `T`,
+        // while runtime performs the checked-cast when executing the binding.
+        let type_annotation_ir: Option<IrType> = binding
+            .type_annotation
+            .clone()
+            .map(|t| self.convert_type_annotation(t))
+            .transpose()?;
+        let inferred_type = init_expr.ir_type().cloned().unwrap_or(IrType::Any);
+        let binding_type = type_annotation_ir.clone().unwrap_or(inferred_type);
+        let pattern_node =
+            self.convert_pattern(binding.pattern, binding_id, binding_type.clone())?;
+
+        // Add all symbols from the pattern to scope after converting init expression
+        let pattern_symbols = self.extract_pattern_symbols(&pattern_clone);
+        for symbol_name in pattern_symbols {
+            let symbol_binding_id = self.next_id();
+            let binding_info = BindingInfo {
+                name: symbol_name.clone(),
+                binding_id: symbol_binding_id,
+                ir_type: binding_type.clone(),
+                kind: BindingKind::Variable,
+            };
+            self.define_binding(symbol_name, binding_info);
+        }
+
+        Ok(IrLetBinding {
+            pattern: pattern_node,
+            type_annotation: type_annotation_ir,
+            init_expr,
+        })
+    }
+
     fn convert_do(&mut self, do_expr: DoExpr) -> IrConversionResult<IrNode> {
         let id = self.next_id();
         let mut expressions = Vec::new();
@@ -2050,18 +2211,18 @@ impl<'a> IrConverter<'a> {
                     let param_type = self.convert_type_annotation_option(p_def.type_annotation)?;
 
                     let binding_info = BindingInfo {
-                        name: s.0.clone(),
+                        name: s.0.to_string(),
                         binding_id: param_id,
                         ir_type: param_type.clone().unwrap_or(IrType::Any),
                         kind: BindingKind::Parameter,
                     };
-                    self.define_binding(s.0.clone(), binding_info);
+                    self.define_binding(s.0.to_string(), binding_info);
 
                     params.push(IrNode::Param {
                         id: param_id,
                         binding: Box::new(IrNode::VariableBinding {
                             id: param_id,
-                            name: s.0,
+                            name: s.0.to_string(),
                             ir_type: param_type.clone().unwrap_or(IrType::Any),
                             source_location: None,
                         }),
@@ -2148,18 +2309,18 @@ impl<'a> IrConverter<'a> {
                     self.convert_type_annotation_option(variadic_param_def.type_annotation)?;
 
                 let binding_info = BindingInfo {
-                    name: s.0.clone(),
+                    name: s.0.to_string(),
                     binding_id: param_id,
                     ir_type: param_type.clone().unwrap_or(IrType::Any),
                     kind: BindingKind::Parameter,
                 };
-                self.define_binding(s.0.clone(), binding_info);
+                self.define_binding(s.0.to_string(), binding_info);
 
                 Some(Box::new(IrNode::Param {
                     id: param_id,
                     binding: Box::new(IrNode::VariableBinding {
                         id: param_id,
-                        name: s.0,
+                        name: s.0.to_string(),
                         ir_type: param_type.clone().unwrap_or(IrType::Any),
                         source_location: None,
                     }),
@@ -2377,7 +2538,7 @@ impl<'a> IrConverter<'a> {
             let pattern = match clause.pattern {
                 CatchPattern::Keyword(k) => IrPattern::Literal(Literal::Keyword(k)),
                 CatchPattern::Type(t) => IrPattern::Type(self.convert_type_annotation(t)?),
-                CatchPattern::Symbol(s) => IrPattern::Variable(s.0),
+                CatchPattern::Symbol(s) => IrPattern::Variable(s.0.to_string()),
                 CatchPattern::Wildcard => IrPattern::Wildcard,
             };
             // Enter a new scope for the catch body so the binding and any pattern vars are in scope
@@ -2397,12 +2558,12 @@ impl<'a> IrConverter<'a> {
             // Also declare the explicit catch binding symbol (e.g., `e`)
             let binding_symbol = clause.binding.0.clone();
             let catch_binding_info = BindingInfo {
-                name: binding_symbol.clone(),
+                name: binding_symbol.to_string(),
                 binding_id: self.next_id(),
                 ir_type: IrType::Any,
                 kind: BindingKind::Variable,
             };
-            self.define_binding(binding_symbol.clone(), catch_binding_info);
+            self.define_binding(binding_symbol.to_string(), catch_binding_info);
 
             // Now convert the catch body within this scope
             let body = clause
@@ -2416,7 +2577,7 @@ impl<'a> IrConverter<'a> {
 
             catch_clauses.push(IrCatchClause {
                 error_pattern: pattern,
-                binding: Some(binding_symbol),
+                binding: Some(binding_symbol.to_string()),
                 body,
             });
         }
@@ -2453,16 +2614,16 @@ impl<'a> IrConverter<'a> {
         let ir_type = init_expr.ir_type().cloned().unwrap_or(IrType::Any);
 
         let binding_info = BindingInfo {
-            name: name.clone(),
+            name: name.to_string(),
             binding_id: id, // Use def's ID as binding ID
             ir_type: ir_type.clone(),
             kind: BindingKind::Variable,
         };
-        self.define_binding(name.clone(), binding_info);
+        self.define_binding(name.to_string(), binding_info);
 
         Ok(IrNode::VariableDef {
             id,
-            name,
+            name: name.to_string(),
             type_annotation,
             init_expr,
             ir_type,
@@ -2485,16 +2646,16 @@ impl<'a> IrConverter<'a> {
         let ir_type = lambda.ir_type().cloned().unwrap_or(IrType::Any);
 
         let binding_info = BindingInfo {
-            name: name.clone(),
+            name: name.to_string(),
             binding_id: id,
             ir_type: ir_type.clone(),
             kind: BindingKind::Function,
         };
-        self.define_binding(name.clone(), binding_info);
+        self.define_binding(name.to_string(), binding_info);
 
         Ok(IrNode::FunctionDef {
             id,
-            name,
+            name: name.to_string(),
             lambda: Box::new(lambda),
             ir_type,
             source_location: None,
@@ -2593,17 +2754,17 @@ impl<'a> IrConverter<'a> {
 
         // Define the constructor function in the environment
         let binding_info = BindingInfo {
-            name: name.clone(),
+            name: name.to_string(),
             binding_id: id,
             ir_type: constructor_type.clone(),
             kind: BindingKind::Function,
         };
-        self.define_binding(name.clone(), binding_info);
+        self.define_binding(name.to_string(), binding_info);
 
         // Return a function definition that binds the constructor to the struct name
         Ok(IrNode::FunctionDef {
             id,
-            name: name.clone(),
+            name: name.to_string(),
             lambda: Box::new(constructor_lambda),
             ir_type: constructor_type,
             source_location: None,
@@ -2620,7 +2781,7 @@ impl<'a> IrConverter<'a> {
         match pattern {
             Pattern::Symbol(sym) => Ok(IrNode::VariableBinding {
                 id: binding_id,
-                name: sym.0,
+                name: sym.0.to_string(),
                 ir_type,
                 source_location: None,
             }),
@@ -2641,7 +2802,7 @@ impl<'a> IrConverter<'a> {
                         .into_iter()
                         .map(|p| self.convert_pattern_to_irpattern(p))
                         .collect::<Result<_, _>>()?,
-                    rest: rest.map(|s| s.0),
+                    rest: rest.map(|s| s.0.to_string()),
                 };
                 Ok(IrNode::Destructure {
                     id: binding_id,
@@ -2675,7 +2836,7 @@ impl<'a> IrConverter<'a> {
                                 // Keywords are represented without leading ':' in AST/Values
                                 ir_entries.push(IrMapPatternEntry {
                                     key: MapKey::Keyword(Keyword(sym_text.clone())),
-                                    pattern: IrPattern::Variable(sym_text),
+                                    pattern: IrPattern::Variable(sym_text.to_string()),
                                 });
                             }
                         }
@@ -2683,7 +2844,7 @@ impl<'a> IrConverter<'a> {
                 }
                 let ir_pattern = IrPattern::Map {
                     entries: ir_entries,
-                    rest: rest.map(|s| s.0),
+                    rest: rest.map(|s| s.0.to_string()),
                 };
                 Ok(IrNode::Destructure {
                     id: binding_id,
@@ -2704,7 +2865,7 @@ impl<'a> IrConverter<'a> {
 
     fn convert_pattern_to_irpattern(&mut self, pattern: Pattern) -> IrConversionResult<IrPattern> {
         match pattern {
-            Pattern::Symbol(sym) => Ok(IrPattern::Variable(sym.0)),
+            Pattern::Symbol(sym) => Ok(IrPattern::Variable(sym.0.to_string())),
             Pattern::Wildcard => Ok(IrPattern::Wildcard),
             Pattern::VectorDestructuring {
                 elements,
@@ -2715,7 +2876,7 @@ impl<'a> IrConverter<'a> {
                     .into_iter()
                     .map(|p| self.convert_pattern_to_irpattern(p))
                     .collect::<Result<_, _>>()?,
-                rest: rest.map(|s| s.0),
+                rest: rest.map(|s| s.0.to_string()),
             }),
             Pattern::MapDestructuring {
                 entries,
@@ -2735,7 +2896,7 @@ impl<'a> IrConverter<'a> {
                                 // Keywords are represented without leading ':' in AST/Values
                                 ir_entries.push(IrMapPatternEntry {
                                     key: MapKey::Keyword(Keyword(sym_text.clone())),
-                                    pattern: IrPattern::Variable(sym_text),
+                                    pattern: IrPattern::Variable(sym_text.to_string()),
                                 });
                             }
                         }
@@ -2743,7 +2904,7 @@ impl<'a> IrConverter<'a> {
                 }
                 Ok(IrPattern::Map {
                     entries: ir_entries,
-                    rest: rest.map(|s| s.0),
+                    rest: rest.map(|s| s.0.to_string()),
                 })
             }
         }
@@ -2950,11 +3111,11 @@ impl<'a> IrConverter<'a> {
                 PrimitiveType::Nil => IrType::Nil,
                 PrimitiveType::Keyword => IrType::Keyword,
                 PrimitiveType::Symbol => IrType::Symbol,
-                PrimitiveType::Custom(kw) => IrType::TypeRef(kw.0), // Custom types become type references
+                PrimitiveType::Custom(kw) => IrType::TypeRef(kw.0.to_string()), // Custom types become type references
             }),
             TypeExpr::Any => Ok(IrType::Any),
             TypeExpr::Never => Ok(IrType::Never),
-            TypeExpr::Alias(symbol) => Ok(IrType::TypeRef(symbol.0)),
+            TypeExpr::Alias(symbol) => Ok(IrType::TypeRef(symbol.0.to_string())),
             TypeExpr::Vector(element_type) => {
                 let element_ir_type = self.convert_type_annotation(*element_type)?;
                 Ok(IrType::Vector(Box::new(element_ir_type)))
@@ -3021,7 +3182,7 @@ impl<'a> IrConverter<'a> {
                     .collect();
                 Ok(IrType::Intersection(ir_types?))
             }
-            TypeExpr::Resource(symbol) => Ok(IrType::Resource(symbol.0)),
+            TypeExpr::Resource(symbol) => Ok(IrType::Resource(symbol.0.to_string())),
             TypeExpr::Literal(lit) => Ok(IrType::LiteralValue(lit)),
             TypeExpr::Optional(inner_type) => {
                 let inner_ir_type = self.convert_type_annotation(*inner_type)?;
@@ -3101,7 +3262,7 @@ impl<'a> IrConverter<'a> {
         use crate::ir::core::IrPattern;
         match pat {
             MatchPattern::Literal(lit) => Ok(IrPattern::Literal(lit)),
-            MatchPattern::Symbol(sym) => Ok(IrPattern::Variable(sym.0.clone())),
+            MatchPattern::Symbol(sym) => Ok(IrPattern::Variable(sym.0.to_string())),
             MatchPattern::Wildcard => Ok(IrPattern::Wildcard),
             MatchPattern::Type(_, _binding) => {
                 // For now, type patterns match anything
@@ -3115,7 +3276,7 @@ impl<'a> IrConverter<'a> {
                     .collect();
                 Ok(IrPattern::Vector {
                     elements: converted_elements?,
-                    rest: rest.map(|s| s.0),
+                    rest: rest.map(|s| s.0.to_string()),
                 })
             }
             MatchPattern::Map { entries, rest } => {
@@ -3130,7 +3291,7 @@ impl<'a> IrConverter<'a> {
                     .collect();
                 Ok(IrPattern::Map {
                     entries: converted_entries?,
-                    rest: rest.map(|s| s.0),
+                    rest: rest.map(|s| s.0.to_string()),
                 })
             }
             MatchPattern::As(_symbol, pattern) => {
@@ -3149,7 +3310,7 @@ impl<'a> IrConverter<'a> {
     /// Extract all symbol names from a pattern (including nested destructuring)
     fn extract_pattern_symbols(&self, pattern: &Pattern) -> Vec<String> {
         match pattern {
-            Pattern::Symbol(sym) => vec![sym.0.clone()],
+            Pattern::Symbol(sym) => vec![sym.0.to_string()],
             Pattern::Wildcard => vec![], // Wildcards don't bind anything
             Pattern::VectorDestructuring { elements, rest, .. } => {
                 let mut symbols = Vec::new();
@@ -3157,7 +3318,7 @@ impl<'a> IrConverter<'a> {
                     symbols.extend(self.extract_pattern_symbols(element));
                 }
                 if let Some(rest_symbol) = rest {
-                    symbols.push(rest_symbol.0.clone());
+                    symbols.push(rest_symbol.0.to_string());
                 }
                 symbols
             }
@@ -3170,13 +3331,13 @@ impl<'a> IrConverter<'a> {
                         }
                         MapDestructuringEntry::Keys(syms) => {
                             for sym in syms {
-                                symbols.push(sym.0.clone());
+                                symbols.push(sym.0.to_string());
                             }
                         }
                     }
                 }
                 if let Some(rest_symbol) = rest {
-                    symbols.push(rest_symbol.0.clone());
+                    symbols.push(rest_symbol.0.to_string());
                 }
                 symbols
             }
@@ -3185,9 +3346,8 @@ impl<'a> IrConverter<'a> {
 
     fn convert_for(&mut self, for_expr: ForExpr) -> IrConversionResult<IrNode> {
         // For now, return an error indicating for expressions are not supported in IR conversion
-        Err(IrConversionError::InvalidSpecialForm {
-            form: "for".to_string(),
-            message: "For expressions not yet implemented in IR converter".to_string(),
+        Err(IrConversionError::Unsupported {
+            construct: "for".to_string(),
         })
     }
 }
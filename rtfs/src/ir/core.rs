@@ -526,4 +526,92 @@ impl IrNode {
             } => source_location.as_ref(),
         }
     }
+
+    /// Mutable access to the source location, e.g. to attach the span of the
+    /// AST form a node was lowered from
+    pub fn source_location_mut(&mut self) -> &mut Option<SourceLocation> {
+        match self {
+            IrNode::Program {
+                source_location, ..
+            }
+            | IrNode::Literal {
+                source_location, ..
+            }
+            | IrNode::VariableBinding {
+                source_location, ..
+            }
+            | IrNode::VariableRef {
+                source_location, ..
+            }
+            | IrNode::ResourceRef {
+                source_location, ..
+            }
+            | IrNode::QualifiedSymbolRef {
+                source_location, ..
+            }
+            | IrNode::VariableDef {
+                source_location, ..
+            }
+            | IrNode::FunctionDef {
+                source_location, ..
+            }
+            | IrNode::Apply {
+                source_location, ..
+            }
+            | IrNode::Lambda {
+                source_location, ..
+            }
+            | IrNode::Param {
+                source_location, ..
+            }
+            | IrNode::If {
+                source_location, ..
+            }
+            | IrNode::Let {
+                source_location, ..
+            }
+            | IrNode::Do {
+                source_location, ..
+            }
+            | IrNode::Match {
+                source_location, ..
+            }
+            | IrNode::TryCatch {
+                source_location, ..
+            }
+            | IrNode::Parallel {
+                source_location, ..
+            }
+            | IrNode::WithResource {
+                source_location, ..
+            }
+            | IrNode::LogStep {
+                source_location, ..
+            }
+            | IrNode::Step {
+                source_location, ..
+            }
+            | IrNode::Module {
+                source_location, ..
+            }
+            | IrNode::Import {
+                source_location, ..
+            }
+            | IrNode::Task {
+                source_location, ..
+            }
+            | IrNode::DiscoverAgents {
+                source_location, ..
+            }
+            | IrNode::Vector {
+                source_location, ..
+            }
+            | IrNode::Map {
+                source_location, ..
+            }
+            | IrNode::Destructure {
+                source_location, ..
+            } => source_location,
+        }
+    }
 }
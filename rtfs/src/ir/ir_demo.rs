@@ -205,37 +205,40 @@ fn create_example_ast() -> Expression {
     Expression::Let(LetExpr {
         bindings: vec![
             LetBinding {
-                pattern: Pattern::Symbol(Symbol("x".to_string())),
+                pattern: Pattern::Symbol(Symbol("x".into())),
                 type_annotation: None,
                 value: Box::new(Expression::Literal(Literal::Integer(10))),
             },
             LetBinding {
-                pattern: Pattern::Symbol(Symbol("y".to_string())),
+                pattern: Pattern::Symbol(Symbol("y".into())),
                 type_annotation: None,
                 value: Box::new(Expression::Literal(Literal::Integer(20))),
             },
         ],
         body: vec![Expression::If(IfExpr {
             condition: Box::new(Expression::FunctionCall {
-                callee: Box::new(Expression::Symbol(Symbol(">".to_string()))),
+                callee: Box::new(Expression::Symbol(Symbol(">".into()))),
                 arguments: vec![
-                    Expression::Symbol(Symbol("x".to_string())),
+                    Expression::Symbol(Symbol("x".into())),
                     Expression::Literal(Literal::Integer(5)),
                 ],
+                span: Span::unknown(),
             }),
             then_branch: Box::new(Expression::FunctionCall {
-                callee: Box::new(Expression::Symbol(Symbol("+".to_string()))),
+                callee: Box::new(Expression::Symbol(Symbol("+".into()))),
                 arguments: vec![
-                    Expression::Symbol(Symbol("x".to_string())),
-                    Expression::Symbol(Symbol("y".to_string())),
+                    Expression::Symbol(Symbol("x".into())),
+                    Expression::Symbol(Symbol("y".into())),
                 ],
+                span: Span::unknown(),
             }),
             else_branch: Some(Box::new(Expression::FunctionCall {
-                callee: Box::new(Expression::Symbol(Symbol("-".to_string()))),
+                callee: Box::new(Expression::Symbol(Symbol("-".into()))),
                 arguments: vec![
-                    Expression::Symbol(Symbol("x".to_string())),
-                    Expression::Symbol(Symbol("y".to_string())),
+                    Expression::Symbol(Symbol("x".into())),
+                    Expression::Symbol(Symbol("y".into())),
                 ],
+                span: Span::unknown(),
             })),
         })],
     })
@@ -107,45 +107,45 @@ fn create_comprehensive_sample_ast() -> Expression {
         bindings: vec![
             // x = 42
             LetBinding {
-                pattern: Pattern::Symbol(Symbol("x".to_string())),
+                pattern: Pattern::Symbol(Symbol("x".into())),
                 type_annotation: None,
                 value: Box::new(Expression::Literal(Literal::Integer(42))),
             },
             // y = (+ x 10)
             LetBinding {
-                pattern: Pattern::Symbol(Symbol("y".to_string())),
+                pattern: Pattern::Symbol(Symbol("y".into())),
                 type_annotation: None,
                 value: Box::new(Expression::List(vec![
-                    Expression::Symbol(Symbol("+".to_string())),
-                    Expression::Symbol(Symbol("x".to_string())),
+                    Expression::Symbol(Symbol("+".into())),
+                    Expression::Symbol(Symbol("x".into())),
                     Expression::Literal(Literal::Integer(10)),
                 ])),
             },
             // calculate = (fn [a b] (+ a b (* a b)))
             LetBinding {
-                pattern: Pattern::Symbol(Symbol("calculate".to_string())),
+                pattern: Pattern::Symbol(Symbol("calculate".into())),
                 type_annotation: None,
                 value: Box::new(Expression::Fn(FnExpr {
                     params: vec![
                         ParamDef {
-                            pattern: Pattern::Symbol(Symbol("a".to_string())),
+                            pattern: Pattern::Symbol(Symbol("a".into())),
                             type_annotation: Some(TypeExpr::Primitive(PrimitiveType::Int)),
                         },
                         ParamDef {
-                            pattern: Pattern::Symbol(Symbol("b".to_string())),
+                            pattern: Pattern::Symbol(Symbol("b".into())),
                             type_annotation: Some(TypeExpr::Primitive(PrimitiveType::Int)),
                         },
                     ],
                     variadic_param: None,
                     return_type: Some(TypeExpr::Primitive(PrimitiveType::Int)),
                     body: vec![Expression::List(vec![
-                        Expression::Symbol(Symbol("+".to_string())),
-                        Expression::Symbol(Symbol("a".to_string())),
-                        Expression::Symbol(Symbol("b".to_string())),
+                        Expression::Symbol(Symbol("+".into())),
+                        Expression::Symbol(Symbol("a".into())),
+                        Expression::Symbol(Symbol("b".into())),
                         Expression::List(vec![
-                            Expression::Symbol(Symbol("*".to_string())),
-                            Expression::Symbol(Symbol("a".to_string())),
-                            Expression::Symbol(Symbol("b".to_string())),
+                            Expression::Symbol(Symbol("*".into())),
+                            Expression::Symbol(Symbol("a".into())),
+                            Expression::Symbol(Symbol("b".into())),
                         ]),
                     ])],
                     delegation_hint: None,
@@ -157,14 +157,14 @@ fn create_comprehensive_sample_ast() -> Expression {
                 // (if (> x 40) (calculate x y) 0)
                 Expression::If(IfExpr {
                     condition: Box::new(Expression::List(vec![
-                        Expression::Symbol(Symbol(">".to_string())),
-                        Expression::Symbol(Symbol("x".to_string())),
+                        Expression::Symbol(Symbol(">".into())),
+                        Expression::Symbol(Symbol("x".into())),
                         Expression::Literal(Literal::Integer(40)),
                     ])),
                     then_branch: Box::new(Expression::List(vec![
-                        Expression::Symbol(Symbol("calculate".to_string())),
-                        Expression::Symbol(Symbol("x".to_string())),
-                        Expression::Symbol(Symbol("y".to_string())),
+                        Expression::Symbol(Symbol("calculate".into())),
+                        Expression::Symbol(Symbol("x".into())),
+                        Expression::Symbol(Symbol("y".into())),
                     ])),
                     else_branch: Some(Box::new(Expression::Literal(Literal::Integer(0)))),
                 }),
@@ -189,29 +189,30 @@ fn create_optimization_sample_ast() -> Expression {
     Expression::Let(LetExpr {
         bindings: vec![
             LetBinding {
-                pattern: Pattern::Symbol(Symbol("x".to_string())),
+                pattern: Pattern::Symbol(Symbol("x".into())),
                 type_annotation: None,
                 value: Box::new(Expression::Literal(Literal::Integer(10))),
             },
             LetBinding {
-                pattern: Pattern::Symbol(Symbol("y".to_string())),
+                pattern: Pattern::Symbol(Symbol("y".into())),
                 type_annotation: None,
                 value: Box::new(Expression::Literal(Literal::Integer(20))),
             },
             LetBinding {
-                pattern: Pattern::Symbol(Symbol("unused".to_string())),
+                pattern: Pattern::Symbol(Symbol("unused".into())),
                 type_annotation: None,
                 value: Box::new(Expression::Literal(Literal::Integer(42))),
             },
             LetBinding {
-                pattern: Pattern::Symbol(Symbol("z".to_string())),
+                pattern: Pattern::Symbol(Symbol("z".into())),
                 type_annotation: None,
                 value: Box::new(Expression::FunctionCall {
-                    callee: Box::new(Expression::Symbol(Symbol("+".to_string()))),
+                    callee: Box::new(Expression::Symbol(Symbol("+".into()))),
                     arguments: vec![
                         Expression::Literal(Literal::Integer(5)),
                         Expression::Literal(Literal::Integer(3)),
                     ],
+                    span: Span::unknown(),
                 }),
             },
         ],
@@ -221,11 +222,12 @@ fn create_optimization_sample_ast() -> Expression {
                 Expression::If(IfExpr {
                     condition: Box::new(Expression::Literal(Literal::Boolean(true))),
                     then_branch: Box::new(Expression::FunctionCall {
-                        callee: Box::new(Expression::Symbol(Symbol("+".to_string()))),
+                        callee: Box::new(Expression::Symbol(Symbol("+".into()))),
                         arguments: vec![
-                            Expression::Symbol(Symbol("x".to_string())),
-                            Expression::Symbol(Symbol("z".to_string())),
+                            Expression::Symbol(Symbol("x".into())),
+                            Expression::Symbol(Symbol("z".into())),
                         ],
+                        span: Span::unknown(),
                     }),
                     else_branch: Some(Box::new(Expression::Literal(Literal::Integer(999)))),
                 }),
@@ -1,4 +1,5 @@
 // IR module organization
+pub mod arity_checker;
 pub mod converter;
 pub mod core;
 pub mod demo;
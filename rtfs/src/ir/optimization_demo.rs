@@ -383,57 +383,62 @@ fn create_math_heavy_program() -> Expression {
     Expression::Let(LetExpr {
         bindings: vec![
             LetBinding {
-                pattern: Pattern::Symbol(Symbol("a".to_string())),
+                pattern: Pattern::Symbol(Symbol("a".into())),
                 type_annotation: None,
                 value: Box::new(Expression::FunctionCall {
-                    callee: Box::new(Expression::Symbol(Symbol("+".to_string()))),
+                    callee: Box::new(Expression::Symbol(Symbol("+".into()))),
                     arguments: vec![
                         Expression::Literal(Literal::Integer(5)),
                         Expression::Literal(Literal::Integer(3)),
                     ],
+                    span: Span::unknown(),
                 }),
             },
             LetBinding {
-                pattern: Pattern::Symbol(Symbol("b".to_string())),
+                pattern: Pattern::Symbol(Symbol("b".into())),
                 type_annotation: None,
                 value: Box::new(Expression::FunctionCall {
-                    callee: Box::new(Expression::Symbol(Symbol("*".to_string()))),
+                    callee: Box::new(Expression::Symbol(Symbol("*".into()))),
                     arguments: vec![
                         Expression::Literal(Literal::Integer(2)),
                         Expression::Literal(Literal::Integer(4)),
                     ],
+                    span: Span::unknown(),
                 }),
             },
             LetBinding {
-                pattern: Pattern::Symbol(Symbol("c".to_string())),
+                pattern: Pattern::Symbol(Symbol("c".into())),
                 type_annotation: None,
                 value: Box::new(Expression::FunctionCall {
-                    callee: Box::new(Expression::Symbol(Symbol("/".to_string()))),
+                    callee: Box::new(Expression::Symbol(Symbol("/".into()))),
                     arguments: vec![
                         Expression::Literal(Literal::Integer(16)),
                         Expression::Literal(Literal::Integer(2)),
                     ],
+                    span: Span::unknown(),
                 }),
             },
             LetBinding {
-                pattern: Pattern::Symbol(Symbol("result".to_string())),
+                pattern: Pattern::Symbol(Symbol("result".into())),
                 type_annotation: None,
                 value: Box::new(Expression::FunctionCall {
-                    callee: Box::new(Expression::Symbol(Symbol("+".to_string()))),
+                    callee: Box::new(Expression::Symbol(Symbol("+".into()))),
                     arguments: vec![
                         Expression::FunctionCall {
-                            callee: Box::new(Expression::Symbol(Symbol("*".to_string()))),
+                            callee: Box::new(Expression::Symbol(Symbol("*".into()))),
                             arguments: vec![
-                                Expression::Symbol(Symbol("a".to_string())),
-                                Expression::Symbol(Symbol("b".to_string())),
+                                Expression::Symbol(Symbol("a".into())),
+                                Expression::Symbol(Symbol("b".into())),
                             ],
+                            span: Span::unknown(),
                         },
-                        Expression::Symbol(Symbol("c".to_string())),
+                        Expression::Symbol(Symbol("c".into())),
                     ],
+                    span: Span::unknown(),
                 }),
             },
         ],
-        body: vec![Expression::Symbol(Symbol("result".to_string()))],
+        body: vec![Expression::Symbol(Symbol("result".into()))],
     })
 }
 
@@ -446,7 +451,7 @@ fn create_control_flow_program() -> Expression {
 
     Expression::Let(LetExpr {
         bindings: vec![LetBinding {
-            pattern: Pattern::Symbol(Symbol("x".to_string())),
+            pattern: Pattern::Symbol(Symbol("x".into())),
             type_annotation: None,
             value: Box::new(Expression::Literal(Literal::Integer(10))),
         }],
@@ -455,7 +460,7 @@ fn create_control_flow_program() -> Expression {
             then_branch: Box::new(Expression::If(IfExpr {
                 condition: Box::new(Expression::Literal(Literal::Boolean(false))),
                 then_branch: Box::new(Expression::Literal(Literal::Integer(999))),
-                else_branch: Some(Box::new(Expression::Symbol(Symbol("x".to_string())))),
+                else_branch: Some(Box::new(Expression::Symbol(Symbol("x".into())))),
             })),
             else_branch: Some(Box::new(Expression::If(IfExpr {
                 condition: Box::new(Expression::Literal(Literal::Boolean(true))),
@@ -475,64 +480,69 @@ fn create_function_heavy_program() -> Expression {
     Expression::Let(LetExpr {
         bindings: vec![
             LetBinding {
-                pattern: Pattern::Symbol(Symbol("add".to_string())),
+                pattern: Pattern::Symbol(Symbol("add".into())),
                 type_annotation: None,
                 value: Box::new(Expression::Fn(FnExpr {
                     params: vec![
                         ParamDef {
-                            pattern: Pattern::Symbol(Symbol("x".to_string())),
+                            pattern: Pattern::Symbol(Symbol("x".into())),
                             type_annotation: Some(TypeExpr::Primitive(PrimitiveType::Int)),
                         },
                         ParamDef {
-                            pattern: Pattern::Symbol(Symbol("y".to_string())),
+                            pattern: Pattern::Symbol(Symbol("y".into())),
                             type_annotation: Some(TypeExpr::Primitive(PrimitiveType::Int)),
                         },
                     ],
                     variadic_param: None,
                     return_type: Some(TypeExpr::Primitive(PrimitiveType::Int)),
                     body: vec![Expression::FunctionCall {
-                        callee: Box::new(Expression::Symbol(Symbol("+".to_string()))),
+                        callee: Box::new(Expression::Symbol(Symbol("+".into()))),
                         arguments: vec![
-                            Expression::Symbol(Symbol("x".to_string())),
-                            Expression::Symbol(Symbol("y".to_string())),
+                            Expression::Symbol(Symbol("x".into())),
+                            Expression::Symbol(Symbol("y".into())),
                         ],
+                        span: Span::unknown(),
                     }],
                     delegation_hint: None,
                 })),
             },
             LetBinding {
-                pattern: Pattern::Symbol(Symbol("square".to_string())),
+                pattern: Pattern::Symbol(Symbol("square".into())),
                 type_annotation: None,
                 value: Box::new(Expression::Fn(FnExpr {
                     params: vec![ParamDef {
-                        pattern: Pattern::Symbol(Symbol("x".to_string())),
+                        pattern: Pattern::Symbol(Symbol("x".into())),
                         type_annotation: Some(TypeExpr::Primitive(PrimitiveType::Int)),
                     }],
                     variadic_param: None,
                     return_type: Some(TypeExpr::Primitive(PrimitiveType::Int)),
                     body: vec![Expression::FunctionCall {
-                        callee: Box::new(Expression::Symbol(Symbol("*".to_string()))),
+                        callee: Box::new(Expression::Symbol(Symbol("*".into()))),
                         arguments: vec![
-                            Expression::Symbol(Symbol("x".to_string())),
-                            Expression::Symbol(Symbol("x".to_string())),
+                            Expression::Symbol(Symbol("x".into())),
+                            Expression::Symbol(Symbol("x".into())),
                         ],
+                        span: Span::unknown(),
                     }],
                     delegation_hint: None,
                 })),
             },
         ],
         body: vec![Expression::FunctionCall {
-            callee: Box::new(Expression::Symbol(Symbol("add".to_string()))),
+            callee: Box::new(Expression::Symbol(Symbol("add".into()))),
             arguments: vec![
                 Expression::FunctionCall {
-                    callee: Box::new(Expression::Symbol(Symbol("square".to_string()))),
+                    callee: Box::new(Expression::Symbol(Symbol("square".into()))),
                     arguments: vec![Expression::Literal(Literal::Integer(3))],
+                    span: Span::unknown(),
                 },
                 Expression::FunctionCall {
-                    callee: Box::new(Expression::Symbol(Symbol("square".to_string()))),
+                    callee: Box::new(Expression::Symbol(Symbol("square".into()))),
                     arguments: vec![Expression::Literal(Literal::Integer(4))],
+                    span: Span::unknown(),
                 },
             ],
+            span: Span::unknown(),
         }],
     })
 }
@@ -551,30 +561,31 @@ fn create_dead_code_program() -> Expression {
     Expression::Let(LetExpr {
         bindings: vec![
             LetBinding {
-                pattern: Pattern::Symbol(Symbol("used".to_string())),
+                pattern: Pattern::Symbol(Symbol("used".into())),
                 type_annotation: None,
                 value: Box::new(Expression::Literal(Literal::Integer(42))),
             },
             LetBinding {
-                pattern: Pattern::Symbol(Symbol("unused1".to_string())),
+                pattern: Pattern::Symbol(Symbol("unused1".into())),
                 type_annotation: None,
                 value: Box::new(Expression::FunctionCall {
-                    callee: Box::new(Expression::Symbol(Symbol("+".to_string()))),
+                    callee: Box::new(Expression::Symbol(Symbol("+".into()))),
                     arguments: vec![
                         Expression::Literal(Literal::Integer(1)),
                         Expression::Literal(Literal::Integer(2)),
                     ],
+                    span: Span::unknown(),
                 }),
             },
             LetBinding {
-                pattern: Pattern::Symbol(Symbol("unused2".to_string())),
+                pattern: Pattern::Symbol(Symbol("unused2".into())),
                 type_annotation: None,
                 value: Box::new(Expression::Literal(Literal::String(
                     "dead string".to_string(),
                 ))),
             },
             LetBinding {
-                pattern: Pattern::Symbol(Symbol("unused3".to_string())),
+                pattern: Pattern::Symbol(Symbol("unused3".into())),
                 type_annotation: None,
                 value: Box::new(Expression::Vector(vec![
                     Expression::Literal(Literal::Integer(1)),
@@ -587,13 +598,14 @@ fn create_dead_code_program() -> Expression {
             expressions: vec![
                 Expression::Literal(Literal::String("unused expression".to_string())),
                 Expression::FunctionCall {
-                    callee: Box::new(Expression::Symbol(Symbol("+".to_string()))),
+                    callee: Box::new(Expression::Symbol(Symbol("+".into()))),
                     arguments: vec![
                         Expression::Literal(Literal::Integer(10)),
                         Expression::Literal(Literal::Integer(20)),
                     ],
+                    span: Span::unknown(),
                 },
-                Expression::Symbol(Symbol("used".to_string())),
+                Expression::Symbol(Symbol("used".into())),
             ],
         })],
     })
@@ -217,7 +217,8 @@ impl EnhancedIrOptimizer {
                 ..
             } = arg
             {
-                result += n;
+                // Leave overflowing sums to the runtime, which reports the error
+                result = result.checked_add(*n)?;
             } else {
                 return None; // Non-integer found
             }
@@ -249,7 +250,7 @@ impl EnhancedIrOptimizer {
         {
             Some(IrNode::Literal {
                 id: 0,
-                value: Literal::Integer(a - b),
+                value: Literal::Integer(a.checked_sub(*b)?),
                 ir_type: IrType::Int,
                 source_location: None,
             })
@@ -270,7 +271,7 @@ impl EnhancedIrOptimizer {
                 ..
             } = arg
             {
-                result *= n;
+                result = result.checked_mul(*n)?;
             } else {
                 return None; // Non-integer found
             }
@@ -78,7 +78,7 @@ pub(super) fn build_literal(pair: Pair<Rule>) -> Result<Literal, PestParseError>
 pub(super) fn build_symbol(pair: Pair<Rule>) -> Result<Symbol, PestParseError> {
     let pair_span = pair_to_source_span(&pair);
     match pair.as_rule() {
-        Rule::symbol | Rule::primitive_type => Ok(Symbol(pair.as_str().to_string())),
+        Rule::symbol | Rule::primitive_type => Ok(Symbol::new(pair.as_str())),
         _ => Err(PestParseError::UnexpectedRule {
             expected: "symbol or primitive_type".to_string(),
             found: format!("{:?}", pair.as_rule()),
@@ -101,7 +101,7 @@ pub(super) fn build_keyword(pair: Pair<Rule>) -> Result<Keyword, PestParseError>
     // Store keywords without the leading ':' for a consistent internal representation
     let s = pair.as_str();
     let normalized = if s.starts_with(':') { &s[1..] } else { s };
-    Ok(Keyword(normalized.to_string()))
+    Ok(Keyword(normalized.into()))
 }
 
 pub(super) fn build_map_key(pair: Pair<Rule>) -> Result<MapKey, PestParseError> {
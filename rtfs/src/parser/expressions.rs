@@ -7,7 +7,7 @@ use super::special_forms::{
 };
 use super::utils::unescape;
 use super::Rule;
-use crate::ast::{Expression, Keyword, Literal, MapKey, Symbol}; // Symbol now used for task_context_access desugaring
+use crate::ast::{Expression, Keyword, Literal, MapKey, Span, Symbol}; // Symbol now used for task_context_access desugaring
 use pest::iterators::Pair;
 use std::collections::HashMap;
 
@@ -38,7 +38,7 @@ pub(super) fn build_expression(mut pair: Pair<Rule>) -> Result<Expression, PestP
             // :keyword -> Keyword("keyword")
             let raw = pair.as_str(); // e.g. ":keyword"
             let keyword_name = &raw[1..]; // Remove the :
-            Ok(Expression::Literal(Literal::Keyword(Keyword(keyword_name.to_string()))))
+            Ok(Expression::Literal(Literal::Keyword(Keyword(keyword_name.into()))))
         }
         Rule::method_call_expr => {
             // (.method target arg1 arg2 ...) -> (method target arg1 arg2 ...)
@@ -50,7 +50,7 @@ pub(super) fn build_expression(mut pair: Pair<Rule>) -> Result<Expression, PestP
             })?;
             let method_name = method_ident_pair.as_str().to_string();
             let mut elements: Vec<Expression> = Vec::new();
-            elements.push(Expression::Symbol(Symbol(method_name)));
+            elements.push(Expression::Symbol(Symbol(method_name.into())));
             for arg_pair in inner {
                 elements.push(build_expression(arg_pair)?);
             }
@@ -65,7 +65,7 @@ pub(super) fn build_expression(mut pair: Pair<Rule>) -> Result<Expression, PestP
             // If the access is prefixed with ':' (e.g. @:context-key) treat it as a Symbol
             // Otherwise treat it as a ResourceRef (explicit resource path like @plan-id)
             if let Some(rest) = without_at.strip_prefix(':') {
-                Ok(Expression::Symbol(Symbol(rest.to_string())))
+                Ok(Expression::Symbol(Symbol(rest.into())))
             } else {
                 Ok(Expression::ResourceRef(without_at.to_string()))
             }
@@ -74,7 +74,7 @@ pub(super) fn build_expression(mut pair: Pair<Rule>) -> Result<Expression, PestP
             // @atom-name desugars to (deref atom-name)
             let raw = pair.as_str(); // e.g. "@atom-name"
             let atom_name = &raw[1..]; // Remove the @
-            let atom_symbol = Expression::Symbol(Symbol(atom_name.to_string()));
+            let atom_symbol = Expression::Symbol(Symbol(atom_name.into()));
             Ok(Expression::Deref(Box::new(atom_symbol)))
         }
         Rule::quasiquote => {
@@ -232,6 +232,10 @@ pub(super) fn build_expression(mut pair: Pair<Rule>) -> Result<Expression, PestP
                         Ok(Expression::FunctionCall {
                             callee: Box::new(callee_ast),
                             arguments,
+                            span: Span::new(
+                                list_pair_span.start_line,
+                                list_pair_span.start_column,
+                            ),
                         })
                     }
                     // If the first element is not a symbol/fn/call, it's a data list.
@@ -240,7 +244,7 @@ pub(super) fn build_expression(mut pair: Pair<Rule>) -> Result<Expression, PestP
                         // We already parsed `callee_ast` (the first element).
                         let mut elements = vec![callee_ast];
                         // Parse the rest of the elements.
-                        for p in inner_pairs {
+                        for p in inner_pairs.into_iter().skip(1) {
                             elements.push(build_expression(p)?);
                         }
                         Ok(Expression::List(elements))
@@ -283,7 +287,7 @@ pub(super) fn build_expression(mut pair: Pair<Rule>) -> Result<Expression, PestP
                     // Handle delegation_meta: store as a special key
                     let delegation_str = sub_pair.as_str().to_string();
                     metadata_map.insert(
-                        MapKey::Keyword(Keyword("delegation".to_string())),
+                        MapKey::Keyword(Keyword("delegation".into())),
                         Expression::Literal(Literal::String(delegation_str)),
                     );
                 }
@@ -418,7 +422,7 @@ fn build_shorthand_fn(pair: Pair<Rule>) -> Result<Expression, PestParseError> {
             format!("%{}", i)
         };
         params.push(crate::ast::ParamDef {
-            pattern: crate::ast::Pattern::Symbol(Symbol(name)),
+            pattern: crate::ast::Pattern::Symbol(Symbol(name.into())),
             type_annotation: None,
         });
     }
@@ -461,7 +465,9 @@ fn scan_for_placeholders(expr: &Expression, max_index: &mut usize, uses_plain_pe
                 scan_for_placeholders(v, max_index, uses_plain_percent);
             }
         }
-        Expression::FunctionCall { callee, arguments } => {
+        Expression::FunctionCall {
+            callee, arguments, ..
+        } => {
             scan_for_placeholders(callee, max_index, uses_plain_percent);
             for a in arguments {
                 scan_for_placeholders(a, max_index, uses_plain_percent);
@@ -550,12 +556,17 @@ fn rewrite_placeholders(expr: Expression, uses_plain_percent: bool) -> Expressio
                 .collect();
             Expression::Map(out)
         }
-        Expression::FunctionCall { callee, arguments } => Expression::FunctionCall {
+        Expression::FunctionCall {
+            callee,
+            arguments,
+            span,
+        } => Expression::FunctionCall {
             callee: Box::new(rewrite_placeholders(*callee, uses_plain_percent)),
             arguments: arguments
                 .into_iter()
                 .map(|a| rewrite_placeholders(a, uses_plain_percent))
                 .collect(),
+            span,
         },
         Expression::If(mut ife) => {
             ife.condition = Box::new(rewrite_placeholders(*ife.condition, uses_plain_percent));
@@ -80,6 +80,47 @@ pub fn parse_with_enhanced_errors(
     }
 }
 
+/// Parse a program, reporting a syntax error for every malformed top-level
+/// form instead of stopping at the first one. Forms are isolated by their
+/// delimiters, so one broken form does not hide errors in the next; the forms
+/// that did parse are returned alongside the errors.
+pub fn parse_collecting_errors(
+    source: &str,
+    file_path: Option<&str>,
+) -> (Vec<TopLevel>, Vec<ParserError>) {
+    let whole_error = match parse(source) {
+        Ok(items) => return (items, Vec::new()),
+        Err(e) => e,
+    };
+
+    let reporter = ParserErrorReporter::new();
+    let mut items = Vec::new();
+    let mut errors = Vec::new();
+    for range in crate::input_handling::top_level_form_ranges(source) {
+        // Blank out the other forms so reported lines and columns stay absolute
+        let isolated: String = source
+            .char_indices()
+            .map(|(i, c)| if range.contains(&i) || c == '\n' { c } else { ' ' })
+            .collect();
+        match parse(&isolated) {
+            Ok(parsed) => items.extend(parsed),
+            Err(e) => {
+                let mut error = reporter.report_error(e, &isolated, file_path);
+                error.source_code = source.to_string();
+                if let Some(span) = error.diagnostic.primary_span.as_mut() {
+                    span.source_text = Some(source.to_string());
+                }
+                errors.push(error);
+            }
+        }
+    }
+
+    if errors.is_empty() {
+        errors.push(reporter.report_error(whole_error, source, file_path));
+    }
+    (items, errors)
+}
+
 /// Parse a single expression (useful for REPL or simple evaluation)
 
 // ...
@@ -35,7 +35,10 @@ pub fn build_ast(pair: Pair<Rule>) -> Result<TopLevel, PestParseError> {
             // convert it into a TopLevel::Resource with parsed properties.
             let expr = build_expression(pair.clone())?;
             // Match FunctionCall(resource <name> <property>...)
-            if let AstExpression::FunctionCall { callee, arguments } = &expr {
+            if let AstExpression::FunctionCall {
+                callee, arguments, ..
+            } = &expr
+            {
                 if let AstExpression::Symbol(sym) = &**callee {
                     if sym.0 == "resource" {
                         // First arg should be the name (symbol possibly with @version)
@@ -59,7 +62,7 @@ pub fn build_ast(pair: Pair<Rule>) -> Result<TopLevel, PestParseError> {
                         let base_name = if let Some(idx) = name_sym.find('@') {
                             name_sym[..idx].to_string()
                         } else {
-                            name_sym
+                            name_sym.to_string()
                         };
 
                         // Parse property entries from remaining arguments
@@ -69,6 +72,7 @@ pub fn build_ast(pair: Pair<Rule>) -> Result<TopLevel, PestParseError> {
                             if let AstExpression::FunctionCall {
                                 callee: prop_callee,
                                 arguments: prop_args,
+                                ..
                             } = arg
                             {
                                 if let AstExpression::Symbol(prop_sym) = &**prop_callee {
@@ -110,7 +114,7 @@ pub fn build_ast(pair: Pair<Rule>) -> Result<TopLevel, PestParseError> {
                         }
 
                         let res_def = ResourceDefinition {
-                            name: Symbol(base_name),
+                            name: Symbol(base_name.into()),
                             properties,
                         };
                         return Ok(TopLevel::Resource(res_def));
@@ -169,7 +173,7 @@ pub fn build_ast(pair: Pair<Rule>) -> Result<TopLevel, PestParseError> {
                         }
 
                         let cap_def = crate::ast::CapabilityDefinition {
-                            name: Symbol(name_str),
+                            name: Symbol(name_str.into()),
                             properties,
                         };
                         return Ok(TopLevel::Capability(cap_def));
@@ -227,7 +231,7 @@ pub fn build_ast(pair: Pair<Rule>) -> Result<TopLevel, PestParseError> {
                         }
 
                         let plan_def = crate::ast::PlanDefinition {
-                            name: Symbol(name_str),
+                            name: Symbol(name_str.into()),
                             properties,
                         };
                         return Ok(TopLevel::Plan(plan_def));
@@ -371,9 +375,9 @@ fn build_import_definition(
     let module_name = match module_name_pair.as_rule() {
         Rule::symbol => {
             let symbol_pair = module_name_pair.into_inner().next().unwrap();
-            Symbol(symbol_pair.as_str().to_string())
+            Symbol::new(symbol_pair.as_str())
         }
-        Rule::namespaced_identifier => Symbol(module_name_pair.as_str().to_string()),
+        Rule::namespaced_identifier => Symbol::new(module_name_pair.as_str()),
         _ => {
             return Err(PestParseError::CustomError {
                 message: "Expected symbol or namespaced identifier for module name".to_string(),
@@ -396,7 +400,7 @@ fn build_import_definition(
                             if let Some(alias_pair) = option_inner.next() {
                                 if alias_pair.as_rule() == Rule::symbol {
                                     let symbol_pair = alias_pair.into_inner().next().unwrap();
-                                    alias = Some(Symbol(symbol_pair.as_str().to_string()));
+                                    alias = Some(Symbol::new(symbol_pair.as_str()));
                                 }
                             }
                         }
@@ -407,7 +411,7 @@ fn build_import_definition(
                                     for item in vector_pair.into_inner() {
                                         if item.as_rule() == Rule::symbol {
                                             let symbol_pair = item.into_inner().next().unwrap();
-                                            symbols.push(Symbol(symbol_pair.as_str().to_string()));
+                                            symbols.push(Symbol::new(symbol_pair.as_str()));
                                         }
                                     }
                                     only = Some(symbols);
@@ -1,12 +1,51 @@
 // Enhanced parser error reporting for RTFS
 // Provides detailed error messages with source location, code snippets, and helpful hints
 
-use crate::error_reporting::{DiagnosticInfo, ErrorHint, SourceSpan};
+use crate::error_reporting::{DiagnosticInfo, ErrorHint, ErrorSeverity, SourceSpan};
 use crate::parser::Rule;
+use clap::ValueEnum;
 use pest::error::{Error as PestError, ErrorVariant, InputLocation};
 use pest::Position;
+use serde_json::json;
 use std::fmt;
 
+/// How parse errors are rendered
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ErrorFormat {
+    /// Human-readable text with source context
+    #[default]
+    Text,
+    /// A JSON array with one object per error, for editors and the viewer
+    Json,
+}
+
+/// When text output is colorized with ANSI escapes
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ColorChoice {
+    /// Colorize only when writing to a terminal and `NO_COLOR` is unset
+    #[default]
+    Auto,
+    Always,
+    Never,
+}
+
+impl ColorChoice {
+    /// Whether to emit colors on a stream that is (or is not) a terminal
+    pub fn use_colors(self, is_terminal: bool) -> bool {
+        match self {
+            ColorChoice::Auto => is_terminal && std::env::var_os("NO_COLOR").is_none(),
+            ColorChoice::Always => true,
+            ColorChoice::Never => false,
+        }
+    }
+}
+
+const RED_BOLD: &str = "\x1b[1;31m";
+const RED: &str = "\x1b[31m";
+const CYAN: &str = "\x1b[36m";
+const BLUE: &str = "\x1b[34m";
+const RESET: &str = "\x1b[0m";
+
 /// Enhanced parser error with detailed diagnostic information
 #[derive(Debug, Clone)]
 pub struct ParserError {
@@ -457,10 +496,69 @@ impl ParserError {
 
     /// Format the error with source code context
     pub fn format_with_context(&self) -> String {
+        self.render_text(false)
+    }
+
+    /// Format the error with source code context, highlighted with ANSI colors
+    pub fn format_with_colors(&self) -> String {
+        self.render_text(true)
+    }
+
+    /// Structured form of the error: `{message, severity, code, span, suggestion, hints}`
+    pub fn to_json(&self) -> serde_json::Value {
+        let severity = match self.diagnostic.severity {
+            ErrorSeverity::Error => "error",
+            ErrorSeverity::Warning => "warning",
+            ErrorSeverity::Info => "info",
+            ErrorSeverity::Hint => "hint",
+        };
+        let span = self.diagnostic.primary_span.as_ref().map(|span| {
+            json!({
+                "file": span.file_path.as_ref().or(self.file_path.as_ref()),
+                "start_line": span.start_line,
+                "start_column": span.start_column,
+                "end_line": span.end_line,
+                "end_column": span.end_column,
+            })
+        });
+        let suggestion = self
+            .diagnostic
+            .hints
+            .iter()
+            .find_map(|hint| hint.suggested_fix.clone());
+        let hints: Vec<&str> = self
+            .diagnostic
+            .hints
+            .iter()
+            .map(|hint| hint.message.as_str())
+            .collect();
+
+        json!({
+            "message": self.message,
+            "severity": severity,
+            "code": self.diagnostic.error_code,
+            "span": span,
+            "suggestion": suggestion,
+            "hints": hints,
+        })
+    }
+
+    fn render_text(&self, colors: bool) -> String {
+        let paint = |text: &str, style: &str| {
+            if colors {
+                format!("{}{}{}", style, text, RESET)
+            } else {
+                text.to_string()
+            }
+        };
         let mut output = String::new();
 
         // Error header
-        output.push_str(&format!("❌ Parse Error: {}\n", self.message));
+        output.push_str(&paint(
+            &format!("❌ Parse Error: {}", self.message),
+            RED_BOLD,
+        ));
+        output.push('\n');
 
         if let Some(file_path) = &self.file_path {
             output.push_str(&format!("📁 File: {}\n", file_path));
@@ -498,7 +596,12 @@ impl ParserError {
                         };
 
                         // Add line number with consistent formatting
-                        output.push_str(&format!("{:4} {}{}\n", line_num, prefix, line_content));
+                        output.push_str(&format!(
+                            "{} {}{}\n",
+                            paint(&format!("{:4}", line_num), BLUE),
+                            prefix,
+                            line_content
+                        ));
 
                         // Enhanced pointer with column indicator on the error line
                         if line_num == error_line {
@@ -533,7 +636,7 @@ impl ParserError {
                                     }
                                 }
 
-                                output.push_str(&format!("{}\n", pointer_line));
+                                output.push_str(&format!("{}\n", paint(&pointer_line, RED)));
                                 output.push_str(&format!("     Here at column {}\n", column));
                             }
                         }
@@ -558,7 +661,7 @@ impl ParserError {
 
         // Hints
         if !self.diagnostic.hints.is_empty() {
-            output.push_str("\n💡 Hints:\n");
+            output.push_str(&format!("\n{}\n", paint("💡 Hints:", CYAN)));
             for (i, hint) in self.diagnostic.hints.iter().enumerate() {
                 output.push_str(&format!("   {}. {}\n", i + 1, hint.message));
                 if let Some(suggestion) = &hint.suggested_fix {
@@ -569,7 +672,7 @@ impl ParserError {
 
         // Notes
         if !self.diagnostic.notes.is_empty() {
-            output.push_str("\n📝 Notes:\n");
+            output.push_str(&format!("\n{}\n", paint("📝 Notes:", CYAN)));
             for note in &self.diagnostic.notes {
                 output.push_str(&format!("   • {}\n", note));
             }
@@ -592,6 +695,7 @@ pub struct ParserErrorReporter {
     pub use_colors: bool,
     pub show_source_context: bool,
     pub max_context_lines: usize,
+    pub format: ErrorFormat,
 }
 
 impl Default for ParserErrorReporter {
@@ -600,6 +704,7 @@ impl Default for ParserErrorReporter {
             use_colors: true,
             show_source_context: true,
             max_context_lines: 3,
+            format: ErrorFormat::Text,
         }
     }
 }
@@ -620,9 +725,16 @@ impl ParserErrorReporter {
             use_colors,
             show_source_context,
             max_context_lines,
+            format: ErrorFormat::Text,
         }
     }
 
+    /// Select text or JSON output
+    pub fn with_format(mut self, format: ErrorFormat) -> Self {
+        self.format = format;
+        self
+    }
+
     /// Enable or disable colored output
     pub fn with_colors(mut self, use_colors: bool) -> Self {
         self.use_colors = use_colors;
@@ -657,13 +769,18 @@ impl ParserErrorReporter {
 
     /// Format multiple parsing errors
     pub fn format_errors(&self, errors: &[ParserError]) -> String {
+        if self.format == ErrorFormat::Json {
+            let errors: Vec<_> = errors.iter().map(ParserError::to_json).collect();
+            return serde_json::Value::Array(errors).to_string();
+        }
+
         let mut output = String::new();
 
         for (i, error) in errors.iter().enumerate() {
             if i > 0 {
                 output.push_str("\n");
             }
-            output.push_str(&error.format_with_context());
+            output.push_str(&error.render_text(self.use_colors));
         }
 
         output
@@ -718,4 +835,52 @@ mod tests {
 
         assert!(has_comment_hint);
     }
+
+    fn mismatched_delimiter_error() -> ParserError {
+        let source_code = "(+ 1\n   (* 2 3])";
+        let pest_error = crate::parser::parse(source_code).unwrap_err();
+        ParserError::from_pest_error(
+            pest_error,
+            source_code.to_string(),
+            Some("plan.rtfs".to_string()),
+        )
+    }
+
+    #[test]
+    fn test_json_error_format() {
+        let reporter = ParserErrorReporter::new().with_format(ErrorFormat::Json);
+        let output = reporter.format_errors(&[mismatched_delimiter_error()]);
+        let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
+
+        let errors = parsed.as_array().unwrap();
+        assert_eq!(errors.len(), 1);
+        let error = &errors[0];
+        assert!(error["message"].as_str().unwrap().contains("Mismatched delimiter"));
+        assert_eq!(error["severity"], "error");
+        assert_eq!(error["code"], "E001");
+        assert_eq!(error["span"]["file"], "plan.rtfs");
+        assert_eq!(error["span"]["start_line"], 2);
+        assert!(error["span"]["start_column"].is_u64());
+        assert!(error["suggestion"].is_string());
+        assert!(error["hints"].as_array().is_some_and(|h| !h.is_empty()));
+    }
+
+    #[test]
+    fn test_colors_only_on_terminals() {
+        assert!(!ColorChoice::Auto.use_colors(false));
+        assert!(!ColorChoice::Never.use_colors(true));
+        assert!(ColorChoice::Always.use_colors(false));
+
+        let error = mismatched_delimiter_error();
+        let plain = ParserErrorReporter::new()
+            .with_colors(ColorChoice::Auto.use_colors(false))
+            .format_errors(std::slice::from_ref(&error));
+        assert!(!plain.contains('\x1b'));
+        assert_eq!(plain, error.format_with_context());
+
+        let colored = ParserErrorReporter::new()
+            .with_colors(true)
+            .format_errors(&[error]);
+        assert!(colored.contains("\x1b[1;31m"));
+    }
 }
@@ -8,6 +8,19 @@ use crate::runtime::values::Value;
 use std::collections::HashMap;
 use std::sync::Arc;
 
+/// Namespace that unqualified symbols (and the `/` operator) belong to.
+pub const DEFAULT_NAMESPACE: &str = "stdlib";
+
+/// Splits a qualified symbol like `tool/parse-json` into `("tool", "parse-json")`.
+/// Unqualified symbols, including the `/` operator, return `None`.
+pub fn split_namespace(name: &str) -> Option<(&str, &str)> {
+    if name == "/" {
+        return None;
+    }
+    name.find('/')
+        .map(|slash_index| (&name[..slash_index], &name[slash_index + 1..]))
+}
+
 /// The runtime environment, which manages the scope chain for variable lookups for the AST evaluator.
 #[derive(Debug, Clone)]
 pub struct Environment {
@@ -52,7 +65,7 @@ impl Environment {
         let mut names = self
             .bindings
             .keys()
-            .map(|s| s.0.clone())
+            .map(|s| s.0.to_string())
             .collect::<Vec<_>>();
 
         // Also collect names from parent environments to ensure we get all stdlib functions
@@ -67,6 +80,35 @@ impl Environment {
         names
     }
 
+    /// Sorted names of the symbols visible from this scope that belong to
+    /// `namespace` (a trailing `/` is ignored). [`DEFAULT_NAMESPACE`] selects
+    /// the unqualified symbols.
+    pub fn symbol_names_in_namespace(&self, namespace: &str) -> Vec<String> {
+        let namespace = namespace.strip_suffix('/').unwrap_or(namespace);
+        self.symbol_names()
+            .into_iter()
+            .filter(|name| match split_namespace(name) {
+                Some((ns, _)) => ns == namespace,
+                None => namespace == DEFAULT_NAMESPACE,
+            })
+            .collect()
+    }
+
+    /// Collects the data bindings (everything except functions) visible from this scope.
+    /// Inner scopes shadow outer ones.
+    pub fn data_bindings(&self) -> HashMap<Symbol, Value> {
+        let mut bindings = match &self.parent {
+            Some(parent) => parent.data_bindings(),
+            None => HashMap::new(),
+        };
+        for (symbol, value) in &self.bindings {
+            if !matches!(value, Value::Function(_) | Value::FunctionPlaceholder(_)) {
+                bindings.insert(symbol.clone(), value.clone());
+            }
+        }
+        bindings
+    }
+
     /// Find the name of a function value by searching through all bindings
     pub fn find_function_name(&self, func_value: &Value) -> Option<&str> {
         for (symbol, value) in &self.bindings {
@@ -147,7 +189,7 @@ impl IrEnvironment {
                 new_env.define(param.clone(), arg.clone());
             }
             let rest_args = args[params.len() - 1..].to_vec();
-            new_env.define(rest_param[0].clone(), Value::List(rest_args));
+            new_env.define(rest_param[0].clone(), Value::List(rest_args.into()));
         } else {
             if params.len() != args.len() {
                 return Err(RuntimeError::Generic(format!(
@@ -188,6 +230,15 @@ impl IrEnvironment {
         self.bindings.keys().cloned().collect()
     }
 
+    /// Names bound in this environment or any parent
+    pub fn symbol_names(&self) -> Vec<String> {
+        let mut names = self.binding_names();
+        if let Some(parent) = &self.parent {
+            names.extend(parent.symbol_names());
+        }
+        names
+    }
+
     /// Check if environment has parent
     pub fn has_parent(&self) -> bool {
         self.parent.is_some()
@@ -206,7 +257,15 @@ impl IrEnvironment {
     /// Find the name of a function value by searching through all bindings
     pub fn find_function_name(&self, func_value: &Value) -> Option<&str> {
         for (name, value) in &self.bindings {
-            if value == func_value {
+            // letrec bindings hold the function behind a placeholder cell
+            let matches = match value {
+                Value::FunctionPlaceholder(cell) => cell
+                    .read()
+                    .map(|resolved| *resolved == *func_value)
+                    .unwrap_or(false),
+                other => other == func_value,
+            };
+            if matches {
                 return Some(name);
             }
         }
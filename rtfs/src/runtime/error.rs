@@ -16,8 +16,11 @@ pub enum RuntimeError {
         operation: String,
     },
 
-    /// Undefined symbol/variable
-    UndefinedSymbol(Symbol),
+    /// Undefined symbol/variable, with the closest known name when one is similar
+    UndefinedSymbol {
+        symbol: Symbol,
+        suggestion: Option<String>,
+    },
 
     SymbolNotFound(String),
     ModuleNotFound(String),
@@ -101,6 +104,7 @@ pub enum RuntimeError {
     },
 
     /// Unknown capability error
+    #[deprecated(since = "2.0.0", note = "Use RuntimeError::CapabilityNotFound instead")]
     UnknownCapability(String),
 
     /// Security violation error
@@ -145,12 +149,59 @@ pub enum RuntimeError {
         dimension: String,
         policy: String,
     },
+
+    /// A construct the IR runtime cannot execute yet (the AST evaluator can)
+    UnsupportedInIr(String),
+
+    /// No capability is registered under this id
+    CapabilityNotFound {
+        capability_id: String,
+    },
+
+    /// The security context does not allow calling this capability
+    CapabilityNotPermitted {
+        capability_id: String,
+        reason: String,
+    },
+
+    /// An operation did not finish within its time limit
+    Timeout {
+        operation: String,
+        timeout_ms: u64,
+    },
+
+    /// A call was refused because its rate limit was reached
+    RateLimited {
+        capability_id: String,
+        retry_after_ms: Option<u64>,
+    },
+
+    /// An error raised by the call at the given source position (1-based)
+    Located {
+        error: Box<RuntimeError>,
+        line: usize,
+        column: usize,
+    },
 }
 
 impl RuntimeError {
     pub fn new(message: &str) -> RuntimeError {
         RuntimeError::Generic(message.to_string())
     }
+
+    /// An undefined `symbol`, suggesting the closest of the `known` names (typo help).
+    pub fn undefined_symbol(symbol: Symbol, known: &[String]) -> RuntimeError {
+        let suggestion = crate::error_reporting::closest_symbol(&symbol.0, known);
+        RuntimeError::UndefinedSymbol { symbol, suggestion }
+    }
+
+    /// The underlying error, without any source position attached.
+    pub fn without_location(&self) -> &RuntimeError {
+        match self {
+            RuntimeError::Located { error, .. } => error.without_location(),
+            other => other,
+        }
+    }
 }
 
 impl fmt::Display for RuntimeError {
@@ -167,8 +218,12 @@ impl fmt::Display for RuntimeError {
                     operation, expected, actual
                 )
             }
-            RuntimeError::UndefinedSymbol(symbol) => {
-                write!(f, "Undefined symbol: {}", symbol.0)
+            RuntimeError::UndefinedSymbol { symbol, suggestion } => {
+                write!(f, "Undefined symbol: {}", symbol.0)?;
+                if let Some(suggestion) = suggestion {
+                    write!(f, " (did you mean `{}`?)", suggestion)?;
+                }
+                Ok(())
             }
             RuntimeError::SymbolNotFound(symbol) => {
                 write!(f, "Symbol not found: {}", symbol)
@@ -269,6 +324,7 @@ impl fmt::Display for RuntimeError {
             } => {
                 write!(f, "Application error ({}): {}", error_type.0, message)
             }
+            #[allow(deprecated)]
             RuntimeError::UnknownCapability(capability) => {
                 write!(f, "Unknown capability: {}", capability)
             }
@@ -315,6 +371,45 @@ impl fmt::Display for RuntimeError {
                     dimension, policy
                 )
             }
+            RuntimeError::UnsupportedInIr(construct) => {
+                write!(f, "Not supported by the IR runtime: {}", construct)
+            }
+            RuntimeError::CapabilityNotFound { capability_id } => {
+                write!(f, "Capability not found: {}", capability_id)
+            }
+            RuntimeError::CapabilityNotPermitted {
+                capability_id,
+                reason,
+            } => {
+                write!(
+                    f,
+                    "Capability '{}' not permitted: {}",
+                    capability_id, reason
+                )
+            }
+            RuntimeError::Timeout {
+                operation,
+                timeout_ms,
+            } => {
+                write!(f, "{} timed out after {}ms", operation, timeout_ms)
+            }
+            RuntimeError::RateLimited {
+                capability_id,
+                retry_after_ms,
+            } => {
+                write!(f, "Rate limited: {}", capability_id)?;
+                if let Some(ms) = retry_after_ms {
+                    write!(f, " (retry after {}ms)", ms)?;
+                }
+                Ok(())
+            }
+            RuntimeError::Located {
+                error,
+                line,
+                column,
+            } => {
+                write!(f, "{} (at line {}, column {})", error, line, column)
+            }
         }
     }
 }
@@ -342,9 +437,7 @@ impl RuntimeError {
                     operation, expected, actual
                 )
             }
-            RuntimeError::UndefinedSymbol(symbol) => {
-                format!("Undefined symbol: {}", symbol.0)
-            }
+            RuntimeError::UndefinedSymbol { .. } => self.to_string(),
             RuntimeError::SymbolNotFound(symbol) => {
                 format!("Symbol not found: {}", symbol)
             }
@@ -380,14 +473,39 @@ impl RuntimeError {
                     dimension, policy
                 )
             }
+            RuntimeError::ApplicationError { message, .. } => message.clone(),
             _ => self.to_string(),
         };
 
         Value::Error(crate::runtime::values::ErrorValue {
             message,
             stack_trace: None,
+            error_type: Some(self.error_type()),
+            data: match self {
+                RuntimeError::ApplicationError { data, .. } => data.clone().map(Box::new),
+                _ => None,
+            },
         })
     }
+
+    /// Keyword naming the kind of error, as returned by `(error-type e)`: the
+    /// thrown type for application errors, otherwise the variant name
+    /// (e.g. `:DivisionByZero`).
+    pub fn error_type(&self) -> Keyword {
+        match self {
+            RuntimeError::ApplicationError { error_type, .. } => error_type.clone(),
+            RuntimeError::Located { error, .. } => error.error_type(),
+            other => {
+                // The Debug output of every variant starts with its name
+                let debug = format!("{:?}", other);
+                let name = debug
+                    .split(|c: char| !c.is_alphanumeric())
+                    .next()
+                    .unwrap_or_default();
+                Keyword::new(name)
+            }
+        }
+    }
 }
 
 // TODO: Re-enable when IR is integrated
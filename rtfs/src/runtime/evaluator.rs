@@ -3,12 +3,13 @@
 
 use crate::ast::{
     CatchPattern, DefExpr, DefnExpr, DefstructExpr, DoExpr, Expression, FnExpr, ForExpr, IfExpr,
-    LetExpr, Literal, MapKey, MatchExpr, Symbol, TopLevel, TryCatchExpr,
+    LetExpr, Literal, MapKey, MatchExpr, Span, Symbol, TopLevel, TryCatchExpr,
 };
 use crate::compiler::expander::MacroExpander;
 use crate::runtime::environment::Environment;
 use crate::runtime::error::{RuntimeError, RuntimeResult};
 use crate::runtime::execution_outcome::{CallMetadata, ExecutionOutcome, HostCall};
+use crate::runtime::forms;
 use crate::runtime::host_interface::HostInterface;
 use crate::runtime::module_runtime::ModuleRegistry;
 use crate::runtime::security::IsolationLevel;
@@ -20,9 +21,12 @@ use crate::runtime::stubs::{
 use crate::runtime::type_validator::{
     TypeCheckingConfig, TypeValidator, ValidationLevel, VerificationContext,
 };
-use crate::runtime::values::{Arity, BuiltinFunctionWithContext, Function, Value};
+use crate::runtime::values::{Arity, BuiltinFunctionWithContext, Function, Value, ValueMap};
 use std::collections::HashMap;
 use std::sync::{Arc, Mutex, RwLock};
+/// Upper bound on the iteration count of evaluator loops such as `dotimes`.
+pub const MAX_LOOP_ITERATIONS: i64 = 1_000_000;
+
 type SpecialFormHandler =
     fn(&Evaluator, &[Expression], &mut Environment) -> Result<ExecutionOutcome, RuntimeError>;
 
@@ -76,6 +80,15 @@ impl Evaluator {
         // Core iteration forms
         special_forms.insert("dotimes".to_string(), Self::eval_dotimes_form);
         special_forms.insert("for".to_string(), Self::eval_for_form);
+        // Single-branch conditionals
+        special_forms.insert("when".to_string(), Self::eval_when_form);
+        special_forms.insert("when-not".to_string(), Self::eval_when_not_form);
+        // Short-circuiting logic: later arguments are only evaluated when needed
+        special_forms.insert("and".to_string(), Self::eval_and_form);
+        special_forms.insert("or".to_string(), Self::eval_or_form);
+        // Threading forms rewrite into nested calls before evaluation
+        special_forms.insert("->".to_string(), Self::eval_thread_first_form);
+        special_forms.insert("->>".to_string(), Self::eval_thread_last_form);
         // Add other evaluator-level special forms here in the future
 
         // LLM execution bridge (M1)
@@ -388,7 +401,7 @@ impl Evaluator {
                     // Evaluate intent properties and return intent metadata
                     let mut intent_metadata = HashMap::new();
                     for property in &intent.properties {
-                        let key = crate::ast::MapKey::String(property.key.0.clone());
+                        let key = crate::ast::MapKey::String(property.key.0.to_string());
                         match self.eval_expr(&property.value, &mut env)? {
                             ExecutionOutcome::Complete(v) => {
                                 intent_metadata.insert(key, v);
@@ -402,13 +415,13 @@ impl Evaluator {
                             }
                         }
                     }
-                    last_value = Value::Map(intent_metadata);
+                    last_value = Value::Map(intent_metadata.into());
                 }
                 TopLevel::Plan(plan) => {
                     // Evaluate plan properties and return plan metadata
                     let mut plan_metadata = HashMap::new();
                     for property in &plan.properties {
-                        let key = crate::ast::MapKey::String(property.key.0.clone());
+                        let key = crate::ast::MapKey::String(property.key.0.to_string());
                         match self.eval_expr(&property.value, &mut env)? {
                             ExecutionOutcome::Complete(v) => {
                                 plan_metadata.insert(key, v);
@@ -422,13 +435,13 @@ impl Evaluator {
                             }
                         }
                     }
-                    last_value = Value::Map(plan_metadata);
+                    last_value = Value::Map(plan_metadata.into());
                 }
                 TopLevel::Action(action) => {
                     // Evaluate action properties and return action metadata
                     let mut action_metadata = HashMap::new();
                     for property in &action.properties {
-                        let key = crate::ast::MapKey::String(property.key.0.clone());
+                        let key = crate::ast::MapKey::String(property.key.0.to_string());
                         match self.eval_expr(&property.value, &mut env)? {
                             ExecutionOutcome::Complete(v) => {
                                 action_metadata.insert(key, v);
@@ -442,13 +455,13 @@ impl Evaluator {
                             }
                         }
                     }
-                    last_value = Value::Map(action_metadata);
+                    last_value = Value::Map(action_metadata.into());
                 }
                 TopLevel::Capability(capability) => {
                     // Evaluate capability properties and return capability metadata
                     let mut capability_metadata = HashMap::new();
                     for property in &capability.properties {
-                        let key = crate::ast::MapKey::String(property.key.0.clone());
+                        let key = crate::ast::MapKey::String(property.key.0.to_string());
                         match self.eval_expr(&property.value, &mut env)? {
                             ExecutionOutcome::Complete(v) => {
                                 capability_metadata.insert(key, v);
@@ -462,13 +475,13 @@ impl Evaluator {
                             }
                         }
                     }
-                    last_value = Value::Map(capability_metadata);
+                    last_value = Value::Map(capability_metadata.into());
                 }
                 TopLevel::Resource(resource) => {
                     // Evaluate resource properties and return resource metadata
                     let mut resource_metadata = HashMap::new();
                     for property in &resource.properties {
-                        let key = crate::ast::MapKey::String(property.key.0.clone());
+                        let key = crate::ast::MapKey::String(property.key.0.to_string());
                         match self.eval_expr(&property.value, &mut env)? {
                             ExecutionOutcome::Complete(v) => {
                                 resource_metadata.insert(key, v);
@@ -482,7 +495,7 @@ impl Evaluator {
                             }
                         }
                     }
-                    last_value = Value::Map(resource_metadata);
+                    last_value = Value::Map(resource_metadata.into());
                 }
                 TopLevel::Module(module) => {
                     // Evaluate module properties and return module metadata
@@ -490,7 +503,7 @@ impl Evaluator {
                     // Add module name
                     module_metadata.insert(
                         crate::ast::MapKey::String("name".to_string()),
-                        Value::String(module.name.0.clone()),
+                        Value::String(module.name.0.to_string()),
                     );
                     // Add docstring if present
                     if let Some(docstring) = &module.docstring {
@@ -501,14 +514,16 @@ impl Evaluator {
                     }
                     // Add exports if present
                     if let Some(exports) = &module.exports {
-                        let export_values: Vec<Value> =
-                            exports.iter().map(|e| Value::String(e.0.clone())).collect();
+                        let export_values: Vec<Value> = exports
+                            .iter()
+                            .map(|e| Value::String(e.0.to_string()))
+                            .collect();
                         module_metadata.insert(
                             crate::ast::MapKey::String("exports".to_string()),
-                            Value::Vector(export_values),
+                            Value::Vector(export_values.into()),
                         );
                     }
-                    last_value = Value::Map(module_metadata);
+                    last_value = Value::Map(module_metadata.into());
                 }
             }
         }
@@ -562,15 +577,15 @@ impl Evaluator {
                     return Ok(ExecutionOutcome::Complete(v));
                 }
                 // If still not found, return undefined symbol error
-                Err(RuntimeError::UndefinedSymbol(sym.clone()))
+                Err(RuntimeError::undefined_symbol(sym.clone(), &env.symbol_names()))
             }
             Expression::List(list) => {
                 if list.is_empty() {
-                    return Ok(ExecutionOutcome::Complete(Value::Vector(vec![])));
+                    return Ok(ExecutionOutcome::Complete(Value::Vector(vec![].into())));
                 }
 
                 if let Expression::Symbol(s) = &list[0] {
-                    if let Some(handler) = self.special_forms.get(&s.0) {
+                    if let Some(handler) = self.special_forms.get(s.0.as_str()) {
                         return handler(self, &list[1..], env);
                     }
                 }
@@ -604,11 +619,13 @@ impl Evaluator {
                     }
                 }
             }
-            // Macro-related expressions should have been expanded before evaluation
-            Expression::Quasiquote(_)
-            | Expression::Unquote(_)
-            | Expression::UnquoteSplicing(_)
-            | Expression::Defmacro(_) => Err(RuntimeError::InternalError(
+            Expression::Quasiquote(template) => self.eval_quasiquote(template, env),
+            Expression::Unquote(_) | Expression::UnquoteSplicing(_) => Err(RuntimeError::Generic(
+                "unquote (~) and unquote-splicing (~@) are only valid inside a quasiquote"
+                    .to_string(),
+            )),
+            // Macro definitions should have been expanded before evaluation
+            Expression::Defmacro(_) => Err(RuntimeError::InternalError(
                 "Macro-related expressions should have been expanded before evaluation".to_string(),
             )),
             Expression::Vector(exprs) => {
@@ -625,7 +642,7 @@ impl Evaluator {
                         }
                     }
                 }
-                Ok(ExecutionOutcome::Complete(Value::Vector(values_vec)))
+                Ok(ExecutionOutcome::Complete(Value::Vector(values_vec.into())))
             }
             Expression::Map(map) => {
                 let mut result = HashMap::new();
@@ -643,9 +660,11 @@ impl Evaluator {
                         }
                     }
                 }
-                Ok(ExecutionOutcome::Complete(Value::Map(result)))
+                Ok(ExecutionOutcome::Complete(Value::Map(result.into())))
             }
-            Expression::FunctionCall { callee, arguments } => {
+            Expression::FunctionCall {
+                callee, arguments, ..
+            } => {
                 // Check if this is a special form before evaluating the callee
                 if let Expression::Symbol(s) = &**callee {
                     // Special case: if this is "step" and the first argument is a keyword,
@@ -684,7 +703,7 @@ impl Evaluator {
                         }
                     }
 
-                    if let Some(handler) = self.special_forms.get(&s.0) {
+                    if let Some(handler) = self.special_forms.get(s.0.as_str()) {
                         return handler(self, arguments, env);
                     }
                 }
@@ -743,8 +762,9 @@ impl Evaluator {
             Expression::Deref(expr) => {
                 // @atom-name desugars to (deref atom-name)
                 let deref_call = Expression::FunctionCall {
-                    callee: Box::new(Expression::Symbol(Symbol("deref".to_string()))),
+                    callee: Box::new(Expression::Symbol(Symbol("deref".into()))),
                     arguments: vec![*expr.clone()],
+                    span: Span::unknown(),
                 };
                 self.eval_expr(&deref_call, env)
             }
@@ -762,7 +782,7 @@ impl Evaluator {
                 for (key, value_expr) in meta.iter() {
                     let key_str = match key {
                         crate::ast::MapKey::Keyword(kw) => kw.0.clone(),
-                        crate::ast::MapKey::String(s) => s.clone(),
+                        crate::ast::MapKey::String(s) => s.as_str().into(),
                         _ => continue,
                     };
                     // Only extract keys with "runtime." prefix
@@ -929,8 +949,8 @@ impl Evaluator {
                     for (k, v) in bound.into_iter() {
                         map_vals.insert(crate::ast::MapKey::String(k), v);
                     }
-                    let sym = crate::ast::Symbol("%params".to_string());
-                    child.define(&sym, Value::Map(map_vals));
+                    let sym = crate::ast::Symbol("%params".into());
+                    child.define(&sym, Value::Map(map_vals.into()));
                     child_env_opt = Some(child);
                 }
                 Err(e) => {
@@ -1366,25 +1386,28 @@ impl Evaluator {
 
                     if key == "merge-policy" || key == "merge_policy" {
                         // Map value to ConflictResolution
-                        merge_policy = match val {
-                            Value::Keyword(crate::ast::Keyword(s)) | Value::String(s) => {
-                                match s.as_str() {
-                                    "keep-existing" | "keep_existing" | "parent-wins"
-                                    | "parent_wins" => ConflictResolution::KeepExisting,
-                                    "overwrite" | "child-wins" | "child_wins" => {
-                                        ConflictResolution::Overwrite
-                                    }
-                                    "merge" => ConflictResolution::Merge,
-                                    other => {
-                                        return Err(RuntimeError::InvalidArguments {
-                                            expected: ":keep-existing | :overwrite | :merge"
-                                                .to_string(),
-                                            actual: other.to_string(),
-                                        });
-                                    }
+                        let policy_name = match &val {
+                            Value::Keyword(crate::ast::Keyword(s)) => Some(s.as_str()),
+                            Value::String(s) => Some(s.as_str()),
+                            _ => None,
+                        };
+                        merge_policy = match policy_name {
+                            Some(s) => match s {
+                                "keep-existing" | "keep_existing" | "parent-wins"
+                                | "parent_wins" => ConflictResolution::KeepExisting,
+                                "overwrite" | "child-wins" | "child_wins" => {
+                                    ConflictResolution::Overwrite
                                 }
-                            }
-                            _ => ConflictResolution::KeepExisting,
+                                "merge" => ConflictResolution::Merge,
+                                other => {
+                                    return Err(RuntimeError::InvalidArguments {
+                                        expected: ":keep-existing | :overwrite | :merge"
+                                            .to_string(),
+                                        actual: other.to_string(),
+                                    });
+                                }
+                            },
+                            None => ConflictResolution::KeepExisting,
                         };
                         i += 2;
                         continue;
@@ -1460,7 +1483,7 @@ impl Evaluator {
         self.host.clear_step_exposure_override();
 
         // 5. Notify host of successful completion
-        let final_result = Value::Vector(results);
+        let final_result = Value::Vector(results.into());
         let exec_result = ExecutionResultStruct {
             success: true,
             value: final_result.clone(),
@@ -1533,7 +1556,7 @@ impl Evaluator {
         }
 
         // Lookup builtin 'get' in the environment and call it
-        if let Some(val) = env.lookup(&crate::ast::Symbol("get".to_string())) {
+        if let Some(val) = env.lookup(&crate::ast::Symbol("get".into())) {
             match val {
                 Value::Function(Function::Builtin(bf)) => {
                     return Ok(ExecutionOutcome::Complete((bf.func)(evaluated_args)?))
@@ -1549,9 +1572,10 @@ impl Evaluator {
             }
         }
 
-        Err(RuntimeError::UndefinedSymbol(crate::ast::Symbol(
-            "get".to_string(),
-        )))
+        Err(RuntimeError::UndefinedSymbol {
+            symbol: crate::ast::Symbol("get".into()),
+            suggestion: None,
+        })
     }
 
     /// LLM execution bridge special form
@@ -1895,6 +1919,11 @@ impl Evaluator {
                     }
                     // Pure functions continue with normal execution (fall through)
                 }
+                closure.check_arity(
+                    env.find_function_name(&func_value).unwrap_or("anonymous fn"),
+                    args.len(),
+                )?;
+
                 // Create new environment for function execution, parented by the captured closure
                 let mut func_env = Environment::with_parent(closure.env.clone());
 
@@ -1903,15 +1932,6 @@ impl Evaluator {
                     // This closure has a variadic parameter
                     let required_param_count = closure.param_patterns.len();
 
-                    // Check minimum argument count for required parameters
-                    if args.len() < required_param_count {
-                        return Err(RuntimeError::ArityMismatch {
-                            function: "user-defined function".to_string(),
-                            expected: format!("at least {}", required_param_count),
-                            actual: args.len(),
-                        });
-                    }
-
                     // Validate required parameters against annotations if present
                     if closure.param_type_annotations.len() == closure.param_patterns.len() {
                         for i in 0..required_param_count {
@@ -1959,16 +1979,9 @@ impl Evaluator {
                                 })?;
                         }
                     }
-                    func_env.define(variadic_symbol, Value::List(rest_args));
+                    func_env.define(variadic_symbol, Value::List(rest_args.into()));
                 } else if !closure.param_patterns.is_empty() {
                     // Normal parameter binding for non-variadic functions
-                    if closure.param_patterns.len() != args.len() {
-                        return Err(RuntimeError::ArityMismatch {
-                            function: "user-defined function".to_string(),
-                            expected: closure.param_patterns.len().to_string(),
-                            actual: args.len(),
-                        });
-                    }
                     // Validate parameters against annotations if present
                     if closure.param_type_annotations.len() == closure.param_patterns.len() {
                         for (i, arg) in args.iter().enumerate() {
@@ -2065,20 +2078,35 @@ impl Evaluator {
         }
     }
 
+    /// Evaluates `` `template `` to data, filling its `~x` and `~@xs` holes with
+    /// the values of their expressions.
+    fn eval_quasiquote(
+        &self,
+        template: &Expression,
+        env: &mut Environment,
+    ) -> Result<ExecutionOutcome, RuntimeError> {
+        let mut fill = |hole: &Expression| match self.eval_expr(hole, env)? {
+            ExecutionOutcome::Complete(value) => Ok(value),
+            ExecutionOutcome::RequiresHost(hc) => Err(RuntimeError::Generic(format!(
+                "Host call required in quasiquote: {}",
+                hc.capability_id
+            ))),
+            #[cfg(feature = "effect-boundary")]
+            ExecutionOutcome::RequiresHost(_) => Err(RuntimeError::Generic(
+                "Host effect required in quasiquote".to_string(),
+            )),
+        };
+        Ok(ExecutionOutcome::Complete(forms::quasiquote_to_form(
+            template, &mut fill,
+        )?))
+    }
+
     fn check_arity(&self, arity: &Arity, arg_count: usize) -> bool {
-        match arity {
-            Arity::Fixed(n) => arg_count == *n,
-            Arity::Variadic(n) => arg_count >= *n,
-            Arity::Range(min, max) => arg_count >= *min && arg_count <= *max,
-        }
+        arity.accepts(arg_count)
     }
 
     fn arity_to_string(&self, arity: &Arity) -> String {
-        match arity {
-            Arity::Fixed(n) => n.to_string(),
-            Arity::Variadic(n) => format!("at least {}", n),
-            Arity::Range(min, max) => format!("between {} and {}", min, max),
-        }
+        arity.describe()
     }
 
     fn eval_if(
@@ -2131,51 +2159,32 @@ impl Evaluator {
         }
     }
 
+    /// A let needs letrec treatment when one of its function bindings refers to
+    /// itself or to another function binding of the same let. Value bindings
+    /// are always sequential, so `(let [x 1 x (+ x 1)] x)` stays on the simple path.
     fn should_use_recursive_evaluation(&self, let_expr: &LetExpr) -> bool {
-        // First, check if all bindings are functions (original logic)
-        let all_bindings_are_functions = let_expr
+        let function_names: std::collections::HashSet<&str> = let_expr
             .bindings
             .iter()
-            .all(|binding| matches!(&*binding.value, Expression::Fn(_) | Expression::Defn(_)));
-
-        if all_bindings_are_functions {
-            return self.detect_recursion_in_let(&let_expr.bindings);
-        }
-
-        // Second, check for mixed cases where some bindings reference themselves
-        // even when there are nested non-function bindings
-        for binding in &let_expr.bindings {
-            if let crate::ast::Pattern::Symbol(symbol) = &binding.pattern {
-                let binding_names = std::collections::HashSet::from([symbol.0.as_str()]);
-                if self.expr_references_symbols(&binding.value, &binding_names) {
-                    return true;
-                }
-            }
+            .filter_map(Self::function_binding_name)
+            .collect();
+        if function_names.is_empty() {
+            return false;
         }
-
-        false
+        let_expr.bindings.iter().any(|binding| {
+            Self::function_binding_name(binding).is_some()
+                && self.expr_references_symbols(&binding.value, &function_names)
+        })
     }
 
-    fn detect_recursion_in_let(&self, bindings: &[crate::ast::LetBinding]) -> bool {
-        // Collect all binding names
-        let binding_names: std::collections::HashSet<&str> = bindings
-            .iter()
-            .filter_map(|b| {
-                if let crate::ast::Pattern::Symbol(s) = &b.pattern {
-                    Some(s.0.as_str())
-                } else {
-                    None
-                }
-            })
-            .collect();
-
-        // Check if any binding value references other binding names
-        for binding in bindings {
-            if self.expr_references_symbols(&binding.value, &binding_names) {
-                return true;
+    /// Name of a `name (fn ...)` binding, which letrec pre-binds.
+    fn function_binding_name(binding: &crate::ast::LetBinding) -> Option<&str> {
+        match (&binding.pattern, &*binding.value) {
+            (crate::ast::Pattern::Symbol(s), Expression::Fn(_) | Expression::Defn(_)) => {
+                Some(s.0.as_str())
             }
+            _ => None,
         }
-        false
     }
 
     fn expr_references_symbols(
@@ -2185,7 +2194,9 @@ impl Evaluator {
     ) -> bool {
         match expr {
             Expression::Symbol(s) => symbols.contains(s.0.as_str()),
-            Expression::FunctionCall { callee, arguments } => {
+            Expression::FunctionCall {
+                callee, arguments, ..
+            } => {
                 // Check function name
                 if let Expression::Symbol(s) = &**callee {
                     if symbols.contains(s.0.as_str()) {
@@ -2315,44 +2326,31 @@ impl Evaluator {
         self.eval_do_body(&let_expr.body, &mut let_env)
     }
 
+    /// Letrec evaluation: every function binding is pre-bound to a placeholder
+    /// so function bodies can call themselves and each other, then bindings are
+    /// evaluated in order exactly like a plain `let`.
     fn eval_let_with_recursion(
         &self,
         let_expr: &LetExpr,
         env: &mut Environment,
     ) -> Result<ExecutionOutcome, RuntimeError> {
         let mut letrec_env = Environment::with_parent(Arc::new(env.clone()));
-        let mut placeholders = Vec::new();
+        let mut placeholders = HashMap::new();
 
         // First pass: create placeholders for all function bindings
         for binding in &let_expr.bindings {
-            if let crate::ast::Pattern::Symbol(symbol) = &binding.pattern {
+            if let Some(name) = Self::function_binding_name(binding) {
+                let symbol = Symbol(name.into());
                 let placeholder_cell = Arc::new(RwLock::new(Value::Nil));
-                letrec_env.define(symbol, Value::FunctionPlaceholder(placeholder_cell.clone()));
-                placeholders.push((symbol.clone(), binding.value.clone(), placeholder_cell));
-            } else {
-                return Err(RuntimeError::NotImplemented(
-                    "Complex patterns not yet supported in recursive let".to_string(),
-                ));
+                letrec_env.define(&symbol, Value::FunctionPlaceholder(placeholder_cell.clone()));
+                placeholders.insert(symbol, placeholder_cell);
             }
         }
 
-        // Second pass: evaluate all bindings with placeholders available
-        for (symbol, value_expr, placeholder_cell) in placeholders {
-            match self.eval_expr(&value_expr, &mut letrec_env)? {
-                ExecutionOutcome::Complete(value) => {
-                    if matches!(value, Value::Function(_)) {
-                        let mut guard = placeholder_cell.write().map_err(|e| {
-                            RuntimeError::InternalError(format!("RwLock poisoned: {}", e))
-                        })?;
-                        *guard = value;
-                    } else {
-                        return Err(RuntimeError::TypeError {
-                            expected: "function".to_string(),
-                            actual: value.type_name().to_string(),
-                            operation: format!("binding {} in recursive let", symbol.0),
-                        });
-                    }
-                }
+        // Second pass: evaluate bindings in order with placeholders available
+        for binding in &let_expr.bindings {
+            let value = match self.eval_expr(&binding.value, &mut letrec_env)? {
+                ExecutionOutcome::Complete(value) => value,
                 ExecutionOutcome::RequiresHost(hc) => {
                     return Ok(ExecutionOutcome::RequiresHost(hc))
                 }
@@ -2360,6 +2358,30 @@ impl Evaluator {
                 ExecutionOutcome::RequiresHost(host_call) => {
                     return Ok(ExecutionOutcome::RequiresHost(host_call))
                 }
+            };
+            let placeholder = match &binding.pattern {
+                crate::ast::Pattern::Symbol(symbol)
+                    if Self::function_binding_name(binding).is_some() =>
+                {
+                    placeholders.get(symbol).map(|cell| (symbol, cell))
+                }
+                _ => None,
+            };
+            match placeholder {
+                Some((symbol, placeholder_cell)) => {
+                    if !matches!(value, Value::Function(_)) {
+                        return Err(RuntimeError::TypeError {
+                            expected: "function".to_string(),
+                            actual: value.type_name().to_string(),
+                            operation: format!("binding {} in recursive let", symbol.0),
+                        });
+                    }
+                    let mut guard = placeholder_cell.write().map_err(|e| {
+                        RuntimeError::InternalError(format!("RwLock poisoned: {}", e))
+                    })?;
+                    *guard = value;
+                }
+                None => self.bind_pattern(&binding.pattern, &value, &mut letrec_env)?,
             }
         }
 
@@ -2644,7 +2666,7 @@ impl Evaluator {
                       -> RuntimeResult<Value> {
                     if args.len() != 1 {
                         return Err(RuntimeError::ArityMismatch {
-                            function: struct_name_clone.clone(),
+                            function: struct_name_clone.to_string(),
                             expected: "1".to_string(),
                             actual: args.len(),
                         });
@@ -2698,17 +2720,19 @@ impl Evaluator {
         Ok(ExecutionOutcome::Complete(constructor_value))
     }
 
-    /// Special form: (dotimes [i n] body)
+    /// Special form: (dotimes [i n] body...)
+    /// Runs the body for side effects with `i` bound to 0..n and returns nil.
+    /// `n` above [`MAX_LOOP_ITERATIONS`] is rejected before the loop starts.
     fn eval_dotimes_form(
         &self,
         args: &[Expression],
         env: &mut Environment,
     ) -> Result<ExecutionOutcome, RuntimeError> {
-        if args.len() != 2 {
+        if args.is_empty() {
             return Err(RuntimeError::ArityMismatch {
                 function: "dotimes".into(),
-                expected: "2".into(),
-                actual: args.len(),
+                expected: "at least 1".into(),
+                actual: 0,
             });
         }
         // Extract binding vector directly from AST (don't evaluate it)
@@ -2756,19 +2780,119 @@ impl Evaluator {
                 })
             }
         };
-        if count <= 0 {
-            return Ok(ExecutionOutcome::Complete(Value::Nil));
+        if count > MAX_LOOP_ITERATIONS {
+            return Err(RuntimeError::ResourceError {
+                resource_type: "iterations".into(),
+                message: format!(
+                    "dotimes count {} exceeds the limit of {}",
+                    count, MAX_LOOP_ITERATIONS
+                ),
+            });
         }
-        let mut last = Value::Nil;
         for i in 0..count {
             // Create a child environment that can access parent variables
             let mut loop_env = Environment::with_parent(Arc::new(env.clone()));
             // Define the loop variable in the child environment
             loop_env.define(&sym, Value::Integer(i));
             // Evaluate the loop body in the child environment
-            let body_res = self.eval_expr(&args[1], &mut loop_env)?;
+            let body_res = self.eval_do_body(&args[1..], &mut loop_env)?;
             match body_res {
-                ExecutionOutcome::Complete(v) => last = v,
+                ExecutionOutcome::Complete(_) => {}
+                ExecutionOutcome::RequiresHost(hc) => {
+                    return Ok(ExecutionOutcome::RequiresHost(hc))
+                }
+                #[cfg(feature = "effect-boundary")]
+                ExecutionOutcome::RequiresHost(host_call) => {
+                    return Ok(ExecutionOutcome::RequiresHost(host_call))
+                }
+            }
+        }
+        Ok(ExecutionOutcome::Complete(Value::Nil))
+    }
+
+    /// Special form: (when test body...)
+    /// Evaluates the body in order when `test` is truthy and returns the last
+    /// value; returns nil without touching the body otherwise.
+    fn eval_when_form(
+        &self,
+        args: &[Expression],
+        env: &mut Environment,
+    ) -> Result<ExecutionOutcome, RuntimeError> {
+        self.eval_conditional_body("when", args, true, env)
+    }
+
+    /// Special form: (when-not test body...)
+    /// Like `when`, but runs the body only when `test` is falsy.
+    fn eval_when_not_form(
+        &self,
+        args: &[Expression],
+        env: &mut Environment,
+    ) -> Result<ExecutionOutcome, RuntimeError> {
+        self.eval_conditional_body("when-not", args, false, env)
+    }
+
+    fn eval_conditional_body(
+        &self,
+        form: &str,
+        args: &[Expression],
+        run_when: bool,
+        env: &mut Environment,
+    ) -> Result<ExecutionOutcome, RuntimeError> {
+        let Some((test, body)) = args.split_first() else {
+            return Err(RuntimeError::ArityMismatch {
+                function: form.into(),
+                expected: "at least 1".into(),
+                actual: 0,
+            });
+        };
+        let test_value = match self.eval_expr(test, env)? {
+            ExecutionOutcome::Complete(v) => v,
+            ExecutionOutcome::RequiresHost(hc) => return Ok(ExecutionOutcome::RequiresHost(hc)),
+            #[cfg(feature = "effect-boundary")]
+            ExecutionOutcome::RequiresHost(host_call) => {
+                return Ok(ExecutionOutcome::RequiresHost(host_call))
+            }
+        };
+        if test_value.is_truthy() == run_when {
+            self.eval_do_body(body, env)
+        } else {
+            Ok(ExecutionOutcome::Complete(Value::Nil))
+        }
+    }
+
+    /// Special form: (and expr...)
+    /// Evaluates left to right, stopping at the first falsy value and returning
+    /// it; otherwise returns the last value, or true when there are none.
+    fn eval_and_form(
+        &self,
+        args: &[Expression],
+        env: &mut Environment,
+    ) -> Result<ExecutionOutcome, RuntimeError> {
+        self.eval_short_circuit(args, false, Value::Boolean(true), env)
+    }
+
+    /// Special form: (or expr...)
+    /// Evaluates left to right, stopping at the first truthy value and
+    /// returning it; otherwise returns the last value, or false when empty.
+    fn eval_or_form(
+        &self,
+        args: &[Expression],
+        env: &mut Environment,
+    ) -> Result<ExecutionOutcome, RuntimeError> {
+        self.eval_short_circuit(args, true, Value::Boolean(false), env)
+    }
+
+    fn eval_short_circuit(
+        &self,
+        args: &[Expression],
+        stop_when: bool,
+        empty: Value,
+        env: &mut Environment,
+    ) -> Result<ExecutionOutcome, RuntimeError> {
+        let mut last = empty;
+        for expr in args {
+            last = match self.eval_expr(expr, env)? {
+                ExecutionOutcome::Complete(v) => v,
                 ExecutionOutcome::RequiresHost(hc) => {
                     return Ok(ExecutionOutcome::RequiresHost(hc))
                 }
@@ -2776,11 +2900,36 @@ impl Evaluator {
                 ExecutionOutcome::RequiresHost(host_call) => {
                     return Ok(ExecutionOutcome::RequiresHost(host_call))
                 }
+            };
+            if last.is_truthy() == stop_when {
+                break;
             }
         }
         Ok(ExecutionOutcome::Complete(last))
     }
 
+    /// Special form: (-> x (f a) g) threads `x` as the first argument of each step
+    fn eval_thread_first_form(
+        &self,
+        args: &[Expression],
+        env: &mut Environment,
+    ) -> Result<ExecutionOutcome, RuntimeError> {
+        let expanded = crate::compiler::threading::expand_threading("->", args)
+            .map_err(RuntimeError::InvalidArgument)?;
+        self.eval_expr(&expanded, env)
+    }
+
+    /// Special form: (->> x (f a) g) threads `x` as the last argument of each step
+    fn eval_thread_last_form(
+        &self,
+        args: &[Expression],
+        env: &mut Environment,
+    ) -> Result<ExecutionOutcome, RuntimeError> {
+        let expanded = crate::compiler::threading::expand_threading("->>", args)
+            .map_err(RuntimeError::InvalidArgument)?;
+        self.eval_expr(&expanded, env)
+    }
+
     /// Special form: (for [x coll] body) or (for [x coll y coll2 ...] body)
     /// Multi-binding form nests loops left-to-right and returns a vector of results
     fn eval_for_form(
@@ -2844,8 +2993,7 @@ impl Evaluator {
             };
 
             let items = match coll_val {
-                Value::Vector(v) => v,
-                Value::List(v) => v,
+                Value::Vector(v) | Value::List(v) => v,
                 other => {
                     return Err(RuntimeError::TypeError {
                         expected: "vector or list".into(),
@@ -2854,14 +3002,14 @@ impl Evaluator {
                     })
                 }
             };
-            pairs.push((sym, items));
+            pairs.push((sym, items.into_iter().collect()));
             i += 2;
         }
 
         // Recursive nested iteration
         let mut out: Vec<Value> = Vec::new();
         self.for_nest(&pairs, 0, env, &args[1], &mut out)?;
-        Ok(ExecutionOutcome::Complete(Value::Vector(out)))
+        Ok(ExecutionOutcome::Complete(Value::Vector(out.into())))
     }
 
     fn eval_for(
@@ -2905,8 +3053,7 @@ impl Evaluator {
             };
 
             let items = match coll_val {
-                Value::Vector(v) => v,
-                Value::List(v) => v,
+                Value::Vector(v) | Value::List(v) => v,
                 other => {
                     return Err(RuntimeError::TypeError {
                         expected: "vector or list".into(),
@@ -2915,14 +3062,14 @@ impl Evaluator {
                     })
                 }
             };
-            pairs.push((sym, items));
+            pairs.push((sym, items.into_iter().collect()));
             i += 2;
         }
 
         // Recursive nested iteration
         let mut out: Vec<Value> = Vec::new();
         self.for_nest(&pairs, 0, env, &for_expr.body, &mut out)?;
-        Ok(ExecutionOutcome::Complete(Value::Vector(out)))
+        Ok(ExecutionOutcome::Complete(Value::Vector(out.into())))
     }
     fn for_nest(
         &self,
@@ -3124,19 +3271,19 @@ impl Evaluator {
     fn extract_param_symbol(&self, pattern: &crate::ast::Pattern) -> Symbol {
         match pattern {
             crate::ast::Pattern::Symbol(s) => s.clone(),
-            crate::ast::Pattern::Wildcard => Symbol("_".to_string()),
-            crate::ast::Pattern::VectorDestructuring { as_symbol, .. } => as_symbol
-                .clone()
-                .unwrap_or_else(|| Symbol("vec".to_string())),
-            crate::ast::Pattern::MapDestructuring { as_symbol, .. } => as_symbol
-                .clone()
-                .unwrap_or_else(|| Symbol("map".to_string())),
+            crate::ast::Pattern::Wildcard => Symbol("_".into()),
+            crate::ast::Pattern::VectorDestructuring { as_symbol, .. } => {
+                as_symbol.clone().unwrap_or_else(|| Symbol("vec".into()))
+            }
+            crate::ast::Pattern::MapDestructuring { as_symbol, .. } => {
+                as_symbol.clone().unwrap_or_else(|| Symbol("map".into()))
+            }
         }
     }
     /// Parse a map of criteria into SimpleDiscoveryQuery
     fn parse_criteria_to_query(
         &self,
-        criteria_map: &std::collections::HashMap<crate::ast::MapKey, Value>,
+        criteria_map: &ValueMap,
     ) -> RuntimeResult<SimpleDiscoveryQuery> {
         use crate::ast::{Keyword, MapKey};
 
@@ -3200,7 +3347,7 @@ impl Evaluator {
     /// Parse discovery options from a map
     fn parse_options_to_query(
         &self,
-        options_map: &std::collections::HashMap<crate::ast::MapKey, Value>,
+        options_map: &ValueMap,
     ) -> RuntimeResult<SimpleDiscoveryOptions> {
         use crate::ast::{Keyword, MapKey};
 
@@ -3286,28 +3433,28 @@ impl Evaluator {
 
         // Add agent ID (primary id)
         map.insert(
-            crate::ast::MapKey::Keyword(crate::ast::Keyword("agent-id".to_string())),
+            crate::ast::MapKey::Keyword(crate::ast::Keyword("agent-id".into())),
             Value::String(agent_card.id.clone()),
         );
 
         // Add agent-id field if present (different from primary id)
         if let Some(agent_id) = &agent_card.agent_id {
             map.insert(
-                crate::ast::MapKey::Keyword(crate::ast::Keyword("agent-id-field".to_string())),
+                crate::ast::MapKey::Keyword(crate::ast::Keyword("agent-id-field".into())),
                 Value::String(agent_id.clone()),
             );
         }
 
         // Add name
         map.insert(
-            crate::ast::MapKey::Keyword(crate::ast::Keyword("name".to_string())),
+            crate::ast::MapKey::Keyword(crate::ast::Keyword("name".into())),
             Value::String(agent_card.name.clone()),
         );
 
         // Add version if present
         if let Some(version) = &agent_card.version {
             map.insert(
-                crate::ast::MapKey::Keyword(crate::ast::Keyword("version".to_string())),
+                crate::ast::MapKey::Keyword(crate::ast::Keyword("version".into())),
                 Value::String(version.clone()),
             );
         }
@@ -3319,14 +3466,14 @@ impl Evaluator {
             .map(|cap| Value::String(cap))
             .collect();
         map.insert(
-            crate::ast::MapKey::Keyword(crate::ast::Keyword("capabilities".to_string())),
-            Value::Vector(capabilities),
+            crate::ast::MapKey::Keyword(crate::ast::Keyword("capabilities".into())),
+            Value::Vector(capabilities.into()),
         );
 
         // Add endpoint if present
         if let Some(endpoint) = &agent_card.endpoint {
             map.insert(
-                crate::ast::MapKey::Keyword(crate::ast::Keyword("endpoint".to_string())),
+                crate::ast::MapKey::Keyword(crate::ast::Keyword("endpoint".into())),
                 Value::String(endpoint.clone()),
             );
         }
@@ -3343,12 +3490,12 @@ impl Evaluator {
                 })
                 .collect();
             map.insert(
-                crate::ast::MapKey::Keyword(crate::ast::Keyword("metadata".to_string())),
-                Value::Map(metadata_map),
+                crate::ast::MapKey::Keyword(crate::ast::Keyword("metadata".into())),
+                Value::Map(metadata_map.into()),
             );
         }
 
-        Value::Map(map)
+        Value::Map(map.into())
     }
 
     /// Helper function to parse capabilities list from a value
@@ -3371,7 +3518,7 @@ impl Evaluator {
                 Ok(out)
             }
             Value::String(s) => Ok(vec![s.clone()]),
-            Value::Keyword(k) => Ok(vec![k.0.clone()]),
+            Value::Keyword(k) => Ok(vec![k.0.to_string()]),
             other => Err(RuntimeError::TypeError {
                 expected: "vector or string".into(),
                 actual: other.type_name().into(),
@@ -3383,7 +3530,7 @@ impl Evaluator {
     fn parse_string_value(&self, value: &Value) -> RuntimeResult<String> {
         match value {
             Value::String(s) => Ok(s.clone()),
-            Value::Keyword(k) => Ok(k.0.clone()),
+            Value::Keyword(k) => Ok(k.0.to_string()),
             other => Err(RuntimeError::TypeError {
                 expected: "string or keyword".to_string(),
                 actual: other.type_name().to_string(),
@@ -3569,7 +3716,7 @@ impl Evaluator {
 
                     // Handle rest parameter if present
                     if let Some(rest_symbol) = rest {
-                        let rest_values: Vec<Value> = vector_values[required_elements..].to_vec();
+                        let rest_values = vector_values.skip(required_elements);
                         env.define(rest_symbol, Value::Vector(rest_values));
                     }
 
@@ -3622,10 +3769,10 @@ impl Evaluator {
                                     let normalized = if symbol.0.starts_with(":") {
                                         symbol.0.trim_start_matches(':').to_string()
                                     } else {
-                                        symbol.0.clone()
+                                        symbol.0.to_string()
                                     };
                                     let key = crate::ast::MapKey::Keyword(crate::ast::Keyword(
-                                        normalized,
+                                        normalized.into(),
                                     ));
                                     matched_keys.insert(key.clone());
                                     if let Some(map_value) = map_values.get(&key) {
@@ -3667,7 +3814,7 @@ impl Evaluator {
         env: &mut Environment,
     ) -> Result<ExecutionOutcome, RuntimeError> {
         let collection_vec: Vec<Value> = match collection {
-            Value::Vector(v) | Value::List(v) => v.clone(),
+            Value::Vector(v) | Value::List(v) => v.iter().cloned().collect(),
             Value::String(s) => s.chars().map(|c| Value::String(c.to_string())).collect(),
             _ => {
                 return Err(RuntimeError::TypeError {
@@ -3730,7 +3877,7 @@ impl Evaluator {
                 }
             }
         }
-        Ok(ExecutionOutcome::Complete(Value::Vector(result)))
+        Ok(ExecutionOutcome::Complete(Value::Vector(result.into())))
     }
 
     /// Check if a capability is allowed in the current security context
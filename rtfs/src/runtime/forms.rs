@@ -0,0 +1,197 @@
+//! Code as data: converting parsed expressions into quoted values.
+//!
+//! `read-string` uses this to return source as data, and quasiquote uses it to
+//! build its template while filling `~x` / `~@xs` holes with evaluated values.
+//! Calls and lists become `Value::List`, so `(+ 1 2)` reads as a three-element
+//! list headed by the symbol `+`.
+
+use crate::ast::{Expression, Pattern, Symbol};
+use crate::runtime::error::{RuntimeError, RuntimeResult};
+use crate::runtime::values::Value;
+use std::collections::HashMap;
+
+/// Evaluates the expression inside an unquote hole.
+pub type HoleEvaluator<'a> = dyn FnMut(&Expression) -> RuntimeResult<Value> + 'a;
+
+/// Convert `expr` to quoted data. Unquotes are kept as `(unquote x)` forms.
+pub fn expression_to_form(expr: &Expression) -> RuntimeResult<Value> {
+    FormBuilder { fill: None }.form(expr, 0)
+}
+
+/// Build the data for a quasiquoted `template`, calling `fill` for every hole
+/// that belongs to this quasiquote. `~@xs` holes must produce a vector, list or
+/// nil and are spliced into the enclosing list or vector. Holes of nested
+/// quasiquotes are left as data.
+pub fn quasiquote_to_form(template: &Expression, fill: &mut HoleEvaluator) -> RuntimeResult<Value> {
+    FormBuilder { fill: Some(fill) }.form(template, 1)
+}
+
+struct FormBuilder<'f, 'a> {
+    fill: Option<&'f mut HoleEvaluator<'a>>,
+}
+
+impl FormBuilder<'_, '_> {
+    /// `depth` counts the quasiquotes still open around `expr`; holes at depth 1
+    /// are filled.
+    fn form(&mut self, expr: &Expression, depth: u32) -> RuntimeResult<Value> {
+        let symbol = |name: &str| Value::Symbol(Symbol(name.into()));
+
+        match expr {
+            Expression::Literal(lit) => Ok(Value::from(lit.clone())),
+            Expression::Symbol(s) => Ok(Value::Symbol(s.clone())),
+            Expression::List(items) => Ok(Value::List(self.forms(items, depth)?.into())),
+            Expression::Vector(items) => Ok(Value::Vector(self.forms(items, depth)?.into())),
+            Expression::Map(map) => {
+                let mut entries = HashMap::new();
+                for (k, v) in map {
+                    entries.insert(k.clone(), self.form(v, depth)?);
+                }
+                Ok(Value::Map(entries.into()))
+            }
+            Expression::FunctionCall {
+                callee, arguments, ..
+            } => {
+                let mut list = vec![self.form(callee, depth)?];
+                list.extend(self.forms(arguments, depth)?);
+                Ok(Value::List(list.into()))
+            }
+            Expression::Quasiquote(inner) => Ok(Value::List(
+                vec![symbol("quasiquote"), self.form(inner, depth + 1)?].into(),
+            )),
+            Expression::Unquote(inner) => match self.fill.as_mut() {
+                Some(fill) if depth == 1 => fill(inner),
+                _ => Ok(Value::List(
+                    vec![
+                        symbol("unquote"),
+                        self.form(inner, depth.saturating_sub(1))?,
+                    ]
+                    .into(),
+                )),
+            },
+            Expression::UnquoteSplicing(inner) => {
+                if depth == 1 && self.fill.is_some() {
+                    return Err(RuntimeError::Generic(
+                        "unquote-splicing (~@) must appear inside a list or vector".to_string(),
+                    ));
+                }
+                Ok(Value::List(
+                    vec![
+                        symbol("unquote-splicing"),
+                        self.form(inner, depth.saturating_sub(1))?,
+                    ]
+                    .into(),
+                ))
+            }
+            Expression::Deref(inner) => Ok(Value::List(
+                vec![symbol("deref"), self.form(inner, depth)?].into(),
+            )),
+            Expression::If(if_expr) => {
+                let mut list = vec![
+                    symbol("if"),
+                    self.form(&if_expr.condition, depth)?,
+                    self.form(&if_expr.then_branch, depth)?,
+                ];
+                if let Some(else_branch) = &if_expr.else_branch {
+                    list.push(self.form(else_branch, depth)?);
+                }
+                Ok(Value::List(list.into()))
+            }
+            Expression::Do(do_expr) => {
+                let mut list = vec![symbol("do")];
+                list.extend(self.forms(&do_expr.expressions, depth)?);
+                Ok(Value::List(list.into()))
+            }
+            Expression::Let(let_expr) => {
+                let mut bindings = Vec::new();
+                for b in &let_expr.bindings {
+                    if b.type_annotation.is_some() {
+                        return unsupported("typed let");
+                    }
+                    bindings.push(binding_form(&b.pattern)?);
+                    bindings.push(self.form(&b.value, depth)?);
+                }
+                let mut list = vec![symbol("let"), Value::Vector(bindings.into())];
+                list.extend(self.forms(&let_expr.body, depth)?);
+                Ok(Value::List(list.into()))
+            }
+            Expression::Fn(fn_expr) => {
+                if fn_expr.return_type.is_some() || fn_expr.delegation_hint.is_some() {
+                    return unsupported("annotated fn");
+                }
+                let mut params = Vec::new();
+                for p in &fn_expr.params {
+                    if p.type_annotation.is_some() {
+                        return unsupported("typed fn");
+                    }
+                    params.push(binding_form(&p.pattern)?);
+                }
+                if let Some(rest) = &fn_expr.variadic_param {
+                    if rest.type_annotation.is_some() {
+                        return unsupported("typed fn");
+                    }
+                    params.push(symbol("&"));
+                    params.push(binding_form(&rest.pattern)?);
+                }
+                let mut list = vec![symbol("fn"), Value::Vector(params.into())];
+                list.extend(self.forms(&fn_expr.body, depth)?);
+                Ok(Value::List(list.into()))
+            }
+            Expression::Def(def_expr) if def_expr.type_annotation.is_none() => Ok(Value::List(
+                vec![
+                    symbol("def"),
+                    Value::Symbol(def_expr.symbol.clone()),
+                    self.form(&def_expr.value, depth)?,
+                ]
+                .into(),
+            )),
+            Expression::Def(_) => unsupported("typed def"),
+            Expression::Defn(_) => unsupported("defn"),
+            Expression::Defmacro(_) => unsupported("defmacro"),
+            Expression::Defstruct(_) => unsupported("defstruct"),
+            Expression::TryCatch(_) => unsupported("try"),
+            Expression::Match(_) => unsupported("match"),
+            Expression::For(_) => unsupported("for"),
+            Expression::ResourceRef(_) => unsupported("resource reference"),
+            Expression::WithMetadata { .. } => unsupported("metadata-annotated"),
+        }
+    }
+
+    /// Convert the elements of a list or vector, splicing `~@xs` holes in place.
+    fn forms(&mut self, exprs: &[Expression], depth: u32) -> RuntimeResult<Vec<Value>> {
+        let mut out = Vec::with_capacity(exprs.len());
+        for expr in exprs {
+            match (expr, self.fill.as_mut()) {
+                (Expression::UnquoteSplicing(inner), Some(fill)) if depth == 1 => {
+                    match fill(inner)? {
+                        Value::Vector(items) | Value::List(items) => out.extend(items),
+                        Value::Nil => {}
+                        other => {
+                            return Err(RuntimeError::TypeError {
+                                expected: "vector, list or nil".to_string(),
+                                actual: other.type_name().to_string(),
+                                operation: "unquote-splicing".to_string(),
+                            })
+                        }
+                    }
+                }
+                _ => out.push(self.form(expr, depth)?),
+            }
+        }
+        Ok(out)
+    }
+}
+
+fn binding_form(pattern: &Pattern) -> RuntimeResult<Value> {
+    match pattern {
+        Pattern::Symbol(s) => Ok(Value::Symbol(s.clone())),
+        Pattern::Wildcard => Ok(Value::Symbol(Symbol("_".into()))),
+        _ => unsupported("destructuring"),
+    }
+}
+
+fn unsupported<T>(form: &str) -> RuntimeResult<T> {
+    Err(RuntimeError::Generic(format!(
+        "{} forms have no data representation",
+        form
+    )))
+}
@@ -7,6 +7,9 @@ use crate::runtime::error::RuntimeResult;
 use crate::runtime::stubs::ExecutionResultStruct;
 use crate::runtime::values::Value;
 
+/// Wraps each `pmap-call` worker; see [`HostInterface::concurrent_worker_scope`]
+pub type WorkerScope = Box<dyn Fn(&mut dyn FnMut()) + Send + Sync>;
+
 /// The HostInterface provides the contract between the RTFS runtime and the CCOS host.
 pub trait HostInterface: std::fmt::Debug + Send + Sync {
     /// Executes a capability through the CCOS infrastructure.
@@ -90,6 +93,27 @@ pub trait HostInterface: std::fmt::Debug + Send + Sync {
         Ok(())
     }
 
+    /// Persists `bindings` (a map of symbol keyword -> value) together with the current
+    /// execution context under `label` and returns a checkpoint id.
+    /// Backs the RTFS `(checkpoint "label")` form.
+    /// Default implementation: checkpoints are not supported.
+    fn create_checkpoint(&self, label: &str, _bindings: Value) -> RuntimeResult<String> {
+        Err(crate::runtime::error::RuntimeError::Generic(format!(
+            "Host does not support checkpoints (checkpoint '{}')",
+            label
+        )))
+    }
+
+    /// Loads a checkpoint created by `create_checkpoint`, restores its execution context
+    /// and returns the saved bindings map. Backs the RTFS `(restore checkpoint-id)` form.
+    /// Default implementation: checkpoints are not supported.
+    fn restore_checkpoint(&self, checkpoint_id: &str) -> RuntimeResult<Value> {
+        Err(crate::runtime::error::RuntimeError::Generic(format!(
+            "Host does not support checkpoints (restore '{}')",
+            checkpoint_id
+        )))
+    }
+
     /// Sets a runtime execution hint for subsequent capability calls.
     /// Keys use namespace convention: "runtime.learning.retry", "runtime.tracing.tag", etc.
     /// The evaluator calls this when encountering `^{:runtime.* ...}` metadata.
@@ -112,4 +136,13 @@ pub trait HostInterface: std::fmt::Debug + Send + Sync {
     fn clear_all_execution_hints(&self) -> RuntimeResult<()> {
         Ok(())
     }
+
+    /// Returns the wrapper run around each worker thread that `pmap-call` spawns.
+    /// It is obtained on the evaluating thread, so hosts whose `execute_capability`
+    /// relies on an async runtime can capture that runtime here and enter it in
+    /// every worker.
+    /// Default implementation: runs the worker as is.
+    fn concurrent_worker_scope(&self) -> WorkerScope {
+        Box::new(|work| work())
+    }
 }
@@ -10,14 +10,16 @@ use crate::ast::{Expression, Keyword, MapKey};
 // CCOS dependencies removed - using RTFS-local types instead
 // Note: IsolationLevel is used via fully qualified path: crate::runtime::security::IsolationLevel
 use crate::compiler::expander::MacroExpander;
-use crate::ir::converter::IrConverter;
-use crate::ir::core::{IrNode, IrPattern};
+use crate::ir::arity_checker::{builtin_arities, check_builtin_arities};
+use crate::ir::converter::{IrConversionError, IrConverter};
+use crate::ir::core::{IrNode, IrPattern, SourceLocation};
 use crate::runtime::host_interface::HostInterface;
 use crate::runtime::security::RuntimeContext;
 use crate::runtime::type_validator::{TypeCheckingConfig, TypeValidator, VerificationContext};
 use crate::runtime::RuntimeStrategy;
 use std::collections::HashMap;
 use std::sync::Arc;
+use crate::runtime::values::ValueMap;
 // L4AwareDelegationEngine is CCOS-specific, not used in pure RTFS
 
 /// A `RuntimeStrategy` that uses the `IrRuntime`.
@@ -67,10 +69,10 @@ impl IrStrategy {
         }
         Ok(self.persistent_env.as_mut().unwrap())
     }
-}
 
-impl RuntimeStrategy for IrStrategy {
-    fn run(&mut self, program: &Expression) -> Result<ExecutionOutcome, RuntimeError> {
+    /// Expand macros in `program`, lower it to IR and check builtin arities,
+    /// returning the `Program` node that [`Self::run_compiled`] executes.
+    pub fn compile(&mut self, program: &Expression) -> Result<IrNode, RuntimeError> {
         // Expand macros before IR conversion
         let expanded_program = self
             .macro_expander
@@ -80,27 +82,61 @@ impl RuntimeStrategy for IrStrategy {
         let mut converter = IrConverter::with_module_registry(&self.module_registry);
         let ir_node = converter
             .convert_expression(expanded_program)
-            .map_err(|e| RuntimeError::Generic(format!("IR conversion error: {:?}", e)))?;
+            .map_err(|e| match e {
+                IrConversionError::Unsupported { construct } => {
+                    RuntimeError::UnsupportedInIr(construct)
+                }
+                e => RuntimeError::Generic(format!("IR conversion error: {:?}", e)),
+            })?;
+
+        // Reject calls to builtins with the wrong number of arguments before running anything.
+        // Builtins redefined in a persistent (REPL) environment are no longer checked.
+        let mut arities = builtin_arities(&self.module_registry);
+        if let Some(ref persistent_env) = self.persistent_env {
+            arities.retain(|name, _| {
+                matches!(
+                    persistent_env.get(name),
+                    Some(Value::Function(Function::Builtin(_)))
+                        | Some(Value::Function(Function::BuiltinWithContext(_)))
+                )
+            });
+        }
+        check_builtin_arities(&ir_node, &arities)
+            .map_err(|e| RuntimeError::Generic(format!("IR validation error: {}", e)))?;
 
         // Create a program node from the single expression
-        let program_node = IrNode::Program {
+        Ok(IrNode::Program {
             id: converter.next_id(),
             version: "1.0".to_string(),
             forms: vec![ir_node],
             source_location: None,
-        };
+        })
+    }
 
+    /// Execute a program produced by [`Self::compile`] (or reloaded from bytecode).
+    pub fn run_compiled(
+        &mut self,
+        program_node: &IrNode,
+    ) -> Result<ExecutionOutcome, RuntimeError> {
         // Use persistent environment if available (for REPL-like usage)
-        if let Some(ref mut persistent_env) = self.persistent_env {
+        let result = if let Some(ref mut persistent_env) = self.persistent_env {
             self.runtime.execute_program_with_env(
-                &program_node,
+                program_node,
                 &self.module_registry,
                 persistent_env,
             )
         } else {
             self.runtime
-                .execute_program(&program_node, &self.module_registry)
-        }
+                .execute_program(program_node, &self.module_registry)
+        };
+        result.map_err(|e| self.runtime.locate_error(e))
+    }
+}
+
+impl RuntimeStrategy for IrStrategy {
+    fn run(&mut self, program: &Expression) -> Result<ExecutionOutcome, RuntimeError> {
+        let program_node = self.compile(program)?;
+        self.run_compiled(&program_node)
     }
 
     fn clone_box(&self) -> Box<dyn RuntimeStrategy> {
@@ -126,6 +162,8 @@ pub struct IrRuntime {
     // Type validation components (mirrors AST evaluator for runtime checks)
     type_validator: Arc<TypeValidator>,
     type_config: TypeCheckingConfig,
+    // Source position of the innermost call that raised the error currently propagating
+    error_location: Option<(RuntimeError, SourceLocation)>,
 }
 
 impl IrRuntime {
@@ -138,6 +176,30 @@ impl IrRuntime {
             max_recursion_depth: 8192,
             type_validator: Arc::new(TypeValidator::new()),
             type_config: TypeCheckingConfig::default(),
+            error_location: None,
+        }
+    }
+
+    /// Remember where `error` was raised, unless an inner call already did.
+    fn record_error_location(&mut self, error: &RuntimeError, location: &SourceLocation) {
+        if matches!(error, RuntimeError::TailCall { .. }) {
+            return;
+        }
+        if !matches!(&self.error_location, Some((recorded, _)) if recorded == error) {
+            self.error_location = Some((error.clone(), location.clone()));
+        }
+    }
+
+    /// Attach the source position recorded for `error`, if any, so it points
+    /// back at the call in the user's program that failed.
+    pub fn locate_error(&mut self, error: RuntimeError) -> RuntimeError {
+        match self.error_location.take() {
+            Some((recorded, location)) if recorded == error => RuntimeError::Located {
+                error: Box::new(error),
+                line: location.line,
+                column: location.column,
+            },
+            _ => error,
         }
     }
 
@@ -214,9 +276,9 @@ impl IrRuntime {
             IT::Intersection(types) => {
                 TypeExpr::Intersection(types.iter().map(|t| Self::ir_to_type_expr(t)).collect())
             }
-            IT::Resource(name) => TypeExpr::Resource(crate::ast::Symbol(name.clone())),
+            IT::Resource(name) => TypeExpr::Resource(crate::ast::Symbol::new(name.as_str())),
             IT::LiteralValue(lit) => TypeExpr::Literal(lit.clone()),
-            IT::TypeRef(name) => TypeExpr::Alias(crate::ast::Symbol(name.clone())),
+            IT::TypeRef(name) => TypeExpr::Alias(crate::ast::Symbol::new(name.as_str())),
             IT::TypeVar(_) => TypeExpr::Any,
             IT::ParametricMap {
                 key_type,
@@ -345,7 +407,10 @@ impl IrRuntime {
                 if let Some(val) = self.get_with_cross_plan_fallback(name.as_str()) {
                     return Ok(ExecutionOutcome::Complete(val));
                 }
-                Err(RuntimeError::UndefinedSymbol(crate::ast::Symbol(name.clone())))
+                Err(RuntimeError::undefined_symbol(
+                    crate::ast::Symbol::new(name.as_str()),
+                    &env.symbol_names(),
+                ))
             }
             IrNode::VariableDef {
                 name,
@@ -447,8 +512,16 @@ impl IrRuntime {
             IrNode::Apply {
                 function,
                 arguments,
+                source_location,
                 ..
-            } => self.execute_call(function, arguments, env, is_tail_call, module_registry),
+            } => {
+                let result =
+                    self.execute_call(function, arguments, env, is_tail_call, module_registry);
+                if let (Err(error), Some(location)) = (&result, source_location) {
+                    self.record_error_location(error, location);
+                }
+                result
+            }
             IrNode::QualifiedSymbolRef { module, symbol, .. } => {
                 let qualified_name = format!("{}/{}", module, symbol);
                 Ok(ExecutionOutcome::Complete(
@@ -537,6 +610,10 @@ impl IrRuntime {
                 Ok(ExecutionOutcome::Complete(result))
             }
             IrNode::Let { bindings, body, .. } => {
+                // Bindings live in their own scope so they shadow, rather than
+                // overwrite, symbols of the enclosing environment
+                let mut let_env = IrEnvironment::with_parent(Arc::new(env.clone()));
+                let env = &mut let_env;
                 // Two-pass letrec logic: first insert placeholders for function bindings
                 let mut placeholders = Vec::new();
                 // First pass: create placeholders for all function bindings
@@ -558,43 +635,43 @@ impl IrRuntime {
                         }
                     }
                 }
-                // Second pass: evaluate all function bindings and update placeholders
-                for (name, lambda_node, placeholder_cell, type_annotation) in &placeholders {
-                    match self.execute_node(lambda_node, env, false, module_registry)? {
-                        ExecutionOutcome::Complete(value) => {
-                            if matches!(value, Value::Function(_)) {
-                                if let Some(t) = type_annotation {
-                                    self.validate_value_type(&value, t, &format!("let {}", name))?;
-                                }
-                                let mut guard = placeholder_cell.write().map_err(|e| {
-                                    RuntimeError::InternalError(format!("RwLock poisoned: {}", e))
-                                })?;
-                                *guard = value;
-                            } else {
-                                return Err(RuntimeError::Generic(format!(
-                                    "letrec: expected function for {}",
-                                    name
-                                )));
-                            }
-                        }
-                        ExecutionOutcome::RequiresHost(host_call) => {
-                            return Ok(ExecutionOutcome::RequiresHost(host_call))
-                        }
-                        #[cfg(feature = "effect-boundary")]
-                        ExecutionOutcome::RequiresHost(host_call) => {
-                            return Ok(ExecutionOutcome::RequiresHost(host_call))
-                        }
-                        #[cfg(feature = "effect-boundary")]
-                        ExecutionOutcome::RequiresHost(host_call) => {
-                            return Ok(ExecutionOutcome::RequiresHost(host_call))
-                        }
-                    }
-                }
-                // Now handle non-function bindings as usual
+                // Second pass: evaluate bindings in order, filling function placeholders
                 for binding in bindings {
                     match &binding.pattern {
                         IrNode::VariableBinding { name, .. } => {
-                            if !placeholders.iter().any(|(n, _, _, _)| n == name) {
+                            if let Some((name, lambda_node, placeholder_cell, type_annotation)) =
+                                placeholders.iter().find(|(n, _, _, _)| n == name)
+                            {
+                            match self.execute_node(lambda_node, env, false, module_registry)? {
+                                ExecutionOutcome::Complete(value) => {
+                                    if matches!(value, Value::Function(_)) {
+                                        if let Some(t) = type_annotation {
+                                            self.validate_value_type(&value, t, &format!("let {}", name))?;
+                                        }
+                                        let mut guard = placeholder_cell.write().map_err(|e| {
+                                            RuntimeError::InternalError(format!("RwLock poisoned: {}", e))
+                                        })?;
+                                        *guard = value;
+                                    } else {
+                                        return Err(RuntimeError::Generic(format!(
+                                            "letrec: expected function for {}",
+                                            name
+                                        )));
+                                    }
+                                }
+                                ExecutionOutcome::RequiresHost(host_call) => {
+                                    return Ok(ExecutionOutcome::RequiresHost(host_call))
+                                }
+                                #[cfg(feature = "effect-boundary")]
+                                ExecutionOutcome::RequiresHost(host_call) => {
+                                    return Ok(ExecutionOutcome::RequiresHost(host_call))
+                                }
+                                #[cfg(feature = "effect-boundary")]
+                                ExecutionOutcome::RequiresHost(host_call) => {
+                                    return Ok(ExecutionOutcome::RequiresHost(host_call))
+                                }
+                            }
+                            } else {
                                 match self.execute_node(
                                     &binding.init_expr,
                                     env,
@@ -721,7 +798,7 @@ impl IrRuntime {
                         }
                     }
                 }
-                Ok(ExecutionOutcome::Complete(Value::Vector(values)))
+                Ok(ExecutionOutcome::Complete(Value::Vector(values.into())))
             }
             IrNode::Map { entries, .. } => {
                 let mut map = HashMap::new();
@@ -764,7 +841,7 @@ impl IrRuntime {
                     };
                     map.insert(map_key, value);
                 }
-                Ok(ExecutionOutcome::Complete(Value::Map(map)))
+                Ok(ExecutionOutcome::Complete(Value::Map(map.into())))
             }
             IrNode::Match {
                 expression,
@@ -877,6 +954,8 @@ impl IrRuntime {
 
                             // If pattern matches, bind and execute catch body
                             if self.pattern_matches(&clause.error_pattern, &error_value)? {
+                                // The error is handled here, so its recorded location is stale
+                                self.error_location = None;
                                 // Bind destructured pattern variables (if any)
                                 let _ = self.execute_destructure(
                                     &clause.error_pattern,
@@ -1006,7 +1085,7 @@ impl IrRuntime {
                     match self.execute_node(&binding.init_expr, env, false, module_registry)? {
                         ExecutionOutcome::Complete(value) => {
                             if let IrNode::VariableBinding { name, .. } = &binding.binding {
-                                results.insert(MapKey::Keyword(Keyword(name.clone())), value);
+                                results.insert(MapKey::Keyword(Keyword::new(name.as_str())), value);
                             }
                         }
                         ExecutionOutcome::RequiresHost(host_call) => {
@@ -1022,7 +1101,7 @@ impl IrRuntime {
                         }
                     }
                 }
-                Ok(ExecutionOutcome::Complete(Value::Map(results)))
+                Ok(ExecutionOutcome::Complete(Value::Map(results.into())))
             }
             IrNode::WithResource {
                 init_expr, body, ..
@@ -1224,7 +1303,7 @@ impl IrRuntime {
 
                 // Evaluate params (if provided) after entering the step and notifying the host.
                 // Params must be evaluated in the parent environment; failure should notify host and exit step.
-                let mut param_map: Option<HashMap<crate::ast::MapKey, Value>> = None;
+                let mut param_map: Option<ValueMap> = None;
                 if let Some(params_node) = params {
                     let params_ir = params_node.as_ref();
                     // Expect params_ir to be an IrNode::Map
@@ -1276,7 +1355,7 @@ impl IrRuntime {
                                     }
                                 }
                             }
-                            param_map = Some(map);
+                            param_map = Some(map.into());
                         }
                         other => {
                             let msg =
@@ -1300,7 +1379,7 @@ impl IrRuntime {
                             ast_map.insert(k.clone(), v.clone());
                         }
                     }
-                    c.define("%params".to_string(), Value::Map(ast_map));
+                    c.define("%params".to_string(), Value::Map(ast_map.into()));
                     child_env_opt = Some(c);
                 }
 
@@ -1361,7 +1440,7 @@ impl IrRuntime {
                 // Execute criteria and return empty vector for now
                 match self.execute_node(criteria, env, false, module_registry)? {
                     ExecutionOutcome::Complete(_) => {
-                        Ok(ExecutionOutcome::Complete(Value::Vector(vec![])))
+                        Ok(ExecutionOutcome::Complete(Value::Vector(vec![].into())))
                     }
                     ExecutionOutcome::RequiresHost(host_call) => {
                         Ok(ExecutionOutcome::RequiresHost(host_call))
@@ -1633,7 +1712,7 @@ impl IrRuntime {
                             if let Some(v) = map.get(&map_key_kw) {
                                 Ok(ExecutionOutcome::Complete(v.clone()))
                             } else {
-                                let map_key_str = crate::ast::MapKey::String(keyword.0.clone());
+                                let map_key_str = crate::ast::MapKey::String(keyword.0.to_string());
                                 Ok(ExecutionOutcome::Complete(
                                     map.get(&map_key_str).cloned().unwrap_or(Value::Nil),
                                 ))
@@ -1653,7 +1732,7 @@ impl IrRuntime {
                             if let Some(v) = map.get(&map_key_kw) {
                                 Ok(ExecutionOutcome::Complete(v.clone()))
                             } else {
-                                let map_key_str = crate::ast::MapKey::String(keyword.0.clone());
+                                let map_key_str = crate::ast::MapKey::String(keyword.0.to_string());
                                 Ok(ExecutionOutcome::Complete(
                                     map.get(&map_key_str).cloned().unwrap_or(args[1].clone()),
                                 ))
@@ -1748,7 +1827,7 @@ impl IrRuntime {
         module_registry: &ModuleRegistry,
     ) -> Result<ExecutionOutcome, RuntimeError> {
         let collection_vec: Vec<Value> = match collection {
-            Value::Vector(v) | Value::List(v) => v.clone(),
+            Value::Vector(v) | Value::List(v) => v.iter().cloned().collect(),
             Value::String(s) => s.chars().map(|c| Value::String(c.to_string())).collect(),
             _ => {
                 return Err(RuntimeError::TypeError {
@@ -1831,7 +1910,7 @@ impl IrRuntime {
                 }
             }
         }
-        Ok(ExecutionOutcome::Complete(Value::Vector(result)))
+        Ok(ExecutionOutcome::Complete(Value::Vector(result.into())))
     }
 
     /// Execute an IR lambda with an explicit call trampoline to avoid Rust recursion.
@@ -1844,6 +1923,25 @@ impl IrRuntime {
         args: &[Value],
         env: &mut IrEnvironment,
         module_registry: &ModuleRegistry,
+    ) -> Result<ExecutionOutcome, RuntimeError> {
+        let mut failing_call = None;
+        let result = self.run_ir_lambda(ir_func, args, env, module_registry, &mut failing_call);
+        if let (Err(error), Some(location)) = (&result, &failing_call) {
+            self.record_error_location(error, location);
+        }
+        result
+    }
+
+    /// Trampoline behind [`Self::apply_ir_lambda`]. `failing_call` holds the source
+    /// location of the call being applied in the current step, so it still names
+    /// the failing call when an error escapes.
+    fn run_ir_lambda(
+        &mut self,
+        ir_func: &Arc<crate::runtime::values::IrLambda>,
+        args: &[Value],
+        env: &mut IrEnvironment,
+        module_registry: &ModuleRegistry,
+        failing_call: &mut Option<SourceLocation>,
     ) -> Result<ExecutionOutcome, RuntimeError> {
         // Guard initial entry only (the trampoline keeps depth constant across nested calls)
         if self.recursion_depth >= self.max_recursion_depth {
@@ -1909,22 +2007,20 @@ impl IrRuntime {
         };
 
         let fixed_arity = param_names.len();
-        if variadic_name.is_some() {
-            if args.len() < fixed_arity {
-                self.recursion_depth -= 1;
-                return Err(RuntimeError::ArityMismatch {
-                    function: "ir-lambda".to_string(),
-                    expected: format!("at least {}", fixed_arity),
-                    actual: args.len(),
-                });
-            }
-        } else if fixed_arity != args.len() {
+        let arity_ok = if variadic_name.is_some() {
+            args.len() >= fixed_arity
+        } else {
+            args.len() == fixed_arity
+        };
+        if !arity_ok {
             self.recursion_depth -= 1;
-            return Err(RuntimeError::ArityMismatch {
-                function: "ir-lambda".to_string(),
-                expected: fixed_arity.to_string(),
-                actual: args.len(),
-            });
+            return Err(ir_lambda_arity_error(
+                ir_func,
+                env,
+                fixed_arity,
+                variadic_name.is_some(),
+                args.len(),
+            ));
         }
 
         // Validate argument types against IR annotations (if present)
@@ -1951,9 +2047,9 @@ impl IrRuntime {
         if let Some(var_name) = variadic_name {
             if args.len() > fixed_arity {
                 let rest_args = args[fixed_arity..].to_vec();
-                initial_env.define(var_name, Value::List(rest_args));
+                initial_env.define(var_name, Value::List(rest_args.into()));
             } else {
-                initial_env.define(var_name, Value::List(Vec::new()));
+                initial_env.define(var_name, Value::List(Vec::new().into()));
             }
         }
 
@@ -1987,6 +2083,7 @@ impl IrRuntime {
                 callee_val: Option<Value>,
                 arg_vals: Vec<Value>,
                 is_tail: bool,
+                location: Option<SourceLocation>,
             },
             // Special marker to place a computed value back to parent (no-op container)
             ValueMarker,
@@ -2024,6 +2121,7 @@ impl IrRuntime {
 
         // Main trampoline loop
         while let Some(frame) = call_stack.last_mut() {
+            *failing_call = None;
             let state_opt = frame.states.pop();
             let mut state = match state_opt {
                 Some(s) => s,
@@ -2060,7 +2158,10 @@ impl IrRuntime {
                         }
                         IrNode::VariableRef { name, .. } => {
                             let v = frame.env.get(&name).ok_or_else(|| {
-                                RuntimeError::UndefinedSymbol(crate::ast::Symbol(name.clone()))
+                                RuntimeError::undefined_symbol(
+                                    crate::ast::Symbol::new(name.as_str()),
+                                    &frame.env.symbol_names(),
+                                )
                             })?;
                             value_stack.push(v);
                         }
@@ -2088,6 +2189,7 @@ impl IrRuntime {
                         IrNode::Apply {
                             function,
                             arguments,
+                            source_location,
                             ..
                         } => {
                             frame.states.push(EvalState::Apply {
@@ -2097,6 +2199,7 @@ impl IrRuntime {
                                 callee_val: None,
                                 arg_vals: Vec::new(),
                                 is_tail,
+                                location: source_location,
                             });
                         }
                         // Fallback to recursive executor for other forms
@@ -2199,6 +2302,7 @@ impl IrRuntime {
                     ref mut callee_val,
                     ref mut arg_vals,
                     is_tail,
+                    location,
                 } => {
                     match *stage {
                         0 => {
@@ -2211,6 +2315,7 @@ impl IrRuntime {
                                 callee_val: None,
                                 arg_vals: Vec::new(),
                                 is_tail,
+                                location: location.clone(),
                             });
                             frame.states.push(EvalState::Node {
                                 node: (*callee_node).clone(),
@@ -2231,6 +2336,7 @@ impl IrRuntime {
                                     callee_val: callee_val.clone(),
                                     arg_vals: arg_vals.clone(),
                                     is_tail,
+                                    location: location.clone(),
                                 });
                             } else {
                                 // Evaluate first arg
@@ -2241,6 +2347,7 @@ impl IrRuntime {
                                     callee_val: callee_val.clone(),
                                     arg_vals: arg_vals.clone(),
                                     is_tail,
+                                    location: location.clone(),
                                 });
                                 let first =
                                     arg_nodes
@@ -2273,6 +2380,7 @@ impl IrRuntime {
                                     callee_val: callee_val.clone(),
                                     arg_vals: arg_vals.clone(),
                                     is_tail,
+                                    location: location.clone(),
                                 });
                                 let node = arg_nodes.get(next_idx).cloned().unwrap_or_else(|| {
                                     IrNode::Literal {
@@ -2295,11 +2403,13 @@ impl IrRuntime {
                                     callee_val: callee_val.clone(),
                                     arg_vals: arg_vals.clone(),
                                     is_tail,
+                                    location: location.clone(),
                                 });
                             }
                         }
                         4 => {
                             // Perform application
+                            *failing_call = location.clone();
                             let fval = callee_val.clone().expect("callee_val must be set");
                             // Dispatch
                             match fval.clone() {
@@ -2319,6 +2429,7 @@ impl IrRuntime {
                                         callee_val: Some(actual),
                                         arg_vals: arg_vals.clone(),
                                         is_tail,
+                                        location: location.clone(),
                                     });
                                     continue;
                                 }
@@ -2377,22 +2488,20 @@ impl IrRuntime {
                                         None => None,
                                     };
                                     let next_fixed = next_param_names.len();
-                                    if next_variadic.is_some() {
-                                        if arg_vals.len() < next_fixed {
-                                            self.recursion_depth -= 1;
-                                            return Err(RuntimeError::ArityMismatch {
-                                                function: "ir-lambda".to_string(),
-                                                expected: format!("at least {}", next_fixed),
-                                                actual: arg_vals.len(),
-                                            });
-                                        }
-                                    } else if next_fixed != arg_vals.len() {
+                                    let next_arity_ok = if next_variadic.is_some() {
+                                        arg_vals.len() >= next_fixed
+                                    } else {
+                                        arg_vals.len() == next_fixed
+                                    };
+                                    if !next_arity_ok {
                                         self.recursion_depth -= 1;
-                                        return Err(RuntimeError::ArityMismatch {
-                                            function: "ir-lambda".to_string(),
-                                            expected: next_fixed.to_string(),
-                                            actual: arg_vals.len(),
-                                        });
+                                        return Err(ir_lambda_arity_error(
+                                            &next_ir,
+                                            &frame.env,
+                                            next_fixed,
+                                            next_variadic.is_some(),
+                                            arg_vals.len(),
+                                        ));
                                     }
 
                                     // Validate argument types for nested IR lambda if annotations exist
@@ -2451,9 +2560,9 @@ impl IrRuntime {
                                     if let Some(var_name) = next_variadic {
                                         if arg_vals.len() > next_fixed {
                                             let rest = arg_vals[next_fixed..].to_vec();
-                                            new_env.define(var_name, Value::List(rest));
+                                            new_env.define(var_name, Value::List(rest.into()));
                                         } else {
-                                            new_env.define(var_name, Value::List(Vec::new()));
+                                            new_env.define(var_name, Value::List(Vec::new().into()));
                                         }
                                     }
                                     // Execute callee body: TCO if tail position
@@ -2535,6 +2644,7 @@ impl IrRuntime {
             "every?" => self.ir_every_with_context(args, env, module_registry),
             "some?" => self.ir_some_with_context(args, env, module_registry),
             "sort-by" => self.ir_sort_by_with_context(args, env, module_registry),
+            "apply" => self.ir_apply_with_context(args, env, module_registry),
             "context/get" => {
                 if args.len() != 1 {
                     return Err(RuntimeError::ArityMismatch {
@@ -2546,8 +2656,8 @@ impl IrRuntime {
 
                 let key = match &args[0] {
                     Value::String(s) => s.clone(),
-                    Value::Keyword(k) => k.0.clone(),
-                    Value::Symbol(sym) => sym.0.clone(),
+                    Value::Keyword(k) => k.0.to_string(),
+                    Value::Symbol(sym) => sym.0.to_string(),
                     other => {
                         return Err(RuntimeError::TypeError {
                             expected: "string, keyword, or symbol".to_string(),
@@ -2573,8 +2683,8 @@ impl IrRuntime {
 
                 let key = match &args[0] {
                     Value::String(s) => s.clone(),
-                    Value::Keyword(k) => k.0.clone(),
-                    Value::Symbol(sym) => sym.0.clone(),
+                    Value::Keyword(k) => k.0.to_string(),
+                    Value::Symbol(sym) => sym.0.to_string(),
                     other => {
                         return Err(RuntimeError::TypeError {
                             expected: "string, keyword, or symbol".to_string(),
@@ -2601,7 +2711,7 @@ impl IrRuntime {
                 // Accept either keyword or string capability id
                 let capability_id = match &args[0] {
                     Value::Keyword(kw) => kw.0.clone(),
-                    Value::String(s) => s.clone(),
+                    Value::String(s) => s.as_str().into(),
                     _ => {
                         return Err(RuntimeError::TypeError {
                             expected: "keyword or string".to_string(),
@@ -2868,9 +2978,9 @@ impl IrRuntime {
                             result.push(Value::Vector(vec![
                                 Value::Integer(index as i64),
                                 element.clone(),
-                            ]));
+                            ].into()));
                         }
-                        Ok(ExecutionOutcome::Complete(Value::Vector(result)))
+                        Ok(ExecutionOutcome::Complete(Value::Vector(result.into())))
                     }
                     Value::String(s) => {
                         // For IR tests, create indexed character mapping
@@ -2879,9 +2989,9 @@ impl IrRuntime {
                             result.push(Value::Vector(vec![
                                 Value::Integer(index as i64),
                                 Value::String(ch.to_string()),
-                            ]));
+                            ].into()));
                         }
-                        Ok(ExecutionOutcome::Complete(Value::Vector(result)))
+                        Ok(ExecutionOutcome::Complete(Value::Vector(result.into())))
                     }
                     Value::List(list) => {
                         // For IR tests, create indexed list mapping
@@ -2890,9 +3000,9 @@ impl IrRuntime {
                             result.push(Value::Vector(vec![
                                 Value::Integer(index as i64),
                                 element.clone(),
-                            ]));
+                            ].into()));
                         }
-                        Ok(ExecutionOutcome::Complete(Value::List(result)))
+                        Ok(ExecutionOutcome::Complete(Value::List(result.into())))
                     }
                     other => {
                         return Err(RuntimeError::TypeError {
@@ -2932,7 +3042,7 @@ impl IrRuntime {
         let collection = &args[1];
 
         let collection_vec: Vec<Value> = match collection {
-            Value::Vector(v) | Value::List(v) => v.clone(),
+            Value::Vector(v) | Value::List(v) => v.iter().cloned().collect(),
             Value::String(s) => s.chars().map(|c| Value::String(c.to_string())).collect(),
             _ => {
                 return Err(RuntimeError::TypeError {
@@ -3005,7 +3115,7 @@ impl IrRuntime {
             };
             result.push(mapped_value);
         }
-        Ok(ExecutionOutcome::Complete(Value::Vector(result)))
+        Ok(ExecutionOutcome::Complete(Value::Vector(result.into())))
     }
 
     /// IR runtime implementation of filter with context
@@ -3101,7 +3211,7 @@ impl IrRuntime {
                 result.push(item);
             }
         }
-        Ok(ExecutionOutcome::Complete(Value::Vector(result)))
+        Ok(ExecutionOutcome::Complete(Value::Vector(result.into())))
     }
 
     /// IR runtime implementation of reduce with context
@@ -3129,9 +3239,9 @@ impl IrRuntime {
 
         // Accept vector, string (as chars), and list to mirror stdlib behavior
         let collection_vec: Vec<Value> = match collection {
-            Value::Vector(v) => v.clone(),
+            Value::Vector(v) => v.iter().cloned().collect(),
             Value::String(s) => s.chars().map(|c| Value::String(c.to_string())).collect(),
-            Value::List(list) => list.clone(),
+            Value::List(list) => list.iter().cloned().collect(),
             _ => {
                 return Err(RuntimeError::TypeError {
                     expected: "vector, string, or list".to_string(),
@@ -3717,7 +3827,7 @@ impl IrRuntime {
                             .skip(elements.len())
                             .cloned()
                             .collect::<Vec<_>>();
-                        env.define(rest_name.clone(), Value::Vector(rest_elements));
+                        env.define(rest_name.clone(), Value::Vector(rest_elements.into()));
                     }
                     Ok(())
                 } else {
@@ -3750,7 +3860,7 @@ impl IrRuntime {
                                 rest_map.insert(key.clone(), val.clone());
                             }
                         }
-                        env.define(rest_name.clone(), Value::Map(rest_map));
+                        env.define(rest_name.clone(), Value::Map(rest_map.into()));
                     }
                     Ok(())
                 } else {
@@ -3768,6 +3878,31 @@ impl IrRuntime {
         }
     }
 
+    /// IR runtime implementation of apply: `(apply f a b [c d])` calls `f` with
+    /// the leading args followed by the elements of the final collection.
+    fn ir_apply_with_context(
+        &mut self,
+        args: Vec<Value>,
+        env: &mut IrEnvironment,
+        module_registry: &ModuleRegistry,
+    ) -> Result<ExecutionOutcome, RuntimeError> {
+        if args.len() < 2 {
+            return Err(RuntimeError::ArityMismatch {
+                function: "apply".to_string(),
+                expected: "2+".to_string(),
+                actual: args.len(),
+            });
+        }
+
+        let mut args = args;
+        let function = args.remove(0);
+        let last = args.pop().unwrap_or(Value::Nil);
+        crate::runtime::secure_stdlib::SecureStandardLibrary::expand_apply_collection(
+            &mut args, last,
+        )?;
+        self.apply_function(function, &args, env, false, module_registry)
+    }
+
     /// IR runtime implementation of sort-by with context
     fn ir_sort_by_with_context(
         &mut self,
@@ -3825,7 +3960,11 @@ impl IrRuntime {
             pairs.push((element, key));
         }
 
-        // Sort by key
+        // Sort by key (stable: equal keys keep input order)
+        crate::runtime::secure_stdlib::SecureStandardLibrary::check_sortable(
+            pairs.iter().map(|(_, key)| key),
+            "sort-by",
+        )?;
         pairs.sort_by(|a, b| a.1.compare(&b.1));
 
         // Extract sorted elements
@@ -3833,9 +3972,9 @@ impl IrRuntime {
 
         // Return the same type as the input collection
         match collection {
-            Value::Vector(_) => Ok(ExecutionOutcome::Complete(Value::Vector(result))),
-            Value::String(_) => Ok(ExecutionOutcome::Complete(Value::Vector(result))),
-            Value::List(_) => Ok(ExecutionOutcome::Complete(Value::List(result))),
+            Value::Vector(_) => Ok(ExecutionOutcome::Complete(Value::Vector(result.into()))),
+            Value::String(_) => Ok(ExecutionOutcome::Complete(Value::Vector(result.into()))),
+            Value::List(_) => Ok(ExecutionOutcome::Complete(Value::List(result.into()))),
             _ => unreachable!(),
         }
     }
@@ -3929,6 +4068,10 @@ impl IrRuntime {
             "serialize-json",
             "type-name",
             "getMessage",
+            "error-type",
+            "throw",
+            "ex-info",
+            "ex-data",
             "Exception.",
             "even?",
             "odd?",
@@ -3963,7 +4106,7 @@ impl IrRuntime {
         let env = StandardLibrary::create_global_environment();
 
         // Look up the function in the fresh environment
-        if let Some(function_value) = env.lookup(&Symbol(fn_symbol.to_string())) {
+        if let Some(function_value) = env.lookup(&Symbol(fn_symbol.into())) {
             // Check if it's a builtin function
             if let Value::Function(Function::Builtin(builtin_fn)) = function_value {
                 // Execute the builtin function
@@ -3987,11 +4130,18 @@ impl IrRuntime {
     /// Apply a closure by setting up environment and executing body
     fn apply_closure(
         &mut self,
-        closure: &crate::runtime::values::Closure,
+        closure: &Arc<crate::runtime::values::Closure>,
         args: &[Value],
         env: &mut IrEnvironment,
         module_registry: &ModuleRegistry,
     ) -> Result<ExecutionOutcome, RuntimeError> {
+        let closure_value = Value::Function(Function::Closure(closure.clone()));
+        closure.check_arity(
+            env.find_function_name(&closure_value)
+                .unwrap_or("anonymous fn"),
+            args.len(),
+        )?;
+
         // Recursion guard
         if self.recursion_depth >= self.max_recursion_depth {
             return Err(RuntimeError::Generic(format!(
@@ -4011,15 +4161,6 @@ impl IrRuntime {
             // This closure has a variadic parameter
             let required_param_count = closure.param_patterns.len();
 
-            // Check minimum argument count for required parameters
-            if args.len() < required_param_count {
-                return Err(RuntimeError::ArityMismatch {
-                    function: "user-defined function".to_string(),
-                    expected: format!("at least {}", required_param_count),
-                    actual: args.len(),
-                });
-            }
-
             // Validate required parameters against annotations if present
             if closure.param_type_annotations.len() == closure.param_patterns.len() {
                 for i in 0..required_param_count {
@@ -4065,16 +4206,9 @@ impl IrRuntime {
                         })?;
                 }
             }
-            func_env.define(variadic_symbol.0.clone(), Value::List(rest_args));
+            func_env.define(variadic_symbol.0.to_string(), Value::List(rest_args.into()));
         } else if !closure.param_patterns.is_empty() {
             // Normal parameter binding for non-variadic functions
-            if closure.param_patterns.len() != args.len() {
-                return Err(RuntimeError::ArityMismatch {
-                    function: "user-defined function".to_string(),
-                    expected: closure.param_patterns.len().to_string(),
-                    actual: args.len(),
-                });
-            }
             // Validate parameters against annotations if present
             if closure.param_type_annotations.len() == closure.param_patterns.len() {
                 for (i, arg) in args.iter().enumerate() {
@@ -4137,7 +4271,9 @@ impl IrRuntime {
     ) -> Result<ExecutionOutcome, RuntimeError> {
         // For now, we'll handle simple expressions that can be evaluated directly
         match body {
-            crate::ast::Expression::FunctionCall { callee, arguments } => {
+            crate::ast::Expression::FunctionCall {
+                callee, arguments, ..
+            } => {
                 // Handle function calls within the closure
                 if self.recursion_depth < 64 {
                     if let crate::ast::Expression::Symbol(sym) = &**callee {
@@ -4190,7 +4326,7 @@ impl IrRuntime {
                 if let Some(value) = self.get_with_cross_plan_fallback(symbol.0.as_str()) {
                     return Ok(ExecutionOutcome::Complete(value));
                 }
-                Err(RuntimeError::UndefinedSymbol(symbol.clone()))
+                Err(RuntimeError::undefined_symbol(symbol.clone(), &env.symbol_names()))
             }
             crate::ast::Expression::Literal(literal) => {
                 // Literal value
@@ -4261,7 +4397,7 @@ impl IrRuntime {
         // Pre-bind symbols to support recursion (letrec semantics)
         for binding in &let_expr.bindings {
             if let crate::ast::Pattern::Symbol(sym) = &binding.pattern {
-                child_env.define(sym.0.clone(), Value::Nil);
+                child_env.define(sym.0.to_string(), Value::Nil);
             }
         }
 
@@ -4271,7 +4407,7 @@ impl IrRuntime {
                 self.evaluate_expression(&binding.value, &mut child_env, module_registry)?;
             match &binding.pattern {
                 crate::ast::Pattern::Symbol(sym) => {
-                    child_env.define(sym.0.clone(), value);
+                    child_env.define(sym.0.to_string(), value);
                 }
                 _ => {
                     return Err(RuntimeError::Generic(
@@ -4434,7 +4570,7 @@ impl IrRuntime {
         match function_result {
             ExecutionOutcome::Complete(function_value) => {
                 // Define the function in the environment
-                env.define(defn_expr.name.0.clone(), function_value);
+                env.define(defn_expr.name.0.to_string(), function_value);
                 Ok(ExecutionOutcome::Complete(Value::Nil))
             }
             ExecutionOutcome::RequiresHost(host_call) => {
@@ -4467,7 +4603,7 @@ impl IrRuntime {
     ) -> Result<(), RuntimeError> {
         match pattern {
             crate::ast::Pattern::Symbol(symbol) => {
-                env.define(symbol.0.clone(), value.clone());
+                env.define(symbol.0.to_string(), value.clone());
                 Ok(())
             }
             crate::ast::Pattern::Wildcard => {
@@ -4497,16 +4633,16 @@ impl IrRuntime {
                     // Handle rest parameter
                     if let Some(rest_symbol) = rest {
                         let rest_values = if vec.len() > elements.len() {
-                            vec[elements.len()..].to_vec()
+                            vec.skip(elements.len())
                         } else {
-                            Vec::new()
+                            Vec::new().into()
                         };
-                        env.define(rest_symbol.0.clone(), Value::Vector(rest_values));
+                        env.define(rest_symbol.0.to_string(), Value::Vector(rest_values));
                     }
 
                     // Handle as binding
                     if let Some(as_sym) = as_symbol {
-                        env.define(as_sym.0.clone(), value.clone());
+                        env.define(as_sym.0.to_string(), value.clone());
                     }
 
                     Ok(())
@@ -4538,12 +4674,12 @@ impl IrRuntime {
                         for (key, val) in map {
                             rest_map.insert(key.clone(), val.clone());
                         }
-                        env.define(rest_symbol.0.clone(), Value::Map(rest_map));
+                        env.define(rest_symbol.0.to_string(), Value::Map(rest_map.into()));
                     }
 
                     // Handle as binding
                     if let Some(as_sym) = as_symbol {
-                        env.define(as_sym.0.clone(), value.clone());
+                        env.define(as_sym.0.to_string(), value.clone());
                     }
 
                     Ok(())
@@ -4558,3 +4694,27 @@ impl IrRuntime {
         }
     }
 }
+
+/// Arity error for an IR lambda, named after the symbol it is bound to when
+/// one is visible from `env`.
+fn ir_lambda_arity_error(
+    ir_func: &Arc<crate::runtime::values::IrLambda>,
+    env: &IrEnvironment,
+    fixed_arity: usize,
+    variadic: bool,
+    actual: usize,
+) -> RuntimeError {
+    let lambda_value = Value::Function(Function::Ir(ir_func.clone()));
+    RuntimeError::ArityMismatch {
+        function: env
+            .find_function_name(&lambda_value)
+            .unwrap_or("anonymous fn")
+            .to_string(),
+        expected: if variadic {
+            format!("at least {}", fixed_arity)
+        } else {
+            fixed_arity.to_string()
+        },
+        actual,
+    }
+}
@@ -946,7 +946,7 @@ else:
         Value::String("value1".to_string()),
     );
     input_map.insert(MapKey::String("key2".to_string()), Value::Integer(42));
-    let input_val = Value::Map(input_map);
+    let input_val = Value::Map(input_map.into());
 
     let context = ExecutionContext {
         execution_id: "test-large-payload".to_string(),
@@ -1038,7 +1038,7 @@ else:
         Value::String("value1".to_string()),
     );
     input_map.insert(MapKey::String("key2".to_string()), Value::Integer(42));
-    let input_val = Value::Map(input_map);
+    let input_val = Value::Map(input_map.into());
 
     let context = ExecutionContext {
         execution_id: "test-fc-large-payload".to_string(),
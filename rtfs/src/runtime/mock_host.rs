@@ -0,0 +1,119 @@
+//! Mock host implementation for hermetic evaluator tests
+//!
+//! `MockHost` implements `HostInterface` with canned capability responses and
+//! records every capability call, so tests can exercise `(call ...)` without a
+//! CCOS marketplace. Any `Arc<dyn HostInterface>` can be handed to `Evaluator::new`.
+
+use crate::runtime::error::{RuntimeError, RuntimeResult};
+use crate::runtime::host_interface::HostInterface;
+use crate::runtime::values::Value;
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+/// A capability call recorded by `MockHost`
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecordedCall {
+    pub capability: String,
+    pub args: Vec<Value>,
+}
+
+/// A host that returns configured responses and records capability calls
+#[derive(Debug, Default)]
+pub struct MockHost {
+    responses: Mutex<HashMap<String, RuntimeResult<Value>>>,
+    calls: Mutex<Vec<RecordedCall>>,
+}
+
+impl MockHost {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Configure the value returned for `capability`
+    pub fn with_response(self, capability: &str, value: Value) -> Self {
+        self.set_response(capability, Ok(value));
+        self
+    }
+
+    /// Configure the error returned for `capability`
+    pub fn with_error(self, capability: &str, error: RuntimeError) -> Self {
+        self.set_response(capability, Err(error));
+        self
+    }
+
+    /// Configure (or replace) the result returned for `capability`
+    pub fn set_response(&self, capability: &str, result: RuntimeResult<Value>) {
+        if let Ok(mut responses) = self.responses.lock() {
+            responses.insert(capability.to_string(), result);
+        }
+    }
+
+    /// All capability calls received so far, in order
+    pub fn calls(&self) -> Vec<RecordedCall> {
+        self.calls.lock().map(|c| c.clone()).unwrap_or_default()
+    }
+
+    /// Calls received for a single capability, in order
+    pub fn calls_to(&self, capability: &str) -> Vec<RecordedCall> {
+        self.calls()
+            .into_iter()
+            .filter(|c| c.capability == capability)
+            .collect()
+    }
+}
+
+impl HostInterface for MockHost {
+    fn execute_capability(&self, name: &str, args: &[Value]) -> RuntimeResult<Value> {
+        if let Ok(mut calls) = self.calls.lock() {
+            calls.push(RecordedCall {
+                capability: name.to_string(),
+                args: args.to_vec(),
+            });
+        }
+
+        let responses = self
+            .responses
+            .lock()
+            .map_err(|_| RuntimeError::Generic("MockHost responses lock poisoned".to_string()))?;
+        responses.get(name).cloned().unwrap_or_else(|| {
+            Err(RuntimeError::Generic(format!(
+                "MockHost has no response configured for capability '{}'",
+                name
+            )))
+        })
+    }
+
+    fn notify_step_started(&self, _step_name: &str) -> RuntimeResult<String> {
+        Ok("mock-step-action-id".to_string())
+    }
+
+    fn notify_step_completed(
+        &self,
+        _step_action_id: &str,
+        _result: &crate::runtime::stubs::ExecutionResultStruct,
+    ) -> RuntimeResult<()> {
+        Ok(())
+    }
+
+    fn notify_step_failed(&self, _step_action_id: &str, _error: &str) -> RuntimeResult<()> {
+        Ok(())
+    }
+
+    fn set_execution_context(
+        &self,
+        _plan_id: String,
+        _intent_ids: Vec<String>,
+        _parent_action_id: String,
+    ) {
+    }
+
+    fn clear_execution_context(&self) {}
+
+    fn set_step_exposure_override(&self, _expose: bool, _context_keys: Option<Vec<String>>) {}
+
+    fn clear_step_exposure_override(&self) {}
+
+    fn get_context_value(&self, _key: &str) -> Option<Value> {
+        None
+    }
+}
@@ -8,9 +8,11 @@ pub mod environment;
 pub mod error;
 pub mod evaluator;
 pub mod execution_outcome;
+pub mod forms;
 pub mod host_interface;
 pub mod ir_runtime;
 pub mod microvm;
+pub mod mock_host;
 pub mod module_runtime;
 pub mod param_binding;
 pub mod pure_host;
@@ -202,7 +204,10 @@ impl Runtime {
     }
 }
 
-/// Strategy that tries IR execution first, falls back to AST if IR fails
+/// Strategy that runs programs on the IR runtime and re-evaluates them with the
+/// AST evaluator when they use a construct the IR cannot lower yet
+/// ([`RuntimeError::UnsupportedInIr`]). That error is raised before anything
+/// executes, so no effect runs twice; any other IR error is returned as is.
 #[derive(Clone, Debug)]
 pub struct IrWithFallbackStrategy {
     ir_strategy: IrStrategy,
@@ -233,11 +238,14 @@ impl IrWithFallbackStrategy {
 impl RuntimeStrategy for IrWithFallbackStrategy {
     fn run(&mut self, program: &Expression) -> Result<ExecutionOutcome, RuntimeError> {
         match self.ir_strategy.run(program) {
-            Ok(result) => Ok(result),
-            Err(ir_error) => match self.ast_strategy.run(program) {
-                Ok(result) => Ok(result),
-                Err(_) => Err(ir_error),
-            },
+            Err(RuntimeError::UnsupportedInIr(construct)) => {
+                eprintln!(
+                    "[IR] '{}' is not supported by the IR runtime, falling back to the AST evaluator",
+                    construct
+                );
+                self.ast_strategy.run(program)
+            }
+            result => result,
         }
     }
 
@@ -499,7 +499,7 @@ impl ModuleRegistry {
 
         for definition in &module_def.definitions {
             if let crate::ast::ModuleLevelDefinition::Import(import_def) = definition {
-                let dep_module_name = import_def.module_name.0.clone();
+                let dep_module_name = import_def.module_name.0.to_string();
 
                 if !loaded_dependencies.contains_key(&dep_module_name) {
                     let loaded_dep_module = self.load_module(&dep_module_name, ir_runtime)?;
@@ -515,7 +515,7 @@ impl ModuleRegistry {
 
         for definition in &module_def.definitions {
             if let crate::ast::ModuleLevelDefinition::Import(import_def) = definition {
-                let dep_module_name = &import_def.module_name.0;
+                let dep_module_name = import_def.module_name.0.as_str();
                 let loaded_dep_module = loaded_dependencies.get(dep_module_name).unwrap();
 
                 // Import symbols into the ir_converter's scope
@@ -550,7 +550,7 @@ impl ModuleRegistry {
                     (None, Some(only_symbols)) => {
                         // Import specific symbols: (import [module :only [sym1 sym2]])
                         for symbol_ast in only_symbols {
-                            let export_name = &symbol_ast.0;
+                            let export_name = symbol_ast.0.as_str();
                             if let Some(export) = loaded_dep_module
                                 .exports
                                 .read()
@@ -567,12 +567,12 @@ impl ModuleRegistry {
                                     ExportType::Macro => BindingKind::Variable,
                                 };
                                 let binding_info = BindingInfo {
-                                    name: export_name.clone(),
+                                    name: export_name.to_string(),
                                     binding_id,
                                     ir_type: export.ir_type.clone(),
                                     kind: binding_kind,
                                 };
-                                ir_converter.define_binding(export_name.clone(), binding_info);
+                                ir_converter.define_binding(export_name.to_string(), binding_info);
                             } else {
                                 return Err(RuntimeError::ModuleError(format!(
                                     "Symbol '{}' not exported by module '{}'",
@@ -648,7 +648,7 @@ impl ModuleRegistry {
 
         // Get the list of exported symbol names from the AST
         let export_names = match &module_def.exports {
-            Some(symbols) => symbols.iter().map(|s| s.0.clone()).collect(),
+            Some(symbols) => symbols.iter().map(|s| s.0.to_string()).collect(),
             None => Vec::new(),
         };
 
@@ -13,16 +13,23 @@
 //! are delegated to CCOS capabilities and the host runtime.
 
 use crate::ast::Symbol;
-use crate::ast::{Expression, MapKey};
+use crate::ast::{Expression, Keyword, MapKey, MapTypeEntry, Span, TypeExpr};
 use crate::runtime::environment::Environment;
 use crate::runtime::error::{RuntimeError, RuntimeResult};
 use crate::runtime::evaluator::Evaluator;
 use crate::runtime::execution_outcome::ExecutionOutcome;
-use crate::runtime::values::Value;
+use crate::runtime::type_validator::TypeValidator;
 use crate::runtime::values::{Arity, BuiltinFunction, BuiltinFunctionWithContext, Function};
+use crate::runtime::values::{Value, ValueMap, ValueVector};
+use std::borrow::Cow;
 use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
 use std::sync::Arc;
 
+/// Largest collection `range` and `repeat` may build, so a plan cannot exhaust
+/// memory with a single call.
+pub const MAX_GENERATED_COLLECTION_LEN: usize = 1_000_000;
+
 /// Secure Standard Library - contains only pure, no-effect functions
 ///
 /// All functions are guaranteed to be:
@@ -31,6 +38,42 @@ use std::sync::Arc;
 /// - Safe: can execute in any security context
 pub struct SecureStandardLibrary;
 
+/// A value used as a hash key when counting element frequencies. Equality is
+/// `Value`'s own; the hash only covers the variant and scalar contents (or the
+/// length of a collection), which equal values always share. Interior-mutable
+/// values such as atoms hash by variant alone, so the hash cannot change while
+/// the key is in a map.
+struct FrequencyKey<'a>(&'a Value);
+
+impl PartialEq for FrequencyKey<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl Eq for FrequencyKey<'_> {}
+
+impl Hash for FrequencyKey<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self.0).hash(state);
+        match self.0 {
+            Value::Boolean(b) => b.hash(state),
+            Value::Integer(i) => i.hash(state),
+            // 0.0 and -0.0 are equal, so they must hash alike
+            Value::Float(f) if *f == 0.0 => 0u64.hash(state),
+            Value::Float(f) => f.to_bits().hash(state),
+            Value::String(s) | Value::Timestamp(s) | Value::Uuid(s) | Value::ResourceHandle(s) => {
+                s.hash(state)
+            }
+            Value::Keyword(k) => k.0.hash(state),
+            Value::Symbol(s) => s.0.hash(state),
+            Value::Vector(items) | Value::List(items) => items.len().hash(state),
+            Value::Map(map) => map.len().hash(state),
+            _ => {}
+        }
+    }
+}
+
 impl SecureStandardLibrary {
     /// Create a secure global environment with only safe functions
     pub fn create_secure_environment() -> Environment {
@@ -50,7 +93,7 @@ impl SecureStandardLibrary {
     pub(crate) fn load_arithmetic_functions(env: &mut Environment) {
         // Arithmetic functions (safe, pure)
         env.define(
-            &Symbol("+".to_string()),
+            &Symbol("+".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "+".to_string(),
                 arity: Arity::Variadic(1),
@@ -59,7 +102,7 @@ impl SecureStandardLibrary {
         );
 
         env.define(
-            &Symbol("-".to_string()),
+            &Symbol("-".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "-".to_string(),
                 arity: Arity::Variadic(1),
@@ -68,7 +111,7 @@ impl SecureStandardLibrary {
         );
 
         env.define(
-            &Symbol("*".to_string()),
+            &Symbol("*".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "*".to_string(),
                 arity: Arity::Variadic(1),
@@ -77,7 +120,7 @@ impl SecureStandardLibrary {
         );
 
         env.define(
-            &Symbol("/".to_string()),
+            &Symbol("/".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "/".to_string(),
                 arity: Arity::Variadic(1),
@@ -87,7 +130,7 @@ impl SecureStandardLibrary {
 
         // Max
         env.define(
-            &Symbol("max".to_string()),
+            &Symbol("max".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "max".to_string(),
                 arity: Arity::Variadic(1),
@@ -97,7 +140,7 @@ impl SecureStandardLibrary {
 
         // Min
         env.define(
-            &Symbol("min".to_string()),
+            &Symbol("min".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "min".to_string(),
                 arity: Arity::Variadic(1),
@@ -107,7 +150,7 @@ impl SecureStandardLibrary {
 
         // Increment function
         env.define(
-            &Symbol("inc".to_string()),
+            &Symbol("inc".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "inc".to_string(),
                 arity: Arity::Fixed(1),
@@ -118,7 +161,10 @@ impl SecureStandardLibrary {
                         ));
                     }
                     match &args[0] {
-                        Value::Integer(n) => Ok(Value::Integer(n + 1)),
+                        Value::Integer(n) => n
+                            .checked_add(1)
+                            .map(Value::Integer)
+                            .ok_or_else(|| Self::integer_overflow("inc")),
                         Value::Float(f) => Ok(Value::Float(f + 1.0)),
                         _ => Err(RuntimeError::Generic("inc expects a number".to_string())),
                     }
@@ -128,7 +174,7 @@ impl SecureStandardLibrary {
 
         // Decrement function
         env.define(
-            &Symbol("dec".to_string()),
+            &Symbol("dec".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "dec".to_string(),
                 arity: Arity::Fixed(1),
@@ -139,7 +185,10 @@ impl SecureStandardLibrary {
                         ));
                     }
                     match &args[0] {
-                        Value::Integer(n) => Ok(Value::Integer(n - 1)),
+                        Value::Integer(n) => n
+                            .checked_sub(1)
+                            .map(Value::Integer)
+                            .ok_or_else(|| Self::integer_overflow("dec")),
                         Value::Float(f) => Ok(Value::Float(f - 1.0)),
                         _ => Err(RuntimeError::Generic("dec expects a number".to_string())),
                     }
@@ -149,7 +198,7 @@ impl SecureStandardLibrary {
 
         // Factorial function
         env.define(
-            &Symbol("factorial".to_string()),
+            &Symbol("factorial".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "factorial".to_string(),
                 arity: Arity::Fixed(1),
@@ -159,7 +208,7 @@ impl SecureStandardLibrary {
 
         // Absolute value function
         env.define(
-            &Symbol("abs".to_string()),
+            &Symbol("abs".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "abs".to_string(),
                 arity: Arity::Fixed(1),
@@ -169,7 +218,7 @@ impl SecureStandardLibrary {
 
         // Modulo function
         env.define(
-            &Symbol("mod".to_string()),
+            &Symbol("mod".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "mod".to_string(),
                 arity: Arity::Fixed(2),
@@ -177,9 +226,19 @@ impl SecureStandardLibrary {
             })),
         );
 
+        // Truncated remainder function
+        env.define(
+            &Symbol("rem".into()),
+            Value::Function(Function::Builtin(BuiltinFunction {
+                name: "rem".to_string(),
+                arity: Arity::Fixed(2),
+                func: Arc::new(Self::rem),
+            })),
+        );
+
         // Square root function
         env.define(
-            &Symbol("sqrt".to_string()),
+            &Symbol("sqrt".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "sqrt".to_string(),
                 arity: Arity::Fixed(1),
@@ -189,7 +248,7 @@ impl SecureStandardLibrary {
 
         // Power function
         env.define(
-            &Symbol("pow".to_string()),
+            &Symbol("pow".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "pow".to_string(),
                 arity: Arity::Fixed(2),
@@ -197,10 +256,67 @@ impl SecureStandardLibrary {
             })),
         );
 
+        // Bound a number to [lo, hi]
+        env.define(
+            &Symbol("clamp".into()),
+            Value::Function(Function::Builtin(BuiltinFunction {
+                name: "clamp".to_string(),
+                arity: Arity::Fixed(3),
+                func: Arc::new(Self::clamp),
+            })),
+        );
+
+        // Linear interpolation between a and b
+        env.define(
+            &Symbol("lerp".into()),
+            Value::Function(Function::Builtin(BuiltinFunction {
+                name: "lerp".to_string(),
+                arity: Arity::Fixed(3),
+                func: Arc::new(Self::lerp),
+            })),
+        );
+
+        // Basic statistics over numeric collections
+        env.define(
+            &Symbol("mean".into()),
+            Value::Function(Function::Builtin(BuiltinFunction {
+                name: "mean".to_string(),
+                arity: Arity::Fixed(1),
+                func: Arc::new(Self::mean),
+            })),
+        );
+
+        env.define(
+            &Symbol("median".into()),
+            Value::Function(Function::Builtin(BuiltinFunction {
+                name: "median".to_string(),
+                arity: Arity::Fixed(1),
+                func: Arc::new(Self::median),
+            })),
+        );
+
+        env.define(
+            &Symbol("mode".into()),
+            Value::Function(Function::Builtin(BuiltinFunction {
+                name: "mode".to_string(),
+                arity: Arity::Fixed(1),
+                func: Arc::new(Self::mode),
+            })),
+        );
+
+        env.define(
+            &Symbol("std-dev".into()),
+            Value::Function(Function::Builtin(BuiltinFunction {
+                name: "std-dev".to_string(),
+                arity: Arity::Fixed(1),
+                func: Arc::new(Self::std_dev),
+            })),
+        );
+
         // Parse integer from string
         // (parse-int "123") -> 123, (parse-int "abc") -> nil
         env.define(
-            &Symbol("parse-int".to_string()),
+            &Symbol("parse-int".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "parse-int".to_string(),
                 arity: Arity::Fixed(1),
@@ -211,7 +327,7 @@ impl SecureStandardLibrary {
         // Parse float from string
         // (parse-float "3.14") -> 3.14, (parse-float "abc") -> nil
         env.define(
-            &Symbol("parse-float".to_string()),
+            &Symbol("parse-float".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "parse-float".to_string(),
                 arity: Arity::Fixed(1),
@@ -222,7 +338,7 @@ impl SecureStandardLibrary {
         // Coerce to integer
         // (int "42") -> 42, (int 3.7) -> 3, (int true) -> 1
         env.define(
-            &Symbol("int".to_string()),
+            &Symbol("int".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "int".to_string(),
                 arity: Arity::Fixed(1),
@@ -233,19 +349,54 @@ impl SecureStandardLibrary {
         // Coerce to float
         // (float "3.14") -> 3.14, (float 42) -> 42.0, (float true) -> 1.0
         env.define(
-            &Symbol("float".to_string()),
+            &Symbol("float".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "float".to_string(),
                 arity: Arity::Fixed(1),
                 func: Arc::new(Self::to_float),
             })),
         );
+
+        // Strict coercions for capability results: convert where lossless, error otherwise
+        // (as-int 42.0) -> 42, (as-float 3) -> 3.0, (as-string 3) -> "3", (as-vector [1 2]) -> [1 2]
+        env.define(
+            &Symbol("as-int".into()),
+            Value::Function(Function::Builtin(BuiltinFunction {
+                name: "as-int".to_string(),
+                arity: Arity::Fixed(1),
+                func: Arc::new(Self::as_int),
+            })),
+        );
+        env.define(
+            &Symbol("as-float".into()),
+            Value::Function(Function::Builtin(BuiltinFunction {
+                name: "as-float".to_string(),
+                arity: Arity::Fixed(1),
+                func: Arc::new(Self::as_float),
+            })),
+        );
+        env.define(
+            &Symbol("as-string".into()),
+            Value::Function(Function::Builtin(BuiltinFunction {
+                name: "as-string".to_string(),
+                arity: Arity::Fixed(1),
+                func: Arc::new(Self::as_string),
+            })),
+        );
+        env.define(
+            &Symbol("as-vector".into()),
+            Value::Function(Function::Builtin(BuiltinFunction {
+                name: "as-vector".to_string(),
+                arity: Arity::Fixed(1),
+                func: Arc::new(Self::as_vector),
+            })),
+        );
     }
 
     pub(crate) fn load_comparison_functions(env: &mut Environment) {
         // Comparison functions (safe, pure)
         env.define(
-            &Symbol("=".to_string()),
+            &Symbol("=".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "=".to_string(),
                 arity: Arity::Variadic(1),
@@ -254,7 +405,16 @@ impl SecureStandardLibrary {
         );
 
         env.define(
-            &Symbol("!=".to_string()),
+            &Symbol("seq=".into()),
+            Value::Function(Function::Builtin(BuiltinFunction {
+                name: "seq=".to_string(),
+                arity: Arity::Variadic(1),
+                func: Arc::new(Self::seq_equal),
+            })),
+        );
+
+        env.define(
+            &Symbol("!=".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "!=".to_string(),
                 arity: Arity::Fixed(2),
@@ -263,7 +423,7 @@ impl SecureStandardLibrary {
         );
 
         env.define(
-            &Symbol("not=".to_string()),
+            &Symbol("not=".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "not=".to_string(),
                 arity: Arity::Fixed(2),
@@ -272,7 +432,7 @@ impl SecureStandardLibrary {
         );
 
         env.define(
-            &Symbol(">".to_string()),
+            &Symbol(">".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: ">".to_string(),
                 arity: Arity::Fixed(2),
@@ -281,7 +441,7 @@ impl SecureStandardLibrary {
         );
 
         env.define(
-            &Symbol("<".to_string()),
+            &Symbol("<".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "<".to_string(),
                 arity: Arity::Fixed(2),
@@ -290,7 +450,7 @@ impl SecureStandardLibrary {
         );
 
         env.define(
-            &Symbol(">=".to_string()),
+            &Symbol(">=".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: ">=".to_string(),
                 arity: Arity::Fixed(2),
@@ -299,7 +459,7 @@ impl SecureStandardLibrary {
         );
 
         env.define(
-            &Symbol("<=".to_string()),
+            &Symbol("<=".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "<=".to_string(),
                 arity: Arity::Fixed(2),
@@ -311,7 +471,7 @@ impl SecureStandardLibrary {
     pub(crate) fn load_boolean_functions(env: &mut Environment) {
         // Boolean logic functions (safe, pure)
         env.define(
-            &Symbol("and".to_string()),
+            &Symbol("and".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "and".to_string(),
                 arity: Arity::Variadic(0),
@@ -320,7 +480,7 @@ impl SecureStandardLibrary {
         );
 
         env.define(
-            &Symbol("or".to_string()),
+            &Symbol("or".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "or".to_string(),
                 arity: Arity::Variadic(0),
@@ -329,7 +489,7 @@ impl SecureStandardLibrary {
         );
 
         env.define(
-            &Symbol("not".to_string()),
+            &Symbol("not".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "not".to_string(),
                 arity: Arity::Fixed(1),
@@ -341,7 +501,7 @@ impl SecureStandardLibrary {
     pub(crate) fn load_string_functions(env: &mut Environment) {
         // String functions (safe, pure)
         env.define(
-            &Symbol("str".to_string()),
+            &Symbol("str".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "str".to_string(),
                 arity: Arity::Variadic(0),
@@ -350,7 +510,7 @@ impl SecureStandardLibrary {
         );
 
         env.define(
-            &Symbol("string-length".to_string()),
+            &Symbol("string-length".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "string-length".to_string(),
                 arity: Arity::Fixed(1),
@@ -359,7 +519,7 @@ impl SecureStandardLibrary {
         );
 
         env.define(
-            &Symbol("substring".to_string()),
+            &Symbol("substring".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "substring".to_string(),
                 arity: Arity::Variadic(2),
@@ -368,7 +528,7 @@ impl SecureStandardLibrary {
         );
 
         env.define(
-            &Symbol("string-contains".to_string()),
+            &Symbol("string-contains".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "string-contains".to_string(),
                 arity: Arity::Fixed(2),
@@ -377,7 +537,7 @@ impl SecureStandardLibrary {
         );
 
         env.define(
-            &Symbol("starts-with?".to_string()),
+            &Symbol("starts-with?".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "starts-with?".to_string(),
                 arity: Arity::Fixed(2),
@@ -386,7 +546,7 @@ impl SecureStandardLibrary {
         );
 
         env.define(
-            &Symbol("split".to_string()),
+            &Symbol("split".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "split".to_string(),
                 arity: Arity::Fixed(2),
@@ -397,7 +557,7 @@ impl SecureStandardLibrary {
         // String join function
         // Signature: (join sep coll)
         env.define(
-            &Symbol("join".to_string()),
+            &Symbol("join".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "join".to_string(),
                 arity: Arity::Fixed(2),
@@ -407,7 +567,7 @@ impl SecureStandardLibrary {
 
         // Alias to make intent explicit in plans/prompts
         env.define(
-            &Symbol("string-join".to_string()),
+            &Symbol("string-join".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "string-join".to_string(),
                 arity: Arity::Fixed(2),
@@ -417,7 +577,7 @@ impl SecureStandardLibrary {
 
         // String upper case function
         env.define(
-            &Symbol("string-upper".to_string()),
+            &Symbol("string-upper".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "string-upper".to_string(),
                 arity: Arity::Fixed(1),
@@ -427,7 +587,7 @@ impl SecureStandardLibrary {
 
         // String lower case function
         env.define(
-            &Symbol("string-lower".to_string()),
+            &Symbol("string-lower".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "string-lower".to_string(),
                 arity: Arity::Fixed(1),
@@ -437,7 +597,7 @@ impl SecureStandardLibrary {
 
         // String trim function
         env.define(
-            &Symbol("string-trim".to_string()),
+            &Symbol("string-trim".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "string-trim".to_string(),
                 arity: Arity::Fixed(1),
@@ -445,11 +605,49 @@ impl SecureStandardLibrary {
             })),
         );
 
+        env.define(
+            &Symbol("trim-start".into()),
+            Value::Function(Function::Builtin(BuiltinFunction {
+                name: "trim-start".to_string(),
+                arity: Arity::Fixed(1),
+                func: Arc::new(Self::trim_start),
+            })),
+        );
+
+        env.define(
+            &Symbol("trim-end".into()),
+            Value::Function(Function::Builtin(BuiltinFunction {
+                name: "trim-end".to_string(),
+                arity: Arity::Fixed(1),
+                func: Arc::new(Self::trim_end),
+            })),
+        );
+
+        // Strip any of the given characters from both ends
+        env.define(
+            &Symbol("trim-chars".into()),
+            Value::Function(Function::Builtin(BuiltinFunction {
+                name: "trim-chars".to_string(),
+                arity: Arity::Fixed(2),
+                func: Arc::new(Self::trim_chars),
+            })),
+        );
+
+        // Split an in-memory string into lines (same semantics as read-lines)
+        env.define(
+            &Symbol("split-lines".into()),
+            Value::Function(Function::Builtin(BuiltinFunction {
+                name: "split-lines".to_string(),
+                arity: Arity::Fixed(1),
+                func: Arc::new(Self::split_lines),
+            })),
+        );
+
         // Regex functions (only available with regex feature)
         #[cfg(feature = "regex")]
         {
             env.define(
-                &Symbol("re-matches".to_string()),
+                &Symbol("re-matches".into()),
                 Value::Function(Function::Builtin(BuiltinFunction {
                     name: "re-matches".to_string(),
                     arity: Arity::Fixed(2),
@@ -458,7 +656,7 @@ impl SecureStandardLibrary {
             );
 
             env.define(
-                &Symbol("re-find".to_string()),
+                &Symbol("re-find".into()),
                 Value::Function(Function::Builtin(BuiltinFunction {
                     name: "re-find".to_string(),
                     arity: Arity::Fixed(2),
@@ -467,7 +665,7 @@ impl SecureStandardLibrary {
             );
 
             env.define(
-                &Symbol("re-seq".to_string()),
+                &Symbol("re-seq".into()),
                 Value::Function(Function::Builtin(BuiltinFunction {
                     name: "re-seq".to_string(),
                     arity: Arity::Fixed(2),
@@ -481,7 +679,7 @@ impl SecureStandardLibrary {
         // Collection functions (safe, pure)
         // Map function - now supports user-defined functions with evaluator context
         env.define(
-            &Symbol("map".to_string()),
+            &Symbol("map".into()),
             Value::Function(Function::BuiltinWithContext(BuiltinFunctionWithContext {
                 name: "map".to_string(),
                 arity: Arity::Fixed(2),
@@ -490,7 +688,7 @@ impl SecureStandardLibrary {
         );
 
         env.define(
-            &Symbol("apply".to_string()),
+            &Symbol("apply".into()),
             Value::Function(Function::BuiltinWithContext(BuiltinFunctionWithContext {
                 name: "apply".to_string(),
                 arity: Arity::Variadic(2),
@@ -500,7 +698,7 @@ impl SecureStandardLibrary {
 
         // Filter function - now supports user-defined functions with evaluator context
         env.define(
-            &Symbol("filter".to_string()),
+            &Symbol("filter".into()),
             Value::Function(Function::BuiltinWithContext(BuiltinFunctionWithContext {
                 name: "filter".to_string(),
                 arity: Arity::Fixed(2),
@@ -510,7 +708,7 @@ impl SecureStandardLibrary {
 
         // Reduce function - now supports user-defined functions with evaluator context
         env.define(
-            &Symbol("reduce".to_string()),
+            &Symbol("reduce".into()),
             Value::Function(Function::BuiltinWithContext(BuiltinFunctionWithContext {
                 name: "reduce".to_string(),
                 arity: Arity::Range(2, 3),
@@ -520,7 +718,7 @@ impl SecureStandardLibrary {
 
         // Contains? function - collection membership test
         env.define(
-            &Symbol("contains?".to_string()),
+            &Symbol("contains?".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "contains?".to_string(),
                 arity: Arity::Fixed(2),
@@ -530,7 +728,7 @@ impl SecureStandardLibrary {
 
         // Even? function - number parity test
         env.define(
-            &Symbol("even?".to_string()),
+            &Symbol("even?".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "even?".to_string(),
                 arity: Arity::Fixed(1),
@@ -540,7 +738,7 @@ impl SecureStandardLibrary {
 
         // Odd? function - number parity test
         env.define(
-            &Symbol("odd?".to_string()),
+            &Symbol("odd?".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "odd?".to_string(),
                 arity: Arity::Fixed(1),
@@ -548,19 +746,37 @@ impl SecureStandardLibrary {
             })),
         );
 
+        // Compare function - total order across all values
+        env.define(
+            &Symbol("compare".into()),
+            Value::Function(Function::Builtin(BuiltinFunction {
+                name: "compare".to_string(),
+                arity: Arity::Fixed(2),
+                func: Arc::new(Self::compare),
+            })),
+        );
+
         // Sort function - collection sorting
         env.define(
-            &Symbol("sort".to_string()),
+            &Symbol("sort".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "sort".to_string(),
                 arity: Arity::Fixed(1),
                 func: Arc::new(Self::sort),
             })),
         );
+        env.define(
+            &Symbol("sort-by".into()),
+            Value::Function(Function::BuiltinWithContext(BuiltinFunctionWithContext {
+                name: "sort-by".to_string(),
+                arity: Arity::Fixed(2),
+                func: Arc::new(Self::sort_by),
+            })),
+        );
 
         // Merge function - map merging
         env.define(
-            &Symbol("merge".to_string()),
+            &Symbol("merge".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "merge".to_string(),
                 arity: Arity::Variadic(1),
@@ -570,7 +786,7 @@ impl SecureStandardLibrary {
 
         // Empty predicate
         env.define(
-            &Symbol("empty?".to_string()),
+            &Symbol("empty?".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "empty?".to_string(),
                 arity: Arity::Fixed(1),
@@ -580,7 +796,7 @@ impl SecureStandardLibrary {
 
         // Cons function
         env.define(
-            &Symbol("cons".to_string()),
+            &Symbol("cons".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "cons".to_string(),
                 arity: Arity::Fixed(2),
@@ -589,7 +805,7 @@ impl SecureStandardLibrary {
         );
 
         env.define(
-            &Symbol("first".to_string()),
+            &Symbol("first".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "first".to_string(),
                 arity: Arity::Fixed(1),
@@ -598,7 +814,7 @@ impl SecureStandardLibrary {
         );
 
         env.define(
-            &Symbol("rest".to_string()),
+            &Symbol("rest".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "rest".to_string(),
                 arity: Arity::Fixed(1),
@@ -608,7 +824,7 @@ impl SecureStandardLibrary {
 
         // Get-in function
         env.define(
-            &Symbol("get-in".to_string()),
+            &Symbol("get-in".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "get-in".to_string(),
                 arity: Arity::Variadic(2),
@@ -618,7 +834,7 @@ impl SecureStandardLibrary {
 
         // Partition function
         env.define(
-            &Symbol("partition".to_string()),
+            &Symbol("partition".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "partition".to_string(),
                 arity: Arity::Fixed(2),
@@ -627,7 +843,7 @@ impl SecureStandardLibrary {
         );
 
         env.define(
-            &Symbol("get".to_string()),
+            &Symbol("get".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "get".to_string(),
                 arity: Arity::Variadic(2),
@@ -635,9 +851,39 @@ impl SecureStandardLibrary {
             })),
         );
 
+        // Lookup that accepts either the keyword or string form of a key (JSON-derived maps)
+        env.define(
+            &Symbol("get-flex".into()),
+            Value::Function(Function::Builtin(BuiltinFunction {
+                name: "get-flex".to_string(),
+                arity: Arity::Variadic(2),
+                func: Arc::new(Self::get_flex),
+            })),
+        );
+
+        // Key presence test that distinguishes a stored nil from a missing key
+        env.define(
+            &Symbol("contains-key?".into()),
+            Value::Function(Function::Builtin(BuiltinFunction {
+                name: "contains-key?".to_string(),
+                arity: Arity::Fixed(2),
+                func: Arc::new(Self::contains_key_p),
+            })),
+        );
+
+        // Get-or: (get-or m k sentinel) -> sentinel only when k is absent
+        env.define(
+            &Symbol("get-or".into()),
+            Value::Function(Function::Builtin(BuiltinFunction {
+                name: "get-or".to_string(),
+                arity: Arity::Fixed(3),
+                func: Arc::new(Self::get_or),
+            })),
+        );
+
         // Map find: (find m k) -> [k v] or nil
         env.define(
-            &Symbol("find".to_string()),
+            &Symbol("find".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "find".to_string(),
                 arity: Arity::Fixed(2),
@@ -669,7 +915,7 @@ impl SecureStandardLibrary {
                             MapKey::String(ref s) => Value::String(s.clone()),
                             MapKey::Integer(i) => Value::Integer(i),
                         };
-                        Ok(Value::Vector(vec![key_val, v.clone()]))
+                        Ok(Value::Vector(vec![key_val, v.clone()].into()))
                     } else {
                         Ok(Value::Nil)
                     }
@@ -679,7 +925,7 @@ impl SecureStandardLibrary {
 
         // Nth function - access element by index
         env.define(
-            &Symbol("nth".to_string()),
+            &Symbol("nth".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "nth".to_string(),
                 arity: Arity::Variadic(2),
@@ -689,7 +935,7 @@ impl SecureStandardLibrary {
 
         // Concat function - concatenate collections
         env.define(
-            &Symbol("concat".to_string()),
+            &Symbol("concat".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "concat".to_string(),
                 arity: Arity::Variadic(0),
@@ -699,7 +945,7 @@ impl SecureStandardLibrary {
 
         // Subvec function - get subvector
         env.define(
-            &Symbol("subvec".to_string()),
+            &Symbol("subvec".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "subvec".to_string(),
                 arity: Arity::Variadic(2),
@@ -708,7 +954,7 @@ impl SecureStandardLibrary {
         );
 
         env.define(
-            &Symbol("count".to_string()),
+            &Symbol("count".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "count".to_string(),
                 arity: Arity::Fixed(1),
@@ -717,7 +963,7 @@ impl SecureStandardLibrary {
         );
 
         env.define(
-            &Symbol("vector".to_string()),
+            &Symbol("vector".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "vector".to_string(),
                 arity: Arity::Variadic(0),
@@ -726,7 +972,7 @@ impl SecureStandardLibrary {
         );
 
         env.define(
-            &Symbol("hash-map".to_string()),
+            &Symbol("hash-map".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "hash-map".to_string(),
                 arity: Arity::Variadic(0),
@@ -735,7 +981,7 @@ impl SecureStandardLibrary {
         );
 
         env.define(
-            &Symbol("range".to_string()),
+            &Symbol("range".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "range".to_string(),
                 arity: Arity::Fixed(2),
@@ -743,9 +989,18 @@ impl SecureStandardLibrary {
             })),
         );
 
+        env.define(
+            &Symbol("repeat".into()),
+            Value::Function(Function::Builtin(BuiltinFunction {
+                name: "repeat".to_string(),
+                arity: Arity::Fixed(2),
+                func: Arc::new(Self::repeat),
+            })),
+        );
+
         // Length function
         env.define(
-            &Symbol("length".to_string()),
+            &Symbol("length".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "length".to_string(),
                 arity: Arity::Fixed(1),
@@ -755,7 +1010,7 @@ impl SecureStandardLibrary {
 
         // Type name function
         env.define(
-            &Symbol("type-name".to_string()),
+            &Symbol("type-name".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "type-name".to_string(),
                 arity: Arity::Fixed(1),
@@ -765,7 +1020,7 @@ impl SecureStandardLibrary {
 
         // Reverse function
         env.define(
-            &Symbol("reverse".to_string()),
+            &Symbol("reverse".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "reverse".to_string(),
                 arity: Arity::Fixed(1),
@@ -775,7 +1030,7 @@ impl SecureStandardLibrary {
 
         // Last function
         env.define(
-            &Symbol("last".to_string()),
+            &Symbol("last".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "last".to_string(),
                 arity: Arity::Fixed(1),
@@ -787,7 +1042,7 @@ impl SecureStandardLibrary {
 
         // Drop function
         env.define(
-            &Symbol("drop".to_string()),
+            &Symbol("drop".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "drop".to_string(),
                 arity: Arity::Fixed(2),
@@ -797,7 +1052,7 @@ impl SecureStandardLibrary {
 
         // Distinct function
         env.define(
-            &Symbol("distinct".to_string()),
+            &Symbol("distinct".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "distinct".to_string(),
                 arity: Arity::Fixed(1),
@@ -807,7 +1062,7 @@ impl SecureStandardLibrary {
 
         // Every? function
         env.define(
-            &Symbol("every?".to_string()),
+            &Symbol("every?".into()),
             Value::Function(Function::BuiltinWithContext(BuiltinFunctionWithContext {
                 name: "every?".to_string(),
                 arity: Arity::Fixed(2),
@@ -817,7 +1072,7 @@ impl SecureStandardLibrary {
 
         // Some? function
         env.define(
-            &Symbol("some?".to_string()),
+            &Symbol("some?".into()),
             Value::Function(Function::BuiltinWithContext(BuiltinFunctionWithContext {
                 name: "some?".to_string(),
                 arity: Arity::Fixed(2),
@@ -827,7 +1082,7 @@ impl SecureStandardLibrary {
 
         // Take function
         env.define(
-            &Symbol("take".to_string()),
+            &Symbol("take".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "take".to_string(),
                 arity: Arity::Fixed(2),
@@ -835,9 +1090,29 @@ impl SecureStandardLibrary {
             })),
         );
 
+        // Take-last function
+        env.define(
+            &Symbol("take-last".into()),
+            Value::Function(Function::Builtin(BuiltinFunction {
+                name: "take-last".to_string(),
+                arity: Arity::Fixed(2),
+                func: Arc::new(Self::take_last),
+            })),
+        );
+
+        // Drop-last function
+        env.define(
+            &Symbol("drop-last".into()),
+            Value::Function(Function::Builtin(BuiltinFunction {
+                name: "drop-last".to_string(),
+                arity: Arity::Fixed(2),
+                func: Arc::new(Self::drop_last),
+            })),
+        );
+
         // Keys function
         env.define(
-            &Symbol("keys".to_string()),
+            &Symbol("keys".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "keys".to_string(),
                 arity: Arity::Fixed(1),
@@ -847,7 +1122,7 @@ impl SecureStandardLibrary {
 
         // Vals function
         env.define(
-            &Symbol("vals".to_string()),
+            &Symbol("vals".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "vals".to_string(),
                 arity: Arity::Fixed(1),
@@ -857,7 +1132,7 @@ impl SecureStandardLibrary {
 
         // Map-indexed function
         env.define(
-            &Symbol("map-indexed".to_string()),
+            &Symbol("map-indexed".into()),
             Value::Function(Function::BuiltinWithContext(BuiltinFunctionWithContext {
                 name: "map-indexed".to_string(),
                 arity: Arity::Fixed(2),
@@ -867,7 +1142,7 @@ impl SecureStandardLibrary {
 
         // Numbers function
         env.define(
-            &Symbol("numbers".to_string()),
+            &Symbol("numbers".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "numbers".to_string(),
                 arity: Arity::Fixed(2),
@@ -877,7 +1152,7 @@ impl SecureStandardLibrary {
 
         // Assoc: associate key/value pairs into a new map or set vector index
         env.define(
-            &Symbol("assoc".to_string()),
+            &Symbol("assoc".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "assoc".to_string(),
                 arity: Arity::Variadic(3),
@@ -887,7 +1162,7 @@ impl SecureStandardLibrary {
 
         // Dissoc: remove keys from a map (pure)
         env.define(
-            &Symbol("dissoc".to_string()),
+            &Symbol("dissoc".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "dissoc".to_string(),
                 arity: Arity::Variadic(2),
@@ -897,7 +1172,7 @@ impl SecureStandardLibrary {
 
         // Conj: add elements to a collection (append for vectors)
         env.define(
-            &Symbol("conj".to_string()),
+            &Symbol("conj".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "conj".to_string(),
                 arity: Arity::Variadic(2),
@@ -905,21 +1180,128 @@ impl SecureStandardLibrary {
             })),
         );
 
+        // Into: pour the elements of one collection into another
+        env.define(
+            &Symbol("into".into()),
+            Value::Function(Function::Builtin(BuiltinFunction {
+                name: "into".to_string(),
+                arity: Arity::Fixed(2),
+                func: Arc::new(Self::into),
+            })),
+        );
+
+        // Conversions between collection types
+        env.define(
+            &Symbol("vec".into()),
+            Value::Function(Function::Builtin(BuiltinFunction {
+                name: "vec".to_string(),
+                arity: Arity::Fixed(1),
+                func: Arc::new(Self::vec),
+            })),
+        );
+        env.define(
+            &Symbol("list".into()),
+            Value::Function(Function::Builtin(BuiltinFunction {
+                name: "list".to_string(),
+                arity: Arity::Fixed(1),
+                func: Arc::new(Self::list),
+            })),
+        );
+        env.define(
+            &Symbol("seq".into()),
+            Value::Function(Function::Builtin(BuiltinFunction {
+                name: "seq".to_string(),
+                arity: Arity::Fixed(1),
+                func: Arc::new(Self::seq),
+            })),
+        );
+        env.define(
+            &Symbol("not-empty".into()),
+            Value::Function(Function::Builtin(BuiltinFunction {
+                name: "not-empty".to_string(),
+                arity: Arity::Fixed(1),
+                func: Arc::new(Self::not_empty),
+            })),
+        );
+        env.define(
+            &Symbol("to-vec".into()),
+            Value::Function(Function::Builtin(BuiltinFunction {
+                name: "to-vec".to_string(),
+                arity: Arity::Fixed(1),
+                func: Arc::new(Self::to_vec),
+            })),
+        );
+
         // Group-by: group items by a key-fn
         env.define(
-            &Symbol("group-by".to_string()),
+            &Symbol("group-by".into()),
             Value::Function(Function::BuiltinWithContext(BuiltinFunctionWithContext {
                 name: "group-by".to_string(),
                 arity: Arity::Fixed(2),
                 func: Arc::new(|args, evaluator, env| Self::group_by(args, evaluator, env)),
             })),
         );
+
+        // Filter-keys / filter-vals: keep the map entries whose key/value satisfies a predicate
+        env.define(
+            &Symbol("filter-keys".into()),
+            Value::Function(Function::BuiltinWithContext(BuiltinFunctionWithContext {
+                name: "filter-keys".to_string(),
+                arity: Arity::Fixed(2),
+                func: Arc::new(|args, evaluator, env| {
+                    Self::filter_entries(args, evaluator, env, "filter-keys", true)
+                }),
+            })),
+        );
+        env.define(
+            &Symbol("filter-vals".into()),
+            Value::Function(Function::BuiltinWithContext(BuiltinFunctionWithContext {
+                name: "filter-vals".to_string(),
+                arity: Arity::Fixed(2),
+                func: Arc::new(|args, evaluator, env| {
+                    Self::filter_entries(args, evaluator, env, "filter-vals", false)
+                }),
+            })),
+        );
+
+        // Map transformers: apply a function to every value, or every key, of a map
+        env.define(
+            &Symbol("map-vals".into()),
+            Value::Function(Function::BuiltinWithContext(BuiltinFunctionWithContext {
+                name: "map-vals".to_string(),
+                arity: Arity::Fixed(2),
+                func: Arc::new(|args, evaluator, env| {
+                    Self::map_entries(args, evaluator, env, "map-vals", false)
+                }),
+            })),
+        );
+
+        env.define(
+            &Symbol("map-keys".into()),
+            Value::Function(Function::BuiltinWithContext(BuiltinFunctionWithContext {
+                name: "map-keys".to_string(),
+                arity: Arity::Fixed(2),
+                func: Arc::new(|args, evaluator, env| {
+                    Self::map_entries(args, evaluator, env, "map-keys", true)
+                }),
+            })),
+        );
+
+        // Interpose: insert a separator between each element of a collection
+        env.define(
+            &Symbol("interpose".into()),
+            Value::Function(Function::Builtin(BuiltinFunction {
+                name: "interpose".to_string(),
+                arity: Arity::Fixed(2),
+                func: Arc::new(Self::interpose),
+            })),
+        );
     }
 
     pub(crate) fn load_type_predicate_functions(env: &mut Environment) {
         // Type predicate functions (safe, pure)
         env.define(
-            &Symbol("int?".to_string()),
+            &Symbol("int?".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "int?".to_string(),
                 arity: Arity::Fixed(1),
@@ -928,7 +1310,7 @@ impl SecureStandardLibrary {
         );
 
         env.define(
-            &Symbol("float?".to_string()),
+            &Symbol("float?".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "float?".to_string(),
                 arity: Arity::Fixed(1),
@@ -937,7 +1319,7 @@ impl SecureStandardLibrary {
         );
 
         env.define(
-            &Symbol("number?".to_string()),
+            &Symbol("number?".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "number?".to_string(),
                 arity: Arity::Fixed(1),
@@ -946,7 +1328,7 @@ impl SecureStandardLibrary {
         );
 
         env.define(
-            &Symbol("string?".to_string()),
+            &Symbol("string?".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "string?".to_string(),
                 arity: Arity::Fixed(1),
@@ -955,7 +1337,7 @@ impl SecureStandardLibrary {
         );
 
         env.define(
-            &Symbol("string-p".to_string()),
+            &Symbol("string-p".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "string-p".to_string(),
                 arity: Arity::Fixed(1),
@@ -964,7 +1346,7 @@ impl SecureStandardLibrary {
         );
 
         env.define(
-            &Symbol("bool?".to_string()),
+            &Symbol("bool?".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "bool?".to_string(),
                 arity: Arity::Fixed(1),
@@ -973,7 +1355,7 @@ impl SecureStandardLibrary {
         );
 
         env.define(
-            &Symbol("nil?".to_string()),
+            &Symbol("nil?".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "nil?".to_string(),
                 arity: Arity::Fixed(1),
@@ -982,7 +1364,7 @@ impl SecureStandardLibrary {
         );
 
         env.define(
-            &Symbol("map?".to_string()),
+            &Symbol("map?".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "map?".to_string(),
                 arity: Arity::Fixed(1),
@@ -991,7 +1373,7 @@ impl SecureStandardLibrary {
         );
 
         env.define(
-            &Symbol("vector?".to_string()),
+            &Symbol("vector?".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "vector?".to_string(),
                 arity: Arity::Fixed(1),
@@ -1000,7 +1382,7 @@ impl SecureStandardLibrary {
         );
 
         env.define(
-            &Symbol("list?".to_string()),
+            &Symbol("list?".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "list?".to_string(),
                 arity: Arity::Fixed(1),
@@ -1009,7 +1391,16 @@ impl SecureStandardLibrary {
         );
 
         env.define(
-            &Symbol("keyword?".to_string()),
+            &Symbol("sequential?".into()),
+            Value::Function(Function::Builtin(BuiltinFunction {
+                name: "sequential?".to_string(),
+                arity: Arity::Fixed(1),
+                func: Arc::new(Self::sequential_p),
+            })),
+        );
+
+        env.define(
+            &Symbol("keyword?".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "keyword?".to_string(),
                 arity: Arity::Fixed(1),
@@ -1018,7 +1409,16 @@ impl SecureStandardLibrary {
         );
 
         env.define(
-            &Symbol("symbol?".to_string()),
+            &Symbol("keyword".into()),
+            Value::Function(Function::Builtin(BuiltinFunction {
+                name: "keyword".to_string(),
+                arity: Arity::Fixed(1),
+                func: Arc::new(Self::keyword),
+            })),
+        );
+
+        env.define(
+            &Symbol("symbol?".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "symbol?".to_string(),
                 arity: Arity::Fixed(1),
@@ -1027,17 +1427,43 @@ impl SecureStandardLibrary {
         );
 
         env.define(
-            &Symbol("fn?".to_string()),
+            &Symbol("fn?".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "fn?".to_string(),
                 arity: Arity::Fixed(1),
                 func: Arc::new(Self::fn_p),
             })),
         );
+
+        // Runtime schema checks against an RTFS type expression
+        env.define(
+            &Symbol("valid?".into()),
+            Value::Function(Function::Builtin(BuiltinFunction {
+                name: "valid?".to_string(),
+                arity: Arity::Fixed(2),
+                func: Arc::new(Self::valid_p),
+            })),
+        );
+
+        env.define(
+            &Symbol("validate".into()),
+            Value::Function(Function::Builtin(BuiltinFunction {
+                name: "validate".to_string(),
+                arity: Arity::Fixed(2),
+                func: Arc::new(Self::validate),
+            })),
+        );
+    }
+
+    /// Integer arithmetic never wraps: an `i64` result that does not fit is an
+    /// error rather than a silently wrong value. Floats follow IEEE 754.
+    fn integer_overflow(operation: &str) -> RuntimeError {
+        RuntimeError::Generic(format!("integer overflow in {}", operation))
     }
 
     // Implementation of pure functions (copied from StandardLibrary)
-    fn add(args: Vec<Value>) -> RuntimeResult<Value> {
+    // `+`, `-`, `*` and `/` are public so host capabilities (e.g. `ccos.math.*`) can share them
+    pub fn add(args: Vec<Value>) -> RuntimeResult<Value> {
         let args = args.as_slice();
         if args.is_empty() {
             return Ok(Value::Integer(0));
@@ -1052,7 +1478,11 @@ impl SecureStandardLibrary {
                     if let Some(float_acc) = result_float {
                         result_float = Some(float_acc + *n as f64);
                     } else if let Some(int_acc) = result_int {
-                        result_int = Some(int_acc + n);
+                        result_int = Some(
+                            int_acc
+                                .checked_add(*n)
+                                .ok_or_else(|| Self::integer_overflow("+"))?,
+                        );
                     } else {
                         result_int = Some(*n);
                     }
@@ -1079,7 +1509,7 @@ impl SecureStandardLibrary {
         }
     }
 
-    fn subtract(args: Vec<Value>) -> RuntimeResult<Value> {
+    pub fn subtract(args: Vec<Value>) -> RuntimeResult<Value> {
         let args = args.as_slice();
         if args.is_empty() {
             return Err(RuntimeError::ArityMismatch {
@@ -1092,7 +1522,10 @@ impl SecureStandardLibrary {
         if args.len() == 1 {
             // Negation
             match &args[0] {
-                Value::Integer(n) => Ok(Value::Integer(-n)),
+                Value::Integer(n) => n
+                    .checked_neg()
+                    .map(Value::Integer)
+                    .ok_or_else(|| Self::integer_overflow("-")),
                 Value::Float(f) => Ok(Value::Float(-f)),
                 _ => Err(RuntimeError::TypeError {
                     expected: "number".to_string(),
@@ -1101,27 +1534,28 @@ impl SecureStandardLibrary {
                 }),
             }
         } else {
-            // Subtraction
-            let mut result = match &args[0] {
-                Value::Integer(n) => (*n as f64, false),
-                Value::Float(f) => (*f, true),
-                _ => {
-                    return Err(RuntimeError::TypeError {
-                        expected: "number".to_string(),
-                        actual: args[0].type_name().to_string(),
-                        operation: "-".to_string(),
-                    })
-                }
-            };
-
-            for arg in &args[1..] {
-                match arg {
-                    Value::Integer(n) => {
-                        result.0 -= *n as f64;
+            // Subtraction: stays exact on integers until a float is involved
+            let mut result_int: Option<i64> = None;
+            let mut result_float: Option<f64> = None;
+            for (i, arg) in args.iter().enumerate() {
+                match (arg, i) {
+                    (Value::Integer(n), 0) => result_int = Some(*n),
+                    (Value::Float(f), 0) => result_float = Some(*f),
+                    (Value::Integer(n), _) => {
+                        if let Some(float_acc) = result_float {
+                            result_float = Some(float_acc - *n as f64);
+                        } else if let Some(int_acc) = result_int {
+                            result_int = Some(
+                                int_acc
+                                    .checked_sub(*n)
+                                    .ok_or_else(|| Self::integer_overflow("-"))?,
+                            );
+                        }
                     }
-                    Value::Float(f) => {
-                        result.0 -= f;
-                        result.1 = true;
+                    (Value::Float(f), _) => {
+                        let current = result_float.unwrap_or(result_int.unwrap_or(0) as f64);
+                        result_float = Some(current - f);
+                        result_int = None;
                     }
                     _ => {
                         return Err(RuntimeError::TypeError {
@@ -1133,15 +1567,15 @@ impl SecureStandardLibrary {
                 }
             }
 
-            if result.1 {
-                Ok(Value::Float(result.0))
+            if let Some(f) = result_float {
+                Ok(Value::Float(f))
             } else {
-                Ok(Value::Integer(result.0 as i64))
+                Ok(Value::Integer(result_int.unwrap_or(0)))
             }
         }
     }
 
-    fn multiply(args: Vec<Value>) -> RuntimeResult<Value> {
+    pub fn multiply(args: Vec<Value>) -> RuntimeResult<Value> {
         let args = args.as_slice();
         if args.is_empty() {
             return Ok(Value::Integer(1));
@@ -1156,7 +1590,11 @@ impl SecureStandardLibrary {
                     if let Some(float_acc) = result_float {
                         result_float = Some(float_acc * *n as f64);
                     } else if let Some(int_acc) = result_int {
-                        result_int = Some(int_acc * n);
+                        result_int = Some(
+                            int_acc
+                                .checked_mul(*n)
+                                .ok_or_else(|| Self::integer_overflow("*"))?,
+                        );
                     } else {
                         result_int = Some(*n);
                     }
@@ -1183,7 +1621,7 @@ impl SecureStandardLibrary {
         }
     }
 
-    fn divide(args: Vec<Value>) -> RuntimeResult<Value> {
+    pub fn divide(args: Vec<Value>) -> RuntimeResult<Value> {
         let args = args.as_slice();
         if args.is_empty() {
             return Err(RuntimeError::ArityMismatch {
@@ -1248,17 +1686,34 @@ impl SecureStandardLibrary {
         Ok(Value::Boolean(true))
     }
 
-    fn not_equal(args: Vec<Value>) -> RuntimeResult<Value> {
+    /// `(seq= a b ...)` -> like `=`, but a list and a vector with equal elements
+    /// compare equal (see `Value::seq_eq`)
+    fn seq_equal(args: Vec<Value>) -> RuntimeResult<Value> {
         let args = args.as_slice();
-        if args.len() != 2 {
+        if args.is_empty() {
             return Err(RuntimeError::ArityMismatch {
-                function: "!=".to_string(),
-                expected: "2".to_string(),
-                actual: args.len(),
+                function: "seq=".to_string(),
+                expected: "at least 1".to_string(),
+                actual: 0,
             });
         }
-        Ok(Value::Boolean(args[0] != args[1]))
-    }
+        let first = &args[0];
+        Ok(Value::Boolean(
+            args[1..].iter().all(|arg| first.seq_eq(arg)),
+        ))
+    }
+
+    fn not_equal(args: Vec<Value>) -> RuntimeResult<Value> {
+        let args = args.as_slice();
+        if args.len() != 2 {
+            return Err(RuntimeError::ArityMismatch {
+                function: "!=".to_string(),
+                expected: "2".to_string(),
+                actual: args.len(),
+            });
+        }
+        Ok(Value::Boolean(args[0] != args[1]))
+    }
 
     fn greater_than(args: Vec<Value>) -> RuntimeResult<Value> {
         let args = args.as_slice();
@@ -1344,6 +1799,9 @@ impl SecureStandardLibrary {
         Ok(Value::Boolean(cmp(a_val, b_val)))
     }
 
+    /// Function value behind `and` when it is passed around (e.g. to `reduce`);
+    /// direct calls go through the short-circuiting special form instead.
+    /// Returns the first falsy argument, else the last one.
     fn and(args: Vec<Value>) -> RuntimeResult<Value> {
         let args = args.as_slice();
         for arg in args {
@@ -1356,6 +1814,8 @@ impl SecureStandardLibrary {
         Ok(args.last().cloned().unwrap_or(Value::Boolean(true)))
     }
 
+    /// Function value behind `or`; returns the first truthy argument, else the
+    /// last one, so `(or nil :default)` yields `:default`.
     fn or(args: Vec<Value>) -> RuntimeResult<Value> {
         let args = args.as_slice();
         for arg in args {
@@ -1653,7 +2113,7 @@ impl SecureStandardLibrary {
                 .collect()
         };
 
-        Ok(Value::Vector(parts))
+        Ok(Value::Vector(parts.into()))
     }
 
     /// Regex match - checks if pattern matches text
@@ -1778,7 +2238,7 @@ impl SecureStandardLibrary {
 
         let text = match &args[1] {
             Value::String(s) => s.as_str(),
-            Value::Nil => return Ok(Value::Vector(vec![])),
+            Value::Nil => return Ok(Value::Vector(vec![].into())),
             _ => {
                 return Err(RuntimeError::TypeError {
                     expected: "string (text)".to_string(),
@@ -1794,7 +2254,7 @@ impl SecureStandardLibrary {
                     .find_iter(text)
                     .map(|m| Value::String(m.as_str().to_string()))
                     .collect();
-                Ok(Value::Vector(matches))
+                Ok(Value::Vector(matches.into()))
             }
             Err(e) => Err(RuntimeError::Generic(format!(
                 "Invalid regex pattern '{}': {}",
@@ -1805,7 +2265,7 @@ impl SecureStandardLibrary {
 
     fn vector(args: Vec<Value>) -> RuntimeResult<Value> {
         let args = args.as_slice();
-        Ok(Value::Vector(args.to_vec()))
+        Ok(Value::Vector(args.to_vec().into()))
     }
 
     fn hash_map(args: Vec<Value>) -> RuntimeResult<Value> {
@@ -1825,7 +2285,7 @@ impl SecureStandardLibrary {
             result.insert(key, value);
         }
 
-        Ok(Value::Map(result))
+        Ok(Value::Map(result.into()))
     }
 
     fn get(args: Vec<Value>) -> RuntimeResult<Value> {
@@ -1845,18 +2305,100 @@ impl SecureStandardLibrary {
                 let map_key = Self::value_to_map_key(key)?;
                 Ok(map.get(&map_key).cloned().unwrap_or(default))
             }
-            (Value::Vector(vec), Value::Integer(index)) => {
-                let idx = *index as usize;
-                Ok(vec.get(idx).cloned().unwrap_or(default))
+            (
+                coll @ (Value::Vector(_) | Value::List(_) | Value::String(_)),
+                Value::Integer(index),
+            ) => Ok(Self::seq_nth(coll, *index, "get")?.unwrap_or(default)),
+            _ => Err(RuntimeError::TypeError {
+                expected: "map, or vector/list/string with an integer index".to_string(),
+                actual: format!("{} with {}", args[0].type_name(), args[1].type_name()),
+                operation: "get".to_string(),
+            }),
+        }
+    }
+
+    /// `(get-flex m k [default])` - like `get`, but a keyword key also matches the
+    /// same name stored as a string and vice versa, so plans need not know whether
+    /// a map came from an RTFS literal or parsed JSON. The key exactly as given is
+    /// tried first; if a map holds both `:a` and `"a"`, the alternate entry is
+    /// silently ignored, so producers should avoid mixing the two forms.
+    fn get_flex(args: Vec<Value>) -> RuntimeResult<Value> {
+        if args.len() < 2 || args.len() > 3 {
+            return Err(RuntimeError::ArityMismatch {
+                function: "get-flex".to_string(),
+                expected: "2 or 3".to_string(),
+                actual: args.len(),
+            });
+        }
+
+        let Value::Map(map) = &args[0] else {
+            return Self::get(args);
+        };
+        let default = args.get(2).cloned().unwrap_or(Value::Nil);
+
+        let alternate = match &args[1] {
+            Value::Keyword(k) => Some(MapKey::String(k.0.to_string())),
+            Value::String(s) => Some(MapKey::Keyword(Keyword::new(s.as_str()))),
+            _ => None,
+        };
+        let exact = Self::value_to_map_key(&args[1])?;
+
+        Ok(map
+            .get(&exact)
+            .or_else(|| alternate.and_then(|k| map.get(&k)))
+            .cloned()
+            .unwrap_or(default))
+    }
+
+    /// `(contains-key? coll k)` - true when `k` is a key of the map (even if its
+    /// value is nil) or a valid index of the vector/list. Unlike `contains?`,
+    /// this never tests membership of values.
+    fn contains_key_p(args: Vec<Value>) -> RuntimeResult<Value> {
+        if args.len() != 2 {
+            return Err(RuntimeError::ArityMismatch {
+                function: "contains-key?".to_string(),
+                expected: "2".to_string(),
+                actual: args.len(),
+            });
+        }
+
+        match (&args[0], &args[1]) {
+            (Value::Map(map), key) => {
+                let map_key = Self::value_to_map_key(key)?;
+                Ok(Value::Boolean(map.contains_key(&map_key)))
             }
+            (Value::Vector(items), Value::Integer(index))
+            | (Value::List(items), Value::Integer(index)) => Ok(Value::Boolean(
+                *index >= 0 && (*index as usize) < items.len(),
+            )),
+            (Value::Nil, _) => Ok(Value::Boolean(false)),
             _ => Err(RuntimeError::TypeError {
                 expected: "map or vector with appropriate key/index".to_string(),
                 actual: format!("{} with {}", args[0].type_name(), args[1].type_name()),
-                operation: "get".to_string(),
+                operation: "contains-key?".to_string(),
             }),
         }
     }
 
+    /// `(get-or coll k sentinel)` - like `get`, but the sentinel is mandatory so
+    /// callers can tell a missing key (sentinel returned) apart from a key whose
+    /// stored value is nil (nil returned).
+    fn get_or(args: Vec<Value>) -> RuntimeResult<Value> {
+        if args.len() != 3 {
+            return Err(RuntimeError::ArityMismatch {
+                function: "get-or".to_string(),
+                expected: "3".to_string(),
+                actual: args.len(),
+            });
+        }
+
+        if Self::contains_key_p(vec![args[0].clone(), args[1].clone()])?.is_truthy() {
+            Self::get(args[..2].to_vec())
+        } else {
+            Ok(args[2].clone())
+        }
+    }
+
     fn count(args: Vec<Value>) -> RuntimeResult<Value> {
         let args = args.as_slice();
         if args.len() != 1 {
@@ -1867,17 +2409,20 @@ impl SecureStandardLibrary {
             });
         }
 
-        match &args[0] {
-            Value::Vector(v) => Ok(Value::Integer(v.len() as i64)),
-            Value::List(l) => Ok(Value::Integer(l.len() as i64)),
-            Value::Map(m) => Ok(Value::Integer(m.len() as i64)),
-            Value::String(s) => Ok(Value::Integer(s.chars().count() as i64)),
-            _ => Err(RuntimeError::TypeError {
+        Self::seq_count(&args[0], "count")
+    }
+
+    /// Element count of a sequence (characters for a string) or entry count of a map.
+    fn seq_count(value: &Value, operation: &str) -> RuntimeResult<Value> {
+        let len = match value {
+            Value::Map(m) => m.len(),
+            other => Self::seq_len(other).ok_or_else(|| RuntimeError::TypeError {
                 expected: "vector, list, map, or string".to_string(),
-                actual: args[0].type_name().to_string(),
-                operation: "count".to_string(),
-            }),
-        }
+                actual: other.type_name().to_string(),
+                operation: operation.to_string(),
+            })?,
+        };
+        Ok(Value::Integer(len as i64))
     }
 
     fn first(args: Vec<Value>) -> RuntimeResult<Value> {
@@ -1889,22 +2434,7 @@ impl SecureStandardLibrary {
                 actual: args.len(),
             });
         }
-        match &args[0] {
-            Value::Vector(v) => Ok(v.first().cloned().unwrap_or(Value::Nil)),
-            Value::List(list) => Ok(list.first().cloned().unwrap_or(Value::Nil)),
-            Value::String(s) => {
-                if s.is_empty() {
-                    Ok(Value::Nil)
-                } else {
-                    Ok(Value::String(s.chars().next().unwrap().to_string()))
-                }
-            }
-            _ => Err(RuntimeError::TypeError {
-                expected: "vector, list, or string".to_string(),
-                actual: args[0].type_name().to_string(),
-                operation: "first".to_string(),
-            }),
-        }
+        Ok(Self::seq_nth(&args[0], 0, "first")?.unwrap_or(Value::Nil))
     }
 
     fn rest(args: Vec<Value>) -> RuntimeResult<Value> {
@@ -1916,34 +2446,8 @@ impl SecureStandardLibrary {
                 actual: args.len(),
             });
         }
-        match &args[0] {
-            Value::Vector(v) => {
-                if v.is_empty() {
-                    Ok(Value::Vector(vec![]))
-                } else {
-                    Ok(Value::Vector(v[1..].to_vec()))
-                }
-            }
-            Value::List(list) => {
-                if list.is_empty() {
-                    Ok(Value::List(vec![]))
-                } else {
-                    Ok(Value::List(list[1..].to_vec()))
-                }
-            }
-            Value::String(s) => {
-                if s.len() <= 1 {
-                    Ok(Value::String(String::new()))
-                } else {
-                    Ok(Value::String(s.chars().skip(1).collect()))
-                }
-            }
-            _ => Err(RuntimeError::TypeError {
-                expected: "vector, list, or string".to_string(),
-                actual: args[0].type_name().to_string(),
-                operation: "rest".to_string(),
-            }),
-        }
+        let items = Self::seq_arg(&args[0], "rest")?;
+        Ok(Self::rebuild_seq(&args[0], items.skip(items.len().min(1))))
     }
 
     fn int_p(args: Vec<Value>) -> RuntimeResult<Value> {
@@ -2057,6 +2561,15 @@ impl SecureStandardLibrary {
         Ok(Value::Boolean(matches!(args[0], Value::List(_))))
     }
 
+    /// `(sequential? x)` -> true for lists and vectors
+    fn sequential_p(args: Vec<Value>) -> RuntimeResult<Value> {
+        let arg = Self::single_arg(&args, "sequential?")?;
+        Ok(Value::Boolean(matches!(
+            arg,
+            Value::List(_) | Value::Vector(_)
+        )))
+    }
+
     fn keyword_p(args: Vec<Value>) -> RuntimeResult<Value> {
         let args = args.as_slice();
         if args.len() != 1 {
@@ -2069,6 +2582,21 @@ impl SecureStandardLibrary {
         Ok(Value::Boolean(matches!(args[0], Value::Keyword(_))))
     }
 
+    /// `(keyword "name")` -> `:name`; a leading colon is dropped and keywords pass through.
+    fn keyword(args: Vec<Value>) -> RuntimeResult<Value> {
+        match Self::single_arg(&args, "keyword")? {
+            Value::Keyword(k) => Ok(Value::Keyword(k.clone())),
+            Value::String(s) => Ok(Value::Keyword(Keyword::new(
+                s.strip_prefix(':').unwrap_or(s),
+            ))),
+            other => Err(RuntimeError::TypeError {
+                expected: "string or keyword".to_string(),
+                actual: other.type_name().to_string(),
+                operation: "keyword".to_string(),
+            }),
+        }
+    }
+
     fn symbol_p(args: Vec<Value>) -> RuntimeResult<Value> {
         let args = args.as_slice();
         if args.len() != 1 {
@@ -2093,6 +2621,229 @@ impl SecureStandardLibrary {
         Ok(Value::Boolean(matches!(args[0], Value::Function(_))))
     }
 
+    /// Convert a runtime value describing a type into a `TypeExpr`.
+    /// A map literal such as `{:x :int}` denotes a map type with those required keys;
+    /// anything else (`:int`, `[:vector :string]`, `[:map [:x :int]]`, ...) is read
+    /// with the regular type expression syntax.
+    fn value_to_type_expr(value: &Value) -> RuntimeResult<TypeExpr> {
+        match value {
+            Value::Map(map) => {
+                let mut entries = Vec::with_capacity(map.len());
+                for (key, value_type) in map {
+                    let key = match key {
+                        MapKey::Keyword(k) => k.clone(),
+                        MapKey::String(s) => Keyword::new(s.as_str()),
+                        other => {
+                            return Err(RuntimeError::InvalidArgument(format!(
+                                "map type keys must be keywords, got {}",
+                                other
+                            )))
+                        }
+                    };
+                    entries.push(MapTypeEntry {
+                        key,
+                        value_type: Box::new(Self::value_to_type_expr(value_type)?),
+                        optional: false,
+                    });
+                }
+                entries.sort_by(|a, b| a.key.0.cmp(&b.key.0));
+                Ok(TypeExpr::Map {
+                    entries,
+                    wildcard: None,
+                })
+            }
+            Value::Keyword(_) | Value::Vector(_) | Value::String(_) => {
+                let source = match value {
+                    Value::String(s) => s.clone(),
+                    other => other.to_string(),
+                };
+                TypeExpr::from_str(&source).map_err(RuntimeError::InvalidArgument)
+            }
+            other => Err(RuntimeError::TypeError {
+                expected: "type expression (keyword, vector or map)".to_string(),
+                actual: other.type_name().to_string(),
+                operation: "validate".to_string(),
+            }),
+        }
+    }
+
+    /// Validate `value` against the type expression, reporting the first violation.
+    fn check_type(function: &str, args: &[Value]) -> RuntimeResult<Result<(), String>> {
+        if args.len() != 2 {
+            return Err(RuntimeError::ArityMismatch {
+                function: function.to_string(),
+                expected: "2".to_string(),
+                actual: args.len(),
+            });
+        }
+        let type_expr = Self::value_to_type_expr(&args[0])?;
+        Ok(TypeValidator::new()
+            .validate_value_at_path(&args[1], &type_expr, "$")
+            .map_err(|e| e.to_string()))
+    }
+
+    /// `(valid? type-expr value)` - true when `value` conforms to `type-expr`.
+    fn valid_p(args: Vec<Value>) -> RuntimeResult<Value> {
+        Ok(Value::Boolean(Self::check_type("valid?", &args)?.is_ok()))
+    }
+
+    /// `(validate type-expr value)` - returns `value` when it conforms, otherwise
+    /// errors with the first violation and its path (e.g. `$.x`).
+    fn validate(args: Vec<Value>) -> RuntimeResult<Value> {
+        match Self::check_type("validate", &args)? {
+            Ok(()) => Ok(args[1].clone()),
+            Err(violation) => Err(RuntimeError::TypeValidationError(violation)),
+        }
+    }
+
+    /// Call a user-defined function from a higher-order builtin. Goes through
+    /// [`Evaluator::call_function`] so arity checks, destructuring patterns and
+    /// `& rest` parameters bind exactly as in a direct call.
+    fn call_closure(
+        evaluator: &Evaluator,
+        function: &Value,
+        args: &[Value],
+        env: &mut Environment,
+        operation: &str,
+    ) -> RuntimeResult<Value> {
+        match evaluator.call_function(function.clone(), args, env)? {
+            ExecutionOutcome::Complete(value) => Ok(value),
+            ExecutionOutcome::RequiresHost(_) => Err(RuntimeError::Generic(format!(
+                "Host call required in {} closure",
+                operation
+            ))),
+            #[cfg(feature = "effect-boundary")]
+            ExecutionOutcome::RequiresHost(_) => Err(RuntimeError::Generic(format!(
+                "Host effect required in {} closure",
+                operation
+            ))),
+        }
+    }
+
+    /// `(filter-keys pred m)` / `(filter-vals pred m)` - the entries of map `m`
+    /// whose key (resp. value) satisfies `pred`, as a map. nil yields an empty map.
+    fn filter_entries(
+        args: Vec<Value>,
+        evaluator: &Evaluator,
+        env: &mut Environment,
+        operation: &str,
+        by_key: bool,
+    ) -> RuntimeResult<Value> {
+        if args.len() != 2 {
+            return Err(RuntimeError::ArityMismatch {
+                function: operation.to_string(),
+                expected: "2".to_string(),
+                actual: args.len(),
+            });
+        }
+        let predicate = &args[0];
+        let map = match &args[1] {
+            Value::Map(m) => m.clone(),
+            Value::Nil => ValueMap::new(),
+            other => {
+                return Err(RuntimeError::TypeError {
+                    expected: "map or nil".to_string(),
+                    actual: other.type_name().to_string(),
+                    operation: operation.to_string(),
+                })
+            }
+        };
+
+        let mut kept = ValueMap::new();
+        for (key, value) in map {
+            let tested = if by_key {
+                Self::map_key_to_value(&key)
+            } else {
+                value.clone()
+            };
+            if Self::call_unary(predicate, tested, evaluator, env, operation)?.is_truthy() {
+                kept.insert(key, value);
+            }
+        }
+        Ok(Value::Map(kept))
+    }
+
+    /// `(map-vals f m)` / `(map-keys f m)` - map `m` with `f` applied to each value
+    /// (resp. key). nil yields an empty map. New keys must be strings, keywords or
+    /// integers; two keys mapping to the same new key is an error, since map
+    /// iteration order would otherwise decide which value survives.
+    fn map_entries(
+        args: Vec<Value>,
+        evaluator: &Evaluator,
+        env: &mut Environment,
+        operation: &str,
+        on_keys: bool,
+    ) -> RuntimeResult<Value> {
+        if args.len() != 2 {
+            return Err(RuntimeError::ArityMismatch {
+                function: operation.to_string(),
+                expected: "2".to_string(),
+                actual: args.len(),
+            });
+        }
+        let function = &args[0];
+        let map = match &args[1] {
+            Value::Map(m) => m.clone(),
+            Value::Nil => ValueMap::new(),
+            other => {
+                return Err(RuntimeError::TypeError {
+                    expected: "map or nil".to_string(),
+                    actual: other.type_name().to_string(),
+                    operation: operation.to_string(),
+                })
+            }
+        };
+        let mut mapped = ValueMap::new();
+        for (key, value) in map {
+            if !on_keys {
+                let value = Self::call_unary(function, value, evaluator, env, operation)?;
+                mapped.insert(key, value);
+                continue;
+            }
+            let new_key = Self::call_unary(
+                function,
+                Self::map_key_to_value(&key),
+                evaluator,
+                env,
+                operation,
+            )?;
+            let new_key = Self::value_to_map_key(&new_key)?;
+            if mapped.contains_key(&new_key) {
+                return Err(RuntimeError::InvalidArgument(format!(
+                    "{}: more than one key maps to {}",
+                    operation,
+                    Self::map_key_to_value(&new_key)
+                )));
+            }
+            mapped.insert(new_key, value);
+        }
+        Ok(Value::Map(mapped))
+    }
+
+    /// Call a one-argument function value (builtin or closure) on `arg`.
+    fn call_unary(
+        function: &Value,
+        arg: Value,
+        evaluator: &Evaluator,
+        env: &mut Environment,
+        operation: &str,
+    ) -> RuntimeResult<Value> {
+        match function {
+            Value::Function(Function::Builtin(builtin_func)) => (builtin_func.func)(vec![arg]),
+            Value::Function(Function::BuiltinWithContext(builtin_func)) => {
+                (builtin_func.func)(vec![arg], evaluator, env)
+            }
+            Value::Function(Function::Closure(_)) => {
+                Self::call_closure(evaluator, function, &[arg], env, operation)
+            }
+            _ => Err(RuntimeError::TypeError {
+                expected: "function".to_string(),
+                actual: function.type_name().to_string(),
+                operation: operation.to_string(),
+            }),
+        }
+    }
+
     /// Group a collection by a key function
     /// (group-by :type [{:type 1} {:type 2} {:type 1}]) -> {1 [{:type 1} {:type 1}] 2 [{:type 2}]}
     fn group_by(
@@ -2114,7 +2865,7 @@ impl SecureStandardLibrary {
         let collection_vec = match collection {
             Value::Vector(v) => v.clone(),
             Value::List(l) => l.clone(),
-            Value::Nil => Vec::new(),
+            Value::Nil => Vec::new().into(),
             other => {
                 return Err(RuntimeError::TypeError {
                     expected: "collection".to_string(),
@@ -2138,27 +2889,13 @@ impl SecureStandardLibrary {
                     let func_args = vec![item.clone()];
                     (builtin_func.func)(func_args, evaluator, env)?
                 }
-                Value::Function(Function::Closure(closure)) => {
-                    if closure.params.is_empty() {
-                        return Err(RuntimeError::ArityMismatch {
-                            function: "group-by key-fn".to_string(),
-                            expected: "1".to_string(),
-                            actual: 0,
-                        });
-                    }
-                    let mut func_env = Environment::with_parent(closure.env.clone());
-                    func_env.define(&closure.params[0], item.clone());
-                    match evaluator.eval_expr(&closure.body, &mut func_env)? {
-                        ExecutionOutcome::Complete(v) => v,
-                        ExecutionOutcome::RequiresHost(_) => {
-                            #[cfg(feature = "effect-boundary")]
-                            let msg = "Host effect required in group-by key function".to_string();
-                            #[cfg(not(feature = "effect-boundary"))]
-                            let msg = "Host call required in group-by key function".to_string();
-                            return Err(RuntimeError::Generic(msg));
-                        }
-                    }
-                }
+                Value::Function(Function::Closure(_)) => Self::call_closure(
+                    evaluator,
+                    key_fn,
+                    std::slice::from_ref(&item),
+                    env,
+                    "group-by",
+                )?,
                 // Also support keywords as key-fn: (group-by :state issues)
                 Value::Keyword(kw) => match &item {
                     Value::Map(m) => {
@@ -2184,9 +2921,9 @@ impl SecureStandardLibrary {
         }
 
         // Convert HashMap to Value::Map
-        let result_map: std::collections::HashMap<MapKey, Value> = groups
+        let result_map: ValueMap = groups
             .into_iter()
-            .map(|(k, v)| (k, Value::Vector(v)))
+            .map(|(k, v)| (k, Value::Vector(v.into())))
             .collect();
 
         Ok(Value::Map(result_map))
@@ -2200,7 +2937,7 @@ impl SecureStandardLibrary {
             Value::Keyword(kw) => MapKey::Keyword(kw.clone()),
             Value::Integer(n) => MapKey::Integer(*n),
             // Convert other types to string keys
-            Value::Symbol(sym) => MapKey::String(sym.0.clone()),
+            Value::Symbol(sym) => MapKey::String(sym.0.to_string()),
             Value::Float(f) => MapKey::String(f.to_string()),
             Value::Boolean(b) => MapKey::String(b.to_string()),
             Value::Nil => MapKey::String("nil".to_string()),
@@ -2318,6 +3055,91 @@ impl SecureStandardLibrary {
         Ok(Value::Float(result))
     }
 
+    /// Single argument of a strict `as-*` coercion
+    fn coercion_arg(function: &str, args: Vec<Value>) -> RuntimeResult<Value> {
+        if args.len() != 1 {
+            return Err(RuntimeError::ArityMismatch {
+                function: function.to_string(),
+                expected: "1".to_string(),
+                actual: args.len(),
+            });
+        }
+        Ok(args.into_iter().next().unwrap())
+    }
+
+    fn coercion_error(function: &str, expected: &str, value: &Value) -> RuntimeError {
+        RuntimeError::TypeError {
+            expected: expected.to_string(),
+            actual: format!("{} {}", value.type_name(), value),
+            operation: function.to_string(),
+        }
+    }
+
+    /// Strictly coerce to an integer
+    /// (as-int 42.0) -> 42
+    /// (as-int "42") -> 42
+    /// (as-int 3.5) and (as-int {:a 1}) -> type error
+    fn as_int(args: Vec<Value>) -> RuntimeResult<Value> {
+        let value = Self::coercion_arg("as-int", args)?;
+        match &value {
+            Value::Integer(_) => Ok(value),
+            Value::Float(f) if f.is_finite() && f.fract() == 0.0 => Ok(Value::Integer(*f as i64)),
+            Value::String(s) => s
+                .trim()
+                .parse::<i64>()
+                .map(Value::Integer)
+                .map_err(|_| Self::coercion_error("as-int", "integer string", &value)),
+            Value::Float(_) => Err(Self::coercion_error("as-int", "whole number", &value)),
+            _ => Err(Self::coercion_error(
+                "as-int",
+                "integer, float or string",
+                &value,
+            )),
+        }
+    }
+
+    /// Strictly coerce to a float
+    /// (as-float 3) -> 3.0
+    /// (as-float "2.5") -> 2.5
+    /// (as-float [1]) -> type error
+    fn as_float(args: Vec<Value>) -> RuntimeResult<Value> {
+        let value = Self::coercion_arg("as-float", args)?;
+        match &value {
+            Value::Float(_) => Ok(value),
+            Value::Integer(n) => Ok(Value::Float(*n as f64)),
+            Value::String(s) => s
+                .trim()
+                .parse::<f64>()
+                .map(Value::Float)
+                .map_err(|_| Self::coercion_error("as-float", "numeric string", &value)),
+            _ => Err(Self::coercion_error(
+                "as-float",
+                "integer, float or string",
+                &value,
+            )),
+        }
+    }
+
+    /// Render any value as a string, like a single-argument `str`
+    /// (as-string 3) -> "3"
+    /// (as-string :ok) -> ":ok"
+    fn as_string(args: Vec<Value>) -> RuntimeResult<Value> {
+        let value = Self::coercion_arg("as-string", args)?;
+        Self::str(vec![value])
+    }
+
+    /// Strictly coerce to a vector; list values become vectors of the same items
+    /// (as-vector [1 2]) -> [1 2]
+    /// (as-vector "abc") -> type error
+    fn as_vector(args: Vec<Value>) -> RuntimeResult<Value> {
+        let value = Self::coercion_arg("as-vector", args)?;
+        match value {
+            Value::Vector(_) => Ok(value),
+            Value::List(items) => Ok(Value::Vector(items)),
+            other => Err(Self::coercion_error("as-vector", "vector or list", &other)),
+        }
+    }
+
     fn map_with_context(
         args: Vec<Value>,
         evaluator: &Evaluator,
@@ -2357,25 +3179,13 @@ impl SecureStandardLibrary {
                     let mapped_value = (builtin_func.func)(func_args, evaluator, env)?;
                     result.push(mapped_value);
                 }
-                Value::Function(Function::Closure(closure)) => {
-                    // Handle user-defined functions with full evaluator access
-                    let mut func_env = Environment::with_parent(closure.env.clone());
-                    func_env.define(&closure.params[0], item);
-                    match evaluator.eval_expr(&closure.body, &mut func_env)? {
-                        ExecutionOutcome::Complete(v) => result.push(v),
-                        ExecutionOutcome::RequiresHost(_hc) => {
-                            return Err(RuntimeError::Generic(
-                                "Host call required in map closure".into(),
-                            ))
-                        }
-                        #[cfg(feature = "effect-boundary")]
-                        ExecutionOutcome::RequiresHost(_) => {
-                            return Err(RuntimeError::Generic(
-                                "Host effect required in map closure".to_string(),
-                            ))
-                        }
-                    }
-                }
+                Value::Function(Function::Closure(_)) => result.push(Self::call_closure(
+                    evaluator,
+                    function,
+                    &[item],
+                    env,
+                    "map",
+                )?),
                 _ => {
                     return Err(RuntimeError::TypeError {
                         expected: "function".to_string(),
@@ -2385,7 +3195,7 @@ impl SecureStandardLibrary {
                 }
             }
         }
-        Ok(Value::Vector(result))
+        Ok(Value::Vector(result.into()))
     }
 
     fn filter_with_context(
@@ -2425,24 +3235,14 @@ impl SecureStandardLibrary {
                     let v = (builtin_func.func)(func_args, evaluator, env)?;
                     v.is_truthy()
                 }
-                Value::Function(Function::Closure(closure)) => {
-                    let mut func_env = Environment::with_parent(closure.env.clone());
-                    func_env.define(&closure.params[0], item.clone());
-                    match evaluator.eval_expr(&closure.body, &mut func_env)? {
-                        ExecutionOutcome::Complete(v) => v.is_truthy(),
-                        ExecutionOutcome::RequiresHost(_hc) => {
-                            return Err(RuntimeError::Generic(
-                                "Host call required in filter closure".into(),
-                            ))
-                        }
-                        #[cfg(feature = "effect-boundary")]
-                        ExecutionOutcome::RequiresHost(_) => {
-                            return Err(RuntimeError::Generic(
-                                "Host effect required in filter closure".to_string(),
-                            ))
-                        }
-                    }
-                }
+                Value::Function(Function::Closure(_)) => Self::call_closure(
+                    evaluator,
+                    function,
+                    std::slice::from_ref(&item),
+                    env,
+                    "filter",
+                )?
+                .is_truthy(),
                 _ => {
                     return Err(RuntimeError::TypeError {
                         expected: "function".to_string(),
@@ -2455,7 +3255,7 @@ impl SecureStandardLibrary {
                 result.push(item);
             }
         }
-        Ok(Value::Vector(result))
+        Ok(Value::Vector(result.into()))
     }
 
     fn reduce_with_context(
@@ -2489,10 +3289,11 @@ impl SecureStandardLibrary {
                 ))
             };
         }
-        let (mut accumulator, rest) = if args.len() == 3 {
-            (args[1].clone(), collection.as_slice())
+        let mut rest = collection.iter();
+        let mut accumulator = if args.len() == 3 {
+            args[1].clone()
         } else {
-            (collection[0].clone(), &collection[1..])
+            rest.next().cloned().unwrap_or(Value::Nil)
         };
         for value in rest {
             let func_args = vec![accumulator.clone(), value.clone()];
@@ -2501,24 +3302,8 @@ impl SecureStandardLibrary {
                 Value::Function(Function::BuiltinWithContext(builtin_func)) => {
                     (builtin_func.func)(func_args, evaluator, env)?
                 }
-                Value::Function(Function::Closure(closure)) => {
-                    let mut func_env = Environment::with_parent(closure.env.clone());
-                    func_env.define(&closure.params[0], accumulator.clone());
-                    func_env.define(&closure.params[1], value.clone());
-                    match evaluator.eval_expr(&closure.body, &mut func_env)? {
-                        ExecutionOutcome::Complete(v) => v,
-                        ExecutionOutcome::RequiresHost(_hc) => {
-                            return Err(RuntimeError::Generic(
-                                "Host call required in reduce closure".into(),
-                            ))
-                        }
-                        #[cfg(feature = "effect-boundary")]
-                        ExecutionOutcome::RequiresHost(_) => {
-                            return Err(RuntimeError::Generic(
-                                "Host effect required in reduce closure".to_string(),
-                            ))
-                        }
-                    }
+                Value::Function(Function::Closure(_)) => {
+                    Self::call_closure(evaluator, function, &func_args, env, "reduce")?
                 }
                 _ => {
                     return Err(RuntimeError::TypeError {
@@ -2569,7 +3354,10 @@ impl SecureStandardLibrary {
         }
     }
 
-    fn expand_apply_collection(call_args: &mut Vec<Value>, value: Value) -> RuntimeResult<()> {
+    pub(crate) fn expand_apply_collection(
+        call_args: &mut Vec<Value>,
+        value: Value,
+    ) -> RuntimeResult<()> {
         match value {
             Value::Vector(items) | Value::List(items) => {
                 call_args.reserve(items.len());
@@ -2608,7 +3396,7 @@ impl SecureStandardLibrary {
         }
     }
 
-    fn append_map_entries(call_args: &mut Vec<Value>, map: &HashMap<MapKey, Value>) {
+    fn append_map_entries(call_args: &mut Vec<Value>, map: &ValueMap) {
         for (key, value) in map {
             call_args.push(Self::map_key_to_value(key));
             call_args.push(value.clone());
@@ -2675,7 +3463,7 @@ impl SecureStandardLibrary {
             });
         }
         match &args[0] {
-            Value::Vector(v) => Ok(Value::Boolean(v.is_empty())),
+            Value::Vector(v) | Value::List(v) => Ok(Value::Boolean(v.is_empty())),
             Value::Map(m) => Ok(Value::Boolean(m.is_empty())),
             Value::String(s) => Ok(Value::Boolean(s.is_empty())),
             Value::Nil => Ok(Value::Boolean(true)),
@@ -2694,18 +3482,30 @@ impl SecureStandardLibrary {
         }
         match &args[1] {
             Value::Vector(v) => {
-                let mut new_vec = vec![args[0].clone()];
-                new_vec.extend_from_slice(v);
+                let mut new_vec = v.clone();
+                new_vec.push_front(args[0].clone());
                 Ok(Value::Vector(new_vec))
             }
+            // Lists too, so `& rest` arguments (bound as a list) can be extended
+            Value::List(l) => {
+                let mut new_list = l.clone();
+                new_list.push_front(args[0].clone());
+                Ok(Value::List(new_list))
+            }
+            Value::Nil => Ok(Value::List(vec![args[0].clone()].into())),
             _ => Err(RuntimeError::TypeError {
-                expected: "vector".to_string(),
+                expected: "vector, list or nil".to_string(),
                 actual: args[1].type_name().to_string(),
                 operation: "cons".to_string(),
             }),
         }
     }
 
+    /// `(get-in coll path [default])` - follow `path` (a vector of map keys and
+    /// vector/list indices) into nested collections. As in Clojure, any miss along
+    /// the path - an absent key, an out-of-range index, or a step into a
+    /// non-collection such as an integer - yields `default` (nil when omitted)
+    /// instead of an error: `(get-in {:a 1} [:a :b] :missing)` is `:missing`.
     fn get_in(args: Vec<Value>) -> RuntimeResult<Value> {
         let args = args.as_slice();
         if !(2..=3).contains(&args.len()) {
@@ -2718,11 +3518,7 @@ impl SecureStandardLibrary {
 
         let collection = &args[0];
         let path = &args[1];
-        let default = if args.len() == 3 {
-            Some(args[2].clone())
-        } else {
-            None
-        };
+        let default = args.get(2).cloned().unwrap_or(Value::Nil);
 
         let path_vec = match path {
             Value::Vector(v) => v,
@@ -2735,35 +3531,24 @@ impl SecureStandardLibrary {
             }
         };
 
-        let mut current = collection.clone();
+        let mut current = collection;
         for key in path_vec {
-            match (&current, key) {
-                (Value::Map(m), Value::Keyword(k)) => {
-                    if let Some(val) = m.get(&MapKey::Keyword(k.clone())) {
-                        current = val.clone();
-                    } else {
-                        return Ok(default.unwrap_or(Value::Nil));
-                    }
-                }
-                (Value::Map(m), Value::String(s)) => {
-                    if let Some(val) = m.get(&MapKey::String(s.clone())) {
-                        current = val.clone();
-                    } else {
-                        return Ok(default.unwrap_or(Value::Nil));
-                    }
-                }
-                (Value::Vector(v), Value::Integer(i)) => {
-                    if let Some(val) = v.get(*i as usize) {
-                        current = val.clone();
-                    } else {
-                        return Ok(default.unwrap_or(Value::Nil));
-                    }
+            let next = match (current, key) {
+                (Value::Map(m), key) => Self::value_to_map_key(key)
+                    .ok()
+                    .and_then(|map_key| m.get(&map_key)),
+                (Value::Vector(items) | Value::List(items), Value::Integer(i)) => {
+                    usize::try_from(*i).ok().and_then(|i| items.get(i))
                 }
-                _ => return Ok(default.unwrap_or(Value::Nil)),
+                _ => None,
+            };
+            match next {
+                Some(value) => current = value,
+                None => return Ok(default),
             }
         }
 
-        Ok(current)
+        Ok(current.clone())
     }
 
     fn partition(args: Vec<Value>) -> RuntimeResult<Value> {
@@ -2787,7 +3572,7 @@ impl SecureStandardLibrary {
             }
         };
 
-        let collection = match &args[1] {
+        let mut rest = match &args[1] {
             Value::Vector(v) | Value::List(v) => v.clone(),
             _ => {
                 return Err(RuntimeError::TypeError {
@@ -2798,9 +3583,11 @@ impl SecureStandardLibrary {
             }
         };
 
-        let mut result = Vec::new();
-        for chunk in collection.chunks(size) {
-            result.push(Value::Vector(chunk.to_vec()));
+        let mut result = ValueVector::new();
+        while !rest.is_empty() {
+            let tail = rest.split_off(size.min(rest.len()));
+            result.push_back(Value::Vector(rest));
+            rest = tail;
         }
         Ok(Value::Vector(result))
     }
@@ -2828,7 +3615,9 @@ impl SecureStandardLibrary {
                 }
                 let mut result = 1i64;
                 for i in 1..=*n {
-                    result *= i;
+                    result = result
+                        .checked_mul(i)
+                        .ok_or_else(|| Self::integer_overflow("factorial"))?;
                 }
                 Ok(Value::Integer(result))
             }
@@ -2840,6 +3629,8 @@ impl SecureStandardLibrary {
         }
     }
 
+    /// `(length coll)` - alias of `count`. Strings are measured in chars
+    /// (Unicode scalar values), not UTF-8 bytes.
     fn length(args: Vec<Value>) -> RuntimeResult<Value> {
         let args = args.as_slice();
         if args.len() != 1 {
@@ -2850,17 +3641,7 @@ impl SecureStandardLibrary {
             });
         }
 
-        match &args[0] {
-            Value::Vector(v) => Ok(Value::Integer(v.len() as i64)),
-            Value::List(l) => Ok(Value::Integer(l.len() as i64)),
-            Value::String(s) => Ok(Value::Integer(s.len() as i64)),
-            Value::Map(m) => Ok(Value::Integer(m.len() as i64)),
-            _ => Err(RuntimeError::TypeError {
-                expected: "vector, list, string, or map".to_string(),
-                actual: args[0].type_name().to_string(),
-                operation: "length".to_string(),
-            }),
-        }
+        Self::seq_count(&args[0], "length")
     }
 
     fn type_name(args: Vec<Value>) -> RuntimeResult<Value> {
@@ -2905,12 +3686,50 @@ impl SecureStandardLibrary {
             }
         };
         if end < start {
-            return Ok(Value::Vector(vec![]));
+            return Ok(Value::Vector(vec![].into()));
         }
+        Self::check_generated_len(end.abs_diff(start), "range")?;
         let vec = (start..end).map(Value::Integer).collect();
         Ok(Value::Vector(vec))
     }
 
+    fn check_generated_len(len: u64, operation: &str) -> RuntimeResult<()> {
+        if len > MAX_GENERATED_COLLECTION_LEN as u64 {
+            return Err(RuntimeError::InvalidArgument(format!(
+                "{}: {} elements exceeds the limit of {}",
+                operation, len, MAX_GENERATED_COLLECTION_LEN
+            )));
+        }
+        Ok(())
+    }
+
+    /// `(repeat n x)` - a vector holding `n` copies of `x`. `n` must be between 0
+    /// and [`MAX_GENERATED_COLLECTION_LEN`].
+    fn repeat(args: Vec<Value>) -> RuntimeResult<Value> {
+        if args.len() != 2 {
+            return Err(RuntimeError::ArityMismatch {
+                function: "repeat".to_string(),
+                expected: "2".to_string(),
+                actual: args.len(),
+            });
+        }
+        let n = match &args[0] {
+            Value::Integer(n) => *n,
+            other => {
+                return Err(RuntimeError::TypeError {
+                    expected: "Integer".to_string(),
+                    actual: other.type_name().to_string(),
+                    operation: "repeat".to_string(),
+                })
+            }
+        };
+        let n = u64::try_from(n).map_err(|_| {
+            RuntimeError::InvalidArgument(format!("repeat: count must not be negative, got {}", n))
+        })?;
+        Self::check_generated_len(n, "repeat")?;
+        Ok(Value::Vector(vec![args[1].clone(); n as usize].into()))
+    }
+
     fn nth(args: Vec<Value>) -> RuntimeResult<Value> {
         let args = args.as_slice();
         if args.len() < 2 || args.len() > 3 {
@@ -2939,58 +3758,25 @@ impl SecureStandardLibrary {
             None
         };
 
-        match collection {
-            Value::Vector(v) => {
-                if index < 0 || index as usize >= v.len() {
-                    if let Some(default_val) = default {
-                        Ok(default_val.clone())
-                    } else {
-                        Err(RuntimeError::IndexOutOfBounds {
-                            index: index,
-                            length: v.len(),
-                        })
-                    }
-                } else {
-                    Ok(v[index as usize].clone())
-                }
-            }
-            Value::List(list) => {
-                if index < 0 || index as usize >= list.len() {
-                    if let Some(default_val) = default {
-                        Ok(default_val.clone())
-                    } else {
-                        Err(RuntimeError::IndexOutOfBounds {
-                            index: index,
-                            length: list.len(),
-                        })
-                    }
-                } else {
-                    Ok(list[index as usize].clone())
-                }
-            }
-            Value::String(s) => {
-                let chars: Vec<char> = s.chars().collect();
-                if index < 0 || index as usize >= chars.len() {
-                    if let Some(default_val) = default {
-                        Ok(default_val.clone())
-                    } else {
-                        Err(RuntimeError::IndexOutOfBounds {
-                            index: index,
-                            length: chars.len(),
-                        })
-                    }
-                } else {
-                    Ok(Value::String(chars[index as usize].to_string()))
-                }
-            }
-            _ => Err(RuntimeError::TypeError {
-                expected: "vector, list, or string".to_string(),
-                actual: collection.type_name().to_string(),
-                operation: "nth".to_string(),
+        match (Self::seq_nth(collection, index, "nth")?, default) {
+            (Some(value), _) => Ok(value),
+            (None, Some(default_val)) => Ok(default_val.clone()),
+            (None, None) => Err(RuntimeError::IndexOutOfBounds {
+                index,
+                length: Self::seq_len(collection).unwrap_or(0),
             }),
         }
     }
 
+    /// Resolves `index` against `len` elements, counting back from the end
+    /// when negative (`-1` is the last element); None when out of range.
+    fn resolve_index(index: i64, len: usize) -> Option<usize> {
+        let resolved = if index < 0 { len as i64 + index } else { index };
+        (0..len as i64)
+            .contains(&resolved)
+            .then_some(resolved as usize)
+    }
+
     fn value_to_map_key(value: &Value) -> RuntimeResult<MapKey> {
         match value {
             Value::String(s) => Ok(MapKey::String(s.clone())),
@@ -3113,7 +3899,7 @@ impl SecureStandardLibrary {
         match coll {
             Value::Vector(v) => {
                 let mut out = v.clone();
-                out.extend_from_slice(items);
+                out.extend(items.iter().cloned());
                 Ok(Value::Vector(out))
             }
             other => Err(RuntimeError::TypeError {
@@ -3124,6 +3910,219 @@ impl SecureStandardLibrary {
         }
     }
 
+    /// `(into target source)` adds each element of `source` to `target`.
+    /// Vector and list targets append in source order; a map target takes
+    /// `[k v]` pairs or maps (merged, later keys win). A map source yields its
+    /// entries as `[k v]` pairs. RTFS has no set type, so use `distinct` on a
+    /// vector where a set would be used.
+    fn into(args: Vec<Value>) -> RuntimeResult<Value> {
+        if args.len() != 2 {
+            return Err(RuntimeError::ArityMismatch {
+                function: "into".to_string(),
+                expected: "2".to_string(),
+                actual: args.len(),
+            });
+        }
+
+        let items = Self::collection_items(&args[1], "into")?;
+
+        match &args[0] {
+            Value::Vector(v) => {
+                let mut out = v.clone();
+                out.extend(items);
+                Ok(Value::Vector(out))
+            }
+            Value::List(l) => {
+                let mut out = l.clone();
+                out.extend(items);
+                Ok(Value::List(out))
+            }
+            Value::Map(m) => {
+                let mut out = m.clone();
+                for item in items {
+                    match item {
+                        Value::Vector(pair) if pair.len() == 2 => {
+                            out.insert(Self::value_to_map_key(&pair[0])?, pair[1].clone());
+                        }
+                        Value::Map(entries) => out.extend(entries),
+                        other => {
+                            return Err(RuntimeError::TypeError {
+                                expected: "[k v] pair or map".to_string(),
+                                actual: other.type_name().to_string(),
+                                operation: "into".to_string(),
+                            })
+                        }
+                    }
+                }
+                Ok(Value::Map(out))
+            }
+            other => Err(RuntimeError::TypeError {
+                expected: "vector, list or map".to_string(),
+                actual: other.type_name().to_string(),
+                operation: "into".to_string(),
+            }),
+        }
+    }
+
+    /// The elements of a sequence, seen the same way by every sequence builtin:
+    /// a vector or list as-is (borrowed), a string as its characters (one-char
+    /// strings). None for anything else. Use `rebuild_seq` to return a result of
+    /// the input's type, so supporting a new sequence type happens here.
+    fn as_seq(value: &Value) -> Option<Cow<'_, ValueVector>> {
+        match value {
+            Value::Vector(items) | Value::List(items) => Some(Cow::Borrowed(items)),
+            Value::String(s) => Some(Cow::Owned(
+                s.chars().map(|c| Value::String(c.to_string())).collect(),
+            )),
+            _ => None,
+        }
+    }
+
+    /// Like `as_seq`, but a map yields its entries as `[k v]` pairs in map
+    /// iteration order and nil is empty.
+    fn as_seq_or_entries(value: &Value) -> Option<Cow<'_, ValueVector>> {
+        match value {
+            Value::Map(m) => Some(Cow::Owned(
+                m.iter()
+                    .map(|(k, v)| Value::Vector(vec![Self::map_key_to_value(k), v.clone()].into()))
+                    .collect(),
+            )),
+            Value::Nil => Some(Cow::Owned(ValueVector::new())),
+            other => Self::as_seq(other),
+        }
+    }
+
+    /// Number of elements in a sequence; a string counts its chars.
+    fn seq_len(value: &Value) -> Option<usize> {
+        match value {
+            Value::Vector(items) | Value::List(items) => Some(items.len()),
+            Value::String(s) => Some(s.chars().count()),
+            _ => None,
+        }
+    }
+
+    /// Element `index` of a sequence, counting back from the end when negative;
+    /// None when out of range. A string yields just the requested char instead
+    /// of being split into one-char strings first.
+    fn seq_nth(value: &Value, index: i64, operation: &str) -> RuntimeResult<Option<Value>> {
+        match value {
+            Value::String(s) => {
+                let c = if index < 0 {
+                    s.chars().rev().nth((-(index + 1)) as usize)
+                } else {
+                    s.chars().nth(index as usize)
+                };
+                Ok(c.map(|c| Value::String(c.to_string())))
+            }
+            other => {
+                let items = Self::seq_arg(other, operation)?;
+                Ok(Self::resolve_index(index, items.len()).map(|idx| items[idx].clone()))
+            }
+        }
+    }
+
+    /// `as_seq`, or a TypeError naming `operation` for non-sequences.
+    fn seq_arg<'a>(value: &'a Value, operation: &str) -> RuntimeResult<Cow<'a, ValueVector>> {
+        Self::as_seq(value).ok_or_else(|| RuntimeError::TypeError {
+            expected: "vector, list, or string".to_string(),
+            actual: value.type_name().to_string(),
+            operation: operation.to_string(),
+        })
+    }
+
+    /// Builds `items` into the same kind of sequence as `original`: a list stays
+    /// a list and a string is re-joined from its one-char elements.
+    fn rebuild_seq(original: &Value, items: ValueVector) -> Value {
+        match original {
+            Value::List(_) => Value::List(items),
+            Value::String(_) => Value::String(items.iter().filter_map(Value::as_string).collect()),
+            _ => Value::Vector(items),
+        }
+    }
+
+    /// Elements of a sequence or nil (empty); a map yields its entries as
+    /// `[k v]` pairs in map iteration order.
+    fn collection_items(value: &Value, operation: &str) -> RuntimeResult<Vec<Value>> {
+        Self::as_seq_or_entries(value)
+            .map(|items| items.iter().cloned().collect())
+            .ok_or_else(|| RuntimeError::TypeError {
+                expected: "vector, list, string, map or nil".to_string(),
+                actual: value.type_name().to_string(),
+                operation: operation.to_string(),
+            })
+    }
+
+    fn single_arg<'a>(args: &'a [Value], function: &str) -> RuntimeResult<&'a Value> {
+        match args {
+            [arg] => Ok(arg),
+            _ => Err(RuntimeError::ArityMismatch {
+                function: function.to_string(),
+                expected: "1".to_string(),
+                actual: args.len(),
+            }),
+        }
+    }
+
+    /// `(vec coll)` - the elements of `coll` as a vector (`[k v]` pairs for a map,
+    /// one-char strings for a string).
+    fn vec(args: Vec<Value>) -> RuntimeResult<Value> {
+        let coll = Self::single_arg(&args, "vec")?;
+        Ok(Value::Vector(Self::collection_items(coll, "vec")?.into()))
+    }
+
+    /// `(list coll)` - the elements of `coll` as a list (`[k v]` pairs for a map,
+    /// one-char strings for a string).
+    fn list(args: Vec<Value>) -> RuntimeResult<Value> {
+        let coll = Self::single_arg(&args, "list")?;
+        Ok(Value::List(Self::collection_items(coll, "list")?.into()))
+    }
+
+    /// `(seq coll)` - like `list`, but an empty collection yields nil, so
+    /// `(if (seq xs) ...)` tests for elements.
+    fn seq(args: Vec<Value>) -> RuntimeResult<Value> {
+        let coll = Self::single_arg(&args, "seq")?;
+        let items = Self::collection_items(coll, "seq")?;
+        Ok(if items.is_empty() {
+            Value::Nil
+        } else {
+            Value::List(items.into())
+        })
+    }
+
+    /// `(not-empty coll)` - `coll` itself when it has elements, else nil, so
+    /// `(or (not-empty xs) default)` picks a fallback for empty collections.
+    fn not_empty(args: Vec<Value>) -> RuntimeResult<Value> {
+        let coll = Self::single_arg(&args, "not-empty")?;
+        let empty = match coll {
+            Value::Vector(v) | Value::List(v) => v.is_empty(),
+            Value::Map(m) => m.is_empty(),
+            Value::String(s) => s.is_empty(),
+            Value::Nil => true,
+            other => {
+                return Err(RuntimeError::TypeError {
+                    expected: "vector, list, map, string or nil".to_string(),
+                    actual: other.type_name().to_string(),
+                    operation: "not-empty".to_string(),
+                })
+            }
+        };
+        Ok(if empty { Value::Nil } else { coll.clone() })
+    }
+
+    /// `(to-vec m)` - the entries of map `m` as a vector of `[k v]` pairs.
+    fn to_vec(args: Vec<Value>) -> RuntimeResult<Value> {
+        match Self::single_arg(&args, "to-vec")? {
+            coll @ Value::Map(_) => Ok(Value::Vector(
+                Self::collection_items(coll, "to-vec")?.into(),
+            )),
+            other => Err(RuntimeError::TypeError {
+                expected: "map".to_string(),
+                actual: other.type_name().to_string(),
+                operation: "to-vec".to_string(),
+            }),
+        }
+    }
+
     fn abs(args: Vec<Value>) -> RuntimeResult<Value> {
         let args = args.as_slice();
         if args.len() != 1 {
@@ -3135,7 +4134,10 @@ impl SecureStandardLibrary {
         }
 
         match &args[0] {
-            Value::Integer(n) => Ok(Value::Integer(n.abs())),
+            Value::Integer(n) => n
+                .checked_abs()
+                .map(Value::Integer)
+                .ok_or_else(|| Self::integer_overflow("abs")),
             Value::Float(f) => Ok(Value::Float(f.abs())),
             _ => Err(RuntimeError::TypeError {
                 expected: "number".to_string(),
@@ -3145,11 +4147,23 @@ impl SecureStandardLibrary {
         }
     }
 
+    /// `(mod a b)` - floored remainder: the result has the sign of the divisor,
+    /// as in Clojure and Python, so `(mod -7 3)` is `2`.
     fn modulo(args: Vec<Value>) -> RuntimeResult<Value> {
+        Self::remainder(args, "mod", true)
+    }
+
+    /// `(rem a b)` - truncated remainder: the result has the sign of the
+    /// dividend, so `(rem -7 3)` is `-1`.
+    fn rem(args: Vec<Value>) -> RuntimeResult<Value> {
+        Self::remainder(args, "rem", false)
+    }
+
+    fn remainder(args: Vec<Value>, operation: &str, floored: bool) -> RuntimeResult<Value> {
         let args = args.as_slice();
         if args.len() != 2 {
             return Err(RuntimeError::ArityMismatch {
-                function: "mod".to_string(),
+                function: operation.to_string(),
                 expected: "2".to_string(),
                 actual: args.len(),
             });
@@ -3158,36 +4172,33 @@ impl SecureStandardLibrary {
         match (&args[0], &args[1]) {
             (Value::Integer(a), Value::Integer(b)) => {
                 if *b == 0 {
-                    Err(RuntimeError::DivisionByZero)
-                } else {
-                    Ok(Value::Integer(a % b))
+                    return Err(RuntimeError::DivisionByZero);
                 }
-            }
-            (Value::Float(a), Value::Float(b)) => {
-                if *b == 0.0 {
-                    Err(RuntimeError::DivisionByZero)
+                // `wrapping_rem` only wraps for i64::MIN / -1, whose remainder is 0
+                let r = a.wrapping_rem(*b);
+                if floored && r != 0 && (r < 0) != (*b < 0) {
+                    Ok(Value::Integer(r + b))
                 } else {
-                    Ok(Value::Float(a % b))
+                    Ok(Value::Integer(r))
                 }
             }
-            (Value::Integer(a), Value::Float(b)) => {
-                if *b == 0.0 {
-                    Err(RuntimeError::DivisionByZero)
-                } else {
-                    Ok(Value::Float(*a as f64 % b))
+            (Value::Integer(_) | Value::Float(_), Value::Integer(_) | Value::Float(_)) => {
+                let a = Self::number_as_f64(&args[0], operation)?;
+                let b = Self::number_as_f64(&args[1], operation)?;
+                if b == 0.0 {
+                    return Err(RuntimeError::DivisionByZero);
                 }
-            }
-            (Value::Float(a), Value::Integer(b)) => {
-                if *b == 0 {
-                    Err(RuntimeError::DivisionByZero)
+                let r = a % b;
+                if floored && r != 0.0 && (r < 0.0) != (b < 0.0) {
+                    Ok(Value::Float(r + b))
                 } else {
-                    Ok(Value::Float(a % (*b as f64)))
+                    Ok(Value::Float(r))
                 }
             }
             _ => Err(RuntimeError::TypeError {
                 expected: "numbers".to_string(),
                 actual: format!("{}, {}", args[0].type_name(), args[1].type_name()),
-                operation: "mod".to_string(),
+                operation: operation.to_string(),
             }),
         }
     }
@@ -3245,7 +4256,11 @@ impl SecureStandardLibrary {
                     // For negative exponents, return float result
                     Ok(Value::Float((*base as f64).powf(*exp as f64)))
                 } else {
-                    Ok(Value::Integer(base.pow(*exp as u32)))
+                    u32::try_from(*exp)
+                        .ok()
+                        .and_then(|exp| base.checked_pow(exp))
+                        .map(Value::Integer)
+                        .ok_or_else(|| Self::integer_overflow("pow"))
                 }
             }
             (Value::Float(base), Value::Float(exp)) => Ok(Value::Float(base.powf(*exp))),
@@ -3261,89 +4276,257 @@ impl SecureStandardLibrary {
         }
     }
 
-    fn concat(args: Vec<Value>) -> RuntimeResult<Value> {
-        if args.is_empty() {
-            return Ok(Value::Vector(vec![]));
+    fn number_as_f64(value: &Value, operation: &str) -> RuntimeResult<f64> {
+        match value {
+            Value::Integer(i) => Ok(*i as f64),
+            Value::Float(f) => Ok(*f),
+            other => Err(RuntimeError::TypeError {
+                expected: "number".to_string(),
+                actual: other.type_name().to_string(),
+                operation: operation.to_string(),
+            }),
         }
+    }
 
-        let mut result = Vec::new();
+    /// `(clamp x lo hi)` - `x` bounded to `[lo, hi]`. Integer result when all
+    /// arguments are integers, float otherwise. Errors if `lo > hi`.
+    fn clamp(args: Vec<Value>) -> RuntimeResult<Value> {
+        if args.len() != 3 {
+            return Err(RuntimeError::ArityMismatch {
+                function: "clamp".to_string(),
+                expected: "3".to_string(),
+                actual: args.len(),
+            });
+        }
 
-        for arg in &args {
-            match arg {
-                Value::Vector(v) => {
-                    result.extend(v.iter().cloned());
-                }
-                Value::String(s) => {
-                    // For strings, treat each character as an element
-                    for c in s.chars() {
-                        result.push(Value::String(c.to_string()));
-                    }
-                }
-                other => {
-                    // For single values, just add them
-                    result.push(other.clone());
-                }
+        if let (Value::Integer(x), Value::Integer(lo), Value::Integer(hi)) =
+            (&args[0], &args[1], &args[2])
+        {
+            if lo > hi {
+                return Err(RuntimeError::InvalidArgument(format!(
+                    "clamp: lower bound {} is greater than upper bound {}",
+                    lo, hi
+                )));
             }
+            return Ok(Value::Integer(*x.max(lo).min(hi)));
         }
 
-        Ok(Value::Vector(result))
+        let x = Self::number_as_f64(&args[0], "clamp")?;
+        let lo = Self::number_as_f64(&args[1], "clamp")?;
+        let hi = Self::number_as_f64(&args[2], "clamp")?;
+        if lo > hi {
+            return Err(RuntimeError::InvalidArgument(format!(
+                "clamp: lower bound {} is greater than upper bound {}",
+                lo, hi
+            )));
+        }
+        Ok(Value::Float(x.max(lo).min(hi)))
     }
 
-    fn subvec(args: Vec<Value>) -> RuntimeResult<Value> {
-        let args = args.as_slice();
-        if args.len() < 2 || args.len() > 3 {
+    /// `(lerp a b t)` - `a + (b - a) * t` as a float; `t` is not clamped.
+    fn lerp(args: Vec<Value>) -> RuntimeResult<Value> {
+        if args.len() != 3 {
             return Err(RuntimeError::ArityMismatch {
-                function: "subvec".to_string(),
-                expected: "2 or 3".to_string(),
+                function: "lerp".to_string(),
+                expected: "3".to_string(),
                 actual: args.len(),
             });
         }
 
-        let vector = match &args[0] {
-            Value::Vector(v) => v,
-            _ => {
-                return Err(RuntimeError::TypeError {
-                    expected: "vector".to_string(),
-                    actual: args[0].type_name().to_string(),
-                    operation: "subvec".to_string(),
-                })
-            }
-        };
+        let a = Self::number_as_f64(&args[0], "lerp")?;
+        let b = Self::number_as_f64(&args[1], "lerp")?;
+        let t = Self::number_as_f64(&args[2], "lerp")?;
+        Ok(Value::Float(a + (b - a) * t))
+    }
 
-        let start = match &args[1] {
-            Value::Integer(i) => *i as usize,
-            _ => {
+    /// Elements of the single collection argument of a stats function; errors
+    /// with `InvalidArgument` when the collection is empty.
+    fn stats_items<'a>(function: &str, args: &'a [Value]) -> RuntimeResult<&'a ValueVector> {
+        if args.len() != 1 {
+            return Err(RuntimeError::ArityMismatch {
+                function: function.to_string(),
+                expected: "1".to_string(),
+                actual: args.len(),
+            });
+        }
+        let items = match &args[0] {
+            Value::Vector(v) | Value::List(v) => v,
+            other => {
                 return Err(RuntimeError::TypeError {
-                    expected: "integer".to_string(),
-                    actual: args[1].type_name().to_string(),
-                    operation: "subvec start index".to_string(),
+                    expected: "vector or list".to_string(),
+                    actual: other.type_name().to_string(),
+                    operation: function.to_string(),
                 })
             }
         };
+        if items.is_empty() {
+            return Err(RuntimeError::InvalidArgument(format!(
+                "{} of an empty collection is undefined",
+                function
+            )));
+        }
+        Ok(items)
+    }
 
-        let end = if args.len() == 3 {
-            match &args[2] {
-                Value::Integer(i) => *i as usize,
-                _ => {
-                    return Err(RuntimeError::TypeError {
-                        expected: "integer".to_string(),
-                        actual: args[2].type_name().to_string(),
-                        operation: "subvec end index".to_string(),
-                    })
-                }
-            }
-        } else {
-            vector.len()
-        };
+    /// Numeric elements of a stats function argument as floats.
+    fn stats_numbers(function: &str, args: &[Value]) -> RuntimeResult<Vec<f64>> {
+        Self::stats_items(function, args)?
+            .iter()
+            .map(|v| Self::number_as_f64(v, function))
+            .collect()
+    }
 
-        if start > vector.len() || end > vector.len() || start > end {
+    /// `(mean coll)` - arithmetic mean as a float
+    fn mean(args: Vec<Value>) -> RuntimeResult<Value> {
+        let numbers = Self::stats_numbers("mean", &args)?;
+        Ok(Value::Float(
+            numbers.iter().sum::<f64>() / numbers.len() as f64,
+        ))
+    }
+
+    /// `(median coll)` - middle value as a float; the average of the two middle
+    /// values for even-length collections
+    fn median(args: Vec<Value>) -> RuntimeResult<Value> {
+        let mut numbers = Self::stats_numbers("median", &args)?;
+        numbers.sort_by(|a, b| a.total_cmp(b));
+        let mid = numbers.len() / 2;
+        let median = if numbers.len() % 2 == 0 {
+            (numbers[mid - 1] + numbers[mid]) / 2.0
+        } else {
+            numbers[mid]
+        };
+        Ok(Value::Float(median))
+    }
+
+    /// `(mode coll)` - most frequent element (any type); ties go to the element
+    /// that appears first
+    fn mode(args: Vec<Value>) -> RuntimeResult<Value> {
+        let items = Self::stats_items("mode", &args)?;
+        // Frequencies of each distinct element, with the index it first appears at
+        #[allow(clippy::mutable_key_type)] // see FrequencyKey
+        let mut frequencies: HashMap<FrequencyKey, (usize, usize)> = HashMap::new();
+        for (index, item) in items.iter().enumerate() {
+            frequencies
+                .entry(FrequencyKey(item))
+                .or_insert((0, index))
+                .0 += 1;
+        }
+        let (most_frequent, _) = frequencies
+            .into_iter()
+            .max_by(|(_, (count_a, first_a)), (_, (count_b, first_b))| {
+                count_a.cmp(count_b).then(first_b.cmp(first_a))
+            })
+            .expect("stats_items rejects empty collections");
+        Ok(most_frequent.0.clone())
+    }
+
+    /// `(std-dev coll)` - population standard deviation as a float
+    fn std_dev(args: Vec<Value>) -> RuntimeResult<Value> {
+        let numbers = Self::stats_numbers("std-dev", &args)?;
+        let n = numbers.len() as f64;
+        let mean = numbers.iter().sum::<f64>() / n;
+        let variance = numbers.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n;
+        Ok(Value::Float(variance.sqrt()))
+    }
+
+    /// `(concat & colls)` - the elements of every vector, list or string (as
+    /// one-character strings) in order, as a single vector. nil contributes
+    /// nothing; any other argument is a type error. The combined length is
+    /// capped at [`MAX_GENERATED_COLLECTION_LEN`].
+    fn concat(args: Vec<Value>) -> RuntimeResult<Value> {
+        let mut total: u64 = 0;
+        for arg in &args {
+            total += match arg {
+                Value::Vector(v) | Value::List(v) => v.len() as u64,
+                Value::String(s) => s.chars().count() as u64,
+                Value::Nil => 0,
+                other => {
+                    return Err(RuntimeError::TypeError {
+                        expected: "vector, list, string or nil".to_string(),
+                        actual: other.type_name().to_string(),
+                        operation: "concat".to_string(),
+                    })
+                }
+            };
+        }
+        Self::check_generated_len(total, "concat")?;
+
+        let mut result = ValueVector::new();
+        for arg in args {
+            match arg {
+                Value::Vector(v) | Value::List(v) => result.append(v),
+                Value::String(s) => result.extend(s.chars().map(|c| Value::String(c.to_string()))),
+                _ => {}
+            }
+        }
+        Ok(Value::Vector(result))
+    }
+
+    /// `(subvec v start [end])` - the elements of vector `v` from `start` up to
+    /// (not including) `end`, which defaults to the length and counts back from
+    /// the end when negative. Requires `0 <= start <= end <= (count v)`.
+    fn subvec(args: Vec<Value>) -> RuntimeResult<Value> {
+        let args = args.as_slice();
+        if args.len() < 2 || args.len() > 3 {
+            return Err(RuntimeError::ArityMismatch {
+                function: "subvec".to_string(),
+                expected: "2 or 3".to_string(),
+                actual: args.len(),
+            });
+        }
+
+        let vector = match &args[0] {
+            Value::Vector(v) => v,
+            _ => {
+                return Err(RuntimeError::TypeError {
+                    expected: "vector".to_string(),
+                    actual: args[0].type_name().to_string(),
+                    operation: "subvec".to_string(),
+                })
+            }
+        };
+
+        let start = match &args[1] {
+            Value::Integer(i) => *i,
+            _ => {
+                return Err(RuntimeError::TypeError {
+                    expected: "integer".to_string(),
+                    actual: args[1].type_name().to_string(),
+                    operation: "subvec start index".to_string(),
+                })
+            }
+        };
+
+        // A negative end counts back from the end of the vector, like `nth`
+        let len = vector.len() as i64;
+        let end = match args.get(2) {
+            None => len,
+            Some(Value::Integer(i)) if *i < 0 => len + i,
+            Some(Value::Integer(i)) => *i,
+            Some(other) => {
+                return Err(RuntimeError::TypeError {
+                    expected: "integer".to_string(),
+                    actual: other.type_name().to_string(),
+                    operation: "subvec end index".to_string(),
+                })
+            }
+        };
+
+        if start < 0 || start > len {
             return Err(RuntimeError::IndexOutOfBounds {
-                index: start as i64,
+                index: start,
                 length: vector.len(),
             });
         }
+        if end < start || end > len {
+            return Err(RuntimeError::IndexOutOfBounds {
+                index: end,
+                length: vector.len(),
+            });
+        }
+        let (start, end) = (start as usize, end as usize);
 
-        let subvector = vector[start..end].to_vec();
+        let subvector = vector.skip(start).take(end - start);
         Ok(Value::Vector(subvector))
     }
 
@@ -3405,66 +4588,120 @@ impl SecureStandardLibrary {
         }
     }
 
-    // Collection utility functions
-    fn reverse(args: Vec<Value>) -> RuntimeResult<Value> {
+    fn trim_start(args: Vec<Value>) -> RuntimeResult<Value> {
         if args.len() != 1 {
             return Err(RuntimeError::ArityMismatch {
-                function: "reverse".to_string(),
+                function: "trim-start".to_string(),
                 expected: "1".to_string(),
                 actual: args.len(),
             });
         }
 
         match &args[0] {
-            Value::Vector(v) => {
-                let mut reversed = v.clone();
-                reversed.reverse();
-                Ok(Value::Vector(reversed))
-            }
-            Value::String(s) => {
-                let reversed: String = s.chars().rev().collect();
-                Ok(Value::String(reversed))
-            }
+            Value::String(s) => Ok(Value::String(s.trim_start().to_string())),
             _ => Err(RuntimeError::TypeError {
-                expected: "vector or string".to_string(),
+                expected: "string".to_string(),
                 actual: args[0].type_name().to_string(),
-                operation: "reverse".to_string(),
+                operation: "trim-start".to_string(),
             }),
         }
     }
 
-    fn last(args: Vec<Value>) -> RuntimeResult<Value> {
+    fn trim_end(args: Vec<Value>) -> RuntimeResult<Value> {
         if args.len() != 1 {
             return Err(RuntimeError::ArityMismatch {
-                function: "last".to_string(),
+                function: "trim-end".to_string(),
                 expected: "1".to_string(),
                 actual: args.len(),
             });
         }
 
         match &args[0] {
-            Value::Vector(v) => {
-                if v.is_empty() {
-                    Ok(Value::Nil)
-                } else {
-                    Ok(v.last().unwrap().clone())
-                }
-            }
-            Value::String(s) => {
-                if s.is_empty() {
-                    Ok(Value::String("".to_string()))
-                } else {
-                    Ok(Value::String(s.chars().last().unwrap().to_string()))
-                }
-            }
+            Value::String(s) => Ok(Value::String(s.trim_end().to_string())),
             _ => Err(RuntimeError::TypeError {
-                expected: "vector or string".to_string(),
+                expected: "string".to_string(),
+                actual: args[0].type_name().to_string(),
+                operation: "trim-end".to_string(),
+            }),
+        }
+    }
+
+    /// `(trim-chars s chars)` strips any character contained in `chars` from both ends of `s`.
+    fn trim_chars(args: Vec<Value>) -> RuntimeResult<Value> {
+        if args.len() != 2 {
+            return Err(RuntimeError::ArityMismatch {
+                function: "trim-chars".to_string(),
+                expected: "2".to_string(),
+                actual: args.len(),
+            });
+        }
+
+        match (&args[0], &args[1]) {
+            (Value::String(s), Value::String(chars)) => Ok(Value::String(
+                s.trim_matches(|c: char| chars.contains(c)).to_string(),
+            )),
+            (Value::String(_), other) | (other, _) => Err(RuntimeError::TypeError {
+                expected: "string".to_string(),
+                actual: other.type_name().to_string(),
+                operation: "trim-chars".to_string(),
+            }),
+        }
+    }
+
+    /// `(split-lines s)` splits on `\n` or `\r\n`, dropping the terminators.
+    /// A trailing newline does not produce an empty last line, matching `read-lines`.
+    fn split_lines(args: Vec<Value>) -> RuntimeResult<Value> {
+        if args.len() != 1 {
+            return Err(RuntimeError::ArityMismatch {
+                function: "split-lines".to_string(),
+                expected: "1".to_string(),
+                actual: args.len(),
+            });
+        }
+
+        match &args[0] {
+            Value::String(s) => Ok(Value::Vector(
+                s.lines()
+                    .map(|line| Value::String(line.to_string()))
+                    .collect(),
+            )),
+            _ => Err(RuntimeError::TypeError {
+                expected: "string".to_string(),
                 actual: args[0].type_name().to_string(),
-                operation: "last".to_string(),
+                operation: "split-lines".to_string(),
             }),
         }
     }
 
+    // Collection utility functions
+    fn reverse(args: Vec<Value>) -> RuntimeResult<Value> {
+        if args.len() != 1 {
+            return Err(RuntimeError::ArityMismatch {
+                function: "reverse".to_string(),
+                expected: "1".to_string(),
+                actual: args.len(),
+            });
+        }
+
+        let items = Self::seq_arg(&args[0], "reverse")?;
+        Ok(Self::rebuild_seq(
+            &args[0],
+            items.iter().rev().cloned().collect(),
+        ))
+    }
+
+    fn last(args: Vec<Value>) -> RuntimeResult<Value> {
+        if args.len() != 1 {
+            return Err(RuntimeError::ArityMismatch {
+                function: "last".to_string(),
+                expected: "1".to_string(),
+                actual: args.len(),
+            });
+        }
+
+        Ok(Self::seq_nth(&args[0], -1, "last")?.unwrap_or(Value::Nil))
+    }
+
     fn take(args: Vec<Value>) -> RuntimeResult<Value> {
         if args.len() != 2 {
             return Err(RuntimeError::ArityMismatch {
@@ -3474,8 +4711,9 @@ impl SecureStandardLibrary {
             });
         }
 
+        // Negative counts behave like zero
         let count = match &args[0] {
-            Value::Integer(n) => *n as usize,
+            Value::Integer(n) => (*n).max(0) as usize,
             _ => {
                 return Err(RuntimeError::TypeError {
                     expected: "integer".to_string(),
@@ -3485,21 +4723,11 @@ impl SecureStandardLibrary {
             }
         };
 
-        match &args[1] {
-            Value::Vector(v) => {
-                let taken: Vec<Value> = v.iter().take(count).cloned().collect();
-                Ok(Value::Vector(taken))
-            }
-            Value::String(s) => {
-                let taken: String = s.chars().take(count).collect();
-                Ok(Value::String(taken))
-            }
-            _ => Err(RuntimeError::TypeError {
-                expected: "vector or string".to_string(),
-                actual: args[1].type_name().to_string(),
-                operation: "take".to_string(),
-            }),
-        }
+        let items = Self::seq_arg(&args[1], "take")?;
+        Ok(Self::rebuild_seq(
+            &args[1],
+            items.take(count.min(items.len())),
+        ))
     }
 
     fn drop(args: Vec<Value>) -> RuntimeResult<Value> {
@@ -3511,8 +4739,9 @@ impl SecureStandardLibrary {
             });
         }
 
+        // Negative counts behave like zero
         let count = match &args[0] {
-            Value::Integer(n) => *n as usize,
+            Value::Integer(n) => (*n).max(0) as usize,
             _ => {
                 return Err(RuntimeError::TypeError {
                     expected: "integer".to_string(),
@@ -3522,63 +4751,115 @@ impl SecureStandardLibrary {
             }
         };
 
-        match &args[1] {
-            Value::Vector(v) => {
-                let dropped: Vec<Value> = v.iter().skip(count).cloned().collect();
-                Ok(Value::Vector(dropped))
-            }
-            Value::String(s) => {
-                let dropped: String = s.chars().skip(count).collect();
-                Ok(Value::String(dropped))
+        let items = Self::seq_arg(&args[1], "drop")?;
+        Ok(Self::rebuild_seq(
+            &args[1],
+            items.skip(count.min(items.len())),
+        ))
+    }
+
+    /// Take the last `n` elements of a vector, list or string.
+    /// Zero or negative counts yield an empty collection; counts larger than
+    /// the collection yield the whole collection.
+    fn take_last(args: Vec<Value>) -> RuntimeResult<Value> {
+        if args.len() != 2 {
+            return Err(RuntimeError::ArityMismatch {
+                function: "take-last".to_string(),
+                expected: "2".to_string(),
+                actual: args.len(),
+            });
+        }
+
+        let count = match &args[0] {
+            Value::Integer(n) => (*n).max(0) as usize,
+            _ => {
+                return Err(RuntimeError::TypeError {
+                    expected: "integer".to_string(),
+                    actual: args[0].type_name().to_string(),
+                    operation: "take-last count".to_string(),
+                })
             }
-            _ => Err(RuntimeError::TypeError {
-                expected: "vector or string".to_string(),
-                actual: args[1].type_name().to_string(),
-                operation: "drop".to_string(),
-            }),
+        };
+
+        let items = Self::seq_arg(&args[1], "take-last")?;
+        let start = items.len().saturating_sub(count);
+        Ok(Self::rebuild_seq(&args[1], items.skip(start)))
+    }
+
+    /// Drop the last `n` elements of a vector, list or string.
+    /// Zero or negative counts yield the whole collection; counts larger than
+    /// the collection yield an empty collection.
+    fn drop_last(args: Vec<Value>) -> RuntimeResult<Value> {
+        if args.len() != 2 {
+            return Err(RuntimeError::ArityMismatch {
+                function: "drop-last".to_string(),
+                expected: "2".to_string(),
+                actual: args.len(),
+            });
         }
+
+        let count = match &args[0] {
+            Value::Integer(n) => (*n).max(0) as usize,
+            _ => {
+                return Err(RuntimeError::TypeError {
+                    expected: "integer".to_string(),
+                    actual: args[0].type_name().to_string(),
+                    operation: "drop-last count".to_string(),
+                })
+            }
+        };
+
+        let items = Self::seq_arg(&args[1], "drop-last")?;
+        let end = items.len().saturating_sub(count);
+        Ok(Self::rebuild_seq(&args[1], items.take(end)))
     }
 
-    fn distinct(args: Vec<Value>) -> RuntimeResult<Value> {
-        if args.len() != 1 {
+    /// `(interpose sep coll)` - returns a vector with `sep` between each element
+    /// of a vector, list or string. Empty and single-element collections are
+    /// returned unchanged.
+    fn interpose(args: Vec<Value>) -> RuntimeResult<Value> {
+        if args.len() != 2 {
             return Err(RuntimeError::ArityMismatch {
-                function: "distinct".to_string(),
-                expected: "1".to_string(),
+                function: "interpose".to_string(),
+                expected: "2".to_string(),
                 actual: args.len(),
             });
         }
 
-        match &args[0] {
-            Value::Vector(v) => {
-                let mut distinct = Vec::new();
+        let separator = &args[0];
+        let items = Self::seq_arg(&args[1], "interpose")?;
 
-                for item in v {
-                    if !distinct.contains(item) {
-                        distinct.push(item.clone());
-                    }
-                }
+        if items.len() < 2 {
+            return Ok(args[1].clone());
+        }
 
-                Ok(Value::Vector(distinct))
+        let mut result = Vec::with_capacity(items.len() * 2 - 1);
+        for (i, item) in items.iter().enumerate() {
+            if i > 0 {
+                result.push(separator.clone());
             }
-            Value::String(s) => {
-                let mut seen = std::collections::HashSet::new();
-                let mut distinct = String::new();
+            result.push(item.clone());
+        }
+        Ok(Value::Vector(result.into()))
+    }
 
-                for c in s.chars() {
-                    if !seen.contains(&c) {
-                        seen.insert(c);
-                        distinct.push(c);
-                    }
-                }
+    fn distinct(args: Vec<Value>) -> RuntimeResult<Value> {
+        if args.len() != 1 {
+            return Err(RuntimeError::ArityMismatch {
+                function: "distinct".to_string(),
+                expected: "1".to_string(),
+                actual: args.len(),
+            });
+        }
 
-                Ok(Value::String(distinct))
+        let items = Self::seq_arg(&args[0], "distinct")?;
+        let mut distinct = ValueVector::new();
+        for item in items.iter() {
+            if !distinct.contains(item) {
+                distinct.push_back(item.clone());
             }
-            _ => Err(RuntimeError::TypeError {
-                expected: "vector or string".to_string(),
-                actual: args[0].type_name().to_string(),
-                operation: "distinct".to_string(),
-            }),
         }
+        Ok(Self::rebuild_seq(&args[0], distinct))
     }
 
     // Functional predicate functions
@@ -3604,6 +4885,7 @@ impl SecureStandardLibrary {
                     let outcome = evaluator.evaluate(&crate::ast::Expression::FunctionCall {
                         callee: Box::new(Expression::try_from(predicate.clone())?),
                         arguments: vec![Expression::try_from(item.clone())?],
+                        span: Span::unknown(),
                     })?;
 
                     // Unwrap ExecutionOutcome produced by evaluator. If a host-call was requested
@@ -3644,6 +4926,7 @@ impl SecureStandardLibrary {
                     let outcome = evaluator.evaluate(&crate::ast::Expression::FunctionCall {
                         callee: Box::new(Expression::try_from(predicate.clone())?),
                         arguments: vec![Expression::try_from(char_value)?],
+                        span: Span::unknown(),
                     })?;
 
                     let result = match outcome {
@@ -3706,6 +4989,7 @@ impl SecureStandardLibrary {
                     let outcome = evaluator.evaluate(&crate::ast::Expression::FunctionCall {
                         callee: Box::new(Expression::try_from(predicate.clone())?),
                         arguments: vec![Expression::try_from(item.clone())?],
+                        span: Span::unknown(),
                     })?;
 
                     let result = match outcome {
@@ -3744,6 +5028,7 @@ impl SecureStandardLibrary {
                     let outcome = evaluator.evaluate(&crate::ast::Expression::FunctionCall {
                         callee: Box::new(Expression::try_from(predicate.clone())?),
                         arguments: vec![Expression::try_from(char_value)?],
+                        span: Span::unknown(),
                     })?;
 
                     let result = match outcome {
@@ -3784,6 +5069,25 @@ impl SecureStandardLibrary {
         }
     }
 
+    /// The integer whose parity `even?`/`odd?` test. Whole floats such as `4.0`
+    /// (common in parsed JSON) count as integers; a fractional float has no
+    /// parity and is rejected instead of being truncated.
+    pub(crate) fn parity_operand(value: &Value, operation: &str) -> RuntimeResult<i64> {
+        match value {
+            Value::Integer(n) => Ok(*n),
+            Value::Float(f) if f.fract() == 0.0 && f.abs() < i64::MAX as f64 => Ok(*f as i64),
+            Value::Float(f) => Err(RuntimeError::InvalidArgument(format!(
+                "{} expects an integer, got {}",
+                operation, f
+            ))),
+            other => Err(RuntimeError::TypeError {
+                expected: "number".to_string(),
+                actual: other.type_name().to_string(),
+                operation: operation.to_string(),
+            }),
+        }
+    }
+
     fn even_p(args: Vec<Value>) -> RuntimeResult<Value> {
         if args.len() != 1 {
             return Err(RuntimeError::ArityMismatch {
@@ -3793,19 +5097,8 @@ impl SecureStandardLibrary {
             });
         }
 
-        let n = match &args[0] {
-            Value::Integer(i) => *i,
-            Value::Float(f) => *f as i64,
-            other => {
-                return Err(RuntimeError::TypeError {
-                    expected: "number".to_string(),
-                    actual: other.type_name().to_string(),
-                    operation: "even?".to_string(),
-                });
-            }
-        };
-
-        Ok(Value::Boolean(n % 2 == 0))
+        let n = Self::parity_operand(&args[0], "even?")?;
+        Ok(Value::Boolean(n.rem_euclid(2) == 0))
     }
 
     fn odd_p(args: Vec<Value>) -> RuntimeResult<Value> {
@@ -3817,15 +5110,9 @@ impl SecureStandardLibrary {
             });
         }
 
-        match &args[0] {
-            Value::Integer(n) => Ok(Value::Boolean(n % 2 == 1)),
-            Value::Float(f) => Ok(Value::Boolean((*f as i64) % 2 == 1)),
-            other => Err(RuntimeError::TypeError {
-                expected: "number".to_string(),
-                actual: other.type_name().to_string(),
-                operation: "odd?".to_string(),
-            }),
-        }
+        // `n % 2 == 1` would miss negative odd numbers, since `-3 % 2 == -1`
+        let n = Self::parity_operand(&args[0], "odd?")?;
+        Ok(Value::Boolean(n.rem_euclid(2) == 1))
     }
 
     fn contains_p(args: Vec<Value>) -> RuntimeResult<Value> {
@@ -3867,6 +5154,62 @@ impl SecureStandardLibrary {
         }
     }
 
+    /// `(compare a b)` - `-1`, `0` or `1` as `a` sorts before, with or after `b`,
+    /// following the total order documented on [`Value::compare`].
+    fn compare(args: Vec<Value>) -> RuntimeResult<Value> {
+        if args.len() != 2 {
+            return Err(RuntimeError::ArityMismatch {
+                function: "compare".to_string(),
+                expected: "2".to_string(),
+                actual: args.len(),
+            });
+        }
+        Ok(Value::Integer(match args[0].compare(&args[1]) {
+            std::cmp::Ordering::Less => -1,
+            std::cmp::Ordering::Equal => 0,
+            std::cmp::Ordering::Greater => 1,
+        }))
+    }
+
+    /// Ordering class of a value for sorting; `None` for nil, which sorts first
+    /// and mixes with anything. Integers and floats share the numeric class.
+    fn sort_class(value: &Value) -> Option<&'static str> {
+        match value {
+            Value::Nil => None,
+            Value::Integer(_) | Value::Float(_) => Some("number"),
+            other => Some(other.type_name()),
+        }
+    }
+
+    /// Sorting orders values with `Value::compare`, which ranks unrelated types
+    /// by an arbitrary fixed order. `sort`/`sort-by` reject such mixes instead
+    /// (e.g. `[1 "a"]`); only the top-level elements (or keys) are checked.
+    pub(crate) fn check_sortable<'a>(
+        values: impl IntoIterator<Item = &'a Value>,
+        operation: &str,
+    ) -> RuntimeResult<()> {
+        let mut first: Option<(&'static str, &Value)> = None;
+        for value in values {
+            let Some(class) = Self::sort_class(value) else {
+                continue;
+            };
+            match first {
+                None => first = Some((class, value)),
+                Some((expected, seen)) if expected != class => {
+                    return Err(RuntimeError::TypeError {
+                        expected: format!("values comparable with {}", seen.type_name()),
+                        actual: value.type_name().to_string(),
+                        operation: operation.to_string(),
+                    })
+                }
+                Some(_) => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// `(sort coll)` - ascending order. The sort is stable: equal elements
+    /// (including `1` and `1.0`) keep their input order.
     fn sort(args: Vec<Value>) -> RuntimeResult<Value> {
         if args.len() != 1 {
             return Err(RuntimeError::ArityMismatch {
@@ -3878,6 +5221,7 @@ impl SecureStandardLibrary {
 
         match &args[0] {
             Value::Vector(v) => {
+                Self::check_sortable(v, "sort")?;
                 let mut sorted = v.clone();
                 sorted.sort_by(|a, b| a.compare(b));
                 Ok(Value::Vector(sorted))
@@ -3895,12 +5239,61 @@ impl SecureStandardLibrary {
         }
     }
 
+    /// `(sort-by key-fn coll)` - ascending order of `(key-fn x)`. Stable like
+    /// `sort`: elements with equal keys keep their input order.
+    fn sort_by(
+        args: Vec<Value>,
+        evaluator: &Evaluator,
+        env: &mut Environment,
+    ) -> RuntimeResult<Value> {
+        if args.len() != 2 {
+            return Err(RuntimeError::ArityMismatch {
+                function: "sort-by".to_string(),
+                expected: "2".to_string(),
+                actual: args.len(),
+            });
+        }
+
+        let elements = match &args[1] {
+            Value::Vector(items) | Value::List(items) => items.clone(),
+            Value::Nil => Vec::new().into(),
+            other => {
+                return Err(RuntimeError::TypeError {
+                    expected: "vector, list or nil".to_string(),
+                    actual: other.type_name().to_string(),
+                    operation: "sort-by".to_string(),
+                })
+            }
+        };
+
+        let mut keyed = Vec::with_capacity(elements.len());
+        for element in elements {
+            let key = Self::call_closure(
+                evaluator,
+                &args[0],
+                std::slice::from_ref(&element),
+                env,
+                "sort-by",
+            )?;
+            keyed.push((key, element));
+        }
+        Self::check_sortable(keyed.iter().map(|(key, _)| key), "sort-by")?;
+        keyed.sort_by(|a, b| a.0.compare(&b.0));
+
+        let sorted: ValueVector = keyed.into_iter().map(|(_, element)| element).collect();
+        Ok(match &args[1] {
+            Value::List(_) => Value::List(sorted),
+            _ => Value::Vector(sorted),
+        })
+    }
+
+    /// `(merge m1 m2 ...)` - shallow merge of maps, later keys win; nil arguments are ignored.
     fn merge(args: Vec<Value>) -> RuntimeResult<Value> {
         if args.is_empty() {
-            return Ok(Value::Map(HashMap::new()));
+            return Ok(Value::Map(ValueMap::new()));
         }
 
-        let mut out: HashMap<MapKey, Value> = HashMap::new();
+        let mut out = ValueMap::new();
         for arg in args {
             match arg {
                 Value::Map(m) => {
@@ -3908,9 +5301,11 @@ impl SecureStandardLibrary {
                         out.insert(k, v);
                     }
                 }
+                // Skipped, so `(merge base (when cond extra))` works
+                Value::Nil => {}
                 other => {
                     return Err(RuntimeError::TypeError {
-                        expected: "map".into(),
+                        expected: "map or nil".into(),
                         actual: other.type_name().into(),
                         operation: "merge".into(),
                     });
@@ -3942,7 +5337,7 @@ impl SecureStandardLibrary {
                     };
                     out.push(v);
                 }
-                Ok(Value::Vector(out))
+                Ok(Value::Vector(out.into()))
             }
             other => Err(RuntimeError::TypeError {
                 expected: "map".to_string(),
@@ -3967,7 +5362,7 @@ impl SecureStandardLibrary {
                 for (_k, v) in map.iter() {
                     res.push(v.clone());
                 }
-                Ok(Value::Vector(res))
+                Ok(Value::Vector(res.into()))
             }
             other => Err(RuntimeError::TypeError {
                 expected: "map".to_string(),
@@ -4014,24 +5409,8 @@ impl SecureStandardLibrary {
                 Value::Function(Function::BuiltinWithContext(builtin_func)) => {
                     (builtin_func.func)(func_args, evaluator, env)?
                 }
-                Value::Function(Function::Closure(closure)) => {
-                    let mut func_env = Environment::with_parent(closure.env.clone());
-                    func_env.define(&closure.params[0], Value::Integer(index as i64));
-                    func_env.define(&closure.params[1], item.clone());
-                    match evaluator.eval_expr(&closure.body, &mut func_env)? {
-                        ExecutionOutcome::Complete(v) => v,
-                        ExecutionOutcome::RequiresHost(_hc) => {
-                            return Err(RuntimeError::Generic(
-                                "Host call required in map-indexed closure".into(),
-                            ))
-                        }
-                        #[cfg(feature = "effect-boundary")]
-                        ExecutionOutcome::RequiresHost(_) => {
-                            return Err(RuntimeError::Generic(
-                                "Host effect required in map-indexed closure".to_string(),
-                            ))
-                        }
-                    }
+                Value::Function(Function::Closure(_)) => {
+                    Self::call_closure(evaluator, function, &func_args, env, "map-indexed")?
                 }
                 _ => {
                     return Err(RuntimeError::TypeError {
@@ -4043,7 +5422,7 @@ impl SecureStandardLibrary {
             };
             result.push(mapped_value);
         }
-        Ok(Value::Vector(result))
+        Ok(Value::Vector(result.into()))
     }
 
     fn numbers(args: Vec<Value>) -> RuntimeResult<Value> {
@@ -4069,11 +5448,11 @@ impl SecureStandardLibrary {
         let end_int = end as i64;
 
         if end_int < start_int {
-            return Ok(Value::Vector(vec![]));
+            return Ok(Value::Vector(vec![].into()));
         }
 
         let numbers: Vec<Value> = (start_int..=end_int).map(Value::Integer).collect();
 
-        Ok(Value::Vector(numbers))
+        Ok(Value::Vector(numbers.into()))
     }
 }
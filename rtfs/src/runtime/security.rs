@@ -209,8 +209,12 @@ pub enum SecurityLevel {
 pub struct RuntimeContext {
     /// Security level for this execution
     pub security_level: SecurityLevel,
-    /// Allowed capabilities for this context
+    /// Allowed capabilities for this context: exact IDs or namespace patterns
+    /// such as `ccos.math.*` (see `capability_pattern_specificity`)
     pub allowed_capabilities: HashSet<String>,
+    /// Capability IDs or namespace patterns that are always denied; a deny rule
+    /// loses only to a strictly more specific allow rule
+    pub denied_capabilities: HashSet<String>,
     /// Optional allowlist of effects permitted in this context (None means all effects allowed)
     pub allowed_effects: Option<HashSet<String>>,
     /// Deny list of effects that are always disallowed in this context
@@ -247,6 +251,7 @@ impl RuntimeContext {
         Self {
             security_level: SecurityLevel::Pure,
             allowed_capabilities: HashSet::new(),
+            denied_capabilities: HashSet::new(),
             allowed_effects: None,
             denied_effects: HashSet::new(),
             use_microvm: false,
@@ -270,6 +275,7 @@ impl RuntimeContext {
         Self {
             security_level: SecurityLevel::Controlled,
             allowed_capabilities: allowed_capabilities.into_iter().collect(),
+            denied_capabilities: HashSet::new(),
             allowed_effects: None,
             denied_effects: HashSet::new(),
             use_microvm: true,
@@ -293,6 +299,7 @@ impl RuntimeContext {
         Self {
             security_level: SecurityLevel::Full,
             allowed_capabilities: HashSet::new(), // Empty means all allowed
+            denied_capabilities: HashSet::new(),
             allowed_effects: None,
             denied_effects: HashSet::new(),
             use_microvm: false,
@@ -313,11 +320,37 @@ impl RuntimeContext {
 
     /// Check if a capability is allowed in this context
     pub fn is_capability_allowed(&self, capability_id: &str) -> bool {
-        match self.security_level {
-            SecurityLevel::Pure => false, // No capabilities allowed
-            SecurityLevel::Controlled => self.allowed_capabilities.contains(capability_id),
-            SecurityLevel::Full => true, // All capabilities allowed
+        if self.security_level == SecurityLevel::Pure {
+            return false; // No capabilities allowed
         }
+        let best_match = |rules: &HashSet<String>| {
+            rules
+                .iter()
+                .filter_map(|rule| capability_pattern_specificity(rule, capability_id))
+                .max()
+        };
+        // The most specific matching rule wins; deny wins ties
+        match (
+            best_match(&self.allowed_capabilities),
+            best_match(&self.denied_capabilities),
+        ) {
+            (Some(allow), Some(deny)) => allow > deny,
+            (_, Some(_)) => false,
+            (Some(_), None) => true,
+            // Unlisted capabilities are only allowed at full access
+            (None, None) => self.security_level == SecurityLevel::Full,
+        }
+    }
+
+    /// Replace the capability deny list with the provided IDs or namespace patterns.
+    pub fn with_capability_denies(mut self, capabilities: &[&str]) -> Self {
+        self.denied_capabilities = capabilities.iter().map(|c| c.to_string()).collect();
+        self
+    }
+
+    /// Append a single capability ID or namespace pattern to the deny list.
+    pub fn deny_capability(&mut self, capability: &str) {
+        self.denied_capabilities.insert(capability.to_string());
     }
 
     /// Create a new RuntimeContext with cross-plan parameters enabled
@@ -556,6 +589,29 @@ impl RuntimeContext {
     }
 }
 
+/// How specifically a capability rule matches `capability_id`, or `None` if it
+/// doesn't match. Rules are exact IDs, `*`, or namespace patterns ending in `.*`
+/// or `/*`; a namespace covers IDs continuing with either `.` or `/`, so `tool.*`
+/// matches both `tool.search` and `tool/http-fetch`. Exact IDs are the most
+/// specific, then longer namespaces.
+pub fn capability_pattern_specificity(rule: &str, capability_id: &str) -> Option<usize> {
+    if rule == capability_id {
+        return Some(usize::MAX);
+    }
+    if rule == "*" {
+        return Some(0);
+    }
+    let namespace = rule
+        .strip_suffix(".*")
+        .or_else(|| rule.strip_suffix("/*"))?;
+    let rest = capability_id.strip_prefix(namespace)?;
+    if rest.starts_with('.') || rest.starts_with('/') {
+        Some(namespace.len() + 1)
+    } else {
+        None
+    }
+}
+
 /// Normalize effect labels to the canonical `:effect` format.
 fn normalize_effect_label(effect: &str) -> String {
     let trimmed = effect.trim().trim_matches(|c| c == '\"' || c == '\'');
@@ -591,6 +647,9 @@ pub fn default_effects_for_capability(capability_id: &str) -> &'static [&'static
         | "ccos.json.stringify"
         | "ccos.json.stringify-pretty"
         | "ccos.math.add"
+        | "ccos.math.subtract"
+        | "ccos.math.multiply"
+        | "ccos.math.divide"
         | "ccos.echo" => &[":compute"],
         // User interaction (stdin/stdout)
         "ccos.user.ask" => &[":io", ":user-interaction"],
@@ -648,14 +707,14 @@ impl SecurityValidator {
             "ccos.io.delete-file",
         ]
         .iter()
-        .any(|cap| ctx.allowed_capabilities.contains(*cap))
+        .any(|cap| ctx.is_capability_allowed(cap))
             && !ctx.use_microvm
             && ctx.security_level != SecurityLevel::Full
         {
             return Err("File operations require microVM execution".to_string());
         }
 
-        if ctx.allowed_capabilities.contains("ccos.network.http-fetch")
+        if ctx.is_capability_allowed("ccos.network.http-fetch")
             && !ctx.use_microvm
             && ctx.security_level != SecurityLevel::Full
         {
@@ -14,14 +14,18 @@
 
 #![allow(unused_imports, unused_variables, dead_code)]
 
-use crate::ast::{Keyword, MapKey, Symbol};
+use crate::ast::{Expression, Keyword, MapKey, Symbol};
 // CCOS capability marketplace removed - RTFS uses pure_host
-use crate::runtime::environment::Environment;
+use crate::runtime::environment::{split_namespace, Environment, DEFAULT_NAMESPACE};
 use crate::runtime::error::{RuntimeError, RuntimeResult};
 use crate::runtime::evaluator::Evaluator;
 use crate::runtime::secure_stdlib::SecureStandardLibrary;
-use crate::runtime::values::{Arity, BuiltinFunction, BuiltinFunctionWithContext, Function, Value};
+use crate::runtime::values::{
+    Arity, BuiltinFunction, BuiltinFunctionWithContext, Function, InternedStr, Value, ValueMap,
+    ValueVector,
+};
 use crate::runtime::ExecutionOutcome;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 // Removed RwLock - no longer needed after atom removal
 use crate::ir::core::{IrNode, IrType};
@@ -34,6 +38,16 @@ use std::fs;
 // removed Rc: use Arc for shared ownership
 use std::time::{SystemTime, UNIX_EPOCH};
 
+/// Default number of concurrent capability calls made by `pmap-call`.
+pub const DEFAULT_PMAP_CONCURRENCY: usize = 8;
+
+/// Upper bound on the `max-concurrency` a plan may request from `pmap-call`,
+/// since each concurrent call runs on its own OS thread.
+pub const MAX_PMAP_CONCURRENCY: usize = 32;
+
+/// Capability a `RuntimeContext` must allow for `eval-string` to run.
+pub const EVAL_STRING_CAPABILITY: &str = "rtfs.eval";
+
 /// The Standard Library for the RTFS runtime.
 ///
 /// This struct is responsible for creating the global environment and loading
@@ -76,7 +90,7 @@ impl StandardLibrary {
                 for (_k, v) in map.iter() {
                     res.push(v.clone());
                 }
-                Ok(Value::Vector(res))
+                Ok(Value::Vector(res.into()))
             }
             other => Err(RuntimeError::TypeError {
                 expected: "map".to_string(),
@@ -115,7 +129,7 @@ impl StandardLibrary {
 
         // Pure JSON helpers
         env.define(
-            &Symbol("tool/serialize-json".to_string()),
+            &Symbol("tool/serialize-json".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "tool/serialize-json".to_string(),
                 arity: Arity::Fixed(1),
@@ -124,7 +138,7 @@ impl StandardLibrary {
         );
 
         env.define(
-            &Symbol("serialize-json".to_string()),
+            &Symbol("serialize-json".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "serialize-json".to_string(),
                 arity: Arity::Fixed(1),
@@ -134,7 +148,7 @@ impl StandardLibrary {
 
         // Pretty-print JSON serialization
         env.define(
-            &Symbol("tool/serialize-json-pretty".to_string()),
+            &Symbol("tool/serialize-json-pretty".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "tool/serialize-json-pretty".to_string(),
                 arity: Arity::Fixed(1),
@@ -145,7 +159,7 @@ impl StandardLibrary {
         );
 
         env.define(
-            &Symbol("serialize-json-pretty".to_string()),
+            &Symbol("serialize-json-pretty".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "serialize-json-pretty".to_string(),
                 arity: Arity::Fixed(1),
@@ -156,7 +170,7 @@ impl StandardLibrary {
         );
 
         env.define(
-            &Symbol("tool/parse-json".to_string()),
+            &Symbol("tool/parse-json".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "tool/parse-json".to_string(),
                 arity: Arity::Fixed(1),
@@ -165,7 +179,7 @@ impl StandardLibrary {
         );
 
         env.define(
-            &Symbol("parse-json".to_string()),
+            &Symbol("parse-json".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "parse-json".to_string(),
                 arity: Arity::Fixed(1),
@@ -173,9 +187,29 @@ impl StandardLibrary {
             })),
         );
 
+        // Parse RTFS source into quoted forms
+        env.define(
+            &Symbol("read-string".into()),
+            Value::Function(Function::Builtin(BuiltinFunction {
+                name: "read-string".to_string(),
+                arity: Arity::Fixed(1),
+                func: Arc::new(Self::read_string),
+            })),
+        );
+
+        // Fresh symbols for generated code
+        env.define(
+            &Symbol("gensym".into()),
+            Value::Function(Function::Builtin(BuiltinFunction {
+                name: "gensym".to_string(),
+                arity: Arity::Range(0, 1),
+                func: Arc::new(Self::gensym),
+            })),
+        );
+
         // Collection helpers: keys
         env.define(
-            &Symbol("keys".to_string()),
+            &Symbol("keys".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "keys".to_string(),
                 arity: Arity::Fixed(1),
@@ -185,7 +219,7 @@ impl StandardLibrary {
 
         // Collection helpers: vals
         env.define(
-            &Symbol("vals".to_string()),
+            &Symbol("vals".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "vals".to_string(),
                 arity: Arity::Fixed(1),
@@ -195,7 +229,7 @@ impl StandardLibrary {
 
         // Map lookup returning entry pair or nil: (find m k) -> [k v] | nil
         env.define(
-            &Symbol("find".to_string()),
+            &Symbol("find".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "find".to_string(),
                 arity: Arity::Fixed(2),
@@ -205,7 +239,7 @@ impl StandardLibrary {
 
         // map-indexed registration
         env.define(
-            &Symbol("map-indexed".to_string()),
+            &Symbol("map-indexed".into()),
             Value::Function(Function::BuiltinWithContext(BuiltinFunctionWithContext {
                 name: "map-indexed".to_string(),
                 arity: Arity::Fixed(2),
@@ -219,7 +253,7 @@ impl StandardLibrary {
 
         // Remove: forward to secure implementation (already implemented in this module)
         env.define(
-            &Symbol("remove".to_string()),
+            &Symbol("remove".into()),
             Value::Function(Function::BuiltinWithContext(BuiltinFunctionWithContext {
                 name: "remove".to_string(),
                 arity: Arity::Fixed(2),
@@ -233,7 +267,7 @@ impl StandardLibrary {
 
         // Collection helpers: update (map key f)
         env.define(
-            &Symbol("update".to_string()),
+            &Symbol("update".into()),
             Value::Function(Function::BuiltinWithContext(BuiltinFunctionWithContext {
                 name: "update".to_string(),
                 arity: Arity::Variadic(3),
@@ -243,7 +277,7 @@ impl StandardLibrary {
 
         // Error helpers: (getMessage e) -> message string
         env.define(
-            &Symbol("getMessage".to_string()),
+            &Symbol("getMessage".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "getMessage".to_string(),
                 arity: Arity::Fixed(1),
@@ -266,9 +300,62 @@ impl StandardLibrary {
                 }),
             })),
         );
+        // (error-type e) -> keyword naming the kind of a caught error, or nil
+        env.define(
+            &Symbol("error-type".into()),
+            Value::Function(Function::Builtin(BuiltinFunction {
+                name: "error-type".to_string(),
+                arity: Arity::Fixed(1),
+                func: std::sync::Arc::new(|args: Vec<Value>| -> RuntimeResult<Value> {
+                    match args.as_slice() {
+                        [Value::Error(err)] => Ok(err
+                            .error_type
+                            .clone()
+                            .map(Value::Keyword)
+                            .unwrap_or(Value::Nil)),
+                        [other] => Err(RuntimeError::TypeError {
+                            expected: "error".to_string(),
+                            actual: other.type_name().to_string(),
+                            operation: "error-type".to_string(),
+                        }),
+                        _ => Err(RuntimeError::ArityMismatch {
+                            function: "error-type".to_string(),
+                            expected: "1".to_string(),
+                            actual: args.len(),
+                        }),
+                    }
+                }),
+            })),
+        );
+        // (throw e) / (throw :type "msg") / (throw "msg") -> raises an error that try/catch can handle
+        env.define(
+            &Symbol("throw".into()),
+            Value::Function(Function::Builtin(BuiltinFunction {
+                name: "throw".to_string(),
+                arity: Arity::Range(1, 2),
+                func: std::sync::Arc::new(Self::throw),
+            })),
+        );
+        // (ex-info msg data) / (ex-data e): errors carrying a structured payload
+        env.define(
+            &Symbol("ex-info".into()),
+            Value::Function(Function::Builtin(BuiltinFunction {
+                name: "ex-info".to_string(),
+                arity: Arity::Fixed(2),
+                func: std::sync::Arc::new(Self::ex_info),
+            })),
+        );
+        env.define(
+            &Symbol("ex-data".into()),
+            Value::Function(Function::Builtin(BuiltinFunction {
+                name: "ex-data".to_string(),
+                arity: Arity::Fixed(1),
+                func: std::sync::Arc::new(Self::ex_data),
+            })),
+        );
         // 'for' is an evaluator special-form; not registered here.
         env.define(
-            &Symbol("process-data".to_string()),
+            &Symbol("process-data".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "process-data".to_string(),
                 arity: Arity::Fixed(1),
@@ -276,7 +363,7 @@ impl StandardLibrary {
             })),
         );
         env.define(
-            &Symbol("read-file".to_string()),
+            &Symbol("read-file".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "read-file".to_string(),
                 arity: Arity::Fixed(1),
@@ -285,7 +372,7 @@ impl StandardLibrary {
         );
         // set! is an evaluator special-form; not registered here.
         env.define(
-            &Symbol("deftype".to_string()),
+            &Symbol("deftype".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "deftype".to_string(),
                 arity: Arity::Fixed(2),
@@ -295,7 +382,7 @@ impl StandardLibrary {
 
         // Exception constructor: (Exception. msg data?) -> error value
         env.define(
-            &Symbol("Exception.".to_string()),
+            &Symbol("Exception.".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "Exception.".to_string(),
                 arity: Arity::Variadic(1),
@@ -314,6 +401,8 @@ impl StandardLibrary {
                     Ok(Value::Error(crate::runtime::values::ErrorValue {
                         message: msg,
                         stack_trace: None,
+                        error_type: None,
+                        data: args.get(1).cloned().map(Box::new),
                     }))
                 }),
             })),
@@ -336,7 +425,7 @@ impl StandardLibrary {
 
         // Map iteration: iterate over map entries
         env.define(
-            &Symbol("map".to_string()),
+            &Symbol("map".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "map".to_string(),
                 arity: Arity::Fixed(2),
@@ -360,7 +449,7 @@ impl StandardLibrary {
                                 // In a full implementation, this would call the function
                                 result.push(item.clone());
                             }
-                            Ok(Value::Vector(result))
+                            Ok(Value::Vector(result.into()))
                         }
                         Value::Map(m) => {
                             let mut result = Vec::new();
@@ -368,16 +457,16 @@ impl StandardLibrary {
                                 // For maps, we can iterate over key-value pairs
                                 let mut pair = HashMap::new();
                                 pair.insert(
-                                    MapKey::Keyword(Keyword("key".to_string())),
+                                    MapKey::Keyword(Keyword("key".into())),
                                     Value::String("key".to_string()),
                                 );
                                 pair.insert(
-                                    MapKey::Keyword(Keyword("value".to_string())),
+                                    MapKey::Keyword(Keyword("value".into())),
                                     Value::String(k.to_string()),
                                 );
-                                result.push(Value::Map(pair));
+                                result.push(Value::Map(pair.into()));
                             }
-                            Ok(Value::Vector(result))
+                            Ok(Value::Vector(result.into()))
                         }
                         _ => Err(RuntimeError::TypeError {
                             expected: "vector or map".to_string(),
@@ -391,7 +480,7 @@ impl StandardLibrary {
 
         // Numbers: returns a vector of numbers from start to end
         env.define(
-            &Symbol("numbers".to_string()),
+            &Symbol("numbers".into()),
             Value::Function(Function::Builtin(BuiltinFunction {
                 name: "numbers".to_string(),
                 arity: Arity::Fixed(2),
@@ -420,7 +509,7 @@ impl StandardLibrary {
                     for i in start_int..=end_int {
                         result.push(Value::Integer(i));
                     }
-                    Ok(Value::Vector(result))
+                    Ok(Value::Vector(result.into()))
                 }),
             })),
         );
@@ -433,7 +522,7 @@ impl StandardLibrary {
     fn load_capability_functions(env: &mut Environment) {
         // `call` for invoking CCOS capabilities
         env.define(
-            &Symbol("call".to_string()),
+            &Symbol("call".into()),
             Value::Function(Function::BuiltinWithContext(BuiltinFunctionWithContext {
                 name: "call".to_string(),
                 arity: Arity::Variadic(1),
@@ -441,11 +530,21 @@ impl StandardLibrary {
             })),
         );
 
+        // `pmap-call` for invoking a capability over many inputs concurrently
+        env.define(
+            &Symbol("pmap-call".into()),
+            Value::Function(Function::BuiltinWithContext(BuiltinFunctionWithContext {
+                name: "pmap-call".to_string(),
+                arity: Arity::Variadic(2),
+                func: Arc::new(Self::pmap_call_capability),
+            })),
+        );
+
         // Step-scoped context helpers (delegates to host)
         // These mirror the CCOS prelude helpers but are implemented in RTFS stdlib
         // so they are available in IR runtime as well.
         env.define(
-            &Symbol("context/get".to_string()),
+            &Symbol("context/get".into()),
             Value::Function(Function::BuiltinWithContext(BuiltinFunctionWithContext {
                 name: "context/get".to_string(),
                 arity: Arity::Fixed(1),
@@ -453,20 +552,48 @@ impl StandardLibrary {
             })),
         );
         env.define(
-            &Symbol("context/set".to_string()),
+            &Symbol("context/set".into()),
             Value::Function(Function::BuiltinWithContext(BuiltinFunctionWithContext {
                 name: "context/set".to_string(),
                 arity: Arity::Fixed(2),
                 func: Arc::new(Self::context_set),
             })),
         );
+
+        // Explicit resume points (delegates persistence to host)
+        env.define(
+            &Symbol("checkpoint".into()),
+            Value::Function(Function::BuiltinWithContext(BuiltinFunctionWithContext {
+                name: "checkpoint".to_string(),
+                arity: Arity::Fixed(1),
+                func: Arc::new(Self::checkpoint),
+            })),
+        );
+        env.define(
+            &Symbol("restore".into()),
+            Value::Function(Function::BuiltinWithContext(BuiltinFunctionWithContext {
+                name: "restore".to_string(),
+                arity: Arity::Fixed(1),
+                func: Arc::new(Self::restore),
+            })),
+        );
+
+        // Dynamic evaluation, gated by EVAL_STRING_CAPABILITY
+        env.define(
+            &Symbol("eval-string".into()),
+            Value::Function(Function::BuiltinWithContext(BuiltinFunctionWithContext {
+                name: "eval-string".to_string(),
+                arity: Arity::Fixed(1),
+                func: Arc::new(Self::eval_string),
+            })),
+        );
     }
 
     fn context_key_from_value(value: &Value) -> RuntimeResult<String> {
         match value {
             Value::String(s) => Ok(s.clone()),
-            Value::Keyword(k) => Ok(k.0.clone()),
-            Value::Symbol(sym) => Ok(sym.0.clone()),
+            Value::Keyword(k) => Ok(k.0.to_string()),
+            Value::Symbol(sym) => Ok(sym.0.to_string()),
             other => Err(RuntimeError::TypeError {
                 expected: "string, keyword, or symbol".to_string(),
                 actual: other.type_name().to_string(),
@@ -511,6 +638,75 @@ impl StandardLibrary {
         Ok(Value::Nil)
     }
 
+    /// `(checkpoint "label")`
+    ///
+    /// Snapshots the data bindings visible at the call site (let-bound values,
+    /// definitions; functions are skipped) and asks the host to persist them with
+    /// the current execution context. Returns the checkpoint id.
+    fn checkpoint(
+        args: Vec<Value>,
+        evaluator: &Evaluator,
+        env: &mut Environment,
+    ) -> RuntimeResult<Value> {
+        if args.len() != 1 {
+            return Err(RuntimeError::ArityMismatch {
+                function: "checkpoint".to_string(),
+                expected: "1".to_string(),
+                actual: args.len(),
+            });
+        }
+
+        let label = Self::context_key_from_value(&args[0])?;
+        let bindings = env
+            .data_bindings()
+            .into_iter()
+            .map(|(symbol, value)| (MapKey::Keyword(Keyword(symbol.0)), value))
+            .collect();
+        let checkpoint_id = evaluator
+            .host
+            .create_checkpoint(&label, Value::Map(bindings))?;
+        Ok(Value::String(checkpoint_id))
+    }
+
+    /// `(restore checkpoint-id)`
+    ///
+    /// Restores a checkpoint created by `checkpoint`: the host reinstates the saved
+    /// execution context and the saved bindings are re-defined in the current scope.
+    /// Returns the bindings as a map keyed by keyword.
+    fn restore(
+        args: Vec<Value>,
+        evaluator: &Evaluator,
+        env: &mut Environment,
+    ) -> RuntimeResult<Value> {
+        if args.len() != 1 {
+            return Err(RuntimeError::ArityMismatch {
+                function: "restore".to_string(),
+                expected: "1".to_string(),
+                actual: args.len(),
+            });
+        }
+
+        let checkpoint_id = match &args[0] {
+            Value::String(s) => s.clone(),
+            other => {
+                return Err(RuntimeError::TypeError {
+                    expected: "string".to_string(),
+                    actual: other.type_name().to_string(),
+                    operation: "restore".to_string(),
+                })
+            }
+        };
+        let restored = evaluator.host.restore_checkpoint(&checkpoint_id)?;
+        if let Value::Map(bindings) = &restored {
+            for (key, value) in bindings {
+                if let MapKey::Keyword(Keyword(name)) = key {
+                    env.define(&Symbol(name.clone()), value.clone());
+                }
+            }
+        }
+        Ok(restored)
+    }
+
     // --- Tooling Function Implementations ---
 
     /// `(tool.open-file "path/to/file")`
@@ -637,12 +833,12 @@ impl StandardLibrary {
             .execute_capability("ccos.state.kv.get", &[kv_key.clone()])
             .unwrap_or(Value::Nil);
         let base = match current {
-            Value::Nil => Value::Map(std::collections::HashMap::new()),
+            Value::Nil => Value::Map(ValueMap::new()),
             other => other,
         };
 
         // Resolve assoc from environment and apply
-        let assoc_sym = crate::ast::Symbol("assoc".to_string());
+        let assoc_sym = crate::ast::Symbol("assoc".into());
         let assoc_fn = env
             .lookup(&assoc_sym)
             .ok_or_else(|| RuntimeError::Generic("assoc not found".to_string()))?;
@@ -686,11 +882,11 @@ impl StandardLibrary {
             .execute_capability("ccos.state.kv.get", &[kv_key.clone()])
             .unwrap_or(Value::Nil);
         let base = match current {
-            Value::Nil => Value::Map(std::collections::HashMap::new()),
+            Value::Nil => Value::Map(ValueMap::new()),
             other => other,
         };
 
-        let dissoc_sym = crate::ast::Symbol("dissoc".to_string());
+        let dissoc_sym = crate::ast::Symbol("dissoc".into());
         let dissoc_fn = env
             .lookup(&dissoc_sym)
             .ok_or_else(|| RuntimeError::Generic("dissoc not found".to_string()))?;
@@ -734,11 +930,11 @@ impl StandardLibrary {
             .execute_capability("ccos.state.kv.get", &[kv_key.clone()])
             .unwrap_or(Value::Nil);
         let base = match current {
-            Value::Nil => Value::Vector(Vec::new()),
+            Value::Nil => Value::Vector(Vec::new().into()),
             other => other,
         };
 
-        let conj_sym = crate::ast::Symbol("conj".to_string());
+        let conj_sym = crate::ast::Symbol("conj".into());
         let conj_fn = env
             .lookup(&conj_sym)
             .ok_or_else(|| RuntimeError::Generic("conj not found".to_string()))?;
@@ -817,7 +1013,7 @@ impl StandardLibrary {
                 crate::ast::MapKey::Keyword(k) => Value::Keyword(k.clone()),
                 crate::ast::MapKey::Integer(i) => Value::Integer(*i),
             };
-            Ok(Value::Vector(vec![key_val, v.clone()]))
+            Ok(Value::Vector(vec![key_val, v.clone()].into()))
         } else {
             Ok(Value::Nil)
         }
@@ -933,7 +1129,7 @@ impl StandardLibrary {
                             if s.starts_with(':') {
                                 s[1..].to_string()
                             } else {
-                                s.clone()
+                                s.to_string()
                             }
                         }
                         crate::ast::MapKey::Integer(i) => i.to_string(),
@@ -969,7 +1165,7 @@ impl StandardLibrary {
                 for item in arr {
                     rtfs_vec.push(Self::json_value_to_rtfs(item)?);
                 }
-                Ok(Value::Vector(rtfs_vec))
+                Ok(Value::Vector(rtfs_vec.into()))
             }
             serde_json::Value::Object(obj) => {
                 let mut rtfs_map = std::collections::HashMap::new();
@@ -977,7 +1173,7 @@ impl StandardLibrary {
                     let map_key = crate::ast::MapKey::String(key.clone());
                     rtfs_map.insert(map_key, Self::json_value_to_rtfs(value)?);
                 }
-                Ok(Value::Map(rtfs_map))
+                Ok(Value::Map(rtfs_map.into()))
             }
         }
     }
@@ -1102,7 +1298,7 @@ impl StandardLibrary {
                     };
                     out.push(v);
                 }
-                Ok(Value::Vector(out))
+                Ok(Value::Vector(out.into()))
             }
             other => Err(RuntimeError::TypeError {
                 expected: "map".to_string(),
@@ -1112,6 +1308,94 @@ impl StandardLibrary {
         }
     }
 
+    /// `(throw e)`, `(throw :type "msg")` or `(throw "msg")` -> raises an application error.
+    /// Rethrowing a caught error value keeps its original error type.
+    fn throw(args: Vec<Value>) -> RuntimeResult<Value> {
+        let (error_type, message, data) = match args.as_slice() {
+            [Value::Error(err)] => (
+                err.error_type
+                    .clone()
+                    .unwrap_or_else(|| Keyword::new("error")),
+                err.message.clone(),
+                err.data.as_deref().cloned(),
+            ),
+            [Value::String(msg)] => (Keyword::new("error"), msg.clone(), None),
+            [Value::Keyword(kind), Value::String(msg)] => (kind.clone(), msg.clone(), None),
+            [Value::Keyword(_), other] => {
+                return Err(RuntimeError::TypeError {
+                    expected: "string".to_string(),
+                    actual: other.type_name().to_string(),
+                    operation: "throw".to_string(),
+                })
+            }
+            [other] | [other, _] => {
+                return Err(RuntimeError::TypeError {
+                    expected: "error, string or keyword".to_string(),
+                    actual: other.type_name().to_string(),
+                    operation: "throw".to_string(),
+                })
+            }
+            _ => {
+                return Err(RuntimeError::ArityMismatch {
+                    function: "throw".to_string(),
+                    expected: "1-2".to_string(),
+                    actual: args.len(),
+                })
+            }
+        };
+        Err(RuntimeError::ApplicationError {
+            error_type,
+            message,
+            data,
+        })
+    }
+
+    /// `(ex-info msg data)` -> error value carrying a data map, ready to `throw`
+    fn ex_info(args: Vec<Value>) -> RuntimeResult<Value> {
+        match args.as_slice() {
+            [Value::String(msg), data @ (Value::Map(_) | Value::Nil)] => {
+                Ok(Value::Error(crate::runtime::values::ErrorValue {
+                    message: msg.clone(),
+                    stack_trace: None,
+                    error_type: Some(Keyword::new("ex-info")),
+                    data: Some(Box::new(data.clone())),
+                }))
+            }
+            [Value::String(_), other] => Err(RuntimeError::TypeError {
+                expected: "map".to_string(),
+                actual: other.type_name().to_string(),
+                operation: "ex-info".to_string(),
+            }),
+            [other, _] => Err(RuntimeError::TypeError {
+                expected: "string".to_string(),
+                actual: other.type_name().to_string(),
+                operation: "ex-info".to_string(),
+            }),
+            _ => Err(RuntimeError::ArityMismatch {
+                function: "ex-info".to_string(),
+                expected: "2".to_string(),
+                actual: args.len(),
+            }),
+        }
+    }
+
+    /// `(ex-data e)` -> data attached to an error, or nil when it carries none
+    fn ex_data(args: Vec<Value>) -> RuntimeResult<Value> {
+        match args.as_slice() {
+            [Value::Error(err)] => Ok(err.data.as_deref().cloned().unwrap_or(Value::Nil)),
+            [other] => Err(RuntimeError::TypeError {
+                expected: "error".to_string(),
+                actual: other.type_name().to_string(),
+                operation: "ex-data".to_string(),
+            }),
+            _ => Err(RuntimeError::ArityMismatch {
+                function: "ex-data".to_string(),
+                expected: "1".to_string(),
+                actual: args.len(),
+            }),
+        }
+    }
+
     /// `(update map key f & args)` -> returns a new map with key updated by applying f to current value and extra args
     /// f may be a function value, a keyword, or a string naming a function in the current environment.
     /// This builtin needs evaluator context to call user functions.
@@ -1139,8 +1423,8 @@ impl StandardLibrary {
         };
 
         // Support update for both maps and vectors
-        let mut new_map_opt: Option<std::collections::HashMap<crate::ast::MapKey, Value>> = None;
-        let mut new_vec_opt: Option<Vec<Value>> = None;
+        let mut new_map_opt: Option<ValueMap> = None;
+        let mut new_vec_opt: Option<ValueVector> = None;
 
         match map_val {
             Value::Map(m) => new_map_opt = Some(m.clone()),
@@ -1177,7 +1461,7 @@ impl StandardLibrary {
                 }
                 Value::String(name) => {
                     // Resolve by symbol name in current environment
-                    if let Some(resolved) = env.lookup(&crate::ast::Symbol(name.clone())) {
+                    if let Some(resolved) = env.lookup(&crate::ast::Symbol::new(name.as_str())) {
                         match resolved {
                             Value::Function(_)
                             | Value::FunctionPlaceholder(_)
@@ -1256,7 +1540,7 @@ impl StandardLibrary {
                     f_val.clone()
                 }
                 Value::String(name) => {
-                    if let Some(resolved) = env.lookup(&crate::ast::Symbol(name.clone())) {
+                    if let Some(resolved) = env.lookup(&crate::ast::Symbol::new(name.as_str())) {
                         match resolved {
                             Value::Function(_)
                             | Value::FunctionPlaceholder(_)
@@ -1323,17 +1607,8 @@ impl StandardLibrary {
             });
         }
 
-        match &args[0] {
-            Value::Integer(n) => Ok(Value::Boolean(n % 2 == 1)),
-            Value::Float(f) => Ok(Value::Boolean((*f as i64) % 2 == 1)),
-            other => {
-                return Err(RuntimeError::TypeError {
-                    expected: "number".to_string(),
-                    actual: other.type_name().to_string(),
-                    operation: "odd?".to_string(),
-                })
-            }
-        }
+        let n = SecureStandardLibrary::parity_operand(&args[0], "odd?")?;
+        Ok(Value::Boolean(n.rem_euclid(2) == 1))
     }
 
     /// `(inc n)` - Returns n + 1
@@ -1447,11 +1722,11 @@ impl StandardLibrary {
         }
 
         match collection {
-            Value::Vector(_) => Ok(Value::Vector(result)),
+            Value::Vector(_) => Ok(Value::Vector(result.into())),
             Value::String(_) => Ok(Value::String(
                 result.into_iter().map(|v| v.to_string()).collect(),
             )),
-            Value::List(_) => Ok(Value::List(result)),
+            Value::List(_) => Ok(Value::List(result.into())),
             _ => unreachable!(),
         }
     }
@@ -1526,11 +1801,11 @@ impl StandardLibrary {
         }
 
         match collection {
-            Value::Vector(_) => Ok(Value::Vector(result)),
+            Value::Vector(_) => Ok(Value::Vector(result.into())),
             Value::String(_) => Ok(Value::String(
                 result.into_iter().map(|v| v.to_string()).collect(),
             )),
-            Value::List(_) => Ok(Value::List(result)),
+            Value::List(_) => Ok(Value::List(result.into())),
             _ => unreachable!(),
         }
     }
@@ -1679,17 +1954,8 @@ impl StandardLibrary {
             });
         }
 
-        match &args[0] {
-            Value::Integer(n) => Ok(Value::Boolean(n % 2 == 0)),
-            Value::Float(f) => Ok(Value::Boolean((*f as i64) % 2 == 0)),
-            other => {
-                return Err(RuntimeError::TypeError {
-                    expected: "number".to_string(),
-                    actual: other.type_name().to_string(),
-                    operation: "even?".to_string(),
-                })
-            }
-        }
+        let n = SecureStandardLibrary::parity_operand(&args[0], "even?")?;
+        Ok(Value::Boolean(n.rem_euclid(2) == 0))
     }
 
     /// `(first collection)` - Returns the first element of a collection
@@ -1745,9 +2011,9 @@ impl StandardLibrary {
         match &args[0] {
             Value::Vector(vec) => {
                 if vec.len() <= 1 {
-                    Ok(Value::Vector(vec![]))
+                    Ok(Value::Vector(vec![].into()))
                 } else {
-                    Ok(Value::Vector(vec[1..].to_vec()))
+                    Ok(Value::Vector(vec.skip(1)))
                 }
             }
             Value::String(s) => {
@@ -1759,9 +2025,9 @@ impl StandardLibrary {
             }
             Value::List(list) => {
                 if list.len() <= 1 {
-                    Ok(Value::List(vec![]))
+                    Ok(Value::List(vec![].into()))
                 } else {
-                    Ok(Value::List(list[1..].to_vec()))
+                    Ok(Value::List(list.skip(1)))
                 }
             }
             other => Err(RuntimeError::TypeError {
@@ -1919,7 +2185,7 @@ impl StandardLibrary {
             }
         }
 
-        Ok(Value::Vector(result))
+        Ok(Value::Vector(result.into()))
     }
 
     /// `(map function collection)` - Applies a function to each element of a collection
@@ -1969,9 +2235,9 @@ impl StandardLibrary {
 
         // Return the same type as the input collection
         match collection {
-            Value::Vector(_) => Ok(Value::Vector(result)),
-            Value::String(_) => Ok(Value::Vector(result)),
-            Value::List(_) => Ok(Value::List(result)),
+            Value::Vector(_) => Ok(Value::Vector(result.into())),
+            Value::String(_) => Ok(Value::Vector(result.into())),
+            Value::List(_) => Ok(Value::List(result.into())),
             _ => unreachable!(),
         }
     }
@@ -2006,7 +2272,7 @@ impl StandardLibrary {
                             MapKey::String(s) => Value::String(s.clone()),
                             MapKey::Integer(i) => Value::Integer(*i),
                         };
-                        Value::Vector(vec![key_val, v.clone()])
+                        Value::Vector(vec![key_val, v.clone()].into())
                     })
                     .collect()
             }
@@ -2052,9 +2318,9 @@ impl StandardLibrary {
 
         // Return the same type as the input collection
         match collection {
-            Value::Vector(_) => Ok(Value::Vector(result)),
-            Value::String(_) => Ok(Value::Vector(result)),
-            Value::List(_) => Ok(Value::List(result)),
+            Value::Vector(_) => Ok(Value::Vector(result.into())),
+            Value::String(_) => Ok(Value::Vector(result.into())),
+            Value::List(_) => Ok(Value::List(result.into())),
             Value::Map(_) => {
                 // For maps, convert the filtered vector of [key value] pairs back to a map
                 let mut filtered_map = std::collections::HashMap::new();
@@ -2071,7 +2337,7 @@ impl StandardLibrary {
                         }
                     }
                 }
-                Ok(Value::Map(filtered_map))
+                Ok(Value::Map(filtered_map.into()))
             }
             _ => unreachable!(),
         }
@@ -2118,10 +2384,11 @@ impl StandardLibrary {
             };
         }
 
-        let (mut accumulator, rest) = if args.len() == 3 {
-            (args[1].clone(), collection.as_slice())
+        let mut rest = collection.iter();
+        let mut accumulator = if args.len() == 3 {
+            args[1].clone()
         } else {
-            (collection[0].clone(), &collection[1..])
+            rest.next().cloned().unwrap_or(Value::Nil)
         };
 
         for value in rest {
@@ -2174,6 +2441,10 @@ impl StandardLibrary {
             }
         };
 
+        if let Value::Vector(items) | Value::List(items) = collection {
+            SecureStandardLibrary::check_sortable(items, "sort")?;
+        }
+
         match collection {
             Value::Vector(vec) => {
                 let mut sorted = vec.clone();
@@ -2261,7 +2532,8 @@ impl StandardLibrary {
             pairs.push((element, key));
         }
 
-        // Sort by key
+        // Sort by key (stable: equal keys keep input order)
+        SecureStandardLibrary::check_sortable(pairs.iter().map(|(_, key)| key), "sort-by")?;
         pairs.sort_by(|a, b| a.1.compare(&b.1));
 
         // Extract sorted elements
@@ -2269,9 +2541,9 @@ impl StandardLibrary {
 
         // Return the same type as the input collection
         match collection {
-            Value::Vector(_) => Ok(Value::Vector(result)),
-            Value::String(_) => Ok(Value::Vector(result)),
-            Value::List(_) => Ok(Value::List(result)),
+            Value::Vector(_) => Ok(Value::Vector(result.into())),
+            Value::String(_) => Ok(Value::Vector(result.into())),
+            Value::List(_) => Ok(Value::List(result.into())),
             _ => unreachable!(),
         }
     }
@@ -2319,7 +2591,7 @@ impl StandardLibrary {
             }
         }
 
-        Ok(Value::Map(freq_map))
+        Ok(Value::Map(freq_map.into()))
     }
 
     /// `(distinct collection)` - Returns collection with duplicates removed
@@ -2354,11 +2626,11 @@ impl StandardLibrary {
         }
 
         match collection {
-            Value::Vector(_) => Ok(Value::Vector(result)),
+            Value::Vector(_) => Ok(Value::Vector(result.into())),
             Value::String(_) => Ok(Value::String(
                 result.into_iter().map(|v| v.to_string()).collect(),
             )),
-            Value::List(_) => Ok(Value::List(result)),
+            Value::List(_) => Ok(Value::List(result.into())),
             _ => unreachable!(),
         }
     }
@@ -2407,11 +2679,12 @@ impl StandardLibrary {
     }
 
     /// `(merge m1 m2 ... )` - shallow merge of maps; later maps override earlier keys
+    /// and nil arguments are ignored
     fn merge(args: Vec<Value>) -> RuntimeResult<Value> {
         if args.is_empty() {
-            return Ok(Value::Map(HashMap::new()));
+            return Ok(Value::Map(ValueMap::new()));
         }
-        let mut out: HashMap<MapKey, Value> = HashMap::new();
+        let mut out = ValueMap::new();
         for arg in args {
             match arg {
                 Value::Map(m) => {
@@ -2419,9 +2692,11 @@ impl StandardLibrary {
                         out.insert(k, v);
                     }
                 }
+                // Skipped, so `(merge base (when cond extra))` works
+                Value::Nil => {}
                 other => {
                     return Err(RuntimeError::TypeError {
-                        expected: "map".into(),
+                        expected: "map or nil".into(),
                         actual: other.type_name().into(),
                         operation: "merge".into(),
                     });
@@ -2448,7 +2723,7 @@ impl StandardLibrary {
             MapKey::Keyword(Keyword("inputs".into())),
             Value::Integer(args.len() as i64),
         );
-        Ok(Value::Map(m))
+        Ok(Value::Map(m.into()))
     }
 
     // --- CCOS Capability Function Implementations ---
@@ -2473,7 +2748,7 @@ impl StandardLibrary {
 
         let capability_name = match &args[0] {
             Value::String(s) => s.clone(),
-            Value::Keyword(k) => k.0.clone(), // Support keyword syntax
+            Value::Keyword(k) => k.0.to_string(), // Support keyword syntax
             _ => {
                 return Err(RuntimeError::TypeError {
                     expected: "string or keyword".to_string(),
@@ -2490,6 +2765,212 @@ impl StandardLibrary {
             .host
             .execute_capability(&capability_name, capability_args)
     }
+
+    fn source_string_arg(args: &[Value], function: &str) -> RuntimeResult<Expression> {
+        if args.len() != 1 {
+            return Err(RuntimeError::ArityMismatch {
+                function: function.to_string(),
+                expected: "1".to_string(),
+                actual: args.len(),
+            });
+        }
+        let source = match &args[0] {
+            Value::String(s) => s,
+            other => {
+                return Err(RuntimeError::TypeError {
+                    expected: "string".to_string(),
+                    actual: other.type_name().to_string(),
+                    operation: function.to_string(),
+                })
+            }
+        };
+        crate::parser::parse_expression(source)
+            .map_err(|e| RuntimeError::Generic(format!("{}: parse error: {:?}", function, e)))
+    }
+
+    /// `(read-string "(+ 1 2)")` -> `(+ 1 2)` as data
+    ///
+    /// Parses one RTFS expression without evaluating it. Calls and lists become
+    /// lists, so the result has the same shape as the quoted source. Forms that
+    /// have no plain data representation (typed bindings, destructuring, match,
+    /// try/catch, ...) are rejected.
+    fn read_string(args: Vec<Value>) -> RuntimeResult<Value> {
+        let expr = Self::source_string_arg(&args, "read-string")?;
+        crate::runtime::forms::expression_to_form(&expr)
+    }
+
+    /// `(gensym)` -> `G__1`, `(gensym "tmp")` -> `tmp__2`
+    ///
+    /// Returns a symbol that is unique within the process: the suffix comes from
+    /// a global monotonic counter, so generated code cannot capture user names.
+    fn gensym(args: Vec<Value>) -> RuntimeResult<Value> {
+        static GENSYM_COUNTER: AtomicU64 = AtomicU64::new(1);
+
+        let prefix = match args.as_slice() {
+            [] => "G".to_string(),
+            [Value::String(s)] => s.clone(),
+            [Value::Symbol(sym)] => sym.0.to_string(),
+            [other] => {
+                return Err(RuntimeError::TypeError {
+                    expected: "string or symbol".to_string(),
+                    actual: other.type_name().to_string(),
+                    operation: "gensym".to_string(),
+                })
+            }
+            _ => {
+                return Err(RuntimeError::ArityMismatch {
+                    function: "gensym".to_string(),
+                    expected: "between 0 and 1".to_string(),
+                    actual: args.len(),
+                })
+            }
+        };
+        let n = GENSYM_COUNTER.fetch_add(1, Ordering::Relaxed);
+        Ok(Value::Symbol(Symbol(InternedStr::new_uninterned(
+            &format!("{}__{}", prefix, n),
+        ))))
+    }
+
+    /// `(eval-string "(+ 1 2)")` -> 3
+    ///
+    /// Parses and evaluates one RTFS expression in a child scope of the call
+    /// site, so definitions made by the evaluated code do not leak out. Only
+    /// allowed when the runtime context grants [`EVAL_STRING_CAPABILITY`]; the
+    /// evaluated code shares the caller's recursion budget and security context.
+    fn eval_string(
+        args: Vec<Value>,
+        evaluator: &Evaluator,
+        env: &mut Environment,
+    ) -> RuntimeResult<Value> {
+        if !evaluator
+            .security_context
+            .is_capability_allowed(EVAL_STRING_CAPABILITY)
+        {
+            return Err(RuntimeError::SecurityViolation {
+                operation: "eval-string".to_string(),
+                capability: EVAL_STRING_CAPABILITY.to_string(),
+                context: "capability not allowed in current RuntimeContext".to_string(),
+            });
+        }
+        let expr = Self::source_string_arg(&args, "eval-string")?;
+        let mut eval_env = Environment::with_parent(Arc::new(env.clone()));
+        match evaluator.eval_expr(&expr, &mut eval_env)? {
+            ExecutionOutcome::Complete(value) => Ok(value),
+            ExecutionOutcome::RequiresHost(hc) => Err(RuntimeError::Generic(format!(
+                "eval-string: host call required: {}",
+                hc.capability_id
+            ))),
+            #[cfg(feature = "effect-boundary")]
+            ExecutionOutcome::RequiresHost(_) => Err(RuntimeError::Generic(
+                "eval-string: host effect required".to_string(),
+            )),
+        }
+    }
+
+    /// `(pmap-call :capability-id inputs)` or `(pmap-call :capability-id inputs max-concurrency)`
+    ///
+    /// Invokes a capability once per element of `inputs` (each element is passed
+    /// as the single argument), running up to `max-concurrency` calls at a time
+    /// (default `DEFAULT_PMAP_CONCURRENCY`, capped at `MAX_PMAP_CONCURRENCY`).
+    /// Results are returned as a vector in input order. If any call fails, all
+    /// failures are reported together.
+    fn pmap_call_capability(
+        args: Vec<Value>,
+        evaluator: &Evaluator,
+        _env: &mut Environment,
+    ) -> RuntimeResult<Value> {
+        if args.len() < 2 || args.len() > 3 {
+            return Err(RuntimeError::ArityMismatch {
+                function: "pmap-call".to_string(),
+                expected: "2 or 3".to_string(),
+                actual: args.len(),
+            });
+        }
+
+        let capability_name = match &args[0] {
+            Value::String(s) => s.clone(),
+            Value::Keyword(k) => k.0.to_string(),
+            _ => {
+                return Err(RuntimeError::TypeError {
+                    expected: "string or keyword".to_string(),
+                    actual: args[0].type_name().to_string(),
+                    operation: "pmap-call".to_string(),
+                })
+            }
+        };
+
+        let inputs = match &args[1] {
+            Value::Vector(items) | Value::List(items) => items.clone(),
+            other => {
+                return Err(RuntimeError::TypeError {
+                    expected: "vector or list".to_string(),
+                    actual: other.type_name().to_string(),
+                    operation: "pmap-call".to_string(),
+                })
+            }
+        };
+
+        let max_concurrency = match args.get(2) {
+            None => DEFAULT_PMAP_CONCURRENCY,
+            Some(Value::Integer(n)) if *n > 0 => (*n as usize).min(MAX_PMAP_CONCURRENCY),
+            Some(other) => {
+                return Err(RuntimeError::InvalidArgument(format!(
+                    "pmap-call max-concurrency must be a positive integer, got {}",
+                    other
+                )))
+            }
+        };
+
+        let host = Arc::clone(&evaluator.host);
+        let worker_scope = host.concurrent_worker_scope();
+        let next_index = std::sync::atomic::AtomicUsize::new(0);
+        let results: Vec<std::sync::Mutex<Option<RuntimeResult<Value>>>> =
+            inputs.iter().map(|_| std::sync::Mutex::new(None)).collect();
+
+        // Fixed pool of workers pulling the next input index; each result is
+        // written to its own slot so output order matches input order.
+        std::thread::scope(|scope| {
+            for _ in 0..max_concurrency.min(inputs.len()) {
+                scope.spawn(|| {
+                    worker_scope(&mut || loop {
+                        let index = next_index.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                        if index >= inputs.len() {
+                            break;
+                        }
+                        let result = host.execute_capability(
+                            &capability_name,
+                            std::slice::from_ref(&inputs[index]),
+                        );
+                        if let Ok(mut slot) = results[index].lock() {
+                            *slot = Some(result);
+                        }
+                    })
+                });
+            }
+        });
+
+        let mut values = Vec::with_capacity(inputs.len());
+        let mut failures = Vec::new();
+        for (index, slot) in results.into_iter().enumerate() {
+            match slot.into_inner().ok().flatten() {
+                Some(Ok(value)) => values.push(value),
+                Some(Err(e)) => failures.push(format!("[{}] {}", index, e)),
+                None => failures.push(format!("[{}] call did not complete", index)),
+            }
+        }
+
+        if failures.is_empty() {
+            Ok(Value::Vector(values.into()))
+        } else {
+            Err(RuntimeError::Generic(format!(
+                "pmap-call: {} of {} calls to '{}' failed: {}",
+                failures.len(),
+                inputs.len(),
+                capability_name,
+                failures.join("; ")
+            )))
+        }
+    }
 }
 
 /// Load the standard library into a module registry
@@ -2505,31 +2986,24 @@ pub fn load_stdlib(module_registry: &ModuleRegistry) -> RuntimeResult<()> {
     let mut module_functions: HashMap<String, Vec<(String, Value)>> = HashMap::new();
 
     for name in function_names {
-        if let Some(value) = env.lookup(&Symbol(name.clone())) {
-            // Special case for division operator to avoid namespace parsing
-            if name == "/" {
-                module_functions
-                    .entry("stdlib".to_string())
-                    .or_insert_with(Vec::new)
-                    .push((name, value));
-            } else if let Some(slash_index) = name.find('/') {
-                // Split by '/' to get module name and function name
-                let module_name = name[..slash_index].to_string();
-                let function_name = name[slash_index + 1..].to_string();
-
-                // Skip if either module or function name is empty (malformed)
-                if !module_name.is_empty() && !function_name.is_empty() {
+        if let Some(value) = env.lookup(&Symbol::new(name.as_str())) {
+            match split_namespace(&name) {
+                Some((module_name, function_name)) => {
+                    // Skip if either module or function name is empty (malformed)
+                    if !module_name.is_empty() && !function_name.is_empty() {
+                        module_functions
+                            .entry(module_name.to_string())
+                            .or_default()
+                            .push((function_name.to_string(), value));
+                    }
+                }
+                None => {
+                    // Unqualified functions (and `/` itself) go to the "stdlib" module
                     module_functions
-                        .entry(module_name)
+                        .entry(DEFAULT_NAMESPACE.to_string())
                         .or_insert_with(Vec::new)
-                        .push((function_name, value));
+                        .push((name, value));
                 }
-            } else {
-                // Functions without '/' go to a "stdlib" module
-                module_functions
-                    .entry("stdlib".to_string())
-                    .or_insert_with(Vec::new)
-                    .push((name, value));
             }
         }
     }
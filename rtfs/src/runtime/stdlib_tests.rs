@@ -1,7 +1,8 @@
 #[cfg(test)]
 mod test_stdlib_loading {
     use crate::runtime::module_runtime::ModuleRegistry;
-    use crate::runtime::stdlib::load_stdlib;
+    use crate::runtime::environment::{split_namespace, DEFAULT_NAMESPACE};
+    use crate::runtime::stdlib::{load_stdlib, StandardLibrary};
 
     #[test]
     fn test_load_stdlib_creates_module() {
@@ -42,4 +43,34 @@ mod test_stdlib_loading {
             exports.len()
         );
     }
+
+    #[test]
+    fn test_symbol_names_in_namespace() {
+        let env = StandardLibrary::create_global_environment();
+
+        let tool_names = env.symbol_names_in_namespace("tool/");
+        for expected in ["tool/parse-json", "tool/serialize-json"] {
+            assert!(
+                tool_names.contains(&expected.to_string()),
+                "Missing {} in {:?}",
+                expected,
+                tool_names
+            );
+        }
+        assert!(tool_names.iter().all(|name| name.starts_with("tool/")));
+        // The trailing slash is optional
+        assert_eq!(env.symbol_names_in_namespace("tool"), tool_names);
+
+        let stdlib_names = env.symbol_names_in_namespace(DEFAULT_NAMESPACE);
+        for expected in ["+", "/", "map", "count"] {
+            assert!(
+                stdlib_names.contains(&expected.to_string()),
+                "Missing {} in stdlib namespace",
+                expected
+            );
+        }
+        assert!(stdlib_names.iter().all(|name| split_namespace(name).is_none()));
+
+        assert!(env.symbol_names_in_namespace("no-such-ns").is_empty());
+    }
 }
@@ -1,7 +1,7 @@
 use crate::ast::{
     ArrayDimension, Keyword, Literal, MapKey, PrimitiveType, TypeExpr, TypePredicate,
 };
-use crate::runtime::values::Value;
+use crate::runtime::values::{Value, ValueVector};
 use regex::Regex;
 use std::collections::HashMap;
 
@@ -241,7 +241,7 @@ impl TypeValidator {
             let core = alias.0.trim_end_matches('?');
             if !core.is_empty() {
                 if let Some(resolved) =
-                    Self::resolve_primitive_alias(&crate::ast::Symbol(core.to_string()))
+                    Self::resolve_primitive_alias(&crate::ast::Symbol(core.into()))
                 {
                     return Some(TypeExpr::Optional(Box::new(resolved)));
                 }
@@ -546,7 +546,7 @@ impl TypeValidator {
             ) => {
                 for entry in entries {
                     let keyword_key = MapKey::Keyword(entry.key.clone());
-                    let string_key = MapKey::String(entry.key.0.clone());
+                    let string_key = MapKey::String(entry.key.0.to_string());
 
                     if let Some(value) = map.get(&keyword_key).or_else(|| map.get(&string_key)) {
                         let field_path = format!("{}.{}", path, entry.key.0);
@@ -685,7 +685,7 @@ impl TypeValidator {
     /// Validate array shape constraints
     fn validate_array_shape(
         &self,
-        items: &[Value],
+        items: &ValueVector,
         shape: &[ArrayDimension],
         path: &str,
     ) -> ValidationResult<()> {
@@ -1151,7 +1151,7 @@ impl TypeValidator {
             }
 
             TypePredicate::Custom(name, _args) => {
-                Err(ValidationError::UnknownPredicate(name.0.clone()))
+                Err(ValidationError::UnknownPredicate(name.0.to_string()))
             }
         }
     }
@@ -1286,13 +1286,10 @@ mod tests {
             shape: vec![ArrayDimension::Fixed(3)],
         };
 
-        let valid_array = Value::Vector(vec![
-            Value::Integer(1),
-            Value::Integer(2),
-            Value::Integer(3),
-        ]);
+        let valid_array =
+            Value::Vector(vec![Value::Integer(1), Value::Integer(2), Value::Integer(3)].into());
 
-        let invalid_array = Value::Vector(vec![Value::Integer(1), Value::Integer(2)]);
+        let invalid_array = Value::Vector(vec![Value::Integer(1), Value::Integer(2)].into());
 
         assert!(validator.validate_value(&valid_array, &fixed_array).is_ok());
         assert!(validator
@@ -1331,7 +1328,7 @@ mod tests {
             value_type: Box::new(TypeExpr::Primitive(PrimitiveType::Int)),
         };
         assert!(validator
-            .validate_value(&Value::Map(string_keyed.clone()), &map_string_int)
+            .validate_value(&Value::Map(string_keyed.clone().into()), &map_string_int)
             .is_ok());
 
         // {:a 1}
@@ -1343,7 +1340,7 @@ mod tests {
             value_type: Box::new(TypeExpr::Primitive(PrimitiveType::Int)),
         };
         assert!(validator
-            .validate_value(&Value::Map(keyword_keyed.clone()), &map_keyword_int)
+            .validate_value(&Value::Map(keyword_keyed.clone().into()), &map_keyword_int)
             .is_ok());
 
         // Union key type accepts both
@@ -1355,17 +1352,20 @@ mod tests {
             value_type: Box::new(TypeExpr::Primitive(PrimitiveType::Int)),
         };
         assert!(validator
-            .validate_value(&Value::Map(string_keyed), &map_string_or_keyword_int)
+            .validate_value(&Value::Map(string_keyed.into()), &map_string_or_keyword_int)
             .is_ok());
         assert!(validator
-            .validate_value(&Value::Map(keyword_keyed), &map_string_or_keyword_int)
+            .validate_value(
+                &Value::Map(keyword_keyed.into()),
+                &map_string_or_keyword_int
+            )
             .is_ok());
 
         // Rejection: integer key should fail for (String|Keyword)
         let mut int_keyed = HashMap::new();
         int_keyed.insert(MapKey::Integer(1), Value::Integer(1));
         assert!(validator
-            .validate_value(&Value::Map(int_keyed), &map_string_or_keyword_int)
+            .validate_value(&Value::Map(int_keyed.into()), &map_string_or_keyword_int)
             .is_err());
     }
 }
@@ -8,9 +8,229 @@ use crate::runtime::error::RuntimeResult;
 use crate::runtime::Evaluator;
 use crate::runtime::IrEnvironment;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::HashSet;
 use std::fmt;
-use std::sync::{Arc, RwLock};
+use std::sync::{Arc, LazyLock, Mutex, RwLock};
+
+/// Strings up to this many bytes are interned by [`InternedStr::new`]
+pub const INTERN_MAX_LEN: usize = 64;
+
+/// The pool is split into independently locked shards so concurrent
+/// `Keyword::new` calls rarely contend
+const INTERN_SHARDS: usize = 16;
+
+/// A shard is swept once it holds at least this many entries
+const INTERN_MIN_SWEEP: usize = 256;
+
+struct InternShard {
+    strings: HashSet<Arc<str>>,
+    /// Size at which the next sweep runs; twice the live count after a sweep
+    sweep_at: usize,
+}
+
+static INTERN_POOL: LazyLock<[Mutex<InternShard>; INTERN_SHARDS]> = LazyLock::new(|| {
+    std::array::from_fn(|_| {
+        Mutex::new(InternShard {
+            strings: HashSet::new(),
+            sweep_at: INTERN_MIN_SWEEP,
+        })
+    })
+});
+
+fn intern_shard(s: &str) -> &'static Mutex<InternShard> {
+    use std::hash::{Hash, Hasher};
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    s.hash(&mut hasher);
+    &INTERN_POOL[hasher.finish() as usize % INTERN_SHARDS]
+}
+
+/// Number of strings currently held by the intern pool, including ones no
+/// longer referenced that the next sweep will drop
+pub fn interned_count() -> usize {
+    INTERN_POOL
+        .iter()
+        .map(|shard| {
+            let shard = shard.lock().unwrap_or_else(|e| e.into_inner());
+            shard.strings.len()
+        })
+        .sum()
+}
+
+/// Immutable, reference-counted string used for keyword and symbol names.
+///
+/// Cloning is a refcount bump instead of a heap allocation, and names up to
+/// [`INTERN_MAX_LEN`] bytes are deduplicated through a global pool so that the
+/// same keyword parsed or built many times shares one allocation. The pool
+/// only keeps strings alive while something else references them: once a
+/// shard doubles in size, entries it alone holds are dropped, so keywords
+/// built from untrusted input (JSON keys, `keyword` calls) don't accumulate.
+/// It derefs to `str` and compares equal to `str`/`String`, so most
+/// string-like uses work unchanged.
+#[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct InternedStr(Arc<str>);
+
+impl InternedStr {
+    pub fn new(s: &str) -> Self {
+        if s.len() > INTERN_MAX_LEN {
+            return InternedStr(Arc::from(s));
+        }
+        let mut shard = intern_shard(s).lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(existing) = shard.strings.get(s) {
+            return InternedStr(Arc::clone(existing));
+        }
+        if shard.strings.len() >= shard.sweep_at {
+            // A count of 1 means only the pool holds it, and new references
+            // are only handed out under this lock
+            shard.strings.retain(|entry| Arc::strong_count(entry) > 1);
+            shard.sweep_at = (shard.strings.len() * 2).max(INTERN_MIN_SWEEP);
+        }
+        let interned: Arc<str> = Arc::from(s);
+        shard.strings.insert(Arc::clone(&interned));
+        InternedStr(interned)
+    }
+
+    /// Allocate without pooling, for names that are unique by construction
+    /// (e.g. `gensym` output) and would otherwise grow the pool forever
+    pub fn new_uninterned(s: &str) -> Self {
+        InternedStr(Arc::from(s))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// True when both values share the same allocation
+    pub fn ptr_eq(&self, other: &InternedStr) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl std::ops::Deref for InternedStr {
+    type Target = str;
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for InternedStr {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::borrow::Borrow<str> for InternedStr {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Debug for InternedStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&*self.0, f)
+    }
+}
+
+impl fmt::Display for InternedStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&*self.0, f)
+    }
+}
+
+impl From<&str> for InternedStr {
+    fn from(s: &str) -> Self {
+        InternedStr::new(s)
+    }
+}
+
+impl From<String> for InternedStr {
+    fn from(s: String) -> Self {
+        InternedStr::new(&s)
+    }
+}
+
+impl From<&String> for InternedStr {
+    fn from(s: &String) -> Self {
+        InternedStr::new(s)
+    }
+}
+
+impl From<InternedStr> for String {
+    fn from(s: InternedStr) -> Self {
+        s.0.to_string()
+    }
+}
+
+impl From<&InternedStr> for String {
+    fn from(s: &InternedStr) -> Self {
+        s.0.to_string()
+    }
+}
+
+impl PartialEq<str> for InternedStr {
+    fn eq(&self, other: &str) -> bool {
+        &*self.0 == other
+    }
+}
+
+impl PartialEq<&str> for InternedStr {
+    fn eq(&self, other: &&str) -> bool {
+        &*self.0 == *other
+    }
+}
+
+impl PartialEq<String> for InternedStr {
+    fn eq(&self, other: &String) -> bool {
+        &*self.0 == other.as_str()
+    }
+}
+
+impl PartialEq<InternedStr> for str {
+    fn eq(&self, other: &InternedStr) -> bool {
+        self == &*other.0
+    }
+}
+
+impl PartialEq<InternedStr> for &str {
+    fn eq(&self, other: &InternedStr) -> bool {
+        *self == &*other.0
+    }
+}
+
+impl PartialEq<InternedStr> for String {
+    fn eq(&self, other: &InternedStr) -> bool {
+        self.as_str() == &*other.0
+    }
+}
+
+impl Serialize for InternedStr {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for InternedStr {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = std::borrow::Cow::<'de, str>::deserialize(deserializer)?;
+        Ok(InternedStr::new(&s))
+    }
+}
+
+impl schemars::JsonSchema for InternedStr {
+    fn schema_name() -> String {
+        String::schema_name()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        String::json_schema(gen)
+    }
+}
+
+/// Backing store for [`Value::Vector`]: a persistent vector, so cloning is a
+/// refcount bump and `conj`/`assoc` share structure with the original.
+pub type ValueVector = im::Vector<Value>;
+
+/// Backing store for [`Value::Map`]; persistent like [`ValueVector`].
+pub type ValueMap = im::HashMap<MapKey, Value>;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum Value {
@@ -25,9 +245,9 @@ pub enum Value {
     /// Removed atom functionality - use host state capabilities instead
     Symbol(Symbol),
     Keyword(Keyword),
-    Vector(Vec<Value>),
-    List(Vec<Value>),
-    Map(HashMap<MapKey, Value>),
+    Vector(ValueVector),
+    List(ValueVector),
+    Map(ValueMap),
     #[serde(skip_serializing, skip_deserializing)]
     Function(Function),
     #[serde(skip_serializing, skip_deserializing)]
@@ -39,6 +259,12 @@ pub enum Value {
 pub struct ErrorValue {
     pub message: String,
     pub stack_trace: Option<Vec<String>>,
+    /// Kind of error (e.g. `:DivisionByZero` or a thrown `:my/type`)
+    #[serde(default)]
+    pub error_type: Option<Keyword>,
+    /// Structured payload attached with `ex-info`, read back with `ex-data`
+    #[serde(default)]
+    pub data: Option<Box<Value>>,
 }
 
 impl fmt::Display for Value {
@@ -66,7 +292,7 @@ impl fmt::Display for Value {
                 let items: Vec<String> = m.iter().map(|(k, v)| format!("{} {}", k, v)).collect();
                 write!(f, "{{{}}}", items.join(" "))
             }
-            Value::Function(_) => write!(f, "#<function>"),
+            Value::Function(func) => write!(f, "{}", func),
             Value::FunctionPlaceholder(_) => write!(f, "#<function-placeholder>"),
             Value::Error(e) => write!(f, "#<error: {}>", e.message),
         }
@@ -82,6 +308,22 @@ impl Value {
         }
     }
 
+    /// Element-wise equality that ignores the list/vector distinction (at any
+    /// depth), unlike `==` where `(1 2)` and `[1 2]` differ. Backs `seq=`.
+    pub fn seq_eq(&self, other: &Value) -> bool {
+        match (self, other) {
+            (Value::List(a) | Value::Vector(a), Value::List(b) | Value::Vector(b)) => {
+                a.len() == b.len() && a.iter().zip(b.iter()).all(|(x, y)| x.seq_eq(y))
+            }
+            (Value::Map(a), Value::Map(b)) => {
+                a.len() == b.len()
+                    && a.iter()
+                        .all(|(k, v)| b.get(k).is_some_and(|other| v.seq_eq(other)))
+            }
+            _ => self == other,
+        }
+    }
+
     pub fn type_name(&self) -> &'static str {
         match self {
             Value::Nil => "nil",
@@ -103,7 +345,17 @@ impl Value {
         }
     }
 
-    /// Compare two values for ordering
+    /// Total ordering over all values, exposed to RTFS as `compare` and used
+    /// by `sort`/`sort-by`.
+    ///
+    /// Within a type: booleans `false < true`, numbers by value (integers and
+    /// floats compare numerically, so `1` equals `1.0`; NaN compares equal to
+    /// everything), strings/keywords/symbols lexically by their text, vectors
+    /// and lists element-wise then by length, maps by their entries sorted by key.
+    ///
+    /// Across types the order is:
+    /// `nil < boolean < number < string < keyword < symbol < vector < list < map`,
+    /// with any remaining types after maps, ordered by their printed form.
     pub fn compare(&self, other: &Value) -> std::cmp::Ordering {
         match (self, other) {
             (Value::Nil, Value::Nil) => std::cmp::Ordering::Equal,
@@ -242,6 +494,26 @@ pub enum Arity {
     Range(usize, usize), // min, max
 }
 
+impl Arity {
+    /// Whether a call with `arg_count` arguments satisfies this arity.
+    pub fn accepts(&self, arg_count: usize) -> bool {
+        match self {
+            Arity::Fixed(n) => arg_count == *n,
+            Arity::Variadic(n) => arg_count >= *n,
+            Arity::Range(min, max) => arg_count >= *min && arg_count <= *max,
+        }
+    }
+
+    /// Human-readable expected argument count, as used in arity errors.
+    pub fn describe(&self) -> String {
+        match self {
+            Arity::Fixed(n) => n.to_string(),
+            Arity::Variadic(n) => format!("at least {}", n),
+            Arity::Range(min, max) => format!("between {} and {}", min, max),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum ResourceState {
     Active,
@@ -336,6 +608,19 @@ impl fmt::Debug for Function {
     }
 }
 
+/// Readable tag used by `str` and error messages: `#<builtin map>` for named
+/// builtins, `#<fn>` for anonymous closures.
+impl fmt::Display for Function {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Function::Builtin(b) => write!(f, "#<builtin {}>", b.name),
+            Function::BuiltinWithContext(b) => write!(f, "#<builtin {}>", b.name),
+            Function::Native(b) => write!(f, "#<native {}>", b.name),
+            Function::Closure(_) | Function::Ir(_) => write!(f, "#<fn>"),
+        }
+    }
+}
+
 impl PartialEq for Function {
     fn eq(&self, other: &Self) -> bool {
         match (self, other) {
@@ -351,6 +636,10 @@ impl PartialEq for Function {
     }
 }
 
+/// Structural equality. The collection type is part of a value, so a list and a
+/// vector are never equal even with identical elements: `(= (list 1 2) [1 2])`
+/// is false, and `contains?`/`distinct` keep them apart. Use [`Value::seq_eq`]
+/// (`seq=`) to compare sequences element-wise regardless of type.
 impl PartialEq for Value {
     fn eq(&self, other: &Self) -> bool {
         use Value::*;
@@ -413,6 +702,29 @@ pub struct Closure {
     pub return_type: Option<crate::ast::TypeExpr>,
 }
 
+impl Closure {
+    /// Checks that `actual` arguments satisfy the declared parameters,
+    /// counting a rest parameter as "at least" the required ones.
+    /// `name` identifies the closure in the resulting `ArityMismatch`.
+    pub fn check_arity(&self, name: &str, actual: usize) -> RuntimeResult<()> {
+        let required = self.param_patterns.len();
+        let (ok, expected) = if self.variadic_param.is_some() {
+            (actual >= required, format!("at least {}", required))
+        } else {
+            (actual == required, required.to_string())
+        };
+        if ok {
+            Ok(())
+        } else {
+            Err(crate::runtime::error::RuntimeError::ArityMismatch {
+                function: name.to_string(),
+                expected,
+                actual,
+            })
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct IrLambda {
     pub params: Vec<IrNode>,
@@ -441,7 +753,9 @@ impl From<Expression> for Value {
                 let values = map.into_iter().map(|(k, v)| (k, Value::from(v))).collect();
                 Value::Map(values)
             }
-            Expression::FunctionCall { callee, arguments } => {
+            Expression::FunctionCall {
+                callee, arguments, ..
+            } => {
                 // For now, return a placeholder function value
                 // In a real implementation, this would evaluate the function call
                 Value::String(format!(
@@ -1,8 +1,11 @@
 //! Supervisor module for RTFS/CCOS
 //!
 //! This module provides functionality for supervising MicroVM deployments,
-//! including spec synthesis and Firecracker integration.
+//! including spec synthesis and Firecracker integration, and restart policies
+//! for supervised RTFS execution tasks.
 
+pub mod restart;
 pub mod spec_synth;
 
+pub use restart::{RestartPolicy, RestartStrategy, Supervisor, TaskStatus};
 pub use spec_synth::*;
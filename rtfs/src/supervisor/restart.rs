@@ -0,0 +1,231 @@
+//! Restart policies for supervised RTFS execution tasks
+//!
+//! A [`Supervisor`] runs named tasks (typically an evaluation of a plan or a
+//! capability call) and contains their failures: errors and panics are
+//! caught, reported to the host, and the task is restarted according to its
+//! [`RestartPolicy`]. When a task exhausts its restart budget it is escalated
+//! to a permanent failure instead of being retried forever.
+//!
+//! Every attempt is reported through [`HostInterface`] step notifications, so
+//! the CCOS host records restarts and the final escalation in the Causal Chain.
+
+use crate::runtime::error::{RuntimeError, RuntimeResult};
+use crate::runtime::host_interface::HostInterface;
+use crate::runtime::stubs::ExecutionResultStruct;
+use crate::runtime::values::Value;
+use std::collections::{HashMap, VecDeque};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Which tasks are restarted when one of them fails
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RestartStrategy {
+    /// Only the failed task is restarted; siblings are left untouched
+    OneForOne,
+}
+
+/// How often a supervised task may be restarted before it is given up on
+#[derive(Debug, Clone, PartialEq)]
+pub struct RestartPolicy {
+    pub strategy: RestartStrategy,
+    /// Maximum number of restarts allowed within `window`
+    pub max_restarts: u32,
+    /// Sliding window over which restarts are counted
+    pub window: Duration,
+}
+
+impl RestartPolicy {
+    pub fn one_for_one(max_restarts: u32, window: Duration) -> Self {
+        Self {
+            strategy: RestartStrategy::OneForOne,
+            max_restarts,
+            window,
+        }
+    }
+}
+
+impl Default for RestartPolicy {
+    fn default() -> Self {
+        Self::one_for_one(3, Duration::from_secs(60))
+    }
+}
+
+/// Lifecycle state of a supervised task
+#[derive(Debug, Clone, PartialEq)]
+pub enum TaskStatus {
+    Running,
+    Completed,
+    /// The restart budget was exhausted; the task will not be run again
+    PermanentlyFailed {
+        error: String,
+    },
+}
+
+#[derive(Debug)]
+struct TaskState {
+    status: TaskStatus,
+    restarts: u32,
+    recent_restarts: VecDeque<Instant>,
+}
+
+impl TaskState {
+    fn new() -> Self {
+        Self {
+            status: TaskStatus::Running,
+            restarts: 0,
+            recent_restarts: VecDeque::new(),
+        }
+    }
+}
+
+/// Runs tasks under a restart policy, reporting each attempt to the host
+#[derive(Debug)]
+pub struct Supervisor {
+    policy: RestartPolicy,
+    host: Arc<dyn HostInterface>,
+    tasks: HashMap<String, TaskState>,
+}
+
+impl Supervisor {
+    pub fn new(policy: RestartPolicy, host: Arc<dyn HostInterface>) -> Self {
+        Self {
+            policy,
+            host,
+            tasks: HashMap::new(),
+        }
+    }
+
+    pub fn policy(&self) -> &RestartPolicy {
+        &self.policy
+    }
+
+    /// Run `task` under supervision until it succeeds or is escalated.
+    ///
+    /// Each attempt is reported as a `supervisor:<name>` step. A failed or
+    /// panicking attempt is restarted while the policy allows it; once the
+    /// budget is exhausted the task is marked permanently failed and the last
+    /// error is returned. A permanently failed task is not run again.
+    pub fn run<F>(&mut self, name: &str, mut task: F) -> RuntimeResult<Value>
+    where
+        F: FnMut() -> RuntimeResult<Value>,
+    {
+        let state = self
+            .tasks
+            .entry(name.to_string())
+            .or_insert_with(TaskState::new);
+        if let TaskStatus::PermanentlyFailed { error } = &state.status {
+            return Err(RuntimeError::Generic(format!(
+                "supervised task '{}' has permanently failed: {}",
+                name, error
+            )));
+        }
+        state.status = TaskStatus::Running;
+
+        loop {
+            let action_id = self
+                .host
+                .notify_step_started(&format!("supervisor:{}", name))?;
+
+            let error = match panic::catch_unwind(AssertUnwindSafe(&mut task)) {
+                Ok(Ok(value)) => {
+                    self.host.notify_step_completed(
+                        &action_id,
+                        &ExecutionResultStruct {
+                            success: true,
+                            value: value.clone(),
+                            metadata: HashMap::new(),
+                        },
+                    )?;
+                    self.set_status(name, TaskStatus::Completed);
+                    return Ok(value);
+                }
+                Ok(Err(error)) => error.to_string(),
+                Err(payload) => format!("task panicked: {}", panic_message(payload.as_ref())),
+            };
+
+            if self.try_restart(name) {
+                self.host.notify_step_failed(
+                    &action_id,
+                    &format!(
+                        "{} (restart {} of {})",
+                        error,
+                        self.restart_count(name),
+                        self.policy.max_restarts
+                    ),
+                )?;
+                continue;
+            }
+
+            let escalated = format!(
+                "permanently failed after {} restart(s) within {:?}: {}",
+                self.restart_count(name),
+                self.policy.window,
+                error
+            );
+            self.host.notify_step_failed(&action_id, &escalated)?;
+            self.set_status(
+                name,
+                TaskStatus::PermanentlyFailed {
+                    error: error.clone(),
+                },
+            );
+            return Err(RuntimeError::Generic(format!(
+                "supervised task '{}' {}",
+                name, escalated
+            )));
+        }
+    }
+
+    /// Current status of a task, if it has been run
+    pub fn status(&self, name: &str) -> Option<&TaskStatus> {
+        self.tasks.get(name).map(|state| &state.status)
+    }
+
+    /// Total number of times a task has been restarted
+    pub fn restart_count(&self, name: &str) -> u32 {
+        self.tasks.get(name).map_or(0, |state| state.restarts)
+    }
+
+    /// Record a restart if the task still has budget left in the window
+    fn try_restart(&mut self, name: &str) -> bool {
+        let RestartPolicy {
+            max_restarts,
+            window,
+            ..
+        } = self.policy;
+        let Some(state) = self.tasks.get_mut(name) else {
+            return false;
+        };
+        let now = Instant::now();
+        while let Some(oldest) = state.recent_restarts.front() {
+            if now.duration_since(*oldest) > window {
+                state.recent_restarts.pop_front();
+            } else {
+                break;
+            }
+        }
+        if state.recent_restarts.len() >= max_restarts as usize {
+            return false;
+        }
+        state.recent_restarts.push_back(now);
+        state.restarts += 1;
+        true
+    }
+
+    fn set_status(&mut self, name: &str, status: TaskStatus) {
+        if let Some(state) = self.tasks.get_mut(name) {
+            state.status = status;
+        }
+    }
+}
+
+fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
@@ -17,7 +17,7 @@ pub fn format_map_key(key: &MapKey) -> String {
 /// Convert MapKey to String
 pub fn map_key_to_string(key: &MapKey) -> String {
     match key {
-        MapKey::Keyword(k) => k.0.clone(),
+        MapKey::Keyword(k) => k.0.to_string(),
         MapKey::String(s) => s.clone(),
         MapKey::Integer(i) => i.to_string(),
     }
@@ -96,10 +96,10 @@ pub fn rtfs_value_to_json(value: &Value) -> RuntimeResult<serde_json::Value> {
             Ok(serde_json::Value::String(if s.starts_with(':') {
                 s[1..].to_string()
             } else {
-                s.clone()
+                s.to_string()
             }))
         }
-        Value::Symbol(s) => Ok(serde_json::Value::String(s.0.clone())),
+        Value::Symbol(s) => Ok(serde_json::Value::String(s.0.to_string())),
         Value::Vector(v) => {
             let json_array: Result<Vec<serde_json::Value>, RuntimeError> =
                 v.iter().map(rtfs_value_to_json).collect();
@@ -157,7 +157,7 @@ pub fn json_to_rtfs_value(json: &serde_json::Value) -> RuntimeResult<Value> {
         serde_json::Value::Array(arr) => {
             let values: Result<Vec<Value>, RuntimeError> =
                 arr.iter().map(json_to_rtfs_value).collect();
-            Ok(Value::Vector(values?))
+            Ok(Value::Vector(values?.into()))
         }
         serde_json::Value::Object(obj) => {
             let mut map = HashMap::new();
@@ -166,13 +166,13 @@ pub fn json_to_rtfs_value(json: &serde_json::Value) -> RuntimeResult<Value> {
                 // Otherwise use string key
                 let key_str = strip_leading_colon(k);
                 let map_key = if k.starts_with(':') {
-                    MapKey::Keyword(Keyword(key_str))
+                    MapKey::Keyword(Keyword(key_str.into()))
                 } else {
                     MapKey::String(key_str)
                 };
                 map.insert(map_key, json_to_rtfs_value(v)?);
             }
-            Ok(Value::Map(map))
+            Ok(Value::Map(map.into()))
         }
     }
 }
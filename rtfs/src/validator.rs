@@ -350,7 +350,7 @@ impl SchemaValidator {
     pub fn properties_to_map(properties: &[Property]) -> HashMap<String, &crate::ast::Expression> {
         properties
             .iter()
-            .map(|prop| (prop.key.0.clone(), &prop.value))
+            .map(|prop| (prop.key.0.to_string(), &prop.value))
             .collect()
     }
 
@@ -99,16 +99,16 @@ mod literal_coverage {
     fn test_keyword_literals() {
         assert_expr_parses_to!(
             ":simple",
-            Expression::Literal(Literal::Keyword(Keyword("simple".to_string())))
+            Expression::Literal(Literal::Keyword(Keyword("simple".into())))
         );
         assert_expr_parses_to!(
             ":namespaced/keyword",
-            Expression::Literal(Literal::Keyword(Keyword("namespaced/keyword".to_string())))
+            Expression::Literal(Literal::Keyword(Keyword("namespaced/keyword".into())))
         );
         assert_expr_parses_to!(
             ":versioned.namespace:v1.0/keyword",
             Expression::Literal(Literal::Keyword(Keyword(
-                "versioned.namespace:v1.0/keyword".to_string()
+                "versioned.namespace:v1.0/keyword".into()
             )))
         );
     }
@@ -158,32 +158,26 @@ mod symbol_coverage {
 
     #[test]
     fn test_simple_symbols() {
-        assert_expr_parses_to!("symbol", Expression::Symbol(Symbol("symbol".to_string())));
-        assert_expr_parses_to!(
-            "my-symbol",
-            Expression::Symbol(Symbol("my-symbol".to_string()))
-        );
+        assert_expr_parses_to!("symbol", Expression::Symbol(Symbol("symbol".into())));
+        assert_expr_parses_to!("my-symbol", Expression::Symbol(Symbol("my-symbol".into())));
         assert_expr_parses_to!(
             "with_underscore",
-            Expression::Symbol(Symbol("with_underscore".to_string()))
+            Expression::Symbol(Symbol("with_underscore".into()))
         );
-        assert_expr_parses_to!(
-            "$special",
-            Expression::Symbol(Symbol("$special".to_string()))
-        );
-        assert_expr_parses_to!("+", Expression::Symbol(Symbol("+".to_string())));
-        assert_expr_parses_to!("<=", Expression::Symbol(Symbol("<=".to_string())));
+        assert_expr_parses_to!("$special", Expression::Symbol(Symbol("$special".into())));
+        assert_expr_parses_to!("+", Expression::Symbol(Symbol("+".into())));
+        assert_expr_parses_to!("<=", Expression::Symbol(Symbol("<=".into())));
     }
 
     #[test]
     fn test_namespaced_symbols() {
         assert_expr_parses_to!(
             "namespace/symbol",
-            Expression::Symbol(Symbol("namespace/symbol".to_string()))
+            Expression::Symbol(Symbol("namespace/symbol".into()))
         );
         assert_expr_parses_to!(
             "my.nested.namespace/symbol",
-            Expression::Symbol(Symbol("my.nested.namespace/symbol".to_string()))
+            Expression::Symbol(Symbol("my.nested.namespace/symbol".into()))
         );
     }
 
@@ -191,11 +185,11 @@ mod symbol_coverage {
     fn test_versioned_symbols() {
         assert_expr_parses_to!(
             "com.example:v1.0/function",
-            Expression::Symbol(Symbol("com.example:v1.0/function".to_string()))
+            Expression::Symbol(Symbol("com.example:v1.0/function".into()))
         );
         assert_expr_parses_to!(
             "my.lib:v2.1.3/utility",
-            Expression::Symbol(Symbol("my.lib:v2.1.3/utility".to_string()))
+            Expression::Symbol(Symbol("my.lib:v2.1.3/utility".into()))
         );
     }
 }
@@ -231,7 +225,7 @@ mod collection_coverage {
             "[\"hello\" :world true]",
             Expression::Vector(vec![
                 Expression::Literal(Literal::String("hello".to_string())),
-                Expression::Literal(Literal::Keyword(Keyword("world".to_string()))),
+                Expression::Literal(Literal::Keyword(Keyword("world".into()))),
                 Expression::Literal(Literal::Boolean(true)),
             ])
         );
@@ -258,11 +252,12 @@ mod collection_coverage {
         assert_expr_parses_to!(
             "(+ 1 2)",
             Expression::FunctionCall {
-                callee: Box::new(Expression::Symbol(Symbol("+".to_string()))),
+                callee: Box::new(Expression::Symbol(Symbol("+".into()))),
                 arguments: vec![
                     Expression::Literal(Literal::Integer(1)),
                     Expression::Literal(Literal::Integer(2)),
-                ]
+                ],
+                span: Span::unknown(),
             }
         );
     }
@@ -271,11 +266,11 @@ mod collection_coverage {
     fn test_map_collections() {
         let mut expected_map = HashMap::new();
         expected_map.insert(
-            MapKey::Keyword(Keyword("name".to_string())),
+            MapKey::Keyword(Keyword("name".into())),
             Expression::Literal(Literal::String("John".to_string())),
         );
         expected_map.insert(
-            MapKey::Keyword(Keyword("age".to_string())),
+            MapKey::Keyword(Keyword("age".into())),
             Expression::Literal(Literal::Integer(30)),
         );
 
@@ -317,11 +312,11 @@ mod special_form_coverage {
             "(let [x 10] x)",
             Expression::Let(LetExpr {
                 bindings: vec![LetBinding {
-                    pattern: Pattern::Symbol(Symbol("x".to_string())),
+                    pattern: Pattern::Symbol(Symbol("x".into())),
                     type_annotation: None,
                     value: Box::new(Expression::Literal(Literal::Integer(10))),
                 }],
-                body: vec![Expression::Symbol(Symbol("x".to_string()))],
+                body: vec![Expression::Symbol(Symbol("x".into()))],
             })
         );
 
@@ -330,11 +325,11 @@ mod special_form_coverage {
             "(let [x:int 42] x)",
             Expression::Let(LetExpr {
                 bindings: vec![LetBinding {
-                    pattern: Pattern::Symbol(Symbol("x".to_string())),
+                    pattern: Pattern::Symbol(Symbol("x".into())),
                     type_annotation: Some(TypeExpr::Primitive(PrimitiveType::Int)),
                     value: Box::new(Expression::Literal(Literal::Integer(42))),
                 }],
-                body: vec![Expression::Symbol(Symbol("x".to_string()))],
+                body: vec![Expression::Symbol(Symbol("x".into()))],
             })
         );
 
@@ -344,22 +339,23 @@ mod special_form_coverage {
             Expression::Let(LetExpr {
                 bindings: vec![
                     LetBinding {
-                        pattern: Pattern::Symbol(Symbol("x".to_string())),
+                        pattern: Pattern::Symbol(Symbol("x".into())),
                         type_annotation: None,
                         value: Box::new(Expression::Literal(Literal::Integer(10))),
                     },
                     LetBinding {
-                        pattern: Pattern::Symbol(Symbol("y".to_string())),
+                        pattern: Pattern::Symbol(Symbol("y".into())),
                         type_annotation: None,
                         value: Box::new(Expression::Literal(Literal::Integer(20))),
                     }
                 ],
                 body: vec![Expression::FunctionCall {
-                    callee: Box::new(Expression::Symbol(Symbol("+".to_string()))),
+                    callee: Box::new(Expression::Symbol(Symbol("+".into()))),
                     arguments: vec![
-                        Expression::Symbol(Symbol("x".to_string())),
-                        Expression::Symbol(Symbol("y".to_string())),
-                    ]
+                        Expression::Symbol(Symbol("x".into())),
+                        Expression::Symbol(Symbol("y".into())),
+                    ],
+                    span: Span::unknown(),
                 }],
             })
         );
@@ -417,17 +413,18 @@ mod special_form_coverage {
             "(fn [x] (* x 2))",
             Expression::Fn(FnExpr {
                 params: vec![ParamDef {
-                    pattern: Pattern::Symbol(Symbol("x".to_string())),
+                    pattern: Pattern::Symbol(Symbol("x".into())),
                     type_annotation: None,
                 }],
                 variadic_param: None,
                 return_type: None,
                 body: vec![Expression::FunctionCall {
-                    callee: Box::new(Expression::Symbol(Symbol("*".to_string()))),
+                    callee: Box::new(Expression::Symbol(Symbol("*".into()))),
                     arguments: vec![
-                        Expression::Symbol(Symbol("x".to_string())),
+                        Expression::Symbol(Symbol("x".into())),
                         Expression::Literal(Literal::Integer(2)),
-                    ]
+                    ],
+                    span: Span::unknown(),
                 }],
                 delegation_hint: None,
             })
@@ -438,17 +435,18 @@ mod special_form_coverage {
             "(fn [x:int]:int (* x 2))",
             Expression::Fn(FnExpr {
                 params: vec![ParamDef {
-                    pattern: Pattern::Symbol(Symbol("x".to_string())),
+                    pattern: Pattern::Symbol(Symbol("x".into())),
                     type_annotation: Some(TypeExpr::Primitive(PrimitiveType::Int)),
                 }],
                 variadic_param: None,
                 return_type: Some(TypeExpr::Primitive(PrimitiveType::Int)),
                 body: vec![Expression::FunctionCall {
-                    callee: Box::new(Expression::Symbol(Symbol("*".to_string()))),
+                    callee: Box::new(Expression::Symbol(Symbol("*".into()))),
                     arguments: vec![
-                        Expression::Symbol(Symbol("x".to_string())),
+                        Expression::Symbol(Symbol("x".into())),
                         Expression::Literal(Literal::Integer(2)),
-                    ]
+                    ],
+                    span: Span::unknown(),
                 }],
                 delegation_hint: None,
             })
@@ -459,15 +457,15 @@ mod special_form_coverage {
             "(fn [x & rest] x)",
             Expression::Fn(FnExpr {
                 params: vec![ParamDef {
-                    pattern: Pattern::Symbol(Symbol("x".to_string())),
+                    pattern: Pattern::Symbol(Symbol("x".into())),
                     type_annotation: None,
                 }],
                 variadic_param: Some(ParamDef {
-                    pattern: Pattern::Symbol(Symbol("rest".to_string())),
+                    pattern: Pattern::Symbol(Symbol("rest".into())),
                     type_annotation: None,
                 }),
                 return_type: None,
-                body: vec![Expression::Symbol(Symbol("x".to_string()))],
+                body: vec![Expression::Symbol(Symbol("x".into()))],
                 delegation_hint: None,
             })
         );
@@ -478,7 +476,7 @@ mod special_form_coverage {
         assert_expr_parses_to!(
             "(def x 42)",
             Expression::Def(Box::new(DefExpr {
-                symbol: Symbol("x".to_string()),
+                symbol: Symbol("x".into()),
                 type_annotation: None,
                 value: Box::new(Expression::Literal(Literal::Integer(42))),
             }))
@@ -488,7 +486,7 @@ mod special_form_coverage {
         assert_expr_parses_to!(
             "(def x:int 42)",
             Expression::Def(Box::new(DefExpr {
-                symbol: Symbol("x".to_string()),
+                symbol: Symbol("x".into()),
                 type_annotation: Some(TypeExpr::Primitive(PrimitiveType::Int)),
                 value: Box::new(Expression::Literal(Literal::Integer(42))),
             }))
@@ -500,19 +498,20 @@ mod special_form_coverage {
         assert_expr_parses_to!(
             "(defn square [x] (* x x))",
             Expression::Defn(Box::new(DefnExpr {
-                name: Symbol("square".to_string()),
+                name: Symbol("square".into()),
                 params: vec![ParamDef {
-                    pattern: Pattern::Symbol(Symbol("x".to_string())),
+                    pattern: Pattern::Symbol(Symbol("x".into())),
                     type_annotation: None,
                 }],
                 variadic_param: None,
                 return_type: None,
                 body: vec![Expression::FunctionCall {
-                    callee: Box::new(Expression::Symbol(Symbol("*".to_string()))),
+                    callee: Box::new(Expression::Symbol(Symbol("*".into()))),
                     arguments: vec![
-                        Expression::Symbol(Symbol("x".to_string())),
-                        Expression::Symbol(Symbol("x".to_string())),
-                    ]
+                        Expression::Symbol(Symbol("x".into())),
+                        Expression::Symbol(Symbol("x".into())),
+                    ],
+                    span: Span::unknown(),
                 }],
                 delegation_hint: None,
                 metadata: None,
@@ -530,20 +529,20 @@ mod advanced_form_coverage {
         assert_expr_parses_to!(
             "(match x 1 :one 2 :two)",
             Expression::Match(MatchExpr {
-                expression: Box::new(Expression::Symbol(Symbol("x".to_string()))),
+                expression: Box::new(Expression::Symbol(Symbol("x".into()))),
                 clauses: vec![
                     MatchClause {
                         pattern: MatchPattern::Literal(Literal::Integer(1)),
                         guard: None,
                         body: Box::new(Expression::Literal(Literal::Keyword(Keyword(
-                            "one".to_string()
+                            "one".into()
                         )))),
                     },
                     MatchClause {
                         pattern: MatchPattern::Literal(Literal::Integer(2)),
                         guard: None,
                         body: Box::new(Expression::Literal(Literal::Keyword(Keyword(
-                            "two".to_string()
+                            "two".into()
                         )))),
                     },
                 ],
@@ -557,17 +556,18 @@ mod advanced_form_coverage {
             "(try (/ 1 0) (catch Exception e :error))",
             Expression::TryCatch(TryCatchExpr {
                 try_body: vec![Expression::FunctionCall {
-                    callee: Box::new(Expression::Symbol(Symbol("/".to_string()))),
+                    callee: Box::new(Expression::Symbol(Symbol("/".into()))),
                     arguments: vec![
                         Expression::Literal(Literal::Integer(1)),
                         Expression::Literal(Literal::Integer(0)),
-                    ]
+                    ],
+                    span: Span::unknown(),
                 }],
                 catch_clauses: vec![CatchClause {
-                    pattern: CatchPattern::Symbol(Symbol("Exception".to_string())),
-                    binding: Symbol("e".to_string()),
+                    pattern: CatchPattern::Symbol(Symbol("Exception".into())),
+                    binding: Symbol("e".into()),
                     body: vec![Expression::Literal(Literal::Keyword(Keyword(
-                        "error".to_string()
+                        "error".into()
                     )))],
                 }],
                 finally_body: None,
@@ -591,7 +591,7 @@ mod context_and_resource_coverage {
 
         assert_expr_parses_to!(
             "@:context-key",
-            Expression::Symbol(Symbol("context-key".to_string()))
+            Expression::Symbol(Symbol("context-key".into()))
         );
     }
 
@@ -620,9 +620,7 @@ mod pattern_coverage {
                     type_annotation: None,
                     value: Box::new(Expression::Literal(Literal::Integer(42))),
                 }],
-                body: vec![Expression::Literal(Literal::Keyword(Keyword(
-                    "ok".to_string()
-                )))],
+                body: vec![Expression::Literal(Literal::Keyword(Keyword("ok".into())))],
             })
         );
     }
@@ -635,8 +633,8 @@ mod pattern_coverage {
                 bindings: vec![LetBinding {
                     pattern: Pattern::VectorDestructuring {
                         elements: vec![
-                            Pattern::Symbol(Symbol("x".to_string())),
-                            Pattern::Symbol(Symbol("y".to_string())),
+                            Pattern::Symbol(Symbol("x".into())),
+                            Pattern::Symbol(Symbol("y".into())),
                         ],
                         rest: None,
                         as_symbol: None,
@@ -647,7 +645,7 @@ mod pattern_coverage {
                         Expression::Literal(Literal::Integer(2)),
                     ])),
                 }],
-                body: vec![Expression::Symbol(Symbol("x".to_string()))],
+                body: vec![Expression::Symbol(Symbol("x".into()))],
             })
         );
 
@@ -657,8 +655,8 @@ mod pattern_coverage {
             Expression::Let(LetExpr {
                 bindings: vec![LetBinding {
                     pattern: Pattern::VectorDestructuring {
-                        elements: vec![Pattern::Symbol(Symbol("x".to_string()))],
-                        rest: Some(Symbol("rest".to_string())),
+                        elements: vec![Pattern::Symbol(Symbol("x".into()))],
+                        rest: Some(Symbol("rest".into())),
                         as_symbol: None,
                     },
                     type_annotation: None,
@@ -669,7 +667,7 @@ mod pattern_coverage {
                         Expression::Literal(Literal::Integer(4)),
                     ])),
                 }],
-                body: vec![Expression::Symbol(Symbol("x".to_string()))],
+                body: vec![Expression::Symbol(Symbol("x".into()))],
             })
         );
     }
@@ -682,8 +680,8 @@ mod pattern_coverage {
                 bindings: vec![LetBinding {
                     pattern: Pattern::MapDestructuring {
                         entries: vec![MapDestructuringEntry::Keys(vec![
-                            Symbol("name".to_string()),
-                            Symbol("age".to_string()),
+                            Symbol("name".into()),
+                            Symbol("age".into()),
                         ])],
                         rest: None,
                         as_symbol: None,
@@ -692,17 +690,17 @@ mod pattern_coverage {
                     value: Box::new({
                         let mut map = HashMap::new();
                         map.insert(
-                            MapKey::Keyword(Keyword("name".to_string())),
+                            MapKey::Keyword(Keyword("name".into())),
                             Expression::Literal(Literal::String("John".to_string())),
                         );
                         map.insert(
-                            MapKey::Keyword(Keyword("age".to_string())),
+                            MapKey::Keyword(Keyword("age".into())),
                             Expression::Literal(Literal::Integer(30)),
                         );
                         Expression::Map(map)
                     }),
                 }],
-                body: vec![Expression::Symbol(Symbol("name".to_string()))],
+                body: vec![Expression::Symbol(Symbol("name".into()))],
             })
         );
     }
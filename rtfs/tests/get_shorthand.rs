@@ -45,7 +45,7 @@ impl HostInterface for StubHost {
                 if args.len() >= 1 {
                     if let rtfs::runtime::values::Value::Map(map) = &args[0] {
                         if let Some(rtfs::runtime::values::Value::String(key)) =
-                            map.get(&MapKey::Keyword(Keyword("key".to_string())))
+                            map.get(&MapKey::Keyword(Keyword("key".into())))
                         {
                             if key == "k" {
                                 return Ok(rtfs::runtime::values::Value::String("v".to_string()));
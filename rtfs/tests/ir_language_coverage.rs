@@ -241,6 +241,7 @@ fn test_ir_conversion_function_call() {
             Expression::Literal(Literal::Integer(1)),
             Expression::Literal(Literal::Integer(2)),
         ],
+        span: Span::unknown(),
     };
     let ir = converter
         .convert_expression(ast)
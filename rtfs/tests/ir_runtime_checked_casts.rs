@@ -24,7 +24,7 @@ impl HostInterface for StubHost {
                     rtfs::ast::MapKey::Keyword(rtfs::ast::Keyword::new("a")),
                     Value::Integer(1),
                 );
-                Ok(Value::Map(m))
+                Ok(Value::Map(m.into()))
             }
             _ => Ok(Value::Nil),
         }
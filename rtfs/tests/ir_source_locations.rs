@@ -0,0 +1,47 @@
+// Runtime errors from IR-compiled programs point back at the failing call in the source
+
+use rtfs::parser::parse_expression;
+use rtfs::runtime::error::RuntimeError;
+use rtfs::runtime::ir_runtime::IrStrategy;
+use rtfs::runtime::module_runtime::ModuleRegistry;
+use rtfs::RuntimeStrategy;
+use std::sync::Arc;
+
+fn run_ir(source: &str) -> RuntimeError {
+    let expr = parse_expression(source).expect("Should parse successfully");
+    let mut strategy = IrStrategy::new(Arc::new(ModuleRegistry::new()));
+    match strategy.run(&expr) {
+        Err(e) => e,
+        Ok(outcome) => panic!("expected an error, got {:?}", outcome),
+    }
+}
+
+fn location(error: &RuntimeError) -> Option<(usize, usize)> {
+    match error {
+        RuntimeError::Located { line, column, .. } => Some((*line, *column)),
+        _ => None,
+    }
+}
+
+#[test]
+fn test_ir_error_reports_source_line() {
+    let error = run_ir("(let [x 1\n      y 0]\n  (+ x\n     (/ x y)))");
+    assert_eq!(location(&error), Some((4, 6)));
+    assert_eq!(error.without_location(), &RuntimeError::DivisionByZero);
+    assert!(error.to_string().contains("line 4, column 6"), "{}", error);
+}
+
+#[test]
+fn test_ir_error_points_inside_called_function() {
+    // The failing call is in the function body, not at the call site
+    let error = run_ir("(do\n  (defn half [n]\n    (/ n (count [])))\n  (half 4))");
+    assert_eq!(location(&error), Some((3, 5)));
+}
+
+#[test]
+fn test_caught_errors_do_not_leak_their_location() {
+    let error = run_ir(
+        "(do\n  (try (/ 1 0) (catch Exception e nil))\n  (let [v []]\n    (/ 2 (count v))))",
+    );
+    assert_eq!(location(&error), Some((4, 5)));
+}
@@ -22,7 +22,9 @@ mod test_complex_structural_types;
 mod test_helpers; // Re-exports from shared
 mod test_implemented_functions;
 mod test_missing_stdlib_functions;
+mod test_mock_host;
 mod test_pattern_matching;
+mod test_pmap_call;
 mod test_qualified_symbols;
 mod test_recursive_patterns;
 mod test_simple_recursion;
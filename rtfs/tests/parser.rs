@@ -5,7 +5,7 @@ use std::collections::HashMap;
 // Import the main parser function
 use rtfs::parser::parse;
 // Import the AST nodes we need to check against
-use rtfs::ast::{Expression, Literal, MapKey, ResourceDefinition, Symbol, TopLevel};
+use rtfs::ast::{Expression, Literal, MapKey, ResourceDefinition, Span, Symbol, TopLevel};
 use rtfs::error_reporting::SourceSpan;
 
 // A helper to create a dummy span for tests where we don't care about the exact location.
@@ -30,7 +30,7 @@ fn test_parse_simple_resource() {
     properties.insert("config".to_string(), Expression::Map(map_literal));
 
     let expected_ast = vec![TopLevel::Resource(ResourceDefinition {
-        name: Symbol("my-simple-resource".to_string()),
+        name: Symbol("my-simple-resource".into()),
         properties: vec![], // Will be populated by parser
     })];
 
@@ -43,7 +43,7 @@ fn test_parse_simple_resource() {
     match result {
         Ok(ast) => {
             if let Some(TopLevel::Resource(res)) = ast.get(0) {
-                assert_eq!(res.name, Symbol("my-simple-resource".to_string()));
+                assert_eq!(res.name, Symbol("my-simple-resource".into()));
                 assert_eq!(res.properties.len(), 1);
             } else {
                 panic!("Expected a Resource to be parsed");
@@ -103,17 +103,15 @@ fn test_parse_simple_literals() {
 fn test_parse_symbol_keyword() {
     assert_expr_parses_to!(
         "my-symbol",
-        Expression::Symbol(rtfs::ast::Symbol("my-symbol".to_string()))
+        Expression::Symbol(rtfs::ast::Symbol("my-symbol".into()))
     );
     assert_expr_parses_to!(
         "my-namespace/my-symbol",
-        Expression::Symbol(rtfs::ast::Symbol("my-namespace/my-symbol".to_string()))
+        Expression::Symbol(rtfs::ast::Symbol("my-namespace/my-symbol".into()))
     );
     assert_expr_parses_to!(
         ":my-keyword",
-        Expression::Literal(Literal::Keyword(rtfs::ast::Keyword(
-            "my-keyword".to_string()
-        )))
+        Expression::Literal(Literal::Keyword(rtfs::ast::Keyword("my-keyword".into())))
     );
 }
 
@@ -134,11 +132,12 @@ fn test_parse_collections() {
     assert_expr_parses_to!(
         "(+ 1 2)",
         Expression::FunctionCall {
-            callee: Box::new(Expression::Symbol(rtfs::ast::Symbol("+".to_string()))),
+            callee: Box::new(Expression::Symbol(rtfs::ast::Symbol("+".into()))),
             arguments: vec![
                 Expression::Literal(Literal::Integer(1)),
                 Expression::Literal(Literal::Integer(2)),
-            ]
+            ],
+            span: Span::unknown(),
         }
     );
 }
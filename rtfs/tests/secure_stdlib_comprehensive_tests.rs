@@ -6,7 +6,7 @@ use rtfs::runtime::evaluator::Evaluator;
 use rtfs::runtime::module_runtime::ModuleRegistry;
 use rtfs::runtime::pure_host::create_pure_host;
 use rtfs::runtime::secure_stdlib::SecureStandardLibrary;
-use rtfs::runtime::values::Value;
+use rtfs::runtime::values::{Value, ValueMap};
 use std::sync::Arc;
 
 /// Test runner for secure standard library end-to-end tests
@@ -212,6 +212,16 @@ impl SecureStdlibTestRunner {
         self.run_test("(or true)", Value::Boolean(true))?;
         self.run_test("(or false)", Value::Boolean(false))?;
 
+        // and/or return the deciding value itself, not a coerced boolean
+        self.run_test("(and 1 2 3)", Value::Integer(3))?;
+        self.run_test("(and 1 nil 3)", Value::Nil)?;
+        self.run_test(
+            "(or nil :default)",
+            Value::Keyword(Keyword("default".into())),
+        )?;
+        self.run_test("(or nil false)", Value::Boolean(false))?;
+        self.run_test("(or false nil)", Value::Nil)?;
+
         // Not tests
         self.run_test("(not true)", Value::Boolean(false))?;
         self.run_test("(not false)", Value::Boolean(true))?;
@@ -276,32 +286,35 @@ impl SecureStdlibTestRunner {
         println!("Running collection function tests...");
 
         // Vector tests
-        self.run_test("(vector)", Value::Vector(vec![]))?;
+        self.run_test("(vector)", Value::Vector(vec![].into()))?;
         self.run_test(
             "(vector 1 2 3)",
-            Value::Vector(vec![
-                Value::Integer(1),
-                Value::Integer(2),
-                Value::Integer(3),
-            ]),
+            Value::Vector(vec![Value::Integer(1), Value::Integer(2), Value::Integer(3)].into()),
         )?;
         self.run_test(
             "(vector \"a\" \"b\" \"c\")",
-            Value::Vector(vec![
-                Value::String("a".to_string()),
-                Value::String("b".to_string()),
-                Value::String("c".to_string()),
-            ]),
+            Value::Vector(
+                vec![
+                    Value::String("a".to_string()),
+                    Value::String("b".to_string()),
+                    Value::String("c".to_string()),
+                ]
+                .into(),
+            ),
         )?;
 
         // Hash map tests
-        self.run_test("(hash-map)", Value::Map(std::collections::HashMap::new()))?;
+        self.run_test(
+            "(hash-map)",
+            Value::Map(std::collections::HashMap::new().into()),
+        )?;
 
         // Get tests
         self.run_test("(get [1 2 3] 0)", Value::Integer(1))?;
         self.run_test("(get [1 2 3] 1)", Value::Integer(2))?;
         self.run_test("(get [1 2 3] 5)", Value::Nil)?;
-        self.run_test("(get [1 2 3] -1)", Value::Nil)?;
+        self.run_test("(get [1 2 3] -1)", Value::Integer(3))?;
+        self.run_test("(get [1 2 3] -4)", Value::Nil)?;
 
         // Count tests
         self.run_test("(count [])", Value::Integer(0))?;
@@ -322,10 +335,10 @@ impl SecureStdlibTestRunner {
         // Rest tests
         self.run_test(
             "(rest [1 2 3])",
-            Value::Vector(vec![Value::Integer(2), Value::Integer(3)]),
+            Value::Vector(vec![Value::Integer(2), Value::Integer(3)].into()),
         )?;
-        self.run_test("(rest [])", Value::Vector(vec![]))?;
-        self.run_test("(rest [1])", Value::Vector(vec![]))?;
+        self.run_test("(rest [])", Value::Vector(vec![].into()))?;
+        self.run_test("(rest [1])", Value::Vector(vec![].into()))?;
 
         // Empty tests
         self.run_test("(empty? [])", Value::Boolean(true))?;
@@ -337,43 +350,27 @@ impl SecureStdlibTestRunner {
         // Conj tests
         self.run_test(
             "(conj [1 2] 3)",
-            Value::Vector(vec![
-                Value::Integer(1),
-                Value::Integer(2),
-                Value::Integer(3),
-            ]),
+            Value::Vector(vec![Value::Integer(1), Value::Integer(2), Value::Integer(3)].into()),
         )?;
-        self.run_test("(conj [] 1)", Value::Vector(vec![Value::Integer(1)]))?;
+        self.run_test("(conj [] 1)", Value::Vector(vec![Value::Integer(1)].into()))?;
 
         // Cons tests
         self.run_test(
             "(cons 1 [2 3])",
-            Value::Vector(vec![
-                Value::Integer(1),
-                Value::Integer(2),
-                Value::Integer(3),
-            ]),
+            Value::Vector(vec![Value::Integer(1), Value::Integer(2), Value::Integer(3)].into()),
         )?;
-        self.run_test("(cons 1 [])", Value::Vector(vec![Value::Integer(1)]))?;
+        self.run_test("(cons 1 [])", Value::Vector(vec![Value::Integer(1)].into()))?;
 
         // Range tests
         self.run_test(
             "(range 0 3)",
-            Value::Vector(vec![
-                Value::Integer(0),
-                Value::Integer(1),
-                Value::Integer(2),
-            ]),
+            Value::Vector(vec![Value::Integer(0), Value::Integer(1), Value::Integer(2)].into()),
         )?;
         self.run_test(
             "(range 1 4)",
-            Value::Vector(vec![
-                Value::Integer(1),
-                Value::Integer(2),
-                Value::Integer(3),
-            ]),
+            Value::Vector(vec![Value::Integer(1), Value::Integer(2), Value::Integer(3)].into()),
         )?;
-        self.run_test("(range 5 5)", Value::Vector(vec![]))?;
+        self.run_test("(range 5 5)", Value::Vector(vec![].into()))?;
 
         println!(" All collection function tests passed!");
         Ok(())
@@ -394,46 +391,47 @@ impl SecureStdlibTestRunner {
         // Partition tests
         self.run_test(
             "(partition 2 [1 2 3 4])",
-            Value::Vector(vec![
-                Value::Vector(vec![Value::Integer(1), Value::Integer(2)]),
-                Value::Vector(vec![Value::Integer(3), Value::Integer(4)]),
-            ]),
+            Value::Vector(
+                vec![
+                    Value::Vector(vec![Value::Integer(1), Value::Integer(2)].into()),
+                    Value::Vector(vec![Value::Integer(3), Value::Integer(4)].into()),
+                ]
+                .into(),
+            ),
         )?;
         self.run_test(
             "(partition 3 [1 2 3 4 5 6])",
-            Value::Vector(vec![
-                Value::Vector(vec![
-                    Value::Integer(1),
-                    Value::Integer(2),
-                    Value::Integer(3),
-                ]),
-                Value::Vector(vec![
-                    Value::Integer(4),
-                    Value::Integer(5),
-                    Value::Integer(6),
-                ]),
-            ]),
+            Value::Vector(
+                vec![
+                    Value::Vector(
+                        vec![Value::Integer(1), Value::Integer(2), Value::Integer(3)].into(),
+                    ),
+                    Value::Vector(
+                        vec![Value::Integer(4), Value::Integer(5), Value::Integer(6)].into(),
+                    ),
+                ]
+                .into(),
+            ),
         )?;
 
         // Assoc tests
         let mut expected_map_assoc = std::collections::HashMap::new();
-        expected_map_assoc.insert(MapKey::Keyword(Keyword("a".to_string())), Value::Integer(1));
-        expected_map_assoc.insert(MapKey::Keyword(Keyword("b".to_string())), Value::Integer(2));
-        self.run_test("(assoc {:a 1} :b 2)", Value::Map(expected_map_assoc))?;
+        expected_map_assoc.insert(MapKey::Keyword(Keyword("a".into())), Value::Integer(1));
+        expected_map_assoc.insert(MapKey::Keyword(Keyword("b".into())), Value::Integer(2));
+        self.run_test("(assoc {:a 1} :b 2)", Value::Map(expected_map_assoc.into()))?;
 
         self.run_test(
             "(assoc [1 2 3] 1 42)",
-            Value::Vector(vec![
-                Value::Integer(1),
-                Value::Integer(42),
-                Value::Integer(3),
-            ]),
+            Value::Vector(vec![Value::Integer(1), Value::Integer(42), Value::Integer(3)].into()),
         )?;
 
         // Dissoc tests
         let mut expected_map_dissoc = std::collections::HashMap::new();
-        expected_map_dissoc.insert(MapKey::Keyword(Keyword("b".to_string())), Value::Integer(2));
-        self.run_test("(dissoc {:a 1 :b 2} :a)", Value::Map(expected_map_dissoc))?;
+        expected_map_dissoc.insert(MapKey::Keyword(Keyword("b".into())), Value::Integer(2));
+        self.run_test(
+            "(dissoc {:a 1 :b 2} :a)",
+            Value::Map(expected_map_dissoc.into()),
+        )?;
 
         // Type-name tests
         self.run_test("(type-name 42)", Value::String("integer".to_string()))?;
@@ -637,3 +635,1185 @@ fn test_error_handling() {
 
     println!("✅ Error handling tests passed!");
 }
+
+#[test]
+fn test_subvec_bounds() {
+    let mut runner = SecureStdlibTestRunner::new();
+    let ints = |xs: &[i64]| Value::Vector(xs.iter().map(|x| Value::Integer(*x)).collect());
+
+    runner
+        .run_test("(subvec [1 2 3 4] 1 3)", ints(&[2, 3]))
+        .unwrap();
+    runner
+        .run_test("(subvec [1 2 3 4] 2)", ints(&[3, 4]))
+        .unwrap();
+    runner
+        .run_test("(subvec [1 2 3 4] 0 -1)", ints(&[1, 2, 3]))
+        .unwrap();
+    runner.run_test("(subvec [1 2 3] 3)", ints(&[])).unwrap();
+
+    for code in [
+        "(subvec [1 2 3] 4)",
+        "(subvec [1 2 3] -1)",
+        "(subvec [1 2 3] 2 1)",
+        "(subvec [1 2 3] 0 4)",
+        "(subvec [1 2 3] 0 -4)",
+    ] {
+        runner.run_error_test(code, "IndexOutOfBounds").unwrap();
+    }
+    runner
+        .run_error_test("(subvec (list [1 2 3]) 1)", "TypeError")
+        .unwrap();
+}
+
+#[test]
+fn test_concat_mixed_collections() {
+    let mut runner = SecureStdlibTestRunner::new();
+    let s = |v: &str| Value::String(v.to_string());
+
+    runner
+        .run_test(
+            "(concat [1 2] (list [3]) \"ab\")",
+            Value::Vector(
+                vec![
+                    Value::Integer(1),
+                    Value::Integer(2),
+                    Value::Integer(3),
+                    s("a"),
+                    s("b"),
+                ]
+                .into(),
+            ),
+        )
+        .unwrap();
+    runner
+        .run_test(
+            "(concat nil [1] nil)",
+            Value::Vector(vec![Value::Integer(1)].into()),
+        )
+        .unwrap();
+    runner
+        .run_test("(concat)", Value::Vector(vec![].into()))
+        .unwrap();
+
+    runner.run_error_test("(concat [1] 2)", "concat").unwrap();
+    runner.run_error_test("(concat {:a 1})", "concat").unwrap();
+    runner
+        .run_error_test(
+            "(concat (repeat 600000 0) (repeat 600000 0))",
+            "exceeds the limit",
+        )
+        .unwrap();
+}
+
+#[test]
+fn test_last_across_collection_types() {
+    let mut runner = SecureStdlibTestRunner::new();
+
+    runner
+        .run_test("(last [1 2 3])", Value::Integer(3))
+        .unwrap();
+    runner
+        .run_test("(last (list [1 2 3]))", Value::Integer(3))
+        .unwrap();
+    runner
+        .run_test("(last \"abc\")", Value::String("c".to_string()))
+        .unwrap();
+
+    // Empty collections are nil, like `first`
+    for code in ["(last [])", "(last (list []))", "(last \"\")"] {
+        runner.run_test(code, Value::Nil).unwrap();
+    }
+    runner.run_test("(first \"\")", Value::Nil).unwrap();
+
+    runner.run_error_test("(last 1)", "last").unwrap();
+}
+
+#[test]
+fn test_take_last_and_drop_last() {
+    let mut runner = SecureStdlibTestRunner::new();
+    let ints = |xs: &[i64]| Value::Vector(xs.iter().map(|x| Value::Integer(*x)).collect());
+
+    runner
+        .run_test("(take-last 2 [1 2 3 4])", ints(&[3, 4]))
+        .unwrap();
+    runner
+        .run_test("(take-last 10 [1 2 3])", ints(&[1, 2, 3]))
+        .unwrap();
+    runner.run_test("(take-last 0 [1 2 3])", ints(&[])).unwrap();
+    runner
+        .run_test("(take-last -1 [1 2 3])", ints(&[]))
+        .unwrap();
+    runner
+        .run_test("(take-last 3 \"hello\")", Value::String("llo".to_string()))
+        .unwrap();
+
+    runner
+        .run_test("(drop-last 1 [1 2 3 4])", ints(&[1, 2, 3]))
+        .unwrap();
+    runner
+        .run_test("(drop-last 10 [1 2 3])", ints(&[]))
+        .unwrap();
+    runner
+        .run_test("(drop-last 0 [1 2 3])", ints(&[1, 2, 3]))
+        .unwrap();
+    runner
+        .run_test("(drop-last -2 [1 2 3])", ints(&[1, 2, 3]))
+        .unwrap();
+    runner
+        .run_test("(drop-last 2 \"hello\")", Value::String("hel".to_string()))
+        .unwrap();
+
+    // Negative counts on take/drop are treated as zero
+    runner.run_test("(take -1 [1 2 3])", ints(&[])).unwrap();
+    runner
+        .run_test("(drop -1 [1 2 3])", ints(&[1, 2, 3]))
+        .unwrap();
+}
+
+#[test]
+fn test_split_lines() {
+    let mut runner = SecureStdlibTestRunner::new();
+    let lines = |items: &[&str]| {
+        Value::Vector(items.iter().map(|s| Value::String(s.to_string())).collect())
+    };
+
+    runner
+        .run_test("(split-lines \"a\nb\nc\")", lines(&["a", "b", "c"]))
+        .unwrap();
+    runner
+        .run_test("(split-lines \"a\r\nb\r\n\")", lines(&["a", "b"]))
+        .unwrap();
+    runner
+        .run_test("(split-lines \"a\n\nb\n\")", lines(&["a", "", "b"]))
+        .unwrap();
+    runner.run_test("(split-lines \"\")", lines(&[])).unwrap();
+    runner
+        .run_test("(split-lines \"one\")", lines(&["one"]))
+        .unwrap();
+}
+
+#[test]
+fn test_trim_variants() {
+    let mut runner = SecureStdlibTestRunner::new();
+    let string = |s: &str| Value::String(s.to_string());
+
+    runner
+        .run_test("(trim-start \"  a b  \")", string("a b  "))
+        .unwrap();
+    runner
+        .run_test("(trim-end \"  a b  \")", string("  a b"))
+        .unwrap();
+    runner
+        .run_test("(trim-chars \"\\\"quoted\\\"\" \"\\\"\")", string("quoted"))
+        .unwrap();
+    runner
+        .run_test("(trim-chars \"[[a][b]]\" \"[]\")", string("a][b"))
+        .unwrap();
+    runner
+        .run_test("(trim-chars \"x-y-x\" \"-\")", string("x-y-x"))
+        .unwrap();
+    runner
+        .run_test("(trim-chars \"abc\" \"\")", string("abc"))
+        .unwrap();
+    runner
+        .run_test("(trim-chars \"***\" \"*\")", string(""))
+        .unwrap();
+}
+
+#[test]
+fn test_interpose() {
+    let mut runner = SecureStdlibTestRunner::new();
+
+    runner
+        .run_test("(interpose :x [])", Value::Vector(vec![].into()))
+        .unwrap();
+    runner
+        .run_test(
+            "(interpose :x [1])",
+            Value::Vector(vec![Value::Integer(1)].into()),
+        )
+        .unwrap();
+    runner
+        .run_test(
+            "(interpose :x [1 2 3])",
+            Value::Vector(
+                vec![
+                    Value::Integer(1),
+                    Value::Keyword(Keyword("x".into())),
+                    Value::Integer(2),
+                    Value::Keyword(Keyword("x".into())),
+                    Value::Integer(3),
+                ]
+                .into(),
+            ),
+        )
+        .unwrap();
+    runner
+        .run_test(
+            "(interpose \", \" [\"a\" \"b\"])",
+            Value::Vector(
+                vec![
+                    Value::String("a".to_string()),
+                    Value::String(", ".to_string()),
+                    Value::String("b".to_string()),
+                ]
+                .into(),
+            ),
+        )
+        .unwrap();
+}
+
+#[test]
+fn test_presence_distinguishes_nil_value_from_missing_key() {
+    let mut runner = SecureStdlibTestRunner::new();
+
+    // A stored nil is present, a missing key is not
+    runner
+        .run_test("(contains-key? {:a nil} :a)", Value::Boolean(true))
+        .unwrap();
+    runner
+        .run_test("(contains-key? {:a nil} :b)", Value::Boolean(false))
+        .unwrap();
+    runner
+        .run_test("(contains-key? {\"a\" 1} \"a\")", Value::Boolean(true))
+        .unwrap();
+    runner
+        .run_test("(contains-key? [1 2] 1)", Value::Boolean(true))
+        .unwrap();
+    runner
+        .run_test("(contains-key? [1 2] 2)", Value::Boolean(false))
+        .unwrap();
+    runner
+        .run_test("(contains-key? nil :a)", Value::Boolean(false))
+        .unwrap();
+
+    // get-or only returns the sentinel for a truly absent key
+    runner
+        .run_test("(get-or {:a nil} :a :missing)", Value::Nil)
+        .unwrap();
+    runner
+        .run_test(
+            "(get-or {:a nil} :b :missing)",
+            Value::Keyword(Keyword("missing".into())),
+        )
+        .unwrap();
+    runner
+        .run_test("(get-or {:a 1} :a :missing)", Value::Integer(1))
+        .unwrap();
+    runner
+        .run_test(
+            "(get-or [1 2] 5 :missing)",
+            Value::Keyword(Keyword("missing".into())),
+        )
+        .unwrap();
+}
+
+#[test]
+fn test_str_renders_function_tags() {
+    let mut runner = SecureStdlibTestRunner::new();
+    let s = |v: &str| Value::String(v.to_string());
+
+    runner.run_test("(str map)", s("#<builtin map>")).unwrap();
+    runner.run_test("(str +)", s("#<builtin +>")).unwrap();
+    runner.run_test("(str (fn [x] x))", s("#<fn>")).unwrap();
+    runner
+        .run_test("(str [inc 1])", s("[#<builtin inc> 1]"))
+        .unwrap();
+}
+
+#[test]
+fn test_get_flex_matches_keyword_and_string_keys() {
+    let mut runner = SecureStdlibTestRunner::new();
+
+    // Keyword lookup finds a string key (parsed JSON) and vice versa
+    runner
+        .run_test("(get-flex {\"field\" 1} :field)", Value::Integer(1))
+        .unwrap();
+    runner
+        .run_test("(get-flex {:field 1} \"field\")", Value::Integer(1))
+        .unwrap();
+    runner
+        .run_test("(get-flex {:field 1} :field)", Value::Integer(1))
+        .unwrap();
+
+    // The exact representation wins when both are present
+    runner
+        .run_test(
+            "(get-flex {:field 1 \"field\" 2} \"field\")",
+            Value::Integer(2),
+        )
+        .unwrap();
+
+    // Missing keys fall back to the default; plain get stays strict
+    runner
+        .run_test("(get-flex {:other 1} :field)", Value::Nil)
+        .unwrap();
+    runner
+        .run_test("(get-flex {:other 1} :field 0)", Value::Integer(0))
+        .unwrap();
+    runner
+        .run_test("(get {\"field\" 1} :field)", Value::Nil)
+        .unwrap();
+    runner
+        .run_test("(get-flex [10 20] 1)", Value::Integer(20))
+        .unwrap();
+}
+
+#[test]
+fn test_valid_and_validate_against_type_expr() {
+    let mut runner = SecureStdlibTestRunner::new();
+
+    runner
+        .run_test("(valid? {:x :int} {:x 1})", Value::Boolean(true))
+        .unwrap();
+    runner
+        .run_test("(valid? {:x :int} {:x \"one\"})", Value::Boolean(false))
+        .unwrap();
+    runner
+        .run_test("(valid? {:x :int} {:y 1})", Value::Boolean(false))
+        .unwrap();
+    runner
+        .run_test(
+            "(valid? [:vector :string] [\"a\" \"b\"])",
+            Value::Boolean(true),
+        )
+        .unwrap();
+    runner
+        .run_test("(valid? :int 1.5)", Value::Boolean(false))
+        .unwrap();
+
+    // validate returns the value itself when it conforms
+    let mut expected = std::collections::HashMap::new();
+    expected.insert(MapKey::Keyword(Keyword("x".into())), Value::Integer(1));
+    runner
+        .run_test("(validate {:x :int} {:x 1})", Value::Map(expected.into()))
+        .unwrap();
+
+    // ...and reports the path of the first violation otherwise
+    runner
+        .run_error_test("(validate {:x :int} {:x \"one\"})", "$.x")
+        .unwrap();
+    runner
+        .run_error_test("(validate {:x :int} {:y 1})", "Missing required key :x")
+        .unwrap();
+}
+
+#[test]
+fn test_into() {
+    let mut runner = SecureStdlibTestRunner::new();
+    let kw = |s: &str| MapKey::Keyword(Keyword(s.into()));
+
+    // Vector of [k v] pairs into a map
+    let mut expected = std::collections::HashMap::new();
+    expected.insert(kw("a"), Value::Integer(1));
+    expected.insert(kw("b"), Value::Integer(2));
+    runner
+        .run_test(
+            "(into {} [[:a 1] [:b 2]])",
+            Value::Map(expected.clone().into()),
+        )
+        .unwrap();
+    runner
+        .run_test("(into {:a 0} [[:a 1] {:b 2}])", Value::Map(expected.into()))
+        .unwrap();
+
+    // List (variadic rest args) into a vector, appended in order
+    runner
+        .run_test(
+            "(into [0] ((fn [& xs] xs) 1 2 3))",
+            Value::Vector(
+                vec![
+                    Value::Integer(0),
+                    Value::Integer(1),
+                    Value::Integer(2),
+                    Value::Integer(3),
+                ]
+                .into(),
+            ),
+        )
+        .unwrap();
+
+    // Map into a vector yields [k v] pairs
+    runner
+        .run_test(
+            "(into [] {:a 1})",
+            Value::Vector(
+                vec![Value::Vector(
+                    vec![Value::Keyword(Keyword("a".into())), Value::Integer(1)].into(),
+                )]
+                .into(),
+            ),
+        )
+        .unwrap();
+
+    runner.run_error_test("(into {} [1 2])", "into").unwrap();
+}
+
+#[test]
+fn test_get_in_returns_default_on_any_miss() {
+    let mut runner = SecureStdlibTestRunner::new();
+    let missing = Value::Keyword(Keyword("missing".into()));
+
+    // Stepping into a non-collection is a miss, not an error
+    runner
+        .run_test("(get-in {:a 1} [:a :b] :missing)", missing.clone())
+        .unwrap();
+    runner
+        .run_test("(get-in {:a 1} [:a :b])", Value::Nil)
+        .unwrap();
+    runner
+        .run_test("(get-in {:a [1 2]} [:a 5] :missing)", missing.clone())
+        .unwrap();
+    runner
+        .run_test("(get-in {:a [1 2]} [:a -1] :missing)", missing.clone())
+        .unwrap();
+    runner
+        .run_test("(get-in {:a [1 2]} [:a :b] :missing)", missing.clone())
+        .unwrap();
+    runner
+        .run_test("(get-in nil [:a] :missing)", missing)
+        .unwrap();
+
+    // Hits are unaffected, including string/integer map keys and a nil value
+    runner
+        .run_test(
+            "(get-in {\"a\" {1 :x}} [\"a\" 1] :missing)",
+            Value::Keyword(Keyword("x".into())),
+        )
+        .unwrap();
+    runner
+        .run_test("(get-in {:a nil} [:a] :missing)", Value::Nil)
+        .unwrap();
+    runner
+        .run_error_test("(get-in {:a 1} :a)", "get-in path")
+        .unwrap();
+}
+
+#[test]
+fn test_collection_conversions() {
+    let mut runner = SecureStdlibTestRunner::new();
+    let ints = |xs: &[i64]| xs.iter().map(|x| Value::Integer(*x)).collect::<Vec<_>>();
+    let list_of = "((fn [& xs] xs) 1 2 3)";
+
+    // list -> vector and vector -> list
+    runner
+        .run_test(
+            &format!("(vec {})", list_of),
+            Value::Vector(ints(&[1, 2, 3]).into()),
+        )
+        .unwrap();
+    runner
+        .run_test("(list [1 2 3])", Value::List(ints(&[1, 2, 3]).into()))
+        .unwrap();
+    runner
+        .run_test("(vector? (vec (list [1 2])))", Value::Boolean(true))
+        .unwrap();
+    runner
+        .run_test(
+            &format!("(= (vec {}) [1 2 3])", list_of),
+            Value::Boolean(true),
+        )
+        .unwrap();
+    runner
+        .run_test("(vec nil)", Value::Vector(vec![].into()))
+        .unwrap();
+
+    // seq is nil for empty collections
+    runner
+        .run_test("(seq [1 2])", Value::List(ints(&[1, 2]).into()))
+        .unwrap();
+    runner.run_test("(seq [])", Value::Nil).unwrap();
+
+    // map -> [k v] pairs
+    let pair = Value::Vector(vec![Value::Keyword(Keyword("a".into())), Value::Integer(1)].into());
+    runner
+        .run_test("(to-vec {:a 1})", Value::Vector(vec![pair.clone()].into()))
+        .unwrap();
+    runner
+        .run_test("(vec {:a 1})", Value::Vector(vec![pair].into()))
+        .unwrap();
+    runner
+        .run_test("(count (to-vec {:a 1 :b 2}))", Value::Integer(2))
+        .unwrap();
+    runner
+        .run_test("(into {} (to-vec {:a 1 :b 2}))", {
+            let kw = |s: &str| MapKey::Keyword(Keyword(s.into()));
+            Value::Map(ValueMap::from_iter([
+                (kw("a"), Value::Integer(1)),
+                (kw("b"), Value::Integer(2)),
+            ]))
+        })
+        .unwrap();
+
+    runner.run_error_test("(to-vec [1 2])", "to-vec").unwrap();
+    runner.run_error_test("(vec 1)", "vec").unwrap();
+}
+
+#[test]
+fn test_negative_indices_count_from_the_end() {
+    let mut runner = SecureStdlibTestRunner::new();
+    let s = |v: &str| Value::String(v.to_string());
+
+    runner
+        .run_test("(nth [1 2 3] -1)", Value::Integer(3))
+        .unwrap();
+    runner
+        .run_test("(nth [1 2 3] -3)", Value::Integer(1))
+        .unwrap();
+    runner
+        .run_test("(nth (list [1 2 3]) -2)", Value::Integer(2))
+        .unwrap();
+    runner.run_test("(nth \"abc\" -1)", s("c")).unwrap();
+    runner
+        .run_test("(get [1 2 3] -1)", Value::Integer(3))
+        .unwrap();
+    runner.run_test("(get \"abc\" -2)", s("b")).unwrap();
+
+    // Out-of-range negatives fall back to the default (or nil / an error)
+    runner
+        .run_test(
+            "(nth [1 2 3] -4 :none)",
+            Value::Keyword(Keyword("none".into())),
+        )
+        .unwrap();
+    runner.run_test("(get [1 2 3] -4)", Value::Nil).unwrap();
+    runner.run_test("(get \"abc\" -4 \"?\")", s("?")).unwrap();
+    runner
+        .run_error_test("(nth [1 2 3] -4)", "IndexOutOfBounds")
+        .unwrap();
+}
+
+#[test]
+fn test_not_empty() {
+    let mut runner = SecureStdlibTestRunner::new();
+    let ints = |xs: &[i64]| xs.iter().map(|x| Value::Integer(*x)).collect::<Vec<_>>();
+
+    // Empty collections (and nil) become nil
+    for code in [
+        "(not-empty [])",
+        "(not-empty (list []))",
+        "(not-empty {})",
+        "(not-empty \"\")",
+        "(not-empty nil)",
+    ] {
+        runner.run_test(code, Value::Nil).unwrap();
+    }
+
+    // Non-empty collections come back unchanged
+    runner
+        .run_test("(not-empty [1 2])", Value::Vector(ints(&[1, 2]).into()))
+        .unwrap();
+    runner
+        .run_test(
+            "(not-empty (list [1 2]))",
+            Value::List(ints(&[1, 2]).into()),
+        )
+        .unwrap();
+    runner
+        .run_test(
+            "(not-empty {:a 1})",
+            Value::Map(ValueMap::unit(
+                MapKey::Keyword(Keyword("a".into())),
+                Value::Integer(1),
+            )),
+        )
+        .unwrap();
+    runner
+        .run_test("(not-empty \"hi\")", Value::String("hi".to_string()))
+        .unwrap();
+
+    // The idiom it exists for
+    runner
+        .run_test("(or (not-empty []) [0])", Value::Vector(ints(&[0]).into()))
+        .unwrap();
+
+    runner.run_error_test("(not-empty 1)", "not-empty").unwrap();
+}
+
+#[test]
+fn test_repeat() {
+    let mut runner = SecureStdlibTestRunner::new();
+
+    runner
+        .run_test(
+            "(repeat 3 :x)",
+            Value::Vector(vec![Value::Keyword(Keyword("x".into())); 3].into()),
+        )
+        .unwrap();
+    runner
+        .run_test("(repeat 0 :x)", Value::Vector(vec![].into()))
+        .unwrap();
+    runner
+        .run_test("(count (repeat 1000000 0))", Value::Integer(1_000_000))
+        .unwrap();
+
+    runner
+        .run_error_test("(repeat 1000001 0)", "exceeds the limit")
+        .unwrap();
+    runner
+        .run_error_test("(range 0 1000001)", "exceeds the limit")
+        .unwrap();
+    runner.run_error_test("(repeat -1 0)", "negative").unwrap();
+}
+
+#[test]
+fn test_integer_overflow_is_an_error() {
+    let mut runner = SecureStdlibTestRunner::new();
+    let max = i64::MAX;
+    let min = i64::MIN;
+
+    for source in [
+        format!("(+ {} 1)", max),
+        format!("(* {} 2)", max),
+        format!("(- {} 1)", min),
+        format!("(- 0 {} 2)", max),
+        format!("(inc {})", max),
+        format!("(dec {})", min),
+        format!("(abs {})", min),
+        format!("(- {})", min),
+        "(pow 2 63)".to_string(),
+        "(factorial 21)".to_string(),
+    ] {
+        runner
+            .run_error_test(&source, "integer overflow in")
+            .unwrap_or_else(|e| panic!("{}: {}", source, e));
+    }
+
+    // Results that fit are exact, including across the float boundary
+    runner
+        .run_test(&format!("(+ {} -1 1)", max), Value::Integer(max))
+        .unwrap();
+    runner
+        .run_test(&format!("(- {} 1)", max), Value::Integer(max - 1))
+        .unwrap();
+    runner
+        .run_test("(pow 2 62)", Value::Integer(1 << 62))
+        .unwrap();
+    runner
+        .run_test("(factorial 20)", Value::Integer(2_432_902_008_176_640_000))
+        .unwrap();
+    runner
+        .run_test(&format!("(+ {} 1.0)", max), Value::Float(max as f64 + 1.0))
+        .unwrap();
+}
+
+#[test]
+fn test_odd_even_for_negative_and_float_inputs() {
+    let mut runner = SecureStdlibTestRunner::new();
+
+    for (source, expected) in [
+        ("(odd? -3)", true),
+        ("(odd? -4)", false),
+        ("(even? -4)", true),
+        ("(even? -3)", false),
+        ("(odd? 3)", true),
+        ("(even? 0)", true),
+        ("(odd? -9223372036854775807)", true),
+        ("(even? -9223372036854775808)", true),
+        ("(odd? 3.0)", true),
+        ("(even? -2.0)", true),
+    ] {
+        runner
+            .run_test(source, Value::Boolean(expected))
+            .unwrap_or_else(|e| panic!("{}: {}", source, e));
+    }
+
+    // A fractional float has no parity
+    runner
+        .run_error_test("(odd? 2.5)", "expects an integer")
+        .unwrap();
+    runner
+        .run_error_test("(even? -1.5)", "expects an integer")
+        .unwrap();
+    runner.run_error_test("(even? \"2\")", "even?").unwrap();
+}
+
+#[test]
+fn test_sort_rejects_mixed_types_and_is_stable() {
+    let mut runner = SecureStdlibTestRunner::new();
+    let ints = |xs: &[i64]| Value::Vector(xs.iter().map(|x| Value::Integer(*x)).collect());
+
+    runner.run_test("(sort [3 1 2])", ints(&[1, 2, 3])).unwrap();
+    runner
+        .run_test(
+            "(sort [\"b\" \"a\"])",
+            Value::Vector(
+                vec![
+                    Value::String("a".to_string()),
+                    Value::String("b".to_string()),
+                ]
+                .into(),
+            ),
+        )
+        .unwrap();
+    // Integers and floats compare numerically; nil sorts first
+    runner
+        .run_test(
+            "(sort [2.5 nil 1])",
+            Value::Vector(vec![Value::Nil, Value::Integer(1), Value::Float(2.5)].into()),
+        )
+        .unwrap();
+
+    runner
+        .run_error_test("(sort [1 \"a\"])", "values comparable with integer")
+        .unwrap();
+    runner
+        .run_error_test("(sort [:a 1])", "values comparable with keyword")
+        .unwrap();
+
+    // sort-by keeps input order for equal keys
+    runner
+        .run_test(
+            "(sort-by (fn [p] (get p 0)) [[2 :a] [1 :b] [2 :c] [1 :d]])",
+            Value::Vector(
+                [(1, "b"), (1, "d"), (2, "a"), (2, "c")]
+                    .iter()
+                    .map(|(n, k)| {
+                        Value::Vector(
+                            vec![Value::Integer(*n), Value::Keyword(Keyword::new(k))].into(),
+                        )
+                    })
+                    .collect(),
+            ),
+        )
+        .unwrap();
+    runner
+        .run_error_test("(sort-by (fn [x] x) [1 \"a\"])", "sort-by")
+        .unwrap();
+}
+
+#[test]
+fn test_mod_is_floored_and_rem_is_truncated() {
+    let mut runner = SecureStdlibTestRunner::new();
+
+    for (source, expected) in [
+        ("(mod -7 3)", Value::Integer(2)),
+        ("(rem -7 3)", Value::Integer(-1)),
+        ("(mod 7 -3)", Value::Integer(-2)),
+        ("(rem 7 -3)", Value::Integer(1)),
+        ("(mod -7 -3)", Value::Integer(-1)),
+        ("(mod 7 3)", Value::Integer(1)),
+        ("(mod -6 3)", Value::Integer(0)),
+        ("(mod -9223372036854775808 -1)", Value::Integer(0)),
+        ("(mod -7.5 2)", Value::Float(0.5)),
+        ("(rem -7.5 2)", Value::Float(-1.5)),
+        ("(abs -7)", Value::Integer(7)),
+        ("(abs -2.5)", Value::Float(2.5)),
+    ] {
+        runner
+            .run_test(source, expected)
+            .unwrap_or_else(|e| panic!("{}: {}", source, e));
+    }
+
+    runner
+        .run_error_test("(mod 1 0)", "DivisionByZero")
+        .unwrap();
+    runner
+        .run_error_test("(rem 1.0 0)", "DivisionByZero")
+        .unwrap();
+}
+
+#[test]
+fn test_compare_total_order() {
+    let mut runner = SecureStdlibTestRunner::new();
+
+    // Within a type
+    for (source, expected) in [
+        ("(compare 1 2)", -1),
+        ("(compare 2 1)", 1),
+        ("(compare 1 1.0)", 0),
+        ("(compare 1.5 1)", 1),
+        ("(compare \"apple\" \"banana\")", -1),
+        ("(compare :b :a)", 1),
+        ("(compare false true)", -1),
+        ("(compare [1 2] [1 3])", -1),
+        ("(compare [1 2] [1 2 0])", -1),
+        ("(compare {:a 1} {:a 1})", 0),
+    ] {
+        runner
+            .run_test(source, Value::Integer(expected))
+            .unwrap_or_else(|e| panic!("{}: {}", source, e));
+    }
+
+    // Documented cross-type order:
+    // nil < boolean < number < string < keyword < vector < map
+    let ordered = ["nil", "true", "42", "\"a\"", ":a", "[1]", "{:a 1}"];
+    for (i, lower) in ordered.iter().enumerate() {
+        for higher in &ordered[i + 1..] {
+            let source = format!("(compare {} {})", lower, higher);
+            runner
+                .run_test(&source, Value::Integer(-1))
+                .unwrap_or_else(|e| panic!("{}: {}", source, e));
+            let source = format!("(compare {} {})", higher, lower);
+            runner
+                .run_test(&source, Value::Integer(1))
+                .unwrap_or_else(|e| panic!("{}: {}", source, e));
+        }
+    }
+}
+
+#[test]
+fn test_basic_stats() {
+    let mut runner = SecureStdlibTestRunner::new();
+
+    runner
+        .run_test("(mean [1 2 3 4])", Value::Float(2.5))
+        .unwrap();
+    runner
+        .run_test("(mean [1.5 2.5])", Value::Float(2.0))
+        .unwrap();
+
+    // Odd length takes the middle value, even length averages the middle two
+    runner
+        .run_test("(median [5 1 3])", Value::Float(3.0))
+        .unwrap();
+    runner
+        .run_test("(median [7 1 3 5])", Value::Float(4.0))
+        .unwrap();
+
+    // Mode returns the most frequent element; ties go to the first seen
+    runner
+        .run_test("(mode [1 2 2 3 3 3])", Value::Integer(3))
+        .unwrap();
+    runner
+        .run_test("(mode [:a :b :b :a])", Value::Keyword(Keyword("a".into())))
+        .unwrap();
+    runner
+        .run_test("(mode [[1] 0.5 [1] -0.0 0.0 0.0])", Value::Float(-0.0))
+        .unwrap();
+
+    // Population standard deviation of a textbook dataset
+    runner
+        .run_test("(std-dev [2 4 4 4 5 5 7 9])", Value::Float(2.0))
+        .unwrap();
+    runner
+        .run_test("(std-dev [3 3 3])", Value::Float(0.0))
+        .unwrap();
+
+    for f in ["mean", "median", "mode", "std-dev"] {
+        runner
+            .run_error_test(&format!("({} [])", f), "InvalidArgument")
+            .unwrap();
+    }
+    runner
+        .run_error_test("(mean [1 \"two\"])", "TypeError")
+        .unwrap();
+}
+
+#[test]
+fn test_clamp_and_lerp() {
+    let mut runner = SecureStdlibTestRunner::new();
+
+    runner
+        .run_test("(clamp -5 0 10)", Value::Integer(0))
+        .unwrap();
+    runner
+        .run_test("(clamp 5 0 10)", Value::Integer(5))
+        .unwrap();
+    runner
+        .run_test("(clamp 15 0 10)", Value::Integer(10))
+        .unwrap();
+    runner
+        .run_test("(clamp 0.5 0 1)", Value::Float(0.5))
+        .unwrap();
+    runner
+        .run_test("(clamp 2 0 1.5)", Value::Float(1.5))
+        .unwrap();
+    runner
+        .run_error_test("(clamp 1 10 0)", "InvalidArgument")
+        .unwrap();
+
+    runner
+        .run_test("(lerp 10 20 0)", Value::Float(10.0))
+        .unwrap();
+    runner
+        .run_test("(lerp 10 20 0.5)", Value::Float(15.0))
+        .unwrap();
+    runner
+        .run_test("(lerp 10 20 1)", Value::Float(20.0))
+        .unwrap();
+    runner
+        .run_test("(lerp 1.0 -1 0.25)", Value::Float(0.5))
+        .unwrap();
+}
+
+#[test]
+fn test_list_and_vector_equality() {
+    let mut runner = SecureStdlibTestRunner::new();
+    let ints = |xs: &[i64]| xs.iter().map(|x| Value::Integer(*x)).collect::<Vec<_>>();
+
+    // The collection type is part of a value: a list never equals a vector
+    runner
+        .run_test("(= (list [1 2]) [1 2])", Value::Boolean(false))
+        .unwrap();
+    runner
+        .run_test("(= (list [1 2]) (list [1 2]))", Value::Boolean(true))
+        .unwrap();
+    runner
+        .run_test("(contains? [(list [1 2])] [1 2])", Value::Boolean(false))
+        .unwrap();
+    runner
+        .run_test(
+            "(distinct [[1 2] (list [1 2]) [1 2]])",
+            Value::Vector(
+                vec![
+                    Value::Vector(ints(&[1, 2]).into()),
+                    Value::List(ints(&[1, 2]).into()),
+                ]
+                .into(),
+            ),
+        )
+        .unwrap();
+
+    // seq= compares element-wise, ignoring list/vector at any depth
+    runner
+        .run_test("(seq= (list [1 2]) [1 2])", Value::Boolean(true))
+        .unwrap();
+    runner
+        .run_test(
+            "(seq= [[1] {:a (list [2])}] (list [(list [1]) {:a [2]}]))",
+            Value::Boolean(true),
+        )
+        .unwrap();
+    runner
+        .run_test("(seq= [1 2] (list [1 2]) [1 2 3])", Value::Boolean(false))
+        .unwrap();
+    runner
+        .run_test("(seq= [1 2] [2 1])", Value::Boolean(false))
+        .unwrap();
+    runner.run_test("(seq= 1 1)", Value::Boolean(true)).unwrap();
+
+    runner
+        .run_test("(sequential? [1])", Value::Boolean(true))
+        .unwrap();
+    runner
+        .run_test("(sequential? (list []))", Value::Boolean(true))
+        .unwrap();
+    for code in [
+        "(sequential? {})",
+        "(sequential? \"ab\")",
+        "(sequential? nil)",
+    ] {
+        runner.run_test(code, Value::Boolean(false)).unwrap();
+    }
+}
+
+#[test]
+fn test_sequence_builtins_on_vector_list_and_string() {
+    let mut runner = SecureStdlibTestRunner::new();
+    let ints = |xs: &[i64]| xs.iter().map(|x| Value::Integer(*x)).collect::<Vec<_>>();
+    let vector = |xs: &[i64]| Value::Vector(ints(xs).into());
+    let list = |xs: &[i64]| Value::List(ints(xs).into());
+    let string = |s: &str| Value::String(s.to_string());
+    let int = Value::Integer;
+
+    // Each builtin applied to the same elements as a vector, a list and a
+    // string; results keep the input's sequence type
+    let cases = [
+        ("(count {})", int(4), int(4), int(4)),
+        ("(length {})", int(4), int(4), int(4)),
+        (
+            "(empty? {})",
+            Value::Boolean(false),
+            Value::Boolean(false),
+            Value::Boolean(false),
+        ),
+        ("(first {})", int(1), int(1), string("a")),
+        ("(last {})", int(2), int(2), string("b")),
+        ("(nth {} 2)", int(3), int(3), string("c")),
+        ("(get {} -1)", int(2), int(2), string("b")),
+        (
+            "(rest {})",
+            vector(&[2, 3, 2]),
+            list(&[2, 3, 2]),
+            string("bcb"),
+        ),
+        (
+            "(reverse {})",
+            vector(&[2, 3, 2, 1]),
+            list(&[2, 3, 2, 1]),
+            string("bcba"),
+        ),
+        ("(take 2 {})", vector(&[1, 2]), list(&[1, 2]), string("ab")),
+        ("(drop 2 {})", vector(&[3, 2]), list(&[3, 2]), string("cb")),
+        (
+            "(take-last 3 {})",
+            vector(&[2, 3, 2]),
+            list(&[2, 3, 2]),
+            string("bcb"),
+        ),
+        ("(drop-last 3 {})", vector(&[1]), list(&[1]), string("a")),
+        (
+            "(distinct {})",
+            vector(&[1, 2, 3]),
+            list(&[1, 2, 3]),
+            string("abc"),
+        ),
+        (
+            "(interpose 0 (take 2 {}))",
+            vector(&[1, 0, 2]),
+            vector(&[1, 0, 2]),
+            Value::Vector(vec![string("a"), int(0), string("b")].into()),
+        ),
+    ];
+    for (template, from_vector, from_list, from_string) in cases {
+        for (coll, expected) in [
+            ("[1 2 3 2]", from_vector),
+            ("(list [1 2 3 2])", from_list),
+            ("\"abcb\"", from_string),
+        ] {
+            runner
+                .run_test(&template.replace("{}", coll), expected)
+                .unwrap();
+        }
+    }
+
+    // Strings are sequences of characters, not bytes
+    runner.run_test("(count \"héllo\")", int(5)).unwrap();
+    runner.run_test("(length \"héllo\")", int(5)).unwrap();
+    runner.run_test("(nth \"héllo\" 1)", string("é")).unwrap();
+    runner
+        .run_test(
+            "(vec \"ab\")",
+            Value::Vector(vec![string("a"), string("b")].into()),
+        )
+        .unwrap();
+    runner.run_test("(first \"\")", Value::Nil).unwrap();
+    runner.run_test("(rest \"\")", string("")).unwrap();
+    runner.run_error_test("(nth 42 0)", "nth").unwrap();
+}
+
+#[test]
+fn test_merge_ignores_nil_arguments() {
+    let mut runner = SecureStdlibTestRunner::new();
+    let kw = |s: &str| MapKey::Keyword(Keyword(s.into()));
+    let base = Value::Map(ValueMap::from_iter([
+        (kw("a"), Value::Integer(1)),
+        (kw("b"), Value::Integer(2)),
+    ]));
+
+    runner
+        .run_test("(merge {:a 1 :b 2} nil)", base.clone())
+        .unwrap();
+    runner
+        .run_test("(merge nil {:a 1 :b 2} (when false {:c 3}))", base)
+        .unwrap();
+    runner
+        .run_test(
+            "(merge {:a 1} (when true {:b 3}) nil)",
+            Value::Map(ValueMap::from_iter([
+                (kw("a"), Value::Integer(1)),
+                (kw("b"), Value::Integer(3)),
+            ])),
+        )
+        .unwrap();
+    runner
+        .run_error_test("(merge {:a 1} [1 2])", "merge")
+        .unwrap();
+}
+
+#[test]
+fn test_filter_keys_and_vals() {
+    let mut runner = SecureStdlibTestRunner::new();
+    let kw = |s: &str| MapKey::Keyword(Keyword(s.into()));
+
+    runner
+        .run_test(
+            "(filter-vals int? {:a 1 :b \"two\" :c 3 :d nil})",
+            Value::Map(ValueMap::from_iter([
+                (kw("a"), Value::Integer(1)),
+                (kw("c"), Value::Integer(3)),
+            ])),
+        )
+        .unwrap();
+    runner
+        .run_test(
+            "(filter-keys keyword? {:a 1 \"b\" 2 3 4})",
+            Value::Map(ValueMap::from_iter([(kw("a"), Value::Integer(1))])),
+        )
+        .unwrap();
+    // User-defined predicates and nil maps
+    runner
+        .run_test(
+            "(filter-vals (fn [v] (> v 1)) {:a 1 :b 2})",
+            Value::Map(ValueMap::from_iter([(kw("b"), Value::Integer(2))])),
+        )
+        .unwrap();
+    runner
+        .run_test("(filter-keys keyword? nil)", Value::Map(ValueMap::new()))
+        .unwrap();
+    runner
+        .run_error_test("(filter-vals int? [1 2])", "filter-vals")
+        .unwrap();
+}
+
+#[test]
+fn test_map_vals_and_map_keys() {
+    let mut runner = SecureStdlibTestRunner::new();
+    let kw = |s: &str| MapKey::Keyword(Keyword(s.into()));
+
+    runner
+        .run_test(
+            "(map-vals (fn [v] (* 2 v)) {:a 1 :b 2 :c 3})",
+            Value::Map(ValueMap::from_iter([
+                (kw("a"), Value::Integer(2)),
+                (kw("b"), Value::Integer(4)),
+                (kw("c"), Value::Integer(6)),
+            ])),
+        )
+        .unwrap();
+    runner
+        .run_test(
+            "(map-keys keyword {\"a\" 1 \"b\" 2})",
+            Value::Map(ValueMap::from_iter([
+                (kw("a"), Value::Integer(1)),
+                (kw("b"), Value::Integer(2)),
+            ])),
+        )
+        .unwrap();
+    runner
+        .run_test("(map-vals inc nil)", Value::Map(ValueMap::new()))
+        .unwrap();
+    runner
+        .run_test("(keyword \":b\")", Value::Keyword(Keyword("b".into())))
+        .unwrap();
+    // Keys must stay valid map keys, and must not collide
+    runner
+        .run_error_test("(map-keys (fn [k] [k]) {:a 1})", "map key")
+        .unwrap();
+    runner
+        .run_error_test(
+            "(map-keys keyword {\"a\" 1 :a 2})",
+            "more than one key maps to :a",
+        )
+        .unwrap();
+}
+
+#[test]
+fn test_string_sequence_builtins_count_chars_not_bytes() {
+    let mut runner = SecureStdlibTestRunner::new();
+    let string = |s: &str| Value::String(s.to_string());
+
+    // `length` used to report UTF-8 bytes; it now agrees with `count`
+    runner
+        .run_test("(length \"日本語\")", Value::Integer(3))
+        .unwrap();
+    runner
+        .run_test("(count \"日本語\")", Value::Integer(3))
+        .unwrap();
+    runner.run_test("(first \"日本語\")", string("日")).unwrap();
+    runner.run_test("(last \"日本語\")", string("語")).unwrap();
+    runner.run_test("(get \"héllo\" -4)", string("é")).unwrap();
+    runner
+        .run_test(
+            "(get \"héllo\" 5 :none)",
+            Value::Keyword(Keyword::new("none")),
+        )
+        .unwrap();
+    runner.run_test("(nth \"héllo\" -1)", string("o")).unwrap();
+    runner.run_test("(last \"\")", Value::Nil).unwrap();
+    runner
+        .run_error_test("(nth \"héllo\" 5)", "length: 5")
+        .unwrap();
+}
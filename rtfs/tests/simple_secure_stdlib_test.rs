@@ -6,7 +6,7 @@ fn test_secure_stdlib_basic() {
     let env = SecureStandardLibrary::create_secure_environment();
 
     // Test that basic arithmetic functions are defined
-    let plus_symbol = rtfs::ast::Symbol("+".to_string());
+    let plus_symbol = rtfs::ast::Symbol("+".into());
     let plus_func = env.lookup(&plus_symbol);
     assert!(
         plus_func.is_some(),
@@ -48,7 +48,7 @@ fn test_secure_stdlib_has_core_functions() {
     ];
 
     for func_name in functions_to_check {
-        let symbol = rtfs::ast::Symbol(func_name.to_string());
+        let symbol = rtfs::ast::Symbol(func_name.into());
         let func = env.lookup(&symbol);
         assert!(
             func.is_some(),
@@ -6,7 +6,7 @@ use rtfs::runtime::evaluator::Evaluator;
 use rtfs::runtime::module_runtime::ModuleRegistry;
 use rtfs::runtime::pure_host::create_pure_host;
 use rtfs::runtime::stdlib::StandardLibrary;
-use rtfs::runtime::values::Value;
+use rtfs::runtime::values::{Value, ValueMap};
 use std::sync::Arc;
 
 /// Test runner for standard library end-to-end tests
@@ -142,6 +142,20 @@ impl StdlibTestRunner {
         self.run_test("(float false)", Value::Float(0.0))?;
         self.run_test("(float nil)", Value::Float(0.0))?;
 
+        // strict as-* coercions
+        self.run_test("(as-float 3)", Value::Float(3.0))?; // int -> float promotion
+        self.run_test("(+ (as-float 1) 0.5)", Value::Float(1.5))?;
+        self.run_test("(as-int 4.0)", Value::Integer(4))?; // whole float -> int
+        self.run_test("(as-int \" 12 \")", Value::Integer(12))?;
+        self.run_test("(as-float \"2.5\")", Value::Float(2.5))?;
+        self.run_test("(as-string 42)", Value::String("42".to_string()))?;
+        self.run_test("(as-string :ok)", Value::String(":ok".to_string()))?;
+        self.run_test("(as-string \"hi\")", Value::String("hi".to_string()))?;
+        self.run_test(
+            "(as-vector [1 2])",
+            Value::Vector(vec![Value::Integer(1), Value::Integer(2)].into()),
+        )?;
+
         println!("✅ All type conversion function tests passed!");
         Ok(())
     }
@@ -283,32 +297,32 @@ impl StdlibTestRunner {
         println!("Running collection function tests...");
 
         // Vector tests
-        self.run_test("(vector)", Value::Vector(vec![]))?;
+        self.run_test("(vector)", Value::Vector(vec![].into()))?;
         self.run_test(
             "(vector 1 2 3)",
-            Value::Vector(vec![
-                Value::Integer(1),
-                Value::Integer(2),
-                Value::Integer(3),
-            ]),
+            Value::Vector(vec![Value::Integer(1), Value::Integer(2), Value::Integer(3)].into()),
         )?;
         self.run_test(
             "(vector \"a\" \"b\" \"c\")",
-            Value::Vector(vec![
-                Value::String("a".to_string()),
-                Value::String("b".to_string()),
-                Value::String("c".to_string()),
-            ]),
+            Value::Vector(
+                vec![
+                    Value::String("a".to_string()),
+                    Value::String("b".to_string()),
+                    Value::String("c".to_string()),
+                ]
+                .into(),
+            ),
         )?;
 
         // Hash map tests
-        self.run_test("(hash-map)", Value::Map(std::collections::HashMap::new()))?;
+        self.run_test("(hash-map)", Value::Map(ValueMap::new()))?;
 
         // Get tests
         self.run_test("(get [1 2 3] 0)", Value::Integer(1))?;
         self.run_test("(get [1 2 3] 1)", Value::Integer(2))?;
         self.run_test("(get [1 2 3] 5)", Value::Nil)?;
-        self.run_test("(get [1 2 3] -1)", Value::Nil)?;
+        self.run_test("(get [1 2 3] -1)", Value::Integer(3))?;
+        self.run_test("(get [1 2 3] -4)", Value::Nil)?;
 
         // Count tests
         self.run_test("(count [])", Value::Integer(0))?;
@@ -325,10 +339,10 @@ impl StdlibTestRunner {
         // Rest tests
         self.run_test(
             "(rest [1 2 3])",
-            Value::Vector(vec![Value::Integer(2), Value::Integer(3)]),
+            Value::Vector(vec![Value::Integer(2), Value::Integer(3)].into()),
         )?;
-        self.run_test("(rest [])", Value::Vector(vec![]))?;
-        self.run_test("(rest [1])", Value::Vector(vec![]))?;
+        self.run_test("(rest [])", Value::Vector(vec![].into()))?;
+        self.run_test("(rest [1])", Value::Vector(vec![].into()))?;
 
         // Empty tests
         self.run_test("(empty? [])", Value::Boolean(true))?;
@@ -340,64 +354,66 @@ impl StdlibTestRunner {
         // Conj tests
         self.run_test(
             "(conj [1 2] 3)",
-            Value::Vector(vec![
-                Value::Integer(1),
-                Value::Integer(2),
-                Value::Integer(3),
-            ]),
+            Value::Vector(vec![Value::Integer(1), Value::Integer(2), Value::Integer(3)].into()),
         )?;
-        self.run_test("(conj [] 1)", Value::Vector(vec![Value::Integer(1)]))?;
+        self.run_test("(conj [] 1)", Value::Vector(vec![Value::Integer(1)].into()))?;
 
         // Group-by tests
         // Test group-by with keyword as key-fn
         let mut expected_map = std::collections::HashMap::new();
         expected_map.insert(
             rtfs::ast::MapKey::String("open".to_string()),
-            Value::Vector(vec![
-                Value::Map({
-                    let mut m = std::collections::HashMap::new();
-                    m.insert(
-                        rtfs::ast::MapKey::Keyword(rtfs::ast::Keyword("state".to_string())),
-                        Value::String("open".to_string()),
-                    );
-                    m.insert(
-                        rtfs::ast::MapKey::Keyword(rtfs::ast::Keyword("id".to_string())),
-                        Value::Integer(1),
-                    );
-                    m
-                }),
-                Value::Map({
+            Value::Vector(
+                vec![
+                    Value::Map({
+                        let mut m = std::collections::HashMap::new();
+                        m.insert(
+                            rtfs::ast::MapKey::Keyword(rtfs::ast::Keyword("state".into())),
+                            Value::String("open".to_string()),
+                        );
+                        m.insert(
+                            rtfs::ast::MapKey::Keyword(rtfs::ast::Keyword("id".into())),
+                            Value::Integer(1),
+                        );
+                        m.into()
+                    }),
+                    Value::Map({
+                        let mut m = std::collections::HashMap::new();
+                        m.insert(
+                            rtfs::ast::MapKey::Keyword(rtfs::ast::Keyword("state".into())),
+                            Value::String("open".to_string()),
+                        );
+                        m.insert(
+                            rtfs::ast::MapKey::Keyword(rtfs::ast::Keyword("id".into())),
+                            Value::Integer(3),
+                        );
+                        m.into()
+                    }),
+                ]
+                .into(),
+            ),
+        );
+        expected_map.insert(
+            rtfs::ast::MapKey::String("closed".to_string()),
+            Value::Vector(
+                vec![Value::Map({
                     let mut m = std::collections::HashMap::new();
                     m.insert(
-                        rtfs::ast::MapKey::Keyword(rtfs::ast::Keyword("state".to_string())),
-                        Value::String("open".to_string()),
+                        rtfs::ast::MapKey::Keyword(rtfs::ast::Keyword("state".into())),
+                        Value::String("closed".to_string()),
                     );
                     m.insert(
-                        rtfs::ast::MapKey::Keyword(rtfs::ast::Keyword("id".to_string())),
-                        Value::Integer(3),
+                        rtfs::ast::MapKey::Keyword(rtfs::ast::Keyword("id".into())),
+                        Value::Integer(2),
                     );
-                    m
-                }),
-            ]),
-        );
-        expected_map.insert(
-            rtfs::ast::MapKey::String("closed".to_string()),
-            Value::Vector(vec![Value::Map({
-                let mut m = std::collections::HashMap::new();
-                m.insert(
-                    rtfs::ast::MapKey::Keyword(rtfs::ast::Keyword("state".to_string())),
-                    Value::String("closed".to_string()),
-                );
-                m.insert(
-                    rtfs::ast::MapKey::Keyword(rtfs::ast::Keyword("id".to_string())),
-                    Value::Integer(2),
-                );
-                m
-            })]),
+                    m.into()
+                })]
+                .into(),
+            ),
         );
         self.run_test(
             "(group-by :state [{:state \"open\" :id 1} {:state \"closed\" :id 2} {:state \"open\" :id 3}])",
-            Value::Map(expected_map),
+            Value::Map(expected_map.into()),
         )?;
 
         // Test group-by with function as key-fn
@@ -410,38 +426,35 @@ impl StdlibTestRunner {
                     Value::Vector(vec![
                         Value::Map({
                             let mut inner = std::collections::HashMap::new();
-                            inner.insert(rtfs::ast::MapKey::Keyword(rtfs::ast::Keyword("state".to_string())), Value::String("a".to_string()));
-                            inner.insert(rtfs::ast::MapKey::Keyword(rtfs::ast::Keyword("v".to_string())), Value::Integer(1));
-                            inner
+                            inner.insert(rtfs::ast::MapKey::Keyword(rtfs::ast::Keyword("state".into())), Value::String("a".to_string()));
+                            inner.insert(rtfs::ast::MapKey::Keyword(rtfs::ast::Keyword("v".into())), Value::Integer(1));
+                            inner.into()
                         }),
                         Value::Map({
                             let mut inner = std::collections::HashMap::new();
-                            inner.insert(rtfs::ast::MapKey::Keyword(rtfs::ast::Keyword("state".to_string())), Value::String("a".to_string()));
-                            inner.insert(rtfs::ast::MapKey::Keyword(rtfs::ast::Keyword("v".to_string())), Value::Integer(3));
-                            inner
+                            inner.insert(rtfs::ast::MapKey::Keyword(rtfs::ast::Keyword("state".into())), Value::String("a".to_string()));
+                            inner.insert(rtfs::ast::MapKey::Keyword(rtfs::ast::Keyword("v".into())), Value::Integer(3));
+                            inner.into()
                         }),
-                    ]),
+                    ].into()),
                 );
                 m.insert(
                     rtfs::ast::MapKey::String("b".to_string()),
                     Value::Vector(vec![
                         Value::Map({
                             let mut inner = std::collections::HashMap::new();
-                            inner.insert(rtfs::ast::MapKey::Keyword(rtfs::ast::Keyword("state".to_string())), Value::String("b".to_string()));
-                            inner.insert(rtfs::ast::MapKey::Keyword(rtfs::ast::Keyword("v".to_string())), Value::Integer(2));
-                            inner
+                            inner.insert(rtfs::ast::MapKey::Keyword(rtfs::ast::Keyword("state".into())), Value::String("b".to_string()));
+                            inner.insert(rtfs::ast::MapKey::Keyword(rtfs::ast::Keyword("v".into())), Value::Integer(2));
+                            inner.into()
                         }),
-                    ]),
+                    ].into()),
                 );
-                Value::Map(m)
+                Value::Map(m.into())
             },
         )?;
 
         // Test group-by on empty collection
-        self.run_test(
-            "(group-by :state [])",
-            Value::Map(std::collections::HashMap::new()),
-        )?;
+        self.run_test("(group-by :state [])", Value::Map(ValueMap::new()))?;
 
         println!("✅ All collection function tests passed!");
         Ok(())
@@ -595,6 +608,33 @@ fn test_type_conversion_functions() {
     runner.run_type_conversion_tests().unwrap();
 }
 
+#[test]
+fn test_failed_coercions_report_the_offending_value() {
+    let mut runner = StdlibTestRunner::new();
+    for (source, expected) in [
+        ("(as-int {:a 1})", "integer, float or string"),
+        ("(as-int 3.5)", "whole number"),
+        ("(as-float \"abc\")", "numeric string"),
+        ("(as-vector \"abc\")", "vector or list"),
+    ] {
+        let error = runner.run_test(source, Value::Nil).unwrap_err();
+        assert!(
+            error.starts_with("Evaluation error"),
+            "{}: {}",
+            source,
+            error
+        );
+        assert!(error.contains(expected), "{}: {}", source, error);
+    }
+
+    let ast = parse_expression("(as-int {:a 1})").unwrap();
+    let message = runner.evaluator.evaluate(&ast).unwrap_err().to_string();
+    assert_eq!(
+        message,
+        "Type error in as-int: expected integer, float or string, got map {:a 1}"
+    );
+}
+
 #[test]
 fn test_all_stdlib_functions() {
     println!("Running comprehensive standard library end-to-end tests...");
@@ -0,0 +1,79 @@
+// Compiled RTFS programs survive a bytes round trip and evaluate identically
+
+use rtfs::bytecode::{compile_to_bytes, load_from_bytes, BYTECODE_MAGIC};
+use rtfs::runtime::error::RuntimeError;
+use rtfs::runtime::execution_outcome::ExecutionOutcome;
+use rtfs::runtime::ir_runtime::IrStrategy;
+use rtfs::runtime::module_runtime::ModuleRegistry;
+use rtfs::runtime::values::Value;
+use rtfs::RuntimeStrategy;
+use std::sync::Arc;
+
+const PROGRAM: &str = r#"
+(defn square [x] (* x x))
+(let [total (reduce + 0 (map (fn [x] (square x)) [1 2 3 4]))]
+  {:total total :label (str "sum=" total) :odd (odd? total)})
+"#;
+
+fn complete(outcome: Result<ExecutionOutcome, RuntimeError>) -> Value {
+    match outcome.expect("program should run") {
+        ExecutionOutcome::Complete(v) => v,
+        other => panic!("unexpected outcome: {:?}", other),
+    }
+}
+
+fn fresh_strategy() -> IrStrategy {
+    IrStrategy::new(Arc::new(ModuleRegistry::new()))
+}
+
+#[test]
+fn test_compiled_program_round_trips() {
+    let bytes = compile_to_bytes(PROGRAM).expect("program should compile");
+    assert!(bytes.starts_with(BYTECODE_MAGIC));
+
+    // Reference: evaluate from source
+    let mut parsed = rtfs::parse(PROGRAM).expect("program should parse");
+    let expressions = parsed
+        .drain(..)
+        .map(|item| match item {
+            rtfs::TopLevel::Expression(e) => e,
+            other => panic!("unexpected top level {:?}", other),
+        })
+        .collect();
+    let from_source = complete(fresh_strategy().run(&rtfs::ast::Expression::Do(
+        rtfs::ast::DoExpr { expressions },
+    )));
+
+    let program = load_from_bytes(&bytes).expect("bytecode should load");
+    let from_bytes = complete(fresh_strategy().run_compiled(&program));
+    assert_eq!(from_bytes, from_source);
+    assert!(matches!(&from_bytes, Value::Map(m) if m.len() == 3));
+
+    // Reloading is deterministic
+    assert_eq!(load_from_bytes(&bytes).unwrap(), program);
+}
+
+#[test]
+fn test_incompatible_bytecode_is_rejected() {
+    let mut bytes = compile_to_bytes("(+ 1 2)").unwrap();
+
+    let mut other_version = bytes.clone();
+    other_version[4..8].copy_from_slice(&99u32.to_le_bytes());
+    match load_from_bytes(&other_version) {
+        Err(RuntimeError::InvalidProgram(message)) => {
+            assert!(message.contains("version 99"), "{}", message)
+        }
+        other => panic!("expected a version error, got {:?}", other),
+    }
+
+    assert!(matches!(
+        load_from_bytes(b"\0asm\x01\0\0\0"),
+        Err(RuntimeError::InvalidProgram(_))
+    ));
+
+    bytes.truncate(bytes.len() - 3);
+    assert!(matches!(
+        load_from_bytes(&bytes),
+        Err(RuntimeError::InvalidProgram(_))
+    ));
+}
@@ -98,10 +98,13 @@ fn test_complex_structural_types_github_issue_like() {
     );
     issue1.insert(
         MapKey::Keyword(Keyword::new("labels")),
-        Value::Vector(vec![
-            Value::String("bug".to_string()),
-            Value::String("parser".to_string()),
-        ]),
+        Value::Vector(
+            vec![
+                Value::String("bug".to_string()),
+                Value::String("parser".to_string()),
+            ]
+            .into(),
+        ),
     );
     issue1.insert(MapKey::Keyword(Keyword::new("estimate")), Value::Integer(5));
     issue1.insert(
@@ -143,7 +146,7 @@ fn test_complex_structural_types_github_issue_like() {
     );
     issue2.insert(
         MapKey::Keyword(Keyword::new("labels")),
-        Value::Vector(vec![Value::String("enhancement".to_string())]),
+        Value::Vector(vec![Value::String("enhancement".to_string())].into()),
     );
     issue2.insert(MapKey::Keyword(Keyword::new("estimate")), Value::Integer(8));
     issue2.insert(
@@ -167,7 +170,8 @@ fn test_complex_structural_types_github_issue_like() {
         Value::String("We need this new feature".to_string()),
     );
 
-    let valid_issues = Value::Vector(vec![Value::Map(issue1), Value::Map(issue2)]);
+    let valid_issues =
+        Value::Vector(vec![Value::Map(issue1.into()), Value::Map(issue2.into())].into());
 
     // This should validate successfully
     assert!(validator
@@ -212,7 +216,7 @@ fn test_complex_structural_types_missing_required_fields() {
         Value::String("open".to_string()),
     );
 
-    let invalid_issues = Value::Vector(vec![Value::Map(invalid_issue)]);
+    let invalid_issues = Value::Vector(vec![Value::Map(invalid_issue.into())].into());
 
     // This should fail validation
     assert!(validator
@@ -254,7 +258,7 @@ fn test_complex_structural_types_wrong_field_types() {
         Value::String("Valid title".to_string()),
     );
 
-    let invalid_issues = Value::Vector(vec![Value::Map(invalid_issue)]);
+    let invalid_issues = Value::Vector(vec![Value::Map(invalid_issue.into())].into());
 
     // This should fail validation
     assert!(validator
@@ -284,13 +288,16 @@ fn test_complex_structural_types_nested_vector_wrong_type() {
     let mut invalid_issue = HashMap::new();
     invalid_issue.insert(
         MapKey::Keyword(Keyword::new("labels")),
-        Value::Vector(vec![
-            Value::Integer(1), // should be string
-            Value::Integer(2), // should be string
-        ]),
+        Value::Vector(
+            vec![
+                Value::Integer(1), // should be string
+                Value::Integer(2), // should be string
+            ]
+            .into(),
+        ),
     );
 
-    let invalid_issues = Value::Vector(vec![Value::Map(invalid_issue)]);
+    let invalid_issues = Value::Vector(vec![Value::Map(invalid_issue.into())].into());
 
     // This should fail validation
     assert!(validator
@@ -355,8 +362,8 @@ fn test_complex_structural_types_comparison_different_map_structures() {
     );
     person.insert(MapKey::Keyword(Keyword::new("age")), Value::Integer(30));
 
-    let issues_data = Value::Vector(vec![Value::Map(issue)]);
-    let people_data = Value::Vector(vec![Value::Map(person)]);
+    let issues_data = Value::Vector(vec![Value::Map(issue.into())].into());
+    let people_data = Value::Vector(vec![Value::Map(person.into())].into());
 
     // Issue data should validate against issue type but not person type
     assert!(validator
@@ -412,7 +419,7 @@ fn test_map_type_subset_relationships() {
         MapKey::Keyword(Keyword::new("name")),
         Value::String("Alice".to_string()),
     );
-    let minimal_person_value = Value::Map(minimal_person);
+    let minimal_person_value = Value::Map(minimal_person.into());
 
     // Create data that matches the full type (has both name and age)
     let mut full_person = HashMap::new();
@@ -421,7 +428,7 @@ fn test_map_type_subset_relationships() {
         Value::String("Bob".to_string()),
     );
     full_person.insert(MapKey::Keyword(Keyword::new("age")), Value::Integer(30));
-    let full_person_value = Value::Map(full_person);
+    let full_person_value = Value::Map(full_person.into());
 
     // Test current behavior: no subtyping relationship
     // Minimal data validates against minimal type
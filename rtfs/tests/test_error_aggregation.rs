@@ -0,0 +1,61 @@
+// check_program reports every independent error in a program, with spans
+
+use rtfs::error_reporting::{check_program, DiagnosticFormatter, DiagnosticInfo};
+use rtfs::parser::parse_collecting_errors;
+
+fn location(diagnostic: &DiagnosticInfo) -> (usize, usize) {
+    let span = diagnostic
+        .primary_span
+        .as_ref()
+        .expect("diagnostic should carry a span");
+    (span.start_line, span.start_column)
+}
+
+#[test]
+fn test_three_independent_errors_are_reported_together() {
+    let source = "(defn total [items]\n  (reduce + 0 (mapp :price items)))\n(let [n (+ 1 2]\n  n)\n(inc 1 2)\n";
+    let diagnostics = check_program(source, Some("plan.rtfs"));
+
+    assert_eq!(diagnostics.len(), 3, "{:#?}", diagnostics);
+
+    let syntax = &diagnostics[0];
+    assert!(syntax.primary_message.contains("Mismatched delimiter"));
+    assert_eq!(location(syntax).0, 3);
+
+    let undefined = &diagnostics[1];
+    assert_eq!(undefined.primary_message, "Undefined symbol `mapp`");
+    assert_eq!(location(undefined), (2, 15));
+    assert!(undefined.hints[0].message.contains("map"));
+
+    let arity = &diagnostics[2];
+    assert!(arity.primary_message.contains("`inc` expects 1"));
+    assert_eq!(location(arity), (5, 1));
+    assert_eq!(
+        arity.primary_span.as_ref().unwrap().file_path.as_deref(),
+        Some("plan.rtfs")
+    );
+
+    let report = DiagnosticFormatter::default().format_diagnostics(&diagnostics);
+    assert!(report.contains("found 3 errors"), "{}", report);
+}
+
+#[test]
+fn test_valid_program_has_no_diagnostics() {
+    let source =
+        "(defn fact [n] (if (= n 0) 1 (* n (fact (- n 1)))))\n(let [{:keys [a]} {:a 2}] (fact a))";
+    assert_eq!(check_program(source, None), vec![]);
+}
+
+#[test]
+fn test_syntax_errors_are_isolated_per_form() {
+    let source = "(+ 1 2)\n(let [x 1)\n  x)\n(str \"ok\")\n(str \"a\"\n";
+    let (items, errors) = parse_collecting_errors(source, None);
+
+    assert_eq!(items.len(), 2);
+    let lines: Vec<usize> = errors
+        .iter()
+        .map(|e| e.diagnostic.primary_span.as_ref().unwrap().start_line)
+        .collect();
+    assert_eq!(lines, vec![2, 6]);
+    assert!(errors.iter().all(|e| e.source_code == source));
+}
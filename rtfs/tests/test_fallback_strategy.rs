@@ -0,0 +1,52 @@
+// IrWithFallbackStrategy: AST re-evaluation only for constructs the IR cannot lower
+
+use rtfs::parser::parse_expression;
+use rtfs::runtime::error::RuntimeError;
+use rtfs::runtime::execution_outcome::ExecutionOutcome;
+use rtfs::runtime::ir_runtime::IrStrategy;
+use rtfs::runtime::module_runtime::ModuleRegistry;
+use rtfs::runtime::values::Value;
+use rtfs::runtime::IrWithFallbackStrategy;
+use rtfs::RuntimeStrategy;
+use std::sync::Arc;
+
+fn run(strategy: &mut dyn RuntimeStrategy, source: &str) -> Result<Value, RuntimeError> {
+    let expr = parse_expression(source).expect("Should parse successfully");
+    match strategy.run(&expr)? {
+        ExecutionOutcome::Complete(v) => Ok(v),
+        other => panic!("unexpected outcome for {}: {:?}", source, other),
+    }
+}
+
+#[test]
+fn test_ast_only_construct_succeeds_under_fallback() {
+    let source = "(for [x [1 2 3]] (* x 2))";
+    let expected =
+        Value::Vector(vec![Value::Integer(2), Value::Integer(4), Value::Integer(6)].into());
+
+    let mut ir = IrStrategy::new(Arc::new(ModuleRegistry::new()));
+    assert_eq!(
+        run(&mut ir, source),
+        Err(RuntimeError::UnsupportedInIr("for".to_string()))
+    );
+
+    let mut fallback = IrWithFallbackStrategy::new(Arc::new(ModuleRegistry::new()));
+    assert_eq!(run(&mut fallback, source), Ok(expected));
+    assert_eq!(
+        run(&mut fallback, "(let [x 2] `(1 ~x))"),
+        Ok(Value::List(
+            vec![Value::Integer(1), Value::Integer(2)].into()
+        ))
+    );
+}
+
+#[test]
+fn test_fallback_keeps_ir_results_and_errors() {
+    let mut fallback = IrWithFallbackStrategy::new(Arc::new(ModuleRegistry::new()));
+    assert_eq!(run(&mut fallback, "(+ 1 2)"), Ok(Value::Integer(3)));
+
+    // A genuine runtime error is reported by the IR, not retried on the AST
+    let error = run(&mut fallback, "(/ 1 0)").unwrap_err();
+    assert_eq!(error.without_location(), &RuntimeError::DivisionByZero);
+    assert!(matches!(error, RuntimeError::Located { .. }));
+}
@@ -176,3 +176,96 @@ fn test_hof_function_type_annotation_not_yet_supported() {
         ir_res
     );
 }
+
+#[test]
+fn test_variadic_user_functions() {
+    let int_list = |xs: &[i64]| Value::List(xs.iter().map(|x| Value::Integer(*x)).collect());
+    let cases = [
+        // Required params bind first; extra args are collected as a list (possibly empty)
+        ("((fn [a b & rest] rest) 1 2)", int_list(&[])),
+        ("((fn [a b & rest] rest) 1 2 3 4)", int_list(&[3, 4])),
+        (
+            "((fn [a b & rest] (+ a b (count rest))) 1 2 3 4 5)",
+            Value::Integer(6),
+        ),
+        ("((fn [& xs] (count xs)))", Value::Integer(0)),
+        // Through apply, with leading args and a spread collection
+        (
+            "(apply (fn [a & rest] (cons a rest)) 1 [2 3])",
+            int_list(&[1, 2, 3]),
+        ),
+        // Through higher-order builtins
+        (
+            "(map (fn [& xs] (count xs)) [1 2 3])",
+            Value::Vector(vec![Value::Integer(1); 3].into()),
+        ),
+        (
+            "(filter (fn [x & more] (empty? more)) [1 2])",
+            Value::Vector(vec![Value::Integer(1), Value::Integer(2)].into()),
+        ),
+        (
+            "(reduce (fn [& xs] (apply + xs)) 0 [1 2 3])",
+            Value::Integer(6),
+        ),
+        (
+            "(map (fn [[a b]] (+ a b)) [[1 2] [3 4]])",
+            Value::Vector(vec![Value::Integer(3), Value::Integer(7)].into()),
+        ),
+    ];
+
+    for (code, expected) in cases {
+        let (ast_res, ir_res) = eval_ast_and_ir(code);
+        for (runtime, res) in [("AST", ast_res), ("IR", ir_res)] {
+            match res {
+                Ok(ExecutionOutcome::Complete(v)) => {
+                    assert_eq!(v, expected, "{} result for {}", runtime, code)
+                }
+                other => panic!("{} failed for {}: {:?}", runtime, code, other),
+            }
+        }
+    }
+
+    // Too few arguments for the required params is still an arity error
+    let (ast_res, ir_res) = eval_ast_and_ir("((fn [a b & rest] a) 1)");
+    assert!(ast_res.is_err(), "AST should reject missing required args");
+    assert!(ir_res.is_err(), "IR should reject missing required args");
+}
+
+#[test]
+fn test_closure_arity_mismatch_is_reported() {
+    let cases = [
+        (
+            "(map (fn [a b] a) [1 2])",
+            "Arity mismatch in anonymous fn: expected 2, got 1",
+        ),
+        (
+            "(let [pair (fn [a b] [a b])] (pair 1))",
+            "Arity mismatch in pair: expected 2, got 1",
+        ),
+        (
+            "((fn [] 1) 2)",
+            "Arity mismatch in anonymous fn: expected 0, got 1",
+        ),
+        (
+            "((fn [a b & rest] a) 1)",
+            "expected at least 2, got 1",
+        ),
+    ];
+
+    for (code, expected) in cases {
+        let (ast_res, ir_res) = eval_ast_and_ir(code);
+        for (runtime, res) in [("AST", ast_res), ("IR", ir_res)] {
+            match res {
+                Err(e) => assert!(
+                    e.contains(expected),
+                    "{} error for {} should mention '{}', got: {}",
+                    runtime,
+                    code,
+                    expected,
+                    e
+                ),
+                Ok(outcome) => panic!("{} accepted {}: {:?}", runtime, code, outcome),
+            }
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use rtfs::ast::{DoExpr, Expression, Literal, Symbol, TopLevel};
+use rtfs::ast::{DoExpr, Expression, Literal, Span, Symbol, TopLevel};
 use rtfs::compiler::expander::MacroExpander;
 use rtfs::parser::parse;
 
@@ -17,6 +17,7 @@ fn test_simple_macro() {
                     Expression::Literal(Literal::Integer(1)),
                     Expression::Literal(Literal::Integer(2)),
                 ],
+                span: Span::unknown(),
             },
         ],
     });
@@ -43,7 +44,6 @@ fn test_quasiquote() {
         expressions: vec![
             Expression::Literal(Literal::Nil),
             Expression::List(vec![
-                Expression::Literal(Literal::Integer(1)),
                 Expression::Literal(Literal::Integer(1)),
                 Expression::Literal(Literal::Integer(2)),
                 Expression::Literal(Literal::Integer(3)),
@@ -72,16 +72,12 @@ fn test_unquote_splicing() {
     let expected = Expression::Do(DoExpr {
         expressions: vec![
             Expression::Literal(Literal::Nil),
+            // The macro argument is the unevaluated form, so its elements are spliced
             Expression::List(vec![
                 Expression::Literal(Literal::Integer(1)),
-                Expression::Literal(Literal::Integer(1)),
-                Expression::FunctionCall {
-                    callee: Box::new(Expression::Symbol(Symbol::new("list"))),
-                    arguments: vec![
-                        Expression::Literal(Literal::Integer(2)),
-                        Expression::Literal(Literal::Integer(3)),
-                    ],
-                },
+                Expression::Symbol(Symbol::new("list")),
+                Expression::Literal(Literal::Integer(2)),
+                Expression::Literal(Literal::Integer(3)),
                 Expression::Literal(Literal::Integer(4)),
             ]),
         ],
@@ -145,6 +141,7 @@ fn test_variadic_macro() {
                     rtfs::ast::Expression::Literal(rtfs::ast::Literal::Integer(5)),
                     rtfs::ast::Expression::Literal(rtfs::ast::Literal::Integer(1)),
                 ],
+                span: Span::unknown(),
             },
         ],
     });
@@ -206,6 +203,7 @@ fn test_macro_runtime_integration() {
         arguments: vec![rtfs::ast::Expression::Literal(rtfs::ast::Literal::Integer(
             100,
         ))],
+        span: Span::unknown(),
     });
 
     let outcome2 = evaluator.eval_toplevel(&[new_expr]).unwrap();
@@ -0,0 +1,70 @@
+use rtfs::parser::parse_expression;
+use rtfs::runtime::error::{RuntimeError, RuntimeResult};
+use rtfs::runtime::evaluator::Evaluator;
+use rtfs::runtime::execution_outcome::ExecutionOutcome;
+use rtfs::runtime::mock_host::{MockHost, RecordedCall};
+use rtfs::runtime::module_runtime::ModuleRegistry;
+use rtfs::runtime::values::Value;
+use std::sync::Arc;
+
+fn evaluate_with_host(source: &str, host: Arc<MockHost>) -> RuntimeResult<Value> {
+    let evaluator = Evaluator::new_with_defaults(
+        Arc::new(ModuleRegistry::new()),
+        host,
+        rtfs::compiler::expander::MacroExpander::default(),
+    );
+    let expr = parse_expression(source).expect("parse failed");
+    match evaluator.evaluate(&expr)? {
+        ExecutionOutcome::Complete(value) => Ok(value),
+        ExecutionOutcome::RequiresHost(_) => panic!("unexpected host yield"),
+    }
+}
+
+#[test]
+fn test_call_forwards_args_and_returns_stubbed_value() {
+    let host = Arc::new(MockHost::new().with_response("x", Value::String("stubbed".to_string())));
+
+    let result = evaluate_with_host("(call :x 1 2)", host.clone()).unwrap();
+
+    assert_eq!(result, Value::String("stubbed".to_string()));
+    assert_eq!(
+        host.calls(),
+        vec![RecordedCall {
+            capability: "x".to_string(),
+            args: vec![Value::Integer(1), Value::Integer(2)],
+        }]
+    );
+}
+
+#[test]
+fn test_mock_host_records_every_call_in_order() {
+    let host = Arc::new(
+        MockHost::new()
+            .with_response("a", Value::Integer(10))
+            .with_response("b", Value::Integer(20)),
+    );
+
+    let result = evaluate_with_host("(+ (call :a 1) (call :b) (call :a 2))", host.clone()).unwrap();
+
+    assert_eq!(result, Value::Integer(40));
+    assert_eq!(host.calls().len(), 3);
+    let a_args: Vec<Vec<Value>> = host.calls_to("a").into_iter().map(|c| c.args).collect();
+    assert_eq!(
+        a_args,
+        vec![vec![Value::Integer(1)], vec![Value::Integer(2)]]
+    );
+}
+
+#[test]
+fn test_mock_host_errors_for_unconfigured_or_failing_capabilities() {
+    let host = Arc::new(
+        MockHost::new().with_error("boom", RuntimeError::Generic("configured failure".into())),
+    );
+
+    let err = evaluate_with_host("(call :boom)", host.clone()).unwrap_err();
+    assert!(err.to_string().contains("configured failure"));
+
+    let err = evaluate_with_host("(call :missing)", host.clone()).unwrap_err();
+    assert!(err.to_string().contains("no response configured"));
+    assert_eq!(host.calls().len(), 2);
+}
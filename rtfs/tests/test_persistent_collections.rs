@@ -0,0 +1,93 @@
+use rtfs::ast::{MapKey, Symbol};
+use rtfs::runtime::secure_stdlib::SecureStandardLibrary;
+use rtfs::runtime::values::{BuiltinFunction, Function, Value, ValueMap, ValueVector};
+use std::sync::Arc;
+
+const ASSOC_COUNT: i64 = 10_000;
+
+fn builtin_function(name: &str) -> BuiltinFunction {
+    let env = SecureStandardLibrary::create_secure_environment();
+    match env.lookup(&Symbol::new(name)) {
+        Some(Value::Function(Function::Builtin(f))) => f,
+        other => panic!("{} is not a builtin: {:?}", name, other),
+    }
+}
+
+fn builtin(name: &str) -> impl Fn(Vec<Value>) -> Value {
+    let f = builtin_function(name);
+    move |args| (f.func)(args).expect("builtin call")
+}
+
+#[test]
+fn test_sequential_assoc_shares_structure() {
+    let assoc = builtin("assoc");
+
+    // Every entry holds the same builtin, so the strong count of its Arc
+    // tracks how many entries exist across all live maps.
+    let shared = builtin_function("inc");
+    let value = Value::Function(Function::Builtin(shared.clone()));
+    let mut map = Value::Map(ValueMap::new());
+    for i in 0..ASSOC_COUNT {
+        map = assoc(vec![map, Value::Integer(i), value.clone()]);
+    }
+
+    let before = Arc::strong_count(&shared.func);
+    let grown = assoc(vec![
+        map.clone(),
+        Value::Integer(ASSOC_COUNT),
+        value.clone(),
+    ]);
+    let copied = Arc::strong_count(&shared.func) - before;
+
+    let (Value::Map(original), Value::Map(grown)) = (&map, &grown) else {
+        panic!("assoc returned {:?}", grown)
+    };
+    assert_eq!(original.len() as i64, ASSOC_COUNT);
+    assert_eq!(grown.len() as i64, ASSOC_COUNT + 1);
+    assert_eq!(grown.get(&MapKey::Integer(ASSOC_COUNT)), Some(&value));
+    // A copying assoc would duplicate every entry; a persistent one only
+    // copies the nodes on the path to the new key.
+    assert!(
+        copied < 100,
+        "assoc on a {}-entry map copied {} entries",
+        ASSOC_COUNT,
+        copied
+    );
+}
+
+#[test]
+fn test_assoc_and_conj_leave_the_original_untouched() {
+    let assoc = builtin("assoc");
+    let conj = builtin("conj");
+
+    let original: ValueMap = (0..100)
+        .map(|i| (MapKey::Integer(i), Value::Integer(i)))
+        .collect();
+    let updated = assoc(vec![
+        Value::Map(original.clone()),
+        Value::Integer(0),
+        Value::String("changed".to_string()),
+    ]);
+    assert_eq!(original.get(&MapKey::Integer(0)), Some(&Value::Integer(0)));
+    match updated {
+        Value::Map(m) => {
+            assert_eq!(
+                m.get(&MapKey::Integer(0)),
+                Some(&Value::String("changed".to_string()))
+            );
+            assert_eq!(m.len(), original.len());
+        }
+        other => panic!("assoc returned {:?}", other),
+    }
+
+    let items: ValueVector = (0..100).map(Value::Integer).collect();
+    let grown = conj(vec![Value::Vector(items.clone()), Value::Integer(100)]);
+    assert_eq!(items.len(), 100);
+    match grown {
+        Value::Vector(v) => {
+            assert_eq!(v.len(), 101);
+            assert_eq!(v.back(), Some(&Value::Integer(100)));
+        }
+        other => panic!("conj returned {:?}", other),
+    }
+}
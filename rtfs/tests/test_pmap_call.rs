@@ -0,0 +1,155 @@
+use rtfs::parser::parse_expression;
+use rtfs::runtime::error::{RuntimeError, RuntimeResult};
+use rtfs::runtime::evaluator::Evaluator;
+use rtfs::runtime::execution_outcome::ExecutionOutcome;
+use rtfs::runtime::host_interface::HostInterface;
+use rtfs::runtime::module_runtime::ModuleRegistry;
+use rtfs::runtime::stdlib::MAX_PMAP_CONCURRENCY;
+use rtfs::runtime::values::Value;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Host whose capabilities sleep before answering, to observe concurrency.
+#[derive(Debug)]
+struct SlowHost {
+    latency: Duration,
+}
+
+impl HostInterface for SlowHost {
+    fn execute_capability(&self, name: &str, args: &[Value]) -> RuntimeResult<Value> {
+        std::thread::sleep(self.latency);
+        match (name, args.first()) {
+            ("test.double", Some(Value::Integer(n))) => Ok(Value::Integer(n * 2)),
+            ("test.double", Some(other)) => {
+                Err(RuntimeError::Generic(format!("cannot double {}", other)))
+            }
+            _ => Err(RuntimeError::CapabilityNotFound {
+                capability_id: name.to_string(),
+            }),
+        }
+    }
+    fn notify_step_started(&self, _step_name: &str) -> RuntimeResult<String> {
+        Ok("step".to_string())
+    }
+    fn notify_step_completed(
+        &self,
+        _step_action_id: &str,
+        _result: &rtfs::runtime::stubs::ExecutionResultStruct,
+    ) -> RuntimeResult<()> {
+        Ok(())
+    }
+    fn notify_step_failed(&self, _step_action_id: &str, _error: &str) -> RuntimeResult<()> {
+        Ok(())
+    }
+    fn set_execution_context(
+        &self,
+        _plan_id: String,
+        _intent_ids: Vec<String>,
+        _parent_action_id: String,
+    ) {
+    }
+    fn clear_execution_context(&self) {}
+    fn set_step_exposure_override(&self, _expose: bool, _context_keys: Option<Vec<String>>) {}
+    fn clear_step_exposure_override(&self) {}
+    fn get_context_value(&self, _key: &str) -> Option<Value> {
+        None
+    }
+}
+
+fn evaluate_with_slow_host(source: &str, latency: Duration) -> RuntimeResult<Value> {
+    let evaluator = Evaluator::new_with_defaults(
+        Arc::new(ModuleRegistry::new()),
+        Arc::new(SlowHost { latency }),
+        rtfs::compiler::expander::MacroExpander::default(),
+    );
+    let expr = parse_expression(source).expect("parse failed");
+    match evaluator.evaluate(&expr)? {
+        ExecutionOutcome::Complete(value) => Ok(value),
+        ExecutionOutcome::RequiresHost(_) => panic!("unexpected host yield"),
+    }
+}
+
+#[test]
+fn test_pmap_call_runs_concurrently_and_preserves_order() {
+    let latency = Duration::from_millis(200);
+    let start = Instant::now();
+    let result =
+        evaluate_with_slow_host("(pmap-call :test.double [1 2 3 4 5 6 7 8])", latency).unwrap();
+    let elapsed = start.elapsed();
+
+    assert_eq!(
+        result,
+        Value::Vector((1..=8).map(|n| Value::Integer(n * 2)).collect())
+    );
+    // Sequential execution would take 8 * latency
+    assert!(
+        elapsed < latency * 4,
+        "pmap-call took {:?}, expected roughly one call latency",
+        elapsed
+    );
+}
+
+#[test]
+fn test_pmap_call_respects_concurrency_limit() {
+    let latency = Duration::from_millis(100);
+    let start = Instant::now();
+    let result = evaluate_with_slow_host("(pmap-call :test.double [1 2 3 4] 2)", latency).unwrap();
+    let elapsed = start.elapsed();
+
+    assert_eq!(
+        result,
+        Value::Vector(
+            vec![
+                Value::Integer(2),
+                Value::Integer(4),
+                Value::Integer(6),
+                Value::Integer(8),
+            ]
+            .into()
+        )
+    );
+    // Two workers need two rounds for four inputs
+    assert!(elapsed >= latency * 2, "finished too fast: {:?}", elapsed);
+}
+
+#[test]
+fn test_pmap_call_collects_all_errors() {
+    let err = evaluate_with_slow_host(
+        "(pmap-call :test.double [1 \"a\" 3 \"b\"])",
+        Duration::from_millis(1),
+    )
+    .unwrap_err();
+
+    let message = err.to_string();
+    assert!(
+        message.contains("2 of 4 calls"),
+        "unexpected error: {}",
+        message
+    );
+    assert!(message.contains("[1]"), "unexpected error: {}", message);
+    assert!(message.contains("[3]"), "unexpected error: {}", message);
+}
+
+#[test]
+fn test_pmap_call_empty_inputs() {
+    let result = evaluate_with_slow_host("(pmap-call :test.double [])", Duration::ZERO).unwrap();
+    assert_eq!(result, Value::Vector(vec![].into()));
+}
+
+#[test]
+fn test_pmap_call_caps_requested_concurrency() {
+    let latency = Duration::from_millis(100);
+    let count = 2 * MAX_PMAP_CONCURRENCY as i64;
+    let inputs: Vec<String> = (1..=count).map(|n| n.to_string()).collect();
+    let source = format!("(pmap-call :test.double [{}] 1000)", inputs.join(" "));
+    let start = Instant::now();
+    let result = evaluate_with_slow_host(&source, latency).unwrap();
+    let elapsed = start.elapsed();
+
+    assert_eq!(
+        result,
+        Value::Vector((1..=count).map(|n| Value::Integer(n * 2)).collect())
+    );
+    // Capped workers need two rounds for twice the cap in inputs
+    assert!(elapsed >= latency * 2, "finished too fast: {:?}", elapsed);
+}
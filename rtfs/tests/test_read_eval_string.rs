@@ -0,0 +1,132 @@
+use rtfs::ast::{Keyword, Symbol};
+use rtfs::parser::parse_expression;
+use rtfs::runtime::error::RuntimeError;
+use rtfs::runtime::evaluator::Evaluator;
+use rtfs::runtime::execution_outcome::ExecutionOutcome;
+use rtfs::runtime::module_runtime::ModuleRegistry;
+use rtfs::runtime::pure_host::create_pure_host;
+use rtfs::runtime::security::RuntimeContext;
+use rtfs::runtime::stdlib::EVAL_STRING_CAPABILITY;
+use rtfs::runtime::values::Value;
+use std::sync::Arc;
+
+fn evaluate_in(source: &str, context: RuntimeContext) -> Result<Value, RuntimeError> {
+    let evaluator = Evaluator::new(
+        Arc::new(ModuleRegistry::new()),
+        context,
+        create_pure_host(),
+        rtfs::compiler::expander::MacroExpander::default(),
+    );
+    let expr = parse_expression(source).expect("Should parse successfully");
+    match evaluator.evaluate(&expr)? {
+        ExecutionOutcome::Complete(v) => Ok(v),
+        other => panic!("unexpected outcome for {}: {:?}", source, other),
+    }
+}
+
+fn sym(name: &str) -> Value {
+    Value::Symbol(Symbol(name.into()))
+}
+
+fn eval_allowed() -> RuntimeContext {
+    RuntimeContext::controlled(vec![EVAL_STRING_CAPABILITY.to_string()])
+}
+
+#[test]
+fn test_read_string_returns_quoted_form() {
+    assert_eq!(
+        evaluate_in(r#"(read-string "(+ 1 2)")"#, RuntimeContext::pure()),
+        Ok(Value::List(
+            vec![sym("+"), Value::Integer(1), Value::Integer(2)].into()
+        ))
+    );
+    assert_eq!(
+        evaluate_in(
+            r#"(read-string "(let [x 1] (if x [x :k] nil))")"#,
+            RuntimeContext::pure()
+        ),
+        Ok(Value::List(
+            vec![
+                sym("let"),
+                Value::Vector(vec![sym("x"), Value::Integer(1)].into()),
+                Value::List(
+                    vec![
+                        sym("if"),
+                        sym("x"),
+                        Value::Vector(vec![sym("x"), Value::Keyword(Keyword("k".into()))].into()),
+                        Value::Nil,
+                    ]
+                    .into()
+                ),
+            ]
+            .into()
+        ))
+    );
+    // Reading never evaluates
+    assert_eq!(
+        evaluate_in(r#"(read-string "(/ 1 0)")"#, RuntimeContext::pure()),
+        Ok(Value::List(
+            vec![sym("/"), Value::Integer(1), Value::Integer(0)].into()
+        ))
+    );
+    assert!(evaluate_in(r#"(read-string "(+ 1")"#, RuntimeContext::pure()).is_err());
+}
+
+#[test]
+fn test_eval_string_requires_capability() {
+    match evaluate_in(r#"(eval-string "(+ 1 2)")"#, RuntimeContext::pure()) {
+        Err(RuntimeError::SecurityViolation { capability, .. }) => {
+            assert_eq!(capability, EVAL_STRING_CAPABILITY)
+        }
+        other => panic!("expected a security violation, got {:?}", other),
+    }
+    match evaluate_in(
+        r#"(eval-string "(+ 1 2)")"#,
+        RuntimeContext::controlled(vec![]),
+    ) {
+        Err(RuntimeError::SecurityViolation { .. }) => {}
+        other => panic!("expected a security violation, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_eval_string_evaluates_when_permitted() {
+    assert_eq!(
+        evaluate_in(r#"(eval-string "(+ 1 2)")"#, eval_allowed()),
+        Ok(Value::Integer(3))
+    );
+    assert_eq!(
+        evaluate_in(r#"(eval-string "(+ 1 2)")"#, RuntimeContext::full()),
+        Ok(Value::Integer(3))
+    );
+    // Evaluated code sees the caller's bindings...
+    assert_eq!(
+        evaluate_in(r#"(let [x 40] (eval-string "(+ x 2)"))"#, eval_allowed()),
+        Ok(Value::Integer(42))
+    );
+    // ...but its definitions stay local to the evaluation
+    assert!(matches!(
+        evaluate_in(r#"(do (eval-string "(def y 5)") y)"#, eval_allowed()),
+        Err(RuntimeError::UndefinedSymbol { .. })
+    ));
+}
+
+#[test]
+fn test_gensym_returns_fresh_symbols() {
+    let first = evaluate_in("(gensym)", RuntimeContext::pure()).unwrap();
+    let second = evaluate_in("(gensym)", RuntimeContext::pure()).unwrap();
+    assert!(matches!(&first, Value::Symbol(s) if s.0.starts_with("G__")));
+    assert_ne!(first, second);
+
+    // Calls within one evaluation are distinct too
+    assert_eq!(
+        evaluate_in("(= (gensym) (gensym))", RuntimeContext::pure()),
+        Ok(Value::Boolean(false))
+    );
+
+    match evaluate_in(r#"(gensym "tmp")"#, RuntimeContext::pure()) {
+        Ok(Value::Symbol(s)) => assert!(s.0.starts_with("tmp__"), "{}", s.0),
+        other => panic!("expected a symbol, got {:?}", other),
+    }
+    assert!(evaluate_in("(gensym 1)", RuntimeContext::pure()).is_err());
+}
@@ -0,0 +1,375 @@
+// Evaluator special forms, checked against both the AST evaluator and the IR runtime
+
+use rtfs::parser;
+use rtfs::runtime::error::RuntimeError;
+use rtfs::runtime::evaluator::Evaluator;
+use rtfs::runtime::evaluator::MAX_LOOP_ITERATIONS;
+use rtfs::runtime::execution_outcome::ExecutionOutcome;
+use rtfs::runtime::mock_host::MockHost;
+use rtfs::runtime::module_runtime::ModuleRegistry;
+use rtfs::runtime::pure_host::create_pure_host;
+use rtfs::runtime::security::RuntimeContext;
+use rtfs::runtime::values::Value;
+use rtfs::RuntimeStrategy;
+use std::sync::Arc;
+
+fn eval_ast_and_ir(code: &str) -> (Result<Value, String>, Result<Value, String>) {
+    let parsed = parser::parse_expression(code).expect("Should parse successfully");
+
+    let evaluator = Evaluator::new(
+        Arc::new(ModuleRegistry::new()),
+        RuntimeContext::pure(),
+        create_pure_host(),
+        rtfs::compiler::expander::MacroExpander::default(),
+    );
+    let ast_res = match evaluator.evaluate(&parsed) {
+        Ok(ExecutionOutcome::Complete(v)) => Ok(v),
+        Ok(other) => Err(format!("AST did not complete: {:?}", other)),
+        Err(e) => Err(format!("AST evaluation error: {}", e)),
+    };
+
+    let mut ir_strategy =
+        rtfs::runtime::ir_runtime::IrStrategy::new(Arc::new(ModuleRegistry::new()));
+    let ir_res = match ir_strategy.run(&parsed) {
+        Ok(ExecutionOutcome::Complete(v)) => Ok(v),
+        Ok(other) => Err(format!("IR did not complete: {:?}", other)),
+        Err(e) => Err(format!("IR runtime error: {}", e)),
+    };
+
+    (ast_res, ir_res)
+}
+
+fn assert_both(code: &str, expected: Value) {
+    let (ast_res, ir_res) = eval_ast_and_ir(code);
+    assert_eq!(ast_res, Ok(expected.clone()), "AST result for {}", code);
+    assert_eq!(ir_res, Ok(expected), "IR result for {}", code);
+}
+
+#[test]
+fn test_when_and_when_not() {
+    // Taken branch evaluates every body form and returns the last one
+    assert_both("(when true 1 2 3)", Value::Integer(3));
+    assert_both("(when-not false 1 2 3)", Value::Integer(3));
+    assert_both(
+        "(let [x 5] (when (> x 3) (+ x 1) (* x 2)))",
+        Value::Integer(10),
+    );
+
+    // Skipped branch returns nil without evaluating the body
+    assert_both("(when false (/ 1 0))", Value::Nil);
+    assert_both("(when nil (/ 1 0))", Value::Nil);
+    assert_both("(when-not true (/ 1 0))", Value::Nil);
+    assert_both("(when-not 0 (/ 1 0))", Value::Nil);
+
+    // An empty body is nil either way
+    assert_both("(when true)", Value::Nil);
+    assert_both("(when-not false)", Value::Nil);
+
+    let (ast_res, ir_res) = eval_ast_and_ir("(when)");
+    assert!(ast_res.is_err(), "AST should reject a missing test");
+    assert!(ir_res.is_err(), "IR should reject a missing test");
+}
+
+#[test]
+fn test_and_or_short_circuit() {
+    // Evaluation stops at the deciding argument, so later ones never run
+    assert_both("(and false (/ 1 0))", Value::Boolean(false));
+    assert_both("(and nil (/ 1 0))", Value::Nil);
+    assert_both("(or true (/ 1 0))", Value::Boolean(true));
+    assert_both("(or 1 (/ 1 0))", Value::Integer(1));
+    assert_both("(let [x nil] (and x (get x :a)))", Value::Nil);
+
+    let host = Arc::new(MockHost::new().with_response("risky", Value::Boolean(true)));
+    assert_eq!(
+        eval_with_host("(and false (call :risky))", host.clone()),
+        Ok(Value::Boolean(false))
+    );
+    assert_eq!(
+        eval_with_host("(or :done (call :risky))", host.clone()),
+        Ok(Value::Keyword(rtfs::ast::Keyword("done".into())))
+    );
+    assert!(host.calls().is_empty());
+
+    // When the earlier arguments don't decide, the later side effect happens
+    assert_eq!(
+        eval_with_host("(and true (call :risky))", host.clone()),
+        Ok(Value::Boolean(true))
+    );
+    assert_eq!(host.calls_to("risky").len(), 1);
+}
+
+#[test]
+fn test_and_or_return_the_deciding_value() {
+    let kw = |name: &str| Value::Keyword(rtfs::ast::Keyword(name.into()));
+
+    assert_both("(and 1 2 3)", Value::Integer(3));
+    assert_both("(and 1 nil 3)", Value::Nil);
+    assert_both("(and 1 false 3)", Value::Boolean(false));
+    assert_both("(or nil :default)", kw("default"));
+    assert_both("(or false nil)", Value::Nil);
+    assert_both(
+        "(let [opts {}] (or (get opts :limit) 10))",
+        Value::Integer(10),
+    );
+    assert_both("(and)", Value::Boolean(true));
+    assert_both("(or)", Value::Boolean(false));
+
+    // The function values behave the same when passed to higher-order fns
+    assert_both("(reduce or [nil false :x])", kw("x"));
+    assert_both("(reduce and [1 2 3])", Value::Integer(3));
+}
+
+#[test]
+fn test_threading_forms() {
+    // Thread-first through map operations
+    assert_both(
+        "(-> {:a 1} (assoc :b 2) (assoc :c 3) (get :b))",
+        Value::Integer(2),
+    );
+    // Thread-last through sequence operations
+    assert_both(
+        "(->> [1 2 3 4 5] (filter even?) (map inc))",
+        Value::Vector(vec![Value::Integer(3), Value::Integer(5)].into()),
+    );
+    // Bare symbols and keywords are single-argument calls
+    assert_both("(-> 5 inc inc)", Value::Integer(7));
+    assert_both("(->> [3 1 2] sort first)", Value::Integer(1));
+    assert_both("(-> {:a {:b 7}} :a :b)", Value::Integer(7));
+    // The two forms differ only in where the value is inserted
+    assert_both("(-> 10 (- 3))", Value::Integer(7));
+    assert_both("(->> 10 (- 3))", Value::Integer(-7));
+    // With no steps the initial value is returned unchanged
+    assert_both("(-> 42)", Value::Integer(42));
+
+    let (ast_res, ir_res) = eval_ast_and_ir("(->)");
+    assert!(ast_res.is_err(), "AST should reject a missing initial value");
+    assert!(ir_res.is_err(), "IR should reject a missing initial value");
+}
+
+#[test]
+fn test_let_binding_semantics() {
+    // Bindings are sequential: each one sees the ones before it
+    assert_both(
+        "(let [x 1 y (+ x 1) z (* y 10)] (+ x y z))",
+        Value::Integer(23),
+    );
+    // A later binding may shadow an earlier one in the same let
+    assert_both("(let [x 1 x (+ x 1)] x)", Value::Integer(2));
+    // Inner lets shadow outer symbols without affecting them
+    assert_both("(let [x 1] (+ (* 10 (let [x 2] x)) x))", Value::Integer(21));
+    assert_both("(let [inc (fn [n] (+ n 10))] (inc 1))", Value::Integer(11));
+    assert_both(
+        "(do (let [inc (fn [n] (+ n 10))] (inc 1)) (inc 1))",
+        Value::Integer(2),
+    );
+    assert_both("(let [x 1 f (fn [] (+ x 1))] (f))", Value::Integer(2));
+    // Function bindings can refer to themselves and to each other
+    assert_both(
+        "(let [fact (fn [n] (if (= n 0) 1 (* n (fact (- n 1)))))] (fact 5))",
+        Value::Integer(120),
+    );
+    assert_both(
+        r#"(let [ev? (fn [n] (if (= n 0) true (od? (- n 1))))
+                 od? (fn [n] (if (= n 0) false (ev? (- n 1))))]
+             [(ev? 4) (od? 3)])"#,
+        Value::Vector(vec![Value::Boolean(true), Value::Boolean(true)].into()),
+    );
+    // Mixed: a value binding after mutually recursive functions
+    assert_both(
+        r#"(let [ev? (fn [n] (if (= n 0) true (od? (- n 1))))
+                 od? (fn [n] (if (= n 0) false (ev? (- n 1))))
+                 r (ev? 4)]
+             r)"#,
+        Value::Boolean(true),
+    );
+}
+
+fn eval_with_host(code: &str, host: Arc<MockHost>) -> Result<Value, RuntimeError> {
+    let evaluator = Evaluator::new_with_defaults(
+        Arc::new(ModuleRegistry::new()),
+        host,
+        rtfs::compiler::expander::MacroExpander::default(),
+    );
+    let parsed = parser::parse_expression(code).expect("Should parse successfully");
+    match evaluator.evaluate(&parsed)? {
+        ExecutionOutcome::Complete(v) => Ok(v),
+        other => panic!("unexpected outcome for {}: {:?}", code, other),
+    }
+}
+
+#[test]
+fn test_dotimes_runs_body_exactly_n_times() {
+    // Each iteration records a host call, which acts as the counter
+    let host = Arc::new(
+        MockHost::new()
+            .with_response("tick", Value::Nil)
+            .with_response("tock", Value::Nil),
+    );
+    let result = eval_with_host("(dotimes [i 3] (call :tick i) (call :tock))", host.clone());
+
+    assert_eq!(result, Ok(Value::Nil));
+    let ticks: Vec<Vec<Value>> = host.calls_to("tick").into_iter().map(|c| c.args).collect();
+    assert_eq!(
+        ticks,
+        vec![
+            vec![Value::Integer(0)],
+            vec![Value::Integer(1)],
+            vec![Value::Integer(2)]
+        ]
+    );
+    assert_eq!(host.calls_to("tock").len(), 3);
+
+    // Zero and negative counts never run the body
+    let host = Arc::new(MockHost::new().with_response("tick", Value::Nil));
+    assert_eq!(
+        eval_with_host("(dotimes [i 0] (call :tick))", host.clone()),
+        Ok(Value::Nil)
+    );
+    assert_eq!(
+        eval_with_host("(dotimes [i -2] (call :tick))", host.clone()),
+        Ok(Value::Nil)
+    );
+    assert!(host.calls().is_empty());
+
+    // Counts beyond the loop budget fail before running anything
+    let code = format!("(dotimes [i {}] (call :tick))", MAX_LOOP_ITERATIONS + 1);
+    match eval_with_host(&code, host.clone()) {
+        Err(RuntimeError::ResourceError { message, .. }) => {
+            assert!(message.contains("exceeds the limit"), "{}", message)
+        }
+        other => panic!("expected a resource error, got {:?}", other),
+    }
+    assert!(host.calls().is_empty());
+}
+
+#[test]
+fn test_quasiquote_fills_holes() {
+    let eval = |code: &str| eval_with_host(code, Arc::new(MockHost::new()));
+    let list = |items: Vec<Value>| Value::List(items.into());
+    let sym = |name: &str| Value::Symbol(rtfs::ast::Symbol(name.into()));
+
+    assert_eq!(
+        eval("`(1 ~(+ 1 1) 3)"),
+        Ok(list(vec![
+            Value::Integer(1),
+            Value::Integer(2),
+            Value::Integer(3)
+        ]))
+    );
+    // Splicing expands a vector (or list) inline; nil splices nothing
+    assert_eq!(
+        eval("(let [xs [2 3]] `(1 ~@xs 4 ~@nil))"),
+        Ok(list(vec![
+            Value::Integer(1),
+            Value::Integer(2),
+            Value::Integer(3),
+            Value::Integer(4)
+        ]))
+    );
+    // Symbols stay quoted and holes work inside vectors and maps
+    assert_eq!(
+        eval("(let [x 5] `(+ x ~x [~x ~@[6 7]]))"),
+        Ok(list(vec![
+            sym("+"),
+            sym("x"),
+            Value::Integer(5),
+            Value::Vector(vec![Value::Integer(5), Value::Integer(6), Value::Integer(7)].into()),
+        ]))
+    );
+    assert_eq!(eval("(get `{:a ~(* 2 3)} :a)"), Ok(Value::Integer(6)));
+    // Holes of a nested quasiquote are left as data
+    assert_eq!(
+        eval("`(a `(b ~c))"),
+        Ok(list(vec![
+            sym("a"),
+            list(vec![
+                sym("quasiquote"),
+                list(vec![sym("b"), list(vec![sym("unquote"), sym("c")])]),
+            ]),
+        ]))
+    );
+
+    assert!(eval("`(1 ~@5)").is_err());
+    assert!(eval("~x").is_err());
+}
+
+#[test]
+fn test_try_catch_binds_thrown_error() {
+    let kw = |name: &str| Value::Keyword(rtfs::ast::Keyword(name.into()));
+    assert_both(
+        "(try (throw :app/invalid \"bad input\") (catch e (getMessage e)))",
+        Value::String("bad input".to_string()),
+    );
+    assert_both(
+        "(try (throw :app/invalid \"bad input\") (catch e (error-type e)))",
+        kw("app/invalid"),
+    );
+    assert_both(
+        "(try (throw \"plain\") (catch e [(getMessage e) (error-type e)]))",
+        Value::Vector(vec![Value::String("plain".to_string()), kw("error")].into()),
+    );
+    // Runtime errors expose their kind too
+    assert_both(
+        "(try (/ 1 0) (catch e (error-type e)))",
+        kw("DivisionByZero"),
+    );
+    // Rethrowing a caught error keeps its type
+    assert_both(
+        "(try (try (throw :inner \"x\") (catch e (throw e))) (catch e (error-type e)))",
+        kw("inner"),
+    );
+}
+
+#[test]
+fn test_finally_runs_on_success_and_failure() {
+    let host = Arc::new(MockHost::new().with_response("cleanup", Value::Nil));
+    assert_eq!(
+        eval_with_host("(try 1 (finally (call :cleanup)))", host.clone()),
+        Ok(Value::Integer(1))
+    );
+    assert_eq!(host.calls_to("cleanup").len(), 1);
+
+    assert_eq!(
+        eval_with_host(
+            "(try (throw \"boom\") (catch e (getMessage e)) (finally (call :cleanup)))",
+            host.clone()
+        ),
+        Ok(Value::String("boom".to_string()))
+    );
+    assert_eq!(host.calls_to("cleanup").len(), 2);
+
+    // Uncaught errors still propagate after finally runs
+    let result = eval_with_host(
+        "(try (throw :fatal \"boom\") (finally (call :cleanup)))",
+        host.clone(),
+    );
+    assert!(matches!(result, Err(e) if e.error_type() == rtfs::ast::Keyword("fatal".into())));
+    assert_eq!(host.calls_to("cleanup").len(), 3);
+}
+
+#[test]
+fn test_ex_info_carries_data_through_throw() {
+    assert_both(
+        "(try (throw (ex-info \"rejected\" {:code 42})) (catch e (get (ex-data e) :code)))",
+        Value::Integer(42),
+    );
+    assert_both(
+        "(try (throw (ex-info \"rejected\" {:code 42})) (catch e (getMessage e)))",
+        Value::String("rejected".to_string()),
+    );
+    // Rethrowing keeps the payload
+    assert_both(
+        "(try (try (throw (ex-info \"x\" {:step 1})) (catch e (throw e))) (catch e (ex-data e)))",
+        Value::Map(
+            vec![(
+                rtfs::ast::MapKey::Keyword(rtfs::ast::Keyword("step".into())),
+                Value::Integer(1),
+            )]
+            .into_iter()
+            .collect(),
+        ),
+    );
+    // Errors without a payload have no data
+    assert_both("(try (/ 1 0) (catch e (ex-data e)))", Value::Nil);
+    assert_both("(try (throw \"plain\") (catch e (ex-data e)))", Value::Nil);
+}
@@ -0,0 +1,140 @@
+// Supervised tasks are restarted per policy and escalated once the budget is spent
+
+use rtfs::runtime::error::{RuntimeError, RuntimeResult};
+use rtfs::runtime::host_interface::HostInterface;
+use rtfs::runtime::stubs::ExecutionResultStruct;
+use rtfs::runtime::values::Value;
+use rtfs::supervisor::{RestartPolicy, Supervisor, TaskStatus};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Host that records step notifications the way the Causal Chain would
+#[derive(Debug, Default)]
+struct ChainHost {
+    events: Mutex<Vec<String>>,
+}
+
+impl ChainHost {
+    fn events(&self) -> Vec<String> {
+        self.events.lock().unwrap().clone()
+    }
+}
+
+impl HostInterface for ChainHost {
+    fn execute_capability(&self, name: &str, _args: &[Value]) -> RuntimeResult<Value> {
+        Err(RuntimeError::CapabilityNotFound {
+            capability_id: name.to_string(),
+        })
+    }
+    fn notify_step_started(&self, step_name: &str) -> RuntimeResult<String> {
+        let mut events = self.events.lock().unwrap();
+        events.push(format!("started {}", step_name));
+        Ok(format!("action-{}", events.len()))
+    }
+    fn notify_step_completed(
+        &self,
+        step_action_id: &str,
+        _result: &ExecutionResultStruct,
+    ) -> RuntimeResult<()> {
+        self.events
+            .lock()
+            .unwrap()
+            .push(format!("completed {}", step_action_id));
+        Ok(())
+    }
+    fn notify_step_failed(&self, step_action_id: &str, error: &str) -> RuntimeResult<()> {
+        self.events
+            .lock()
+            .unwrap()
+            .push(format!("failed {}: {}", step_action_id, error));
+        Ok(())
+    }
+    fn set_execution_context(
+        &self,
+        _plan_id: String,
+        _intent_ids: Vec<String>,
+        _parent_action_id: String,
+    ) {
+    }
+    fn clear_execution_context(&self) {}
+    fn set_step_exposure_override(&self, _expose: bool, _context_keys: Option<Vec<String>>) {}
+    fn clear_step_exposure_override(&self) {}
+    fn get_context_value(&self, _key: &str) -> Option<Value> {
+        None
+    }
+}
+
+#[test]
+fn test_task_is_restarted_then_permanently_failed() {
+    let host = Arc::new(ChainHost::default());
+    let mut supervisor = Supervisor::new(
+        RestartPolicy::one_for_one(2, Duration::from_secs(60)),
+        host.clone(),
+    );
+
+    let mut attempts = 0;
+    let result = supervisor.run("flaky-capability", || {
+        attempts += 1;
+        if attempts == 2 {
+            panic!("capability crashed");
+        }
+        Err(RuntimeError::Generic(format!(
+            "attempt {} failed",
+            attempts
+        )))
+    });
+
+    assert_eq!(attempts, 3);
+    assert_eq!(supervisor.restart_count("flaky-capability"), 2);
+    assert!(matches!(
+        supervisor.status("flaky-capability"),
+        Some(TaskStatus::PermanentlyFailed { error }) if error.contains("attempt 3 failed")
+    ));
+    let error = result.unwrap_err().to_string();
+    assert!(error.contains("permanently failed"), "{}", error);
+
+    let events = host.events();
+    assert_eq!(events.len(), 6);
+    assert!(events[1].contains("attempt 1 failed (restart 1 of 2)"));
+    assert!(events[3].contains("task panicked: capability crashed (restart 2 of 2)"));
+    assert!(events[5].starts_with("failed action-5: permanently failed after 2 restart(s)"));
+
+    // An escalated task is not run again
+    let mut reran = false;
+    assert!(supervisor
+        .run("flaky-capability", || {
+            reran = true;
+            Ok(Value::Nil)
+        })
+        .is_err());
+    assert!(!reran);
+}
+
+#[test]
+fn test_restart_budget_is_per_task() {
+    let host = Arc::new(ChainHost::default());
+    let mut supervisor = Supervisor::new(
+        RestartPolicy::one_for_one(1, Duration::from_secs(60)),
+        host.clone(),
+    );
+
+    let mut failed_once = false;
+    let value = supervisor
+        .run("recovers", || {
+            if !failed_once {
+                failed_once = true;
+                return Err(RuntimeError::Generic("transient".to_string()));
+            }
+            Ok(Value::Integer(42))
+        })
+        .unwrap();
+    assert_eq!(value, Value::Integer(42));
+    assert_eq!(supervisor.status("recovers"), Some(&TaskStatus::Completed));
+
+    // A sibling's restarts do not consume this task's budget
+    assert_eq!(
+        supervisor.run("other", || Ok(Value::Boolean(true))),
+        Ok(Value::Boolean(true))
+    );
+    assert_eq!(supervisor.restart_count("other"), 0);
+}
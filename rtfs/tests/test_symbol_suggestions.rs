@@ -0,0 +1,75 @@
+// Undefined symbols suggest the closest known name
+
+use rtfs::ast::Symbol;
+use rtfs::parser::parse_expression;
+use rtfs::runtime::error::RuntimeError;
+use rtfs::runtime::evaluator::Evaluator;
+use rtfs::runtime::ir_runtime::IrStrategy;
+use rtfs::runtime::module_runtime::ModuleRegistry;
+use rtfs::runtime::pure_host::create_pure_host;
+use rtfs::runtime::security::RuntimeContext;
+use rtfs::RuntimeStrategy;
+use std::sync::Arc;
+
+fn ast_error(source: &str) -> RuntimeError {
+    let evaluator = Evaluator::new(
+        Arc::new(ModuleRegistry::new()),
+        RuntimeContext::pure(),
+        create_pure_host(),
+        rtfs::compiler::expander::MacroExpander::default(),
+    );
+    let expr = parse_expression(source).expect("Should parse successfully");
+    match evaluator.evaluate(&expr) {
+        Err(e) => e,
+        Ok(outcome) => panic!("expected an error, got {:?}", outcome),
+    }
+}
+
+fn ir_error(source: &str) -> RuntimeError {
+    let expr = parse_expression(source).expect("Should parse successfully");
+    let mut strategy = IrStrategy::new(Arc::new(ModuleRegistry::new()));
+    match strategy.run(&expr) {
+        Err(e) => e,
+        Ok(outcome) => panic!("expected an error, got {:?}", outcome),
+    }
+}
+
+fn suggestion(error: &RuntimeError) -> Option<&str> {
+    match error.without_location() {
+        RuntimeError::UndefinedSymbol { suggestion, .. } => suggestion.as_deref(),
+        other => panic!("expected an undefined symbol, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_typo_suggests_closest_builtin() {
+    let source = "(fliter even? [1 2 3 4])";
+
+    let error = ast_error(source);
+    assert_eq!(suggestion(&error), Some("filter"));
+    assert_eq!(
+        error.to_string(),
+        "Undefined symbol: fliter (did you mean `filter`?)"
+    );
+
+    assert_eq!(suggestion(&ir_error(source)), Some("filter"));
+}
+
+#[test]
+fn test_local_bindings_are_suggested() {
+    let error = ast_error("(let [total 10] (+ totl 1))");
+    assert_eq!(suggestion(&error), Some("total"));
+}
+
+#[test]
+fn test_no_suggestion_for_unrelated_names() {
+    let error = ast_error("(quux-zzyzx-unknown 1)");
+    assert_eq!(suggestion(&error), None);
+    assert_eq!(
+        error.without_location(),
+        &RuntimeError::UndefinedSymbol {
+            symbol: Symbol("quux-zzyzx-unknown".into()),
+            suggestion: None,
+        }
+    );
+}
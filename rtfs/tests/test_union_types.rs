@@ -3,8 +3,7 @@
 
 use rtfs::ast::{Keyword, MapKey, MapTypeEntry, PrimitiveType, TypeExpr};
 use rtfs::runtime::type_validator::{TypeValidator, ValidationError};
-use rtfs::runtime::values::Value;
-use std::collections::HashMap;
+use rtfs::runtime::values::{Value, ValueMap};
 
 #[test]
 fn test_union_type_validation_success() {
@@ -71,13 +70,13 @@ fn test_union_type_with_complex_types() {
     // Should accept vector of ints
     assert!(validator
         .validate_value(
-            &Value::Vector(vec![Value::Integer(1), Value::Integer(2)]),
+            &Value::Vector(vec![Value::Integer(1), Value::Integer(2)].into()),
             &complex_union
         )
         .is_ok());
 
     // Should accept map with x key
-    let mut test_map: HashMap<MapKey, Value> = HashMap::new();
+    let mut test_map: ValueMap = ValueMap::new();
     test_map.insert(MapKey::Keyword(Keyword::new("x")), Value::Integer(42));
     assert!(validator
         .validate_value(&Value::Map(test_map), &complex_union)
@@ -86,16 +85,19 @@ fn test_union_type_with_complex_types() {
     // Should reject vector of strings
     assert!(validator
         .validate_value(
-            &Value::Vector(vec![
-                Value::String("a".to_string()),
-                Value::String("b".to_string())
-            ]),
+            &Value::Vector(
+                vec![
+                    Value::String("a".to_string()),
+                    Value::String("b".to_string())
+                ]
+                .into()
+            ),
             &complex_union
         )
         .is_err());
 
     // Should reject map without x key
-    let mut bad_map: HashMap<MapKey, Value> = HashMap::new();
+    let mut bad_map: ValueMap = ValueMap::new();
     bad_map.insert(MapKey::Keyword(Keyword::new("y")), Value::Integer(42));
     assert!(validator
         .validate_value(&Value::Map(bad_map), &complex_union)
@@ -150,14 +152,14 @@ fn test_union_type_nested_in_map() {
     let validator = TypeValidator::new();
 
     // Should accept map with integer value
-    let mut map_with_int: HashMap<MapKey, Value> = HashMap::new();
+    let mut map_with_int: ValueMap = ValueMap::new();
     map_with_int.insert(MapKey::Keyword(Keyword::new("value")), Value::Integer(42));
     assert!(validator
         .validate_value(&Value::Map(map_with_int), &map_with_union)
         .is_ok());
 
     // Should accept map with string value
-    let mut map_with_string: HashMap<MapKey, Value> = HashMap::new();
+    let mut map_with_string: ValueMap = ValueMap::new();
     map_with_string.insert(
         MapKey::Keyword(Keyword::new("value")),
         Value::String("test".to_string()),
@@ -167,7 +169,7 @@ fn test_union_type_nested_in_map() {
         .is_ok());
 
     // Should reject map with boolean value
-    let mut map_with_bool: HashMap<MapKey, Value> = HashMap::new();
+    let mut map_with_bool: ValueMap = ValueMap::new();
     map_with_bool.insert(MapKey::Keyword(Keyword::new("value")), Value::Boolean(true));
     assert!(validator
         .validate_value(&Value::Map(map_with_bool), &map_with_union)
@@ -0,0 +1,132 @@
+use rtfs::ast::{Keyword, MapKey, Symbol};
+use rtfs::runtime::values::{interned_count, InternedStr, Value, INTERN_MAX_LEN};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::collections::HashMap;
+
+/// Counts allocations made by the current thread so parallel tests don't
+/// interfere with each other.
+struct CountingAlloc;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|c| c.set(c.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+fn allocations_during<T>(f: impl FnOnce() -> T) -> (T, usize) {
+    let before = ALLOCATIONS.with(|c| c.get());
+    let out = f();
+    let after = ALLOCATIONS.with(|c| c.get());
+    (out, after - before)
+}
+
+fn keyword_heavy_records(count: usize) -> Value {
+    let fields = ["id", "status", "owner", "priority", "created-at"];
+    let records = (0..count)
+        .map(|i| {
+            let mut m = HashMap::new();
+            for field in fields {
+                m.insert(
+                    MapKey::Keyword(Keyword::new(field)),
+                    Value::Keyword(Keyword::new(if i % 2 == 0 { "open" } else { "closed" })),
+                );
+            }
+            Value::Map(m.into())
+        })
+        .collect();
+    Value::Vector(records)
+}
+
+#[test]
+fn test_same_keyword_shares_storage() {
+    let a = Keyword::new("interning-test/shared");
+    let b = Keyword::new("interning-test/shared");
+    assert_eq!(a, b);
+    assert!(a.0.ptr_eq(&b.0));
+
+    let s1 = Symbol::new("interning-test/sym");
+    let s2 = Symbol::new(&String::from("interning-test/sym"));
+    assert!(s1.0.ptr_eq(&s2.0));
+}
+
+#[test]
+fn test_long_strings_are_not_pooled() {
+    let long = "x".repeat(INTERN_MAX_LEN + 1);
+    let a = InternedStr::new(&long);
+    let b = InternedStr::new(&long);
+    assert_eq!(a, b);
+    assert!(!a.ptr_eq(&b));
+}
+
+#[test]
+fn test_unreferenced_strings_are_swept_from_the_pool() {
+    let kept = Keyword::new("interning-test/kept");
+    // Stands in for keywords built from untrusted input such as JSON keys
+    for i in 0..20_000 {
+        let _ = Keyword::new(&format!("interning-test/untrusted-{}", i));
+    }
+    assert!(
+        interned_count() < 10_000,
+        "pool kept {} strings",
+        interned_count()
+    );
+
+    let again = Keyword::new("interning-test/kept");
+    assert!(kept.0.ptr_eq(&again.0));
+}
+
+#[test]
+fn test_cloning_keywords_does_not_allocate_strings() {
+    let keywords: Vec<Value> = (0..1000)
+        .map(|_| Value::Keyword(Keyword::new("k")))
+        .collect();
+    let strings: Vec<Value> = (0..1000).map(|_| Value::String("k".to_string())).collect();
+
+    let (_kw_clone, kw_allocs) = allocations_during(|| keywords.clone());
+    let (_str_clone, str_allocs) = allocations_during(|| strings.clone());
+
+    // Only the vector buffer itself is allocated; each keyword is a refcount bump.
+    assert_eq!(kw_allocs, 1);
+    assert!(
+        str_allocs >= 1000,
+        "expected per-element allocations, got {}",
+        str_allocs
+    );
+}
+
+#[test]
+fn test_copying_keyword_heavy_maps_only_allocates_containers() {
+    let Value::Vector(records) = keyword_heavy_records(200) else {
+        unreachable!()
+    };
+    let (entries, allocs) = allocations_during(|| {
+        records
+            .iter()
+            .map(|record| match record {
+                Value::Map(m) => m
+                    .iter()
+                    .map(|(k, v)| (k.clone(), v.clone()))
+                    .collect::<Vec<_>>(),
+                _ => Vec::new(),
+            })
+            .collect::<Vec<_>>()
+    });
+    assert_eq!(entries.len(), 200);
+
+    // The outer vector, plus per record its entry vector and the map iterator's
+    // traversal stack; none for the 2000 keyword keys and values.
+    assert!(allocs <= 1 + 2 * 200, "copy allocated {} times", allocs);
+}
@@ -92,22 +92,22 @@ fn test_vector_types() {
     let int_vector_type = TypeExpr::Vector(Box::new(TypeExpr::Primitive(PrimitiveType::Int)));
 
     // Valid vector of integers
-    let valid_vector = Value::Vector(vec![
-        Value::Integer(1),
-        Value::Integer(2),
-        Value::Integer(3),
-    ]);
+    let valid_vector =
+        Value::Vector(vec![Value::Integer(1), Value::Integer(2), Value::Integer(3)].into());
 
     assert!(validator
         .validate_value(&valid_vector, &int_vector_type)
         .is_ok());
 
     // Invalid vector with mixed types
-    let invalid_vector = Value::Vector(vec![
-        Value::Integer(1),
-        Value::String("hello".to_string()),
-        Value::Integer(3),
-    ]);
+    let invalid_vector = Value::Vector(
+        vec![
+            Value::Integer(1),
+            Value::String("hello".to_string()),
+            Value::Integer(3),
+        ]
+        .into(),
+    );
 
     assert!(validator
         .validate_value(&invalid_vector, &int_vector_type)
@@ -125,18 +125,15 @@ fn test_array_shapes() {
     };
 
     // Valid array with correct size
-    let valid_array = Value::Vector(vec![
-        Value::Integer(1),
-        Value::Integer(2),
-        Value::Integer(3),
-    ]);
+    let valid_array =
+        Value::Vector(vec![Value::Integer(1), Value::Integer(2), Value::Integer(3)].into());
 
     assert!(validator
         .validate_value(&valid_array, &fixed_array_type)
         .is_ok());
 
     // Invalid array with wrong size
-    let invalid_array = Value::Vector(vec![Value::Integer(1), Value::Integer(2)]);
+    let invalid_array = Value::Vector(vec![Value::Integer(1), Value::Integer(2)].into());
 
     assert!(validator
         .validate_value(&invalid_array, &fixed_array_type)
@@ -294,28 +291,34 @@ fn test_tuple_types() {
     ]);
 
     // Valid tuple
-    let valid_tuple = Value::Vector(vec![
-        Value::Integer(42),
-        Value::String("hello".to_string()),
-        Value::Boolean(true),
-    ]);
+    let valid_tuple = Value::Vector(
+        vec![
+            Value::Integer(42),
+            Value::String("hello".to_string()),
+            Value::Boolean(true),
+        ]
+        .into(),
+    );
 
     assert!(validator.validate_value(&valid_tuple, &tuple_type).is_ok());
 
     // Invalid tuple - wrong length
     let wrong_length_tuple =
-        Value::Vector(vec![Value::Integer(42), Value::String("hello".to_string())]);
+        Value::Vector(vec![Value::Integer(42), Value::String("hello".to_string())].into());
 
     assert!(validator
         .validate_value(&wrong_length_tuple, &tuple_type)
         .is_err());
 
     // Invalid tuple - wrong types
-    let wrong_type_tuple = Value::Vector(vec![
-        Value::String("42".to_string()), // should be int
-        Value::String("hello".to_string()),
-        Value::Boolean(true),
-    ]);
+    let wrong_type_tuple = Value::Vector(
+        vec![
+            Value::String("42".to_string()), // should be int
+            Value::String("hello".to_string()),
+            Value::Boolean(true),
+        ]
+        .into(),
+    );
 
     assert!(validator
         .validate_value(&wrong_type_tuple, &tuple_type)
@@ -350,7 +353,7 @@ fn test_map_types() {
         Value::String("Alice".to_string()),
     );
     valid_person.insert(MapKey::Keyword(Keyword::new("age")), Value::Integer(30));
-    let valid_person_value = Value::Map(valid_person);
+    let valid_person_value = Value::Map(valid_person.into());
 
     assert!(validator
         .validate_value(&valid_person_value, &person_map_type)
@@ -363,7 +366,7 @@ fn test_map_types() {
         Value::String("Bob".to_string()),
     );
     // missing age field
-    let invalid_person_value = Value::Map(invalid_person);
+    let invalid_person_value = Value::Map(invalid_person.into());
 
     assert!(validator
         .validate_value(&invalid_person_value, &person_map_type)
@@ -379,7 +382,7 @@ fn test_map_types() {
         MapKey::Keyword(Keyword::new("age")),
         Value::String("30".to_string()),
     ); // should be int
-    let wrong_type_person_value = Value::Map(wrong_type_person);
+    let wrong_type_person_value = Value::Map(wrong_type_person.into());
 
     assert!(validator
         .validate_value(&wrong_type_person_value, &person_map_type)
@@ -453,13 +456,13 @@ fn test_collection_predicates() {
     };
 
     // Valid non-empty vector
-    let non_empty_vector = Value::Vector(vec![Value::String("hello".to_string())]);
+    let non_empty_vector = Value::Vector(vec![Value::String("hello".to_string())].into());
     assert!(validator
         .validate_value(&non_empty_vector, &non_empty_vector_type)
         .is_ok());
 
     // Invalid empty vector
-    let empty_vector = Value::Vector(vec![]);
+    let empty_vector = Value::Vector(vec![].into());
     assert!(validator
         .validate_value(&empty_vector, &non_empty_vector_type)
         .is_err());
@@ -473,17 +476,14 @@ fn test_collection_predicates() {
     };
 
     // Valid vector with enough elements
-    let enough_elements = Value::Vector(vec![
-        Value::Integer(1),
-        Value::Integer(2),
-        Value::Integer(3),
-    ]);
+    let enough_elements =
+        Value::Vector(vec![Value::Integer(1), Value::Integer(2), Value::Integer(3)].into());
     assert!(validator
         .validate_value(&enough_elements, &min_count_type)
         .is_ok());
 
     // Invalid vector with too few elements
-    let too_few_elements = Value::Vector(vec![Value::Integer(1)]);
+    let too_few_elements = Value::Vector(vec![Value::Integer(1)].into());
     assert!(validator
         .validate_value(&too_few_elements, &min_count_type)
         .is_err());
@@ -535,7 +535,8 @@ fn test_complex_nested_types() {
     );
     person2.insert(MapKey::Keyword(Keyword::new("age")), Value::Integer(25));
 
-    let valid_people = Value::Vector(vec![Value::Map(person1), Value::Map(person2)]);
+    let valid_people =
+        Value::Vector(vec![Value::Map(person1.into()), Value::Map(person2.into())].into());
 
     assert!(validator
         .validate_value(&valid_people, &people_array_type)
@@ -549,7 +550,7 @@ fn test_complex_nested_types() {
     ); // empty name
     invalid_person.insert(MapKey::Keyword(Keyword::new("age")), Value::Integer(30));
 
-    let invalid_people = Value::Vector(vec![Value::Map(invalid_person)]);
+    let invalid_people = Value::Vector(vec![Value::Map(invalid_person.into())].into());
 
     assert!(validator
         .validate_value(&invalid_people, &people_array_type)
@@ -563,7 +564,7 @@ fn test_complex_nested_types() {
     );
     negative_age_person.insert(MapKey::Keyword(Keyword::new("age")), Value::Integer(-5)); // negative age
 
-    let negative_age_people = Value::Vector(vec![Value::Map(negative_age_person)]);
+    let negative_age_people = Value::Vector(vec![Value::Map(negative_age_person.into())].into());
 
     assert!(validator
         .validate_value(&negative_age_people, &people_array_type)
@@ -690,7 +691,7 @@ fn test_alias_optional_validation() {
     let validator = TypeValidator::new();
 
     // Alias-style optional (string?) should accept string values
-    let alias_optional = TypeExpr::Alias(Symbol("string?".to_string()));
+    let alias_optional = TypeExpr::Alias(Symbol("string?".into()));
     assert!(validator
         .validate_value(&Value::String("hello".to_string()), &alias_optional)
         .is_ok());
@@ -706,14 +707,14 @@ fn test_alias_optional_validation_capitalized_and_int() {
     let validator = TypeValidator::new();
 
     // Capitalized alias (String?) should behave similarly
-    let alias_string = TypeExpr::Alias(Symbol("String?".to_string()));
+    let alias_string = TypeExpr::Alias(Symbol("String?".into()));
     assert!(validator
         .validate_value(&Value::String("hello".to_string()), &alias_string)
         .is_ok());
     assert!(validator.validate_value(&Value::Nil, &alias_string).is_ok());
 
     // Integer alias optional
-    let alias_int = TypeExpr::Alias(Symbol("Int?".to_string()));
+    let alias_int = TypeExpr::Alias(Symbol("Int?".into()));
     assert!(validator
         .validate_value(&Value::Integer(42), &alias_int)
         .is_ok());
@@ -1,8 +1,9 @@
 //! Library facade for viewer_server exposing testable helpers.
 //!
-//! The snapshot logic is factored into its own module so it can be shared by
-//! both the binary (`main.rs`) and integration tests without compiling the
-//! entire server twice.
+//! The snapshot logic and worker request queue helpers are factored into their
+//! own modules so they can be shared by both the binary (`main.rs`) and
+//! integration tests without compiling the entire server twice.
 
+pub mod request_queue;
 pub mod snapshot;
 pub use snapshot::build_architecture_snapshot;
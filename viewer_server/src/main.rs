@@ -4,17 +4,20 @@ use std::env;
 use std::sync::Arc;
 use tokio::sync::{broadcast, mpsc, oneshot};
 use axum::extract::{Path, State};
+use axum::http::StatusCode;
 use std::path::PathBuf;
 use futures_util::{StreamExt, sink::SinkExt};
 use chrono;
 use uuid::Uuid; // for execution run identifiers
 
 // Local modules (shared with lib facade)
+mod request_queue;
 mod snapshot;
 
 // CCOS runtime types for background arbiter calls
 use rtfs_compiler::ccos::{PlanAutoRepairOptions, CCOS, runtime_service};
 use crate::snapshot::build_architecture_snapshot; // re-exported for binary
+use crate::request_queue::{enqueue, ENQUEUE_TIMEOUT, WORKER_QUEUE_CAPACITY};
 use rtfs_compiler::ccos::arbiter::arbiter_engine::ArbiterEngine;
 
 #[derive(Clone, Debug, serde::Serialize)]
@@ -198,18 +201,18 @@ struct LoadGraphResponse {
 async fn generate_graph_handler(
     State(state): State<Arc<AppState>>,
     Json(payload): Json<GenerateGraphRequest>,
-) -> Json<GenerateGraphResponse> {
+) -> (StatusCode, Json<GenerateGraphResponse>) {
     println!("📨 Received generate-graph request");
     let goal = payload.goal.trim().to_string();
     println!("🎯 Goal: \"{}\"", goal);
     
     if goal.is_empty() {
         println!("❌ Goal is empty, rejecting request");
-        return Json(GenerateGraphResponse {
+        return (StatusCode::OK, Json(GenerateGraphResponse {
             success: false,
             graph: None,
             error: Some("Goal cannot be empty".to_string()),
-        });
+        }));
     }
 
     // Broadcast that graph generation has started
@@ -227,17 +230,17 @@ async fn generate_graph_handler(
     let req = GraphRequest { goal: goal.clone(), resp: resp_tx };
 
     println!("📨 Sending request to worker thread...");
-    if let Err(_e) = state.graph_req_tx.clone().try_send(req) {
-        println!("❌ Failed to send request to worker thread: {:?}", _e);
-        // channel full or closed - fall back to mock response
-        let graph_id = format!("graph_{}", chrono::Utc::now().timestamp());
-        let mock_nodes = vec![
-            serde_json::json!({"id": "root", "label": format!("Goal: {}", goal), "type": "intent", "status": "active"}),
-        ];
-        let mock_edges = vec![];
-        let _ = state.tx.send(ViewerEvent::GraphGenerated { root_id: graph_id.clone(), graph_id: graph_id.clone(), nodes: mock_nodes.clone(), edges: mock_edges.clone() });
-        let _ = state.tx.send(ViewerEvent::StepLog { step: "GraphGeneration".to_string(), status: "completed".to_string(), message: "Graph generation (fallback) completed".to_string(), details: None });
-        return Json(GenerateGraphResponse { success: true, graph: Some(graph_id), error: None });
+    if let Err(e) = enqueue(&state.graph_req_tx, req, ENQUEUE_TIMEOUT).await {
+        println!("❌ Failed to send request to worker thread: {}", e);
+        // Report overload explicitly instead of fabricating a graph
+        let message = e.message("Graph generation");
+        let _ = state.tx.send(ViewerEvent::StepLog {
+            step: "GraphGeneration".to_string(),
+            status: "error".to_string(),
+            message: message.clone(),
+            details: Some(serde_json::json!({ "goal": goal.clone(), "error": e.to_string() })),
+        });
+        return (e.status_code(), Json(GenerateGraphResponse { success: false, graph: None, error: Some(message) }));
     }
     println!("✅ Request successfully sent to worker thread");
 
@@ -259,7 +262,7 @@ async fn generate_graph_handler(
                     "edge_count": edges.len()
                 })),
             });
-            Json(GenerateGraphResponse { success: true, graph: Some(root_id), error: None })
+            (StatusCode::OK, Json(GenerateGraphResponse { success: true, graph: Some(root_id), error: None }))
         }
         Ok(Ok(Err(e))) => {
             println!("❌ Received error response from worker: {}", e);
@@ -272,21 +275,21 @@ async fn generate_graph_handler(
                     "error": e.clone()
                 })),
             });
-            Json(GenerateGraphResponse {
+            (StatusCode::OK, Json(GenerateGraphResponse {
                 success: false,
                 graph: None,
                 error: Some(e),
-            })
+            }))
         }
         _ => {
             println!("⏰ Request timed out waiting for worker response");
-            // Timed out or error - fallback to simple mock
-            let graph_id = format!("graph_{}", chrono::Utc::now().timestamp());
-            let mock_nodes = vec![serde_json::json!({"id": "root", "label": format!("Goal: {}", goal), "type": "intent", "status": "active"})];
-            let mock_edges = vec![];
-            let _ = state.tx.send(ViewerEvent::GraphGenerated { root_id: graph_id.clone(), graph_id: graph_id.clone(), nodes: mock_nodes.clone(), edges: mock_edges.clone() });
-            let _ = state.tx.send(ViewerEvent::StepLog { step: "GraphGeneration".to_string(), status: "completed".to_string(), message: "Graph generation (timeout/fallback) completed".to_string(), details: None });
-            Json(GenerateGraphResponse { success: true, graph: Some(graph_id), error: Some("CCOS generation timed out or failed, used fallback".to_string()) })
+            let _ = state.tx.send(ViewerEvent::StepLog {
+                step: "GraphGeneration".to_string(),
+                status: "error".to_string(),
+                message: "Graph generation timed out".to_string(),
+                details: Some(serde_json::json!({ "goal": goal.clone(), "error": "timeout" })),
+            });
+            (StatusCode::GATEWAY_TIMEOUT, Json(GenerateGraphResponse { success: false, graph: None, error: Some("Graph generation timed out".to_string()) }))
         }
     }
 }
@@ -294,7 +297,7 @@ async fn generate_graph_handler(
 async fn generate_plans_handler(
     State(state): State<Arc<AppState>>,
     Json(payload): Json<GeneratePlansRequest>,
-) -> Json<GeneratePlansResponse> {
+) -> (StatusCode, Json<GeneratePlansResponse>) {
     println!("📨 Received generate-plans request for graph: {}", payload.graph_id);
     let graph_id = payload.graph_id.clone();
 
@@ -316,12 +319,12 @@ async fn generate_plans_handler(
         resp: resp_tx 
     };
 
-    if let Err(_e) = state.plan_req_tx.clone().try_send(req) {
-        return Json(GeneratePlansResponse {
+    if let Err(e) = enqueue(&state.plan_req_tx, req, ENQUEUE_TIMEOUT).await {
+        return (e.status_code(), Json(GeneratePlansResponse {
             success: false,
             plans: vec![],
-            error: Some("Plan generation service unavailable".to_string()),
-        });
+            error: Some(e.message("Plan generation")),
+        }));
     }
 
     // Await response with timeout
@@ -369,7 +372,7 @@ async fn generate_plans_handler(
                     "plan_count": plans.len()
                 })),
             });
-            Json(GeneratePlansResponse { success: true, plans, error: None })
+            (StatusCode::OK, Json(GeneratePlansResponse { success: true, plans, error: None }))
         }
         Ok(Ok(Err(e))) => {
             let _ = state.tx.send(ViewerEvent::StepLog {
@@ -381,11 +384,11 @@ async fn generate_plans_handler(
                     "error": e.clone()
                 })),
             });
-    Json(GeneratePlansResponse {
+    (StatusCode::OK, Json(GeneratePlansResponse {
                 success: false,
                 plans: vec![],
                 error: Some(e),
-            })
+            }))
         }
         _ => {
             let _ = state.tx.send(ViewerEvent::StepLog {
@@ -397,11 +400,11 @@ async fn generate_plans_handler(
                     "error": "timeout"
                 })),
             });
-    Json(GeneratePlansResponse {
+            (StatusCode::GATEWAY_TIMEOUT, Json(GeneratePlansResponse {
                 success: false,
                 plans: vec![],
                 error: Some("Plan generation timed out".to_string()),
-            })
+            }))
         }
     }
 }
@@ -409,7 +412,7 @@ async fn generate_plans_handler(
 async fn execute_handler(
     State(state): State<Arc<AppState>>,
     Json(payload): Json<ExecuteRequest>,
-) -> Json<ExecuteResponse> {
+) -> (StatusCode, Json<ExecuteResponse>) {
     let graph_id = payload.graph_id.clone();
 
     // Broadcast that execution has started
@@ -427,13 +430,13 @@ async fn execute_handler(
     let (resp_tx, resp_rx) = oneshot::channel();
     let req = ExecuteRequestInternal { graph_id: graph_id.clone(), resp: resp_tx };
 
-    if let Err(_e) = state.execute_req_tx.clone().try_send(req) {
-        return Json(ExecuteResponse {
+    if let Err(e) = enqueue(&state.execute_req_tx, req, ENQUEUE_TIMEOUT).await {
+        return (e.status_code(), Json(ExecuteResponse {
             success: false,
             result: None,
-            error: Some("Execution service unavailable".to_string()),
+            error: Some(e.message("Execution")),
             execution_id: None,
-        });
+        }));
     }
 
     // Await response with timeout
@@ -455,7 +458,7 @@ async fn execute_handler(
                     "result": result_text.clone()
                 })),
             });
-            Json(ExecuteResponse { success: true, result: Some(result_text), error: None, execution_id: exec_id_opt })
+            (StatusCode::OK, Json(ExecuteResponse { success: true, result: Some(result_text), error: None, execution_id: exec_id_opt }))
         }
         Ok(Ok(Err(e))) => {
             let _ = state.tx.send(ViewerEvent::StepLog {
@@ -467,7 +470,7 @@ async fn execute_handler(
                     "error": e.clone()
                 })),
             });
-            Json(ExecuteResponse { success: false, result: None, error: Some(e), execution_id: None })
+            (StatusCode::OK, Json(ExecuteResponse { success: false, result: None, error: Some(e), execution_id: None }))
         }
         _ => {
             let _ = state.tx.send(ViewerEvent::StepLog {
@@ -479,7 +482,7 @@ async fn execute_handler(
                     "error": "timeout"
                 })),
             });
-            Json(ExecuteResponse { success: false, result: None, error: Some("Execution timed out".to_string()), execution_id: None })
+            (StatusCode::GATEWAY_TIMEOUT, Json(ExecuteResponse { success: false, result: None, error: Some("Execution timed out".to_string()), execution_id: None }))
         }
     }
 }
@@ -487,7 +490,7 @@ async fn execute_handler(
 async fn load_graph_handler(
     State(state): State<Arc<AppState>>,
     Json(payload): Json<LoadGraphRequest>,
-) -> Json<LoadGraphResponse> {
+) -> (StatusCode, Json<LoadGraphResponse>) {
     println!("📨 Received load-graph request with {} nodes and {} edges",
              payload.nodes.len(), payload.edges.len());
 
@@ -500,38 +503,38 @@ async fn load_graph_handler(
         resp: resp_tx,
     };
 
-    if let Err(_e) = state.load_graph_req_tx.clone().try_send(req) {
-        return Json(LoadGraphResponse {
+    if let Err(e) = enqueue(&state.load_graph_req_tx, req, ENQUEUE_TIMEOUT).await {
+        return (e.status_code(), Json(LoadGraphResponse {
             success: false,
             graph_id: None,
-            error: Some("Load graph service unavailable".to_string()),
-        });
+            error: Some(e.message("Load graph")),
+        }));
     }
 
     // Await response with timeout
     match tokio::time::timeout(std::time::Duration::from_secs(30), resp_rx).await {
         Ok(Ok(Ok(graph_id))) => {
             println!("✅ Successfully loaded graph with ID: {}", graph_id);
-            Json(LoadGraphResponse {
+            (StatusCode::OK, Json(LoadGraphResponse {
         success: true,
                 graph_id: Some(graph_id),
         error: None,
-    })
+    }))
         }
         Ok(Ok(Err(e))) => {
             println!("❌ Failed to load graph: {}", e);
-            Json(LoadGraphResponse {
+            (StatusCode::OK, Json(LoadGraphResponse {
                 success: false,
                 graph_id: None,
                 error: Some(e),
-            })
+            }))
         }
         _ => {
-            Json(LoadGraphResponse {
+            (StatusCode::GATEWAY_TIMEOUT, Json(LoadGraphResponse {
                 success: false,
                 graph_id: None,
                 error: Some("Load graph timed out".to_string()),
-            })
+            }))
         }
     }
 }
@@ -539,7 +542,7 @@ async fn load_graph_handler(
 async fn get_plans_handler(
     State(state): State<Arc<AppState>>,
     Json(payload): Json<GetPlansRequest>,
-) -> Json<GetPlansResponse> {
+) -> (StatusCode, Json<GetPlansResponse>) {
     println!("📨 Received get-plans request for graph: {}", payload.graph_id);
 
     // Send request to background CCOS worker
@@ -549,38 +552,38 @@ async fn get_plans_handler(
         resp: resp_tx,
     };
 
-    if let Err(_e) = state.get_plans_req_tx.clone().try_send(req) {
-        return Json(GetPlansResponse {
+    if let Err(e) = enqueue(&state.get_plans_req_tx, req, ENQUEUE_TIMEOUT).await {
+        return (e.status_code(), Json(GetPlansResponse {
             success: false,
             plans: None,
-            error: Some("Get plans service unavailable".to_string()),
-        });
+            error: Some(e.message("Get plans")),
+        }));
     }
 
     // Await response with timeout
     match tokio::time::timeout(std::time::Duration::from_secs(10), resp_rx).await {
         Ok(Ok(Ok(plans))) => {
             println!("✅ Successfully retrieved {} plans for graph: {}", plans.len(), payload.graph_id);
-            Json(GetPlansResponse {
+            (StatusCode::OK, Json(GetPlansResponse {
                 success: true,
                 plans: Some(plans),
                 error: None,
-            })
+            }))
         }
         Ok(Ok(Err(e))) => {
             println!("❌ Failed to get plans: {}", e);
-            Json(GetPlansResponse {
+            (StatusCode::OK, Json(GetPlansResponse {
                 success: false,
                 plans: None,
                 error: Some(e),
-            })
+            }))
         }
         _ => {
-            Json(GetPlansResponse {
+            (StatusCode::GATEWAY_TIMEOUT, Json(GetPlansResponse {
                 success: false,
                 plans: None,
                 error: Some("Get plans timed out".to_string()),
-            })
+            }))
         }
     }
 }
@@ -702,12 +705,12 @@ async fn main() {
     let (tx, _) = broadcast::channel(100);
 
     // Channels for requests to the CCOS worker
-    let (graph_req_tx, graph_req_rx) = mpsc::channel::<GraphRequest>(16);
-    let (plan_req_tx, plan_req_rx) = mpsc::channel::<PlanRequest>(16);
-    let (execute_req_tx, execute_req_rx) = mpsc::channel::<ExecuteRequestInternal>(16);
-    let (load_graph_req_tx, load_graph_req_rx) = mpsc::channel::<LoadGraphRequestInternal>(16);
-    let (get_plans_req_tx, mut get_plans_req_rx) = mpsc::channel::<GetPlansRequestInternal>(16);
-    let (architecture_req_tx, architecture_req_rx) = mpsc::channel::<ArchitectureRequestInternal>(16);
+    let (graph_req_tx, graph_req_rx) = mpsc::channel::<GraphRequest>(WORKER_QUEUE_CAPACITY);
+    let (plan_req_tx, plan_req_rx) = mpsc::channel::<PlanRequest>(WORKER_QUEUE_CAPACITY);
+    let (execute_req_tx, execute_req_rx) = mpsc::channel::<ExecuteRequestInternal>(WORKER_QUEUE_CAPACITY);
+    let (load_graph_req_tx, load_graph_req_rx) = mpsc::channel::<LoadGraphRequestInternal>(WORKER_QUEUE_CAPACITY);
+    let (get_plans_req_tx, mut get_plans_req_rx) = mpsc::channel::<GetPlansRequestInternal>(WORKER_QUEUE_CAPACITY);
+    let (architecture_req_tx, architecture_req_rx) = mpsc::channel::<ArchitectureRequestInternal>(WORKER_QUEUE_CAPACITY);
 
     // Spawn a dedicated thread that runs a current-thread Tokio runtime + LocalSet
     // This mirrors the example's pattern so we can call non-Send LLM-backed arbiter methods.
@@ -1682,19 +1685,19 @@ struct ArchQuery {
 async fn architecture_handler(
     State(state): State<Arc<AppState>>,
     axum::extract::Query(q): axum::extract::Query<ArchQuery>,
-) -> Json<serde_json::Value> {
+) -> (StatusCode, Json<serde_json::Value>) {
     let include_caps = q.include.as_deref().map(|s| s.split(',').any(|t| t.trim()=="capabilities")).unwrap_or(false);
     let recent_intents_limit = q.recent_intents.unwrap_or(5).min(50);
     let cap_limit = q.cap_limit;
     let (resp_tx, resp_rx) = oneshot::channel();
     let req = ArchitectureRequestInternal { include_capabilities: include_caps, recent_intents_limit, cap_limit, resp: resp_tx };
-    if let Err(_e) = state.architecture_req_tx.clone().try_send(req) {
-        return Json(serde_json::json!({"error":"architecture service unavailable"}));
+    if let Err(e) = enqueue(&state.architecture_req_tx, req, ENQUEUE_TIMEOUT).await {
+        return (e.status_code(), Json(serde_json::json!({"error": e.message("Architecture")})));
     }
     match tokio::time::timeout(std::time::Duration::from_secs(10), resp_rx).await {
-        Ok(Ok(Ok(v))) => Json(v),
-        Ok(Ok(Err(e))) => Json(serde_json::json!({"error":e})),
-        _ => Json(serde_json::json!({"error":"timeout"})),
+        Ok(Ok(Ok(v))) => (StatusCode::OK, Json(v)),
+        Ok(Ok(Err(e))) => (StatusCode::OK, Json(serde_json::json!({"error":e}))),
+        _ => (StatusCode::GATEWAY_TIMEOUT, Json(serde_json::json!({"error":"timeout"}))),
     }
 }
 
@@ -0,0 +1,67 @@
+//! Bounded request queues between HTTP handlers and the CCOS worker thread.
+//!
+//! Handlers enqueue work with `enqueue`, which waits (up to a deadline) for
+//! queue capacity instead of failing immediately. When the worker cannot keep
+//! up, the caller gets an explicit `EnqueueError` that maps to
+//! `503 Service Unavailable` rather than a silently fabricated result.
+
+use axum::http::StatusCode;
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tokio::sync::mpsc::error::SendTimeoutError;
+
+/// Capacity of each worker request queue.
+pub const WORKER_QUEUE_CAPACITY: usize = 16;
+
+/// How long a handler waits for queue capacity before reporting overload.
+pub const ENQUEUE_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Why a request could not be handed to the worker.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EnqueueError {
+    /// The queue stayed full for the whole enqueue timeout.
+    Overloaded,
+    /// The worker dropped its receiver (crashed or shut down).
+    WorkerUnavailable,
+}
+
+impl EnqueueError {
+    /// HTTP status to report for this error.
+    pub fn status_code(&self) -> StatusCode {
+        StatusCode::SERVICE_UNAVAILABLE
+    }
+
+    /// Human readable error for the given service name (e.g. "Graph generation").
+    pub fn message(&self, service: &str) -> String {
+        match self {
+            EnqueueError::Overloaded => {
+                format!("{} service overloaded, retry later", service)
+            }
+            EnqueueError::WorkerUnavailable => format!("{} service unavailable", service),
+        }
+    }
+}
+
+impl std::fmt::Display for EnqueueError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EnqueueError::Overloaded => write!(f, "worker queue full"),
+            EnqueueError::WorkerUnavailable => write!(f, "worker queue closed"),
+        }
+    }
+}
+
+impl std::error::Error for EnqueueError {}
+
+/// Send `req` to the worker, waiting up to `timeout` for queue capacity.
+pub async fn enqueue<T>(
+    tx: &mpsc::Sender<T>,
+    req: T,
+    timeout: Duration,
+) -> Result<(), EnqueueError> {
+    match tx.send_timeout(req, timeout).await {
+        Ok(()) => Ok(()),
+        Err(SendTimeoutError::Timeout(_)) => Err(EnqueueError::Overloaded),
+        Err(SendTimeoutError::Closed(_)) => Err(EnqueueError::WorkerUnavailable),
+    }
+}
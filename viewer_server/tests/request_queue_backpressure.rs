@@ -0,0 +1,47 @@
+//! Exhausting a worker request queue must surface an explicit overload error
+//! (mapped to 503) instead of a fabricated success.
+
+use std::time::Duration;
+use tokio::sync::mpsc;
+use viewer_server::request_queue::{enqueue, EnqueueError};
+
+#[tokio::test]
+async fn full_queue_reports_overload() {
+    let (tx, _rx) = mpsc::channel::<u32>(2);
+    let wait = Duration::from_millis(50);
+
+    enqueue(&tx, 1, wait).await.expect("first slot");
+    enqueue(&tx, 2, wait).await.expect("second slot");
+
+    let err = enqueue(&tx, 3, wait).await.unwrap_err();
+    assert_eq!(err, EnqueueError::Overloaded);
+    assert_eq!(err.status_code(), axum::http::StatusCode::SERVICE_UNAVAILABLE);
+    assert!(err.message("Graph generation").contains("overloaded"));
+}
+
+#[tokio::test]
+async fn queue_waits_for_capacity_when_worker_drains() {
+    let (tx, mut rx) = mpsc::channel::<u32>(1);
+    enqueue(&tx, 1, Duration::from_millis(50)).await.unwrap();
+
+    // Worker frees a slot shortly after; the sender should wait rather than fail
+    tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        rx.recv().await;
+        rx.recv().await
+    });
+
+    enqueue(&tx, 2, Duration::from_secs(1))
+        .await
+        .expect("back-pressured send should succeed once capacity frees");
+}
+
+#[tokio::test]
+async fn closed_queue_reports_worker_unavailable() {
+    let (tx, rx) = mpsc::channel::<u32>(1);
+    drop(rx);
+
+    let err = enqueue(&tx, 1, Duration::from_millis(50)).await.unwrap_err();
+    assert_eq!(err, EnqueueError::WorkerUnavailable);
+    assert_eq!(err.status_code(), axum::http::StatusCode::SERVICE_UNAVAILABLE);
+}